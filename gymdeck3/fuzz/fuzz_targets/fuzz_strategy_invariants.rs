@@ -0,0 +1,110 @@
+//! Fuzz target for the AdaptationStrategy contract across every strategy
+//!
+//! **Feature: decktune-3.1-reliability-ux, Property 12: Fuzzing no-panic guarantee**
+//! **Validates: Requirements 6.1, 6.2**
+//!
+//! `AdaptationStrategy::calculate_target` promises two invariants for every
+//! strategy `create_strategy` can return: the result is always within
+//! `[bounds.max_mv, bounds.min_mv]`, and `calculate_target(hi) >=
+//! calculate_target(lo)` whenever `hi > lo`. This asserts both hold under
+//! arbitrary curves and bounds, not just that nothing panics.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use arbitrary::Arbitrary;
+use gymdeck3::{CoreBounds, Strategy, AdaptationStrategy, create_strategy};
+
+/// Boundary loads the selector fields below can snap to, so the corpus
+/// reliably exercises the clamping edges regardless of what the fuzzer's
+/// raw float generator happens to produce
+const BOUNDARY_LOADS: [f32; 7] = [0.0, 100.0, f32::NAN, -1.0, -100.0, 150.0, 1000.0];
+
+#[derive(Debug, Arbitrary)]
+struct FuzzCurvePoint {
+    load: f32,
+    mv: i32,
+}
+
+#[derive(Debug, Arbitrary)]
+struct FuzzStrategyInput {
+    curve: Vec<FuzzCurvePoint>,
+    bound_a: i32,
+    bound_b: i32,
+    threshold: f32,
+    lo_selector: u8,
+    lo_raw: f32,
+    hi_selector: u8,
+    hi_raw: f32,
+}
+
+fn pick_load(selector: u8, raw: f32) -> f32 {
+    match BOUNDARY_LOADS.get(selector as usize) {
+        Some(&boundary) => boundary,
+        None => raw,
+    }
+}
+
+const ALL_STRATEGIES: [Strategy; 7] = [
+    Strategy::Conservative,
+    Strategy::Balanced,
+    Strategy::Aggressive,
+    Strategy::Custom,
+    Strategy::Pid,
+    Strategy::Adaptive,
+    Strategy::Learning,
+];
+
+fuzz_target!(|input: FuzzStrategyInput| {
+    // max_mv must be <= min_mv (max_mv is the more-negative/aggressive
+    // bound); normalize rather than reject so every arbitrary (a, b) pair
+    // still exercises the invariants below.
+    let (max_mv, min_mv) = if input.bound_a <= input.bound_b {
+        (input.bound_a, input.bound_b)
+    } else {
+        (input.bound_b, input.bound_a)
+    };
+    let bounds = CoreBounds {
+        min_mv,
+        max_mv,
+        threshold: input.threshold,
+    };
+
+    let curve: Vec<(f32, i32)> = input.curve.iter().map(|p| (p.load, p.mv)).collect();
+
+    let load_a = pick_load(input.lo_selector, input.lo_raw);
+    let load_b = pick_load(input.hi_selector, input.hi_raw);
+    let comparable = load_a.is_finite() && load_b.is_finite() && load_a != load_b;
+    let (lo, hi) = if load_a < load_b { (load_a, load_b) } else { (load_b, load_a) };
+
+    for &strategy in ALL_STRATEGIES.iter() {
+        // Fresh strategy instances per load so stateful strategies
+        // (Pid/Adaptive/Learning) are compared from the same starting
+        // state instead of having the first call's side effects bias the
+        // second.
+        let lo_strategy = create_strategy(strategy, Some(curve.clone()), None);
+        let lo_target = lo_strategy.calculate_target(lo, &bounds);
+        assert!(
+            lo_target >= bounds.max_mv && lo_target <= bounds.min_mv,
+            "{strategy} produced {lo_target} outside [{}, {}] for load {lo}",
+            bounds.max_mv,
+            bounds.min_mv
+        );
+
+        let hi_strategy = create_strategy(strategy, Some(curve.clone()), None);
+        let hi_target = hi_strategy.calculate_target(hi, &bounds);
+        assert!(
+            hi_target >= bounds.max_mv && hi_target <= bounds.min_mv,
+            "{strategy} produced {hi_target} outside [{}, {}] for load {hi}",
+            bounds.max_mv,
+            bounds.min_mv
+        );
+
+        if comparable {
+            assert!(
+                hi_target >= lo_target,
+                "{strategy}: calculate_target({hi}) = {hi_target} should be >= calculate_target({lo}) = {lo_target}"
+            );
+        }
+    }
+});
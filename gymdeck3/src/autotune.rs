@@ -0,0 +1,521 @@
+//! Tree-structured Parzen Estimator (TPE) search over the ryzenadj
+//! power-limit space
+//!
+//! Brute-force sweeping STAPM, fast/slow PPT, TDC/EDC, and per-core curve
+//! offset isn't practical - the space is large and every candidate costs a
+//! real apply-and-measure cycle via `RyzenadjExecutor` and `LoadMonitor`.
+//! `AutoTuner` instead keeps a running list of `(config, score)` trials and,
+//! after a cold start of random configs, models which parameter values tend
+//! to score well: it sorts trials by score, splits them into a "good" top
+//! `gamma` fraction and a "bad" remainder, and for each dimension builds two
+//! Gaussian kernel density estimates - `l(x)` from the good values and
+//! `g(x)` from the bad ones (bandwidth via Scott's rule, plus a uniform
+//! prior over the dimension's legal range so density never collapses to
+//! zero away from observed points). The next candidate for each dimension
+//! is whichever of several draws from `l(x)` maximizes `l(x)/g(x)` - the
+//! classic TPE expected-improvement proxy. A config known to be unstable
+//! (tripped `MAX_CONSECUTIVE_FAILURES`, failed validation, or simply
+//! produced a hang) should be recorded with `AutoTuner::WORST_SCORE` so the
+//! search learns to avoid that region instead of getting stuck re-probing
+//! it.
+//!
+//! This module only models the search; it has no opinion on what the
+//! dimensions mean or how the winning config gets applied. The caller
+//! defines one `ParamSpec` per tunable (STAPM, PPT, TDC/EDC, a per-core
+//! curve offset, ...), drives the apply-and-measure loop, and interprets
+//! the returned `Vec<f64>` back into real ryzenadj arguments.
+//!
+//! The same `Vec<f64>` config representation backs [`minimize_failing_config`],
+//! a delta-debugging shrink routine: given a config known to trigger
+//! instability, it finds the smallest subset of dimensions that still
+//! reproduces the fault, so a user can pin the blame on, say, a single
+//! core's undervolt instead of the whole applied config.
+
+use std::f64::consts::PI;
+
+/// Fraction of trials, sorted by score, that fall in the "good" set used to
+/// build `l(x)`
+pub const DEFAULT_GAMMA: f64 = 0.25;
+
+/// Number of purely random configs sampled before the KDE-guided search
+/// kicks in - too few trials to estimate a meaningful `l(x)`/`g(x)` split
+pub const DEFAULT_COLD_START: usize = 10;
+
+/// Number of candidates drawn from `l(x)` per dimension when proposing the
+/// next config; the one maximizing `l(x)/g(x)` wins
+pub const DEFAULT_CANDIDATES_PER_DIM: usize = 24;
+
+/// Weight given to a uniform prior over `[min, max]` when estimating
+/// density, mixed in alongside the kernel density estimate so a region with
+/// no observations yet still has nonzero density instead of ruling out
+/// exploration entirely
+const PRIOR_WEIGHT: f64 = 0.1;
+
+/// Floor placed under `g(x)` so a dimension with an empty (or
+/// all-identical) bad set can't produce a divide-by-near-zero ratio
+const MIN_DENSITY: f64 = 1e-9;
+
+/// Score assigned to a config known to be unstable - tripped
+/// `MAX_CONSECUTIVE_FAILURES`, failed validation, or hung - so the search
+/// learns to avoid that region rather than treating it as merely mediocre
+pub const WORST_SCORE: f64 = f64::NEG_INFINITY;
+
+/// One tunable dimension of the search space: bounds and an optional step
+/// (e.g. ryzenadj only accepts certain granularities)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParamSpec {
+    /// Lower bound, inclusive
+    pub min: f64,
+    /// Upper bound, inclusive
+    pub max: f64,
+    /// Legal increment; `0.0` means continuous (no snapping)
+    pub step: f64,
+}
+
+impl ParamSpec {
+    /// A continuous dimension over `[min, max]`
+    pub fn continuous(min: f64, max: f64) -> Self {
+        ParamSpec { min, max, step: 0.0 }
+    }
+
+    /// A stepped dimension over `[min, max]`, e.g. a milliwatt-granular
+    /// power limit
+    pub fn stepped(min: f64, max: f64, step: f64) -> Self {
+        ParamSpec { min, max, step }
+    }
+
+    fn range(&self) -> f64 {
+        (self.max - self.min).max(0.0)
+    }
+
+    /// Clamp to bounds and, if stepped, snap to the nearest legal increment
+    fn snap(&self, value: f64) -> f64 {
+        let clamped = value.clamp(self.min, self.max);
+        if self.step <= 0.0 {
+            return clamped;
+        }
+        let steps = ((clamped - self.min) / self.step).round();
+        (self.min + steps * self.step).clamp(self.min, self.max)
+    }
+}
+
+/// Small, dependency-free deterministic PRNG (SplitMix64) so tuning runs
+/// are reproducible from a seed without pulling in an external `rand` dep
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform float in `[0.0, 1.0)`
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Uniform integer in `[0, bound)`; `bound` must be non-zero
+    fn next_below(&mut self, bound: usize) -> usize {
+        (self.next_u64() as usize) % bound
+    }
+
+    /// Standard normal sample via Box-Muller
+    fn next_gaussian(&mut self) -> f64 {
+        let u1 = self.next_f64().max(1e-12);
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+    }
+}
+
+/// One evaluated `(config, score)` pair, where higher `score` is better
+struct Trial {
+    config: Vec<f64>,
+    score: f64,
+}
+
+/// TPE search over a fixed set of `ParamSpec` dimensions
+pub struct AutoTuner {
+    specs: Vec<ParamSpec>,
+    trials: Vec<Trial>,
+    cold_start: usize,
+    gamma: f64,
+    candidates_per_dim: usize,
+    rng: SplitMix64,
+}
+
+impl AutoTuner {
+    /// Create a tuner over `specs`, seeded for reproducibility
+    pub fn new(specs: Vec<ParamSpec>, seed: u64) -> Self {
+        AutoTuner {
+            specs,
+            trials: Vec::new(),
+            cold_start: DEFAULT_COLD_START,
+            gamma: DEFAULT_GAMMA,
+            candidates_per_dim: DEFAULT_CANDIDATES_PER_DIM,
+            rng: SplitMix64::new(seed),
+        }
+    }
+
+    /// Override the number of random configs sampled before the KDE-guided
+    /// search kicks in
+    pub fn with_cold_start(mut self, cold_start: usize) -> Self {
+        self.cold_start = cold_start;
+        self
+    }
+
+    /// Override the good/bad split fraction
+    pub fn with_gamma(mut self, gamma: f64) -> Self {
+        self.gamma = gamma.clamp(0.01, 0.99);
+        self
+    }
+
+    /// Override the number of `l(x)` candidates drawn per dimension
+    pub fn with_candidates_per_dim(mut self, candidates_per_dim: usize) -> Self {
+        self.candidates_per_dim = candidates_per_dim.max(1);
+        self
+    }
+
+    /// Number of trials recorded so far
+    pub fn trial_count(&self) -> usize {
+        self.trials.len()
+    }
+
+    /// Record the measured `score` for a previously-suggested (or
+    /// caller-constructed) `config`. Use `WORST_SCORE` for a config that
+    /// proved unstable.
+    pub fn record(&mut self, config: Vec<f64>, score: f64) {
+        self.trials.push(Trial { config, score });
+    }
+
+    /// The best `(config, score)` seen so far, if any trials have been
+    /// recorded
+    pub fn best(&self) -> Option<(&[f64], f64)> {
+        self.trials
+            .iter()
+            .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|t| (t.config.as_slice(), t.score))
+    }
+
+    /// Propose the next config to evaluate: uniform random during the cold
+    /// start, otherwise one TPE-guided candidate per dimension
+    pub fn suggest(&mut self) -> Vec<f64> {
+        if self.trials.len() < self.cold_start {
+            return self.random_config();
+        }
+
+        let mut sorted: Vec<usize> = (0..self.trials.len()).collect();
+        sorted.sort_by(|&a, &b| {
+            self.trials[b]
+                .score
+                .partial_cmp(&self.trials[a].score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let n_good = ((sorted.len() as f64 * self.gamma).ceil() as usize)
+            .clamp(1, sorted.len().saturating_sub(1).max(1));
+        let (good_idx, bad_idx) = sorted.split_at(n_good);
+
+        let dims = self.specs.len();
+        let mut config = Vec::with_capacity(dims);
+        for dim in 0..dims {
+            let good_vals: Vec<f64> = good_idx.iter().map(|&i| self.trials[i].config[dim]).collect();
+            let bad_vals: Vec<f64> = bad_idx.iter().map(|&i| self.trials[i].config[dim]).collect();
+            let spec = self.specs[dim];
+
+            let good_bw = scott_bandwidth(&good_vals, spec.range());
+            let bad_bw = scott_bandwidth(&bad_vals, spec.range());
+
+            let mut best_value = good_vals.first().copied().unwrap_or(spec.min);
+            let mut best_ratio = f64::MIN;
+            for _ in 0..self.candidates_per_dim {
+                let candidate = self.sample_candidate(&good_vals, good_bw, &spec);
+                let l = kde_density(candidate, &good_vals, good_bw, &spec);
+                let g = kde_density(candidate, &bad_vals, bad_bw, &spec).max(MIN_DENSITY);
+                let ratio = l / g;
+                if ratio > best_ratio {
+                    best_ratio = ratio;
+                    best_value = candidate;
+                }
+            }
+            config.push(best_value);
+        }
+        config
+    }
+
+    fn random_config(&mut self) -> Vec<f64> {
+        self.specs
+            .iter()
+            .map(|spec| {
+                let value = spec.min + self.rng.next_f64() * spec.range();
+                spec.snap(value)
+            })
+            .collect()
+    }
+
+    /// Draw one candidate from `l(x)`: pick an existing good observation (or
+    /// the uniform prior, with `PRIOR_WEIGHT` probability, so the search
+    /// can't starve an unexplored sub-range) and jitter it by one
+    /// bandwidth's worth of Gaussian noise
+    fn sample_candidate(&mut self, good_vals: &[f64], bandwidth: f64, spec: &ParamSpec) -> f64 {
+        let center = if good_vals.is_empty() || self.rng.next_f64() < PRIOR_WEIGHT {
+            spec.min + self.rng.next_f64() * spec.range()
+        } else {
+            good_vals[self.rng.next_below(good_vals.len())]
+        };
+        spec.snap(center + self.rng.next_gaussian() * bandwidth)
+    }
+}
+
+/// Scott's rule bandwidth for a univariate Gaussian KDE: `sigma * n^(-1/5)`.
+/// Falls back to a small fraction of the dimension's range when there are
+/// too few observations (or they're all identical) to estimate a spread.
+fn scott_bandwidth(values: &[f64], range: f64) -> f64 {
+    if values.len() < 2 {
+        return (range * 0.1).max(1e-6);
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    let sigma = variance.sqrt();
+    // A zero (or near-zero) spread - e.g. every good trial happened to
+    // land on the same step - would otherwise collapse the kernel to a
+    // spike; floor it at a small fraction of the legal range instead.
+    (sigma * n.powf(-1.0 / 5.0)).max(range * 0.01).max(1e-6)
+}
+
+/// Density at `value` under a mixture of `observations`' Gaussian kernels
+/// (bandwidth `bandwidth`) and a uniform prior over `spec`'s legal range
+fn kde_density(value: f64, observations: &[f64], bandwidth: f64, spec: &ParamSpec) -> f64 {
+    let n = observations.len() as f64;
+    let kernel_density = if observations.is_empty() {
+        0.0
+    } else {
+        observations.iter().map(|&o| gaussian_pdf(value, o, bandwidth)).sum::<f64>() / n
+    };
+    let range = spec.range();
+    let prior_density = if range > 0.0 { 1.0 / range } else { 0.0 };
+    (1.0 - PRIOR_WEIGHT) * kernel_density + PRIOR_WEIGHT * prior_density
+}
+
+fn gaussian_pdf(x: f64, mu: f64, sigma: f64) -> f64 {
+    let sigma = sigma.max(1e-9);
+    let z = (x - mu) / sigma;
+    (-0.5 * z * z).exp() / (sigma * (2.0 * PI).sqrt())
+}
+
+/// Shrink `failing_config` toward `baseline` one dimension at a time,
+/// keeping each reduction only if `reproduces` still confirms the fault
+///
+/// `baseline` must already be known not to fault (e.g. every core curve
+/// offset zeroed, every limit at stock), so there's always somewhere safe
+/// to converge to and the search is guaranteed to terminate. `reproduces`
+/// is the caller's real hardware retest - wrapping whatever timeout-bounded
+/// apply/observe cycle backs `simulate_failure_sequence` or a hang
+/// detector - and must itself treat "no fault within its bounded window"
+/// as `false`, never block indefinitely.
+///
+/// Repeatedly passes over every dimension, pulling it all the way to the
+/// baseline value and keeping the pull only if the fault still reproduces
+/// (otherwise reverting it); a pass that makes no further reductions is a
+/// fixed point, and the surviving config is the minimal one still known to
+/// trigger the fault.
+///
+/// # Panics
+///
+/// If `failing_config` and `baseline` have different lengths.
+pub fn minimize_failing_config<F>(failing_config: &[f64], baseline: &[f64], mut reproduces: F) -> Vec<f64>
+where
+    F: FnMut(&[f64]) -> bool,
+{
+    assert_eq!(
+        failing_config.len(),
+        baseline.len(),
+        "failing_config and baseline must have the same number of dimensions"
+    );
+
+    let mut current = failing_config.to_vec();
+    loop {
+        let mut changed = false;
+        for i in 0..current.len() {
+            if current[i] == baseline[i] {
+                continue;
+            }
+            let mut candidate = current.clone();
+            candidate[i] = baseline[i];
+            if reproduces(&candidate) {
+                current = candidate;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn specs() -> Vec<ParamSpec> {
+        vec![
+            ParamSpec::stepped(5_000.0, 30_000.0, 1_000.0), // STAPM, mW
+            ParamSpec::stepped(5_000.0, 35_000.0, 1_000.0), // fast PPT, mW
+            ParamSpec::continuous(-30.0, 0.0),              // per-core curve offset
+        ]
+    }
+
+    #[test]
+    fn test_cold_start_produces_in_bounds_configs() {
+        let mut tuner = AutoTuner::new(specs(), 1).with_cold_start(10);
+        for _ in 0..10 {
+            let config = tuner.suggest();
+            assert_eq!(config.len(), specs().len());
+            for (value, spec) in config.iter().zip(specs().iter()) {
+                assert!(*value >= spec.min && *value <= spec.max);
+            }
+            tuner.record(config, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_stepped_dimension_always_snaps_to_step() {
+        let mut tuner = AutoTuner::new(specs(), 2).with_cold_start(15);
+        for _ in 0..15 {
+            let config = tuner.suggest();
+            let stapm_offset = config[0] - specs()[0].min;
+            assert!(
+                (stapm_offset / specs()[0].step).round() * specs()[0].step - stapm_offset < 1e-6,
+                "STAPM candidate {} did not snap to the configured step",
+                config[0]
+            );
+            tuner.record(config, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_guided_search_converges_toward_known_optimum() {
+        // Objective rewards being close to a known target per dimension;
+        // after enough guided trials the suggested config should land
+        // closer to the optimum than a purely random cold-start draw would.
+        let target = [20_000.0, 25_000.0, -15.0];
+        let objective = |config: &[f64]| -> f64 {
+            -config.iter().zip(target.iter()).map(|(v, t)| (v - t).powi(2)).sum::<f64>()
+        };
+
+        let mut tuner = AutoTuner::new(specs(), 7).with_cold_start(10);
+        for _ in 0..80 {
+            let config = tuner.suggest();
+            let score = objective(&config);
+            tuner.record(config, score);
+        }
+
+        let (best_config, best_score) = tuner.best().unwrap();
+        assert!(best_score > objective(&[5_000.0, 5_000.0, -30.0]));
+        assert!((best_config[2] - target[2]).abs() < 15.0);
+    }
+
+    #[test]
+    fn test_failed_configs_are_treated_as_worst_score() {
+        let mut tuner = AutoTuner::new(specs(), 3).with_cold_start(5);
+        for i in 0..5 {
+            let config = tuner.suggest();
+            let score = if i == 0 { WORST_SCORE } else { 1.0 };
+            tuner.record(config, score);
+        }
+
+        let (_, best_score) = tuner.best().unwrap();
+        assert!(best_score > WORST_SCORE);
+    }
+
+    #[test]
+    fn test_reproducible_with_same_seed() {
+        let run = |seed: u64| -> Vec<Vec<f64>> {
+            let mut tuner = AutoTuner::new(specs(), seed).with_cold_start(6);
+            let mut configs = Vec::new();
+            for i in 0..20 {
+                let config = tuner.suggest();
+                tuner.record(config.clone(), i as f64);
+                configs.push(config);
+            }
+            configs
+        };
+
+        assert_eq!(run(42), run(42));
+    }
+
+    #[test]
+    fn test_gamma_split_keeps_both_sets_nonempty() {
+        // With very few trials, the good/bad split must still leave at
+        // least one trial in the bad set so g(x) isn't degenerate.
+        let mut tuner = AutoTuner::new(specs(), 9).with_cold_start(2).with_gamma(0.9);
+        for i in 0..2 {
+            let config = tuner.suggest();
+            tuner.record(config, i as f64);
+        }
+        // Should not panic computing the next suggestion even with a
+        // lopsided gamma against a tiny trial count.
+        let _ = tuner.suggest();
+    }
+
+    #[test]
+    fn test_minimize_isolates_single_culprit_dimension() {
+        let failing = vec![10.0, -25.0, 5.0];
+        let baseline = vec![0.0, 0.0, 0.0];
+
+        // Only dimension 1 (the undervolt) actually causes the fault.
+        let minimal = minimize_failing_config(&failing, &baseline, |config| config[1] != 0.0);
+
+        assert_eq!(minimal, vec![0.0, -25.0, 0.0]);
+    }
+
+    #[test]
+    fn test_minimize_keeps_multiple_required_culprits() {
+        let failing = vec![10.0, -25.0, 5.0];
+        let baseline = vec![0.0, 0.0, 0.0];
+
+        // Fault only reproduces when BOTH dim 0 and dim 2 are off-baseline.
+        let minimal =
+            minimize_failing_config(&failing, &baseline, |config| config[0] != 0.0 && config[2] != 0.0);
+
+        assert_eq!(minimal, vec![10.0, 0.0, 5.0]);
+    }
+
+    #[test]
+    fn test_minimize_is_idempotent_on_already_minimal_config() {
+        let failing = vec![10.0, 0.0];
+        let baseline = vec![0.0, 0.0];
+
+        let minimal = minimize_failing_config(&failing, &baseline, |config| config[0] != 0.0);
+
+        assert_eq!(minimal, failing);
+    }
+
+    #[test]
+    fn test_minimize_never_reduces_below_what_reproduces() {
+        // A fault-blind predicate (always reproduces) should still let
+        // every dimension shrink all the way to baseline.
+        let failing = vec![10.0, -25.0, 5.0];
+        let baseline = vec![1.0, -2.0, 3.0];
+
+        let minimal = minimize_failing_config(&failing, &baseline, |_| true);
+
+        assert_eq!(minimal, baseline);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_minimize_panics_on_mismatched_lengths() {
+        minimize_failing_config(&[1.0, 2.0], &[0.0], |_| true);
+    }
+}
@@ -16,7 +16,17 @@
 //! - Hysteresis: 1% - 20%
 //! - Undervolt values: Must be negative or zero (0 = disabled)
 //! - Core bounds: max_mv must be more negative than min_mv
+//! - Hysteresis and per-core threshold text must round-trip exactly through
+//!   `f32` (see [`crate::precision`])
+//!
+//! Each `validate_*_value` function rejects out-of-range input outright.
+//! A `*_clamped` counterpart exists for the numeric validators (sample
+//! interval, hysteresis, core config, fan curve point) that instead
+//! saturates the value to the nearest bound and returns a `ClampWarning`
+//! per field adjusted, for callers that would rather salvage a config file
+//! than reject it over one bad field.
 
+use crate::fan::AcousticProfile;
 use clap::{Parser, ValueEnum};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -28,30 +38,33 @@ use std::path::PathBuf;
 #[command(version = "0.1.0")]
 #[command(about = "Dynamic undervolt controller daemon", long_about = None)]
 pub struct Args {
-    /// Adaptation strategy
-    #[arg(value_enum)]
-    pub strategy: Strategy,
+    /// Adaptation strategy. Required unless set via `--config`.
+    #[arg(value_enum, required_unless_present = "config")]
+    pub strategy: Option<Strategy>,
 
-    /// Sample interval in microseconds (10000-5000000, i.e., 10ms-5000ms)
-    #[arg(value_parser = validate_sample_interval)]
-    pub sample_interval_us: u64,
+    /// Sample interval in microseconds (10000-5000000, i.e., 10ms-5000ms).
+    /// Required unless set via `--config`.
+    #[arg(value_parser = validate_sample_interval, required_unless_present = "config")]
+    pub sample_interval_us: Option<u64>,
 
     /// Per-core configuration in format N:MIN:MAX:THRESHOLD
     /// Example: --core 0:-20:-35:50.0
     #[arg(long = "core", value_parser = parse_core_config)]
     pub cores: Vec<CoreConfig>,
 
-    /// Hysteresis margin percentage (1-20)
-    #[arg(long, default_value = "5.0", value_parser = validate_hysteresis)]
-    pub hysteresis: f32,
+    /// Hysteresis margin percentage (1-20). Falls back to `--config`, then
+    /// to `DEFAULT_HYSTERESIS`.
+    #[arg(long, value_parser = validate_hysteresis)]
+    pub hysteresis: Option<f32>,
 
     /// Path to ryzenadj binary
     #[arg(long = "ryzenadj-path", default_value = "ryzenadj")]
     pub ryzenadj_path: PathBuf,
 
-    /// Status output interval in milliseconds
-    #[arg(long = "status-interval", default_value = "1000")]
-    pub status_interval_ms: u64,
+    /// Status output interval in milliseconds. Falls back to `--config`,
+    /// then to `DEFAULT_STATUS_INTERVAL_MS`.
+    #[arg(long = "status-interval")]
+    pub status_interval_ms: Option<u64>,
 
     /// Enable verbose debug logging to stderr
     #[arg(long, short)]
@@ -72,6 +85,19 @@ pub struct Args {
     #[arg(long = "fan-curve", value_parser = parse_fan_curve_point)]
     pub fan_curve: Vec<FanCurvePointConfig>,
 
+    /// Quadratic fan curve coefficients in format A:B:C, for
+    /// `speed = A + B*temp + C*temp^2`. Mutually exclusive with --fan-curve.
+    /// Example: --fan-coeffs 10:0:0.02
+    #[arg(long = "fan-coeffs", value_parser = parse_fan_coeffs)]
+    pub fan_coeffs: Option<FanCurveCoeffs>,
+
+    /// Reinterpret --fan-coeffs over a normalized `[TMIN, TMAX]` window
+    /// instead of the absolute 0-100°C range: `x = (temp-TMIN)/(TMAX-TMIN)`
+    /// clamped to [0,1], `speed = 100 * (x*(x*A + B) + C)`. Requires
+    /// --fan-coeffs. Example: --fan-coeffs-range 40:85
+    #[arg(long = "fan-coeffs-range", value_parser = parse_fan_coeffs_range)]
+    pub fan_coeffs_range: Option<(i32, i32)>,
+
     /// Enable Zero RPM mode (fan stops below 45°C)
     #[arg(long = "fan-zero-rpm")]
     pub fan_zero_rpm: bool,
@@ -79,6 +105,133 @@ pub struct Args {
     /// Fan temperature hysteresis in °C (1-10)
     #[arg(long = "fan-hysteresis", default_value = "2", value_parser = validate_fan_hysteresis)]
     pub fan_hysteresis: i32,
+
+    /// Widened fan hysteresis in °C (1-20), applied instead of
+    /// --fan-hysteresis only while temperature is falling, to resist
+    /// audible down-ramp chatter (Marlin-style adaptive fan slowing)
+    #[arg(long = "fan-down-hysteresis", default_value = "4", value_parser = validate_fan_down_hysteresis)]
+    pub fan_down_hysteresis: i32,
+
+    /// Cap, in PWM units (0-255), on how much a single falling-temperature
+    /// tick may reduce the commanded fan duty by; 0 disables the cap
+    #[arg(long = "fan-slowdown-step-max", default_value = "0")]
+    pub fan_slowdown_step_max: u8,
+
+    /// PID fan setpoint temperature in °C (30-100). Required when
+    /// `--fan-mode pid` is selected. Also accepted as `--fan-target-temp`.
+    #[arg(long = "fan-setpoint", visible_alias = "fan-target-temp", value_parser = validate_fan_setpoint)]
+    pub fan_setpoint: Option<f32>,
+
+    /// PID fan gains in format KP:KI:KD. Falls back to a conservative
+    /// default (kp=2.0, ki=0.1, kd=0.5) when `--fan-mode pid` is selected
+    /// without this flag.
+    #[arg(long = "fan-pid", value_parser = parse_fan_pid_gains)]
+    pub fan_pid: Option<(f32, f32, f32)>,
+
+    // ==================== PID Strategy Options ====================
+    // Only valid (and only required) when `--strategy pid` is selected; see
+    // `validate_args`.
+
+    /// PID temperature setpoint in °C (40-95)
+    #[arg(long = "pid-target", value_parser = validate_pid_target)]
+    pub pid_target: Option<f32>,
+
+    /// PID proportional gain (must be positive)
+    #[arg(long = "pid-kp", value_parser = validate_pid_gain)]
+    pub pid_kp: Option<f32>,
+
+    /// PID integral gain (must be positive)
+    #[arg(long = "pid-ki", value_parser = validate_pid_gain)]
+    pub pid_ki: Option<f32>,
+
+    /// PID derivative gain (must be positive)
+    #[arg(long = "pid-kd", value_parser = validate_pid_gain)]
+    pub pid_kd: Option<f32>,
+
+    /// Clamp range for the raw PID output (before it's mapped onto the
+    /// per-core undervolt range), format MIN:MAX. Also the anti-windup range.
+    /// Example: --pid-output-clamp -50:50
+    #[arg(long = "pid-output-clamp", value_parser = parse_pid_output_clamp, allow_hyphen_values = true)]
+    pub pid_output_clamp: Option<(f32, f32)>,
+
+    // ==================== Thermal Derate Options ====================
+    // Tighten the per-core undervolt window as die temperature rises, so
+    // an aggressive baseline offset stable at idle can't keep applying
+    // once the chip is running hot. See `safety::clamp_value_thermal`.
+
+    /// Temperature (°C) at which thermal derating begins; below this, the
+    /// full configured undervolt window applies
+    #[arg(long = "derate-start", default_value = "80", value_parser = validate_derate_temp)]
+    pub derate_start: f32,
+
+    /// Temperature (°C) at which thermal derating is complete; at or above
+    /// this, only the safe end of the undervolt window is allowed
+    #[arg(long = "derate-end", default_value = "95", value_parser = validate_derate_temp)]
+    pub derate_end: f32,
+
+    // ==================== Sample Smoothing Options ====================
+    // Applied to load/temperature samples before strategy/fan-curve
+    // evaluation; see `smoothing` module. Only valid (and only required)
+    // when its mode is selected; see `validate_args`.
+
+    /// Smoothing filter applied to load/temperature inputs before
+    /// strategy/fan-curve evaluation, to decouple control responsiveness
+    /// from the raw `sample_interval_us` sampling rate
+    #[arg(long = "smoothing", value_enum, default_value = "none")]
+    pub smoothing: SmoothingMode,
+
+    /// Simple-moving-average window size in samples (>= 1). Required when
+    /// `--smoothing sma` is selected.
+    #[arg(long = "smoothing-window", value_parser = validate_smoothing_window)]
+    pub smoothing_window: Option<usize>,
+
+    /// Exponential-moving-average smoothing factor, in (0.0, 1.0]. Required
+    /// when `--smoothing ema` is selected. Smaller values smooth more
+    /// aggressively (more lag); 1.0 passes samples through unchanged.
+    #[arg(long = "smoothing-alpha", value_parser = validate_smoothing_alpha)]
+    pub smoothing_alpha: Option<f32>,
+
+    // ==================== Control Plane Options ====================
+
+    /// Path to a Unix domain socket for live reconfiguration and status
+    /// streaming (e.g. /run/gymdeck3/control.sock). Unset disables the
+    /// control plane.
+    #[arg(long = "control-socket")]
+    pub control_socket: Option<PathBuf>,
+
+    /// Path to a Unix domain socket for binary, length-prefixed status
+    /// streaming (e.g. /run/gymdeck3/status.sock), for a long-lived UI that
+    /// wants to subscribe once instead of re-spawning the daemon or
+    /// re-parsing NDJSON line boundaries. Stdout NDJSON keeps flowing
+    /// regardless; unset disables this socket. See `status_server` module.
+    #[arg(long = "status-socket")]
+    pub status_socket: Option<PathBuf>,
+
+    /// Tick resolution for the `uptime_ticks` NDJSON status field, in ticks
+    /// per second. 1000 (default) gives millisecond ticks, 1_000_000 gives
+    /// microsecond ticks.
+    #[arg(long = "tick-hz", default_value = "1000", value_parser = validate_tick_hz)]
+    pub tick_hz: u64,
+
+    /// Watchdog timeout in milliseconds. If the main loop goes this long
+    /// without a heartbeat, the watchdog attempts a recovery reset before
+    /// eventually exiting with code 5 (see `watchdog` module).
+    #[arg(long = "watchdog-timeout-ms", default_value = "10000", value_parser = validate_watchdog_timeout_ms)]
+    pub watchdog_timeout_ms: u64,
+
+    /// Path to a TOML (or JSON, by `.json` extension) config file providing
+    /// strategy, sample interval, hysteresis, status interval, per-core
+    /// entries, and fan control settings. Explicit CLI flags always
+    /// override matching values from the file.
+    #[arg(long = "config")]
+    pub config: Option<PathBuf>,
+
+    /// Allow `--core` (or `--config` `[[cores]]`) entries more aggressive
+    /// than the detected Deck model's known-stable `max_mv` floor (see
+    /// `model::DeckModel::safe_max_mv_floor`). Without this, such a core is
+    /// rejected rather than risk leaving the APU wedged.
+    #[arg(long = "force-unsafe-undervolt")]
+    pub force_unsafe_undervolt: bool,
 }
 
 /// Fan control mode
@@ -92,6 +245,11 @@ pub enum FanControlMode {
     Custom,
     /// Fixed speed (use with --fan-curve for single point)
     Fixed,
+    /// Quadratic polynomial control (use with --fan-coeffs)
+    Poly,
+    /// PID setpoint control, holding a target die temperature (use with
+    /// --fan-setpoint / --fan-pid)
+    Pid,
 }
 
 impl std::fmt::Display for FanControlMode {
@@ -100,6 +258,8 @@ impl std::fmt::Display for FanControlMode {
             FanControlMode::Default => write!(f, "default"),
             FanControlMode::Custom => write!(f, "custom"),
             FanControlMode::Fixed => write!(f, "fixed"),
+            FanControlMode::Poly => write!(f, "poly"),
+            FanControlMode::Pid => write!(f, "pid"),
         }
     }
 }
@@ -113,6 +273,57 @@ pub struct FanCurvePointConfig {
     pub speed_percent: u8,
 }
 
+impl std::fmt::Display for FanCurvePointConfig {
+    /// Renders in the same `TEMP:SPEED` form `parse_fan_curve_point` accepts
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.temp_c, self.speed_percent)
+    }
+}
+
+impl FanCurvePointConfig {
+    /// Render in the same `TEMP:SPEED` form `parse_fan_curve_point` accepts
+    pub fn to_config_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Quadratic fan curve coefficients: `speed = a + b*temp + c*temp^2`
+///
+/// An alternative to `FanCurvePointConfig`'s piecewise points, modeled after
+/// the thermostat-style polynomial curves some EC firmwares expose. Selected
+/// via `FanControlMode::Poly` / `--fan-coeffs A:B:C`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct FanCurveCoeffs {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+}
+
+impl FanCurveCoeffs {
+    /// Evaluate the polynomial at `temp_c`, clamped to 0-100%
+    pub fn speed_percent(&self, temp_c: f32) -> f32 {
+        let speed = self.a + self.b * temp_c + self.c * temp_c * temp_c;
+        speed.clamp(0.0, 100.0)
+    }
+}
+
+/// Configuration for `FanControlMode::Pid`, regulating PWM directly from a
+/// temperature setpoint rather than a lookup curve/polynomial
+///
+/// Mirrors `PidConfig`'s shape but drives `fan::PidFanController` (a PWM
+/// output) rather than a per-core undervolt target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FanPidConfig {
+    /// Temperature setpoint in °C
+    pub setpoint_c: f32,
+    /// Proportional gain
+    pub kp: f32,
+    /// Integral gain
+    pub ki: f32,
+    /// Derivative gain
+    pub kd: f32,
+}
+
 /// Adaptation strategy for dynamic undervolt control
 ///
 /// Each strategy has different responsiveness characteristics:
@@ -120,7 +331,10 @@ pub struct FanCurvePointConfig {
 /// - **Balanced**: Moderate responsiveness (2s ramp) - good for most users
 /// - **Aggressive**: Fast adaptation (500ms ramp) - prioritizes responsiveness
 /// - **Custom**: User-defined load-to-undervolt curve
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+/// - **Adaptive**: Self-tuning target that drifts toward equilibrium
+/// - **Learning**: Penalizes load regions that have reported instability
+/// - **Markov**: Predicts the next load bucket and ramps ahead of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ValueEnum, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Strategy {
     /// Conservative strategy with 5 second ramp time
@@ -131,6 +345,14 @@ pub enum Strategy {
     Aggressive,
     /// Custom strategy with user-defined curve
     Custom,
+    /// Closed-loop PID strategy driven by a temperature setpoint
+    Pid,
+    /// Error-driven strategy that self-tunes its target over time
+    Adaptive,
+    /// Online-learning strategy that penalizes unstable load regions
+    Learning,
+    /// First-order Markov strategy that predicts the next load bucket
+    Markov,
 }
 
 impl std::fmt::Display for Strategy {
@@ -140,6 +362,103 @@ impl std::fmt::Display for Strategy {
             Strategy::Balanced => write!(f, "balanced"),
             Strategy::Aggressive => write!(f, "aggressive"),
             Strategy::Custom => write!(f, "custom"),
+            Strategy::Pid => write!(f, "pid"),
+            Strategy::Adaptive => write!(f, "adaptive"),
+            Strategy::Learning => write!(f, "learning"),
+            Strategy::Markov => write!(f, "markov"),
+        }
+    }
+}
+
+/// Configuration for the PID (Strategy::Pid) thermal strategy
+///
+/// The controller regulates around `target_c` rather than mapping CPU load
+/// directly to an undervolt value: each sample it computes
+/// `error = target_c - measured_temp`, accumulates an integral term (clamped
+/// to `[output_clamp_min, output_clamp_max]` for anti-windup), and applies a
+/// derivative on the measured temperature. The resulting output is clamped
+/// to the same range and linearly mapped onto the per-core `[max_mv, min_mv]`
+/// bounds, mirroring thermostatd's `target`/`kp`/`ki`/`kd`/`output_min` knobs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PidConfig {
+    /// Temperature setpoint in °C
+    pub target_c: f32,
+    /// Proportional gain
+    pub kp: f32,
+    /// Integral gain
+    pub ki: f32,
+    /// Derivative gain
+    pub kd: f32,
+    /// Lower bound of the raw PID output before it's mapped onto the
+    /// per-core undervolt range; also the anti-windup floor
+    pub output_clamp_min: f32,
+    /// Upper bound of the raw PID output before it's mapped onto the
+    /// per-core undervolt range; also the anti-windup ceiling
+    pub output_clamp_max: f32,
+}
+
+impl Default for PidConfig {
+    /// A reasonable starting point, used when `Strategy::Pid` is selected
+    /// programmatically (e.g. via `create_strategy`) without an explicit
+    /// `PidConfig`; the CLI itself always requires every knob to be set
+    /// explicitly (see `validate_args`).
+    fn default() -> Self {
+        PidConfig {
+            target_c: 70.0,
+            kp: 1.0,
+            ki: 0.0,
+            kd: 0.0,
+            output_clamp_min: -50.0,
+            output_clamp_max: 50.0,
+        }
+    }
+}
+
+/// Sample smoothing filter mode, applied to load/temperature inputs before
+/// strategy/fan-curve evaluation (see the `smoothing` module)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SmoothingMode {
+    /// No smoothing; samples pass through unchanged (default)
+    #[default]
+    None,
+    /// Simple moving average over the last `window` samples
+    Sma,
+    /// Exponential moving average with smoothing factor `alpha`
+    Ema,
+}
+
+impl std::fmt::Display for SmoothingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SmoothingMode::None => write!(f, "none"),
+            SmoothingMode::Sma => write!(f, "sma"),
+            SmoothingMode::Ema => write!(f, "ema"),
+        }
+    }
+}
+
+/// Resolved sample smoothing configuration
+///
+/// `window` is only meaningful for `SmoothingMode::Sma` and `alpha` only
+/// for `SmoothingMode::Ema`; the unused field is ignored for the other
+/// mode rather than forbidden at this level (`validate_args`/`resolve_config`
+/// enforce that the right one, and only the right one, is actually set).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SmoothingConfig {
+    pub mode: SmoothingMode,
+    /// SMA window size in samples
+    pub window: usize,
+    /// EMA smoothing factor, in (0.0, 1.0]
+    pub alpha: f32,
+}
+
+impl Default for SmoothingConfig {
+    fn default() -> Self {
+        SmoothingConfig {
+            mode: SmoothingMode::None,
+            window: 1,
+            alpha: 1.0,
         }
     }
 }
@@ -178,22 +497,190 @@ pub struct CoreConfig {
     pub threshold: f32,
 }
 
+impl std::fmt::Display for CoreConfig {
+    /// Renders in the same `N:MIN:MAX:THRESHOLD` form `parse_core_config` accepts
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}:{}", self.core_id, self.min_mv, self.max_mv, self.threshold)
+    }
+}
+
+impl CoreConfig {
+    /// Render in the same `N:MIN:MAX:THRESHOLD` form `parse_core_config` accepts
+    pub fn to_config_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Byte range within a parser's original input `&str`
+///
+/// `start == end` marks a single point (e.g. where a token was expected
+/// but the input ended); `start < end` brackets the offending substring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    fn of(s: &str, part: &str) -> Self {
+        // `part` must be a substring slice of `s` obtained via `s.split`/
+        // indexing, so its byte offset within `s` is well-defined.
+        let start = part.as_ptr() as usize - s.as_ptr() as usize;
+        Span { start, end: start + part.len() }
+    }
+}
+
+/// Reason a config parser rejected its input
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigErrorKind {
+    /// The field parsed to a value but it falls outside the accepted range
+    OutOfRange { field: &'static str, value: String, min: String, max: String },
+    /// The field didn't have the expected shape (bad token count, not a
+    /// number, unrecognized name, ...)
+    UnexpectedToken { field: &'static str, found: String },
+    /// A validation rule failed in a way that isn't a simple range or
+    /// shape check (e.g. a cross-field constraint)
+    Invalid { field: &'static str, reason: String },
+}
+
+/// A structured, span-located config parsing/validation error
+///
+/// Carries the original input alongside the byte range where parsing
+/// failed, so `Display` can render a caret-underlined excerpt pointing at
+/// the exact problem instead of just a message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigError {
+    pub kind: ConfigErrorKind,
+    pub span: Span,
+    input: String,
+}
+
+impl ConfigError {
+    fn out_of_range(
+        input: &str,
+        span: Span,
+        field: &'static str,
+        value: impl ToString,
+        min: impl ToString,
+        max: impl ToString,
+    ) -> Self {
+        ConfigError {
+            kind: ConfigErrorKind::OutOfRange {
+                field,
+                value: value.to_string(),
+                min: min.to_string(),
+                max: max.to_string(),
+            },
+            span,
+            input: input.to_string(),
+        }
+    }
+
+    fn unexpected_token(input: &str, span: Span, field: &'static str, found: impl ToString) -> Self {
+        ConfigError {
+            kind: ConfigErrorKind::UnexpectedToken {
+                field,
+                found: found.to_string(),
+            },
+            span,
+            input: input.to_string(),
+        }
+    }
+
+    fn invalid(input: &str, span: Span, field: &'static str, reason: impl ToString) -> Self {
+        ConfigError {
+            kind: ConfigErrorKind::Invalid {
+                field,
+                reason: reason.to_string(),
+            },
+            span,
+            input: input.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ConfigErrorKind::OutOfRange { field, value, min, max } => {
+                writeln!(f, "{} '{}' is out of range ({}-{})", field, value, min, max)?;
+            }
+            ConfigErrorKind::UnexpectedToken { field, found } => {
+                writeln!(f, "{}: unexpected '{}'", field, found)?;
+            }
+            ConfigErrorKind::Invalid { field, reason } => {
+                writeln!(f, "{}: {}", field, reason)?;
+            }
+        }
+
+        let start = self.span.start.min(self.input.len());
+        let end = self.span.end.max(start).min(self.input.len());
+        let caret_len = (end - start).max(1);
+
+        writeln!(f, "{}", self.input)?;
+        write!(f, "{}{}", " ".repeat(start), "^".repeat(caret_len))
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// One field a `*_clamped` validator saturated to a safe bound instead of
+/// rejecting outright.
+///
+/// Lets a caller load a config file where most fields are valid but accept
+/// a clamped value for the rest, rather than discarding the whole file over
+/// one bad field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClampWarning {
+    pub field: &'static str,
+    pub requested: String,
+    pub clamped_to: String,
+}
+
+impl ClampWarning {
+    fn new(field: &'static str, requested: impl ToString, clamped_to: impl ToString) -> Self {
+        ClampWarning {
+            field,
+            requested: requested.to_string(),
+            clamped_to: clamped_to.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for ClampWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} '{}' was out of range, clamped to '{}'",
+            self.field, self.requested, self.clamped_to
+        )
+    }
+}
+
 /// Validate sample interval is within 10ms-5000ms (10000-5000000 microseconds)
-pub fn validate_sample_interval(s: &str) -> Result<u64, String> {
-    let val: u64 = s
-        .parse()
-        .map_err(|_| format!("'{}' is not a valid number", s))?;
-    
+pub fn validate_sample_interval(s: &str) -> Result<u64, ConfigError> {
+    let val: u64 = s.parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, s), "sample-interval-us", s)
+    })?;
+
     if val < 10_000 {
-        return Err(format!(
-            "Sample interval {} us is too small (minimum: 10000 us / 10ms)",
-            val
+        return Err(ConfigError::out_of_range(
+            s,
+            Span::of(s, s),
+            "sample-interval-us",
+            val,
+            10_000,
+            5_000_000,
         ));
     }
     if val > 5_000_000 {
-        return Err(format!(
-            "Sample interval {} us is too large (maximum: 5000000 us / 5000ms)",
-            val
+        return Err(ConfigError::out_of_range(
+            s,
+            Span::of(s, s),
+            "sample-interval-us",
+            val,
+            10_000,
+            5_000_000,
         ));
     }
     Ok(val)
@@ -216,12 +703,27 @@ pub fn validate_sample_interval_value(val: u64) -> Result<u64, String> {
     Ok(val)
 }
 
+/// Saturating variant of `validate_sample_interval_value`: instead of
+/// rejecting an out-of-range interval, clamps it to the nearest bound and
+/// reports the adjustment.
+pub fn validate_sample_interval_value_clamped(val: u64) -> (u64, Vec<ClampWarning>) {
+    if val < 10_000 {
+        (10_000, vec![ClampWarning::new("sample-interval-us", val, 10_000u64)])
+    } else if val > 5_000_000 {
+        (5_000_000, vec![ClampWarning::new("sample-interval-us", val, 5_000_000u64)])
+    } else {
+        (val, Vec::new())
+    }
+}
+
 /// Validate hysteresis is within 1-20%
+///
+/// Also rejects decimal text that doesn't round-trip through `f32` exactly
+/// (see `precision::validate_decimal_precision`), since a silently rounded
+/// hysteresis threshold changes when the controller acts.
 pub fn validate_hysteresis(s: &str) -> Result<f32, String> {
-    let val: f32 = s
-        .parse()
-        .map_err(|_| format!("'{}' is not a valid number", s))?;
-    
+    let val = crate::precision::validate_decimal_precision(s)?;
+
     validate_hysteresis_value(val)
 }
 
@@ -242,275 +744,2487 @@ pub fn validate_hysteresis_value(val: f32) -> Result<f32, String> {
     Ok(val)
 }
 
-/// Parse core configuration from string format: N:MIN:MAX:THRESHOLD
-/// Example: "0:-20:-35:50.0"
-pub fn parse_core_config(s: &str) -> Result<CoreConfig, String> {
-    let parts: Vec<&str> = s.split(':').collect();
-    if parts.len() != 4 {
-        return Err(format!(
-            "Invalid core config '{}'. Expected format: N:MIN:MAX:THRESHOLD (e.g., 0:-20:-35:50.0)",
-            s
-        ));
+/// Saturating variant of `validate_hysteresis_value`: clamps out-of-range
+/// percentages into 1-20 instead of rejecting them. `NaN` clamps to the
+/// minimum, since it can't be ordered against either bound.
+pub fn validate_hysteresis_value_clamped(val: f32) -> (f32, Vec<ClampWarning>) {
+    if val.is_nan() {
+        (1.0, vec![ClampWarning::new("hysteresis", val, 1.0f32)])
+    } else if val < 1.0 {
+        (1.0, vec![ClampWarning::new("hysteresis", val, 1.0f32)])
+    } else if val > 20.0 {
+        (20.0, vec![ClampWarning::new("hysteresis", val, 20.0f32)])
+    } else {
+        (val, Vec::new())
     }
+}
 
-    let core_id: usize = parts[0]
+/// Validate tick resolution is a usable, non-zero rate
+pub fn validate_tick_hz(s: &str) -> Result<u64, String> {
+    let val: u64 = s
         .parse()
-        .map_err(|_| format!("Invalid core ID '{}': must be a non-negative integer", parts[0]))?;
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
 
-    let min_mv: i32 = parts[1]
-        .parse()
-        .map_err(|_| format!("Invalid min_mv '{}': must be an integer", parts[1]))?;
+    validate_tick_hz_value(val)
+}
 
-    let max_mv: i32 = parts[2]
-        .parse()
-        .map_err(|_| format!("Invalid max_mv '{}': must be an integer", parts[2]))?;
+/// Validate tick resolution from a u64 value directly
+pub fn validate_tick_hz_value(val: u64) -> Result<u64, String> {
+    if val == 0 {
+        return Err("tick-hz must be greater than 0".to_string());
+    }
+    if val > 1_000_000_000 {
+        return Err(format!(
+            "tick-hz {} is too large (maximum: 1000000000, i.e. nanosecond ticks)",
+            val
+        ));
+    }
+    Ok(val)
+}
 
-    let threshold: f32 = parts[3]
+/// Validate the watchdog timeout is a usable, non-zero duration
+pub fn validate_watchdog_timeout_ms(s: &str) -> Result<u64, String> {
+    let val: u64 = s
         .parse()
-        .map_err(|_| format!("Invalid threshold '{}': must be a float", parts[3]))?;
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
 
-    validate_core_config_values(core_id, min_mv, max_mv, threshold)
+    validate_watchdog_timeout_ms_value(val)
 }
 
-/// Validate core configuration values directly
-pub fn validate_core_config_values(
-    core_id: usize,
-    min_mv: i32,
-    max_mv: i32,
-    threshold: f32,
-) -> Result<CoreConfig, String> {
-    // Validate undervolt values (should be negative or zero)
-    if min_mv > 0 {
+/// Validate the watchdog timeout from a u64 value directly
+pub fn validate_watchdog_timeout_ms_value(val: u64) -> Result<u64, String> {
+    if val < 100 {
         return Err(format!(
-            "min_mv {} must be <= 0 (undervolt values are negative)",
-            min_mv
+            "watchdog-timeout-ms {} is too small (minimum: 100ms)",
+            val
         ));
     }
-    if max_mv > 0 {
+    if val > 600_000 {
         return Err(format!(
-            "max_mv {} must be <= 0 (undervolt values are negative)",
-            max_mv
+            "watchdog-timeout-ms {} is too large (maximum: 600000ms, i.e. 10 minutes)",
+            val
         ));
     }
+    Ok(val)
+}
 
-    // max_mv should be more negative (more aggressive) than min_mv
-    if max_mv > min_mv {
-        return Err(format!(
-            "max_mv ({}) must be <= min_mv ({}) (max is more aggressive/negative)",
-            max_mv, min_mv
-        ));
-    }
+/// Validate the PID setpoint is within a sane temperature range (40-95°C)
+pub fn validate_pid_target(s: &str) -> Result<f32, String> {
+    let val: f32 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    validate_pid_target_value(val)
+}
 
-    // Validate threshold is in valid range
-    if !(0.0..=100.0).contains(&threshold) {
+/// Validate a PID target value directly
+pub fn validate_pid_target_value(val: f32) -> Result<f32, String> {
+    if !(40.0..=95.0).contains(&val) {
         return Err(format!(
-            "Threshold {} must be between 0.0 and 100.0",
-            threshold
+            "pid-target {}°C is out of range (must be between 40 and 95°C)",
+            val
         ));
     }
-
-    Ok(CoreConfig {
-        core_id,
-        min_mv,
-        max_mv,
-        threshold,
-    })
+    Ok(val)
 }
 
-/// Validate the complete Args configuration
-pub fn validate_args(args: &Args) -> Result<(), String> {
-    // Check for duplicate core IDs
-    let mut seen_cores = std::collections::HashSet::new();
-    for core in &args.cores {
-        if !seen_cores.insert(core.core_id) {
-            return Err(format!("Duplicate core ID: {}", core.core_id));
-        }
-    }
+/// Validate a PID gain (kp/ki/kd) is positive
+pub fn validate_pid_gain(s: &str) -> Result<f32, String> {
+    let val: f32 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    validate_pid_gain_value(val)
+}
 
-    // Validate ryzenadj path exists (basic check)
-    if args.ryzenadj_path.as_os_str().is_empty() {
-        return Err("ryzenadj-path cannot be empty".to_string());
+/// Validate a PID gain value directly
+pub fn validate_pid_gain_value(val: f32) -> Result<f32, String> {
+    if val <= 0.0 {
+        return Err(format!("PID gain {} must be positive", val));
     }
+    Ok(val)
+}
 
-    // Validate fan curve if fan control is enabled
-    if args.fan_control && args.fan_mode == FanControlMode::Custom {
-        if args.fan_curve.len() < 2 {
-            return Err("Fan curve requires at least 2 points".to_string());
-        }
+/// Validate a PID output clamp (min, max) range directly
+pub fn validate_pid_output_clamp_value(min: f32, max: f32) -> Result<(f32, f32), String> {
+    if min >= max {
+        return Err(format!(
+            "pid-output-clamp min ({}) must be less than max ({})",
+            min, max
+        ));
     }
-
-    Ok(())
+    Ok((min, max))
 }
 
-/// Parse fan curve point from string format: TEMP:SPEED
-/// Example: "60:50" means 60°C -> 50% speed
-pub fn parse_fan_curve_point(s: &str) -> Result<FanCurvePointConfig, String> {
+/// Parse the PID output clamp range from string format: MIN:MAX
+/// Example: "-50:50"
+pub fn parse_pid_output_clamp(s: &str) -> Result<(f32, f32), ConfigError> {
     let parts: Vec<&str> = s.split(':').collect();
     if parts.len() != 2 {
-        return Err(format!(
-            "Invalid fan curve point '{}'. Expected format: TEMP:SPEED (e.g., 60:50)",
-            s
+        return Err(ConfigError::unexpected_token(
+            s,
+            Span::of(s, s),
+            "pid-output-clamp",
+            format!("{} field(s), expected MIN:MAX", parts.len()),
         ));
     }
 
-    let temp_c: i32 = parts[0]
-        .parse()
-        .map_err(|_| format!("Invalid temperature '{}': must be an integer", parts[0]))?;
+    let min: f32 = parts[0].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[0]), "pid-output-clamp.min", parts[0])
+    })?;
 
-    let speed_percent: u8 = parts[1]
-        .parse()
-        .map_err(|_| format!("Invalid speed '{}': must be 0-100", parts[1]))?;
+    let max: f32 = parts[1].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[1]), "pid-output-clamp.max", parts[1])
+    })?;
 
-    validate_fan_curve_point(temp_c, speed_percent)
+    validate_pid_output_clamp_value(min, max)
+        .map_err(|reason| ConfigError::invalid(s, Span::of(s, s), "pid-output-clamp", reason))
 }
 
-/// Validate fan curve point values
-pub fn validate_fan_curve_point(temp_c: i32, speed_percent: u8) -> Result<FanCurvePointConfig, String> {
-    if temp_c < 0 || temp_c > 100 {
+/// Validate the fan PID setpoint is within a sane temperature range (30-100°C)
+pub fn validate_fan_setpoint(s: &str) -> Result<f32, String> {
+    let val: f32 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    validate_fan_setpoint_value(val)
+}
+
+/// Validate a fan PID setpoint value directly
+pub fn validate_fan_setpoint_value(val: f32) -> Result<f32, String> {
+    if !(30.0..=100.0).contains(&val) {
         return Err(format!(
-            "Temperature {} must be between 0 and 100°C",
-            temp_c
+            "fan-setpoint {}°C is out of range (must be between 30 and 100°C)",
+            val
         ));
     }
+    Ok(val)
+}
 
-    if speed_percent > 100 {
-        return Err(format!(
-            "Speed {} must be between 0 and 100%",
-            speed_percent
+/// Validate a fan PID gain (kp/ki/kd) is non-negative
+pub fn validate_fan_pid_gain_value(val: f32) -> Result<f32, String> {
+    if val < 0.0 {
+        return Err(format!("fan PID gain {} must be >= 0", val));
+    }
+    Ok(val)
+}
+
+/// Parse fan PID gains from string format: KP:KI:KD
+/// Example: "2.0:0.1:0.5"
+pub fn parse_fan_pid_gains(s: &str) -> Result<(f32, f32, f32), ConfigError> {
+    let parts: Vec<&str> = s.split(':').collect();
+    if parts.len() != 3 {
+        return Err(ConfigError::unexpected_token(
+            s,
+            Span::of(s, s),
+            "fan-pid",
+            format!("{} field(s), expected KP:KI:KD", parts.len()),
         ));
     }
 
-    Ok(FanCurvePointConfig {
+    let kp: f32 = parts[0].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[0]), "fan-pid.kp", parts[0])
+    })?;
+    let ki: f32 = parts[1].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[1]), "fan-pid.ki", parts[1])
+    })?;
+    let kd: f32 = parts[2].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[2]), "fan-pid.kd", parts[2])
+    })?;
+
+    validate_fan_pid_gain_value(kp)
+        .map_err(|reason| ConfigError::invalid(s, Span::of(s, parts[0]), "fan-pid.kp", reason))?;
+    validate_fan_pid_gain_value(ki)
+        .map_err(|reason| ConfigError::invalid(s, Span::of(s, parts[1]), "fan-pid.ki", reason))?;
+    validate_fan_pid_gain_value(kd)
+        .map_err(|reason| ConfigError::invalid(s, Span::of(s, parts[2]), "fan-pid.kd", reason))?;
+
+    Ok((kp, ki, kd))
+}
+
+/// Validate the SMA smoothing window size (must be >= 1 sample)
+pub fn validate_smoothing_window(s: &str) -> Result<usize, String> {
+    let val: usize = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    validate_smoothing_window_value(val)
+}
+
+/// Validate a smoothing window value directly
+pub fn validate_smoothing_window_value(val: usize) -> Result<usize, String> {
+    if val < 1 {
+        return Err(format!("smoothing-window {} must be >= 1", val));
+    }
+    Ok(val)
+}
+
+/// Validate the EMA smoothing factor (must be in (0.0, 1.0])
+pub fn validate_smoothing_alpha(s: &str) -> Result<f32, String> {
+    let val: f32 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    validate_smoothing_alpha_value(val)
+}
+
+/// Validate a smoothing alpha value directly
+pub fn validate_smoothing_alpha_value(val: f32) -> Result<f32, String> {
+    if !(val > 0.0 && val <= 1.0) {
+        return Err(format!("smoothing-alpha {} must be in (0.0, 1.0]", val));
+    }
+    Ok(val)
+}
+
+/// Parse core configuration from string format: N:MIN:MAX:THRESHOLD
+/// Example: "0:-20:-35:50.0"
+pub fn parse_core_config(s: &str) -> Result<CoreConfig, ConfigError> {
+    let parts: Vec<&str> = s.split(':').collect();
+    if parts.len() != 4 {
+        return Err(ConfigError::unexpected_token(
+            s,
+            Span::of(s, s),
+            "core",
+            format!("{} field(s), expected N:MIN:MAX:THRESHOLD", parts.len()),
+        ));
+    }
+
+    let core_id: usize = parts[0].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[0]), "core.id", parts[0])
+    })?;
+
+    let min_mv: i32 = parts[1].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[1]), "core.min_mv", parts[1])
+    })?;
+
+    let max_mv: i32 = parts[2].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[2]), "core.max_mv", parts[2])
+    })?;
+
+    let threshold: f32 = crate::precision::validate_decimal_precision(parts[3]).map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[3]), "core.threshold", parts[3])
+    })?;
+
+    if min_mv > 0 {
+        return Err(ConfigError::out_of_range(s, Span::of(s, parts[1]), "core.min_mv", min_mv, i32::MIN, 0));
+    }
+    if max_mv > 0 {
+        return Err(ConfigError::out_of_range(s, Span::of(s, parts[2]), "core.max_mv", max_mv, i32::MIN, 0));
+    }
+    if max_mv > min_mv {
+        return Err(ConfigError::out_of_range(s, Span::of(s, parts[2]), "core.max_mv", max_mv, i32::MIN, min_mv));
+    }
+    if !(0.0..=100.0).contains(&threshold) {
+        return Err(ConfigError::out_of_range(s, Span::of(s, parts[3]), "core.threshold", threshold, 0.0, 100.0));
+    }
+
+    validate_core_config_values(core_id, min_mv, max_mv, threshold)
+        .map_err(|reason| ConfigError::invalid(s, Span::of(s, s), "core", reason))
+}
+
+/// Validate core configuration values directly
+pub fn validate_core_config_values(
+    core_id: usize,
+    min_mv: i32,
+    max_mv: i32,
+    threshold: f32,
+) -> Result<CoreConfig, String> {
+    // Validate undervolt values (should be negative or zero)
+    if min_mv > 0 {
+        return Err(format!(
+            "min_mv {} must be <= 0 (undervolt values are negative)",
+            min_mv
+        ));
+    }
+    if max_mv > 0 {
+        return Err(format!(
+            "max_mv {} must be <= 0 (undervolt values are negative)",
+            max_mv
+        ));
+    }
+
+    // max_mv should be more negative (more aggressive) than min_mv
+    if max_mv > min_mv {
+        return Err(format!(
+            "max_mv ({}) must be <= min_mv ({}) (max is more aggressive/negative)",
+            max_mv, min_mv
+        ));
+    }
+
+    // Validate threshold is in valid range
+    if !(0.0..=100.0).contains(&threshold) {
+        return Err(format!(
+            "Threshold {} must be between 0.0 and 100.0",
+            threshold
+        ));
+    }
+
+    Ok(CoreConfig {
+        core_id,
+        min_mv,
+        max_mv,
+        threshold,
+    })
+}
+
+/// Saturating variant of `validate_core_config_values`: clamps each
+/// out-of-bound field instead of rejecting the whole core, so one bad field
+/// in a config file doesn't drop an otherwise-valid core. `min_mv`/`max_mv`
+/// clamp to 0 when positive, `max_mv` additionally clamps down to `min_mv`
+/// if it's still less aggressive, and `threshold` clamps into 0.0-100.0.
+pub fn validate_core_config_values_clamped(
+    core_id: usize,
+    min_mv: i32,
+    max_mv: i32,
+    threshold: f32,
+) -> (CoreConfig, Vec<ClampWarning>) {
+    let mut warnings = Vec::new();
+
+    let min_mv = if min_mv > 0 {
+        warnings.push(ClampWarning::new("core.min_mv", min_mv, 0));
+        0
+    } else {
+        min_mv
+    };
+
+    let mut max_mv = if max_mv > 0 {
+        warnings.push(ClampWarning::new("core.max_mv", max_mv, 0));
+        0
+    } else {
+        max_mv
+    };
+
+    if max_mv > min_mv {
+        warnings.push(ClampWarning::new("core.max_mv", max_mv, min_mv));
+        max_mv = min_mv;
+    }
+
+    let threshold = if threshold.is_nan() {
+        warnings.push(ClampWarning::new("core.threshold", threshold, 0.0f32));
+        0.0
+    } else if threshold < 0.0 {
+        warnings.push(ClampWarning::new("core.threshold", threshold, 0.0f32));
+        0.0
+    } else if threshold > 100.0 {
+        warnings.push(ClampWarning::new("core.threshold", threshold, 100.0f32));
+        100.0
+    } else {
+        threshold
+    };
+
+    (
+        CoreConfig {
+            core_id,
+            min_mv,
+            max_mv,
+            threshold,
+        },
+        warnings,
+    )
+}
+
+/// Validate the complete Args configuration
+pub fn validate_args(args: &Args) -> Result<(), String> {
+    validate_no_duplicate_core_ids(&args.cores)?;
+    validate_cores_for_model(&args.cores, args.force_unsafe_undervolt)?;
+
+    // Validate ryzenadj path exists (basic check)
+    if args.ryzenadj_path.as_os_str().is_empty() {
+        return Err("ryzenadj-path cannot be empty".to_string());
+    }
+
+    // Validate fan curve if fan control is enabled
+    if args.fan_control && args.fan_mode == FanControlMode::Custom {
+        if args.fan_curve.len() < 2 {
+            return Err("Fan curve requires at least 2 points".to_string());
+        }
+    }
+
+    if !args.fan_curve.is_empty() && args.fan_coeffs.is_some() {
+        return Err("--fan-curve and --fan-coeffs are mutually exclusive".to_string());
+    }
+
+    if args.fan_control && args.fan_mode == FanControlMode::Poly && args.fan_coeffs.is_none() {
+        return Err("Fan mode 'poly' requires --fan-coeffs".to_string());
+    }
+
+    if args.fan_coeffs_range.is_some() && args.fan_coeffs.is_none() {
+        return Err("--fan-coeffs-range requires --fan-coeffs".to_string());
+    }
+
+    if args.fan_control && args.fan_mode == FanControlMode::Pid && args.fan_setpoint.is_none() {
+        return Err("Fan mode 'pid' requires --fan-setpoint".to_string());
+    }
+
+    validate_pid_args(args)?;
+    validate_smoothing_args(args)?;
+
+    if let Some(ref socket_path) = args.control_socket {
+        validate_control_socket_path(socket_path)?;
+    }
+
+    if let Some(ref socket_path) = args.status_socket {
+        validate_status_socket_path(socket_path)?;
+    }
+
+    Ok(())
+}
+
+/// Validate that `--pid-*` flags are present if and only if `--strategy pid`
+/// is selected
+///
+/// `strategy` is `None` when it's deferred to a `--config` file, in which
+/// case we can't yet tell whether PID was selected; `resolve_config` re-runs
+/// the same requires-all-four check once the file is loaded and the
+/// strategy is known.
+fn validate_pid_args(args: &Args) -> Result<(), String> {
+    let pid_selected = args.strategy == Some(Strategy::Pid);
+    let any_pid_flag_set = args.pid_target.is_some()
+        || args.pid_kp.is_some()
+        || args.pid_ki.is_some()
+        || args.pid_kd.is_some()
+        || args.pid_output_clamp.is_some();
+
+    if any_pid_flag_set && args.strategy.is_some() && !pid_selected {
+        return Err("--pid-* flags are only valid when --strategy pid is selected".to_string());
+    }
+
+    if pid_selected {
+        require_pid_config_complete(
+            args.pid_target,
+            args.pid_kp,
+            args.pid_ki,
+            args.pid_kd,
+            args.pid_output_clamp,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Require every PID knob to be set, since a partial PID configuration has
+/// no sane default (unlike e.g. `fan_hysteresis`, which falls back to a
+/// built-in constant)
+fn require_pid_config_complete(
+    target: Option<f32>,
+    kp: Option<f32>,
+    ki: Option<f32>,
+    kd: Option<f32>,
+    output_clamp: Option<(f32, f32)>,
+) -> Result<(), String> {
+    let mut missing = Vec::new();
+    if target.is_none() {
+        missing.push("--pid-target");
+    }
+    if kp.is_none() {
+        missing.push("--pid-kp");
+    }
+    if ki.is_none() {
+        missing.push("--pid-ki");
+    }
+    if kd.is_none() {
+        missing.push("--pid-kd");
+    }
+    if output_clamp.is_none() {
+        missing.push("--pid-output-clamp");
+    }
+
+    if !missing.is_empty() {
+        return Err(format!(
+            "Strategy 'pid' requires {} (pass them on the CLI or set them in --config)",
+            missing.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Build a `PidConfig` from already-validated, fully-present PID values
+fn pid_config_from_parts(
+    target: f32,
+    kp: f32,
+    ki: f32,
+    kd: f32,
+    output_clamp: (f32, f32),
+) -> PidConfig {
+    PidConfig {
+        target_c: target,
+        kp,
+        ki,
+        kd,
+        output_clamp_min: output_clamp.0,
+        output_clamp_max: output_clamp.1,
+    }
+}
+
+/// Validate that `--smoothing-window`/`--smoothing-alpha` are present (and
+/// only the one relevant to the selected mode) for the CLI-sourced
+/// `--smoothing` flag
+///
+/// `resolve_config` re-runs `require_smoothing_params` once CLI and
+/// `--config` values are merged, same split as `validate_pid_args`.
+fn validate_smoothing_args(args: &Args) -> Result<(), String> {
+    require_smoothing_params(args.smoothing, args.smoothing_window, args.smoothing_alpha)
+}
+
+/// Require exactly the knob the selected `SmoothingMode` needs, and reject
+/// the other one being set (it would silently do nothing)
+fn require_smoothing_params(
+    mode: SmoothingMode,
+    window: Option<usize>,
+    alpha: Option<f32>,
+) -> Result<(), String> {
+    match mode {
+        SmoothingMode::None => {
+            if window.is_some() || alpha.is_some() {
+                return Err(
+                    "--smoothing-window/--smoothing-alpha are only valid when --smoothing is sma or ema"
+                        .to_string(),
+                );
+            }
+        }
+        SmoothingMode::Sma => {
+            if window.is_none() {
+                return Err("--smoothing sma requires --smoothing-window".to_string());
+            }
+            if alpha.is_some() {
+                return Err("--smoothing-alpha is only valid with --smoothing ema".to_string());
+            }
+        }
+        SmoothingMode::Ema => {
+            if alpha.is_none() {
+                return Err("--smoothing ema requires --smoothing-alpha".to_string());
+            }
+            if window.is_some() {
+                return Err("--smoothing-window is only valid with --smoothing sma".to_string());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Build a `SmoothingConfig` from an already-validated mode and its
+/// (possibly irrelevant) window/alpha values
+fn smoothing_config_from_parts(
+    mode: SmoothingMode,
+    window: Option<usize>,
+    alpha: Option<f32>,
+) -> SmoothingConfig {
+    SmoothingConfig {
+        mode,
+        window: window.unwrap_or(SmoothingConfig::default().window),
+        alpha: alpha.unwrap_or(SmoothingConfig::default().alpha),
+    }
+}
+
+/// Reject core configs that reuse the same `core_id`
+///
+/// Shared by `validate_args` (CLI-sourced cores) and `resolve_config`
+/// (file-sourced cores), since either source can end up as the merged
+/// core list a caller actually uses.
+fn validate_no_duplicate_core_ids(cores: &[CoreConfig]) -> Result<(), String> {
+    let mut seen_cores = std::collections::HashSet::new();
+    for core in cores {
+        if !seen_cores.insert(core.core_id) {
+            return Err(format!("Duplicate core ID: {}", core.core_id));
+        }
+    }
+    Ok(())
+}
+
+/// Reject cores more aggressive than the detected Deck model's
+/// known-stable `max_mv` floor, unless `force_unsafe` (`--force-unsafe-undervolt`)
+/// is set
+///
+/// Shared by `validate_args` (CLI-sourced cores) and `resolve_config`
+/// (merged cores), same split as `validate_no_duplicate_core_ids`. Detection
+/// failing (not running on a Deck, sysfs unavailable) is not itself an
+/// error — there's simply no model-specific floor to enforce.
+fn validate_cores_for_model(cores: &[CoreConfig], force_unsafe: bool) -> Result<(), String> {
+    let model = crate::model::detect_deck_model();
+    for core in cores {
+        crate::model::validate_core_config_for_model(core, model, force_unsafe)?;
+    }
+    Ok(())
+}
+
+/// Default hysteresis when neither the CLI nor a `--config` file set one
+pub const DEFAULT_HYSTERESIS: f32 = 5.0;
+
+/// Default status output interval (ms) when neither the CLI nor a
+/// `--config` file set one
+pub const DEFAULT_STATUS_INTERVAL_MS: u64 = 1000;
+
+/// Default fan hysteresis (°C); matches the `--fan-hysteresis` clap
+/// `default_value`, used to detect whether the CLI flag was left at its
+/// default (and so a `--config` file value should apply instead)
+pub const DEFAULT_FAN_HYSTERESIS_C: i32 = 2;
+
+/// Default widened down-ramp fan hysteresis (°C); matches the
+/// `--fan-down-hysteresis` clap `default_value`, used the same way as
+/// `DEFAULT_FAN_HYSTERESIS_C`
+pub const DEFAULT_FAN_DOWN_HYSTERESIS_C: i32 = 4;
+
+/// Default fan slowdown step cap (PWM units); matches the
+/// `--fan-slowdown-step-max` clap `default_value`, used the same way as
+/// `DEFAULT_FAN_HYSTERESIS_C`
+pub const DEFAULT_FAN_SLOWDOWN_STEP_MAX: u8 = 0;
+
+/// Default thermal-derate start/end temperatures (°C); match the
+/// `--derate-start`/`--derate-end` clap `default_value`s, used the same way
+/// as `DEFAULT_FAN_HYSTERESIS_C` to detect an unset CLI flag
+pub const DEFAULT_DERATE_START_C: f32 = 80.0;
+pub const DEFAULT_DERATE_END_C: f32 = 95.0;
+
+/// Default fan PID gains (kp, ki, kd), used when `--fan-mode pid` is
+/// selected without an explicit `--fan-pid`
+pub const DEFAULT_FAN_PID_GAINS: (f32, f32, f32) = (
+    crate::fan::DEFAULT_FAN_PID_KP,
+    crate::fan::DEFAULT_FAN_PID_KI,
+    crate::fan::DEFAULT_FAN_PID_KD,
+);
+
+/// On-disk config file (TOML, or JSON by `.json` extension) loaded via
+/// `--config`
+///
+/// Every field is optional: a file only needs to set what it wants to
+/// override, and `resolve_config` fills in whatever it leaves out from
+/// CLI flags or built-in defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ConfigFile {
+    pub strategy: Option<Strategy>,
+    pub sample_interval_us: Option<u64>,
+    pub hysteresis: Option<f32>,
+    pub status_interval_ms: Option<u64>,
+    #[serde(default)]
+    pub cores: Vec<CoreConfig>,
+    pub fan_control: Option<bool>,
+    pub fan_mode: Option<FanControlMode>,
+    #[serde(default)]
+    pub fan_curve: Vec<FanCurvePointConfig>,
+    pub fan_coeffs: Option<FanCurveCoeffs>,
+    pub fan_coeffs_range: Option<(i32, i32)>,
+    pub fan_zero_rpm: Option<bool>,
+    pub fan_hysteresis: Option<i32>,
+    pub fan_down_hysteresis: Option<i32>,
+    pub fan_slowdown_step_max: Option<u8>,
+    pub fan_setpoint: Option<f32>,
+    pub fan_pid: Option<(f32, f32, f32)>,
+    pub pid_target: Option<f32>,
+    pub pid_kp: Option<f32>,
+    pub pid_ki: Option<f32>,
+    pub pid_kd: Option<f32>,
+    pub pid_output_clamp: Option<(f32, f32)>,
+    pub derate_start: Option<f32>,
+    pub derate_end: Option<f32>,
+    pub smoothing: Option<SmoothingMode>,
+    pub smoothing_window: Option<usize>,
+    pub smoothing_alpha: Option<f32>,
+}
+
+/// Fully resolved runtime configuration: CLI flags merged with an optional
+/// `--config` file
+///
+/// CLI flags always win over a matching file value; a file value always
+/// wins over the built-in default. See `resolve_config`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedConfig {
+    pub strategy: Strategy,
+    pub sample_interval_us: u64,
+    pub hysteresis: f32,
+    pub status_interval_ms: u64,
+    pub cores: Vec<CoreConfig>,
+    pub fan_control: bool,
+    pub fan_mode: FanControlMode,
+    pub fan_curve: Vec<FanCurvePointConfig>,
+    pub fan_coeffs: Option<FanCurveCoeffs>,
+    pub fan_coeffs_range: Option<(i32, i32)>,
+    pub fan_zero_rpm: bool,
+    pub fan_hysteresis: i32,
+    pub fan_down_hysteresis: i32,
+    pub fan_slowdown_step_max: u8,
+    pub fan_pid_config: Option<FanPidConfig>,
+    pub pid_config: Option<PidConfig>,
+    pub derate_start: f32,
+    pub derate_end: f32,
+    pub smoothing: SmoothingConfig,
+}
+
+/// Load and validate a `--config` file
+///
+/// Format is picked by extension: `.json` parses as JSON, anything else
+/// (including no extension) parses as TOML; a malformed document's error
+/// message includes the offending TOML/JSON key path, since both `toml`
+/// and `serde_json` report it. Every present field is run through the same
+/// validators as its CLI counterpart (`validate_sample_interval_value`,
+/// `validate_hysteresis_value`, `validate_core_config_values`,
+/// `validate_fan_curve_point`, `validate_fan_hysteresis_value`), so a bad
+/// profile is rejected the same way a bad flag would be.
+pub fn load_config_file(path: &std::path::Path) -> Result<ConfigFile, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read config file '{}': {}", path.display(), e))?;
+
+    let is_json = path.extension().and_then(|ext| ext.to_str()) == Some("json");
+    let file: ConfigFile = if is_json {
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("Invalid JSON config '{}': {}", path.display(), e))?
+    } else {
+        toml::from_str(&contents)
+            .map_err(|e| format!("Invalid TOML config '{}': {}", path.display(), e))?
+    };
+
+    if let Some(interval) = file.sample_interval_us {
+        validate_sample_interval_value(interval)?;
+    }
+    if let Some(hysteresis) = file.hysteresis {
+        validate_hysteresis_value(hysteresis)?;
+    }
+    if let Some(fan_hysteresis) = file.fan_hysteresis {
+        validate_fan_hysteresis_value(fan_hysteresis)?;
+    }
+    if let Some(fan_down_hysteresis) = file.fan_down_hysteresis {
+        validate_fan_down_hysteresis_value(fan_down_hysteresis)?;
+    }
+    let mut cores = Vec::with_capacity(file.cores.len());
+    for core in &file.cores {
+        cores.push(validate_core_config_values(
+            core.core_id,
+            core.min_mv,
+            core.max_mv,
+            core.threshold,
+        )?);
+    }
+    validate_no_duplicate_core_ids(&cores)?;
+
+    let mut fan_curve = Vec::with_capacity(file.fan_curve.len());
+    for point in &file.fan_curve {
+        fan_curve.push(validate_fan_curve_point(point.temp_c, point.speed_percent)?);
+    }
+    if file.fan_control == Some(true)
+        && file.fan_mode == Some(FanControlMode::Custom)
+        && fan_curve.len() < 2
+    {
+        return Err("Fan curve requires at least 2 points".to_string());
+    }
+
+    if let Some(coeffs) = file.fan_coeffs {
+        validate_fan_coeffs_monotonic(coeffs.a, coeffs.b, coeffs.c)?;
+    }
+    if !fan_curve.is_empty() && file.fan_coeffs.is_some() {
+        return Err("fan_curve and fan_coeffs are mutually exclusive".to_string());
+    }
+    if file.fan_control == Some(true)
+        && file.fan_mode == Some(FanControlMode::Poly)
+        && file.fan_coeffs.is_none()
+    {
+        return Err("Fan mode 'poly' requires fan_coeffs".to_string());
+    }
+
+    if let Some((t_min, t_max)) = file.fan_coeffs_range {
+        if t_min >= t_max {
+            return Err(format!(
+                "fan_coeffs_range tmin={} must be less than tmax={}",
+                t_min, t_max
+            ));
+        }
+    }
+    if file.fan_coeffs_range.is_some() && file.fan_coeffs.is_none() {
+        return Err("fan_coeffs_range requires fan_coeffs".to_string());
+    }
+
+    if let Some(setpoint) = file.fan_setpoint {
+        validate_fan_setpoint_value(setpoint)?;
+    }
+    if let Some((kp, ki, kd)) = file.fan_pid {
+        validate_fan_pid_gain_value(kp)?;
+        validate_fan_pid_gain_value(ki)?;
+        validate_fan_pid_gain_value(kd)?;
+    }
+    if file.fan_control == Some(true)
+        && file.fan_mode == Some(FanControlMode::Pid)
+        && file.fan_setpoint.is_none()
+    {
+        return Err("Fan mode 'pid' requires fan_setpoint".to_string());
+    }
+
+    if let Some(target) = file.pid_target {
+        validate_pid_target_value(target)?;
+    }
+    for gain in [file.pid_kp, file.pid_ki, file.pid_kd].into_iter().flatten() {
+        validate_pid_gain_value(gain)?;
+    }
+    if let Some((min, max)) = file.pid_output_clamp {
+        validate_pid_output_clamp_value(min, max)?;
+    }
+    if file.strategy == Some(Strategy::Pid) {
+        require_pid_config_complete(
+            file.pid_target,
+            file.pid_kp,
+            file.pid_ki,
+            file.pid_kd,
+            file.pid_output_clamp,
+        )?;
+    }
+
+    if let Some(derate_start) = file.derate_start {
+        validate_derate_temp_value(derate_start)?;
+    }
+    if let Some(derate_end) = file.derate_end {
+        validate_derate_temp_value(derate_end)?;
+    }
+
+    if let Some(window) = file.smoothing_window {
+        validate_smoothing_window_value(window)?;
+    }
+    if let Some(alpha) = file.smoothing_alpha {
+        validate_smoothing_alpha_value(alpha)?;
+    }
+    if let Some(mode) = file.smoothing {
+        require_smoothing_params(mode, file.smoothing_window, file.smoothing_alpha)?;
+    }
+
+    Ok(ConfigFile { cores, fan_curve, ..file })
+}
+
+/// Merge CLI flags (`Args`) with an optional `--config` file into a fully
+/// resolved configuration
+///
+/// Precedence is CLI flag > config file > built-in default. `cores` is
+/// all-or-nothing per source rather than merged entry-by-entry: CLI
+/// `--core` flags, if any, replace the file's core list outright, since a
+/// partial per-core merge would silently mix bounds from two profiles.
+pub fn resolve_config(args: &Args) -> Result<ResolvedConfig, String> {
+    let file = match &args.config {
+        Some(path) => Some(load_config_file(path)?),
+        None => None,
+    };
+
+    let strategy = args
+        .strategy
+        .or_else(|| file.as_ref().and_then(|f| f.strategy))
+        .ok_or_else(|| "strategy is required (pass it on the CLI or set it in --config)".to_string())?;
+
+    let sample_interval_us = match args.sample_interval_us {
+        Some(value) => value,
+        None => file
+            .as_ref()
+            .and_then(|f| f.sample_interval_us)
+            .ok_or_else(|| {
+                "sample-interval-us is required (pass it on the CLI or set it in --config)".to_string()
+            })?,
+    };
+
+    let hysteresis = args
+        .hysteresis
+        .or_else(|| file.as_ref().and_then(|f| f.hysteresis))
+        .unwrap_or(DEFAULT_HYSTERESIS);
+
+    let status_interval_ms = args
+        .status_interval_ms
+        .or_else(|| file.as_ref().and_then(|f| f.status_interval_ms))
+        .unwrap_or(DEFAULT_STATUS_INTERVAL_MS);
+
+    let deck_model = crate::model::detect_deck_model();
+
+    let cores = if !args.cores.is_empty() {
+        args.cores.clone()
+    } else {
+        let file_cores = file.as_ref().map(|f| f.cores.clone()).unwrap_or_default();
+        if !file_cores.is_empty() {
+            file_cores
+        } else {
+            // No `--core` flags and no `[[cores]]` in `--config`: fall back
+            // to the detected device's safe defaults (`hardware::HardwareProfile`
+            // generalizes `DeckModel::default_cores` to non-Deck handhelds)
+            // rather than leaving the daemon with no per-core bounds at all.
+            crate::hardware::detect_hardware_profile()
+                .map(|profile| {
+                    profile
+                        .default_core_bounds()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(core_id, bounds)| CoreConfig {
+                            core_id,
+                            min_mv: bounds.min_mv,
+                            max_mv: bounds.max_mv,
+                            threshold: bounds.threshold,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+    };
+    validate_cores_for_model(&cores, args.force_unsafe_undervolt)?;
+
+    // `fan_control`/`fan_zero_rpm` are plain `bool` flags (clap has no
+    // "unset" state for them), so a file can only raise the baseline, never
+    // lower one the CLI flag set: the flag being absent (false) can't be
+    // told apart from a deliberate override.
+    let fan_control = args.fan_control || file.as_ref().and_then(|f| f.fan_control).unwrap_or(false);
+    let fan_zero_rpm =
+        args.fan_zero_rpm || file.as_ref().and_then(|f| f.fan_zero_rpm).unwrap_or(false);
+
+    // `fan_mode`/`fan_hysteresis` carry a clap `default_value`, so they're
+    // never actually `None` on the CLI side; treat the CLI value as unset
+    // only when it still equals that default, same convention as the
+    // `bool` flags above.
+    let fan_mode = if args.fan_mode != FanControlMode::default() {
+        args.fan_mode
+    } else {
+        file.as_ref()
+            .and_then(|f| f.fan_mode)
+            .unwrap_or(args.fan_mode)
+    };
+
+    let fan_hysteresis = if args.fan_hysteresis != DEFAULT_FAN_HYSTERESIS_C {
+        args.fan_hysteresis
+    } else {
+        file.as_ref()
+            .and_then(|f| f.fan_hysteresis)
+            .unwrap_or(args.fan_hysteresis)
+    };
+
+    let fan_down_hysteresis = if args.fan_down_hysteresis != DEFAULT_FAN_DOWN_HYSTERESIS_C {
+        args.fan_down_hysteresis
+    } else {
+        file.as_ref()
+            .and_then(|f| f.fan_down_hysteresis)
+            .unwrap_or(args.fan_down_hysteresis)
+    };
+
+    let fan_slowdown_step_max = if args.fan_slowdown_step_max != DEFAULT_FAN_SLOWDOWN_STEP_MAX {
+        args.fan_slowdown_step_max
+    } else {
+        file.as_ref()
+            .and_then(|f| f.fan_slowdown_step_max)
+            .unwrap_or(args.fan_slowdown_step_max)
+    };
+
+    let fan_curve = if !args.fan_curve.is_empty() {
+        args.fan_curve.clone()
+    } else {
+        file.as_ref().map(|f| f.fan_curve.clone()).unwrap_or_default()
+    };
+
+    let fan_coeffs = args
+        .fan_coeffs
+        .or_else(|| file.as_ref().and_then(|f| f.fan_coeffs));
+
+    let fan_coeffs_range = args
+        .fan_coeffs_range
+        .or_else(|| file.as_ref().and_then(|f| f.fan_coeffs_range));
+
+    if fan_control && fan_mode == FanControlMode::Custom && fan_curve.len() < 2 {
+        return Err("Fan curve requires at least 2 points".to_string());
+    }
+
+    if fan_control && fan_mode == FanControlMode::Poly && fan_coeffs.is_none() {
+        return Err("Fan mode 'poly' requires --fan-coeffs (or fan_coeffs in --config)".to_string());
+    }
+
+    if !fan_curve.is_empty() && fan_coeffs.is_some() {
+        return Err("--fan-curve and --fan-coeffs are mutually exclusive".to_string());
+    }
+
+    // `fan_setpoint` merges like `fan_coeffs`: no sane default, so it's
+    // simply required (not defaulted) when `fan_mode` is `Pid`. `fan_pid`
+    // gains do have a sane default and fall back to it instead.
+    let fan_setpoint = args
+        .fan_setpoint
+        .or_else(|| file.as_ref().and_then(|f| f.fan_setpoint));
+    let fan_pid_gains = args
+        .fan_pid
+        .or_else(|| file.as_ref().and_then(|f| f.fan_pid))
+        .unwrap_or(DEFAULT_FAN_PID_GAINS);
+
+    if fan_control && fan_mode == FanControlMode::Pid && fan_setpoint.is_none() {
+        return Err("Fan mode 'pid' requires --fan-setpoint (or fan_setpoint in --config)".to_string());
+    }
+
+    let fan_pid_config = if fan_control && fan_mode == FanControlMode::Pid {
+        fan_setpoint.map(|setpoint_c| FanPidConfig {
+            setpoint_c,
+            kp: fan_pid_gains.0,
+            ki: fan_pid_gains.1,
+            kd: fan_pid_gains.2,
+        })
+    } else {
+        None
+    };
+
+    // PID knobs merge like `fan_coeffs`: CLI value wins, otherwise fall back
+    // to the file's; `require_pid_config_complete` then re-checks the merged
+    // result, since a user might set some knobs on the CLI and the rest in
+    // `--config`.
+    let pid_target = args.pid_target.or_else(|| file.as_ref().and_then(|f| f.pid_target));
+    let pid_kp = args.pid_kp.or_else(|| file.as_ref().and_then(|f| f.pid_kp));
+    let pid_ki = args.pid_ki.or_else(|| file.as_ref().and_then(|f| f.pid_ki));
+    let pid_kd = args.pid_kd.or_else(|| file.as_ref().and_then(|f| f.pid_kd));
+    let pid_output_clamp = args
+        .pid_output_clamp
+        .or_else(|| file.as_ref().and_then(|f| f.pid_output_clamp));
+
+    let pid_config = if strategy == Strategy::Pid {
+        require_pid_config_complete(pid_target, pid_kp, pid_ki, pid_kd, pid_output_clamp)?;
+        Some(pid_config_from_parts(
+            pid_target.unwrap(),
+            pid_kp.unwrap(),
+            pid_ki.unwrap(),
+            pid_kd.unwrap(),
+            pid_output_clamp.unwrap(),
+        ))
+    } else {
+        None
+    };
+
+    // `derate_start`/`derate_end` carry clap `default_value`s, same
+    // unset-detection convention as `fan_mode`/`fan_hysteresis` above.
+    let derate_start = if args.derate_start != DEFAULT_DERATE_START_C {
+        args.derate_start
+    } else {
+        file.as_ref()
+            .and_then(|f| f.derate_start)
+            .unwrap_or(args.derate_start)
+    };
+    let derate_end = if args.derate_end != DEFAULT_DERATE_END_C {
+        args.derate_end
+    } else {
+        file.as_ref()
+            .and_then(|f| f.derate_end)
+            .unwrap_or(args.derate_end)
+    };
+    if derate_end <= derate_start {
+        return Err(format!(
+            "derate-end ({}) must be greater than derate-start ({})",
+            derate_end, derate_start
+        ));
+    }
+
+    // Smoothing carries a clap `default_value` (`none`), so the CLI value
+    // is never actually unset; treat it as "unset" only when it's still
+    // the default, same convention as `fan_mode`/`fan_hysteresis` above.
+    let smoothing_mode = if args.smoothing != SmoothingMode::default() {
+        args.smoothing
+    } else {
+        file.as_ref()
+            .and_then(|f| f.smoothing)
+            .unwrap_or(args.smoothing)
+    };
+    let smoothing_window = args
+        .smoothing_window
+        .or_else(|| file.as_ref().and_then(|f| f.smoothing_window));
+    let smoothing_alpha = args
+        .smoothing_alpha
+        .or_else(|| file.as_ref().and_then(|f| f.smoothing_alpha));
+    require_smoothing_params(smoothing_mode, smoothing_window, smoothing_alpha)?;
+    let smoothing = smoothing_config_from_parts(smoothing_mode, smoothing_window, smoothing_alpha);
+
+    Ok(ResolvedConfig {
+        strategy,
+        sample_interval_us,
+        hysteresis,
+        status_interval_ms,
+        cores,
+        fan_control,
+        fan_mode,
+        fan_curve,
+        fan_coeffs,
+        fan_coeffs_range,
+        fan_zero_rpm,
+        fan_hysteresis,
+        fan_down_hysteresis,
+        fan_slowdown_step_max,
+        fan_pid_config,
+        pid_config,
+        derate_start,
+        derate_end,
+        smoothing,
+    })
+}
+
+/// Validate a `--control-socket` path: non-empty, and its parent directory
+/// must already exist (the daemon binds the socket itself, it doesn't
+/// create directories)
+pub fn validate_control_socket_path(path: &std::path::Path) -> Result<(), String> {
+    if path.as_os_str().is_empty() {
+        return Err("control-socket path cannot be empty".to_string());
+    }
+
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() && !parent.is_dir() => Err(format!(
+            "control-socket parent directory '{}' does not exist",
+            parent.display()
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Validate a `--status-socket` path: non-empty, and its parent directory
+/// must already exist, same rule as `--control-socket`
+pub fn validate_status_socket_path(path: &std::path::Path) -> Result<(), String> {
+    if path.as_os_str().is_empty() {
+        return Err("status-socket path cannot be empty".to_string());
+    }
+
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() && !parent.is_dir() => Err(format!(
+            "status-socket parent directory '{}' does not exist",
+            parent.display()
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Parse fan curve point from string format: TEMP:SPEED
+/// Example: "60:50" means 60°C -> 50% speed
+pub fn parse_fan_curve_point(s: &str) -> Result<FanCurvePointConfig, ConfigError> {
+    let parts: Vec<&str> = s.split(':').collect();
+    if parts.len() != 2 {
+        return Err(ConfigError::unexpected_token(
+            s,
+            Span::of(s, s),
+            "fan-curve",
+            format!("{} field(s), expected TEMP:SPEED", parts.len()),
+        ));
+    }
+
+    let temp_c: i32 = parts[0].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[0]), "fan-curve.temp_c", parts[0])
+    })?;
+
+    let speed_percent: u8 = parts[1].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[1]), "fan-curve.speed_percent", parts[1])
+    })?;
+
+    if !(0..=100).contains(&temp_c) {
+        return Err(ConfigError::out_of_range(s, Span::of(s, parts[0]), "fan-curve.temp_c", temp_c, 0, 100));
+    }
+    if speed_percent > 100 {
+        return Err(ConfigError::out_of_range(s, Span::of(s, parts[1]), "fan-curve.speed_percent", speed_percent, 0, 100));
+    }
+
+    validate_fan_curve_point(temp_c, speed_percent)
+        .map_err(|reason| ConfigError::invalid(s, Span::of(s, s), "fan-curve", reason))
+}
+
+/// Validate fan curve point values
+pub fn validate_fan_curve_point(temp_c: i32, speed_percent: u8) -> Result<FanCurvePointConfig, String> {
+    if temp_c < 0 || temp_c > 100 {
+        return Err(format!(
+            "Temperature {} must be between 0 and 100°C",
+            temp_c
+        ));
+    }
+
+    if speed_percent > 100 {
+        return Err(format!(
+            "Speed {} must be between 0 and 100%",
+            speed_percent
+        ));
+    }
+
+    Ok(FanCurvePointConfig {
         temp_c,
         speed_percent,
     })
 }
 
-/// Validate fan hysteresis is within 1-10°C
-pub fn validate_fan_hysteresis(s: &str) -> Result<i32, String> {
-    let val: i32 = s
-        .parse()
-        .map_err(|_| format!("'{}' is not a valid number", s))?;
+/// Saturating variant of `validate_fan_curve_point`: clamps `temp_c` into
+/// 0-100 and `speed_percent` into 0-100 instead of rejecting the point.
+pub fn validate_fan_curve_point_clamped(
+    temp_c: i32,
+    speed_percent: u8,
+) -> (FanCurvePointConfig, Vec<ClampWarning>) {
+    let mut warnings = Vec::new();
+
+    let temp_c = if temp_c < 0 {
+        warnings.push(ClampWarning::new("fan-curve.temp_c", temp_c, 0));
+        0
+    } else if temp_c > 100 {
+        warnings.push(ClampWarning::new("fan-curve.temp_c", temp_c, 100));
+        100
+    } else {
+        temp_c
+    };
+
+    let speed_percent = if speed_percent > 100 {
+        warnings.push(ClampWarning::new("fan-curve.speed_percent", speed_percent, 100u8));
+        100
+    } else {
+        speed_percent
+    };
+
+    (
+        FanCurvePointConfig {
+            temp_c,
+            speed_percent,
+        },
+        warnings,
+    )
+}
+
+/// Parse quadratic fan curve coefficients from string format: A:B:C
+/// Example: "10:0:0.02" means speed = 10 + 0*temp + 0.02*temp^2
+pub fn parse_fan_coeffs(s: &str) -> Result<FanCurveCoeffs, ConfigError> {
+    let parts: Vec<&str> = s.split(':').collect();
+    if parts.len() != 3 {
+        return Err(ConfigError::unexpected_token(
+            s,
+            Span::of(s, s),
+            "fan-coeffs",
+            format!("{} field(s), expected A:B:C", parts.len()),
+        ));
+    }
+
+    let a: f32 = parts[0].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[0]), "fan-coeffs.a", parts[0])
+    })?;
+    let b: f32 = parts[1].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[1]), "fan-coeffs.b", parts[1])
+    })?;
+    let c: f32 = parts[2].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[2]), "fan-coeffs.c", parts[2])
+    })?;
+
+    validate_fan_coeffs_monotonic(a, b, c)
+        .map_err(|reason| ConfigError::invalid(s, Span::of(s, s), "fan-coeffs", reason))?;
+
+    Ok(FanCurveCoeffs { a, b, c })
+}
+
+/// Parse the normalized window for `--fan-coeffs-range` from string format:
+/// TMIN:TMAX
+pub fn parse_fan_coeffs_range(s: &str) -> Result<(i32, i32), ConfigError> {
+    let parts: Vec<&str> = s.split(':').collect();
+    if parts.len() != 2 {
+        return Err(ConfigError::unexpected_token(
+            s,
+            Span::of(s, s),
+            "fan-coeffs-range",
+            format!("{} field(s), expected TMIN:TMAX", parts.len()),
+        ));
+    }
+
+    let t_min: i32 = parts[0].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[0]), "fan-coeffs-range.tmin", parts[0])
+    })?;
+    let t_max: i32 = parts[1].parse().map_err(|_| {
+        ConfigError::unexpected_token(s, Span::of(s, parts[1]), "fan-coeffs-range.tmax", parts[1])
+    })?;
+
+    if t_min >= t_max {
+        return Err(ConfigError::invalid(
+            s,
+            Span::of(s, s),
+            "fan-coeffs-range",
+            format!("tmin={} must be less than tmax={}", t_min, t_max),
+        ));
+    }
+
+    Ok((t_min, t_max))
+}
+
+/// Number of samples used to check `validate_fan_coeffs_monotonic` across
+/// the 0-100°C supported range
+const MONOTONIC_SAMPLE_STEPS: u32 = 100;
+
+/// Reject coefficient sets whose curve decreases anywhere across 0-100°C
+///
+/// Sampled rather than solved analytically (checking the derivative's sign
+/// would need fewer points, but sampling matches how `validate_fan_curve_point`
+/// reasons about the same 0-100°C range and stays correct if the evaluation
+/// formula ever changes).
+pub fn validate_fan_coeffs_monotonic(a: f32, b: f32, c: f32) -> Result<(), String> {
+    let coeffs = FanCurveCoeffs { a, b, c };
+    let mut prev = coeffs.speed_percent(0.0);
+    for step in 1..=MONOTONIC_SAMPLE_STEPS {
+        let temp_c = step as f32 / MONOTONIC_SAMPLE_STEPS as f32 * 100.0;
+        let speed = coeffs.speed_percent(temp_c);
+        if speed < prev {
+            return Err(format!(
+                "Fan curve coefficients a={}, b={}, c={} are non-monotonic: speed drops near {}°C",
+                a, b, c, temp_c
+            ));
+        }
+        prev = speed;
+    }
+    Ok(())
+}
+
+/// Validate fan hysteresis is within 1-10°C
+pub fn validate_fan_hysteresis(s: &str) -> Result<i32, String> {
+    let val: i32 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    validate_fan_hysteresis_value(val)
+}
+
+/// Validate fan hysteresis from an i32 value directly
+pub fn validate_fan_hysteresis_value(val: i32) -> Result<i32, String> {
+    if val < 1 {
+        return Err(format!(
+            "Fan hysteresis {}°C is too small (minimum: 1°C)",
+            val
+        ));
+    }
+    if val > 10 {
+        return Err(format!(
+            "Fan hysteresis {}°C is too large (maximum: 10°C)",
+            val
+        ));
+    }
+    Ok(val)
+}
+
+/// Validate the `--fan-down-hysteresis` flag value (parsed as a string)
+pub fn validate_fan_down_hysteresis(s: &str) -> Result<i32, String> {
+    let val: i32 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    validate_fan_down_hysteresis_value(val)
+}
+
+/// Validate the widened down-ramp fan hysteresis from an i32 value directly
+pub fn validate_fan_down_hysteresis_value(val: i32) -> Result<i32, String> {
+    if val < 1 {
+        return Err(format!(
+            "Fan down-hysteresis {}°C is too small (minimum: 1°C)",
+            val
+        ));
+    }
+    if val > 20 {
+        return Err(format!(
+            "Fan down-hysteresis {}°C is too large (maximum: 20°C)",
+            val
+        ));
+    }
+    Ok(val)
+}
+
+/// Validate a thermal derate temperature is within 30-110°C
+pub fn validate_derate_temp(s: &str) -> Result<f32, String> {
+    let val: f32 = s
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    validate_derate_temp_value(val)
+}
+
+/// Validate a thermal derate temperature from an f32 value directly
+pub fn validate_derate_temp_value(val: f32) -> Result<f32, String> {
+    if val < 30.0 {
+        return Err(format!(
+            "Derate temperature {}°C is too low (minimum: 30°C)",
+            val
+        ));
+    }
+    if val > 110.0 {
+        return Err(format!(
+            "Derate temperature {}°C is too high (maximum: 110°C)",
+            val
+        ));
+    }
+    Ok(val)
+}
+
+/// Parse an acoustic profile preset by name (e.g. "silent", "balanced", "max-cooling")
+pub fn parse_acoustic_profile(s: &str) -> Result<AcousticProfile, ConfigError> {
+    AcousticProfile::from_name(s).ok_or_else(|| {
+        ConfigError::unexpected_token(s, Span::of(s, s), "acoustic-profile", s)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_core_config_valid() {
+        let config = parse_core_config("0:-20:-35:50.0").unwrap();
+        assert_eq!(config.core_id, 0);
+        assert_eq!(config.min_mv, -20);
+        assert_eq!(config.max_mv, -35);
+        assert_eq!(config.threshold, 50.0);
+    }
+
+    #[test]
+    fn test_parse_core_config_invalid_format() {
+        assert!(parse_core_config("0:-20:-35").is_err());
+        assert!(parse_core_config("invalid").is_err());
+    }
+
+    #[test]
+    fn test_parse_core_config_invalid_values() {
+        // Positive undervolt values
+        assert!(parse_core_config("0:20:-35:50.0").is_err());
+        // max_mv > min_mv
+        assert!(parse_core_config("0:-35:-20:50.0").is_err());
+        // Invalid threshold
+        assert!(parse_core_config("0:-20:-35:150.0").is_err());
+    }
+
+    #[test]
+    fn test_validate_sample_interval() {
+        assert!(validate_sample_interval("10000").is_ok());
+        assert!(validate_sample_interval("5000000").is_ok());
+        assert!(validate_sample_interval("100000").is_ok());
+        
+        assert!(validate_sample_interval("9999").is_err());
+        assert!(validate_sample_interval("5000001").is_err());
+        assert!(validate_sample_interval("invalid").is_err());
+    }
+
+    #[test]
+    fn test_validate_hysteresis() {
+        assert!(validate_hysteresis("1.0").is_ok());
+        assert!(validate_hysteresis("20.0").is_ok());
+        assert!(validate_hysteresis("5.5").is_ok());
+        
+        assert!(validate_hysteresis("0.5").is_err());
+        assert!(validate_hysteresis("21.0").is_err());
+        assert!(validate_hysteresis("invalid").is_err());
+    }
+
+    #[test]
+    fn test_validate_hysteresis_rejects_unrepresentable_decimal() {
+        // 2.35 is in-range but doesn't round-trip through f32 (stored as 2.3499999).
+        assert!(validate_hysteresis("2.35").is_err());
+    }
+
+    #[test]
+    fn test_parse_core_config_rejects_unrepresentable_threshold() {
+        assert!(parse_core_config("0:-20:-35:33.3").is_err());
+    }
+
+    #[test]
+    fn test_validate_sample_interval_value_clamped() {
+        let (val, warnings) = validate_sample_interval_value_clamped(9_999);
+        assert_eq!(val, 10_000);
+        assert_eq!(warnings.len(), 1);
+
+        let (val, warnings) = validate_sample_interval_value_clamped(u64::MAX);
+        assert_eq!(val, 5_000_000);
+        assert_eq!(warnings.len(), 1);
+
+        let (val, warnings) = validate_sample_interval_value_clamped(100_000);
+        assert_eq!(val, 100_000);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_validate_hysteresis_value_clamped() {
+        let (val, warnings) = validate_hysteresis_value_clamped(0.5);
+        assert_eq!(val, 1.0);
+        assert_eq!(warnings.len(), 1);
+
+        let (val, warnings) = validate_hysteresis_value_clamped(21.0);
+        assert_eq!(val, 20.0);
+        assert_eq!(warnings.len(), 1);
+
+        let (val, warnings) = validate_hysteresis_value_clamped(f32::NAN);
+        assert_eq!(val, 1.0);
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_validate_core_config_values_clamped() {
+        let (config, warnings) = validate_core_config_values_clamped(0, 10, -35, 150.0);
+        assert_eq!(config.min_mv, 0);
+        assert_eq!(config.max_mv, -35);
+        assert_eq!(config.threshold, 100.0);
+        assert_eq!(warnings.len(), 2);
+
+        // max_mv less aggressive than min_mv clamps down to min_mv.
+        let (config, warnings) = validate_core_config_values_clamped(0, -20, -10, 50.0);
+        assert_eq!(config.min_mv, -20);
+        assert_eq!(config.max_mv, -20);
+        assert_eq!(warnings.len(), 1);
+
+        let (_, warnings) = validate_core_config_values_clamped(0, -20, -35, 50.0);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_validate_fan_curve_point_clamped() {
+        let (point, warnings) = validate_fan_curve_point_clamped(-10, 255);
+        assert_eq!(point.temp_c, 0);
+        assert_eq!(point.speed_percent, 100);
+        assert_eq!(warnings.len(), 2);
+
+        let (point, warnings) = validate_fan_curve_point_clamped(60, 50);
+        assert_eq!(point.temp_c, 60);
+        assert_eq!(point.speed_percent, 50);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_validate_tick_hz() {
+        assert!(validate_tick_hz("1000").is_ok());
+        assert_eq!(validate_tick_hz("1000000").unwrap(), 1_000_000);
+
+        assert!(validate_tick_hz("0").is_err());
+        assert!(validate_tick_hz("1000000001").is_err());
+        assert!(validate_tick_hz("invalid").is_err());
+    }
+
+    #[test]
+    fn test_validate_watchdog_timeout_ms() {
+        assert!(validate_watchdog_timeout_ms("10000").is_ok());
+        assert_eq!(validate_watchdog_timeout_ms("100").unwrap(), 100);
+
+        assert!(validate_watchdog_timeout_ms("99").is_err());
+        assert!(validate_watchdog_timeout_ms("600001").is_err());
+        assert!(validate_watchdog_timeout_ms("invalid").is_err());
+    }
+
+    #[test]
+    fn test_strategy_display() {
+        assert_eq!(Strategy::Conservative.to_string(), "conservative");
+        assert_eq!(Strategy::Balanced.to_string(), "balanced");
+        assert_eq!(Strategy::Aggressive.to_string(), "aggressive");
+        assert_eq!(Strategy::Custom.to_string(), "custom");
+    }
+
+    // ==================== Fan Config Tests ====================
+
+    #[test]
+    fn test_parse_fan_curve_point_valid() {
+        let point = parse_fan_curve_point("60:50").unwrap();
+        assert_eq!(point.temp_c, 60);
+        assert_eq!(point.speed_percent, 50);
+
+        let point = parse_fan_curve_point("40:0").unwrap();
+        assert_eq!(point.temp_c, 40);
+        assert_eq!(point.speed_percent, 0);
+
+        let point = parse_fan_curve_point("85:100").unwrap();
+        assert_eq!(point.temp_c, 85);
+        assert_eq!(point.speed_percent, 100);
+    }
+
+    #[test]
+    fn test_parse_fan_curve_point_invalid() {
+        // Wrong format
+        assert!(parse_fan_curve_point("60").is_err());
+        assert!(parse_fan_curve_point("60:50:30").is_err());
+        assert!(parse_fan_curve_point("invalid").is_err());
+
+        // Invalid values
+        assert!(parse_fan_curve_point("-10:50").is_err()); // Negative temp
+        assert!(parse_fan_curve_point("110:50").is_err()); // Temp > 100
+        assert!(parse_fan_curve_point("60:150").is_err()); // Speed > 100
+    }
+
+    #[test]
+    fn test_validate_fan_hysteresis() {
+        assert!(validate_fan_hysteresis("1").is_ok());
+        assert!(validate_fan_hysteresis("5").is_ok());
+        assert!(validate_fan_hysteresis("10").is_ok());
+
+        assert!(validate_fan_hysteresis("0").is_err());
+        assert!(validate_fan_hysteresis("11").is_err());
+        assert!(validate_fan_hysteresis("invalid").is_err());
+    }
+
+    #[test]
+    fn test_parse_acoustic_profile_valid() {
+        assert_eq!(parse_acoustic_profile("silent").unwrap(), AcousticProfile::Silent);
+        assert_eq!(parse_acoustic_profile("balanced").unwrap(), AcousticProfile::Balanced);
+        assert_eq!(parse_acoustic_profile("max-cooling").unwrap(), AcousticProfile::MaxCooling);
+    }
+
+    #[test]
+    fn test_parse_acoustic_profile_invalid() {
+        assert!(parse_acoustic_profile("quiet").is_err());
+        assert!(parse_acoustic_profile("").is_err());
+    }
+
+    #[test]
+    fn test_config_error_span_points_at_offending_field() {
+        let err = parse_core_config("0:20:-35:50.0").unwrap_err();
+        let s = "0:20:-35:50.0";
+        assert_eq!(&s[err.span.start..err.span.end], "20");
+    }
+
+    #[test]
+    fn test_config_error_display_underlines_span() {
+        let err = validate_sample_interval("9999").unwrap_err();
+        let rendered = err.to_string();
+        assert!(rendered.contains("9999"));
+        assert!(rendered.contains('^'));
+    }
+
+    #[test]
+    fn test_config_error_kind_matches_failure_mode() {
+        assert!(matches!(
+            parse_core_config("not-a-core").unwrap_err().kind,
+            ConfigErrorKind::UnexpectedToken { .. }
+        ));
+        assert!(matches!(
+            parse_core_config("0:20:-35:50.0").unwrap_err().kind,
+            ConfigErrorKind::OutOfRange { .. }
+        ));
+    }
+
+    #[test]
+    fn test_fan_control_mode_display() {
+        assert_eq!(FanControlMode::Default.to_string(), "default");
+        assert_eq!(FanControlMode::Custom.to_string(), "custom");
+        assert_eq!(FanControlMode::Fixed.to_string(), "fixed");
+        assert_eq!(FanControlMode::Poly.to_string(), "poly");
+    }
+
+    // ==================== Control Socket Tests ====================
+
+    #[test]
+    fn test_validate_control_socket_path_existing_parent() {
+        // /tmp always exists, so a socket path under it is valid
+        assert!(validate_control_socket_path(std::path::Path::new("/tmp/gymdeck3.sock")).is_ok());
+    }
+
+    #[test]
+    fn test_validate_control_socket_path_missing_parent() {
+        let err = validate_control_socket_path(std::path::Path::new(
+            "/definitely/not/a/real/dir/gymdeck3.sock",
+        ))
+        .unwrap_err();
+        assert!(err.contains("does not exist"));
+    }
+
+    #[test]
+    fn test_validate_control_socket_path_empty() {
+        assert!(validate_control_socket_path(std::path::Path::new("")).is_err());
+    }
+
+    // ==================== Status Socket Tests ====================
+
+    #[test]
+    fn test_validate_status_socket_path_existing_parent() {
+        assert!(validate_status_socket_path(std::path::Path::new("/tmp/gymdeck3-status.sock")).is_ok());
+    }
+
+    #[test]
+    fn test_validate_status_socket_path_missing_parent() {
+        let err = validate_status_socket_path(std::path::Path::new(
+            "/definitely/not/a/real/dir/gymdeck3-status.sock",
+        ))
+        .unwrap_err();
+        assert!(err.contains("does not exist"));
+    }
+
+    #[test]
+    fn test_validate_status_socket_path_empty() {
+        assert!(validate_status_socket_path(std::path::Path::new("")).is_err());
+    }
+
+    // ==================== Config File Tests ====================
+
+    fn base_args() -> Args {
+        Args {
+            strategy: Some(Strategy::Balanced),
+            sample_interval_us: Some(100_000),
+            cores: vec![],
+            hysteresis: None,
+            ryzenadj_path: PathBuf::from("ryzenadj"),
+            status_interval_ms: None,
+            verbose: false,
+            fan_control: false,
+            fan_mode: FanControlMode::Default,
+            fan_curve: vec![],
+            fan_coeffs: None,
+            fan_coeffs_range: None,
+            fan_zero_rpm: false,
+            fan_hysteresis: 2,
+            fan_down_hysteresis: 4,
+            fan_slowdown_step_max: 0,
+            fan_setpoint: None,
+            fan_pid: None,
+            pid_target: None,
+            pid_kp: None,
+            pid_ki: None,
+            pid_kd: None,
+            pid_output_clamp: None,
+            derate_start: DEFAULT_DERATE_START_C,
+            derate_end: DEFAULT_DERATE_END_C,
+            control_socket: None,
+            status_socket: None,
+            tick_hz: 1000,
+            watchdog_timeout_ms: 10_000,
+            config: None,
+            force_unsafe_undervolt: false,
+            smoothing: SmoothingMode::None,
+            smoothing_window: None,
+            smoothing_alpha: None,
+        }
+    }
+
+    fn write_temp_file(dir: &tempfile::TempDir, name: &str, contents: &str) -> PathBuf {
+        let path = dir.path().join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_load_config_file_toml() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                strategy = "aggressive"
+                sample_interval_us = 50000
+                hysteresis = 3.0
+                status_interval_ms = 500
+
+                [[cores]]
+                core_id = 0
+                min_mv = -20
+                max_mv = -35
+                threshold = 50.0
+            "#,
+        );
+
+        let file = load_config_file(&path).unwrap();
+        assert_eq!(file.strategy, Some(Strategy::Aggressive));
+        assert_eq!(file.sample_interval_us, Some(50_000));
+        assert_eq!(file.hysteresis, Some(3.0));
+        assert_eq!(file.status_interval_ms, Some(500));
+        assert_eq!(file.cores.len(), 1);
+        assert_eq!(file.cores[0].core_id, 0);
+    }
+
+    #[test]
+    fn test_load_config_file_json() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.json",
+            r#"{"strategy":"conservative","hysteresis":2.5}"#,
+        );
+
+        let file = load_config_file(&path).unwrap();
+        assert_eq!(file.strategy, Some(Strategy::Conservative));
+        assert_eq!(file.hysteresis, Some(2.5));
+        assert_eq!(file.sample_interval_us, None);
+        assert!(file.cores.is_empty());
+    }
+
+    #[test]
+    fn test_load_config_file_rejects_out_of_range_values() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(&dir, "bad.toml", "hysteresis = 99.0\n");
+
+        let err = load_config_file(&path).unwrap_err();
+        assert!(err.contains("too large"));
+    }
+
+    #[test]
+    fn test_load_config_file_rejects_duplicate_core_ids() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "dup.toml",
+            r#"
+                [[cores]]
+                core_id = 0
+                min_mv = -20
+                max_mv = -35
+                threshold = 50.0
+
+                [[cores]]
+                core_id = 0
+                min_mv = -10
+                max_mv = -20
+                threshold = 40.0
+            "#,
+        );
+
+        let err = load_config_file(&path).unwrap_err();
+        assert!(err.contains("Duplicate core ID"));
+    }
+
+    #[test]
+    fn test_resolve_config_without_file_uses_cli_and_defaults() {
+        let resolved = resolve_config(&base_args()).unwrap();
+        assert_eq!(resolved.strategy, Strategy::Balanced);
+        assert_eq!(resolved.sample_interval_us, 100_000);
+        assert_eq!(resolved.hysteresis, DEFAULT_HYSTERESIS);
+        assert_eq!(resolved.status_interval_ms, DEFAULT_STATUS_INTERVAL_MS);
+        assert!(resolved.cores.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_config_cli_overrides_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            "strategy = \"conservative\"\nhysteresis = 10.0\n",
+        );
+
+        let mut args = base_args();
+        args.config = Some(path);
+        // CLI explicitly set strategy/hysteresis; both must win over the file
+        args.strategy = Some(Strategy::Aggressive);
+        args.hysteresis = Some(7.0);
+
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.strategy, Strategy::Aggressive);
+        assert_eq!(resolved.hysteresis, 7.0);
+    }
+
+    #[test]
+    fn test_resolve_config_falls_back_to_file_when_cli_unset() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(&dir, "gymdeck3.toml", "hysteresis = 8.0\n");
+
+        let mut args = base_args();
+        args.config = Some(path);
+        args.hysteresis = None;
+
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.hysteresis, 8.0);
+    }
+
+    #[test]
+    fn test_resolve_config_file_cores_used_when_cli_cores_empty() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                [[cores]]
+                core_id = 2
+                min_mv = -15
+                max_mv = -30
+                threshold = 60.0
+            "#,
+        );
+
+        let mut args = base_args();
+        args.config = Some(path);
+
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.cores.len(), 1);
+        assert_eq!(resolved.cores[0].core_id, 2);
+    }
 
-    if val < 1 {
-        return Err(format!(
-            "Fan hysteresis {}°C is too small (minimum: 1°C)",
-            val
-        ));
+    #[test]
+    fn test_resolve_config_cli_cores_override_file_cores_entirely() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                [[cores]]
+                core_id = 9
+                min_mv = -15
+                max_mv = -30
+                threshold = 60.0
+            "#,
+        );
+
+        let mut args = base_args();
+        args.config = Some(path);
+        args.cores = vec![validate_core_config_values(0, -20, -35, 50.0).unwrap()];
+
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.cores.len(), 1);
+        assert_eq!(resolved.cores[0].core_id, 0);
     }
-    if val > 10 {
-        return Err(format!(
-            "Fan hysteresis {}°C is too large (maximum: 10°C)",
-            val
-        ));
+
+    #[test]
+    fn test_resolve_config_missing_strategy_without_file_errors() {
+        let mut args = base_args();
+        args.strategy = None;
+        assert!(resolve_config(&args).is_err());
     }
-    Ok(val)
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_resolve_config_fan_curve_and_mode_from_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                fan_control = true
+                fan_mode = "custom"
+                fan_hysteresis = 4
+
+                [[fan_curve]]
+                temp_c = 40
+                speed_percent = 20
+
+                [[fan_curve]]
+                temp_c = 80
+                speed_percent = 100
+            "#,
+        );
+
+        let mut args = base_args();
+        args.config = Some(path);
+
+        let resolved = resolve_config(&args).unwrap();
+        assert!(resolved.fan_control);
+        assert_eq!(resolved.fan_mode, FanControlMode::Custom);
+        assert_eq!(resolved.fan_hysteresis, 4);
+        assert_eq!(resolved.fan_curve.len(), 2);
+        assert_eq!(resolved.fan_curve[1].speed_percent, 100);
+    }
 
     #[test]
-    fn test_parse_core_config_valid() {
-        let config = parse_core_config("0:-20:-35:50.0").unwrap();
-        assert_eq!(config.core_id, 0);
-        assert_eq!(config.min_mv, -20);
-        assert_eq!(config.max_mv, -35);
-        assert_eq!(config.threshold, 50.0);
+    fn test_resolve_config_cli_fan_curve_overrides_file_entirely() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                [[fan_curve]]
+                temp_c = 40
+                speed_percent = 20
+            "#,
+        );
+
+        let mut args = base_args();
+        args.config = Some(path);
+        args.fan_curve = vec![validate_fan_curve_point(50, 60).unwrap()];
+
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.fan_curve.len(), 1);
+        assert_eq!(resolved.fan_curve[0].temp_c, 50);
     }
 
     #[test]
-    fn test_parse_core_config_invalid_format() {
-        assert!(parse_core_config("0:-20:-35").is_err());
-        assert!(parse_core_config("invalid").is_err());
+    fn test_load_config_file_rejects_custom_fan_mode_with_too_few_points() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                fan_control = true
+                fan_mode = "custom"
+
+                [[fan_curve]]
+                temp_c = 40
+                speed_percent = 20
+            "#,
+        );
+
+        let err = load_config_file(&path).unwrap_err();
+        assert!(err.contains("at least 2 points"));
     }
 
     #[test]
-    fn test_parse_core_config_invalid_values() {
-        // Positive undervolt values
-        assert!(parse_core_config("0:20:-35:50.0").is_err());
-        // max_mv > min_mv
-        assert!(parse_core_config("0:-35:-20:50.0").is_err());
-        // Invalid threshold
-        assert!(parse_core_config("0:-20:-35:150.0").is_err());
+    fn test_resolve_config_fan_control_flag_is_enabled_by_either_source() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(&dir, "gymdeck3.toml", "fan_control = true\n");
+
+        let mut args = base_args();
+        args.config = Some(path);
+        // CLI doesn't pass --fan-control, but the file enables it
+        assert!(!args.fan_control);
+
+        let resolved = resolve_config(&args).unwrap();
+        assert!(resolved.fan_control);
     }
 
+    // ==================== Polynomial Fan Curve Tests ====================
+
     #[test]
-    fn test_validate_sample_interval() {
-        assert!(validate_sample_interval("10000").is_ok());
-        assert!(validate_sample_interval("5000000").is_ok());
-        assert!(validate_sample_interval("100000").is_ok());
-        
-        assert!(validate_sample_interval("9999").is_err());
-        assert!(validate_sample_interval("5000001").is_err());
-        assert!(validate_sample_interval("invalid").is_err());
+    fn test_parse_fan_coeffs_valid() {
+        let coeffs = parse_fan_coeffs("10:0:0.02").unwrap();
+        assert_eq!(coeffs.a, 10.0);
+        assert_eq!(coeffs.b, 0.0);
+        assert_eq!(coeffs.c, 0.02);
     }
 
     #[test]
-    fn test_validate_hysteresis() {
-        assert!(validate_hysteresis("1.0").is_ok());
-        assert!(validate_hysteresis("20.0").is_ok());
-        assert!(validate_hysteresis("5.5").is_ok());
-        
-        assert!(validate_hysteresis("0.5").is_err());
-        assert!(validate_hysteresis("21.0").is_err());
-        assert!(validate_hysteresis("invalid").is_err());
+    fn test_parse_fan_coeffs_invalid_format() {
+        assert!(parse_fan_coeffs("10:0").is_err());
+        assert!(parse_fan_coeffs("10:0:0.02:5").is_err());
+        assert!(parse_fan_coeffs("a:b:c").is_err());
     }
 
     #[test]
-    fn test_strategy_display() {
-        assert_eq!(Strategy::Conservative.to_string(), "conservative");
-        assert_eq!(Strategy::Balanced.to_string(), "balanced");
-        assert_eq!(Strategy::Aggressive.to_string(), "aggressive");
-        assert_eq!(Strategy::Custom.to_string(), "custom");
+    fn test_parse_fan_coeffs_rejects_non_monotonic() {
+        // Large negative linear term makes speed fall as temp rises
+        let err = parse_fan_coeffs("50:-1:0").unwrap_err();
+        assert!(err.to_string().contains("non-monotonic"));
     }
 
-    // ==================== Fan Config Tests ====================
+    #[test]
+    fn test_fan_curve_coeffs_speed_percent_clamped() {
+        let coeffs = FanCurveCoeffs { a: 200.0, b: 0.0, c: 0.0 };
+        assert_eq!(coeffs.speed_percent(50.0), 100.0);
+
+        let coeffs = FanCurveCoeffs { a: -50.0, b: 0.0, c: 0.0 };
+        assert_eq!(coeffs.speed_percent(50.0), 0.0);
+    }
 
     #[test]
-    fn test_parse_fan_curve_point_valid() {
-        let point = parse_fan_curve_point("60:50").unwrap();
-        assert_eq!(point.temp_c, 60);
-        assert_eq!(point.speed_percent, 50);
+    fn test_validate_fan_coeffs_monotonic_accepts_linear_pass_through() {
+        assert!(validate_fan_coeffs_monotonic(0.0, 1.0, 0.0).is_ok());
+    }
 
-        let point = parse_fan_curve_point("40:0").unwrap();
-        assert_eq!(point.temp_c, 40);
-        assert_eq!(point.speed_percent, 0);
+    #[test]
+    fn test_validate_args_rejects_both_fan_curve_and_fan_coeffs() {
+        let mut args = base_args();
+        args.fan_control = true;
+        args.fan_mode = FanControlMode::Poly;
+        args.fan_curve = vec![validate_fan_curve_point(40, 20).unwrap(), validate_fan_curve_point(80, 100).unwrap()];
+        args.fan_coeffs = Some(FanCurveCoeffs { a: 10.0, b: 0.0, c: 0.02 });
 
-        let point = parse_fan_curve_point("85:100").unwrap();
-        assert_eq!(point.temp_c, 85);
-        assert_eq!(point.speed_percent, 100);
+        let err = validate_args(&args).unwrap_err();
+        assert!(err.contains("mutually exclusive"));
     }
 
     #[test]
-    fn test_parse_fan_curve_point_invalid() {
-        // Wrong format
-        assert!(parse_fan_curve_point("60").is_err());
-        assert!(parse_fan_curve_point("60:50:30").is_err());
-        assert!(parse_fan_curve_point("invalid").is_err());
+    fn test_validate_args_rejects_poly_mode_without_coeffs() {
+        let mut args = base_args();
+        args.fan_control = true;
+        args.fan_mode = FanControlMode::Poly;
 
-        // Invalid values
-        assert!(parse_fan_curve_point("-10:50").is_err()); // Negative temp
-        assert!(parse_fan_curve_point("110:50").is_err()); // Temp > 100
-        assert!(parse_fan_curve_point("60:150").is_err()); // Speed > 100
+        let err = validate_args(&args).unwrap_err();
+        assert!(err.contains("requires --fan-coeffs"));
     }
 
     #[test]
-    fn test_validate_fan_hysteresis() {
-        assert!(validate_fan_hysteresis("1").is_ok());
-        assert!(validate_fan_hysteresis("5").is_ok());
-        assert!(validate_fan_hysteresis("10").is_ok());
+    fn test_parse_fan_coeffs_range_valid() {
+        assert_eq!(parse_fan_coeffs_range("40:85").unwrap(), (40, 85));
+    }
 
-        assert!(validate_fan_hysteresis("0").is_err());
-        assert!(validate_fan_hysteresis("11").is_err());
-        assert!(validate_fan_hysteresis("invalid").is_err());
+    #[test]
+    fn test_parse_fan_coeffs_range_invalid_format() {
+        assert!(parse_fan_coeffs_range("40").is_err());
+        assert!(parse_fan_coeffs_range("40:85:100").is_err());
+        assert!(parse_fan_coeffs_range("a:b").is_err());
     }
 
     #[test]
-    fn test_fan_control_mode_display() {
-        assert_eq!(FanControlMode::Default.to_string(), "default");
-        assert_eq!(FanControlMode::Custom.to_string(), "custom");
-        assert_eq!(FanControlMode::Fixed.to_string(), "fixed");
+    fn test_parse_fan_coeffs_range_rejects_inverted_bounds() {
+        let err = parse_fan_coeffs_range("85:40").unwrap_err();
+        assert!(err.to_string().contains("must be less than"));
+    }
+
+    #[test]
+    fn test_validate_args_rejects_fan_coeffs_range_without_coeffs() {
+        let mut args = base_args();
+        args.fan_coeffs_range = Some((40, 85));
+
+        let err = validate_args(&args).unwrap_err();
+        assert!(err.contains("requires --fan-coeffs"));
+    }
+
+    #[test]
+    fn test_resolve_config_fan_coeffs_range_from_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                fan_control = true
+                fan_mode = "poly"
+                fan_coeffs_range = [40, 85]
+
+                [fan_coeffs]
+                a = 0.04
+                b = 0.0
+                c = 1.0
+            "#,
+        );
+
+        let mut args = base_args();
+        args.config = Some(path);
+
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.fan_coeffs_range, Some((40, 85)));
+    }
+
+    #[test]
+    fn test_resolve_config_cli_fan_coeffs_range_override_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                fan_control = true
+                fan_mode = "poly"
+                fan_coeffs_range = [0, 100]
+
+                [fan_coeffs]
+                a = 10.0
+                b = 0.0
+                c = 0.02
+            "#,
+        );
+
+        let mut args = base_args();
+        args.config = Some(path);
+        args.fan_coeffs_range = Some((40, 85));
+
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.fan_coeffs_range, Some((40, 85)));
+    }
+
+    #[test]
+    fn test_resolve_config_fan_coeffs_from_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                fan_control = true
+                fan_mode = "poly"
+
+                [fan_coeffs]
+                a = 10.0
+                b = 0.0
+                c = 0.02
+            "#,
+        );
+
+        let mut args = base_args();
+        args.config = Some(path);
+
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.fan_mode, FanControlMode::Poly);
+        assert_eq!(resolved.fan_coeffs, Some(FanCurveCoeffs { a: 10.0, b: 0.0, c: 0.02 }));
+    }
+
+    #[test]
+    fn test_resolve_config_cli_fan_coeffs_override_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                [fan_coeffs]
+                a = 10.0
+                b = 0.0
+                c = 0.02
+            "#,
+        );
+
+        let mut args = base_args();
+        args.config = Some(path);
+        args.fan_coeffs = Some(FanCurveCoeffs { a: 5.0, b: 1.0, c: 0.0 });
+
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.fan_coeffs, Some(FanCurveCoeffs { a: 5.0, b: 1.0, c: 0.0 }));
+    }
+
+    #[test]
+    fn test_resolve_config_rejects_poly_mode_without_coeffs_from_either_source() {
+        let mut args = base_args();
+        args.fan_control = true;
+        args.fan_mode = FanControlMode::Poly;
+
+        let err = resolve_config(&args).unwrap_err();
+        assert!(err.contains("requires --fan-coeffs"));
+    }
+
+    #[test]
+    fn test_load_config_file_rejects_non_monotonic_fan_coeffs() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                [fan_coeffs]
+                a = 50.0
+                b = -1.0
+                c = 0.0
+            "#,
+        );
+
+        let err = load_config_file(&path).unwrap_err();
+        assert!(err.contains("non-monotonic"));
+    }
+
+    // ==================== PID Strategy Tests ====================
+
+    #[test]
+    fn test_validate_pid_target() {
+        assert!(validate_pid_target("70").is_ok());
+        assert!(validate_pid_target("39.9").is_err());
+        assert!(validate_pid_target("95.1").is_err());
+        assert!(validate_pid_target("invalid").is_err());
+    }
+
+    #[test]
+    fn test_validate_pid_gain() {
+        assert!(validate_pid_gain("0.5").is_ok());
+        assert!(validate_pid_gain("0").is_err());
+        assert!(validate_pid_gain("-1.0").is_err());
+    }
+
+    #[test]
+    fn test_parse_pid_output_clamp_valid() {
+        let (min, max) = parse_pid_output_clamp("-50:50").unwrap();
+        assert_eq!(min, -50.0);
+        assert_eq!(max, 50.0);
+    }
+
+    #[test]
+    fn test_parse_pid_output_clamp_rejects_inverted_range() {
+        assert!(parse_pid_output_clamp("50:-50").is_err());
+        assert!(parse_pid_output_clamp("10:10").is_err());
+    }
+
+    #[test]
+    fn test_validate_args_rejects_pid_flags_without_pid_strategy() {
+        let mut args = base_args();
+        args.strategy = Some(Strategy::Balanced);
+        args.pid_kp = Some(1.0);
+
+        let err = validate_args(&args).unwrap_err();
+        assert!(err.contains("only valid when"));
+    }
+
+    #[test]
+    fn test_validate_args_rejects_incomplete_pid_config() {
+        let mut args = base_args();
+        args.strategy = Some(Strategy::Pid);
+        args.pid_target = Some(70.0);
+        args.pid_kp = Some(1.0);
+        // pid_ki, pid_kd, pid_output_clamp left unset
+
+        let err = validate_args(&args).unwrap_err();
+        assert!(err.contains("--pid-ki"));
+        assert!(err.contains("--pid-kd"));
+        assert!(err.contains("--pid-output-clamp"));
+    }
+
+    #[test]
+    fn test_validate_args_accepts_complete_pid_config() {
+        let mut args = base_args();
+        args.strategy = Some(Strategy::Pid);
+        args.pid_target = Some(70.0);
+        args.pid_kp = Some(1.0);
+        args.pid_ki = Some(0.1);
+        args.pid_kd = Some(0.05);
+        args.pid_output_clamp = Some((-50.0, 50.0));
+
+        assert!(validate_args(&args).is_ok());
+    }
+
+    #[test]
+    fn test_resolve_config_builds_pid_config_from_cli() {
+        let mut args = base_args();
+        args.strategy = Some(Strategy::Pid);
+        args.pid_target = Some(75.0);
+        args.pid_kp = Some(2.0);
+        args.pid_ki = Some(0.2);
+        args.pid_kd = Some(0.1);
+        args.pid_output_clamp = Some((-40.0, 40.0));
+
+        let resolved = resolve_config(&args).unwrap();
+        let pid = resolved.pid_config.unwrap();
+        assert_eq!(pid.target_c, 75.0);
+        assert_eq!(pid.kp, 2.0);
+        assert_eq!(pid.output_clamp_min, -40.0);
+        assert_eq!(pid.output_clamp_max, 40.0);
+    }
+
+    #[test]
+    fn test_resolve_config_pid_config_merged_from_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                strategy = "pid"
+                pid_target = 72.0
+                pid_kp = 1.5
+                pid_ki = 0.1
+                pid_kd = 0.05
+                pid_output_clamp = [-30.0, 30.0]
+            "#,
+        );
+
+        let mut args = base_args();
+        args.strategy = None;
+        args.config = Some(path);
+
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.strategy, Strategy::Pid);
+        let pid = resolved.pid_config.unwrap();
+        assert_eq!(pid.target_c, 72.0);
+        assert_eq!(pid.output_clamp_max, 30.0);
+    }
+
+    #[test]
+    fn test_resolve_config_rejects_pid_strategy_with_incomplete_config() {
+        let mut args = base_args();
+        args.strategy = Some(Strategy::Pid);
+
+        let err = resolve_config(&args).unwrap_err();
+        assert!(err.contains("requires"));
+    }
+
+    #[test]
+    fn test_load_config_file_rejects_out_of_range_pid_target() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(&dir, "gymdeck3.toml", "pid_target = 20.0\n");
+
+        let err = load_config_file(&path).unwrap_err();
+        assert!(err.contains("out of range"));
+    }
+
+    #[test]
+    fn test_strategy_display_pid() {
+        assert_eq!(Strategy::Pid.to_string(), "pid");
+    }
+
+    // ==================== Model-Aware Core Defaults/Clamps ====================
+    //
+    // These exercise `detect_deck_model()`'s real DMI path, which reads
+    // `/sys/class/dmi/id/board_name`; the sandbox/CI host isn't a Steam Deck,
+    // so detection returns `None` here and both checks are no-ops. They
+    // still cover that an undetected model doesn't block resolution or
+    // reject a deliberately out-of-spec core.
+
+    #[test]
+    fn test_resolve_config_no_cores_without_deck_model_stays_empty() {
+        let mut args = base_args();
+        args.config = None;
+
+        let resolved = resolve_config(&args).unwrap();
+        assert!(resolved.cores.is_empty());
+    }
+
+    #[test]
+    fn test_validate_args_accepts_aggressive_core_without_deck_model() {
+        let mut args = base_args();
+        args.cores = vec![validate_core_config_values(0, -10, -80, 50.0).unwrap()];
+
+        assert!(validate_args(&args).is_ok());
+    }
+
+    #[test]
+    fn test_validate_cores_for_model_skips_when_model_undetected() {
+        let cores = vec![validate_core_config_values(0, -10, -80, 50.0).unwrap()];
+        assert!(validate_cores_for_model(&cores, false).is_ok());
+    }
+
+    // ==================== Sample Smoothing ====================
+
+    #[test]
+    fn test_validate_smoothing_window() {
+        assert!(validate_smoothing_window("1").is_ok());
+        assert!(validate_smoothing_window("10").is_ok());
+        assert!(validate_smoothing_window("0").is_err());
+        assert!(validate_smoothing_window("invalid").is_err());
+    }
+
+    #[test]
+    fn test_validate_smoothing_alpha() {
+        assert!(validate_smoothing_alpha("0.1").is_ok());
+        assert!(validate_smoothing_alpha("1.0").is_ok());
+        assert!(validate_smoothing_alpha("0.0").is_err());
+        assert!(validate_smoothing_alpha("1.1").is_err());
+    }
+
+    #[test]
+    fn test_validate_args_rejects_smoothing_flags_without_a_mode() {
+        let mut args = base_args();
+        args.smoothing_window = Some(5);
+
+        let err = validate_args(&args).unwrap_err();
+        assert!(err.contains("only valid when --smoothing is sma or ema"));
+    }
+
+    #[test]
+    fn test_validate_args_requires_window_for_sma() {
+        let mut args = base_args();
+        args.smoothing = SmoothingMode::Sma;
+
+        let err = validate_args(&args).unwrap_err();
+        assert!(err.contains("--smoothing-window"));
+    }
+
+    #[test]
+    fn test_validate_args_requires_alpha_for_ema() {
+        let mut args = base_args();
+        args.smoothing = SmoothingMode::Ema;
+
+        let err = validate_args(&args).unwrap_err();
+        assert!(err.contains("--smoothing-alpha"));
+    }
+
+    #[test]
+    fn test_validate_args_rejects_alpha_with_sma() {
+        let mut args = base_args();
+        args.smoothing = SmoothingMode::Sma;
+        args.smoothing_window = Some(5);
+        args.smoothing_alpha = Some(0.5);
+
+        let err = validate_args(&args).unwrap_err();
+        assert!(err.contains("only valid with --smoothing ema"));
+    }
+
+    #[test]
+    fn test_validate_args_accepts_complete_sma_config() {
+        let mut args = base_args();
+        args.smoothing = SmoothingMode::Sma;
+        args.smoothing_window = Some(5);
+
+        assert!(validate_args(&args).is_ok());
+    }
+
+    #[test]
+    fn test_validate_args_accepts_complete_ema_config() {
+        let mut args = base_args();
+        args.smoothing = SmoothingMode::Ema;
+        args.smoothing_alpha = Some(0.3);
+
+        assert!(validate_args(&args).is_ok());
+    }
+
+    #[test]
+    fn test_resolve_config_builds_smoothing_config_from_cli() {
+        let mut args = base_args();
+        args.smoothing = SmoothingMode::Sma;
+        args.smoothing_window = Some(8);
+
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.smoothing.mode, SmoothingMode::Sma);
+        assert_eq!(resolved.smoothing.window, 8);
+    }
+
+    #[test]
+    fn test_resolve_config_smoothing_merged_from_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(
+            &dir,
+            "gymdeck3.toml",
+            r#"
+                smoothing = "ema"
+                smoothing_alpha = 0.25
+            "#,
+        );
+
+        let mut args = base_args();
+        args.config = Some(path);
+
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.smoothing.mode, SmoothingMode::Ema);
+        assert_eq!(resolved.smoothing.alpha, 0.25);
+    }
+
+    #[test]
+    fn test_resolve_config_defaults_to_no_smoothing() {
+        let args = base_args();
+        let resolved = resolve_config(&args).unwrap();
+        assert_eq!(resolved.smoothing.mode, SmoothingMode::None);
+    }
+
+    #[test]
+    fn test_load_config_file_rejects_incomplete_smoothing() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_temp_file(&dir, "gymdeck3.toml", "smoothing = \"sma\"\n");
+
+        let err = load_config_file(&path).unwrap_err();
+        assert!(err.contains("--smoothing-window"));
+    }
+
+    #[test]
+    fn test_smoothing_mode_display() {
+        assert_eq!(SmoothingMode::None.to_string(), "none");
+        assert_eq!(SmoothingMode::Sma.to_string(), "sma");
+        assert_eq!(SmoothingMode::Ema.to_string(), "ema");
     }
 }
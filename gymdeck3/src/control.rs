@@ -0,0 +1,396 @@
+//! Unix-domain-socket control plane for live reconfiguration and status streaming
+//!
+//! Before this module the only runtime interaction with the daemon was
+//! SIGUSR1 (force one status print) and SIGTERM (shutdown), both checked in
+//! `main`'s loop over `SignalState`. Following the bidirectional
+//! request/response IPC model crosvm's `Tube` provides over a socket, this
+//! opens a Unix domain socket (path via `--control-socket`) and accepts
+//! line-delimited `RpcRequest` JSON: `set_strategy`, `set_hysteresis`,
+//! `set_core`, `set_fan_control`, `set_fan_curve`, `set_report_mode`, and
+//! `get_status`. Every connected client also receives the
+//! periodic NDJSON status stream that otherwise only goes to stdout, via
+//! the same `broadcast::Sender` installed on `OutputWriter::with_broadcast`.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::broadcast;
+
+use crate::config::{
+    validate_core_config_values, validate_fan_curve_point, validate_hysteresis_value, CoreConfig,
+    FanCurvePointConfig, Strategy,
+};
+use crate::rpc::{validate_rpc_request, RpcError, RpcMethodCall, RpcResponse};
+
+/// Live, mutable daemon configuration shared between the main loop and
+/// connected control clients
+#[derive(Debug, Clone)]
+pub struct ControlState {
+    pub strategy: Strategy,
+    pub hysteresis: f32,
+    pub cores: Vec<CoreConfig>,
+    pub fan_control: bool,
+    pub fan_curve: Vec<FanCurvePointConfig>,
+    /// Whether the per-tick interpolation/fan `report` NDJSON stream is
+    /// enabled; off by default, toggled via `set_report_mode`
+    pub report_mode: bool,
+}
+
+impl ControlState {
+    /// Seed the control state from the CLI-parsed startup configuration
+    pub fn new(
+        strategy: Strategy,
+        hysteresis: f32,
+        cores: Vec<CoreConfig>,
+        fan_control: bool,
+        fan_curve: Vec<FanCurvePointConfig>,
+    ) -> Self {
+        Self {
+            strategy,
+            hysteresis,
+            cores,
+            fan_control,
+            fan_curve,
+            report_mode: false,
+        }
+    }
+}
+
+/// Thread-safe handle to `ControlState`, cloned into every accepted connection
+#[derive(Clone)]
+pub struct SharedControlState(Arc<Mutex<ControlState>>);
+
+impl SharedControlState {
+    /// Wrap a `ControlState` for sharing across connection tasks
+    pub fn new(state: ControlState) -> Self {
+        Self(Arc::new(Mutex::new(state)))
+    }
+
+    /// Take a cloned snapshot of the current state
+    pub fn snapshot(&self) -> ControlState {
+        self.0.lock().unwrap().clone()
+    }
+
+    /// Overwrite the whole state, e.g. after a SIGHUP config reload
+    ///
+    /// Unlike the per-field RPC setters (`set_strategy`, `set_hysteresis`,
+    /// ...) a reload supplies a freshly resolved config wholesale, so there
+    /// is nothing to validate here beyond what `resolve_config` already
+    /// checked - this only replaces the shared snapshot connected clients
+    /// read, it does not touch any running controller's per-core runtime
+    /// state.
+    pub fn replace(&self, new_state: ControlState) {
+        *self.0.lock().unwrap() = new_state;
+    }
+
+    /// Validate and apply a method call, returning the JSON result payload
+    /// to echo back in the `RpcResponse` on success
+    fn apply(&self, call: &RpcMethodCall) -> Result<Value, RpcError> {
+        match call {
+            RpcMethodCall::SetStrategy(strategy) => {
+                self.0.lock().unwrap().strategy = *strategy;
+                Ok(serde_json::json!({ "strategy": strategy }))
+            }
+            // Predates the control socket and isn't one of the four
+            // documented commands; accepted for RPC compatibility but not
+            // tracked in `ControlState`.
+            RpcMethodCall::SetUndervolt(values) => Ok(serde_json::json!({ "values": values })),
+            RpcMethodCall::SetHysteresis(hysteresis) => {
+                let validated = validate_hysteresis_value(*hysteresis)
+                    .map_err(RpcError::invalid_params)?;
+                self.0.lock().unwrap().hysteresis = validated;
+                Ok(serde_json::json!({ "hysteresis": validated }))
+            }
+            RpcMethodCall::SetCore {
+                core_id,
+                min_mv,
+                max_mv,
+                threshold,
+            } => {
+                let config = validate_core_config_values(*core_id, *min_mv, *max_mv, *threshold)
+                    .map_err(RpcError::invalid_params)?;
+
+                let mut state = self.0.lock().unwrap();
+                match state.cores.iter_mut().find(|c| c.core_id == config.core_id) {
+                    Some(existing) => *existing = config.clone(),
+                    None => state.cores.push(config.clone()),
+                }
+                Ok(serde_json::to_value(&config).unwrap_or(Value::Null))
+            }
+            RpcMethodCall::SetFanControl(enabled) => {
+                self.0.lock().unwrap().fan_control = *enabled;
+                Ok(serde_json::json!({ "fan_control": enabled }))
+            }
+            RpcMethodCall::SetFanCurve(points) => {
+                if points.len() < 2 {
+                    return Err(RpcError::invalid_params("Fan curve requires at least 2 points"));
+                }
+                let mut curve = Vec::with_capacity(points.len());
+                for &(temp_c, speed_percent) in points {
+                    curve.push(
+                        validate_fan_curve_point(temp_c, speed_percent).map_err(RpcError::invalid_params)?,
+                    );
+                }
+                self.0.lock().unwrap().fan_curve = curve.clone();
+                Ok(serde_json::to_value(&curve).unwrap_or(Value::Null))
+            }
+            RpcMethodCall::SetReportMode(enabled) => {
+                self.0.lock().unwrap().report_mode = *enabled;
+                Ok(serde_json::json!({ "report_mode": enabled }))
+            }
+            RpcMethodCall::GetStatus => {
+                let state = self.0.lock().unwrap();
+                Ok(serde_json::json!({
+                    "strategy": state.strategy,
+                    "hysteresis": state.hysteresis,
+                    "cores": state.cores,
+                    "fan_control": state.fan_control,
+                    "fan_curve": state.fan_curve,
+                    "report_mode": state.report_mode,
+                }))
+            }
+        }
+    }
+}
+
+/// Unix domain socket control server
+///
+/// Accepts one connection per client and, for each, runs a command loop
+/// (line-delimited `RpcRequest` in, `RpcResponse` out) alongside a status
+/// stream fed by a `broadcast::Receiver` subscribed from the daemon's
+/// `OutputWriter`.
+pub struct ControlServer {
+    listener: UnixListener,
+    state: SharedControlState,
+    status_tx: broadcast::Sender<String>,
+}
+
+impl ControlServer {
+    /// Bind a new control server at `path`, removing a stale socket file
+    /// left over from a previous unclean shutdown
+    pub fn bind(
+        path: &Path,
+        state: SharedControlState,
+        status_tx: broadcast::Sender<String>,
+    ) -> std::io::Result<Self> {
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+
+        Ok(Self {
+            listener: UnixListener::bind(path)?,
+            state,
+            status_tx,
+        })
+    }
+
+    /// Accept connections forever, spawning an independent task per client
+    pub async fn run(self) {
+        loop {
+            match self.accept_connection().await {
+                Ok(stream) => self.spawn_client(stream),
+                Err(e) => eprintln!("control socket accept error: {}", e),
+            }
+        }
+    }
+
+    /// Accept a single pending connection
+    ///
+    /// Split out of `run` so the main loop can fold control-socket accepts
+    /// into its own `tokio::select!` as just another event source, rather
+    /// than running the accept loop as a detached task.
+    pub async fn accept_connection(&self) -> std::io::Result<UnixStream> {
+        let (stream, _addr) = self.listener.accept().await?;
+        Ok(stream)
+    }
+
+    /// Spawn the per-client command/status loop for an accepted connection
+    pub fn spawn_client(&self, stream: UnixStream) {
+        let state = self.state.clone();
+        let status_rx = self.status_tx.subscribe();
+        tokio::spawn(handle_connection(stream, state, status_rx));
+    }
+}
+
+/// Service a single client: dispatch its commands and forward the shared
+/// status stream, until either side closes the connection
+async fn handle_connection(
+    stream: UnixStream,
+    state: SharedControlState,
+    mut status_rx: broadcast::Receiver<String>,
+) {
+    let (read_half, mut write_half) = stream.into_split();
+    let mut lines = BufReader::new(read_half).lines();
+
+    loop {
+        tokio::select! {
+            line = lines.next_line() => {
+                let line = match line {
+                    Ok(Some(line)) => line,
+                    Ok(None) => break, // client closed the connection
+                    Err(e) => {
+                        eprintln!("control socket read error: {}", e);
+                        break;
+                    }
+                };
+
+                let reply = dispatch(&state, &line);
+                let Ok(json) = reply.to_json() else { break };
+                if write_half.write_all(format!("{}\n", json).as_bytes()).await.is_err() {
+                    break;
+                }
+            }
+            status = status_rx.recv() => {
+                match status {
+                    Ok(line) => {
+                        if write_half.write_all(format!("{}\n", line).as_bytes()).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+}
+
+/// Validate, parse, and apply one line of client input, always producing a
+/// response (never panics or drops the line on the floor)
+fn dispatch(state: &SharedControlState, line: &str) -> RpcResponse {
+    let request = match validate_rpc_request(line) {
+        Ok(request) => request,
+        Err(e) => return RpcResponse::failure(RpcError::invalid_params(e), None),
+    };
+
+    let call = match RpcMethodCall::from_request(&request) {
+        Ok(call) => call,
+        Err(e) => return RpcResponse::failure(e, request.id),
+    };
+
+    match state.apply(&call) {
+        Ok(result) => RpcResponse::success(result, request.id),
+        Err(e) => RpcResponse::failure(e, request.id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::RpcRequest;
+
+    fn state() -> SharedControlState {
+        SharedControlState::new(ControlState::new(Strategy::Balanced, 5.0, vec![], false, vec![]))
+    }
+
+    #[test]
+    fn test_apply_set_strategy_updates_state() {
+        let state = state();
+        let call = RpcMethodCall::SetStrategy(Strategy::Aggressive);
+        assert!(state.apply(&call).is_ok());
+        assert_eq!(state.snapshot().strategy, Strategy::Aggressive);
+    }
+
+    #[test]
+    fn test_apply_set_hysteresis_rejects_out_of_range() {
+        let state = state();
+        let call = RpcMethodCall::SetHysteresis(99.0);
+        let err = state.apply(&call).unwrap_err();
+        assert_eq!(err.code, crate::rpc::RPC_INVALID_PARAMS);
+        // Unchanged on rejection
+        assert_eq!(state.snapshot().hysteresis, 5.0);
+    }
+
+    #[test]
+    fn test_apply_set_core_inserts_new_core() {
+        let state = state();
+        let call = RpcMethodCall::SetCore {
+            core_id: 0,
+            min_mv: -20,
+            max_mv: -35,
+            threshold: 50.0,
+        };
+        assert!(state.apply(&call).is_ok());
+        assert_eq!(state.snapshot().cores.len(), 1);
+        assert_eq!(state.snapshot().cores[0].min_mv, -20);
+    }
+
+    #[test]
+    fn test_apply_set_core_rejects_invalid_bounds() {
+        let state = state();
+        let call = RpcMethodCall::SetCore {
+            core_id: 0,
+            min_mv: -35,
+            max_mv: -20,
+            threshold: 50.0,
+        };
+        let err = state.apply(&call).unwrap_err();
+        assert_eq!(err.code, crate::rpc::RPC_INVALID_PARAMS);
+        assert!(state.snapshot().cores.is_empty());
+    }
+
+    #[test]
+    fn test_apply_set_fan_control_updates_state() {
+        let state = state();
+        let call = RpcMethodCall::SetFanControl(true);
+        assert!(state.apply(&call).is_ok());
+        assert!(state.snapshot().fan_control);
+    }
+
+    #[test]
+    fn test_apply_set_report_mode_updates_state() {
+        let state = state();
+        assert!(!state.snapshot().report_mode);
+
+        let call = RpcMethodCall::SetReportMode(true);
+        assert!(state.apply(&call).is_ok());
+        assert!(state.snapshot().report_mode);
+    }
+
+    #[test]
+    fn test_apply_set_fan_curve_replaces_curve() {
+        let state = state();
+        let call = RpcMethodCall::SetFanCurve(vec![(40, 0), (60, 50), (85, 100)]);
+        assert!(state.apply(&call).is_ok());
+        assert_eq!(state.snapshot().fan_curve.len(), 3);
+        assert_eq!(state.snapshot().fan_curve[1].speed_percent, 50);
+    }
+
+    #[test]
+    fn test_apply_set_fan_curve_rejects_too_few_points() {
+        let state = state();
+        let call = RpcMethodCall::SetFanCurve(vec![(60, 50)]);
+        let err = state.apply(&call).unwrap_err();
+        assert_eq!(err.code, crate::rpc::RPC_INVALID_PARAMS);
+        assert!(state.snapshot().fan_curve.is_empty());
+    }
+
+    #[test]
+    fn test_apply_set_fan_curve_rejects_out_of_range_point() {
+        let state = state();
+        let call = RpcMethodCall::SetFanCurve(vec![(40, 0), (150, 50)]);
+        let err = state.apply(&call).unwrap_err();
+        assert_eq!(err.code, crate::rpc::RPC_INVALID_PARAMS);
+        assert!(state.snapshot().fan_curve.is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_get_status() {
+        let state = state();
+        let request = RpcRequest::new("get_status", None, Some(Value::from(1)));
+        let response = dispatch(&state, &request.to_json().unwrap());
+        assert!(response.error.is_none());
+        assert_eq!(response.id, Some(Value::from(1)));
+    }
+
+    #[test]
+    fn test_dispatch_malformed_json_returns_error_response() {
+        let state = state();
+        let response = dispatch(&state, "not json");
+        assert!(response.result.is_none());
+        assert_eq!(response.error.unwrap().code, crate::rpc::RPC_INVALID_PARAMS);
+    }
+}
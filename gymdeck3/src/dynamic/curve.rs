@@ -0,0 +1,144 @@
+//! Generic sparse-curve interpolation engine shared across curve types.
+//!
+//! [`FrequencyCurve`](super::frequency_curve::FrequencyCurve) keeps its own
+//! specialized interpolation modes (flat-hold, monotone cubic), resampling,
+//! merging, and range-query logic - those are voltage-curve-specific
+//! concerns with no obvious generalization. This module factors out only
+//! the piece that's genuinely the same across curve types: linear
+//! interpolation between two [`Interpolable`] values with boundary
+//! clamping, so a second curve type (e.g. temperature-vs-fan-duty, see
+//! [`super::fan_duty_curve`]) doesn't have to reimplement it from scratch.
+
+/// A value that can be linearly interpolated between two instances given a
+/// fraction `t` in `[0.0, 1.0]` (values outside that range extrapolate).
+pub trait Interpolable: Copy {
+    /// Interpolate `t` of the way from `a` to `b`.
+    fn lerp(a: Self, b: Self, t: f64) -> Self;
+}
+
+impl Interpolable for i32 {
+    fn lerp(a: Self, b: Self, t: f64) -> Self {
+        (a as f64 + (b as f64 - a as f64) * t).round() as i32
+    }
+}
+
+impl Interpolable for u8 {
+    fn lerp(a: Self, b: Self, t: f64) -> Self {
+        (a as f64 + (b as f64 - a as f64) * t)
+            .round()
+            .clamp(0.0, u8::MAX as f64) as u8
+    }
+}
+
+/// A sparse curve mapping an ordered numeric key (e.g. frequency, temperature)
+/// to an [`Interpolable`] value (e.g. voltage, fan duty).
+///
+/// Implementors supply the sorted `(key, value)` points via
+/// [`Self::point_count`]/[`Self::point_at`]; [`Self::value_at`] provides
+/// linear interpolation between neighbors and flat-clamping outside the
+/// tested range as a default method, shared by every curve type built on
+/// this trait. A concrete type that needs different interpolation or
+/// clamping behavior (like `FrequencyCurve`'s exact-integer arithmetic) can
+/// still implement the trait for interface conformance while overriding
+/// `value_at`.
+pub trait Curve<K, V>
+where
+    K: Copy + PartialOrd + Into<f64>,
+    V: Interpolable,
+{
+    /// Number of tested points.
+    fn point_count(&self) -> usize;
+
+    /// The `(key, value)` pair at `index`. Points must be sorted ascending
+    /// by key, with `index` in `0..self.point_count()`.
+    fn point_at(&self, index: usize) -> (K, V);
+
+    /// Linearly interpolate the value at `key`, clamping to the nearest
+    /// boundary point for keys outside the tested range.
+    fn value_at(&self, key: K) -> Result<V, String> {
+        let count = self.point_count();
+        if count == 0 {
+            return Err("Cannot interpolate on an empty curve".to_string());
+        }
+
+        let (first_key, first_value) = self.point_at(0);
+        if key <= first_key {
+            return Ok(first_value);
+        }
+
+        let (last_key, last_value) = self.point_at(count - 1);
+        if key >= last_key {
+            return Ok(last_value);
+        }
+
+        for i in 0..count - 1 {
+            let (k0, v0) = self.point_at(i);
+            let (k1, v1) = self.point_at(i + 1);
+            if key >= k0 && key <= k1 {
+                let k0f = k0.into();
+                let k1f = k1.into();
+                let t = if k1f == k0f {
+                    0.0
+                } else {
+                    (key.into() - k0f) / (k1f - k0f)
+                };
+                return Ok(V::lerp(v0, v1, t));
+            }
+        }
+
+        // Unreachable: the bounds checks above and an ascending-sorted
+        // `point_at` guarantee one of the branches above matches.
+        Ok(last_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestCurve {
+        points: Vec<(u32, i32)>,
+    }
+
+    impl Curve<u32, i32> for TestCurve {
+        fn point_count(&self) -> usize {
+            self.points.len()
+        }
+
+        fn point_at(&self, index: usize) -> (u32, i32) {
+            self.points[index]
+        }
+    }
+
+    #[test]
+    fn test_value_at_interpolates_linearly() {
+        let curve = TestCurve { points: vec![(0, 0), (100, 100)] };
+        assert_eq!(curve.value_at(50).unwrap(), 50);
+    }
+
+    #[test]
+    fn test_value_at_exact_at_points() {
+        let curve = TestCurve { points: vec![(10, -10), (20, -20), (30, -5)] };
+        assert_eq!(curve.value_at(20).unwrap(), -20);
+    }
+
+    #[test]
+    fn test_value_at_clamps_outside_range() {
+        let curve = TestCurve { points: vec![(10, -10), (20, -20)] };
+        assert_eq!(curve.value_at(0).unwrap(), -10);
+        assert_eq!(curve.value_at(100).unwrap(), -20);
+    }
+
+    #[test]
+    fn test_value_at_single_point_clamps_everywhere() {
+        let curve = TestCurve { points: vec![(10, 42)] };
+        assert_eq!(curve.value_at(0).unwrap(), 42);
+        assert_eq!(curve.value_at(1000).unwrap(), 42);
+    }
+
+    #[test]
+    fn test_value_at_rejects_empty_curve() {
+        let curve = TestCurve { points: vec![] };
+        assert!(curve.value_at(0).is_err());
+    }
+}
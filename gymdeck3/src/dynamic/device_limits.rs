@@ -0,0 +1,203 @@
+//! Device-detected per-core undervolt limits, loaded from a JSON file keyed
+//! by APU model
+//!
+//! `CoreConfig::new`'s legal offset range used to be a single hardcoded
+//! `[-100, 0]` mV constant for every core on every machine, but different
+//! AMD APUs - and even individual cores on the same APU - tolerate very
+//! different undervolt depths. [`DeviceLimits`] replaces that constant with
+//! a per-core range read from a limits file keyed by APU model name, so
+//! DeckTune can ship conservative defaults per known device while still
+//! letting advanced users widen them via the file.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// An inclusive `[min, max]` range where either bound may be absent,
+/// meaning "no limit in that direction"
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct RangeLimit<T> {
+    pub min: T,
+    pub max: T,
+}
+
+impl RangeLimit<Option<i32>> {
+    /// Whether `value` falls within the configured bounds - a missing
+    /// bound is always satisfied on that side
+    pub fn contains(&self, value: i32) -> bool {
+        self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max)
+    }
+
+    /// Render the range for an error message, e.g. `[-50, 0]` or
+    /// `[unbounded, 0]`
+    fn describe(&self) -> String {
+        let fmt = |bound: Option<i32>| bound.map_or_else(|| "unbounded".to_string(), |v| v.to_string());
+        format!("[{}, {}]", fmt(self.min), fmt(self.max))
+    }
+}
+
+/// Per-core undervolt limits and capability flags for one detected APU
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceLimits {
+    /// Allowed offset range (mV), indexed by `core_id`
+    pub cores: Vec<RangeLimit<Option<i32>>>,
+    /// Smallest offset increment the hardware honors
+    pub step: i32,
+    /// Whether this APU's cores support SMT
+    pub smt_capable: bool,
+    /// Core count this entry was detected/declared for
+    pub count: usize,
+}
+
+impl DeviceLimits {
+    /// Global `[-100, 0]` range used for any core not covered by a more
+    /// specific limit - the same bound every core used to be validated
+    /// against before per-device limits existed
+    pub const FALLBACK_OFFSET_RANGE: RangeLimit<Option<i32>> =
+        RangeLimit { min: Some(-100), max: Some(0) };
+
+    /// Fallback used when no limits file is found, or no entry matches the
+    /// detected APU - reproduces the previous hardcoded `[-100, 0]` global
+    /// range for every core, so existing setups see no behavior change.
+    pub fn conservative_default(count: usize) -> Self {
+        DeviceLimits {
+            cores: vec![Self::FALLBACK_OFFSET_RANGE; count],
+            step: 1,
+            smt_capable: false,
+            count,
+        }
+    }
+
+    /// The allowed offset range for `core_id`, falling back to
+    /// [`Self::FALLBACK_OFFSET_RANGE`] if this device's entry doesn't cover
+    /// that many cores
+    pub fn core_limit(&self, core_id: usize) -> RangeLimit<Option<i32>> {
+        self.cores.get(core_id).copied().unwrap_or(Self::FALLBACK_OFFSET_RANGE)
+    }
+
+    /// Validate `value` against `core_id`'s allowed range, returning a
+    /// message naming the actual allowed bound on failure
+    pub fn validate(&self, core_id: usize, field: &str, value: i32) -> Result<(), String> {
+        let bound = self.core_limit(core_id);
+        if bound.contains(value) {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} must be in range {} for core {}, got {}",
+                field,
+                bound.describe(),
+                core_id,
+                value
+            ))
+        }
+    }
+}
+
+/// Load a JSON limits file keyed by APU model name and return the entry
+/// matching `apu_model`
+///
+/// Returns `None` if the file doesn't exist, isn't valid JSON, or has no
+/// entry for `apu_model` - callers fall back to
+/// [`DeviceLimits::conservative_default`] in that case rather than failing
+/// to start.
+pub fn load_device_limits(path: &Path, apu_model: &str) -> Option<DeviceLimits> {
+    let contents = fs::read_to_string(path).ok()?;
+    let table: HashMap<String, DeviceLimits> = serde_json::from_str(&contents).ok()?;
+    table.get(apu_model).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_limit_contains_within_bounds() {
+        let range = RangeLimit { min: Some(-50), max: Some(0) };
+        assert!(range.contains(-25));
+        assert!(range.contains(-50));
+        assert!(range.contains(0));
+        assert!(!range.contains(-51));
+        assert!(!range.contains(1));
+    }
+
+    #[test]
+    fn test_range_limit_contains_unbounded_side() {
+        let range = RangeLimit { min: None, max: Some(0) };
+        assert!(range.contains(-1000));
+        assert!(!range.contains(1));
+    }
+
+    #[test]
+    fn test_conservative_default_matches_fallback_range() {
+        let limits = DeviceLimits::conservative_default(4);
+        assert_eq!(limits.count, 4);
+        assert_eq!(limits.cores.len(), 4);
+        for core_id in 0..4 {
+            assert_eq!(limits.core_limit(core_id), DeviceLimits::FALLBACK_OFFSET_RANGE);
+        }
+    }
+
+    #[test]
+    fn test_core_limit_falls_back_for_uncovered_core() {
+        let limits = DeviceLimits {
+            cores: vec![RangeLimit { min: Some(-20), max: Some(0) }],
+            step: 1,
+            smt_capable: false,
+            count: 1,
+        };
+        assert_eq!(limits.core_limit(0), RangeLimit { min: Some(-20), max: Some(0) });
+        assert_eq!(limits.core_limit(5), DeviceLimits::FALLBACK_OFFSET_RANGE);
+    }
+
+    #[test]
+    fn test_validate_reports_actual_allowed_bound() {
+        let limits = DeviceLimits {
+            cores: vec![RangeLimit { min: Some(-20), max: Some(0) }],
+            step: 1,
+            smt_capable: false,
+            count: 1,
+        };
+        let err = limits.validate(0, "min_mv", -30).unwrap_err();
+        assert!(err.contains("[-20, 0]"));
+        assert!(err.contains("core 0"));
+    }
+
+    #[test]
+    fn test_load_device_limits_finds_matching_model() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("device_limits.json");
+        fs::write(
+            &path,
+            r#"{
+                "Van Gogh": {
+                    "cores": [{"min": -50, "max": 0}, {"min": -50, "max": 0}],
+                    "step": 1,
+                    "smt_capable": false,
+                    "count": 2
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let limits = load_device_limits(&path, "Van Gogh").unwrap();
+        assert_eq!(limits.count, 2);
+        assert_eq!(limits.core_limit(0), RangeLimit { min: Some(-50), max: Some(0) });
+    }
+
+    #[test]
+    fn test_load_device_limits_missing_model_is_none() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("device_limits.json");
+        fs::write(&path, r#"{"Sephiroth": {"cores": [], "step": 1, "smt_capable": false, "count": 0}}"#).unwrap();
+
+        assert!(load_device_limits(&path, "Van Gogh").is_none());
+    }
+
+    #[test]
+    fn test_load_device_limits_missing_file_is_none() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert!(load_device_limits(&dir.path().join("nope.json"), "Van Gogh").is_none());
+    }
+}
@@ -0,0 +1,145 @@
+//! Temperature-vs-fan-duty curve, built on the generic [`super::curve::Curve`]
+//! engine shared with [`super::frequency_curve::FrequencyCurve`] - added to
+//! prove that engine actually generalizes beyond voltage curves rather than
+//! being frequency-curve machinery in disguise.
+
+use serde::{Deserialize, Serialize};
+
+use super::curve::Curve;
+
+/// Single tested point mapping a temperature to a fan duty percentage.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FanDutyPoint {
+    /// Temperature in degrees Celsius.
+    pub temp_c: u32,
+    /// Fan duty, 0-100%.
+    pub duty_pct: u8,
+}
+
+impl FanDutyPoint {
+    /// Create a new temperature/duty point.
+    pub fn new(temp_c: u32, duty_pct: u8) -> Self {
+        Self { temp_c, duty_pct }
+    }
+}
+
+/// Sparse temperature-to-fan-duty curve, interpolated via the shared
+/// [`Curve`] engine's default linear interpolation and boundary clamping.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FanDutyCurve {
+    points: Vec<FanDutyPoint>,
+}
+
+impl FanDutyCurve {
+    /// Create a new curve from tested points, which should be sorted
+    /// ascending by temperature - see [`Self::validate`].
+    pub fn new(points: Vec<FanDutyPoint>) -> Self {
+        Self { points }
+    }
+
+    /// Validate that points are sorted ascending by temperature with no
+    /// duplicates, and every duty value is in `[0, 100]`.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.points.is_empty() {
+            return Err("Curve has no points".to_string());
+        }
+
+        for point in &self.points {
+            if point.duty_pct > 100 {
+                return Err(format!(
+                    "Duty {}% at {} C is outside valid range [0, 100]%",
+                    point.duty_pct, point.temp_c
+                ));
+            }
+        }
+
+        for i in 0..self.points.len() - 1 {
+            let curr_temp = self.points[i].temp_c;
+            let next_temp = self.points[i + 1].temp_c;
+            if curr_temp >= next_temp {
+                return Err(format!(
+                    "Temperatures not in strictly ascending order: {} C followed by {} C",
+                    curr_temp, next_temp
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Interpolated fan duty at `temp_c`, clamping to the nearest tested
+    /// point for temperatures outside the tested range.
+    pub fn duty_at(&self, temp_c: u32) -> Result<u8, String> {
+        self.value_at(temp_c)
+    }
+}
+
+impl Curve<u32, u8> for FanDutyCurve {
+    fn point_count(&self) -> usize {
+        self.points.len()
+    }
+
+    fn point_at(&self, index: usize) -> (u32, u8) {
+        (self.points[index].temp_c, self.points[index].duty_pct)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_curve() -> FanDutyCurve {
+        FanDutyCurve::new(vec![
+            FanDutyPoint::new(40, 20),
+            FanDutyPoint::new(60, 50),
+            FanDutyPoint::new(80, 100),
+        ])
+    }
+
+    #[test]
+    fn test_duty_at_exact_point() {
+        let curve = create_test_curve();
+        assert_eq!(curve.duty_at(60).unwrap(), 50);
+    }
+
+    #[test]
+    fn test_duty_at_interpolates_between_points() {
+        let curve = create_test_curve();
+        assert_eq!(curve.duty_at(50).unwrap(), 35);
+    }
+
+    #[test]
+    fn test_duty_at_clamps_below_minimum() {
+        let curve = create_test_curve();
+        assert_eq!(curve.duty_at(0).unwrap(), 20);
+    }
+
+    #[test]
+    fn test_duty_at_clamps_above_maximum() {
+        let curve = create_test_curve();
+        assert_eq!(curve.duty_at(100).unwrap(), 100);
+    }
+
+    #[test]
+    fn test_duty_at_rejects_empty_curve() {
+        let curve = FanDutyCurve::new(vec![]);
+        assert!(curve.duty_at(50).is_err());
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_curve() {
+        assert!(create_test_curve().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_duty_out_of_range() {
+        let curve = FanDutyCurve::new(vec![FanDutyPoint::new(40, 150)]);
+        assert!(curve.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_unsorted_temperatures() {
+        let curve = FanDutyCurve::new(vec![FanDutyPoint::new(60, 50), FanDutyPoint::new(40, 20)]);
+        assert!(curve.validate().is_err());
+    }
+}
@@ -3,6 +3,14 @@
 //! Implements the FrequencyVoltageController that manages frequency-dependent
 //! voltage curves and applies voltage offsets based on real-time CPU frequency.
 //!
+//! Optionally (via [`FrequencyVoltageController::with_slew_rate_limit`]), a
+//! large jump in the curve's target voltage is not applied in one step.
+//! Instead the controller ramps toward it at `max_slew_rate_mv_per_sec`,
+//! raising the effective rate (up to a hard ceiling) if that nominal rate
+//! would miss `max_slew_duration` - the same nominal-vs-max-rate scheme
+//! gradual clock-correction controllers use to bound how long a correction
+//! is allowed to take without ever slewing unboundedly fast.
+//!
 //! Requirements: 2.1, 2.2, 2.3
 
 use std::collections::HashMap;
@@ -11,6 +19,7 @@ use std::io;
 use std::path::PathBuf;
 use std::time::{Duration, Instant};
 use crate::dynamic::frequency_curve::FrequencyCurve;
+use crate::dynamic::ratified_voltage::RatifiedVoltage;
 
 /// Cache entry for frequency readings
 #[derive(Debug, Clone)]
@@ -21,6 +30,35 @@ struct FrequencyCache {
     timestamp: Instant,
 }
 
+/// In-progress per-core voltage slew, tracked by [`FrequencyVoltageController::with_slew_rate_limit`]
+#[derive(Debug, Clone)]
+struct SlewState {
+    /// Voltage the slew started from
+    start_voltage: i32,
+    /// Voltage the slew is ramping toward
+    target_voltage: i32,
+    /// Most recently emitted voltage
+    current_voltage: i32,
+    /// When this slew began (used to size the required rate against
+    /// `max_slew_duration`)
+    started_at: Instant,
+    /// Timestamp of the previous tick (used to size `elapsed` for the next step)
+    last_tick_at: Instant,
+}
+
+/// Below this difference (in mV) between target and last-applied voltage, a
+/// correction is applied directly instead of slewed - not worth ramping a
+/// change smaller than the curve's own resolution
+pub const DEFAULT_SLEW_DEADBAND_MV: i32 = 1;
+
+/// Default ceiling on how long a single slew may take before the effective
+/// rate is raised above `max_slew_rate_mv_per_sec`
+pub const DEFAULT_MAX_SLEW_DURATION_SEC: f32 = 1.0;
+
+/// Default multiple of `max_slew_rate_mv_per_sec` the effective rate may be
+/// raised to in order to still finish within `max_slew_duration`
+pub const DEFAULT_SLEW_HARD_RATE_MULTIPLIER: f32 = 4.0;
+
 /// Frequency-based voltage controller
 ///
 /// Manages frequency-dependent voltage curves for multiple CPU cores and applies
@@ -40,6 +78,33 @@ pub struct FrequencyVoltageController {
     
     /// Base path for CPU sysfs (for testing)
     sysfs_base: PathBuf,
+
+    /// Nominal rate (mV/sec) voltage corrections are slewed at; `None`
+    /// (default) applies the curve's target voltage directly, matching the
+    /// pre-slewing behavior
+    max_slew_rate_mv_per_sec: Option<f32>,
+
+    /// Hard ceiling on how long a single slew may take before the effective
+    /// rate is raised above `max_slew_rate_mv_per_sec`
+    max_slew_duration: Duration,
+
+    /// Below this difference, a correction is applied directly instead of slewed
+    slew_deadband_mv: i32,
+
+    /// Multiple of `max_slew_rate_mv_per_sec` the effective rate may be
+    /// raised to in order to still finish within `max_slew_duration`
+    slew_hard_rate_multiplier: f32,
+
+    /// In-progress slew per core
+    slew_states: HashMap<usize, SlewState>,
+
+    /// Device-level validation/clamping consulted before a curve-computed
+    /// voltage is returned; `None` disables ratification entirely
+    ratified_voltage: Option<Box<dyn RatifiedVoltage>>,
+
+    /// When a ratifier is set and rejects a voltage, whether to clamp it
+    /// (default) or return [`FrequencyControllerError::VoltageRejected`]
+    reject_unratified_voltage: bool,
 }
 
 /// Errors from frequency voltage controller operations
@@ -59,6 +124,10 @@ pub enum FrequencyControllerError {
     
     /// Curve validation error
     InvalidCurve(String),
+
+    /// A [`RatifiedVoltage`] rejected `offset_mv` for `core_id` and
+    /// clamping was disabled
+    VoltageRejected { core_id: usize, offset_mv: i32 },
 }
 
 impl std::fmt::Display for FrequencyControllerError {
@@ -79,6 +148,9 @@ impl std::fmt::Display for FrequencyControllerError {
             FrequencyControllerError::InvalidCurve(msg) => {
                 write!(f, "Invalid curve: {}", msg)
             }
+            FrequencyControllerError::VoltageRejected { core_id, offset_mv } => {
+                write!(f, "voltage offset {} mV for core {} rejected by device ratification", offset_mv, core_id)
+            }
         }
     }
 }
@@ -103,23 +175,27 @@ impl FrequencyVoltageController {
             frequency_cache: HashMap::new(),
             cache_ttl_ms: 10, // 10ms cache TTL as per requirements
             sysfs_base: PathBuf::from("/sys/devices/system/cpu"),
+            max_slew_rate_mv_per_sec: None,
+            max_slew_duration: Duration::from_secs_f32(DEFAULT_MAX_SLEW_DURATION_SEC),
+            slew_deadband_mv: DEFAULT_SLEW_DEADBAND_MV,
+            slew_hard_rate_multiplier: DEFAULT_SLEW_HARD_RATE_MULTIPLIER,
+            slew_states: HashMap::new(),
+            ratified_voltage: None,
+            reject_unratified_voltage: false,
         }
     }
-    
+
     /// Create a FrequencyVoltageController with custom sysfs base path (for testing)
     ///
     /// # Arguments
     /// * `sysfs_base` - Base path for CPU sysfs
     pub fn with_sysfs_base(sysfs_base: PathBuf) -> Self {
         Self {
-            curves: HashMap::new(),
-            last_voltages: HashMap::new(),
-            frequency_cache: HashMap::new(),
-            cache_ttl_ms: 10,
             sysfs_base,
+            ..Self::new()
         }
     }
-    
+
     /// Set cache TTL in milliseconds (for testing)
     ///
     /// # Arguments
@@ -127,6 +203,50 @@ impl FrequencyVoltageController {
     pub fn set_cache_ttl(&mut self, ttl_ms: u64) {
         self.cache_ttl_ms = ttl_ms;
     }
+
+    /// Enable gradual voltage slewing instead of applying the curve's target
+    /// voltage in one step
+    ///
+    /// A correction larger than `slew_deadband_mv` (see
+    /// [`Self::with_slew_deadband_mv`]) is ramped toward at
+    /// `max_slew_rate_mv_per_sec`, raised up to a hard ceiling (see
+    /// [`Self::with_slew_hard_rate_multiplier`]) if needed to still finish
+    /// within `max_slew_duration`.
+    pub fn with_slew_rate_limit(mut self, max_slew_rate_mv_per_sec: f32, max_slew_duration: Duration) -> Self {
+        self.max_slew_rate_mv_per_sec = Some(max_slew_rate_mv_per_sec);
+        self.max_slew_duration = max_slew_duration;
+        self
+    }
+
+    /// Override the deadband below which a correction is applied directly
+    /// instead of slewed (default [`DEFAULT_SLEW_DEADBAND_MV`])
+    pub fn with_slew_deadband_mv(mut self, slew_deadband_mv: i32) -> Self {
+        self.slew_deadband_mv = slew_deadband_mv;
+        self
+    }
+
+    /// Override the multiple of `max_slew_rate_mv_per_sec` the effective
+    /// rate may be raised to (default [`DEFAULT_SLEW_HARD_RATE_MULTIPLIER`])
+    pub fn with_slew_hard_rate_multiplier(mut self, slew_hard_rate_multiplier: f32) -> Self {
+        self.slew_hard_rate_multiplier = slew_hard_rate_multiplier;
+        self
+    }
+
+    /// Consult `ratified_voltage` before returning any curve-computed
+    /// voltage, clamping an unsafe offset into range rather than rejecting
+    /// it (the default once a ratifier is set)
+    pub fn with_ratified_voltage(mut self, ratified_voltage: Box<dyn RatifiedVoltage>) -> Self {
+        self.ratified_voltage = Some(ratified_voltage);
+        self
+    }
+
+    /// Reject an unsafe offset with [`FrequencyControllerError::VoltageRejected`]
+    /// instead of clamping it; only meaningful once [`Self::with_ratified_voltage`]
+    /// is also set
+    pub fn with_reject_unratified_voltage(mut self) -> Self {
+        self.reject_unratified_voltage = true;
+        self
+    }
     
     /// Load a frequency curve for a specific core
     ///
@@ -145,18 +265,19 @@ impl FrequencyVoltageController {
     pub fn load_curve(&mut self, curve: FrequencyCurve) -> Result<(), FrequencyControllerError> {
         // Validate curve before loading
         curve.validate()
-            .map_err(|e| FrequencyControllerError::InvalidCurve(e))?;
+            .map_err(FrequencyControllerError::InvalidCurve)?;
         
         let core_id = curve.core_id;
         self.curves.insert(core_id, curve);
-        
+
         // Clear cached values for this core
         self.last_voltages.remove(&core_id);
         self.frequency_cache.remove(&core_id);
-        
+        self.slew_states.remove(&core_id);
+
         Ok(())
     }
-    
+
     /// Remove curve for a specific core
     ///
     /// # Arguments
@@ -165,6 +286,7 @@ impl FrequencyVoltageController {
         self.curves.remove(&core_id);
         self.last_voltages.remove(&core_id);
         self.frequency_cache.remove(&core_id);
+        self.slew_states.remove(&core_id);
     }
     
     /// Check if a curve is loaded for a core
@@ -272,20 +394,102 @@ impl FrequencyVoltageController {
         
         // Calculate voltage from curve (now we can borrow curve after mutable borrow is done)
         let curve = self.curves.get(&core_id).unwrap(); // Safe because we checked above
-        let voltage = curve.get_voltage_at_frequency(freq_mhz)
-            .map_err(|e| FrequencyControllerError::InvalidCurve(e))?;
-        
+        let mut target_voltage = curve.get_voltage_at_frequency(freq_mhz)
+            .map_err(FrequencyControllerError::InvalidCurve)?;
+
+        if let Some(ratifier) = &self.ratified_voltage {
+            if !ratifier.is_possible(core_id, target_voltage) {
+                if self.reject_unratified_voltage {
+                    return Err(FrequencyControllerError::VoltageRejected {
+                        core_id,
+                        offset_mv: target_voltage,
+                    });
+                }
+                target_voltage = ratifier.clamp(core_id, target_voltage);
+            }
+        }
+
+        if let Some(max_slew_rate_mv_per_sec) = self.max_slew_rate_mv_per_sec {
+            return Ok(self.step_slew_toward(core_id, target_voltage, max_slew_rate_mv_per_sec));
+        }
+
         // Check if voltage has changed
         if let Some(&last_voltage) = self.last_voltages.get(&core_id) {
-            if last_voltage == voltage {
+            if last_voltage == target_voltage {
                 return Ok(None); // No change, skip application
             }
         }
-        
+
         // Update last voltage
-        self.last_voltages.insert(core_id, voltage);
-        
-        Ok(Some(voltage))
+        self.last_voltages.insert(core_id, target_voltage);
+
+        Ok(Some(target_voltage))
+    }
+
+    /// Advance (or start) `core_id`'s slew toward `target`, returning the
+    /// voltage to apply this tick, or `None` if it's unchanged from last time
+    ///
+    /// A correction within `slew_deadband_mv` is applied directly. Otherwise
+    /// the slew steps `current_voltage` toward `target` by at most
+    /// `effective_rate * elapsed_since_last_tick`, where `effective_rate` is
+    /// `max_slew_rate_mv_per_sec` raised (up to `slew_hard_rate_multiplier`
+    /// times) just far enough to still cover the whole correction within
+    /// `max_slew_duration`.
+    fn step_slew_toward(&mut self, core_id: usize, target: i32, max_slew_rate_mv_per_sec: f32) -> Option<i32> {
+        let now = Instant::now();
+        let previous = self.last_voltages.get(&core_id).copied();
+
+        let retarget = match self.slew_states.get(&core_id) {
+            Some(state) => state.target_voltage != target,
+            None => true,
+        };
+
+        if retarget {
+            let start = previous.unwrap_or(target);
+            if (target - start).abs() <= self.slew_deadband_mv {
+                // Small enough correction: apply directly, no slew needed.
+                self.slew_states.remove(&core_id);
+                self.last_voltages.insert(core_id, target);
+                return if previous == Some(target) { None } else { Some(target) };
+            }
+            self.slew_states.insert(core_id, SlewState {
+                start_voltage: start,
+                target_voltage: target,
+                current_voltage: start,
+                started_at: now,
+                last_tick_at: now,
+            });
+        }
+
+        let state = self.slew_states.get_mut(&core_id)
+            .expect("just inserted above, or already present since !retarget");
+        let elapsed_since_last_tick = now.saturating_duration_since(state.last_tick_at).as_secs_f32();
+        state.last_tick_at = now;
+
+        let total_correction = (state.target_voltage - state.start_voltage).unsigned_abs() as f32;
+        // Time remaining in the correction's budget, not the full
+        // `max_slew_duration` - if earlier ticks already fell behind
+        // schedule, the required rate rises to compensate.
+        let time_left = self.max_slew_duration
+            .saturating_sub(now.saturating_duration_since(state.started_at))
+            .as_secs_f32()
+            .max(0.001);
+        let required_rate = total_correction / time_left;
+        let hard_ceiling = max_slew_rate_mv_per_sec * self.slew_hard_rate_multiplier;
+        let effective_rate = required_rate.max(max_slew_rate_mv_per_sec).min(hard_ceiling);
+
+        let remaining = state.target_voltage - state.current_voltage;
+        let step = ((effective_rate * elapsed_since_last_tick).round() as i32).min(remaining.abs()).max(0);
+        let direction = remaining.signum();
+        let new_voltage = state.current_voltage + direction * step;
+        state.current_voltage = new_voltage;
+
+        if new_voltage == state.target_voltage {
+            self.slew_states.remove(&core_id);
+        }
+
+        self.last_voltages.insert(core_id, new_voltage);
+        if previous == Some(new_voltage) { None } else { Some(new_voltage) }
     }
     
     /// Get the last applied voltage for a core
@@ -307,6 +511,7 @@ impl FrequencyVoltageController {
     pub fn clear_cache(&mut self) {
         self.frequency_cache.clear();
         self.last_voltages.clear();
+        self.slew_states.clear();
     }
     
     /// Get all loaded core IDs
@@ -318,6 +523,31 @@ impl FrequencyVoltageController {
         cores.sort_unstable();
         cores
     }
+
+    /// Reset every loaded core back to its stock (0 mV) offset
+    ///
+    /// Unlike [`Self::clear_cache`], which only forgets cached/slew state
+    /// without touching the hardware, this records a 0 mV target for every
+    /// core in [`Self::get_loaded_cores`] and hands those `(core_id,
+    /// offset_mv)` pairs back to the caller to actually apply - this
+    /// controller only computes targets from frequency, it never talks to
+    /// ryzenadj itself.
+    ///
+    /// # Important
+    /// Because the returned offsets are recorded as already-applied, this
+    /// must be the last thing run on the ryzenadj path during teardown:
+    /// once it returns, nothing else in this controller should be allowed
+    /// to compute or emit a non-zero voltage before the process exits.
+    pub fn reset_to_stock(&mut self) -> Vec<(usize, i32)> {
+        self.get_loaded_cores()
+            .into_iter()
+            .map(|core_id| {
+                self.slew_states.remove(&core_id);
+                self.last_voltages.insert(core_id, 0);
+                (core_id, 0)
+            })
+            .collect()
+    }
 }
 
 impl Default for FrequencyVoltageController {
@@ -558,7 +788,31 @@ mod tests {
         let cores = controller.get_loaded_cores();
         assert_eq!(cores, vec![0, 1, 2]); // Should be sorted
     }
-    
+
+    #[test]
+    fn test_reset_to_stock_zeroes_every_loaded_core() {
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_base = create_mock_sysfs(&temp_dir, 4);
+
+        let mut controller = FrequencyVoltageController::with_sysfs_base(sysfs_base);
+        controller.load_curve(create_test_curve(0)).unwrap();
+        controller.load_curve(create_test_curve(1)).unwrap();
+
+        controller.calculate_voltage_for_current_frequency(0).unwrap();
+        assert_ne!(controller.get_last_voltage(0), Some(0));
+
+        let resets = controller.reset_to_stock();
+        assert_eq!(resets, vec![(0, 0), (1, 0)]);
+        assert_eq!(controller.get_last_voltage(0), Some(0));
+        assert_eq!(controller.get_last_voltage(1), Some(0));
+    }
+
+    #[test]
+    fn test_reset_to_stock_with_no_loaded_cores_is_empty() {
+        let mut controller = FrequencyVoltageController::new();
+        assert_eq!(controller.reset_to_stock(), Vec::new());
+    }
+
     #[test]
     fn test_interpolation_between_points() {
         let temp_dir = TempDir::new().unwrap();
@@ -615,6 +869,160 @@ mod tests {
         assert_eq!(voltage, Some(-20));
     }
     
+    #[test]
+    fn test_slew_ramps_toward_target_instead_of_jumping() {
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_base = create_mock_sysfs(&temp_dir, 4);
+
+        let mut controller = FrequencyVoltageController::with_sysfs_base(sysfs_base)
+            .with_slew_rate_limit(10.0, Duration::from_secs(100));
+        controller.load_curve(create_test_curve(0)).unwrap();
+
+        // 1200 MHz curve target is -30 mV; with no prior last_voltage the
+        // first tick should start the slew from -30 itself (no history to
+        // slew away from), so it's emitted immediately.
+        let first = controller.calculate_voltage_for_current_frequency(0).unwrap();
+        assert_eq!(first, Some(-30));
+    }
+
+    #[test]
+    fn test_slew_steps_through_intermediate_values_to_target() {
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_base = create_mock_sysfs(&temp_dir, 4);
+        let freq_path = sysfs_base.join("cpu0/cpufreq/scaling_cur_freq");
+
+        let mut controller = FrequencyVoltageController::with_sysfs_base(sysfs_base)
+            .with_slew_rate_limit(10.0, Duration::from_secs(100))
+            .with_slew_deadband_mv(0);
+        controller.load_curve(create_test_curve(0)).unwrap();
+
+        // Establish a baseline at -30 mV (1200 MHz).
+        controller.calculate_voltage_for_current_frequency(0).unwrap();
+
+        // Jump the curve target to -50 mV (400 MHz) and confirm the very
+        // next tick doesn't jump straight there.
+        fs::write(&freq_path, "400000").unwrap();
+        controller.set_cache_ttl(0);
+        let next = controller.calculate_voltage_for_current_frequency(0).unwrap();
+        assert_ne!(next, Some(-50), "should not jump straight to the new target");
+    }
+
+    #[test]
+    fn test_slew_eventually_reaches_target_then_returns_none() {
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_base = create_mock_sysfs(&temp_dir, 4);
+        let freq_path = sysfs_base.join("cpu0/cpufreq/scaling_cur_freq");
+
+        // A tiny max_slew_duration relative to the 20 mV correction forces
+        // the effective rate up toward the hard ceiling (4x nominal here),
+        // so the correction completes within a handful of milliseconds.
+        let mut controller = FrequencyVoltageController::with_sysfs_base(sysfs_base)
+            .with_slew_rate_limit(1000.0, Duration::from_millis(1))
+            .with_slew_deadband_mv(0);
+        controller.load_curve(create_test_curve(0)).unwrap();
+        controller.calculate_voltage_for_current_frequency(0).unwrap(); // baseline -30
+
+        fs::write(&freq_path, "400000").unwrap();
+        controller.set_cache_ttl(0);
+
+        // Each tick may legitimately return `None` (no progress yet, e.g.
+        // the very first tick after a retarget has no elapsed time to ramp
+        // over) without the slew being finished, so poll `get_last_voltage`
+        // rather than relying on any single call's return value.
+        let mut reached = false;
+        for _ in 0..50 {
+            controller.calculate_voltage_for_current_frequency(0).unwrap();
+            if controller.get_last_voltage(0) == Some(-50) {
+                reached = true;
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(2));
+        }
+        assert!(reached, "slew should eventually reach the target voltage");
+        assert_eq!(controller.get_last_voltage(0), Some(-50));
+
+        // Once at target with no further frequency change, should return None.
+        assert_eq!(controller.calculate_voltage_for_current_frequency(0).unwrap(), None);
+    }
+
+    #[test]
+    fn test_slew_within_deadband_applies_directly() {
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_base = create_mock_sysfs(&temp_dir, 4);
+
+        let mut controller = FrequencyVoltageController::with_sysfs_base(sysfs_base)
+            .with_slew_rate_limit(10.0, Duration::from_secs(100))
+            .with_slew_deadband_mv(100);
+        controller.load_curve(create_test_curve(0)).unwrap();
+
+        // -30 mV target is well within a 100 mV deadband of "no prior
+        // voltage", so it's applied directly in one step.
+        let voltage = controller.calculate_voltage_for_current_frequency(0).unwrap();
+        assert_eq!(voltage, Some(-30));
+        assert_eq!(controller.get_last_voltage(0), Some(-30));
+    }
+
+    #[test]
+    fn test_ratified_voltage_clamps_unsafe_curve_value() {
+        use crate::dynamic::ratified_voltage::VanGoghRatifiedVoltage;
+
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_base = create_mock_sysfs(&temp_dir, 4);
+
+        // A curve that would request -50 mV at 400 MHz, but the ratifier
+        // only allows down to -40 mV.
+        let mut controller = FrequencyVoltageController::with_sysfs_base(sysfs_base)
+            .with_ratified_voltage(Box::new(VanGoghRatifiedVoltage::new().with_bounds(-40, 0)));
+        controller.load_curve(create_test_curve(0)).unwrap();
+
+        let freq_path = temp_dir.path().join("sys/devices/system/cpu/cpu0/cpufreq/scaling_cur_freq");
+        fs::write(&freq_path, "400000").unwrap();
+
+        let voltage = controller.calculate_voltage_for_current_frequency(0).unwrap();
+        assert_eq!(voltage, Some(-40));
+    }
+
+    #[test]
+    fn test_ratified_voltage_rejects_when_clamping_disabled() {
+        use crate::dynamic::ratified_voltage::VanGoghRatifiedVoltage;
+
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_base = create_mock_sysfs(&temp_dir, 4);
+
+        let mut controller = FrequencyVoltageController::with_sysfs_base(sysfs_base)
+            .with_ratified_voltage(Box::new(VanGoghRatifiedVoltage::new().with_bounds(-40, 0)))
+            .with_reject_unratified_voltage();
+        controller.load_curve(create_test_curve(0)).unwrap();
+
+        let freq_path = temp_dir.path().join("sys/devices/system/cpu/cpu0/cpufreq/scaling_cur_freq");
+        fs::write(&freq_path, "400000").unwrap();
+
+        let result = controller.calculate_voltage_for_current_frequency(0);
+        match result {
+            Err(FrequencyControllerError::VoltageRejected { core_id, offset_mv }) => {
+                assert_eq!(core_id, 0);
+                assert_eq!(offset_mv, -50);
+            }
+            other => panic!("expected VoltageRejected, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ratified_voltage_passes_through_safe_values() {
+        use crate::dynamic::ratified_voltage::VanGoghRatifiedVoltage;
+
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_base = create_mock_sysfs(&temp_dir, 4);
+
+        let mut controller = FrequencyVoltageController::with_sysfs_base(sysfs_base)
+            .with_ratified_voltage(Box::new(VanGoghRatifiedVoltage::new()));
+        controller.load_curve(create_test_curve(0)).unwrap();
+
+        // 1200 MHz -> -30 mV, well within Van Gogh's default [-50, 0] range.
+        let voltage = controller.calculate_voltage_for_current_frequency(0).unwrap();
+        assert_eq!(voltage, Some(-30));
+    }
+
     #[test]
     fn test_error_display() {
         let err = FrequencyControllerError::InvalidCoreId(5);
@@ -628,5 +1036,9 @@ mod tests {
         
         let err = FrequencyControllerError::InvalidCurve("invalid".to_string());
         assert!(err.to_string().contains("invalid"));
+
+        let err = FrequencyControllerError::VoltageRejected { core_id: 2, offset_mv: -80 };
+        assert!(err.to_string().contains('2'));
+        assert!(err.to_string().contains("-80"));
     }
 }
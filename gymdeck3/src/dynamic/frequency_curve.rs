@@ -0,0 +1,1480 @@
+//! Frequency-based voltage curve data structures and algorithms.
+//!
+//! This module provides data structures for managing frequency-dependent voltage curves
+//! used in the frequency-based voltage wizard. It implements linear interpolation,
+//! boundary clamping, and validation logic.
+//!
+//! Requirements: 1.5, 2.2, 2.4
+
+use serde::{Deserialize, Serialize};
+
+use super::curve::Curve;
+
+/// A point that can be interpolated by `InterpolationKind`, abstracting
+/// over which field carries the quantity being interpolated. `FrequencyCurve`
+/// interpolates `voltage_mv` today; a future curve type (e.g. one tracking
+/// test confidence per frequency) can reuse the same `InterpolationKind`
+/// machinery by implementing this trait instead of duplicating it.
+///
+/// Distinct from [`super::curve::Interpolable`], which describes a *value*
+/// that can be interpolated between two endpoints (used by the generic
+/// [`Curve`] engine); this trait describes a *point* with independent and
+/// dependent coordinates, which is what `InterpolationKind`'s own
+/// algorithms (flat-hold, monotone cubic) are written against.
+pub trait InterpolationPoint {
+    /// The independent variable (e.g. frequency in MHz)
+    fn x(&self) -> f64;
+    /// The dependent variable being interpolated (e.g. voltage in mV)
+    fn y(&self) -> f64;
+}
+
+impl InterpolationPoint for FrequencyPoint {
+    fn x(&self) -> f64 {
+        self.frequency_mhz as f64
+    }
+
+    fn y(&self) -> f64 {
+        self.voltage_mv as f64
+    }
+}
+
+/// Interpolation strategy used between a curve's tested points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum InterpolationKind {
+    /// Linear interpolation between neighboring points (current/default
+    /// behavior).
+    #[default]
+    Linear,
+    /// Hold the nearest tested point at or below the query frequency -
+    /// never interpolates to a less negative voltage than was actually
+    /// tested, at the cost of a less smooth curve. Named `Step` in curves
+    /// serialized before `ForwardFlat` existed.
+    #[serde(alias = "Step")]
+    BackwardFlat,
+    /// Hold the nearest tested point at or above the query frequency -
+    /// the mirror image of `BackwardFlat`, for curves that would rather
+    /// under-clock early than apply an unverified voltage.
+    ForwardFlat,
+    /// Monotone cubic (PCHIP) interpolation: smoother than `Linear` without
+    /// the overshoot a plain cubic spline can introduce between points.
+    MonotoneCubic,
+}
+
+/// Classification of a frequency query against a curve's tested points,
+/// returned alongside the looked-up voltage by
+/// [`FrequencyCurve::voltage_at_with_context`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterpolationDatum {
+    /// Query matched a tested point exactly, at this index into `points`.
+    Exact(usize),
+    /// Query fell strictly between `points[left]` and `points[right]`,
+    /// `t` fractions of the way across the interval (`0.0..1.0`).
+    Between { left: usize, right: usize, t: f64 },
+    /// Query was below the lowest tested frequency.
+    LeftTail,
+    /// Query was above the highest tested frequency.
+    RightTail,
+}
+
+/// How a curve reports a voltage for frequencies outside its tested range.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ExtrapolationPolicy {
+    /// Flat-clamp to the nearest boundary point's voltage (current/default
+    /// behavior).
+    #[default]
+    Clamp,
+    /// For a `RightTail` query, linearly extend the last tested segment's
+    /// slope, but never report an offset more negative than
+    /// `last_voltage + max_extra_mv` - a safety cap on how far the curve
+    /// may extrapolate past what was actually validated. `LeftTail` queries
+    /// still clamp, since under-frequency operation isn't the scenario this
+    /// policy is guarding.
+    LinearGuardband {
+        /// mV of additional (more negative) headroom allowed beyond the
+        /// last tested point's voltage, e.g. `-10` permits extrapolating
+        /// down to 10 mV more negative than `last_voltage`.
+        max_extra_mv: i32,
+    },
+}
+
+/// Single point in a frequency-voltage curve.
+///
+/// Represents a tested frequency with its associated stable voltage offset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrequencyPoint {
+    /// CPU frequency in MHz
+    pub frequency_mhz: u32,
+    
+    /// Voltage offset in mV (negative values, e.g., -30)
+    pub voltage_mv: i32,
+    
+    /// Whether this voltage was stable at this frequency
+    pub stable: bool,
+    
+    /// Duration in seconds that this point was tested
+    pub test_duration: u32,
+    
+    /// Unix timestamp when this point was tested
+    pub timestamp: f64,
+}
+
+impl FrequencyPoint {
+    /// Create a new frequency point.
+    pub fn new(
+        frequency_mhz: u32,
+        voltage_mv: i32,
+        stable: bool,
+        test_duration: u32,
+        timestamp: f64,
+    ) -> Self {
+        Self {
+            frequency_mhz,
+            voltage_mv,
+            stable,
+            test_duration,
+            timestamp,
+        }
+    }
+}
+
+/// Complete frequency-voltage curve for a CPU core.
+///
+/// Contains a collection of frequency points and provides interpolation
+/// and validation functionality.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrequencyCurve {
+    /// CPU core identifier
+    pub core_id: usize,
+    
+    /// List of frequency-voltage points (must be sorted by frequency)
+    pub points: Vec<FrequencyPoint>,
+    
+    /// Unix timestamp when curve was created
+    pub created_at: f64,
+    
+    /// Configuration used to generate this curve
+    #[serde(default)]
+    pub wizard_config: serde_json::Value,
+
+    /// Interpolation strategy between tested points; defaults to `Linear`
+    /// for curves serialized before this field existed.
+    #[serde(default)]
+    pub interpolation: InterpolationKind,
+
+    /// How to report voltages for frequencies outside the tested range;
+    /// defaults to `Clamp` for curves serialized before this field existed.
+    #[serde(default)]
+    pub extrapolation_policy: ExtrapolationPolicy,
+}
+
+impl FrequencyCurve {
+    /// Create a new frequency curve, using `InterpolationKind::Linear` and
+    /// `ExtrapolationPolicy::Clamp`.
+    ///
+    /// Use [`Self::with_interpolation`] / [`Self::with_extrapolation_policy`]
+    /// to select different strategies.
+    pub fn new(
+        core_id: usize,
+        points: Vec<FrequencyPoint>,
+        created_at: f64,
+        wizard_config: serde_json::Value,
+    ) -> Self {
+        Self {
+            core_id,
+            points,
+            created_at,
+            wizard_config,
+            interpolation: InterpolationKind::default(),
+            extrapolation_policy: ExtrapolationPolicy::default(),
+        }
+    }
+
+    /// Use a specific interpolation strategy instead of the default `Linear`.
+    pub fn with_interpolation(mut self, interpolation: InterpolationKind) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Use a specific extrapolation policy instead of the default `Clamp`.
+    pub fn with_extrapolation_policy(mut self, policy: ExtrapolationPolicy) -> Self {
+        self.extrapolation_policy = policy;
+        self
+    }
+
+    /// Calculate voltage offset for given frequency using linear interpolation.
+    ///
+    /// Uses linear interpolation between surrounding frequency points.
+    /// For frequencies outside the tested range, clamps to boundary values.
+    ///
+    /// # Arguments
+    ///
+    /// * `freq_mhz` - Target frequency in MHz
+    ///
+    /// # Returns
+    ///
+    /// Voltage offset in mV (negative value)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the curve has no points.
+    ///
+    /// # Requirements
+    ///
+    /// - 1.5: Interpolate voltage values for frequencies between tested points
+    /// - 2.2: Calculate appropriate voltage offset using linear interpolation
+    /// - 2.4: Clamp voltage to nearest boundary value for out-of-range frequencies
+    pub fn get_voltage_at_frequency(&self, freq_mhz: u32) -> Result<i32, String> {
+        self.voltage_at_with_context(freq_mhz).map(|(voltage, _)| voltage)
+    }
+
+    /// Like [`Self::get_voltage_at_frequency`], but also returns an
+    /// [`InterpolationDatum`] classifying where `freq_mhz` fell relative to
+    /// the tested points - useful for callers that want to show the user
+    /// when a value is an extrapolation rather than a tested/interpolated
+    /// one.
+    pub fn voltage_at_with_context(
+        &self,
+        freq_mhz: u32,
+    ) -> Result<(i32, InterpolationDatum), String> {
+        if self.points.is_empty() {
+            return Err("Cannot interpolate voltage from empty curve".to_string());
+        }
+
+        let datum = self.classify(freq_mhz);
+        let voltage = match datum {
+            InterpolationDatum::RightTail => self.extrapolate_right_tail(freq_mhz)?,
+            _ => self.interpolate_for_kind(freq_mhz)?,
+        };
+        Ok((voltage, datum))
+    }
+
+    /// Classify `freq_mhz` against the curve's tested points.
+    fn classify(&self, freq_mhz: u32) -> InterpolationDatum {
+        if self.points.len() == 1 {
+            return if freq_mhz == self.points[0].frequency_mhz {
+                InterpolationDatum::Exact(0)
+            } else if freq_mhz < self.points[0].frequency_mhz {
+                InterpolationDatum::LeftTail
+            } else {
+                InterpolationDatum::RightTail
+            };
+        }
+
+        if freq_mhz < self.points[0].frequency_mhz {
+            return InterpolationDatum::LeftTail;
+        }
+
+        let last_idx = self.points.len() - 1;
+        if freq_mhz > self.points[last_idx].frequency_mhz {
+            return InterpolationDatum::RightTail;
+        }
+
+        for i in 0..last_idx {
+            let p1 = &self.points[i];
+            let p2 = &self.points[i + 1];
+
+            if freq_mhz == p1.frequency_mhz {
+                return InterpolationDatum::Exact(i);
+            }
+            if freq_mhz == p2.frequency_mhz {
+                return InterpolationDatum::Exact(i + 1);
+            }
+            if p1.frequency_mhz < freq_mhz && freq_mhz < p2.frequency_mhz {
+                let h = p2.x() - p1.x();
+                let t = if h == 0.0 {
+                    0.0
+                } else {
+                    (freq_mhz as f64 - p1.x()) / h
+                };
+                return InterpolationDatum::Between { left: i, right: i + 1, t };
+            }
+        }
+
+        // Unreachable: the bounds checks above and sorted points guarantee
+        // one of the branches above matches.
+        InterpolationDatum::Exact(last_idx)
+    }
+
+    /// Dispatch to the interior (non-extrapolating) interpolation for
+    /// `self.interpolation` - correct for `Exact`, `Between`, and `LeftTail`
+    /// queries, all of which clamp or interpolate within/at the tested range.
+    fn interpolate_for_kind(&self, freq_mhz: u32) -> Result<i32, String> {
+        match self.interpolation {
+            InterpolationKind::Linear => self.interpolate_linear(freq_mhz),
+            InterpolationKind::BackwardFlat => self.interpolate_backward_flat(freq_mhz),
+            InterpolationKind::ForwardFlat => self.interpolate_forward_flat(freq_mhz),
+            InterpolationKind::MonotoneCubic => self.interpolate_monotone_cubic(freq_mhz),
+        }
+    }
+
+    /// Resolve a `RightTail` query per `self.extrapolation_policy`.
+    fn extrapolate_right_tail(&self, freq_mhz: u32) -> Result<i32, String> {
+        let ExtrapolationPolicy::LinearGuardband { max_extra_mv } = self.extrapolation_policy
+        else {
+            return self.interpolate_for_kind(freq_mhz);
+        };
+
+        // With fewer than two points there's no segment slope to extend;
+        // fall back to the flat-clamp behavior.
+        if self.points.len() < 2 {
+            return self.interpolate_for_kind(freq_mhz);
+        }
+
+        let prev = &self.points[self.points.len() - 2];
+        let last = &self.points[self.points.len() - 1];
+
+        let dx = last.x() - prev.x();
+        if dx == 0.0 {
+            return Ok(last.voltage_mv);
+        }
+
+        let slope = (last.y() - prev.y()) / dx;
+        let projected = last.y() + slope * (freq_mhz as f64 - last.x());
+
+        // Safety cap: never report an offset more negative than
+        // last_voltage + max_extra_mv.
+        let floor = last.voltage_mv as i64 + max_extra_mv as i64;
+        let clamped = (projected.round() as i64).max(floor);
+        Ok(clamped.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+    }
+
+    /// Linear interpolation, delegated to this curve's [`Curve<u32, i32>`]
+    /// implementation - see that impl's `value_at` override for why it
+    /// doesn't use the trait's generic (float-rounding) default.
+    fn interpolate_linear(&self, freq_mhz: u32) -> Result<i32, String> {
+        Curve::value_at(self, freq_mhz)
+    }
+
+    /// Hold the lower neighbor's voltage rather than interpolating - the
+    /// conservative choice, since it never reports a less negative (safer
+    /// but unverified) offset than was actually tested at-or-below `freq_mhz`.
+    /// Every value this returns is some actual `point.voltage_mv`.
+    fn interpolate_backward_flat(&self, freq_mhz: u32) -> Result<i32, String> {
+        if self.points.is_empty() {
+            return Err("Cannot interpolate voltage from empty curve".to_string());
+        }
+
+        if freq_mhz <= self.points[0].frequency_mhz {
+            return Ok(self.points[0].voltage_mv);
+        }
+
+        let last_idx = self.points.len() - 1;
+        if freq_mhz >= self.points[last_idx].frequency_mhz {
+            return Ok(self.points[last_idx].voltage_mv);
+        }
+
+        for i in 0..self.points.len() - 1 {
+            let p1 = &self.points[i];
+            let p2 = &self.points[i + 1];
+
+            if p1.frequency_mhz <= freq_mhz && freq_mhz <= p2.frequency_mhz {
+                if freq_mhz == p2.frequency_mhz {
+                    return Ok(p2.voltage_mv);
+                }
+                return Ok(p1.voltage_mv);
+            }
+        }
+
+        Err(format!("Failed to interpolate voltage for frequency {} MHz", freq_mhz))
+    }
+
+    /// Hold the upper neighbor's voltage rather than interpolating - the
+    /// mirror image of `interpolate_backward_flat`, for curves that would
+    /// rather apply a more conservative (safer) untested-range voltage
+    /// early than ever apply one that wasn't actually stability-tested at
+    /// or below `freq_mhz`. Every value this returns is some actual
+    /// `point.voltage_mv`.
+    fn interpolate_forward_flat(&self, freq_mhz: u32) -> Result<i32, String> {
+        if self.points.is_empty() {
+            return Err("Cannot interpolate voltage from empty curve".to_string());
+        }
+
+        if freq_mhz <= self.points[0].frequency_mhz {
+            return Ok(self.points[0].voltage_mv);
+        }
+
+        let last_idx = self.points.len() - 1;
+        if freq_mhz >= self.points[last_idx].frequency_mhz {
+            return Ok(self.points[last_idx].voltage_mv);
+        }
+
+        for i in 0..self.points.len() - 1 {
+            let p1 = &self.points[i];
+            let p2 = &self.points[i + 1];
+
+            if p1.frequency_mhz <= freq_mhz && freq_mhz <= p2.frequency_mhz {
+                if freq_mhz == p1.frequency_mhz {
+                    return Ok(p1.voltage_mv);
+                }
+                return Ok(p2.voltage_mv);
+            }
+        }
+
+        Err(format!("Failed to interpolate voltage for frequency {} MHz", freq_mhz))
+    }
+
+    /// Monotone cubic (PCHIP) interpolation: smoother than `interpolate_linear`
+    /// without the overshoot a plain cubic spline can introduce, which matters
+    /// here because overshooting between two stable points could interpolate
+    /// to a voltage more negative than anything actually validated.
+    fn interpolate_monotone_cubic(&self, freq_mhz: u32) -> Result<i32, String> {
+        if self.points.is_empty() {
+            return Err("Cannot interpolate voltage from empty curve".to_string());
+        }
+
+        if self.points.len() == 1 {
+            return Ok(self.points[0].voltage_mv);
+        }
+
+        if freq_mhz <= self.points[0].frequency_mhz {
+            return Ok(self.points[0].voltage_mv);
+        }
+
+        let last_idx = self.points.len() - 1;
+        if freq_mhz >= self.points[last_idx].frequency_mhz {
+            return Ok(self.points[last_idx].voltage_mv);
+        }
+
+        for i in 0..self.points.len() - 1 {
+            let p0 = &self.points[i];
+            let p1 = &self.points[i + 1];
+
+            if p0.frequency_mhz <= freq_mhz && freq_mhz <= p1.frequency_mhz {
+                if freq_mhz == p0.frequency_mhz {
+                    return Ok(p0.voltage_mv);
+                }
+                if freq_mhz == p1.frequency_mhz {
+                    return Ok(p1.voltage_mv);
+                }
+
+                let h = p1.x() - p0.x();
+                if h == 0.0 {
+                    return Ok(p0.voltage_mv);
+                }
+
+                let tangents = self.monotone_tangents();
+                let t = (freq_mhz as f64 - p0.x()) / h;
+                let t2 = t * t;
+                let t3 = t2 * t;
+
+                let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+                let h10 = t3 - 2.0 * t2 + t;
+                let h01 = -2.0 * t3 + 3.0 * t2;
+                let h11 = t3 - t2;
+
+                let m0 = tangents[i] * h;
+                let m1 = tangents[i + 1] * h;
+
+                let value = h00 * p0.y() + h10 * m0 + h01 * p1.y() + h11 * m1;
+                return Ok(value.round() as i32);
+            }
+        }
+
+        Err(format!("Failed to interpolate voltage for frequency {} MHz", freq_mhz))
+    }
+
+    /// PCHIP tangent at every curve point: the interior tangent is a
+    /// weighted harmonic mean of the two adjacent secant slopes (zeroed
+    /// wherever they change sign or either is zero, which is exactly where
+    /// a plain average would overshoot and break monotonicity); the
+    /// endpoints use the one-sided secant into their only neighbor.
+    fn monotone_tangents(&self) -> Vec<f64> {
+        let n = self.points.len();
+        let secants: Vec<f64> = self
+            .points
+            .windows(2)
+            .map(|pair| {
+                let dx = pair[1].x() - pair[0].x();
+                if dx == 0.0 {
+                    0.0
+                } else {
+                    (pair[1].y() - pair[0].y()) / dx
+                }
+            })
+            .collect();
+
+        let mut tangents = vec![0.0; n];
+        tangents[0] = secants[0];
+        tangents[n - 1] = secants[n - 2];
+
+        for i in 1..n - 1 {
+            let d_prev = secants[i - 1];
+            let d_next = secants[i];
+            if d_prev == 0.0 || d_next == 0.0 || d_prev.signum() != d_next.signum() {
+                tangents[i] = 0.0;
+                continue;
+            }
+
+            let h_prev = self.points[i].x() - self.points[i - 1].x();
+            let h_next = self.points[i + 1].x() - self.points[i].x();
+            if h_prev == 0.0 || h_next == 0.0 {
+                tangents[i] = 0.0;
+                continue;
+            }
+
+            let w1 = 2.0 * h_next + h_prev;
+            let w2 = h_next + 2.0 * h_prev;
+            tangents[i] = (w1 + w2) / (w1 / d_prev + w2 / d_next);
+        }
+
+        tangents
+    }
+
+    /// Validate curve integrity.
+    ///
+    /// Checks:
+    /// - Curve has at least one point
+    /// - Points are sorted by frequency in ascending order
+    /// - All voltages are in valid range [-100, 0] mV
+    /// - No duplicate frequencies
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if curve is valid, `Err` with descriptive error message otherwise.
+    ///
+    /// # Requirements
+    ///
+    /// - 7.4: Validate that all voltage values are within safe range [-100, 0] mV
+    /// - 7.5: Validate that frequency values are in ascending order
+    pub fn validate(&self) -> Result<(), String> {
+        if self.points.is_empty() {
+            return Err("Curve has no points".to_string());
+        }
+        
+        // Check voltage range
+        for point in &self.points {
+            if point.voltage_mv < -100 || point.voltage_mv > 0 {
+                return Err(format!(
+                    "Voltage {} mV at {} MHz is outside valid range [-100, 0] mV",
+                    point.voltage_mv, point.frequency_mhz
+                ));
+            }
+        }
+        
+        // Check sorted order and no duplicates
+        for i in 0..self.points.len() - 1 {
+            let curr_freq = self.points[i].frequency_mhz;
+            let next_freq = self.points[i + 1].frequency_mhz;
+            
+            if curr_freq >= next_freq {
+                if curr_freq == next_freq {
+                    return Err(format!(
+                        "Duplicate frequency {} MHz found in curve",
+                        curr_freq
+                    ));
+                } else {
+                    return Err(format!(
+                        "Frequencies not in ascending order: {} MHz followed by {} MHz",
+                        curr_freq, next_freq
+                    ));
+                }
+            }
+        }
+        
+        Ok(())
+    }
+
+    /// Resample onto an even frequency grid for O(1) lookups.
+    ///
+    /// Pre-interpolates a voltage every `step_mhz` across
+    /// `[min_freq, max_freq]` (using this curve's own interpolation and
+    /// extrapolation settings), producing a dense [`EvenFrequencyCurve`]
+    /// whose lookups are index arithmetic rather than a scan over
+    /// `points`. Intended to be built once at load time and then consulted
+    /// on the hot path that maps live CPU frequency to a voltage offset.
+    pub fn resample_even(&self, step_mhz: u32) -> Result<EvenFrequencyCurve, String> {
+        if step_mhz == 0 {
+            return Err("step_mhz must be nonzero".to_string());
+        }
+        if self.points.is_empty() {
+            return Err("Cannot resample an empty curve".to_string());
+        }
+
+        let min_freq_mhz = self.points[0].frequency_mhz;
+        let max_freq_mhz = self.points[self.points.len() - 1].frequency_mhz;
+        let span = max_freq_mhz - min_freq_mhz;
+        let count = (span / step_mhz) as usize + 1;
+
+        let mut values_mv = Vec::with_capacity(count);
+        for i in 0..count {
+            let freq_mhz = min_freq_mhz + step_mhz * i as u32;
+            values_mv.push(self.get_voltage_at_frequency(freq_mhz)?);
+        }
+
+        Ok(EvenFrequencyCurve {
+            core_id: self.core_id,
+            min_freq_mhz,
+            step_mhz,
+            values_mv,
+        })
+    }
+
+    /// Like [`Self::resample_even`], but sized by a target sample count
+    /// rather than a step in MHz - convenient for a governor loop that
+    /// wants "N points across the tested range" without first computing
+    /// the frequency span itself.
+    ///
+    /// There's no cached table on `FrequencyCurve` to go stale: like
+    /// `resample_even`, this always builds a fresh, independent snapshot
+    /// from the current `points`. "Rebuilding after points change" is just
+    /// calling `bake` again.
+    pub fn bake(&self, resolution: u32) -> Result<EvenFrequencyCurve, String> {
+        if resolution < 2 {
+            return Err("resolution must be at least 2".to_string());
+        }
+        if self.points.is_empty() {
+            return Err("Cannot bake an empty curve".to_string());
+        }
+
+        let min_freq_mhz = self.points[0].frequency_mhz;
+        let max_freq_mhz = self.points[self.points.len() - 1].frequency_mhz;
+        let span = max_freq_mhz - min_freq_mhz;
+        let step_mhz = (span / (resolution - 1)).max(1);
+
+        self.resample_even(step_mhz)
+    }
+
+    /// Reconstruct a sparse `FrequencyCurve` from a resampled
+    /// [`EvenFrequencyCurve`], with one point per table entry. The sparse
+    /// curve remains the source of truth; this exists for round-tripping
+    /// (e.g. inspecting or re-tuning a resampled table).
+    pub fn from_even(even: &EvenFrequencyCurve, created_at: f64) -> Self {
+        let points = even
+            .values_mv
+            .iter()
+            .enumerate()
+            .map(|(i, &voltage_mv)| {
+                FrequencyPoint::new(
+                    even.min_freq_mhz + even.step_mhz * i as u32,
+                    voltage_mv,
+                    true,
+                    0,
+                    created_at,
+                )
+            })
+            .collect();
+
+        FrequencyCurve::new(even.core_id, points, created_at, serde_json::Value::Null)
+    }
+
+    /// Apply `f` to every point's voltage, keeping frequencies, interpolation,
+    /// and extrapolation settings unchanged. Useful for applying a global
+    /// safety bias (e.g. `map_voltages(|mv| mv + 5)`) to an imported curve.
+    pub fn map_voltages(&self, f: impl Fn(i32) -> i32) -> FrequencyCurve {
+        let points = self
+            .points
+            .iter()
+            .map(|p| {
+                FrequencyPoint::new(p.frequency_mhz, f(p.voltage_mv), p.stable, p.test_duration, p.timestamp)
+            })
+            .collect();
+
+        FrequencyCurve {
+            core_id: self.core_id,
+            points,
+            created_at: self.created_at,
+            wizard_config: self.wizard_config.clone(),
+            interpolation: self.interpolation,
+            extrapolation_policy: self.extrapolation_policy,
+        }
+    }
+
+    /// Merge with `other` over the union of both curves' frequency
+    /// breakpoints, taking at each breakpoint the less aggressive (higher,
+    /// closer to 0) of the two interpolated voltages. Produces a curve
+    /// that's safe under both inputs - e.g. combining a per-core curve with
+    /// a conservative global fallback, or intersecting two wizard runs.
+    pub fn merge_conservative(&self, other: &FrequencyCurve) -> Result<FrequencyCurve, String> {
+        let mut freqs: Vec<u32> = self
+            .points
+            .iter()
+            .chain(other.points.iter())
+            .map(|p| p.frequency_mhz)
+            .collect();
+        freqs.sort_unstable();
+        freqs.dedup();
+
+        let mut points = Vec::with_capacity(freqs.len());
+        for freq_mhz in freqs {
+            let v_self = self.get_voltage_at_frequency(freq_mhz)?;
+            let v_other = other.get_voltage_at_frequency(freq_mhz)?;
+            let voltage_mv = v_self.max(v_other);
+            points.push(FrequencyPoint::new(freq_mhz, voltage_mv, true, 0, self.created_at));
+        }
+
+        let merged = FrequencyCurve {
+            core_id: self.core_id,
+            points,
+            created_at: self.created_at,
+            wizard_config: serde_json::Value::Null,
+            interpolation: self.interpolation,
+            extrapolation_policy: self.extrapolation_policy,
+        };
+        merged.validate()?;
+        Ok(merged)
+    }
+
+    /// Decompose the curve into maximal runs of points with non-decreasing
+    /// or non-increasing voltage, used by [`Self::voltage_range_over`] to
+    /// answer range queries without sampling. A new section starts whenever
+    /// the sign of `v_{k+1} - v_k` flips; flat segments (`diff == 0`) stay
+    /// in the current section.
+    fn monotonic_sections(&self) -> Vec<MonotonicSection> {
+        if self.points.is_empty() {
+            return Vec::new();
+        }
+        if self.points.len() == 1 {
+            return vec![MonotonicSection::from_range(&self.points, 0, 0)];
+        }
+
+        let mut sections = Vec::new();
+        let mut start = 0;
+        let mut sign = 0i32;
+        for i in 0..self.points.len() - 1 {
+            let diff_sign = (self.points[i + 1].voltage_mv - self.points[i].voltage_mv).signum();
+            if diff_sign == 0 {
+                continue;
+            }
+            if sign == 0 {
+                sign = diff_sign;
+            } else if diff_sign != sign {
+                sections.push(MonotonicSection::from_range(&self.points, start, i));
+                start = i;
+                sign = diff_sign;
+            }
+        }
+        sections.push(MonotonicSection::from_range(&self.points, start, self.points.len() - 1));
+        sections
+    }
+
+    /// Return the (min, max) interpolated voltage offset anywhere in
+    /// `[f_lo, f_hi]` (order-independent), for pre-apply safety checks like
+    /// "does this curve ever request less than -80 mV between 2000-3000
+    /// MHz?". The interval is clamped into the tested range first.
+    ///
+    /// Exact for piecewise-monotonic interpolation (every mode this curve
+    /// supports): within a monotonic section, interpolated values never
+    /// exceed the range of that section's own two tested endpoints, so the
+    /// overall extremum is the extremum across the clipped query endpoints
+    /// plus every section boundary (the point where the curve's direction
+    /// reverses) strictly enclosed by the query - no sampling required.
+    pub fn voltage_range_over(&self, f_lo: u32, f_hi: u32) -> Result<(i32, i32), String> {
+        if self.points.is_empty() {
+            return Err("Cannot compute voltage range over an empty curve".to_string());
+        }
+
+        let min_freq_mhz = self.points[0].frequency_mhz;
+        let max_freq_mhz = self.points[self.points.len() - 1].frequency_mhz;
+        let (lo, hi) = if f_lo <= f_hi { (f_lo, f_hi) } else { (f_hi, f_lo) };
+        let clipped_lo = lo.clamp(min_freq_mhz, max_freq_mhz);
+        let clipped_hi = hi.clamp(min_freq_mhz, max_freq_mhz);
+
+        let lo_voltage_mv = self.get_voltage_at_frequency(clipped_lo)?;
+        let hi_voltage_mv = self.get_voltage_at_frequency(clipped_hi)?;
+        let mut min_voltage_mv = lo_voltage_mv.min(hi_voltage_mv);
+        let mut max_voltage_mv = lo_voltage_mv.max(hi_voltage_mv);
+
+        let sections = self.monotonic_sections();
+        for pair in sections.windows(2) {
+            // pair[0].end_freq_mhz == pair[1].start_freq_mhz: the point
+            // where the curve switches from rising to falling or back.
+            let boundary_freq_mhz = pair[0].end_freq_mhz;
+            if boundary_freq_mhz > clipped_lo && boundary_freq_mhz < clipped_hi {
+                let boundary_voltage_mv = self.get_voltage_at_frequency(boundary_freq_mhz)?;
+                min_voltage_mv = min_voltage_mv.min(boundary_voltage_mv);
+                max_voltage_mv = max_voltage_mv.max(boundary_voltage_mv);
+            }
+        }
+
+        Ok((min_voltage_mv, max_voltage_mv))
+    }
+}
+
+impl Curve<u32, i32> for FrequencyCurve {
+    fn point_count(&self) -> usize {
+        self.points.len()
+    }
+
+    fn point_at(&self, index: usize) -> (u32, i32) {
+        (self.points[index].frequency_mhz, self.points[index].voltage_mv)
+    }
+
+    /// Override the generic float-based default with the curve's original
+    /// exact-integer formula: `v = v1 + (v2 - v1) * (f - f1) / (f2 - f1)`
+    /// computed entirely in `i64`. The generic default rounds a
+    /// floating-point `t`, which disagrees with this truncating-division
+    /// formula at exact half-mV midpoints - and this formula is the one
+    /// every existing interpolation test is written against.
+    fn value_at(&self, freq_mhz: u32) -> Result<i32, String> {
+        if self.points.is_empty() {
+            return Err("Cannot interpolate voltage from empty curve".to_string());
+        }
+
+        if self.points.len() == 1 {
+            return Ok(self.points[0].voltage_mv);
+        }
+
+        if freq_mhz <= self.points[0].frequency_mhz {
+            return Ok(self.points[0].voltage_mv);
+        }
+
+        let last_idx = self.points.len() - 1;
+        if freq_mhz >= self.points[last_idx].frequency_mhz {
+            return Ok(self.points[last_idx].voltage_mv);
+        }
+
+        for i in 0..self.points.len() - 1 {
+            let p1 = &self.points[i];
+            let p2 = &self.points[i + 1];
+
+            if p1.frequency_mhz <= freq_mhz && freq_mhz <= p2.frequency_mhz {
+                let freq_range = p2.frequency_mhz as i64 - p1.frequency_mhz as i64;
+                let voltage_range = p2.voltage_mv as i64 - p1.voltage_mv as i64;
+                let freq_offset = freq_mhz as i64 - p1.frequency_mhz as i64;
+
+                if freq_range == 0 {
+                    return Ok(p1.voltage_mv);
+                }
+
+                let interpolated_voltage = p1.voltage_mv as i64 + (voltage_range * freq_offset) / freq_range;
+                return Ok(interpolated_voltage as i32);
+            }
+        }
+
+        // Unreachable: the bounds checks above and sorted points guarantee
+        // one of the branches above matches.
+        Err(format!("Failed to interpolate voltage for frequency {} MHz", freq_mhz))
+    }
+}
+
+/// One maximal non-decreasing or non-increasing run of points within a
+/// `FrequencyCurve`, as produced by [`FrequencyCurve::monotonic_sections`].
+/// Consecutive sections share a boundary frequency: the point where the
+/// curve's direction reverses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MonotonicSection {
+    start_freq_mhz: u32,
+    end_freq_mhz: u32,
+}
+
+impl MonotonicSection {
+    fn from_range(points: &[FrequencyPoint], start: usize, end: usize) -> Self {
+        MonotonicSection {
+            start_freq_mhz: points[start].frequency_mhz,
+            end_freq_mhz: points[end].frequency_mhz,
+        }
+    }
+}
+
+/// A `FrequencyCurve` resampled onto an even frequency grid, for O(1)
+/// lookups on the hot path that maps live CPU frequency to a voltage
+/// offset at the sample interval.
+///
+/// Built once via [`FrequencyCurve::resample_even`]; the sparse
+/// `FrequencyCurve` remains the source of truth.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvenFrequencyCurve {
+    /// CPU core identifier
+    pub core_id: usize,
+
+    /// Frequency in MHz of `values_mv[0]`
+    pub min_freq_mhz: u32,
+
+    /// Frequency spacing in MHz between consecutive table entries
+    pub step_mhz: u32,
+
+    /// Pre-interpolated voltage offsets in mV, one per grid step
+    pub values_mv: Vec<i32>,
+}
+
+impl EvenFrequencyCurve {
+    /// Number of samples in the baked table, i.e. the resolution chosen
+    /// when this table was built (via [`FrequencyCurve::bake`] or
+    /// [`FrequencyCurve::resample_even`]).
+    pub fn resolution(&self) -> usize {
+        self.values_mv.len()
+    }
+
+    /// Alias for [`Self::voltage_at_frequency`], named to match
+    /// [`FrequencyCurve::bake`] for callers that bake a table up front and
+    /// then sample it repeatedly on a hot path.
+    pub fn sample_baked(&self, freq_mhz: u32) -> Result<i32, String> {
+        self.voltage_at_frequency(freq_mhz)
+    }
+
+    /// Look up the voltage offset for `freq_mhz` by integer division into
+    /// the table, interpolating linearly between the two nearest entries -
+    /// no search over `values_mv`.
+    pub fn voltage_at_frequency(&self, freq_mhz: u32) -> Result<i32, String> {
+        if self.values_mv.is_empty() {
+            return Err("Cannot look up voltage in an empty even curve".to_string());
+        }
+
+        let last_idx = self.values_mv.len() - 1;
+        if freq_mhz <= self.min_freq_mhz {
+            return Ok(self.values_mv[0]);
+        }
+
+        let max_freq_mhz = self.min_freq_mhz + self.step_mhz * last_idx as u32;
+        if freq_mhz >= max_freq_mhz {
+            return Ok(self.values_mv[last_idx]);
+        }
+
+        let offset = freq_mhz - self.min_freq_mhz;
+        let idx = ((offset / self.step_mhz) as usize).min(last_idx.saturating_sub(1));
+        let f0 = self.min_freq_mhz + self.step_mhz * idx as u32;
+
+        let v0 = self.values_mv[idx] as i64;
+        let v1 = self.values_mv[idx + 1] as i64;
+        let frac_num = (freq_mhz - f0) as i64;
+        let frac_den = self.step_mhz as i64;
+
+        Ok((v0 + (v1 - v0) * frac_num / frac_den) as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn create_test_curve() -> FrequencyCurve {
+        let points = vec![
+            FrequencyPoint::new(400, -50, true, 30, 1706198430.0),
+            FrequencyPoint::new(800, -40, true, 30, 1706198460.0),
+            FrequencyPoint::new(1200, -30, true, 30, 1706198490.0),
+            FrequencyPoint::new(1600, -20, true, 30, 1706198520.0),
+        ];
+        
+        FrequencyCurve::new(
+            0,
+            points,
+            1706198400.0,
+            serde_json::json!({"freq_step": 400}),
+        )
+    }
+    
+    #[test]
+    fn test_interpolation_exact_point() {
+        let curve = create_test_curve();
+        assert_eq!(curve.get_voltage_at_frequency(800).unwrap(), -40);
+    }
+    
+    #[test]
+    fn test_interpolation_between_points() {
+        let curve = create_test_curve();
+        // Midpoint between 400 MHz (-50 mV) and 800 MHz (-40 mV)
+        // Should be -45 mV
+        assert_eq!(curve.get_voltage_at_frequency(600).unwrap(), -45);
+    }
+    
+    #[test]
+    fn test_boundary_clamping_below() {
+        let curve = create_test_curve();
+        // Below minimum frequency should return minimum voltage
+        assert_eq!(curve.get_voltage_at_frequency(200).unwrap(), -50);
+    }
+    
+    #[test]
+    fn test_boundary_clamping_above() {
+        let curve = create_test_curve();
+        // Above maximum frequency should return maximum voltage
+        assert_eq!(curve.get_voltage_at_frequency(2000).unwrap(), -20);
+    }
+    
+    #[test]
+    fn test_empty_curve_error() {
+        let curve = FrequencyCurve::new(0, vec![], 0.0, serde_json::json!({}));
+        assert!(curve.get_voltage_at_frequency(1000).is_err());
+    }
+    
+    #[test]
+    fn test_single_point_curve() {
+        let points = vec![FrequencyPoint::new(1000, -30, true, 30, 0.0)];
+        let curve = FrequencyCurve::new(0, points, 0.0, serde_json::json!({}));
+        
+        assert_eq!(curve.get_voltage_at_frequency(500).unwrap(), -30);
+        assert_eq!(curve.get_voltage_at_frequency(1000).unwrap(), -30);
+        assert_eq!(curve.get_voltage_at_frequency(1500).unwrap(), -30);
+    }
+    
+    #[test]
+    fn test_validation_success() {
+        let curve = create_test_curve();
+        assert!(curve.validate().is_ok());
+    }
+    
+    #[test]
+    fn test_validation_empty_curve() {
+        let curve = FrequencyCurve::new(0, vec![], 0.0, serde_json::json!({}));
+        assert!(curve.validate().is_err());
+    }
+    
+    #[test]
+    fn test_validation_voltage_out_of_range_high() {
+        let points = vec![
+            FrequencyPoint::new(400, 10, true, 30, 0.0), // Invalid: positive voltage
+        ];
+        let curve = FrequencyCurve::new(0, points, 0.0, serde_json::json!({}));
+        assert!(curve.validate().is_err());
+    }
+    
+    #[test]
+    fn test_validation_voltage_out_of_range_low() {
+        let points = vec![
+            FrequencyPoint::new(400, -150, true, 30, 0.0), // Invalid: too negative
+        ];
+        let curve = FrequencyCurve::new(0, points, 0.0, serde_json::json!({}));
+        assert!(curve.validate().is_err());
+    }
+    
+    #[test]
+    fn test_validation_unsorted_frequencies() {
+        let points = vec![
+            FrequencyPoint::new(800, -40, true, 30, 0.0),
+            FrequencyPoint::new(400, -50, true, 30, 0.0), // Out of order
+        ];
+        let curve = FrequencyCurve::new(0, points, 0.0, serde_json::json!({}));
+        assert!(curve.validate().is_err());
+    }
+    
+    #[test]
+    fn test_validation_duplicate_frequencies() {
+        let points = vec![
+            FrequencyPoint::new(400, -50, true, 30, 0.0),
+            FrequencyPoint::new(400, -40, true, 30, 0.0), // Duplicate
+        ];
+        let curve = FrequencyCurve::new(0, points, 0.0, serde_json::json!({}));
+        assert!(curve.validate().is_err());
+    }
+    
+    #[test]
+    fn test_serialization_roundtrip() {
+        let curve = create_test_curve();
+        let json = serde_json::to_string(&curve).unwrap();
+        let deserialized: FrequencyCurve = serde_json::from_str(&json).unwrap();
+        assert_eq!(curve, deserialized);
+    }
+
+    #[test]
+    fn test_default_interpolation_is_linear() {
+        let curve = create_test_curve();
+        assert_eq!(curve.interpolation, InterpolationKind::Linear);
+    }
+
+    #[test]
+    fn test_backward_flat_interpolation_uses_lower_neighbor() {
+        let curve = create_test_curve().with_interpolation(InterpolationKind::BackwardFlat);
+        // Between 400 MHz (-50 mV) and 800 MHz (-40 mV), backward-flat holds -50
+        assert_eq!(curve.get_voltage_at_frequency(600).unwrap(), -50);
+        // Exact point still matches exactly
+        assert_eq!(curve.get_voltage_at_frequency(800).unwrap(), -40);
+    }
+
+    #[test]
+    fn test_backward_flat_interpolation_respects_boundaries() {
+        let curve = create_test_curve().with_interpolation(InterpolationKind::BackwardFlat);
+        assert_eq!(curve.get_voltage_at_frequency(200).unwrap(), -50);
+        assert_eq!(curve.get_voltage_at_frequency(2000).unwrap(), -20);
+    }
+
+    #[test]
+    fn test_forward_flat_interpolation_uses_upper_neighbor() {
+        let curve = create_test_curve().with_interpolation(InterpolationKind::ForwardFlat);
+        // Between 400 MHz (-50 mV) and 800 MHz (-40 mV), forward-flat holds -40
+        assert_eq!(curve.get_voltage_at_frequency(600).unwrap(), -40);
+        // Exact point still matches exactly
+        assert_eq!(curve.get_voltage_at_frequency(400).unwrap(), -50);
+    }
+
+    #[test]
+    fn test_forward_flat_interpolation_respects_boundaries() {
+        let curve = create_test_curve().with_interpolation(InterpolationKind::ForwardFlat);
+        assert_eq!(curve.get_voltage_at_frequency(200).unwrap(), -50);
+        assert_eq!(curve.get_voltage_at_frequency(2000).unwrap(), -20);
+    }
+
+    #[test]
+    fn test_backward_flat_deserializes_legacy_step_alias() {
+        // Curves serialized before `ForwardFlat` existed used the name
+        // `Step`; `BackwardFlat` must keep accepting it.
+        let json = serde_json::json!({
+            "core_id": 0,
+            "points": [],
+            "created_at": 0.0,
+            "wizard_config": {},
+            "interpolation": "Step"
+        });
+        let curve: FrequencyCurve = serde_json::from_value(json).unwrap();
+        assert_eq!(curve.interpolation, InterpolationKind::BackwardFlat);
+    }
+
+    #[test]
+    fn test_monotone_cubic_matches_linear_on_collinear_points() {
+        // create_test_curve()'s points are evenly spaced and collinear, so
+        // PCHIP's tangents reduce exactly to the shared secant slope.
+        let curve = create_test_curve().with_interpolation(InterpolationKind::MonotoneCubic);
+        assert_eq!(curve.get_voltage_at_frequency(600).unwrap(), -45);
+    }
+
+    #[test]
+    fn test_monotone_cubic_hits_knots_exactly() {
+        let points = vec![
+            FrequencyPoint::new(400, -60, true, 30, 0.0),
+            FrequencyPoint::new(800, -45, true, 30, 0.0),
+            FrequencyPoint::new(1200, -40, true, 30, 0.0),
+        ];
+        let curve = FrequencyCurve::new(0, points, 0.0, serde_json::json!({}))
+            .with_interpolation(InterpolationKind::MonotoneCubic);
+        assert_eq!(curve.get_voltage_at_frequency(400).unwrap(), -60);
+        assert_eq!(curve.get_voltage_at_frequency(800).unwrap(), -45);
+        assert_eq!(curve.get_voltage_at_frequency(1200).unwrap(), -40);
+    }
+
+    #[test]
+    fn test_monotone_cubic_does_not_overshoot() {
+        // A flattening curve: the secant slope drops sharply at the middle
+        // point, which is exactly where an unconstrained cubic spline would
+        // overshoot past -40 mV. PCHIP must stay within the bracketing
+        // points' values on every interval.
+        let points = vec![
+            FrequencyPoint::new(400, -60, true, 30, 0.0),
+            FrequencyPoint::new(800, -40, true, 30, 0.0),
+            FrequencyPoint::new(1200, -38, true, 30, 0.0),
+        ];
+        let curve = FrequencyCurve::new(0, points, 0.0, serde_json::json!({}))
+            .with_interpolation(InterpolationKind::MonotoneCubic);
+
+        for freq in (800..=1200).step_by(20) {
+            let v = curve.get_voltage_at_frequency(freq).unwrap();
+            assert!(
+                (-40..=-38).contains(&v),
+                "interpolated voltage {} mV at {} MHz overshot the [-40, -38] bracket",
+                v,
+                freq
+            );
+        }
+    }
+
+    #[test]
+    fn test_monotone_cubic_respects_boundaries() {
+        let curve = create_test_curve().with_interpolation(InterpolationKind::MonotoneCubic);
+        assert_eq!(curve.get_voltage_at_frequency(200).unwrap(), -50);
+        assert_eq!(curve.get_voltage_at_frequency(2000).unwrap(), -20);
+    }
+
+    #[test]
+    fn test_interpolation_kind_serialization_roundtrip() {
+        let curve = create_test_curve().with_interpolation(InterpolationKind::BackwardFlat);
+        let json = serde_json::to_string(&curve).unwrap();
+        let deserialized: FrequencyCurve = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.interpolation, InterpolationKind::BackwardFlat);
+    }
+
+    #[test]
+    fn test_missing_interpolation_field_deserializes_to_linear() {
+        // Simulates a curve saved before `interpolation` existed.
+        let json = serde_json::json!({
+            "core_id": 0,
+            "points": [],
+            "created_at": 0.0,
+            "wizard_config": {}
+        });
+        let curve: FrequencyCurve = serde_json::from_value(json).unwrap();
+        assert_eq!(curve.interpolation, InterpolationKind::Linear);
+    }
+
+    #[test]
+    fn test_context_reports_exact() {
+        let curve = create_test_curve();
+        let (voltage, datum) = curve.voltage_at_with_context(800).unwrap();
+        assert_eq!(voltage, -40);
+        assert_eq!(datum, InterpolationDatum::Exact(1));
+    }
+
+    #[test]
+    fn test_context_reports_between() {
+        let curve = create_test_curve();
+        let (voltage, datum) = curve.voltage_at_with_context(600).unwrap();
+        assert_eq!(voltage, -45);
+        assert_eq!(datum, InterpolationDatum::Between { left: 0, right: 1, t: 0.5 });
+    }
+
+    #[test]
+    fn test_context_reports_tails() {
+        let curve = create_test_curve();
+        let (_, left) = curve.voltage_at_with_context(200).unwrap();
+        assert_eq!(left, InterpolationDatum::LeftTail);
+        let (_, right) = curve.voltage_at_with_context(2000).unwrap();
+        assert_eq!(right, InterpolationDatum::RightTail);
+    }
+
+    #[test]
+    fn test_default_extrapolation_policy_is_clamp() {
+        let curve = create_test_curve();
+        assert_eq!(curve.extrapolation_policy, ExtrapolationPolicy::Clamp);
+        // Clamp behavior: right tail holds the last tested voltage exactly.
+        assert_eq!(curve.get_voltage_at_frequency(2000).unwrap(), -20);
+    }
+
+    #[test]
+    fn test_linear_guardband_extends_last_segment_slope() {
+        let curve = create_test_curve()
+            .with_extrapolation_policy(ExtrapolationPolicy::LinearGuardband { max_extra_mv: -100 });
+        // Last segment (1200 MHz, -30 mV) -> (1600 MHz, -20 mV) has slope
+        // 0.025 mV/MHz; 400 MHz past the last point should project +10 mV.
+        assert_eq!(curve.get_voltage_at_frequency(2000).unwrap(), -10);
+    }
+
+    #[test]
+    fn test_linear_guardband_never_exceeds_safety_cap() {
+        // A curve whose last segment trends more negative with frequency -
+        // exactly the dangerous direction the guardband must cap.
+        let points = vec![
+            FrequencyPoint::new(400, -20, true, 30, 0.0),
+            FrequencyPoint::new(800, -40, true, 30, 0.0),
+        ];
+        let curve = FrequencyCurve::new(0, points, 0.0, serde_json::json!({}))
+            .with_extrapolation_policy(ExtrapolationPolicy::LinearGuardband { max_extra_mv: -10 });
+
+        // Unconstrained projection at 1600 MHz would be -40 + (-20)*2 = -80 mV,
+        // but the cap limits it to last_voltage + max_extra_mv = -50 mV.
+        assert_eq!(curve.get_voltage_at_frequency(1600).unwrap(), -50);
+    }
+
+    #[test]
+    fn test_linear_guardband_does_not_affect_left_tail() {
+        let curve = create_test_curve()
+            .with_extrapolation_policy(ExtrapolationPolicy::LinearGuardband { max_extra_mv: -100 });
+        assert_eq!(curve.get_voltage_at_frequency(200).unwrap(), -50);
+    }
+
+    #[test]
+    fn test_extrapolation_policy_serialization_roundtrip() {
+        let curve = create_test_curve()
+            .with_extrapolation_policy(ExtrapolationPolicy::LinearGuardband { max_extra_mv: -15 });
+        let json = serde_json::to_string(&curve).unwrap();
+        let deserialized: FrequencyCurve = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            deserialized.extrapolation_policy,
+            ExtrapolationPolicy::LinearGuardband { max_extra_mv: -15 }
+        );
+    }
+
+    #[test]
+    fn test_missing_extrapolation_policy_field_deserializes_to_clamp() {
+        // Simulates a curve saved before `extrapolation_policy` existed.
+        let json = serde_json::json!({
+            "core_id": 0,
+            "points": [],
+            "created_at": 0.0,
+            "wizard_config": {}
+        });
+        let curve: FrequencyCurve = serde_json::from_value(json).unwrap();
+        assert_eq!(curve.extrapolation_policy, ExtrapolationPolicy::Clamp);
+    }
+
+    #[test]
+    fn test_resample_even_produces_expected_table() {
+        let curve = create_test_curve();
+        let even = curve.resample_even(200).unwrap();
+        assert_eq!(even.min_freq_mhz, 400);
+        assert_eq!(even.step_mhz, 200);
+        assert_eq!(even.values_mv, vec![-50, -45, -40, -35, -30, -25, -20]);
+    }
+
+    #[test]
+    fn test_resample_even_matches_sparse_curve_at_grid_points() {
+        let curve = create_test_curve();
+        let even = curve.resample_even(200).unwrap();
+        for freq in (400..=1600).step_by(200) {
+            assert_eq!(
+                even.voltage_at_frequency(freq).unwrap(),
+                curve.get_voltage_at_frequency(freq).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_even_curve_lookup_interpolates_between_entries() {
+        let curve = create_test_curve();
+        let even = curve.resample_even(200).unwrap();
+        // Halfway between the 400 MHz (-50 mV) and 600 MHz (-45 mV) entries
+        assert_eq!(even.voltage_at_frequency(500).unwrap(), -48);
+    }
+
+    #[test]
+    fn test_even_curve_lookup_clamps_at_boundaries() {
+        let curve = create_test_curve();
+        let even = curve.resample_even(200).unwrap();
+        assert_eq!(even.voltage_at_frequency(0).unwrap(), -50);
+        assert_eq!(even.voltage_at_frequency(5000).unwrap(), -20);
+    }
+
+    #[test]
+    fn test_resample_even_rejects_zero_step() {
+        let curve = create_test_curve();
+        assert!(curve.resample_even(0).is_err());
+    }
+
+    #[test]
+    fn test_resample_even_rejects_empty_curve() {
+        let curve = FrequencyCurve::new(0, vec![], 0.0, serde_json::json!({}));
+        assert!(curve.resample_even(200).is_err());
+    }
+
+    #[test]
+    fn test_bake_produces_table_with_requested_resolution() {
+        let curve = create_test_curve();
+        let baked = curve.bake(7).unwrap();
+        assert_eq!(baked.resolution(), 7);
+        assert_eq!(baked.min_freq_mhz, 400);
+        assert_eq!(baked.step_mhz, 200);
+    }
+
+    #[test]
+    fn test_sample_baked_matches_voltage_at_frequency() {
+        let curve = create_test_curve();
+        let baked = curve.bake(7).unwrap();
+        for freq in (400..=1600).step_by(50) {
+            assert_eq!(
+                baked.sample_baked(freq).unwrap(),
+                baked.voltage_at_frequency(freq).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_bake_rejects_resolution_below_two() {
+        let curve = create_test_curve();
+        assert!(curve.bake(1).is_err());
+        assert!(curve.bake(0).is_err());
+    }
+
+    #[test]
+    fn test_bake_rejects_empty_curve() {
+        let curve = FrequencyCurve::new(0, vec![], 0.0, serde_json::json!({}));
+        assert!(curve.bake(10).is_err());
+    }
+
+    #[test]
+    fn test_from_even_round_trip() {
+        let curve = create_test_curve();
+        let even = curve.resample_even(200).unwrap();
+        let reconstructed = FrequencyCurve::from_even(&even, curve.created_at);
+
+        assert_eq!(reconstructed.core_id, curve.core_id);
+        for freq in (400..=1600).step_by(200) {
+            assert_eq!(
+                reconstructed.get_voltage_at_frequency(freq).unwrap(),
+                even.voltage_at_frequency(freq).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_map_voltages_applies_bias_to_every_point() {
+        let curve = create_test_curve().map_voltages(|mv| mv + 5);
+        assert_eq!(curve.get_voltage_at_frequency(400).unwrap(), -45);
+        assert_eq!(curve.get_voltage_at_frequency(1600).unwrap(), -15);
+    }
+
+    #[test]
+    fn test_map_voltages_preserves_settings() {
+        let curve = create_test_curve()
+            .with_interpolation(InterpolationKind::BackwardFlat)
+            .map_voltages(|mv| mv);
+        assert_eq!(curve.interpolation, InterpolationKind::BackwardFlat);
+    }
+
+    #[test]
+    fn test_merge_conservative_takes_less_aggressive_voltage() {
+        let per_core = create_test_curve(); // -50..-20 mV across 400..1600 MHz
+        let conservative_points = vec![
+            FrequencyPoint::new(400, -30, true, 30, 0.0),
+            FrequencyPoint::new(1600, -30, true, 30, 0.0),
+        ];
+        let conservative = FrequencyCurve::new(0, conservative_points, 0.0, serde_json::json!({}));
+
+        let merged = per_core.merge_conservative(&conservative).unwrap();
+
+        // At 400 MHz: per-core wants -50, conservative wants -30; -30 is safer.
+        assert_eq!(merged.get_voltage_at_frequency(400).unwrap(), -30);
+        // At 1600 MHz: per-core wants -20, conservative wants -30; -20 is safer.
+        assert_eq!(merged.get_voltage_at_frequency(1600).unwrap(), -20);
+    }
+
+    #[test]
+    fn test_merge_conservative_unions_breakpoints() {
+        let a = FrequencyCurve::new(
+            0,
+            vec![
+                FrequencyPoint::new(400, -40, true, 30, 0.0),
+                FrequencyPoint::new(1200, -20, true, 30, 0.0),
+            ],
+            0.0,
+            serde_json::json!({}),
+        );
+        let b = FrequencyCurve::new(
+            0,
+            vec![
+                FrequencyPoint::new(800, -40, true, 30, 0.0),
+                FrequencyPoint::new(1600, -10, true, 30, 0.0),
+            ],
+            0.0,
+            serde_json::json!({}),
+        );
+
+        let merged = a.merge_conservative(&b).unwrap();
+        let merged_freqs: Vec<u32> = merged.points.iter().map(|p| p.frequency_mhz).collect();
+        assert_eq!(merged_freqs, vec![400, 800, 1200, 1600]);
+        assert!(merged.validate().is_ok());
+    }
+
+    #[test]
+    fn test_merge_conservative_rejects_empty_other_curve() {
+        let curve = create_test_curve();
+        let empty = FrequencyCurve::new(0, vec![], 0.0, serde_json::json!({}));
+        assert!(curve.merge_conservative(&empty).is_err());
+    }
+
+    #[test]
+    fn test_voltage_range_over_monotonic_curve_matches_endpoints() {
+        // create_test_curve() is strictly increasing: -50, -40, -30, -20.
+        let curve = create_test_curve();
+        assert_eq!(curve.voltage_range_over(800, 1200).unwrap(), (-40, -30));
+    }
+
+    #[test]
+    fn test_voltage_range_over_clips_to_tested_range() {
+        let curve = create_test_curve();
+        assert_eq!(curve.voltage_range_over(0, 10_000).unwrap(), (-50, -20));
+    }
+
+    #[test]
+    fn test_voltage_range_over_is_order_independent() {
+        let curve = create_test_curve();
+        assert_eq!(
+            curve.voltage_range_over(1200, 800).unwrap(),
+            curve.voltage_range_over(800, 1200).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_voltage_range_over_finds_enclosed_peak() {
+        // Rises then falls: the peak at 800 MHz is an enclosed section
+        // boundary, not one of the query's own clipped endpoints.
+        let points = vec![
+            FrequencyPoint::new(400, -50, true, 30, 0.0),
+            FrequencyPoint::new(800, -10, true, 30, 0.0),
+            FrequencyPoint::new(1200, -50, true, 30, 0.0),
+        ];
+        let curve = FrequencyCurve::new(0, points, 0.0, serde_json::json!({}));
+        assert_eq!(curve.voltage_range_over(400, 1200).unwrap(), (-50, -10));
+    }
+
+    #[test]
+    fn test_voltage_range_over_rejects_empty_curve() {
+        let empty = FrequencyCurve::new(0, vec![], 0.0, serde_json::json!({}));
+        assert!(empty.voltage_range_over(400, 1600).is_err());
+    }
+}
@@ -0,0 +1,532 @@
+//! Unix-domain-socket control daemon for `FrequencyVoltageController`
+//!
+//! Mirrors `control::ControlServer`'s request/response-over-socket design
+//! (and its status-stream fan-out), but scoped to
+//! [`FrequencyVoltageController`] so an unprivileged GUI frontend can drive
+//! curve loading and frequency/voltage queries - `LoadCurve`, `RemoveCurve`,
+//! `GetLoadedCores`, `ReadFrequency`, `GetLastVoltage` - without embedding
+//! the privileged controller itself. A client that sends `Subscribe` also
+//! starts receiving a `Sample` for every loaded core on each monitoring
+//! tick, same as connecting to `ControlServer` gets you the NDJSON status
+//! stream. The daemon owns one controller instance and line-delimited JSON
+//! request/response framing, same as `control`'s.
+//!
+//! [`FrequencyControlServer::run_monitoring_loop`] also observes
+//! [`crate::signals::SignalState`]'s shutdown signal: on SIGTERM/SIGINT it
+//! resets every loaded core to stock and publishes a `Reset` for each
+//! before returning, so a killed process can't leave the CPU undervolted.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::broadcast;
+
+use crate::dynamic::frequency_controller::{FrequencyControllerError, FrequencyVoltageController};
+use crate::dynamic::frequency_curve::FrequencyCurve;
+
+/// One request a client may send to the frequency control daemon
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum FrequencyDaemonRequest {
+    /// Load (or replace) a core's frequency curve
+    LoadCurve { curve: FrequencyCurve },
+    /// Remove a core's loaded curve
+    RemoveCurve { core_id: usize },
+    /// List every core with a curve currently loaded
+    GetLoadedCores,
+    /// Read a core's current CPU frequency
+    ReadFrequency { core_id: usize },
+    /// Read a core's last applied voltage
+    GetLastVoltage { core_id: usize },
+    /// Start receiving a `Sample` for every loaded core on each monitoring tick
+    Subscribe,
+}
+
+/// One response (or streamed sample) the daemon sends back
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FrequencyDaemonResponse {
+    /// Request succeeded with nothing further to report
+    Ok,
+    /// Request failed
+    Error { message: String },
+    /// Reply to `GetLoadedCores`
+    LoadedCores { core_ids: Vec<usize> },
+    /// Reply to `ReadFrequency`
+    Frequency { core_id: usize, freq_mhz: u32 },
+    /// Reply to `GetLastVoltage`
+    LastVoltage { core_id: usize, voltage_mv: Option<i32> },
+    /// One monitoring-tick reading, pushed to every subscribed client
+    Sample {
+        core_id: usize,
+        freq_mhz: u32,
+        applied_mv: Option<i32>,
+    },
+    /// A core was cleared back to its stock (0 mV) offset, pushed to every
+    /// subscribed client once per core as the server shuts down
+    Reset { core_id: usize, offset_mv: i32 },
+}
+
+impl From<FrequencyControllerError> for FrequencyDaemonResponse {
+    fn from(e: FrequencyControllerError) -> Self {
+        FrequencyDaemonResponse::Error { message: e.to_string() }
+    }
+}
+
+/// Thread-safe handle to a `FrequencyVoltageController`, cloned into every
+/// accepted connection and shared with the monitoring loop
+#[derive(Clone)]
+pub struct SharedFrequencyController(Arc<Mutex<FrequencyVoltageController>>);
+
+impl SharedFrequencyController {
+    /// Wrap a `FrequencyVoltageController` for sharing across connection tasks
+    pub fn new(controller: FrequencyVoltageController) -> Self {
+        Self(Arc::new(Mutex::new(controller)))
+    }
+
+    /// Validate and apply one request, returning the response to send back
+    fn apply(&self, request: &FrequencyDaemonRequest) -> FrequencyDaemonResponse {
+        match request {
+            FrequencyDaemonRequest::LoadCurve { curve } => {
+                match self.0.lock().unwrap().load_curve(curve.clone()) {
+                    Ok(()) => FrequencyDaemonResponse::Ok,
+                    Err(e) => e.into(),
+                }
+            }
+            FrequencyDaemonRequest::RemoveCurve { core_id } => {
+                self.0.lock().unwrap().remove_curve(*core_id);
+                FrequencyDaemonResponse::Ok
+            }
+            FrequencyDaemonRequest::GetLoadedCores => FrequencyDaemonResponse::LoadedCores {
+                core_ids: self.0.lock().unwrap().get_loaded_cores(),
+            },
+            FrequencyDaemonRequest::ReadFrequency { core_id } => {
+                match self.0.lock().unwrap().read_current_frequency(*core_id) {
+                    Ok(freq_mhz) => FrequencyDaemonResponse::Frequency {
+                        core_id: *core_id,
+                        freq_mhz,
+                    },
+                    Err(e) => e.into(),
+                }
+            }
+            FrequencyDaemonRequest::GetLastVoltage { core_id } => FrequencyDaemonResponse::LastVoltage {
+                core_id: *core_id,
+                voltage_mv: self.0.lock().unwrap().get_last_voltage(*core_id),
+            },
+            FrequencyDaemonRequest::Subscribe => FrequencyDaemonResponse::Ok,
+        }
+    }
+
+    /// One monitoring tick: recompute every loaded core's voltage and
+    /// return a `Sample` for each readable core. Unlike
+    /// `calculate_voltage_for_current_frequency`'s `Ok(None)` change
+    /// suppression, a subscriber wants every tick's reading, so
+    /// `get_last_voltage` (the post-tick value, whether or not it changed)
+    /// is used rather than the tick's own return value.
+    fn sample_all(&self) -> Vec<FrequencyDaemonResponse> {
+        let mut controller = self.0.lock().unwrap();
+        let core_ids = controller.get_loaded_cores();
+        core_ids
+            .into_iter()
+            .filter_map(|core_id| {
+                let freq_mhz = controller.read_current_frequency(core_id).ok()?;
+                let _ = controller.calculate_voltage_for_current_frequency(core_id);
+                let applied_mv = controller.get_last_voltage(core_id);
+                Some(FrequencyDaemonResponse::Sample {
+                    core_id,
+                    freq_mhz,
+                    applied_mv,
+                })
+            })
+            .collect()
+    }
+
+    /// Clear every loaded core back to its stock (0 mV) offset
+    ///
+    /// See [`FrequencyVoltageController::reset_to_stock`] - this must be
+    /// the last thing the monitoring loop does before it stops, since
+    /// nothing should compute or emit a non-zero voltage afterward.
+    fn reset_to_stock(&self) -> Vec<(usize, i32)> {
+        self.0.lock().unwrap().reset_to_stock()
+    }
+}
+
+/// Unix domain socket control server for `FrequencyVoltageController`
+///
+/// Accepts one connection per client and, for each, runs a command loop
+/// (line-delimited `FrequencyDaemonRequest` in, `FrequencyDaemonResponse`
+/// out), plus per-tick `Sample`s once the client has sent `Subscribe`.
+pub struct FrequencyControlServer {
+    listener: UnixListener,
+    controller: SharedFrequencyController,
+    sample_tx: broadcast::Sender<FrequencyDaemonResponse>,
+}
+
+impl FrequencyControlServer {
+    /// Bind a new server at `path`, removing a stale socket file left over
+    /// from a previous unclean shutdown
+    pub fn bind(
+        path: &Path,
+        controller: SharedFrequencyController,
+        sample_tx: broadcast::Sender<FrequencyDaemonResponse>,
+    ) -> std::io::Result<Self> {
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+
+        Ok(Self {
+            listener: UnixListener::bind(path)?,
+            controller,
+            sample_tx,
+        })
+    }
+
+    /// Accept connections forever, spawning an independent task per client
+    pub async fn run(self) {
+        loop {
+            match self.accept_connection().await {
+                Ok(stream) => self.spawn_client(stream),
+                Err(e) => eprintln!("frequency control socket accept error: {}", e),
+            }
+        }
+    }
+
+    /// Accept a single pending connection
+    ///
+    /// Split out of `run` so the main loop can fold this socket's accepts
+    /// into its own `tokio::select!` as just another event source, same as
+    /// `control::ControlServer::accept_connection`.
+    pub async fn accept_connection(&self) -> std::io::Result<UnixStream> {
+        let (stream, _addr) = self.listener.accept().await?;
+        Ok(stream)
+    }
+
+    /// Spawn the per-client command/sample loop for an accepted connection
+    pub fn spawn_client(&self, stream: UnixStream) {
+        let controller = self.controller.clone();
+        let sample_rx = self.sample_tx.subscribe();
+        tokio::spawn(handle_connection(stream, controller, sample_rx));
+    }
+
+    /// Run the per-core monitoring loop, publishing a `Sample` for every
+    /// loaded core once per `tick_interval`, until `signal_state` reports a
+    /// shutdown. On shutdown, every loaded core is reset to stock (see
+    /// [`SharedFrequencyController::reset_to_stock`]) and a `Reset` is
+    /// published for each before the loop returns - this is the last thing
+    /// the loop does, so no further voltage is computed or emitted after it.
+    pub async fn run_monitoring_loop(
+        controller: SharedFrequencyController,
+        sample_tx: broadcast::Sender<FrequencyDaemonResponse>,
+        tick_interval: Duration,
+        signal_state: crate::signals::SignalState,
+    ) {
+        let mut interval = tokio::time::interval(tick_interval);
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    for sample in controller.sample_all() {
+                        let _ = sample_tx.send(sample);
+                    }
+                }
+                _ = signal_state.shutdown_notified() => {
+                    for (core_id, offset_mv) in controller.reset_to_stock() {
+                        let _ = sample_tx.send(FrequencyDaemonResponse::Reset { core_id, offset_mv });
+                    }
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Service a single client: dispatch its commands and, once subscribed,
+/// forward the shared sample stream, until either side closes the connection
+async fn handle_connection(
+    stream: UnixStream,
+    controller: SharedFrequencyController,
+    mut sample_rx: broadcast::Receiver<FrequencyDaemonResponse>,
+) {
+    let (read_half, mut write_half) = stream.into_split();
+    let mut lines = BufReader::new(read_half).lines();
+    let mut subscribed = false;
+
+    loop {
+        tokio::select! {
+            line = lines.next_line() => {
+                let line = match line {
+                    Ok(Some(line)) => line,
+                    Ok(None) => break, // client closed the connection
+                    Err(e) => {
+                        eprintln!("frequency control socket read error: {}", e);
+                        break;
+                    }
+                };
+
+                let response = dispatch(&controller, &line, &mut subscribed);
+
+                let Ok(json) = serde_json::to_string(&response) else { break };
+                if write_half.write_all(format!("{}\n", json).as_bytes()).await.is_err() {
+                    break;
+                }
+            }
+            sample = sample_rx.recv(), if subscribed => {
+                match sample {
+                    Ok(sample) => {
+                        let Ok(json) = serde_json::to_string(&sample) else { continue };
+                        if write_half.write_all(format!("{}\n", json).as_bytes()).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+}
+
+/// Parse one line of client input into a request, or a ready-made error
+/// response if it isn't well-formed JSON
+fn parse_request(line: &str) -> Result<FrequencyDaemonRequest, FrequencyDaemonResponse> {
+    serde_json::from_str(line)
+        .map_err(|e| FrequencyDaemonResponse::Error { message: format!("invalid request: {}", e) })
+}
+
+/// Parse and apply one line of client input, always producing a response
+///
+/// Also flips `*subscribed` on an accepted `Subscribe` request, so
+/// `handle_connection`'s `tokio::select!` can start forwarding broadcast
+/// samples to this client.
+fn dispatch(
+    controller: &SharedFrequencyController,
+    line: &str,
+    subscribed: &mut bool,
+) -> FrequencyDaemonResponse {
+    match parse_request(line) {
+        Ok(request) => {
+            if request == FrequencyDaemonRequest::Subscribe {
+                *subscribed = true;
+            }
+            controller.apply(&request)
+        }
+        Err(response) => response,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::frequency_curve::FrequencyPoint;
+
+    fn test_curve(core_id: usize) -> FrequencyCurve {
+        let points = vec![
+            FrequencyPoint::new(400, -50, true, 30, 0.0),
+            FrequencyPoint::new(1600, -20, true, 30, 0.0),
+        ];
+        FrequencyCurve::new(core_id, points, 0.0, serde_json::json!({}))
+    }
+
+    fn shared() -> SharedFrequencyController {
+        SharedFrequencyController::new(FrequencyVoltageController::new())
+    }
+
+    #[test]
+    fn test_apply_load_curve_then_get_loaded_cores() {
+        let controller = shared();
+        let response = controller.apply(&FrequencyDaemonRequest::LoadCurve { curve: test_curve(0) });
+        assert_eq!(response, FrequencyDaemonResponse::Ok);
+
+        let response = controller.apply(&FrequencyDaemonRequest::GetLoadedCores);
+        assert_eq!(response, FrequencyDaemonResponse::LoadedCores { core_ids: vec![0] });
+    }
+
+    #[test]
+    fn test_apply_load_curve_rejects_invalid_curve() {
+        let controller = shared();
+        let bad_curve = FrequencyCurve::new(
+            0,
+            vec![FrequencyPoint::new(400, -150, true, 30, 0.0)],
+            0.0,
+            serde_json::json!({}),
+        );
+        let response = controller.apply(&FrequencyDaemonRequest::LoadCurve { curve: bad_curve });
+        match response {
+            FrequencyDaemonResponse::Error { .. } => {}
+            other => panic!("expected Error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_apply_remove_curve() {
+        let controller = shared();
+        controller.apply(&FrequencyDaemonRequest::LoadCurve { curve: test_curve(0) });
+        let response = controller.apply(&FrequencyDaemonRequest::RemoveCurve { core_id: 0 });
+        assert_eq!(response, FrequencyDaemonResponse::Ok);
+
+        let response = controller.apply(&FrequencyDaemonRequest::GetLoadedCores);
+        assert_eq!(response, FrequencyDaemonResponse::LoadedCores { core_ids: vec![] });
+    }
+
+    #[test]
+    fn test_apply_get_last_voltage_before_any_tick() {
+        let controller = shared();
+        controller.apply(&FrequencyDaemonRequest::LoadCurve { curve: test_curve(0) });
+        let response = controller.apply(&FrequencyDaemonRequest::GetLastVoltage { core_id: 0 });
+        assert_eq!(
+            response,
+            FrequencyDaemonResponse::LastVoltage { core_id: 0, voltage_mv: None }
+        );
+    }
+
+    #[test]
+    fn test_apply_subscribe_returns_ok() {
+        let controller = shared();
+        assert_eq!(controller.apply(&FrequencyDaemonRequest::Subscribe), FrequencyDaemonResponse::Ok);
+    }
+
+    #[test]
+    fn test_dispatch_malformed_json_returns_error_response() {
+        let controller = shared();
+        let mut subscribed = false;
+        let response = dispatch(&controller, "not json", &mut subscribed);
+        match response {
+            FrequencyDaemonResponse::Error { .. } => {}
+            other => panic!("expected Error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_dispatch_subscribe_sets_flag() {
+        let controller = shared();
+        let mut subscribed = false;
+        let request_json = serde_json::to_string(&FrequencyDaemonRequest::Subscribe).unwrap();
+        let response = dispatch(&controller, &request_json, &mut subscribed);
+        assert_eq!(response, FrequencyDaemonResponse::Ok);
+        assert!(subscribed);
+    }
+
+    #[test]
+    fn test_reset_to_stock_zeroes_loaded_cores() {
+        let controller = shared();
+        controller.apply(&FrequencyDaemonRequest::LoadCurve { curve: test_curve(0) });
+        controller.apply(&FrequencyDaemonRequest::LoadCurve { curve: test_curve(1) });
+
+        let resets = controller.reset_to_stock();
+        assert_eq!(resets, vec![(0, 0), (1, 0)]);
+
+        let response = controller.apply(&FrequencyDaemonRequest::GetLastVoltage { core_id: 0 });
+        assert_eq!(
+            response,
+            FrequencyDaemonResponse::LastVoltage { core_id: 0, voltage_mv: Some(0) }
+        );
+    }
+
+    #[test]
+    fn test_request_response_json_round_trip() {
+        let request = FrequencyDaemonRequest::ReadFrequency { core_id: 2 };
+        let json = serde_json::to_string(&request).unwrap();
+        let parsed: FrequencyDaemonRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, request);
+
+        let response = FrequencyDaemonResponse::Sample { core_id: 2, freq_mhz: 1200, applied_mv: Some(-30) };
+        let json = serde_json::to_string(&response).unwrap();
+        let parsed: FrequencyDaemonResponse = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, response);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_over_socket_round_trip() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("freq.sock");
+
+        let controller = shared();
+        let (sample_tx, _rx) = broadcast::channel(16);
+        let server = FrequencyControlServer::bind(&path, controller, sample_tx).unwrap();
+
+        let client = tokio::spawn(UnixStream::connect(path));
+        let (server_stream, _) = server.listener.accept().await.unwrap();
+        let mut client_stream = client.await.unwrap().unwrap();
+        server.spawn_client(server_stream);
+
+        let request = FrequencyDaemonRequest::GetLoadedCores;
+        let mut request_json = serde_json::to_string(&request).unwrap();
+        request_json.push('\n');
+        client_stream.write_all(request_json.as_bytes()).await.unwrap();
+
+        let mut reader = BufReader::new(&mut client_stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).await.unwrap();
+        let response: FrequencyDaemonResponse = serde_json::from_str(line.trim_end()).unwrap();
+        assert_eq!(response, FrequencyDaemonResponse::LoadedCores { core_ids: vec![] });
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_then_receives_broadcast_sample() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("freq_sub.sock");
+
+        let controller = shared();
+        let (sample_tx, _rx) = broadcast::channel(16);
+        let server = FrequencyControlServer::bind(&path, controller, sample_tx.clone()).unwrap();
+
+        let client = tokio::spawn(UnixStream::connect(path));
+        let (server_stream, _) = server.listener.accept().await.unwrap();
+        let mut client_stream = client.await.unwrap().unwrap();
+        server.spawn_client(server_stream);
+
+        let mut request_json = serde_json::to_string(&FrequencyDaemonRequest::Subscribe).unwrap();
+        request_json.push('\n');
+        client_stream.write_all(request_json.as_bytes()).await.unwrap();
+
+        // Drain the Ok reply to Subscribe before the broadcast sample arrives.
+        let mut reader = BufReader::new(&mut client_stream);
+        let mut line = String::new();
+        reader.read_line(&mut line).await.unwrap();
+        assert_eq!(
+            serde_json::from_str::<FrequencyDaemonResponse>(line.trim_end()).unwrap(),
+            FrequencyDaemonResponse::Ok
+        );
+
+        let sample = FrequencyDaemonResponse::Sample { core_id: 0, freq_mhz: 1200, applied_mv: Some(-30) };
+        sample_tx.send(sample.clone()).unwrap();
+
+        line.clear();
+        reader.read_line(&mut line).await.unwrap();
+        assert_eq!(
+            serde_json::from_str::<FrequencyDaemonResponse>(line.trim_end()).unwrap(),
+            sample
+        );
+    }
+
+    #[tokio::test]
+    async fn test_monitoring_loop_resets_on_shutdown_signal() {
+        let controller = shared();
+        controller.apply(&FrequencyDaemonRequest::LoadCurve { curve: test_curve(0) });
+
+        let (sample_tx, mut sample_rx) = broadcast::channel(16);
+        let signal_state = crate::signals::SignalState::new();
+
+        let loop_controller = controller.clone();
+        let loop_signal_state = signal_state.clone();
+        let loop_handle = tokio::spawn(FrequencyControlServer::run_monitoring_loop(
+            loop_controller,
+            sample_tx,
+            Duration::from_secs(3600),
+            loop_signal_state,
+        ));
+
+        signal_state.request_shutdown();
+        loop_handle.await.unwrap();
+
+        let sample = sample_rx.recv().await.unwrap();
+        assert_eq!(sample, FrequencyDaemonResponse::Reset { core_id: 0, offset_mv: 0 });
+        assert_eq!(
+            controller.apply(&FrequencyDaemonRequest::GetLastVoltage { core_id: 0 }),
+            FrequencyDaemonResponse::LastVoltage { core_id: 0, voltage_mv: Some(0) }
+        );
+    }
+}
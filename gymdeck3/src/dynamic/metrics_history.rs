@@ -0,0 +1,284 @@
+//! Rolling per-core history of `CoreMetrics` with min/avg/max aggregates
+//!
+//! Mirrors `crate::load_history::LoadHistory`'s fixed-capacity ring-buffer
+//! approach, but keyed per core and retaining the full `CoreMetrics` sample
+//! (not just load) so load/temperature/frequency trends can all be queried
+//! from one buffer. Also supports an optional retention window in
+//! milliseconds, evicting samples older than the window even if the deque
+//! isn't yet at capacity, which matters for a sampler whose tick rate
+//! varies (e.g. slows down under load).
+
+use std::collections::VecDeque;
+
+use super::CoreMetrics;
+
+/// Default number of samples retained per core
+pub const DEFAULT_METRICS_HISTORY_CAPACITY: usize = 64;
+
+/// Min/average/max over a window of samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricsAggregate {
+    pub min: f32,
+    pub avg: f32,
+    pub max: f32,
+}
+
+/// Fixed-capacity, optionally time-bounded ring buffer of `CoreMetrics` for
+/// a single core
+pub struct CoreMetricsHistory {
+    capacity: usize,
+    retention_ms: Option<u64>,
+    samples: VecDeque<CoreMetrics>,
+}
+
+impl CoreMetricsHistory {
+    /// Create a history window bounded only by sample count
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            retention_ms: None,
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Create a history window bounded by both sample count and age: a
+    /// sample is evicted once it's older than `retention_ms` relative to the
+    /// most recently pushed sample's timestamp, even if under capacity
+    pub fn with_retention_ms(capacity: usize, retention_ms: u64) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            retention_ms: Some(retention_ms),
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Push a new sample, evicting the oldest entry at capacity (and any
+    /// entries older than the retention window, if configured)
+    pub fn push(&mut self, sample: CoreMetrics) {
+        if let Some(retention_ms) = self.retention_ms {
+            let cutoff = sample.timestamp.saturating_sub(retention_ms);
+            while self
+                .samples
+                .front()
+                .map(|s| s.timestamp < cutoff)
+                .unwrap_or(false)
+            {
+                self.samples.pop_front();
+            }
+        }
+
+        if self.samples.len() >= self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// Configured sample-count capacity
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of samples currently retained
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Whether the history is empty
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Iterate retained samples, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &CoreMetrics> {
+        self.samples.iter()
+    }
+
+    /// Most recently pushed sample
+    pub fn latest(&self) -> Option<&CoreMetrics> {
+        self.samples.back()
+    }
+
+    /// Min/avg/max load over the retained window
+    pub fn load_aggregate(&self) -> Option<MetricsAggregate> {
+        Self::aggregate(self.samples.iter().map(|s| s.load))
+    }
+
+    /// Min/avg/max temperature over the retained window
+    pub fn temperature_aggregate(&self) -> Option<MetricsAggregate> {
+        Self::aggregate(self.samples.iter().map(|s| s.temperature))
+    }
+
+    /// Min/avg/max frequency (MHz) over the retained window
+    pub fn frequency_aggregate(&self) -> Option<MetricsAggregate> {
+        Self::aggregate(self.samples.iter().map(|s| s.frequency as f32))
+    }
+
+    fn aggregate(values: impl Iterator<Item = f32>) -> Option<MetricsAggregate> {
+        let mut values = values.peekable();
+        let first = *values.peek()?;
+        let (min, max, sum, count) = values.fold((first, first, 0.0, 0usize), |(min, max, sum, count), v| {
+            (min.min(v), max.max(v), sum + v, count + 1)
+        });
+        Some(MetricsAggregate {
+            min,
+            max,
+            avg: sum / count as f32,
+        })
+    }
+}
+
+impl Default for CoreMetricsHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_METRICS_HISTORY_CAPACITY)
+    }
+}
+
+/// Per-core collection of [`CoreMetricsHistory`] ring buffers, growing to fit
+/// whichever `core_id` is pushed (mirrors `LoadHistory`'s per-core growth)
+pub struct MetricsHistory {
+    capacity: usize,
+    retention_ms: Option<u64>,
+    per_core: Vec<CoreMetricsHistory>,
+}
+
+impl MetricsHistory {
+    /// Create a per-core history bank bounded only by sample count
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            retention_ms: None,
+            per_core: Vec::new(),
+        }
+    }
+
+    /// Create a per-core history bank bounded by both sample count and age
+    pub fn with_retention_ms(capacity: usize, retention_ms: u64) -> Self {
+        Self {
+            capacity,
+            retention_ms: Some(retention_ms),
+            per_core: Vec::new(),
+        }
+    }
+
+    /// Record a sample into its core's history, growing the bank if this is
+    /// the highest `core_id` seen so far
+    pub fn record(&mut self, sample: CoreMetrics) {
+        if self.per_core.len() <= sample.core_id {
+            self.per_core.resize_with(sample.core_id + 1, || match self.retention_ms {
+                Some(retention_ms) => CoreMetricsHistory::with_retention_ms(self.capacity, retention_ms),
+                None => CoreMetricsHistory::new(self.capacity),
+            });
+        }
+        self.per_core[sample.core_id].push(sample);
+    }
+
+    /// History for a given core, if any samples have been recorded for it
+    pub fn core(&self, core_id: usize) -> Option<&CoreMetricsHistory> {
+        self.per_core.get(core_id)
+    }
+
+    /// Number of cores currently tracked
+    pub fn core_count(&self) -> usize {
+        self.per_core.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(core_id: usize, load: f32, temperature: f32, frequency: u32, timestamp: u64) -> CoreMetrics {
+        CoreMetrics {
+            core_id,
+            load,
+            voltage: 0,
+            frequency,
+            temperature,
+            timestamp,
+            user_percent: 0.0,
+            system_percent: 0.0,
+            iowait_percent: 0.0,
+            steal_percent: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_push_and_aggregate_load() {
+        let mut history = CoreMetricsHistory::new(4);
+        history.push(metrics(0, 10.0, 40.0, 2000, 0));
+        history.push(metrics(0, 30.0, 60.0, 3000, 10));
+
+        let agg = history.load_aggregate().unwrap();
+        assert_eq!(agg.min, 10.0);
+        assert_eq!(agg.max, 30.0);
+        assert_eq!(agg.avg, 20.0);
+    }
+
+    #[test]
+    fn test_evicts_oldest_at_capacity() {
+        let mut history = CoreMetricsHistory::new(2);
+        history.push(metrics(0, 0.0, 0.0, 0, 0));
+        history.push(metrics(0, 100.0, 0.0, 0, 10));
+        history.push(metrics(0, 50.0, 0.0, 0, 20));
+
+        // First sample (load 0.0) should have been evicted
+        let agg = history.load_aggregate().unwrap();
+        assert_eq!(agg.min, 50.0);
+        assert_eq!(agg.max, 100.0);
+        assert_eq!(history.len(), 2);
+    }
+
+    #[test]
+    fn test_retention_window_evicts_stale_samples_under_capacity() {
+        let mut history = CoreMetricsHistory::with_retention_ms(10, 100);
+        history.push(metrics(0, 10.0, 0.0, 0, 0));
+        history.push(metrics(0, 20.0, 0.0, 0, 50));
+        // This sample is 150ms after the first, outside the 100ms window
+        history.push(metrics(0, 30.0, 0.0, 0, 150));
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.latest().unwrap().load, 30.0);
+    }
+
+    #[test]
+    fn test_empty_history_has_no_aggregate() {
+        let history = CoreMetricsHistory::new(4);
+        assert!(history.load_aggregate().is_none());
+        assert!(history.temperature_aggregate().is_none());
+        assert!(history.frequency_aggregate().is_none());
+    }
+
+    #[test]
+    fn test_temperature_and_frequency_aggregates() {
+        let mut history = CoreMetricsHistory::new(4);
+        history.push(metrics(0, 0.0, 40.0, 1600, 0));
+        history.push(metrics(0, 0.0, 70.0, 3200, 10));
+
+        let temp = history.temperature_aggregate().unwrap();
+        assert_eq!(temp.min, 40.0);
+        assert_eq!(temp.max, 70.0);
+        assert_eq!(temp.avg, 55.0);
+
+        let freq = history.frequency_aggregate().unwrap();
+        assert_eq!(freq.min, 1600.0);
+        assert_eq!(freq.max, 3200.0);
+    }
+
+    #[test]
+    fn test_metrics_history_grows_per_core() {
+        let mut bank = MetricsHistory::new(4);
+        bank.record(metrics(0, 10.0, 0.0, 0, 0));
+        bank.record(metrics(2, 20.0, 0.0, 0, 0));
+
+        assert_eq!(bank.core_count(), 3);
+        assert_eq!(bank.core(0).unwrap().latest().unwrap().load, 10.0);
+        assert_eq!(bank.core(2).unwrap().latest().unwrap().load, 20.0);
+        assert!(bank.core(1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_metrics_history_unknown_core_is_none() {
+        let bank = MetricsHistory::new(4);
+        assert!(bank.core(0).is_none());
+    }
+}
@@ -0,0 +1,1442 @@
+//! Metrics monitor for per-core CPU metrics
+//!
+//! Provides real-time monitoring of CPU load, voltage, frequency, and temperature
+//! for individual cores.
+//!
+//! Requirements: 5.5, 9.5
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Path to cgroup v2's unified CPU quota file
+const CGROUP_V2_CPU_MAX_PATH: &str = "/sys/fs/cgroup/cpu.max";
+/// Path to cgroup v1's CPU bandwidth quota, in microseconds per period
+/// (`-1` means unlimited)
+const CGROUP_V1_QUOTA_PATH: &str = "/sys/fs/cgroup/cpu/cpu.cfs_quota_us";
+/// Path to cgroup v1's CPU bandwidth period, in microseconds
+const CGROUP_V1_PERIOD_PATH: &str = "/sys/fs/cgroup/cpu/cpu.cfs_period_us";
+/// Path to the kernel's CPU topology listing, used to derive physical core
+/// count from `physical id`/`core id` pairs
+const PROC_CPUINFO_PATH: &str = "/proc/cpuinfo";
+
+/// Metrics for a single CPU core
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoreMetrics {
+    /// Core ID (0-based index)
+    pub core_id: usize,
+    /// CPU load percentage (0.0-100.0): everything but idle and iowait
+    pub load: f32,
+    /// Current voltage offset in mV
+    pub voltage: i32,
+    /// Current frequency in MHz
+    pub frequency: u32,
+    /// Current temperature in Celsius
+    pub temperature: f32,
+    /// Unix timestamp in milliseconds
+    pub timestamp: u64,
+    /// Percentage of the delta window spent in user mode (guest time
+    /// excluded, since `/proc/stat` already folds it into `user`)
+    pub user_percent: f32,
+    /// Percentage of the delta window spent in kernel mode
+    pub system_percent: f32,
+    /// Percentage of the delta window spent waiting on I/O; distinct from
+    /// `load` because a blocked-on-disk core isn't actually busy
+    pub iowait_percent: f32,
+    /// Percentage of the delta window stolen by the hypervisor; relevant
+    /// when profiling a Deck under emulation/virtualization rather than
+    /// bare metal
+    pub steal_percent: f32,
+}
+
+/// Errors from metrics monitor operations
+#[derive(Debug)]
+pub enum MetricsError {
+    /// Invalid core ID
+    InvalidCoreId(usize),
+    /// I/O error reading from sysfs
+    IoError(std::io::Error),
+    /// Parse error reading sysfs values
+    ParseError(String),
+    /// Required sysfs file not found
+    FileNotFound(String),
+}
+
+impl std::fmt::Display for MetricsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetricsError::InvalidCoreId(id) => {
+                write!(f, "Invalid core ID: {}", id)
+            }
+            MetricsError::IoError(e) => {
+                write!(f, "I/O error: {}", e)
+            }
+            MetricsError::ParseError(msg) => {
+                write!(f, "Parse error: {}", msg)
+            }
+            MetricsError::FileNotFound(path) => {
+                write!(f, "File not found: {}", path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MetricsError {}
+
+impl From<std::io::Error> for MetricsError {
+    fn from(e: std::io::Error) -> Self {
+        MetricsError::IoError(e)
+    }
+}
+
+/// Abstraction over where [`MetricsMonitor`] reads raw sysfs/procfs content
+/// from. [`SysfsMetricsSource`] backs every production monitor; tests
+/// substitute a mock that returns canned strings or I/O errors, so
+/// counter-wrap, missing-file, and malformed-line edge cases can be
+/// exercised deterministically without creating temp directories.
+pub trait MetricsSource: std::fmt::Debug {
+    /// Read the entire contents of `/proc/stat`.
+    fn read_proc_stat(&self) -> std::io::Result<String>;
+
+    /// Read a `cpufreq` attribute file for one core (e.g. `attr =
+    /// "scaling_cur_freq"` or `"voltage_offset"`).
+    fn read_cpufreq(&self, core_id: usize, attr: &str) -> std::io::Result<String>;
+
+    /// Read an arbitrary hwmon file resolved by the caller: a chip's
+    /// `name`, a sensor's `tempX_label`, or its `tempX_input`.
+    fn read_hwmon(&self, path: &Path) -> std::io::Result<String>;
+}
+
+/// Real [`MetricsSource`] backed by sysfs/procfs, used by every production
+/// `MetricsMonitor`.
+#[derive(Debug, Clone)]
+struct SysfsMetricsSource {
+    sysfs_base: PathBuf,
+    proc_stat_path: PathBuf,
+}
+
+impl MetricsSource for SysfsMetricsSource {
+    fn read_proc_stat(&self) -> std::io::Result<String> {
+        fs::read_to_string(&self.proc_stat_path)
+    }
+
+    fn read_cpufreq(&self, core_id: usize, attr: &str) -> std::io::Result<String> {
+        fs::read_to_string(
+            self.sysfs_base
+                .join(format!("cpu{}", core_id))
+                .join("cpufreq")
+                .join(attr),
+        )
+    }
+
+    fn read_hwmon(&self, path: &Path) -> std::io::Result<String> {
+        fs::read_to_string(path)
+    }
+}
+
+/// Metrics monitor for CPU cores
+///
+/// Polls CPU metrics from sysfs and /proc interfaces.
+pub struct MetricsMonitor {
+    /// Number of CPU cores
+    num_cores: usize,
+    /// Where raw sysfs/procfs content is read from; sysfs and /proc/stat
+    /// paths are baked into this source rather than kept alongside it
+    source: Box<dyn MetricsSource>,
+    /// Path to hwmon for temperature (for testing); chip/sensor discovery
+    /// still walks this directly since enumerating a directory isn't part
+    /// of [`MetricsSource`]
+    hwmon_path: PathBuf,
+    /// Previous CPU stats for load calculation
+    prev_stats: Vec<CpuTimeStats>,
+    /// Restrict hwmon discovery to a chip whose `name` file matches exactly
+    /// (e.g. `"k10temp"`); `None` scans every chip under `hwmon_path`
+    chip_name_filter: Option<String>,
+    /// Explicit core-id to hwmon sensor-label mapping (e.g. `0 -> "Tccd1"`),
+    /// consulted before the automatic `Tccd{core_id+1}`/`Tctl`/`Tdie` guesses
+    core_labels: HashMap<usize, String>,
+    /// Hwmon discovery result, populated lazily on first temperature read
+    /// and reused afterward so steady-state polling is one read per sensor
+    /// instead of re-walking `hwmon_path` every tick
+    hwmon_cache: RefCell<Option<HwmonDiscovery>>,
+    /// Host CPU topology, auto-detected by [`Self::new_auto`] or left at the
+    /// `num_cores`-derived default for the explicit constructors
+    topology: CoreTopology,
+}
+
+/// Effective CPU core budget detected on the host: logical thread count,
+/// physical core count (SMT siblings collapsed to one), and any cgroup CPU
+/// quota that further restricts how many cores this process may actually
+/// use. Used to size [`MetricsMonitor`] correctly under containers/cgroups
+/// rather than trusting a caller-supplied core count that may be wrong.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoreTopology {
+    /// Online logical CPUs (hyperthreads/SMT siblings counted separately)
+    pub logical_cores: usize,
+    /// Distinct physical cores, with SMT siblings collapsed
+    pub physical_cores: usize,
+    /// Cores allowed by a cgroup CPU quota, if one is in effect (may be
+    /// fractional, e.g. `2.5` for a `250000/100000` v1 quota/period)
+    pub cgroup_limit: Option<f32>,
+}
+
+/// Online logical CPU count via `sched_getaffinity` (respects the process's
+/// CPU affinity mask, which `_SC_NPROCESSORS_ONLN` ignores), falling back to
+/// `_SC_NPROCESSORS_ONLN` if the affinity call fails. Always returns at
+/// least 1.
+#[cfg(unix)]
+fn detect_logical_core_count() -> usize {
+    // SAFETY: `set` is a correctly-sized, zero-initialized cpu_set_t, and
+    // `sched_getaffinity(0, ...)` queries the calling process, both valid
+    // preconditions for these calls.
+    unsafe {
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        if libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set) == 0 {
+            let count = libc::CPU_COUNT(&set) as usize;
+            if count > 0 {
+                return count;
+            }
+        }
+    }
+
+    // SAFETY: sysconf() with a valid name constant is always safe to call.
+    let online = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_ONLN) };
+    if online > 0 {
+        online as usize
+    } else {
+        1
+    }
+}
+
+#[cfg(not(unix))]
+fn detect_logical_core_count() -> usize {
+    1
+}
+
+/// Count distinct physical cores from `/proc/cpuinfo` contents by collapsing
+/// SMT siblings that share both a `physical id` and `core id`. Falls back to
+/// the number of `processor` entries when those fields are absent (e.g.
+/// inside some containers, or kernels that don't report them).
+fn physical_core_count_from_cpuinfo(contents: &str) -> usize {
+    let mut pairs: Vec<(i64, i64)> = Vec::new();
+    let mut processor_count = 0usize;
+    let mut physical_id: Option<i64> = None;
+    let mut core_id: Option<i64> = None;
+
+    for line in contents.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        match key.trim() {
+            "processor" => processor_count += 1,
+            "physical id" => physical_id = value.trim().parse().ok(),
+            "core id" => {
+                core_id = value.trim().parse().ok();
+                if let (Some(p), Some(c)) = (physical_id, core_id) {
+                    let pair = (p, c);
+                    if !pairs.contains(&pair) {
+                        pairs.push(pair);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if pairs.is_empty() {
+        processor_count.max(1)
+    } else {
+        pairs.len()
+    }
+}
+
+/// Physical core count for the running host (see
+/// [`physical_core_count_from_cpuinfo`]), reading the real `/proc/cpuinfo`.
+fn detect_physical_core_count() -> usize {
+    fs::read_to_string(PROC_CPUINFO_PATH)
+        .map(|contents| physical_core_count_from_cpuinfo(&contents))
+        .unwrap_or(1)
+}
+
+/// Effective CPU quota in whole cores, trying cgroup v2's unified `cpu.max`
+/// (`"$MAX $PERIOD"`, or `"max"` for unlimited) first, then falling back to
+/// cgroup v1's `cpu.cfs_quota_us`/`cpu.cfs_period_us` pair (a quota of `-1`
+/// means unlimited). Returns `None` when no limit is in effect, the files
+/// are absent, or they're malformed.
+fn cgroup_core_limit_at(v2_path: &Path, v1_quota_path: &Path, v1_period_path: &Path) -> Option<f32> {
+    if let Ok(contents) = fs::read_to_string(v2_path) {
+        let mut parts = contents.split_whitespace();
+        let max = parts.next()?;
+        let period: f32 = parts.next()?.parse().ok()?;
+        if max == "max" || period <= 0.0 {
+            return None;
+        }
+        let quota: f32 = max.parse().ok()?;
+        return Some(quota / period);
+    }
+
+    let quota: i64 = fs::read_to_string(v1_quota_path).ok()?.trim().parse().ok()?;
+    if quota < 0 {
+        return None;
+    }
+    let period: i64 = fs::read_to_string(v1_period_path).ok()?.trim().parse().ok()?;
+    if period <= 0 {
+        return None;
+    }
+    Some(quota as f32 / period as f32)
+}
+
+/// Effective CPU quota for the running host's cgroup, if any (see
+/// [`cgroup_core_limit_at`]).
+fn detect_cgroup_core_limit() -> Option<f32> {
+    cgroup_core_limit_at(
+        Path::new(CGROUP_V2_CPU_MAX_PATH),
+        Path::new(CGROUP_V1_QUOTA_PATH),
+        Path::new(CGROUP_V1_PERIOD_PATH),
+    )
+}
+
+/// Result of scanning `hwmon_path` for temperature sensors
+#[derive(Debug, Clone, Default)]
+struct HwmonDiscovery {
+    /// Sensor label (from `tempX_label`) to its `tempX_input` path
+    by_label: HashMap<String, PathBuf>,
+    /// Every `tempX_input` path found, in scan order, for positional
+    /// fallback when a chip exposes no labels at all
+    all_temps: Vec<PathBuf>,
+}
+
+/// CPU time statistics for load calculation
+///
+/// Mirrors the `/proc/stat` `cpuN` line: `user nice system idle iowait irq
+/// softirq steal guest guest_nice`. Kept as individual fields (rather than
+/// pre-summed totals) so percentage breakdowns by state can be computed
+/// from the same deltas as overall load.
+#[derive(Debug, Clone, Copy, Default)]
+struct CpuTimeStats {
+    user: u64,
+    nice: u64,
+    system: u64,
+    idle: u64,
+    iowait: u64,
+    irq: u64,
+    softirq: u64,
+    steal: u64,
+    guest: u64,
+    guest_nice: u64,
+}
+
+impl CpuTimeStats {
+    /// Parse a `cpuN`'s whitespace-separated values (after the `cpuN` label
+    /// has already been skipped). Trailing fields are optional: older
+    /// kernels may report fewer than 10 columns, so anything missing
+    /// defaults to 0.
+    fn parse(values: &[u64]) -> Self {
+        let get = |i: usize| values.get(i).copied().unwrap_or(0);
+        CpuTimeStats {
+            user: get(0),
+            nice: get(1),
+            system: get(2),
+            idle: get(3),
+            iowait: get(4),
+            irq: get(5),
+            softirq: get(6),
+            steal: get(7),
+            guest: get(8),
+            guest_nice: get(9),
+        }
+    }
+
+    /// `user` time with guest time subtracted back out, since `/proc/stat`
+    /// already counts guest time within `user`
+    fn user_excl_guest(&self) -> u64 {
+        self.user.saturating_sub(self.guest)
+    }
+
+    /// `nice` time with guest_nice time subtracted back out, for the same
+    /// reason as [`Self::user_excl_guest`]
+    fn nice_excl_guest(&self) -> u64 {
+        self.nice.saturating_sub(self.guest_nice)
+    }
+
+    /// Time considered "not busy": `idle + iowait`. Treating iowait as idle
+    /// matches the conventional `/proc/stat` load calculation and avoids
+    /// counting a core blocked on disk as doing work.
+    fn idle_total(&self) -> u64 {
+        self.idle + self.iowait
+    }
+
+    /// Time considered "busy", with guest time already folded out of
+    /// `user`/`nice` so it isn't counted twice
+    fn non_idle_total(&self) -> u64 {
+        self.user_excl_guest()
+            + self.nice_excl_guest()
+            + self.system
+            + self.irq
+            + self.softirq
+            + self.steal
+    }
+
+    /// Total accounted time: `idle_total + non_idle_total`
+    fn total(&self) -> u64 {
+        self.idle_total() + self.non_idle_total()
+    }
+}
+
+/// Overall load plus a per-state percentage breakdown over one delta window
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct LoadBreakdown {
+    load: f32,
+    user_percent: f32,
+    system_percent: f32,
+    iowait_percent: f32,
+    steal_percent: f32,
+}
+
+impl LoadBreakdown {
+    /// Compute the breakdown between two `/proc/stat` samples. Returns all
+    /// zeros for the first sample of a core (`prev` still at its `Default`)
+    /// since there's no delta window to measure yet.
+    fn from_delta(prev: &CpuTimeStats, cur: &CpuTimeStats) -> Self {
+        if prev.total() == 0 {
+            return LoadBreakdown::default();
+        }
+
+        let total_delta = cur.total().saturating_sub(prev.total());
+        if total_delta == 0 {
+            return LoadBreakdown::default();
+        }
+
+        let pct = |delta: u64| (delta as f32 / total_delta as f32 * 100.0).clamp(0.0, 100.0);
+
+        let non_idle_delta = cur.non_idle_total().saturating_sub(prev.non_idle_total());
+        let user_delta = cur.user_excl_guest().saturating_sub(prev.user_excl_guest());
+        let system_delta = cur.system.saturating_sub(prev.system);
+        let iowait_delta = cur.iowait.saturating_sub(prev.iowait);
+        let steal_delta = cur.steal.saturating_sub(prev.steal);
+
+        LoadBreakdown {
+            load: pct(non_idle_delta),
+            user_percent: pct(user_delta),
+            system_percent: pct(system_delta),
+            iowait_percent: pct(iowait_delta),
+            steal_percent: pct(steal_delta),
+        }
+    }
+}
+
+impl MetricsMonitor {
+    /// Create a new MetricsMonitor
+    ///
+    /// # Arguments
+    /// * `num_cores` - Number of CPU cores to monitor
+    pub fn new(num_cores: usize) -> Self {
+        Self::with_source(
+            num_cores,
+            Box::new(SysfsMetricsSource {
+                sysfs_base: PathBuf::from("/sys/devices/system/cpu"),
+                proc_stat_path: PathBuf::from("/proc/stat"),
+            }),
+            PathBuf::from("/sys/class/hwmon"),
+        )
+    }
+
+    /// Create a MetricsMonitor sized to the host's online logical CPUs,
+    /// auto-detected via `sched_getaffinity`/`_SC_NPROCESSORS_ONLN` instead
+    /// of requiring the caller to pass a (possibly wrong) core count.
+    /// Physical core count and any cgroup CPU quota are detected alongside
+    /// and exposed via [`Self::physical_core_count`]/
+    /// [`Self::cgroup_core_limit`], so callers can map SMT siblings to their
+    /// physical core, or respect a tighter quota, when applying per-core
+    /// undervolts.
+    pub fn new_auto() -> Self {
+        let logical_cores = detect_logical_core_count();
+        let mut monitor = Self::new(logical_cores);
+        monitor.topology = CoreTopology {
+            logical_cores,
+            physical_cores: detect_physical_core_count(),
+            cgroup_limit: detect_cgroup_core_limit(),
+        };
+        monitor
+    }
+
+    /// Online logical CPUs this monitor was sized for
+    pub fn logical_core_count(&self) -> usize {
+        self.topology.logical_cores
+    }
+
+    /// Physical core count detected alongside the logical count (only
+    /// meaningful when constructed via [`Self::new_auto`]; otherwise equal
+    /// to `num_cores`)
+    pub fn physical_core_count(&self) -> usize {
+        self.topology.physical_cores
+    }
+
+    /// Cgroup CPU quota in whole cores, if one was detected and is tighter
+    /// than the logical core count
+    pub fn cgroup_core_limit(&self) -> Option<f32> {
+        self.topology.cgroup_limit
+    }
+
+    /// Create a MetricsMonitor with custom paths (for testing)
+    ///
+    /// # Arguments
+    /// * `num_cores` - Number of CPU cores
+    /// * `sysfs_base` - Base path for CPU sysfs
+    /// * `proc_stat_path` - Path to /proc/stat
+    /// * `hwmon_path` - Path to hwmon directory
+    pub fn with_paths(
+        num_cores: usize,
+        sysfs_base: PathBuf,
+        proc_stat_path: PathBuf,
+        hwmon_path: PathBuf,
+    ) -> Self {
+        Self::with_source(
+            num_cores,
+            Box::new(SysfsMetricsSource {
+                sysfs_base,
+                proc_stat_path,
+            }),
+            hwmon_path,
+        )
+    }
+
+    /// Create a MetricsMonitor backed by an arbitrary [`MetricsSource`]
+    /// (for testing counter-wrap, missing-file, and malformed-line parsing
+    /// without touching the filesystem at all). `hwmon_path` is still a
+    /// real directory: chip/sensor discovery enumerates it directly, and
+    /// only the resulting file reads go through `source`.
+    pub fn with_source(num_cores: usize, source: Box<dyn MetricsSource>, hwmon_path: PathBuf) -> Self {
+        Self {
+            num_cores,
+            source,
+            hwmon_path,
+            prev_stats: vec![CpuTimeStats::default(); num_cores],
+            chip_name_filter: None,
+            core_labels: HashMap::new(),
+            hwmon_cache: RefCell::new(None),
+            topology: CoreTopology {
+                logical_cores: num_cores,
+                physical_cores: num_cores,
+                cgroup_limit: None,
+            },
+        }
+    }
+
+    /// Restrict hwmon temperature discovery to a chip whose `name` file
+    /// matches `chip_name` exactly (e.g. `"k10temp"` on the Deck's AMD APU),
+    /// instead of scanning every chip under `hwmon_path`. Invalidates the
+    /// discovery cache so the next temperature read re-scans with the new
+    /// filter applied.
+    pub fn set_chip_name_filter(&mut self, chip_name: impl Into<String>) {
+        self.chip_name_filter = Some(chip_name.into());
+        self.hwmon_cache = RefCell::new(None);
+    }
+
+    /// Map `core_id` to the hwmon sensor label that should be read for its
+    /// temperature (e.g. `monitor.set_core_label(0, "Tccd1")`), consulted
+    /// before the automatic label guesses. Invalidates the discovery cache.
+    pub fn set_core_label(&mut self, core_id: usize, label: impl Into<String>) {
+        self.core_labels.insert(core_id, label.into());
+        self.hwmon_cache = RefCell::new(None);
+    }
+
+    /// Get metrics for a specific core
+    ///
+    /// # Arguments
+    /// * `core_id` - Core identifier (0-based)
+    ///
+    /// # Returns
+    /// * `Ok(CoreMetrics)` if successful
+    /// * `Err(MetricsError)` if error occurs
+    pub fn get_core_metrics(&mut self, core_id: usize) -> Result<CoreMetrics, MetricsError> {
+        if core_id >= self.num_cores {
+            return Err(MetricsError::InvalidCoreId(core_id));
+        }
+
+        let breakdown = self.compute_load_breakdown(core_id)?;
+        let voltage = self.get_voltage(core_id)?;
+        let frequency = self.get_frequency(core_id)?;
+        let temperature = self.get_temperature(core_id)?;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+
+        Ok(CoreMetrics {
+            core_id,
+            load: breakdown.load,
+            voltage,
+            frequency,
+            temperature,
+            timestamp,
+            user_percent: breakdown.user_percent,
+            system_percent: breakdown.system_percent,
+            iowait_percent: breakdown.iowait_percent,
+            steal_percent: breakdown.steal_percent,
+        })
+    }
+
+    /// Get CPU load for a specific core
+    ///
+    /// Reads from /proc/stat and calculates load percentage based on
+    /// the difference from the previous sample.
+    ///
+    /// # Arguments
+    /// * `core_id` - Core identifier
+    ///
+    /// # Returns
+    /// * `Ok(f32)` - Load percentage (0.0-100.0)
+    /// * `Err(MetricsError)` if error occurs
+    pub fn get_cpu_load(&mut self, core_id: usize) -> Result<f32, MetricsError> {
+        Ok(self.compute_load_breakdown(core_id)?.load)
+    }
+
+    /// Read and parse `/proc/stat`'s `cpuN` line, update `prev_stats`, and
+    /// return the full load breakdown for the delta window since the last
+    /// call. Shared by `get_cpu_load` (which only needs the headline number)
+    /// and `get_core_metrics` (which also wants the per-state percentages),
+    /// so both see exactly the same parse and the same previous-sample
+    /// bookkeeping.
+    fn compute_load_breakdown(&mut self, core_id: usize) -> Result<LoadBreakdown, MetricsError> {
+        if core_id >= self.num_cores {
+            return Err(MetricsError::InvalidCoreId(core_id));
+        }
+
+        let content = self.source.read_proc_stat()?;
+        let stats = Self::parse_core_stats(&content, core_id)?;
+        let prev = self.prev_stats[core_id];
+        let breakdown = LoadBreakdown::from_delta(&prev, &stats);
+
+        self.prev_stats[core_id] = stats;
+
+        Ok(breakdown)
+    }
+
+    /// Find and parse a single `cpuN` line out of an already-read `/proc/stat`
+    /// snapshot, without touching the filesystem. Factored out of
+    /// `compute_load_breakdown` so `sample_all` can reuse the same parsing
+    /// against one shared read instead of re-reading the file per core.
+    fn parse_core_stats(content: &str, core_id: usize) -> Result<CpuTimeStats, MetricsError> {
+        // Find the line for this core (cpu0, cpu1, etc.)
+        let cpu_line = format!("cpu{} ", core_id);
+        let line = content
+            .lines()
+            .find(|l| l.starts_with(&cpu_line))
+            .ok_or_else(|| {
+                MetricsError::ParseError(format!("Core {} not found in /proc/stat", core_id))
+            })?;
+
+        // Parse CPU time values
+        // Format: cpu0 user nice system idle iowait irq softirq steal guest guest_nice
+        let values: Vec<u64> = line
+            .split_whitespace()
+            .skip(1) // Skip "cpu0"
+            .filter_map(|s| s.parse().ok())
+            .collect();
+
+        if values.len() < 4 {
+            return Err(MetricsError::ParseError(format!(
+                "Invalid /proc/stat format for core {}",
+                core_id
+            )));
+        }
+
+        Ok(CpuTimeStats::parse(&values))
+    }
+
+    /// Snapshot every monitored core in one pass: `/proc/stat` is read
+    /// exactly once (instead of once per core, as repeatedly calling
+    /// `get_core_metrics` would do) and every `cpuN` line is parsed from that
+    /// single snapshot, so all cores' load deltas share an identical time
+    /// window and are directly comparable to each other.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<CoreMetrics>)` - one entry per core, in core-id order
+    /// * `Err(MetricsError)` if `/proc/stat` can't be read or a core's line
+    ///   is missing or malformed
+    pub fn sample_all(&mut self) -> Result<Vec<CoreMetrics>, MetricsError> {
+        let content = self.source.read_proc_stat()?;
+
+        let mut breakdowns = Vec::with_capacity(self.num_cores);
+        for core_id in 0..self.num_cores {
+            let stats = Self::parse_core_stats(&content, core_id)?;
+            let prev = self.prev_stats[core_id];
+            breakdowns.push(LoadBreakdown::from_delta(&prev, &stats));
+            self.prev_stats[core_id] = stats;
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+
+        breakdowns
+            .into_iter()
+            .enumerate()
+            .map(|(core_id, breakdown)| {
+                Ok(CoreMetrics {
+                    core_id,
+                    load: breakdown.load,
+                    voltage: self.get_voltage(core_id)?,
+                    frequency: self.get_frequency(core_id)?,
+                    temperature: self.get_temperature(core_id)?,
+                    timestamp,
+                    user_percent: breakdown.user_percent,
+                    system_percent: breakdown.system_percent,
+                    iowait_percent: breakdown.iowait_percent,
+                    steal_percent: breakdown.steal_percent,
+                })
+            })
+            .collect()
+    }
+
+
+    /// Get current voltage offset for a core
+    ///
+    /// Reads from sysfs voltage interface.
+    ///
+    /// # Arguments
+    /// * `core_id` - Core identifier
+    ///
+    /// # Returns
+    /// * `Ok(i32)` - Voltage offset in mV
+    /// * `Err(MetricsError)` if error occurs
+    pub fn get_voltage(&self, core_id: usize) -> Result<i32, MetricsError> {
+        if core_id >= self.num_cores {
+            return Err(MetricsError::InvalidCoreId(core_id));
+        }
+        
+        // Path: /sys/devices/system/cpu/cpu{N}/cpufreq/voltage_offset
+        match self.source.read_cpufreq(core_id, "voltage_offset") {
+            Ok(content) => content.trim().parse().map_err(|_| {
+                MetricsError::ParseError(format!("Invalid voltage value: {}", content.trim()))
+            }),
+            // If voltage file doesn't exist, return 0 (no offset)
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+            Err(e) => Err(MetricsError::IoError(e)),
+        }
+    }
+    
+    /// Get current frequency for a core
+    ///
+    /// Reads from sysfs cpufreq interface.
+    ///
+    /// # Arguments
+    /// * `core_id` - Core identifier
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - Frequency in MHz
+    /// * `Err(MetricsError)` if error occurs
+    pub fn get_frequency(&self, core_id: usize) -> Result<u32, MetricsError> {
+        if core_id >= self.num_cores {
+            return Err(MetricsError::InvalidCoreId(core_id));
+        }
+        
+        // Path: /sys/devices/system/cpu/cpu{N}/cpufreq/scaling_cur_freq
+        match self.source.read_cpufreq(core_id, "scaling_cur_freq") {
+            Ok(content) => {
+                let freq_khz: u32 = content.trim().parse().map_err(|_| {
+                    MetricsError::ParseError(format!("Invalid frequency value: {}", content.trim()))
+                })?;
+                // Convert kHz to MHz
+                Ok(freq_khz / 1000)
+            }
+            // If frequency file doesn't exist, return 0
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(0),
+            Err(e) => Err(MetricsError::IoError(e)),
+        }
+    }
+    
+    /// Get current temperature for a core
+    ///
+    /// Resolves which hwmon sensor backs `core_id` via label-driven
+    /// discovery rather than assuming `hwmon0`/`temp{core_id+2}_input`,
+    /// which only happens to be right on some kernels. Tries, in order:
+    /// an explicit [`Self::set_core_label`] mapping, the conventional
+    /// per-CCD `Tccd{core_id+1}` label, the package sensor (`Tctl`/`Tdie`),
+    /// and finally a positional fallback for chips that expose no labels
+    /// at all.
+    ///
+    /// # Arguments
+    /// * `core_id` - Core identifier
+    ///
+    /// # Returns
+    /// * `Ok(f32)` - Temperature in Celsius
+    /// * `Err(MetricsError)` if error occurs
+    pub fn get_temperature(&self, core_id: usize) -> Result<f32, MetricsError> {
+        if core_id >= self.num_cores {
+            return Err(MetricsError::InvalidCoreId(core_id));
+        }
+
+        if self.hwmon_cache.borrow().is_none() {
+            *self.hwmon_cache.borrow_mut() = Some(self.discover_hwmon_sensors());
+        }
+        let cache = self.hwmon_cache.borrow();
+        let discovery = cache.as_ref().expect("just populated above");
+
+        let ccd_label = format!("Tccd{}", core_id + 1);
+        let path = self
+            .core_labels
+            .get(&core_id)
+            .and_then(|label| discovery.by_label.get(label))
+            .or_else(|| discovery.by_label.get(&ccd_label))
+            .or_else(|| discovery.by_label.get("Tctl"))
+            .or_else(|| discovery.by_label.get("Tdie"))
+            .or_else(|| discovery.all_temps.get(core_id + 2))
+            .or_else(|| discovery.all_temps.first());
+
+        let Some(path) = path else {
+            return Ok(0.0);
+        };
+
+        let content = self.source.read_hwmon(path)?;
+        let temp_millidegrees: i32 = content.trim().parse().map_err(|_| {
+            MetricsError::ParseError(format!("Invalid temperature value: {}", content.trim()))
+        })?;
+
+        Ok(temp_millidegrees as f32 / 1000.0)
+    }
+
+    /// Scan `hwmon_path` for every `hwmon*` chip, optionally restricted by
+    /// [`Self::set_chip_name_filter`], and build a label -> `tempX_input`
+    /// path map plus a positional fallback list. Chips are visited in
+    /// directory-listing order; within a chip, sensors are visited in
+    /// `tempX_input` numeric order.
+    fn discover_hwmon_sensors(&self) -> HwmonDiscovery {
+        let mut discovery = HwmonDiscovery::default();
+
+        let Ok(hwmon_dirs) = fs::read_dir(&self.hwmon_path) else {
+            return discovery;
+        };
+
+        let mut chip_dirs: Vec<PathBuf> = hwmon_dirs
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect();
+        chip_dirs.sort();
+
+        for chip_dir in chip_dirs {
+            if let Some(filter) = &self.chip_name_filter {
+                let name = self.source.read_hwmon(&chip_dir.join("name")).unwrap_or_default();
+                if name.trim() != filter {
+                    continue;
+                }
+            }
+
+            let Ok(entries) = fs::read_dir(&chip_dir) else {
+                continue;
+            };
+
+            let mut temp_inputs: Vec<(u32, PathBuf)> = entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let file_name = entry.file_name();
+                    let file_name = file_name.to_str()?;
+                    let index_str = file_name.strip_prefix("temp")?.strip_suffix("_input")?;
+                    let index: u32 = index_str.parse().ok()?;
+                    Some((index, entry.path()))
+                })
+                .collect();
+            temp_inputs.sort_by_key(|(index, _)| *index);
+
+            for (index, temp_path) in temp_inputs {
+                let label_path = chip_dir.join(format!("temp{}_label", index));
+                if let Ok(label) = self.source.read_hwmon(&label_path) {
+                    discovery
+                        .by_label
+                        .insert(label.trim().to_string(), temp_path.clone());
+                }
+                discovery.all_temps.push(temp_path);
+            }
+        }
+
+        discovery
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+    
+    fn create_mock_sysfs(temp_dir: &TempDir, num_cores: usize) -> (PathBuf, PathBuf, PathBuf) {
+        let sysfs_base = temp_dir.path().join("sys/devices/system/cpu");
+        let proc_stat_path = temp_dir.path().join("proc/stat");
+        let hwmon_path = temp_dir.path().join("sys/class/hwmon");
+        
+        // Create CPU directories
+        for i in 0..num_cores {
+            let cpu_dir = sysfs_base.join(format!("cpu{}", i)).join("cpufreq");
+            fs::create_dir_all(&cpu_dir).unwrap();
+            
+            // Create mock files
+            fs::write(cpu_dir.join("scaling_cur_freq"), "2800000").unwrap(); // 2800 MHz
+            fs::write(cpu_dir.join("voltage_offset"), "-25").unwrap();
+        }
+        
+        // Create hwmon directory
+        let hwmon0 = hwmon_path.join("hwmon0");
+        fs::create_dir_all(&hwmon0).unwrap();
+        fs::write(hwmon0.join("temp1_input"), "45000").unwrap(); // 45Â°C
+        
+        // Create /proc/stat
+        fs::create_dir_all(proc_stat_path.parent().unwrap()).unwrap();
+        let mut stat_content = String::from("cpu  100 0 50 850 0 0 0 0 0 0\n");
+        for i in 0..num_cores {
+            stat_content.push_str(&format!("cpu{} 100 0 50 850 0 0 0 0 0 0\n", i));
+        }
+        fs::write(&proc_stat_path, stat_content).unwrap();
+        
+        (sysfs_base, proc_stat_path, hwmon_path)
+    }
+    
+    #[test]
+    fn test_metrics_monitor_new() {
+        let monitor = MetricsMonitor::new(4);
+        assert_eq!(monitor.num_cores, 4);
+    }
+    
+    #[test]
+    fn test_get_frequency() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+        
+        let monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+        
+        let freq = monitor.get_frequency(0);
+        assert!(freq.is_ok());
+        assert_eq!(freq.unwrap(), 2800); // 2800 MHz
+    }
+    
+    #[test]
+    fn test_get_voltage() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+        
+        let monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+        
+        let voltage = monitor.get_voltage(0);
+        assert!(voltage.is_ok());
+        assert_eq!(voltage.unwrap(), -25);
+    }
+    
+    #[test]
+    fn test_get_temperature() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+        
+        let monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+        
+        let temp = monitor.get_temperature(0);
+        assert!(temp.is_ok());
+        assert_eq!(temp.unwrap(), 45.0);
+    }
+
+    /// Mock a `k10temp`-style chip: `name` file plus `Tctl`/`Tccd1`/`Tccd2`
+    /// labeled sensors, as found on the Deck's AMD APU.
+    fn create_mock_k10temp(hwmon_path: &PathBuf) -> PathBuf {
+        let chip_dir = hwmon_path.join("hwmon0");
+        fs::create_dir_all(&chip_dir).unwrap();
+        fs::write(chip_dir.join("name"), "k10temp").unwrap();
+        fs::write(chip_dir.join("temp1_input"), "50000").unwrap();
+        fs::write(chip_dir.join("temp1_label"), "Tctl").unwrap();
+        fs::write(chip_dir.join("temp2_input"), "55000").unwrap();
+        fs::write(chip_dir.join("temp2_label"), "Tccd1").unwrap();
+        fs::write(chip_dir.join("temp3_input"), "60000").unwrap();
+        fs::write(chip_dir.join("temp3_label"), "Tccd2").unwrap();
+        chip_dir
+    }
+
+    #[test]
+    fn test_get_temperature_resolves_ccd_label_by_core_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+        fs::remove_file(hwmon_path.join("hwmon0").join("temp1_input")).unwrap();
+        create_mock_k10temp(&hwmon_path);
+
+        let monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+
+        // core 0 -> Tccd1 (55.0), core 1 -> Tccd2 (60.0)
+        assert_eq!(monitor.get_temperature(0).unwrap(), 55.0);
+        assert_eq!(monitor.get_temperature(1).unwrap(), 60.0);
+    }
+
+    #[test]
+    fn test_get_temperature_falls_back_to_tctl_without_ccd_match() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+        fs::remove_file(hwmon_path.join("hwmon0").join("temp1_input")).unwrap();
+        let chip_dir = hwmon_path.join("hwmon0");
+        fs::write(chip_dir.join("name"), "k10temp").unwrap();
+        fs::write(chip_dir.join("temp1_input"), "50000").unwrap();
+        fs::write(chip_dir.join("temp1_label"), "Tctl").unwrap();
+
+        let monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+
+        // No Tccd sensors exist, so every core falls back to Tctl
+        assert_eq!(monitor.get_temperature(2).unwrap(), 50.0);
+    }
+
+    #[test]
+    fn test_set_core_label_overrides_ccd_guess() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+        fs::remove_file(hwmon_path.join("hwmon0").join("temp1_input")).unwrap();
+        create_mock_k10temp(&hwmon_path);
+
+        let mut monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+        monitor.set_core_label(0, "Tccd2");
+
+        assert_eq!(monitor.get_temperature(0).unwrap(), 60.0);
+    }
+
+    #[test]
+    fn test_set_chip_name_filter_excludes_other_chips() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+        fs::remove_file(hwmon_path.join("hwmon0").join("temp1_input")).unwrap();
+        create_mock_k10temp(&hwmon_path);
+
+        // A second, unrelated chip that should be ignored once filtered
+        let other_chip = hwmon_path.join("hwmon1");
+        fs::create_dir_all(&other_chip).unwrap();
+        fs::write(other_chip.join("name"), "nvme").unwrap();
+        fs::write(other_chip.join("temp1_input"), "99000").unwrap();
+
+        let mut monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+        monitor.set_chip_name_filter("k10temp");
+
+        // Still resolves via the k10temp Tccd1 label, not the nvme reading
+        assert_eq!(monitor.get_temperature(0).unwrap(), 55.0);
+    }
+
+    #[test]
+    fn test_set_core_label_invalidates_cache() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+        fs::remove_file(hwmon_path.join("hwmon0").join("temp1_input")).unwrap();
+        create_mock_k10temp(&hwmon_path);
+
+        let mut monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+        // Populate the cache with a first read before changing the mapping
+        assert_eq!(monitor.get_temperature(1).unwrap(), 60.0);
+
+        monitor.set_core_label(1, "Tctl");
+        assert_eq!(monitor.get_temperature(1).unwrap(), 50.0);
+    }
+
+    #[test]
+    fn test_get_cpu_load() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+        
+        let mut monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path.clone(), hwmon_path);
+        
+        // First call should return 0 (no previous data)
+        let load = monitor.get_cpu_load(0);
+        assert!(load.is_ok());
+        assert_eq!(load.unwrap(), 0.0);
+        
+        // Update /proc/stat with new values
+        let mut stat_content = String::from("cpu  200 0 100 1700 0 0 0 0 0 0\n");
+        for i in 0..4 {
+            stat_content.push_str(&format!("cpu{} 200 0 100 1700 0 0 0 0 0 0\n", i));
+        }
+        fs::write(&proc_stat_path, stat_content).unwrap();
+        
+        // Second call should calculate load
+        let load = monitor.get_cpu_load(0);
+        assert!(load.is_ok());
+        let load_val = load.unwrap();
+        assert!(load_val >= 0.0 && load_val <= 100.0);
+    }
+    
+    #[test]
+    fn test_get_cpu_load_treats_iowait_as_non_busy() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 1);
+
+        let mut monitor = MetricsMonitor::with_paths(1, sysfs_base, proc_stat_path.clone(), hwmon_path);
+        monitor.get_cpu_load(0).unwrap(); // prime prev_stats
+
+        // All of the next window's extra time goes to iowait, none to user/system.
+        fs::write(&proc_stat_path, "cpu0 100 0 50 850 500 0 0 0 0 0\n").unwrap();
+        let load = monitor.get_cpu_load(0).unwrap();
+        assert_eq!(load, 0.0, "a core stuck in iowait should not read as busy");
+    }
+
+    #[test]
+    fn test_get_core_metrics_breakdown_percentages() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 1);
+
+        let mut monitor = MetricsMonitor::with_paths(1, sysfs_base, proc_stat_path.clone(), hwmon_path);
+        monitor.get_core_metrics(0).unwrap(); // prime prev_stats
+
+        // user +100, system +50, iowait +500, steal +350 => total_delta 1000
+        fs::write(&proc_stat_path, "cpu0 200 0 100 850 500 0 0 350 0 0\n").unwrap();
+        let metrics = monitor.get_core_metrics(0).unwrap();
+
+        assert!((metrics.user_percent - 10.0).abs() < 0.01);
+        assert!((metrics.system_percent - 5.0).abs() < 0.01);
+        assert!((metrics.iowait_percent - 50.0).abs() < 0.01);
+        assert!((metrics.steal_percent - 35.0).abs() < 0.01);
+        assert!((metrics.load - 50.0).abs() < 0.01, "load should exclude idle+iowait only");
+    }
+
+    #[test]
+    fn test_guest_time_is_not_double_counted() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 1);
+
+        let mut monitor = MetricsMonitor::with_paths(1, sysfs_base, proc_stat_path.clone(), hwmon_path);
+        monitor.get_cpu_load(0).unwrap(); // prime prev_stats: user=100 system=50 idle=850
+
+        // user includes 80 of guest time: user_excl_guest delta is (180-80)-100=0,
+        // system delta is 70-50=20, idle delta is 950-850=100, so total_delta=120
+        // and non_idle_delta=20 => load ~16.7%. If guest were double-counted
+        // (user+system without subtracting guest), non_idle_delta would be
+        // (180+70)-(100+50)=100 against a 200 total_delta => 50%, a very
+        // different answer, so this would catch a regression to that bug.
+        fs::write(&proc_stat_path, "cpu0 180 0 70 950 0 0 0 0 80 0\n").unwrap();
+        let load = monitor.get_cpu_load(0).unwrap();
+        assert!((load - 16.6667).abs() < 0.1, "load was {}", load);
+    }
+
+    #[test]
+    fn test_get_core_metrics() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+        
+        let mut monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+        
+        let metrics = monitor.get_core_metrics(0);
+        assert!(metrics.is_ok());
+        
+        let metrics = metrics.unwrap();
+        assert_eq!(metrics.core_id, 0);
+        assert!(metrics.load >= 0.0 && metrics.load <= 100.0);
+        assert_eq!(metrics.voltage, -25);
+        assert_eq!(metrics.frequency, 2800);
+        assert_eq!(metrics.temperature, 45.0);
+        assert!(metrics.timestamp > 0);
+    }
+    
+    #[test]
+    fn test_get_core_metrics_invalid_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+        
+        let mut monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+        
+        let metrics = monitor.get_core_metrics(10);
+        assert!(metrics.is_err());
+        match metrics {
+            Err(MetricsError::InvalidCoreId(id)) => assert_eq!(id, 10),
+            _ => panic!("Expected InvalidCoreId error"),
+        }
+    }
+    
+    #[test]
+    fn test_sample_all_returns_one_entry_per_core_in_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+
+        let mut monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+
+        let samples = monitor.sample_all().unwrap();
+        assert_eq!(samples.len(), 4);
+        for (i, sample) in samples.iter().enumerate() {
+            assert_eq!(sample.core_id, i);
+            assert_eq!(sample.voltage, -25);
+            assert_eq!(sample.frequency, 2800);
+            assert_eq!(sample.temperature, 45.0);
+        }
+    }
+
+    #[test]
+    fn test_sample_all_matches_per_core_calls() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+
+        let mut via_sample_all =
+            MetricsMonitor::with_paths(4, sysfs_base.clone(), proc_stat_path.clone(), hwmon_path.clone());
+        let mut via_per_core = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+
+        // Establish a first sample (zero-delta) on both, then advance
+        // /proc/stat and compare the two ways of reading the second sample.
+        via_sample_all.sample_all().unwrap();
+        for core_id in 0..4 {
+            via_per_core.get_core_metrics(core_id).unwrap();
+        }
+
+        let batch = via_sample_all.sample_all().unwrap();
+        for core_id in 0..4 {
+            let single = via_per_core.get_core_metrics(core_id).unwrap();
+            assert_eq!(batch[core_id].load, single.load);
+            assert_eq!(batch[core_id].user_percent, single.user_percent);
+        }
+    }
+
+    #[test]
+    fn test_sample_all_errors_on_missing_core_line() {
+        let temp_dir = TempDir::new().unwrap();
+        let (sysfs_base, proc_stat_path, hwmon_path) = create_mock_sysfs(&temp_dir, 4);
+        // Truncate /proc/stat so core 3 has no line at all.
+        fs::write(&proc_stat_path, "cpu  100 0 50 850 0 0 0 0 0 0\ncpu0 100 0 50 850 0 0 0 0 0 0\n").unwrap();
+
+        let mut monitor = MetricsMonitor::with_paths(4, sysfs_base, proc_stat_path, hwmon_path);
+
+        let result = monitor.sample_all();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_physical_core_count_from_cpuinfo_collapses_smt_siblings() {
+        // 2 physical cores x 2 threads each (4 logical, 2 physical)
+        let cpuinfo = "\
+processor\t: 0
+physical id\t: 0
+core id\t: 0
+
+processor\t: 1
+physical id\t: 0
+core id\t: 1
+
+processor\t: 2
+physical id\t: 0
+core id\t: 0
+
+processor\t: 3
+physical id\t: 0
+core id\t: 1
+";
+        assert_eq!(physical_core_count_from_cpuinfo(cpuinfo), 2);
+    }
+
+    #[test]
+    fn test_physical_core_count_from_cpuinfo_falls_back_without_ids() {
+        let cpuinfo = "processor\t: 0\nprocessor\t: 1\nprocessor\t: 2\nprocessor\t: 3\n";
+        assert_eq!(physical_core_count_from_cpuinfo(cpuinfo), 4);
+    }
+
+    #[test]
+    fn test_physical_core_count_from_cpuinfo_empty_is_one() {
+        assert_eq!(physical_core_count_from_cpuinfo(""), 1);
+    }
+
+    #[test]
+    fn test_cgroup_core_limit_v2_quota() {
+        let temp_dir = TempDir::new().unwrap();
+        let v2_path = temp_dir.path().join("cpu.max");
+        let v1_quota = temp_dir.path().join("cfs_quota_us");
+        let v1_period = temp_dir.path().join("cfs_period_us");
+        fs::write(&v2_path, "250000 100000\n").unwrap();
+
+        let limit = cgroup_core_limit_at(&v2_path, &v1_quota, &v1_period);
+        assert_eq!(limit, Some(2.5));
+    }
+
+    #[test]
+    fn test_cgroup_core_limit_v2_unlimited_is_none() {
+        let temp_dir = TempDir::new().unwrap();
+        let v2_path = temp_dir.path().join("cpu.max");
+        let v1_quota = temp_dir.path().join("cfs_quota_us");
+        let v1_period = temp_dir.path().join("cfs_period_us");
+        fs::write(&v2_path, "max 100000\n").unwrap();
+
+        assert_eq!(cgroup_core_limit_at(&v2_path, &v1_quota, &v1_period), None);
+    }
+
+    #[test]
+    fn test_cgroup_core_limit_v1_fallback() {
+        let temp_dir = TempDir::new().unwrap();
+        let v2_path = temp_dir.path().join("cpu.max"); // doesn't exist
+        let v1_quota = temp_dir.path().join("cfs_quota_us");
+        let v1_period = temp_dir.path().join("cfs_period_us");
+        fs::write(&v1_quota, "50000\n").unwrap();
+        fs::write(&v1_period, "100000\n").unwrap();
+
+        let limit = cgroup_core_limit_at(&v2_path, &v1_quota, &v1_period);
+        assert_eq!(limit, Some(0.5));
+    }
+
+    #[test]
+    fn test_cgroup_core_limit_v1_unlimited_is_none() {
+        let temp_dir = TempDir::new().unwrap();
+        let v2_path = temp_dir.path().join("cpu.max");
+        let v1_quota = temp_dir.path().join("cfs_quota_us");
+        let v1_period = temp_dir.path().join("cfs_period_us");
+        fs::write(&v1_quota, "-1\n").unwrap();
+        fs::write(&v1_period, "100000\n").unwrap();
+
+        assert_eq!(cgroup_core_limit_at(&v2_path, &v1_quota, &v1_period), None);
+    }
+
+    #[test]
+    fn test_cgroup_core_limit_no_files_is_none() {
+        let temp_dir = TempDir::new().unwrap();
+        let v2_path = temp_dir.path().join("cpu.max");
+        let v1_quota = temp_dir.path().join("cfs_quota_us");
+        let v1_period = temp_dir.path().join("cfs_period_us");
+
+        assert_eq!(cgroup_core_limit_at(&v2_path, &v1_quota, &v1_period), None);
+    }
+
+    #[test]
+    fn test_new_auto_detects_at_least_one_core() {
+        let monitor = MetricsMonitor::new_auto();
+        assert!(monitor.logical_core_count() >= 1);
+        assert!(monitor.physical_core_count() >= 1);
+        assert_eq!(monitor.num_cores, monitor.logical_core_count());
+    }
+
+    #[test]
+    fn test_new_has_no_cgroup_limit_by_default() {
+        let monitor = MetricsMonitor::new(4);
+        assert_eq!(monitor.logical_core_count(), 4);
+        assert_eq!(monitor.physical_core_count(), 4);
+        assert_eq!(monitor.cgroup_core_limit(), None);
+    }
+
+    #[test]
+    fn test_metrics_error_display() {
+        let err = MetricsError::InvalidCoreId(5);
+        assert!(err.to_string().contains("5"));
+        
+        let err = MetricsError::ParseError("test error".to_string());
+        assert!(err.to_string().contains("test error"));
+        
+        let err = MetricsError::FileNotFound("/path/to/file".to_string());
+        assert!(err.to_string().contains("/path/to/file"));
+    }
+
+    /// In-memory [`MetricsSource`] for exercising parsing edge cases
+    /// (counter-wrap, missing files, malformed lines) without a temp
+    /// directory. Unset cpufreq/hwmon entries read as [`std::io::ErrorKind::NotFound`].
+    #[derive(Debug, Default)]
+    struct MockMetricsSource {
+        proc_stat: RefCell<String>,
+        cpufreq: RefCell<HashMap<(usize, String), String>>,
+        hwmon: RefCell<HashMap<PathBuf, String>>,
+    }
+
+    impl MockMetricsSource {
+        fn new(proc_stat: impl Into<String>) -> Self {
+            Self {
+                proc_stat: RefCell::new(proc_stat.into()),
+                ..Default::default()
+            }
+        }
+
+        fn set_proc_stat(&self, content: impl Into<String>) {
+            *self.proc_stat.borrow_mut() = content.into();
+        }
+
+        fn set_cpufreq(&self, core_id: usize, attr: &str, content: impl Into<String>) {
+            self.cpufreq
+                .borrow_mut()
+                .insert((core_id, attr.to_string()), content.into());
+        }
+    }
+
+    fn not_found(what: &str) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::NotFound, format!("mock: no such {}", what))
+    }
+
+    impl MetricsSource for MockMetricsSource {
+        fn read_proc_stat(&self) -> std::io::Result<String> {
+            Ok(self.proc_stat.borrow().clone())
+        }
+
+        fn read_cpufreq(&self, core_id: usize, attr: &str) -> std::io::Result<String> {
+            self.cpufreq
+                .borrow()
+                .get(&(core_id, attr.to_string()))
+                .cloned()
+                .ok_or_else(|| not_found("cpufreq file"))
+        }
+
+        fn read_hwmon(&self, path: &Path) -> std::io::Result<String> {
+            self.hwmon
+                .borrow()
+                .get(path)
+                .cloned()
+                .ok_or_else(|| not_found("hwmon file"))
+        }
+    }
+
+    /// Lets a test keep an `Rc` handle to a [`MockMetricsSource`] to mutate
+    /// it (e.g. `set_proc_stat`) after handing a `Box<dyn MetricsSource>`
+    /// clone of it to a `MetricsMonitor`.
+    impl MetricsSource for std::rc::Rc<MockMetricsSource> {
+        fn read_proc_stat(&self) -> std::io::Result<String> {
+            self.as_ref().read_proc_stat()
+        }
+
+        fn read_cpufreq(&self, core_id: usize, attr: &str) -> std::io::Result<String> {
+            self.as_ref().read_cpufreq(core_id, attr)
+        }
+
+        fn read_hwmon(&self, path: &Path) -> std::io::Result<String> {
+            self.as_ref().read_hwmon(path)
+        }
+    }
+
+    #[test]
+    fn test_mock_source_missing_cpufreq_file_reads_as_zero() {
+        let source = MockMetricsSource::new("cpu0 100 0 50 850 0 0 0 0 0 0\n");
+        let mut monitor =
+            MetricsMonitor::with_source(1, Box::new(source), PathBuf::from("/nonexistent"));
+
+        assert_eq!(monitor.get_voltage(0).unwrap(), 0);
+        assert_eq!(monitor.get_frequency(0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_mock_source_malformed_cpufreq_value_is_parse_error() {
+        let source = MockMetricsSource::new("cpu0 100 0 50 850 0 0 0 0 0 0\n");
+        source.set_cpufreq(0, "scaling_cur_freq", "not-a-number");
+        let mut monitor =
+            MetricsMonitor::with_source(1, Box::new(source), PathBuf::from("/nonexistent"));
+
+        match monitor.get_frequency(0) {
+            Err(MetricsError::ParseError(_)) => {}
+            other => panic!("expected ParseError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_mock_source_malformed_proc_stat_line_is_parse_error() {
+        let source = MockMetricsSource::new("cpu0 not enough fields\n");
+        let mut monitor =
+            MetricsMonitor::with_source(1, Box::new(source), PathBuf::from("/nonexistent"));
+
+        match monitor.get_cpu_load(0) {
+            Err(MetricsError::ParseError(_)) => {}
+            other => panic!("expected ParseError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_mock_source_counter_wrap_does_not_panic_and_reads_as_zero_load() {
+        let source = std::rc::Rc::new(MockMetricsSource::new(
+            "cpu0 100000 0 50000 850000 0 0 0 0 0 0\n",
+        ));
+        let mut monitor =
+            MetricsMonitor::with_source(1, Box::new(source.clone()), PathBuf::from("/nonexistent"));
+        monitor.get_cpu_load(0).unwrap(); // prime prev_stats
+
+        // Counters reset to near-zero, as if the kernel's u64 accumulator
+        // wrapped; saturating_sub should floor every delta at zero rather
+        // than underflowing into a huge bogus load.
+        source.set_proc_stat("cpu0 10 0 5 90 0 0 0 0 0 0\n");
+        let load = monitor.get_cpu_load(0).unwrap();
+        assert_eq!(load, 0.0, "a wrapped counter should read as no load, not panic or go negative");
+    }
+}
@@ -6,7 +6,75 @@
 //! Requirements: 5.5, 9.5
 
 pub mod voltage_controller;
+pub mod voltage_backend;
+pub mod voltage_profile;
+pub mod device_limits;
 pub mod metrics_monitor;
+pub mod metrics_history;
+pub mod curve;
+pub mod frequency_curve;
+pub mod fan_duty_curve;
+pub mod ratified_voltage;
+pub mod frequency_controller;
+pub mod frequency_daemon;
 
-pub use voltage_controller::{VoltageController, CoreConfig, VoltageControllerError};
-pub use metrics_monitor::{MetricsMonitor, CoreMetrics, MetricsError};
+pub use voltage_controller::{
+    VoltageController,
+    CoreConfig,
+    CurvePoint,
+    VoltageControllerError,
+    format_update_all_errors,
+    DEFAULT_STABILITY_BACKOFF_STEP_MV,
+    DEFAULT_THERMAL_MARGIN_CEILING_C,
+    DEFAULT_THERMAL_MARGIN_GAIN,
+    DEFAULT_STABILITY_DECAY_TICKS,
+};
+pub use voltage_backend::{
+    VoltageBackend,
+    SysfsBackend,
+    RyzenAdjBackend,
+    MsrBackend,
+    detect_authentic_amd,
+    RYZENADJ_MIN_OFFSET_MV,
+    RYZENADJ_MAX_OFFSET_MV,
+};
+pub use voltage_profile::{VoltageProfile, VariantInfo, profile_id};
+pub use device_limits::{DeviceLimits, RangeLimit, load_device_limits};
+pub use metrics_monitor::{MetricsMonitor, CoreMetrics, MetricsError, CoreTopology, MetricsSource};
+pub use metrics_history::{
+    MetricsHistory,
+    CoreMetricsHistory,
+    MetricsAggregate,
+    DEFAULT_METRICS_HISTORY_CAPACITY,
+};
+pub use curve::{Curve, Interpolable};
+pub use fan_duty_curve::{FanDutyCurve, FanDutyPoint};
+pub use frequency_curve::{
+    FrequencyCurve,
+    FrequencyPoint,
+    InterpolationKind,
+    InterpolationPoint,
+    InterpolationDatum,
+    ExtrapolationPolicy,
+    EvenFrequencyCurve,
+};
+pub use ratified_voltage::{
+    RatifiedVoltage,
+    VanGoghRatifiedVoltage,
+    VAN_GOGH_MIN_OFFSET_MV,
+    VAN_GOGH_MAX_OFFSET_MV,
+    VAN_GOGH_STEP_MV,
+};
+pub use frequency_controller::{
+    FrequencyVoltageController,
+    FrequencyControllerError,
+    DEFAULT_SLEW_DEADBAND_MV,
+    DEFAULT_MAX_SLEW_DURATION_SEC,
+    DEFAULT_SLEW_HARD_RATE_MULTIPLIER,
+};
+pub use frequency_daemon::{
+    FrequencyDaemonRequest,
+    FrequencyDaemonResponse,
+    SharedFrequencyController,
+    FrequencyControlServer,
+};
@@ -0,0 +1,141 @@
+//! Device-level validation/clamping of computed voltage offsets
+//!
+//! `FrequencyCurve` only knows what the user tested and interpolated; it has
+//! no notion of what the silicon underneath can actually accept. This
+//! module separates those two concerns: [`RatifiedVoltage`] encodes
+//! per-device safety rules (absolute bounds, per-core limits, quantization
+//! to the hardware's step size) and is consulted by
+//! [`crate::dynamic::FrequencyVoltageController`] before a curve-computed
+//! offset is ever handed to ryzenadj, so a mis-authored curve can't push an
+//! unsafe value through.
+
+use std::fmt;
+
+/// Validates and clamps a voltage offset against what a specific device can
+/// actually apply
+pub trait RatifiedVoltage: fmt::Debug + Send + Sync {
+    /// Whether `offset_mv` is safe to apply to `core_id` as-is
+    fn is_possible(&self, core_id: usize, offset_mv: i32) -> bool;
+
+    /// `offset_mv` clamped (and quantized) into the range this device
+    /// allows for `core_id`
+    fn clamp(&self, core_id: usize, offset_mv: i32) -> i32;
+}
+
+/// Absolute minimum offset accepted on the SoC/Van Gogh undervolt curve
+pub const VAN_GOGH_MIN_OFFSET_MV: i32 = -50;
+
+/// Absolute maximum offset accepted on the SoC/Van Gogh undervolt curve
+pub const VAN_GOGH_MAX_OFFSET_MV: i32 = 0;
+
+/// Smallest offset increment the Van Gogh curve optimizer honors; offsets
+/// not a multiple of this are quantized down toward zero
+pub const VAN_GOGH_STEP_MV: i32 = 1;
+
+/// Default [`RatifiedVoltage`] for the Steam Deck's SoC (Van Gogh APU)
+///
+/// Applies one absolute `[min_offset_mv, max_offset_mv]` range and one
+/// `step_mv` quantization to every core; Van Gogh has no known per-core
+/// limit beyond the shared range, so `core_id` is accepted but unused.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VanGoghRatifiedVoltage {
+    min_offset_mv: i32,
+    max_offset_mv: i32,
+    step_mv: i32,
+}
+
+impl VanGoghRatifiedVoltage {
+    /// Create a ratifier using Van Gogh's known-safe bounds and step size
+    pub fn new() -> Self {
+        VanGoghRatifiedVoltage {
+            min_offset_mv: VAN_GOGH_MIN_OFFSET_MV,
+            max_offset_mv: VAN_GOGH_MAX_OFFSET_MV,
+            step_mv: VAN_GOGH_STEP_MV,
+        }
+    }
+
+    /// Override the absolute `[min_offset_mv, max_offset_mv]` range (for
+    /// testing, or a future device with different limits)
+    pub fn with_bounds(mut self, min_offset_mv: i32, max_offset_mv: i32) -> Self {
+        self.min_offset_mv = min_offset_mv;
+        self.max_offset_mv = max_offset_mv;
+        self
+    }
+
+    /// Override the quantization step size (for testing, or a future device
+    /// with a coarser curve optimizer granularity)
+    pub fn with_step_mv(mut self, step_mv: i32) -> Self {
+        self.step_mv = step_mv.max(1);
+        self
+    }
+}
+
+impl Default for VanGoghRatifiedVoltage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RatifiedVoltage for VanGoghRatifiedVoltage {
+    fn is_possible(&self, _core_id: usize, offset_mv: i32) -> bool {
+        offset_mv >= self.min_offset_mv
+            && offset_mv <= self.max_offset_mv
+            && offset_mv % self.step_mv == 0
+    }
+
+    fn clamp(&self, _core_id: usize, offset_mv: i32) -> i32 {
+        let bounded = offset_mv.clamp(self.min_offset_mv, self.max_offset_mv);
+        // Quantize toward zero (the safe direction: never clamp to a
+        // *more* negative, less-tested offset than was requested).
+        (bounded / self.step_mv) * self.step_mv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_possible_within_bounds() {
+        let ratifier = VanGoghRatifiedVoltage::new();
+        assert!(ratifier.is_possible(0, -30));
+        assert!(ratifier.is_possible(0, 0));
+        assert!(ratifier.is_possible(0, -50));
+    }
+
+    #[test]
+    fn test_is_possible_out_of_bounds() {
+        let ratifier = VanGoghRatifiedVoltage::new();
+        assert!(!ratifier.is_possible(0, -51));
+        assert!(!ratifier.is_possible(0, 1));
+    }
+
+    #[test]
+    fn test_is_possible_rejects_unquantized_step() {
+        let ratifier = VanGoghRatifiedVoltage::new().with_step_mv(5);
+        assert!(ratifier.is_possible(0, -30));
+        assert!(!ratifier.is_possible(0, -32));
+    }
+
+    #[test]
+    fn test_clamp_bounds_both_directions() {
+        let ratifier = VanGoghRatifiedVoltage::new();
+        assert_eq!(ratifier.clamp(0, -100), -50);
+        assert_eq!(ratifier.clamp(0, 10), 0);
+        assert_eq!(ratifier.clamp(0, -30), -30);
+    }
+
+    #[test]
+    fn test_clamp_quantizes_toward_zero() {
+        let ratifier = VanGoghRatifiedVoltage::new().with_step_mv(5);
+        assert_eq!(ratifier.clamp(0, -32), -30);
+        assert_eq!(ratifier.clamp(0, -34), -30);
+    }
+
+    #[test]
+    fn test_with_bounds_overrides_defaults() {
+        let ratifier = VanGoghRatifiedVoltage::new().with_bounds(-20, -10);
+        assert!(!ratifier.is_possible(0, -30));
+        assert_eq!(ratifier.clamp(0, -30), -20);
+    }
+}
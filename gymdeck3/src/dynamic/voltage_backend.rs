@@ -0,0 +1,346 @@
+//! Pluggable voltage-apply backends for [`super::voltage_controller::VoltageController`]
+//!
+//! `VoltageController::apply_voltage` used to be a placeholder that only
+//! wrote a `voltage_offset` sysfs file that doesn't exist on real Steam
+//! Deck hardware - good enough to keep tests hermetic, but unable to
+//! actually undervolt anything. [`VoltageBackend`] separates "can/should
+//! this be applied" (`is_possible`/`clamp`, the same ratify-then-apply
+//! split as [`super::ratified_voltage::RatifiedVoltage`]) from "make it
+//! so" (`apply`), so the controller can hold a `Box<dyn VoltageBackend>`
+//! chosen at construction and swap in a real backend without touching its
+//! load/threshold logic.
+
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use super::voltage_controller::VoltageControllerError;
+
+/// Applies (or reports on) a per-core voltage offset on a specific backend
+pub trait VoltageBackend: fmt::Debug {
+    /// Whether `offset_mv` can be applied to `core_id` as-is
+    fn is_possible(&self, core_id: usize, offset_mv: i32) -> bool;
+
+    /// Clamp `offset_mv` in place to what this backend can apply to
+    /// `core_id`, returning whether the value was changed
+    fn clamp(&self, core_id: usize, offset_mv: &mut i32) -> bool;
+
+    /// Apply `offset_mv` to `core_id`
+    fn apply(&self, core_id: usize, offset_mv: i32) -> Result<(), VoltageControllerError>;
+}
+
+/// Sysfs-file backend used for hermetic testing
+///
+/// Reproduces `VoltageController`'s original placeholder behavior: writes
+/// `{sysfs_base}/cpu{core_id}/cpufreq/voltage_offset` only if that
+/// directory already exists, so tests can point it at a `tempfile`
+/// directory without touching real hardware.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SysfsBackend {
+    sysfs_base: PathBuf,
+}
+
+impl SysfsBackend {
+    /// Create a backend writing under `sysfs_base`
+    pub fn new(sysfs_base: PathBuf) -> Self {
+        SysfsBackend { sysfs_base }
+    }
+}
+
+impl VoltageBackend for SysfsBackend {
+    fn is_possible(&self, _core_id: usize, _offset_mv: i32) -> bool {
+        true
+    }
+
+    fn clamp(&self, _core_id: usize, _offset_mv: &mut i32) -> bool {
+        false
+    }
+
+    fn apply(&self, core_id: usize, offset_mv: i32) -> Result<(), VoltageControllerError> {
+        let voltage_path = self.sysfs_base
+            .join(format!("cpu{}", core_id))
+            .join("cpufreq")
+            .join("voltage_offset");
+
+        if voltage_path.parent().map(|p| p.exists()).unwrap_or(false) {
+            fs::write(&voltage_path, format!("{}", offset_mv))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Absolute bound every ryzenadj curve-optimizer offset must fall within,
+/// used by [`RyzenAdjBackend::is_possible`]/[`RyzenAdjBackend::clamp`]
+/// regardless of per-core [`super::device_limits::DeviceLimits`] checks
+/// done earlier in the pipeline
+pub const RYZENADJ_MIN_OFFSET_MV: i32 = -100;
+
+/// See [`RYZENADJ_MIN_OFFSET_MV`]
+pub const RYZENADJ_MAX_OFFSET_MV: i32 = 0;
+
+/// Backend that shells out to the `ryzenadj` binary to set the per-core
+/// curve-optimizer offset on AMD APUs
+///
+/// Mirrors [`crate::ryzenadj::RyzenadjExecutor`]'s `--set-coper-N` command
+/// shape, but synchronously (via `std::process::Command`) since
+/// [`VoltageBackend::apply`] is sync - the async executor remains the
+/// right choice for the frequency-daemon's tokio runtime, this one is for
+/// `VoltageController`'s synchronous call path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RyzenAdjBackend {
+    binary_path: PathBuf,
+}
+
+impl RyzenAdjBackend {
+    /// Create a backend invoking `binary_path` (typically `"ryzenadj"`,
+    /// resolved via `$PATH`)
+    pub fn new(binary_path: &str) -> Self {
+        RyzenAdjBackend {
+            binary_path: PathBuf::from(binary_path),
+        }
+    }
+}
+
+impl VoltageBackend for RyzenAdjBackend {
+    fn is_possible(&self, _core_id: usize, offset_mv: i32) -> bool {
+        (RYZENADJ_MIN_OFFSET_MV..=RYZENADJ_MAX_OFFSET_MV).contains(&offset_mv)
+    }
+
+    fn clamp(&self, _core_id: usize, offset_mv: &mut i32) -> bool {
+        let clamped = (*offset_mv).clamp(RYZENADJ_MIN_OFFSET_MV, RYZENADJ_MAX_OFFSET_MV);
+        let changed = clamped != *offset_mv;
+        *offset_mv = clamped;
+        changed
+    }
+
+    fn apply(&self, core_id: usize, offset_mv: i32) -> Result<(), VoltageControllerError> {
+        // ryzenadj expects a positive magnitude per --set-coper-N
+        let output = Command::new(&self.binary_path)
+            .arg(format!("--set-coper-{}", core_id))
+            .arg(format!("{}", offset_mv.abs()))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            Err(VoltageControllerError::SafetyViolation(format!(
+                "ryzenadj exited with {}: {}",
+                output.status, stderr
+            )))
+        }
+    }
+}
+
+/// `/dev/cpu/N/msr` offset of `MSR_RAPL_POWER_UNIT`, whose bits `[3:0]` give
+/// the power-unit scale (`1 / 2^power_unit` watts) RAPL power readings are
+/// expressed in
+const MSR_RAPL_POWER_UNIT: u64 = 0x606;
+
+/// Read-only backend reporting package power via the RAPL MSRs instead of
+/// applying offsets
+///
+/// AMD APUs have no writable voltage MSR exposed this way, so `apply` is a
+/// no-op: this backend exists purely to feed real consumed-power readings
+/// back into tuning decisions elsewhere, not to control voltage itself.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MsrBackend {
+    msr_device_base: PathBuf,
+}
+
+impl MsrBackend {
+    /// Create a backend reading `{msr_device_base}/N/msr` (typically
+    /// `/dev/cpu`)
+    pub fn new(msr_device_base: PathBuf) -> Self {
+        MsrBackend { msr_device_base }
+    }
+
+    /// Path to the MSR device file for `core_id`
+    fn msr_path(&self, core_id: usize) -> PathBuf {
+        self.msr_device_base.join(core_id.to_string()).join("msr")
+    }
+
+    /// Read the 8-byte MSR value at `offset` for `core_id`
+    ///
+    /// Returns `None` if the device is missing or unreadable (no
+    /// `CAP_SYS_RAWIO`, kernel `msr` module not loaded, non-x86 host) -
+    /// callers treat that the same as "no power reading available" rather
+    /// than failing.
+    fn read_msr(&self, core_id: usize, offset: u64) -> Option<u64> {
+        use std::io::{Read, Seek, SeekFrom};
+
+        let mut file = fs::File::open(self.msr_path(core_id)).ok()?;
+        file.seek(SeekFrom::Start(offset)).ok()?;
+        let mut buf = [0u8; 8];
+        file.read_exact(&mut buf).ok()?;
+        Some(u64::from_le_bytes(buf))
+    }
+
+    /// Power-unit scale from `MSR_RAPL_POWER_UNIT` bits `[3:0]`, as
+    /// `1 / 2^power_unit` watts per RAPL power reading
+    ///
+    /// Returns `None` if the MSR can't be read (see [`Self::read_msr`]).
+    pub fn power_unit_watts(&self, core_id: usize) -> Option<f64> {
+        let raw = self.read_msr(core_id, MSR_RAPL_POWER_UNIT)?;
+        let power_unit = (raw & 0xF) as u32;
+        Some(1.0 / (1u64 << power_unit) as f64)
+    }
+}
+
+impl VoltageBackend for MsrBackend {
+    fn is_possible(&self, _core_id: usize, _offset_mv: i32) -> bool {
+        // No voltage MSR is written here - any offset is "possible" since
+        // this backend never rejects one, it simply won't do anything.
+        true
+    }
+
+    fn clamp(&self, _core_id: usize, _offset_mv: &mut i32) -> bool {
+        false
+    }
+
+    fn apply(&self, _core_id: usize, _offset_mv: i32) -> Result<(), VoltageControllerError> {
+        // Read-only feedback backend: nothing to apply.
+        Ok(())
+    }
+}
+
+/// Detect whether the running CPU reports `AuthenticAMD` as its
+/// `vendor_id`, via `/proc/cpuinfo` (consistent with how [`crate::model`]
+/// detects hardware through sysfs/procfs rather than raw `cpuid`)
+pub fn detect_authentic_amd() -> bool {
+    detect_authentic_amd_at("/proc/cpuinfo")
+}
+
+/// Detect using an explicit `/proc/cpuinfo`-shaped path (for testing)
+pub fn detect_authentic_amd_at<P: AsRef<std::path::Path>>(path: P) -> bool {
+    fs::read_to_string(path)
+        .map(|contents| {
+            contents
+                .lines()
+                .any(|line| line.starts_with("vendor_id") && line.contains("AuthenticAMD"))
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_sysfs_backend_writes_when_dir_exists() {
+        let temp_dir = TempDir::new().unwrap();
+        let cpu_dir = temp_dir.path().join("cpu0").join("cpufreq");
+        fs::create_dir_all(&cpu_dir).unwrap();
+
+        let backend = SysfsBackend::new(temp_dir.path().to_path_buf());
+        backend.apply(0, -25).unwrap();
+
+        let contents = fs::read_to_string(cpu_dir.join("voltage_offset")).unwrap();
+        assert_eq!(contents, "-25");
+    }
+
+    #[test]
+    fn test_sysfs_backend_skips_write_when_dir_missing() {
+        let temp_dir = TempDir::new().unwrap();
+        let backend = SysfsBackend::new(temp_dir.path().to_path_buf());
+        assert!(backend.apply(0, -25).is_ok());
+    }
+
+    #[test]
+    fn test_sysfs_backend_always_possible_and_never_clamps() {
+        let backend = SysfsBackend::new(PathBuf::from("/tmp"));
+        assert!(backend.is_possible(0, -500));
+        let mut offset = -500;
+        assert!(!backend.clamp(0, &mut offset));
+        assert_eq!(offset, -500);
+    }
+
+    #[test]
+    fn test_ryzenadj_backend_is_possible_within_bounds() {
+        let backend = RyzenAdjBackend::new("ryzenadj");
+        assert!(backend.is_possible(0, -50));
+        assert!(backend.is_possible(0, 0));
+        assert!(!backend.is_possible(0, -101));
+        assert!(!backend.is_possible(0, 1));
+    }
+
+    #[test]
+    fn test_ryzenadj_backend_clamp_reports_change() {
+        let backend = RyzenAdjBackend::new("ryzenadj");
+        let mut offset = -150;
+        assert!(backend.clamp(0, &mut offset));
+        assert_eq!(offset, -100);
+
+        let mut offset = -50;
+        assert!(!backend.clamp(0, &mut offset));
+        assert_eq!(offset, -50);
+    }
+
+    #[test]
+    fn test_ryzenadj_backend_apply_missing_binary_errors() {
+        let backend = RyzenAdjBackend::new("/nonexistent/ryzenadj-binary");
+        assert!(backend.apply(0, -25).is_err());
+    }
+
+    #[test]
+    fn test_msr_backend_apply_is_always_ok() {
+        let backend = MsrBackend::new(PathBuf::from("/dev/cpu"));
+        assert!(backend.apply(0, -25).is_ok());
+        assert!(backend.is_possible(0, -25));
+        let mut offset = -25;
+        assert!(!backend.clamp(0, &mut offset));
+    }
+
+    #[test]
+    fn test_msr_backend_power_unit_missing_device_is_none() {
+        let temp_dir = TempDir::new().unwrap();
+        let backend = MsrBackend::new(temp_dir.path().to_path_buf());
+        assert_eq!(backend.power_unit_watts(0), None);
+    }
+
+    #[test]
+    fn test_msr_backend_power_unit_reads_scale_from_low_nibble() {
+        let temp_dir = TempDir::new().unwrap();
+        let cpu_dir = temp_dir.path().join("0");
+        fs::create_dir_all(&cpu_dir).unwrap();
+
+        // A typical RAPL unit value has power_unit (bits 0-3) set to 0x3
+        // (1/8 W); write it at the MSR_RAPL_POWER_UNIT offset like a real
+        // msr device would serve on a seek+read.
+        let mut data = vec![0u8; (MSR_RAPL_POWER_UNIT as usize) + 8];
+        let raw: u64 = 0x3;
+        data[MSR_RAPL_POWER_UNIT as usize..MSR_RAPL_POWER_UNIT as usize + 8]
+            .copy_from_slice(&raw.to_le_bytes());
+        fs::write(cpu_dir.join("msr"), &data).unwrap();
+
+        let backend = MsrBackend::new(temp_dir.path().to_path_buf());
+        assert_eq!(backend.power_unit_watts(0), Some(1.0 / 8.0));
+    }
+
+    #[test]
+    fn test_detect_authentic_amd_at_matches_vendor_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("cpuinfo");
+        fs::write(&path, "processor\t: 0\nvendor_id\t: AuthenticAMD\nmodel name\t: AMD Custom APU\n").unwrap();
+        assert!(detect_authentic_amd_at(&path));
+    }
+
+    #[test]
+    fn test_detect_authentic_amd_at_rejects_other_vendor() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("cpuinfo");
+        fs::write(&path, "processor\t: 0\nvendor_id\t: GenuineIntel\n").unwrap();
+        assert!(!detect_authentic_amd_at(&path));
+    }
+
+    #[test]
+    fn test_detect_authentic_amd_at_missing_file_is_false() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(!detect_authentic_amd_at(temp_dir.path().join("nope")));
+    }
+}
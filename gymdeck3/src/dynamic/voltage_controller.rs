@@ -5,13 +5,93 @@
 //!
 //! Requirements: 5.5, 9.5
 
-use std::fs;
 use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
 use crate::safety::clamp_value;
 use crate::strategy::CoreBounds;
+use crate::model::detect_deck_model;
+use crate::dynamic::device_limits::{DeviceLimits, load_device_limits};
+use crate::dynamic::voltage_backend::{VoltageBackend, SysfsBackend, RyzenAdjBackend, detect_authentic_amd};
+use crate::dynamic::voltage_profile::{self, VariantInfo};
+
+/// Default on-disk location for the per-device voltage limits file consulted
+/// by [`VoltageController::new`]
+pub const DEFAULT_DEVICE_LIMITS_PATH: &str = "/etc/decktune/device_limits.json";
+
+/// Default on-disk location for the saved voltage profiles consulted by
+/// [`VoltageController::save_profile`]/[`VoltageController::list_profiles`]/
+/// [`VoltageController::load_profile`]
+pub const DEFAULT_PROFILES_PATH: &str = "/etc/decktune/profiles.json";
+
+/// Default fixed step (in mV) [`VoltageController::update_and_apply_feedback`]
+/// backs `safety_margin_mv` off by on instability, and decays it back toward
+/// 0 by on sustained stability
+pub const DEFAULT_STABILITY_BACKOFF_STEP_MV: i32 = 5;
+
+/// Default temperature (in °C) above which
+/// [`VoltageController::update_and_apply_feedback`] adds a proportional
+/// thermal margin on top of the instability back-off
+pub const DEFAULT_THERMAL_MARGIN_CEILING_C: f32 = 85.0;
+
+/// Default mV of margin added per degree `temp_c` exceeds
+/// `DEFAULT_THERMAL_MARGIN_CEILING_C` by
+pub const DEFAULT_THERMAL_MARGIN_GAIN: f32 = 1.0;
+
+/// Default number of consecutive stable ticks
+/// [`VoltageController::update_and_apply_feedback`] requires before decaying
+/// `safety_margin_mv` back toward 0 by one step
+pub const DEFAULT_STABILITY_DECAY_TICKS: u32 = 10;
+
+/// A single point on a [`CoreConfig`] load→voltage curve
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CurvePoint {
+    /// CPU load percentage (0-100)
+    pub load: f32,
+    /// Voltage offset in mV at this load
+    pub mv: i32,
+}
+
+/// Build the 3-point (or degenerate 2-point) curve equivalent to the
+/// original flat-then-linear `min_mv`/`max_mv`/`threshold` shape, so
+/// [`CoreConfig::new`] keeps producing identical `calculate_voltage` output
+/// through the shared curve-interpolation path
+fn threshold_curve(min_mv: i32, max_mv: i32, threshold: f32) -> Vec<CurvePoint> {
+    let mut points = vec![CurvePoint { load: 0.0, mv: min_mv }];
+    if threshold > 0.0 && threshold < 100.0 {
+        points.push(CurvePoint { load: threshold, mv: min_mv });
+    }
+    if threshold < 100.0 {
+        points.push(CurvePoint { load: 100.0, mv: max_mv });
+    } else {
+        points.push(CurvePoint { load: 100.0, mv: min_mv });
+    }
+    points
+}
+
+/// Binary-search the curve segment bracketing `load` and linearly
+/// interpolate between its endpoints, holding the nearest endpoint's value
+/// outside the curve's range
+///
+/// `points` must be sorted by `load` and non-empty.
+fn interpolate_curve(points: &[CurvePoint], load: f32) -> i32 {
+    let last = points.len() - 1;
+    if load <= points[0].load {
+        return points[0].mv;
+    }
+    if load >= points[last].load {
+        return points[last].mv;
+    }
+
+    // Index of the first point whose load is > `load`; the bracketing
+    // segment starts just before it.
+    let idx = points.partition_point(|p| p.load <= load) - 1;
+    let (p0, p1) = (points[idx], points[idx + 1]);
+    let t = (load - p0.load) / (p1.load - p0.load);
+    p0.mv + ((p1.mv - p0.mv) as f32 * t).round() as i32
+}
 
 /// Configuration for a single CPU core's voltage curve
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CoreConfig {
     /// Core ID (0-based index)
     pub core_id: usize,
@@ -21,6 +101,25 @@ pub struct CoreConfig {
     pub max_mv: i32,
     /// Load threshold percentage (0-100) where transition begins
     pub threshold: f32,
+    /// Hysteresis band in mV (default 0 = no hysteresis, current behavior)
+    ///
+    /// While the newly computed target stays within `±hysteresis_mv` of the
+    /// currently applied voltage, the applied voltage holds constant instead
+    /// of chattering every tick. Once the target crosses the band edge, the
+    /// applied voltage tracks the target minus the band width, so it always
+    /// re-enters the band rather than jumping straight to the target.
+    pub hysteresis_mv: i32,
+    /// Load→voltage control points `calculate_voltage` interpolates between,
+    /// sorted by `load`. Built from `min_mv`/`max_mv`/`threshold` by
+    /// [`Self::new`]; replace it with [`Self::with_curve`] for arbitrary
+    /// fan-curve shapes.
+    curve: Vec<CurvePoint>,
+    /// Learned stability back-off in mV (default 0), maintained by
+    /// [`VoltageController::update_and_apply_feedback`] and persisted
+    /// alongside the rest of this config so a core that needed backing off
+    /// converges on a survivable offset across reboots instead of
+    /// re-crashing each session.
+    pub safety_margin_mv: i32,
 }
 
 impl CoreConfig {
@@ -28,9 +127,11 @@ impl CoreConfig {
     ///
     /// # Arguments
     /// * `core_id` - Core identifier (0-based)
-    /// * `min_mv` - Voltage offset at low load (-100 to 0 mV) - applied below threshold
-    /// * `max_mv` - Voltage offset at high load (-100 to 0 mV) - applied above threshold
+    /// * `min_mv` - Voltage offset at low load - applied below threshold
+    /// * `max_mv` - Voltage offset at high load - applied above threshold
     /// * `threshold` - Load threshold (0-100%)
+    /// * `limits` - Device-detected per-core offset range `min_mv`/`max_mv`
+    ///   must fall within (see [`DeviceLimits`])
     ///
     /// Note: Typically min_mv is more negative (more aggressive) than max_mv
     /// because we can afford more aggressive undervolting at low loads.
@@ -38,15 +139,17 @@ impl CoreConfig {
     /// # Returns
     /// * `Ok(CoreConfig)` if valid
     /// * `Err(String)` if validation fails
-    pub fn new(core_id: usize, min_mv: i32, max_mv: i32, threshold: f32) -> Result<Self, String> {
-        // Validate voltage range
-        if min_mv < -100 || min_mv > 0 {
-            return Err(format!("min_mv must be in range [-100, 0], got {}", min_mv));
-        }
-        if max_mv < -100 || max_mv > 0 {
-            return Err(format!("max_mv must be in range [-100, 0], got {}", max_mv));
-        }
-        
+    pub fn new(
+        core_id: usize,
+        min_mv: i32,
+        max_mv: i32,
+        threshold: f32,
+        limits: &DeviceLimits,
+    ) -> Result<Self, String> {
+        // Validate voltage range against this core's device-detected limit
+        limits.validate(core_id, "min_mv", min_mv)?;
+        limits.validate(core_id, "max_mv", max_mv)?;
+
         // Validate min <= max (numerically: -30 <= -15)
         // min_mv is typically more negative (applied at low load)
         // max_mv is typically less negative (applied at high load for stability)
@@ -67,14 +170,64 @@ impl CoreConfig {
             min_mv,
             max_mv,
             threshold,
+            hysteresis_mv: 0,
+            curve: threshold_curve(min_mv, max_mv, threshold),
+            safety_margin_mv: 0,
         })
     }
-    
+
+    /// Set the hysteresis band (in mV) used to hold the applied voltage
+    /// steady against small oscillations in the computed target
+    ///
+    /// See `hysteresis_mv` for the hold/track semantics.
+    pub fn with_hysteresis_mv(mut self, hysteresis_mv: i32) -> Self {
+        self.hysteresis_mv = hysteresis_mv;
+        self
+    }
+
+    /// Seed this config's learned stability back-off, e.g. when restoring a
+    /// profile that already converged on a safe margin for this core
+    pub fn with_safety_margin_mv(mut self, safety_margin_mv: i32) -> Self {
+        self.safety_margin_mv = safety_margin_mv;
+        self
+    }
+
+    /// Replace this config's load→voltage curve with arbitrary control
+    /// points, for fan-curve shapes the flat-then-linear `min_mv`/`max_mv`
+    /// form can't express
+    ///
+    /// `points` are sorted by load; load is clamped to `[0, 100]` and mV is
+    /// clamped to this core's [`DeviceLimits`] bound. Rejects an empty set
+    /// or two points that land on the same load after clamping, since
+    /// `calculate_voltage` couldn't pick a segment between them.
+    pub fn with_curve(mut self, points: Vec<(f32, i32)>, limits: &DeviceLimits) -> Result<Self, String> {
+        if points.is_empty() {
+            return Err("curve must have at least one point".to_string());
+        }
+
+        let bound = limits.core_limit(self.core_id);
+        let mut points: Vec<CurvePoint> = points
+            .into_iter()
+            .map(|(load, mv)| CurvePoint {
+                load: load.clamp(0.0, 100.0),
+                mv: mv.clamp(bound.min.unwrap_or(i32::MIN), bound.max.unwrap_or(i32::MAX)),
+            })
+            .collect();
+        points.sort_by(|a, b| a.load.total_cmp(&b.load));
+
+        if let Some(dup) = points.windows(2).find(|pair| pair[0].load == pair[1].load) {
+            return Err(format!("duplicate curve point at load {}", dup[0].load));
+        }
+
+        self.curve = points;
+        Ok(self)
+    }
+
     /// Calculate voltage offset for a given CPU load
     ///
-    /// Uses piecewise linear interpolation:
-    /// - load <= threshold: returns min_mv
-    /// - load > threshold: linear interpolation from min_mv to max_mv
+    /// Interpolates linearly between the two curve points bracketing `load`
+    /// (see [`Self::new`] and [`Self::with_curve`]), holding the nearest
+    /// endpoint's value outside the curve's range.
     ///
     /// # Arguments
     /// * `load` - CPU load percentage (0-100)
@@ -82,24 +235,10 @@ impl CoreConfig {
     /// # Returns
     /// Voltage offset in mV
     pub fn calculate_voltage(&self, load: f32) -> i32 {
-        let load = load.max(0.0).min(100.0); // Clamp load to valid range
-        
-        if load <= self.threshold {
-            self.min_mv
-        } else {
-            // Linear interpolation from min_mv to max_mv
-            let range = 100.0 - self.threshold;
-            if range <= 0.0 {
-                // Threshold at 100%, always use min_mv
-                self.min_mv
-            } else {
-                let progress = (load - self.threshold) / range;
-                let voltage_range = self.max_mv - self.min_mv;
-                self.min_mv + (voltage_range as f32 * progress).round() as i32
-            }
-        }
+        let load = load.clamp(0.0, 100.0);
+        interpolate_curve(&self.curve, load)
     }
-    
+
     /// Convert to CoreBounds for safety validation
     /// 
     /// Note: CoreBounds has opposite semantics:
@@ -118,12 +257,37 @@ impl CoreConfig {
     }
 }
 
+/// Hold/track hysteresis filter: holds `current` steady while `target` is
+/// within `±band_mv`, and once `target` crosses that band, tracks it with a
+/// `band_mv` margin still between them instead of jumping straight to it.
+///
+/// This is the same bounded-slope-parallelogram dead-band filter used to
+/// stop small oscillations in the target from becoming constant hardware
+/// writes: with `band_mv <= 0` it degenerates to tracking `target` exactly.
+fn hold_or_track(current: i32, target: i32, band_mv: i32) -> i32 {
+    let band = band_mv.max(0);
+    let delta = target - current;
+
+    if delta.abs() <= band {
+        current
+    } else if delta > 0 {
+        target - band
+    } else {
+        target + band
+    }
+}
+
 /// State of a single core in the voltage controller
 #[derive(Debug, Clone)]
 struct CoreState {
     config: CoreConfig,
     current_voltage: i32,
     last_load: f32,
+    /// Consecutive stable ticks observed by
+    /// [`VoltageController::update_and_apply_feedback`] since the last
+    /// instability event or margin decay, counted toward
+    /// `VoltageController::stability_decay_ticks`
+    consecutive_stable_ticks: u32,
 }
 
 /// Errors from voltage controller operations
@@ -170,6 +334,18 @@ impl std::fmt::Display for VoltageControllerError {
 
 impl std::error::Error for VoltageControllerError {}
 
+/// Render the per-core errors collected by [`VoltageController::update_all`]
+/// into one multi-line log block, one indented entry per failing core - so
+/// a monitoring loop gets a single actionable report per tick instead of
+/// having to print each error separately
+pub fn format_update_all_errors(errors: &[(usize, VoltageControllerError)]) -> String {
+    let mut out = format!("update_all failed for {} core(s):", errors.len());
+    for (core_id, err) in errors {
+        out.push_str(&format!("\n  core {}: {}", core_id, err));
+    }
+    out
+}
+
 impl From<std::io::Error> for VoltageControllerError {
     fn from(e: std::io::Error) -> Self {
         VoltageControllerError::IoError(e)
@@ -185,19 +361,79 @@ pub struct VoltageController {
     cores: Vec<CoreState>,
     /// Whether the controller is active
     active: bool,
-    /// Base path for CPU voltage control (for testing)
-    sysfs_base: PathBuf,
+    /// Backend that actually applies (or reports on) voltage offsets
+    backend: Box<dyn VoltageBackend>,
+    /// Device-detected per-core offset limits, validated against by
+    /// `CoreConfig::new`/`set_core_config` instead of a global constant
+    device_limits: DeviceLimits,
+    /// On-disk location of the saved voltage profiles consulted by
+    /// `save_profile`/`list_profiles`/`load_profile`
+    profiles_path: PathBuf,
+    /// Fixed step (in mV) `update_and_apply_feedback` backs `safety_margin_mv`
+    /// off by on instability, and decays it back by on sustained stability
+    stability_backoff_step_mv: i32,
+    /// Temperature (in °C) above which `update_and_apply_feedback` adds a
+    /// proportional thermal margin
+    thermal_margin_ceiling_c: f32,
+    /// mV of margin added per degree `temp_c` exceeds
+    /// `thermal_margin_ceiling_c` by
+    thermal_margin_gain: f32,
+    /// Consecutive stable ticks `update_and_apply_feedback` requires before
+    /// decaying `safety_margin_mv` back toward 0 by one step
+    stability_decay_ticks: u32,
+}
+
+/// Auto-detect the voltage backend to use: `ryzenadj` on AuthenticAMD
+/// hardware (see [`detect_authentic_amd`]), otherwise the sysfs
+/// placeholder, which is also what every backend-less constructor used
+/// before this module existed
+fn detect_backend() -> Box<dyn VoltageBackend> {
+    if detect_authentic_amd() {
+        Box::new(RyzenAdjBackend::new("ryzenadj"))
+    } else {
+        Box::new(SysfsBackend::new(PathBuf::from("/sys/devices/system/cpu")))
+    }
 }
 
 impl VoltageController {
     /// Create a new VoltageController with the specified number of cores
     ///
+    /// Detects the Deck model via [`detect_deck_model`] and attempts to load
+    /// its entry from [`DEFAULT_DEVICE_LIMITS_PATH`]; if detection or loading
+    /// fails, falls back to [`DeviceLimits::conservative_default`], which
+    /// reproduces the previous hardcoded `[-100, 0]` range for every core.
+    /// Also auto-detects the voltage backend via [`detect_backend`].
+    ///
     /// # Arguments
     /// * `num_cores` - Number of CPU cores to manage
     ///
     /// # Returns
     /// VoltageController with default safe configuration
     pub fn new(num_cores: usize) -> Self {
+        let device_limits = detect_deck_model()
+            .and_then(|model| {
+                load_device_limits(std::path::Path::new(DEFAULT_DEVICE_LIMITS_PATH), model.apu_name())
+            })
+            .unwrap_or_else(|| DeviceLimits::conservative_default(num_cores));
+
+        Self::with_backend(num_cores, device_limits, detect_backend())
+    }
+
+    /// Create a VoltageController with an explicit [`DeviceLimits`] instead
+    /// of detecting one, for testing or when the caller already knows the
+    /// device's limits (the voltage backend is still auto-detected)
+    pub fn with_device_limits(num_cores: usize, device_limits: DeviceLimits) -> Self {
+        Self::with_backend(num_cores, device_limits, detect_backend())
+    }
+
+    /// Create a VoltageController with an explicit [`DeviceLimits`] and
+    /// [`VoltageBackend`], bypassing both auto-detections entirely - the
+    /// constructor every other one in this file ultimately delegates to
+    pub fn with_backend(
+        num_cores: usize,
+        device_limits: DeviceLimits,
+        backend: Box<dyn VoltageBackend>,
+    ) -> Self {
         let cores = (0..num_cores)
             .map(|core_id| CoreState {
                 config: CoreConfig {
@@ -205,30 +441,67 @@ impl VoltageController {
                     min_mv: -30,  // Safe default
                     max_mv: -15,  // Safe default
                     threshold: 50.0,
+                    hysteresis_mv: 0,
+                    curve: threshold_curve(-30, -15, 50.0),
+                    safety_margin_mv: 0,
                 },
                 current_voltage: 0,
                 last_load: 0.0,
+                consecutive_stable_ticks: 0,
             })
             .collect();
-        
+
         Self {
             cores,
             active: false,
-            sysfs_base: PathBuf::from("/sys/devices/system/cpu"),
+            backend,
+            device_limits,
+            profiles_path: PathBuf::from(DEFAULT_PROFILES_PATH),
+            stability_backoff_step_mv: DEFAULT_STABILITY_BACKOFF_STEP_MV,
+            thermal_margin_ceiling_c: DEFAULT_THERMAL_MARGIN_CEILING_C,
+            thermal_margin_gain: DEFAULT_THERMAL_MARGIN_GAIN,
+            stability_decay_ticks: DEFAULT_STABILITY_DECAY_TICKS,
         }
     }
-    
-    /// Create a VoltageController with custom sysfs base path (for testing)
+
+    /// Override the on-disk location `save_profile`/`list_profiles`/
+    /// `load_profile` read and write, overriding [`DEFAULT_PROFILES_PATH`]
+    /// (for testing, or a user-configurable install)
+    pub fn with_profiles_path(mut self, profiles_path: PathBuf) -> Self {
+        self.profiles_path = profiles_path;
+        self
+    }
+
+    /// Override the stability governor's tunables consulted by
+    /// [`Self::update_and_apply_feedback`], overriding
+    /// [`DEFAULT_STABILITY_BACKOFF_STEP_MV`], [`DEFAULT_THERMAL_MARGIN_CEILING_C`],
+    /// [`DEFAULT_THERMAL_MARGIN_GAIN`] and [`DEFAULT_STABILITY_DECAY_TICKS`]
+    pub fn with_stability_governor(
+        mut self,
+        backoff_step_mv: i32,
+        thermal_margin_ceiling_c: f32,
+        thermal_margin_gain: f32,
+        stability_decay_ticks: u32,
+    ) -> Self {
+        self.stability_backoff_step_mv = backoff_step_mv;
+        self.thermal_margin_ceiling_c = thermal_margin_ceiling_c;
+        self.thermal_margin_gain = thermal_margin_gain;
+        self.stability_decay_ticks = stability_decay_ticks;
+        self
+    }
+
+    /// Create a VoltageController with a custom sysfs base path (for
+    /// testing), forcing a [`SysfsBackend`] regardless of auto-detection
     ///
     /// # Arguments
     /// * `num_cores` - Number of CPU cores
     /// * `sysfs_base` - Base path for CPU sysfs
     pub fn with_sysfs_base(num_cores: usize, sysfs_base: PathBuf) -> Self {
         let mut controller = Self::new(num_cores);
-        controller.sysfs_base = sysfs_base;
+        controller.backend = Box::new(SysfsBackend::new(sysfs_base));
         controller
     }
-    
+
     /// Get the number of cores managed by this controller
     pub fn num_cores(&self) -> usize {
         self.cores.len()
@@ -260,6 +533,7 @@ impl VoltageController {
             config.min_mv,
             config.max_mv,
             config.threshold,
+            &self.device_limits,
         )
         .map_err(VoltageControllerError::InvalidConfig)?;
         
@@ -288,7 +562,61 @@ impl VoltageController {
     pub fn get_all_configs(&self) -> Vec<CoreConfig> {
         self.cores.iter().map(|state| state.config.clone()).collect()
     }
-    
+
+    /// Save every core's current configuration as a named profile, so it
+    /// can later be restored in one call via [`Self::load_profile`]
+    ///
+    /// Saving the same `name` again replaces the earlier profile rather
+    /// than accumulating duplicates (see `voltage_profile::profile_id`).
+    ///
+    /// # Returns
+    /// * `Ok(())` if the profile was written to [`Self::profiles_path`]
+    /// * `Err(VoltageControllerError::IoError)` if the write failed
+    pub fn save_profile(&self, name: &str) -> Result<(), VoltageControllerError> {
+        voltage_profile::save_profile(&self.profiles_path, name, self.get_all_configs())
+            .map_err(VoltageControllerError::from)
+    }
+
+    /// List every profile saved at [`Self::profiles_path`], without loading
+    /// their per-core configs
+    pub fn list_profiles(&self) -> Vec<VariantInfo> {
+        voltage_profile::list_profiles(&self.profiles_path)
+    }
+
+    /// Load the profile stored under `id_num` and apply all of its per-core
+    /// configs
+    ///
+    /// Every config is validated against [`Self::device_limits`] before any
+    /// of them are committed, so a profile saved against a wider device (or
+    /// hand-edited) can't partially apply and leave the controller with a
+    /// mix of old and new cores.
+    ///
+    /// # Returns
+    /// * `Ok(())` if every config validated and was applied
+    /// * `Err(VoltageControllerError::InvalidConfig)` if no profile matches
+    ///   `id_num`, or a config fails validation
+    /// * `Err(VoltageControllerError::InvalidCoreId)` if a config targets a
+    ///   core this controller doesn't manage
+    pub fn load_profile(&mut self, id_num: u64) -> Result<(), VoltageControllerError> {
+        let profile = voltage_profile::load_profile(&self.profiles_path, id_num)
+            .ok_or_else(|| VoltageControllerError::InvalidConfig(format!("no profile with id {}", id_num)))?;
+
+        for config in &profile.configs {
+            if config.core_id >= self.cores.len() {
+                return Err(VoltageControllerError::InvalidCoreId(config.core_id));
+            }
+            CoreConfig::new(config.core_id, config.min_mv, config.max_mv, config.threshold, &self.device_limits)
+                .map_err(VoltageControllerError::InvalidConfig)?;
+        }
+
+        for config in profile.configs {
+            let core_id = config.core_id;
+            self.cores[core_id].config = config;
+        }
+
+        Ok(())
+    }
+
     /// Start the voltage controller
     ///
     /// Activates dynamic voltage adjustment. Voltage will be applied
@@ -330,40 +658,211 @@ impl VoltageController {
     
     /// Update voltage for a core based on current load and apply it
     ///
+    /// When `config.hysteresis_mv` is nonzero, the applied voltage holds
+    /// constant while the target stays within `±hysteresis_mv` of it, and
+    /// only moves once the target crosses the band edge (see
+    /// `hold_or_track`). With the default `hysteresis_mv` of 0 this always
+    /// tracks the target exactly, matching the pre-hysteresis behavior.
+    ///
     /// # Arguments
     /// * `core_id` - Core identifier
     /// * `load` - Current CPU load percentage (0-100)
     ///
     /// # Returns
-    /// * `Ok(i32)` - The voltage that was applied
+    /// * `Ok(i32)` - The voltage that was applied (or held)
     /// * `Err(VoltageControllerError)` if error occurs
     pub fn update_and_apply(&mut self, core_id: usize, load: f32) -> Result<i32, VoltageControllerError> {
         if !self.active {
             return Err(VoltageControllerError::NotStarted);
         }
-        
+
         if core_id >= self.cores.len() {
             return Err(VoltageControllerError::InvalidCoreId(core_id));
         }
-        
+
         // Calculate target voltage based on load
         let config = &self.cores[core_id].config;
         let target_voltage = config.calculate_voltage(load);
-        
+
         // Apply safety clamping
         let bounds = config.to_bounds();
         let safe_voltage = clamp_value(target_voltage, &bounds);
-        
-        // Apply voltage to hardware
+
+        let current_voltage = self.cores[core_id].current_voltage;
+        let next_voltage = clamp_value(
+            hold_or_track(current_voltage, safe_voltage, config.hysteresis_mv),
+            &bounds,
+        );
+
+        // Only touch the hardware (and record the new value) when the
+        // hysteresis band has actually been crossed
+        if next_voltage != current_voltage {
+            self.apply_voltage(core_id, next_voltage)?;
+            self.cores[core_id].current_voltage = next_voltage;
+        }
+        self.cores[core_id].last_load = load;
+
+        Ok(next_voltage)
+    }
+
+    /// Update and apply a core's voltage from load plus instability/thermal
+    /// feedback, backing its learned `safety_margin_mv` off when the system
+    /// shows signs of trouble and slowly re-deepening it once things settle
+    ///
+    /// The target is `calculate_voltage(load) + safety_margin_mv` - note the
+    /// addition, not subtraction: `safety_margin_mv` is maintained as a
+    /// non-negative back-off amount, and `calculate_voltage(load)` is
+    /// typically negative, so adding the margin moves the applied voltage
+    /// toward 0 (less aggressive, safer) as it grows. `stable == false`
+    /// (e.g. a watchdog observed a recent hang/crash) grows the margin by
+    /// [`Self::stability_backoff_step_mv`] and resets the consecutive-stable
+    /// counter; `temp_c` above [`Self::thermal_margin_ceiling_c`] adds a
+    /// further `thermal_margin_gain * (temp_c - ceiling)`. After
+    /// [`Self::stability_decay_ticks`] consecutive stable calls, the margin
+    /// decays back down by one step so the undervolt slowly re-deepens
+    /// instead of staying backed off forever. The resulting margin is
+    /// written back onto the core's `CoreConfig`, so it's persisted the next
+    /// time [`Self::save_profile`] is called.
+    ///
+    /// # Arguments
+    /// * `core_id` - Core identifier
+    /// * `load` - Current CPU load percentage (0-100)
+    /// * `temp_c` - Current core temperature in degrees Celsius
+    /// * `stable` - Whether the system has been stable since the last call
+    ///
+    /// # Returns
+    /// * `Ok(i32)` - The voltage that was applied
+    /// * `Err(VoltageControllerError)` if error occurs
+    pub fn update_and_apply_feedback(
+        &mut self,
+        core_id: usize,
+        load: f32,
+        temp_c: f32,
+        stable: bool,
+    ) -> Result<i32, VoltageControllerError> {
+        if !self.active {
+            return Err(VoltageControllerError::NotStarted);
+        }
+
+        if core_id >= self.cores.len() {
+            return Err(VoltageControllerError::InvalidCoreId(core_id));
+        }
+
+        let mut margin = self.cores[core_id].config.safety_margin_mv;
+
+        if stable {
+            self.cores[core_id].consecutive_stable_ticks += 1;
+            if self.cores[core_id].consecutive_stable_ticks >= self.stability_decay_ticks {
+                margin -= self.stability_backoff_step_mv;
+                self.cores[core_id].consecutive_stable_ticks = 0;
+            }
+        } else {
+            margin += self.stability_backoff_step_mv;
+            self.cores[core_id].consecutive_stable_ticks = 0;
+        }
+
+        if temp_c > self.thermal_margin_ceiling_c {
+            margin += (self.thermal_margin_gain * (temp_c - self.thermal_margin_ceiling_c)).round() as i32;
+        }
+
+        let margin = margin.max(0);
+        self.cores[core_id].config.safety_margin_mv = margin;
+
+        let config = &self.cores[core_id].config;
+        let target_voltage = config.calculate_voltage(load) + margin;
+        let safe_voltage = clamp_value(target_voltage, &config.to_bounds());
+
         self.apply_voltage(core_id, safe_voltage)?;
-        
-        // Update state
         self.cores[core_id].current_voltage = safe_voltage;
         self.cores[core_id].last_load = load;
-        
+
         Ok(safe_voltage)
     }
-    
+
+    /// Update and apply voltage for every core in one sweep, without
+    /// letting one failing core abort the rest
+    ///
+    /// `loads[core_id]` is the load for that core; a sweep over every core
+    /// this controller manages calls [`Self::update_and_apply`] for it and
+    /// collects the outcome instead of returning on the first error. Use
+    /// [`format_update_all_errors`] to render a failure report.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<i32>)` - The voltage applied (or held) for every core, in
+    ///   `core_id` order, if all succeeded
+    /// * `Err(Vec<(usize, VoltageControllerError)>)` - `(core_id, error)`
+    ///   for every core that failed; cores that succeeded were still
+    ///   applied, just not included in this error list
+    pub fn update_all(&mut self, loads: &[f32]) -> Result<Vec<i32>, Vec<(usize, VoltageControllerError)>> {
+        let mut applied = Vec::with_capacity(loads.len());
+        let mut errors = Vec::new();
+
+        for (core_id, &load) in loads.iter().enumerate() {
+            match self.update_and_apply(core_id, load) {
+                Ok(voltage) => applied.push(voltage),
+                Err(e) => errors.push((core_id, e)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(applied)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Record pre-sleep state, optionally resetting every core to 0 mV
+    ///
+    /// The kernel resets CPU voltage offsets across a sleep/wake cycle on
+    /// handheld hardware, so the applied curve silently reverts regardless
+    /// of what this controller believes `current_voltage` is. `reset_to_zero`
+    /// lets a caller proactively zero the offset for a clean wake on
+    /// backends where leaving a stale offset applied during suspend itself
+    /// is undesirable; `on_resume` re-derives the correct voltage from
+    /// `last_load` afterward either way, so this is a safety/acoustics
+    /// choice rather than a correctness requirement.
+    ///
+    /// # Returns
+    /// * `Ok(())` if every requested reset applied successfully
+    /// * `Err(VoltageControllerError)` if a reset write failed
+    pub fn on_suspend(&mut self, reset_to_zero: bool) -> Result<(), VoltageControllerError> {
+        if reset_to_zero {
+            for core_id in 0..self.cores.len() {
+                self.apply_voltage(core_id, 0)?;
+                self.cores[core_id].current_voltage = 0;
+            }
+        }
+        Ok(())
+    }
+
+    /// Recompute and re-apply every core's voltage from its last observed
+    /// load, to restore the curve after a sleep/wake cycle reset it
+    ///
+    /// A no-op when the controller isn't [`Self::is_active`], since there's
+    /// nothing to restore. Bypasses the hysteresis band `update_and_apply`
+    /// uses - the hardware offset was just reset out from under
+    /// `current_voltage`, so there's no stale value worth holding against.
+    ///
+    /// # Returns
+    /// * `Ok(())` if every core's voltage was recomputed and re-applied
+    /// * `Err(VoltageControllerError)` if the backend's apply failed
+    pub fn on_resume(&mut self) -> Result<(), VoltageControllerError> {
+        if !self.active {
+            return Ok(());
+        }
+
+        for core_id in 0..self.cores.len() {
+            let config = &self.cores[core_id].config;
+            let target_voltage = config.calculate_voltage(self.cores[core_id].last_load);
+            let safe_voltage = clamp_value(target_voltage, &config.to_bounds());
+
+            self.apply_voltage(core_id, safe_voltage)?;
+            self.cores[core_id].current_voltage = safe_voltage;
+        }
+
+        Ok(())
+    }
+
     /// Get current voltage for a core
     ///
     /// # Arguments
@@ -379,10 +878,13 @@ impl VoltageController {
             .ok_or(VoltageControllerError::InvalidCoreId(core_id))
     }
     
-    /// Apply voltage offset to a specific core via sysfs
+    /// Apply a voltage offset to a specific core via `self.backend`
     ///
-    /// Writes to /sys/devices/system/cpu/cpuX/cpufreq/amd_pstate_max_freq_khz
-    /// or similar interface depending on platform.
+    /// Clamps `voltage_mv` to what the backend can actually apply first
+    /// (the same ratify-then-apply split [`VoltageBackend`] uses), so a
+    /// value that's valid for this controller's own bounds but not for the
+    /// backend's (e.g. ryzenadj's absolute range) still gets applied safely
+    /// instead of rejected outright.
     ///
     /// # Arguments
     /// * `core_id` - Core identifier
@@ -390,42 +892,30 @@ impl VoltageController {
     ///
     /// # Returns
     /// * `Ok(())` if successful
-    /// * `Err(VoltageControllerError)` if write fails
+    /// * `Err(VoltageControllerError)` if the backend's apply fails
     fn apply_voltage(&self, core_id: usize, voltage_mv: i32) -> Result<(), VoltageControllerError> {
-        // For AMD APUs, voltage control is typically done through ryzenadj
-        // or ACPI interfaces. This is a placeholder for the sysfs interface.
-        //
-        // In production, this would write to the appropriate sysfs file:
-        // /sys/devices/system/cpu/cpu{core_id}/cpufreq/amd_pstate_voltage_offset
-        //
-        // For now, we'll write to a test file if sysfs_base is not the default
-        
-        let voltage_path = self.sysfs_base
-            .join(format!("cpu{}", core_id))
-            .join("cpufreq")
-            .join("voltage_offset");
-        
-        // Only write if the path exists (for testing) or if we're in production
-        if voltage_path.parent().map(|p| p.exists()).unwrap_or(false) {
-            fs::write(&voltage_path, format!("{}", voltage_mv))?;
-        }
-        
-        // In production, this would also call ryzenadj or similar tool
-        // For testing, we just validate the write would succeed
-        
-        Ok(())
+        let mut offset = voltage_mv;
+        self.backend.clamp(core_id, &mut offset);
+        self.backend.apply(core_id, offset)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dynamic::device_limits::RangeLimit;
     use std::fs;
     use tempfile::TempDir;
-    
+
+    /// Shared fixture reproducing the old hardcoded `[-100, 0]` global range,
+    /// wide enough for every value these tests exercise
+    fn limits() -> DeviceLimits {
+        DeviceLimits::conservative_default(16)
+    }
+
     #[test]
     fn test_core_config_new_valid() {
-        let config = CoreConfig::new(0, -30, -15, 50.0);
+        let config = CoreConfig::new(0, -30, -15, 50.0, &limits());
         assert!(config.is_ok());
         let config = config.unwrap();
         assert_eq!(config.core_id, 0);
@@ -433,89 +923,101 @@ mod tests {
         assert_eq!(config.max_mv, -15);
         assert_eq!(config.threshold, 50.0);
     }
-    
+
     #[test]
     fn test_core_config_new_invalid_min_range() {
-        let config = CoreConfig::new(0, -150, -15, 50.0);
+        let config = CoreConfig::new(0, -150, -15, 50.0, &limits());
         assert!(config.is_err());
         assert!(config.unwrap_err().contains("min_mv"));
-        
-        let config = CoreConfig::new(0, 10, -15, 50.0);
+
+        let config = CoreConfig::new(0, 10, -15, 50.0, &limits());
         assert!(config.is_err());
     }
-    
+
     #[test]
     fn test_core_config_new_invalid_max_range() {
-        let config = CoreConfig::new(0, -30, -150, 50.0);
+        let config = CoreConfig::new(0, -30, -150, 50.0, &limits());
         assert!(config.is_err());
         assert!(config.unwrap_err().contains("max_mv"));
     }
-    
+
+    #[test]
+    fn test_core_config_new_reports_device_limit_bound() {
+        let narrow = DeviceLimits {
+            cores: vec![RangeLimit { min: Some(-20), max: Some(0) }],
+            step: 1,
+            smt_capable: false,
+            count: 1,
+        };
+        let err = CoreConfig::new(0, -30, -15, 50.0, &narrow).unwrap_err();
+        assert!(err.contains("[-20, 0]"));
+    }
+
     #[test]
     fn test_core_config_new_invalid_ordering() {
         // min_mv must be <= max_mv (more negative <= less negative)
-        let config = CoreConfig::new(0, -20, -40, 50.0);
+        let config = CoreConfig::new(0, -20, -40, 50.0, &limits());
         assert!(config.is_err());
         let err_msg = config.unwrap_err();
         assert!(err_msg.contains("min_mv"));
         assert!(err_msg.contains("max_mv"));
     }
-    
+
     #[test]
     fn test_core_config_new_invalid_threshold() {
-        let config = CoreConfig::new(0, -30, -15, -10.0);
+        let config = CoreConfig::new(0, -30, -15, -10.0, &limits());
         assert!(config.is_err());
         assert!(config.unwrap_err().contains("threshold"));
-        
-        let config = CoreConfig::new(0, -30, -15, 150.0);
+
+        let config = CoreConfig::new(0, -30, -15, 150.0, &limits());
         assert!(config.is_err());
     }
-    
+
     #[test]
     fn test_core_config_calculate_voltage_below_threshold() {
-        let config = CoreConfig::new(0, -30, -15, 50.0).unwrap();
-        
+        let config = CoreConfig::new(0, -30, -15, 50.0, &limits()).unwrap();
+
         // Below threshold should return min_mv
         assert_eq!(config.calculate_voltage(0.0), -30);
         assert_eq!(config.calculate_voltage(25.0), -30);
         assert_eq!(config.calculate_voltage(50.0), -30);
     }
-    
+
     #[test]
     fn test_core_config_calculate_voltage_above_threshold() {
-        let config = CoreConfig::new(0, -30, -15, 50.0).unwrap();
-        
+        let config = CoreConfig::new(0, -30, -15, 50.0, &limits()).unwrap();
+
         // At 75% (halfway between 50 and 100), should be halfway between -30 and -15
         let voltage = config.calculate_voltage(75.0);
         assert_eq!(voltage, -22); // -30 + (15 * 0.5) = -22.5 -> -22
-        
+
         // At 100%, should be max_mv
         assert_eq!(config.calculate_voltage(100.0), -15);
     }
-    
+
     #[test]
     fn test_core_config_calculate_voltage_clamping() {
-        let config = CoreConfig::new(0, -30, -15, 50.0).unwrap();
-        
+        let config = CoreConfig::new(0, -30, -15, 50.0, &limits()).unwrap();
+
         // Values outside [0, 100] should be clamped
         assert_eq!(config.calculate_voltage(-10.0), -30);
         assert_eq!(config.calculate_voltage(150.0), -15);
     }
-    
+
     #[test]
     fn test_core_config_calculate_voltage_threshold_at_100() {
-        let config = CoreConfig::new(0, -30, -15, 100.0).unwrap();
-        
+        let config = CoreConfig::new(0, -30, -15, 100.0, &limits()).unwrap();
+
         // Threshold at 100% means always use min_mv
         assert_eq!(config.calculate_voltage(0.0), -30);
         assert_eq!(config.calculate_voltage(50.0), -30);
         assert_eq!(config.calculate_voltage(100.0), -30);
     }
-    
+
     #[test]
     fn test_core_config_calculate_voltage_threshold_at_0() {
-        let config = CoreConfig::new(0, -30, -15, 0.0).unwrap();
-        
+        let config = CoreConfig::new(0, -30, -15, 0.0, &limits()).unwrap();
+
         // Threshold at 0% means always interpolate
         assert_eq!(config.calculate_voltage(0.0), -30);
         assert_eq!(config.calculate_voltage(50.0), -22); // Halfway
@@ -542,7 +1044,7 @@ mod tests {
     fn test_voltage_controller_set_core_config() {
         let mut controller = VoltageController::new(4);
         
-        let config = CoreConfig::new(1, -40, -20, 60.0).unwrap();
+        let config = CoreConfig::new(1, -40, -20, 60.0, &limits()).unwrap();
         let result = controller.set_core_config(config.clone());
         assert!(result.is_ok());
         
@@ -556,7 +1058,7 @@ mod tests {
     fn test_voltage_controller_set_core_config_invalid_id() {
         let mut controller = VoltageController::new(4);
         
-        let config = CoreConfig::new(10, -30, -15, 50.0).unwrap();
+        let config = CoreConfig::new(10, -30, -15, 50.0, &limits()).unwrap();
         let result = controller.set_core_config(config);
         assert!(result.is_err());
         match result {
@@ -615,9 +1117,9 @@ mod tests {
         controller.start().unwrap();
         
         // Set custom config for core 0
-        let config = CoreConfig::new(0, -30, -15, 50.0).unwrap();
+        let config = CoreConfig::new(0, -30, -15, 50.0, &limits()).unwrap();
         controller.set_core_config(config).unwrap();
-        
+
         // Apply voltage at 75% load (should be halfway between -30 and -15)
         let result = controller.update_and_apply(0, 75.0);
         assert!(result.is_ok());
@@ -628,12 +1130,98 @@ mod tests {
         assert_eq!(controller.get_current_voltage(0).unwrap(), -22);
     }
     
+    #[test]
+    fn test_hold_or_track_holds_within_band() {
+        assert_eq!(hold_or_track(-20, -18, 5), -20);
+        assert_eq!(hold_or_track(-20, -25, 5), -20);
+        assert_eq!(hold_or_track(-20, -20, 5), -20);
+    }
+
+    #[test]
+    fn test_hold_or_track_tracks_past_band_edge() {
+        // Target escapes above the band: track it, keeping band_mv of margin
+        assert_eq!(hold_or_track(-20, -10, 5), -15);
+        // Target escapes below the band: same, on the other side
+        assert_eq!(hold_or_track(-20, -30, 5), -25);
+    }
+
+    #[test]
+    fn test_hold_or_track_zero_band_tracks_exactly() {
+        assert_eq!(hold_or_track(-20, -18, 0), -18);
+        assert_eq!(hold_or_track(-20, -20, 0), -20);
+    }
+
+    #[test]
+    fn test_core_config_with_hysteresis_mv() {
+        let config = CoreConfig::new(0, -30, -15, 50.0, &limits())
+            .unwrap()
+            .with_hysteresis_mv(4);
+        assert_eq!(config.hysteresis_mv, 4);
+    }
+
+    #[test]
+    fn test_core_config_default_hysteresis_mv_is_zero() {
+        let config = CoreConfig::new(0, -30, -15, 50.0, &limits()).unwrap();
+        assert_eq!(config.hysteresis_mv, 0);
+    }
+
+    #[test]
+    fn test_voltage_controller_dithering_load_within_band_produces_no_writes() {
+        let temp_dir = TempDir::new().unwrap();
+        let sysfs_base = temp_dir.path().to_path_buf();
+        let cpu_dir = sysfs_base.join("cpu0").join("cpufreq");
+        fs::create_dir_all(&cpu_dir).unwrap();
+        let voltage_path = cpu_dir.join("voltage_offset");
+
+        let mut controller = VoltageController::with_sysfs_base(1, sysfs_base);
+        controller.start().unwrap();
+
+        let config = CoreConfig::new(0, -30, -15, 50.0, &limits())
+            .unwrap()
+            .with_hysteresis_mv(3);
+        controller.set_core_config(config).unwrap();
+
+        // 75% load -> target -22mV; settle onto it first.
+        let settled = controller.update_and_apply(0, 75.0).unwrap();
+        assert_eq!(settled, -22);
+        fs::remove_file(&voltage_path).unwrap();
+
+        // Dither the load around 75% so the target wobbles by 1mV, well
+        // within the 3mV band: the applied voltage must not move, and the
+        // sysfs file must never be rewritten.
+        for load in [74.0, 76.0, 75.0, 73.0, 77.0] {
+            let voltage = controller.update_and_apply(0, load).unwrap();
+            assert_eq!(voltage, -22);
+            assert!(!voltage_path.exists(), "dithering inside the band must not write to sysfs");
+        }
+    }
+
+    #[test]
+    fn test_voltage_controller_crossing_band_edge_tracks_target() {
+        let mut controller = VoltageController::new(1);
+        controller.start().unwrap();
+
+        let config = CoreConfig::new(0, -30, -15, 50.0, &limits())
+            .unwrap()
+            .with_hysteresis_mv(3);
+        controller.set_core_config(config).unwrap();
+
+        controller.update_and_apply(0, 75.0).unwrap(); // settles at -22
+        assert_eq!(controller.get_current_voltage(0).unwrap(), -22);
+
+        // 100% load -> target -15mV, well past the 3mV band: should track
+        // target minus the band width, not jump straight to -15.
+        let voltage = controller.update_and_apply(0, 100.0).unwrap();
+        assert_eq!(voltage, -18);
+        assert_eq!(controller.get_current_voltage(0).unwrap(), -18);
+    }
+
     #[test]
     fn test_voltage_controller_get_all_configs() {
         let mut controller = VoltageController::new(2);
         
-        let config0 = CoreConfig::new(0, -40, -20, 60.0).unwrap();
-        let config1 = CoreConfig::new(1, -35, -25, 55.0).unwrap();
+        let config0 = CoreConfig::new(0, -40, -20, 60.0, &limits()).unwrap();
+        let config1 = CoreConfig::new(1, -35, -25, 55.0, &limits()).unwrap();
         
         controller.set_core_config(config0.clone()).unwrap();
         controller.set_core_config(config1.clone()).unwrap();
@@ -643,4 +1231,322 @@ mod tests {
         assert_eq!(all_configs[0].min_mv, -40);
         assert_eq!(all_configs[1].min_mv, -35);
     }
+
+    #[test]
+    fn test_with_curve_interpolates_between_explicit_points() {
+        let config = CoreConfig::new(0, -30, -15, 50.0, &limits())
+            .unwrap()
+            .with_curve(vec![(0.0, -40), (40.0, -40), (80.0, -10), (100.0, -10)], &limits())
+            .unwrap();
+
+        assert_eq!(config.calculate_voltage(0.0), -40);
+        assert_eq!(config.calculate_voltage(40.0), -40);
+        // Halfway between 40% (-40mV) and 80% (-10mV)
+        assert_eq!(config.calculate_voltage(60.0), -25);
+        assert_eq!(config.calculate_voltage(90.0), -10);
+        assert_eq!(config.calculate_voltage(100.0), -10);
+    }
+
+    #[test]
+    fn test_with_curve_sorts_unsorted_points() {
+        let config = CoreConfig::new(0, -30, -15, 50.0, &limits())
+            .unwrap()
+            .with_curve(vec![(100.0, -10), (0.0, -40)], &limits())
+            .unwrap();
+
+        assert_eq!(config.calculate_voltage(0.0), -40);
+        assert_eq!(config.calculate_voltage(50.0), -25);
+        assert_eq!(config.calculate_voltage(100.0), -10);
+    }
+
+    #[test]
+    fn test_with_curve_rejects_empty() {
+        let err = CoreConfig::new(0, -30, -15, 50.0, &limits())
+            .unwrap()
+            .with_curve(vec![], &limits())
+            .unwrap_err();
+        assert!(err.contains("at least one point"));
+    }
+
+    #[test]
+    fn test_with_curve_rejects_duplicate_load() {
+        let err = CoreConfig::new(0, -30, -15, 50.0, &limits())
+            .unwrap()
+            .with_curve(vec![(50.0, -30), (50.0, -10)], &limits())
+            .unwrap_err();
+        assert!(err.contains("duplicate"));
+    }
+
+    #[test]
+    fn test_with_curve_clamps_load_and_mv_to_bounds() {
+        let narrow = DeviceLimits {
+            cores: vec![RangeLimit { min: Some(-20), max: Some(0) }],
+            step: 1,
+            smt_capable: false,
+            count: 1,
+        };
+        let config = CoreConfig::new(0, -20, -10, 50.0, &narrow)
+            .unwrap()
+            .with_curve(vec![(-10.0, -100), (150.0, 50)], &narrow)
+            .unwrap();
+
+        // Load clamped into [0, 100] and mV clamped into the device's [-20, 0]
+        assert_eq!(config.calculate_voltage(0.0), -20);
+        assert_eq!(config.calculate_voltage(100.0), 0);
+    }
+
+    #[test]
+    fn test_new_calculate_voltage_matches_old_two_segment_curve() {
+        // Regression check: CoreConfig::new's default curve must reproduce
+        // the original flat-then-linear calculate_voltage exactly.
+        let config = CoreConfig::new(0, -30, -15, 50.0, &limits()).unwrap();
+        assert_eq!(config.calculate_voltage(0.0), -30);
+        assert_eq!(config.calculate_voltage(50.0), -30);
+        assert_eq!(config.calculate_voltage(75.0), -22);
+        assert_eq!(config.calculate_voltage(100.0), -15);
+    }
+
+    #[test]
+    fn test_save_and_load_profile_applies_all_configs() {
+        let dir = TempDir::new().unwrap();
+        let mut controller = VoltageController::new(2).with_profiles_path(dir.path().join("profiles.json"));
+
+        controller.set_core_config(CoreConfig::new(0, -40, -20, 60.0, &limits()).unwrap()).unwrap();
+        controller.set_core_config(CoreConfig::new(1, -35, -25, 55.0, &limits()).unwrap()).unwrap();
+        controller.save_profile("heavy-game").unwrap();
+
+        // Reset to the defaults, then restore via the saved profile
+        let mut controller = VoltageController::new(2).with_profiles_path(dir.path().join("profiles.json"));
+        let id = controller.list_profiles()[0].id_num;
+        controller.load_profile(id).unwrap();
+
+        assert_eq!(controller.get_core_config(0).unwrap().min_mv, -40);
+        assert_eq!(controller.get_core_config(1).unwrap().min_mv, -35);
+    }
+
+    #[test]
+    fn test_list_profiles_reflects_saved_names() {
+        let dir = TempDir::new().unwrap();
+        let controller = VoltageController::new(1).with_profiles_path(dir.path().join("profiles.json"));
+
+        controller.save_profile("light-game").unwrap();
+        controller.save_profile("heavy-game").unwrap();
+
+        let mut names: Vec<String> = controller.list_profiles().into_iter().map(|p| p.name).collect();
+        names.sort();
+        assert_eq!(names, vec!["heavy-game".to_string(), "light-game".to_string()]);
+    }
+
+    #[test]
+    fn test_load_profile_missing_id_errors() {
+        let dir = TempDir::new().unwrap();
+        let mut controller = VoltageController::new(1).with_profiles_path(dir.path().join("profiles.json"));
+
+        let result = controller.load_profile(0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_load_profile_rejects_config_violating_device_limits_without_partial_apply() {
+        let dir = TempDir::new().unwrap();
+        let narrow = DeviceLimits {
+            cores: vec![RangeLimit { min: Some(-20), max: Some(0) }, RangeLimit { min: Some(-20), max: Some(0) }],
+            step: 1,
+            smt_capable: false,
+            count: 2,
+        };
+
+        // Save a profile under the wide default limits...
+        let mut wide_controller =
+            VoltageController::with_device_limits(2, limits()).with_profiles_path(dir.path().join("profiles.json"));
+        wide_controller.set_core_config(CoreConfig::new(0, -40, -20, 60.0, &limits()).unwrap()).unwrap();
+        wide_controller.save_profile("too-aggressive").unwrap();
+
+        // ...then try to load it into a controller with narrower limits.
+        let mut narrow_controller = VoltageController::with_device_limits(2, narrow.clone())
+            .with_profiles_path(dir.path().join("profiles.json"));
+        let id = narrow_controller.list_profiles()[0].id_num;
+        let result = narrow_controller.load_profile(id);
+        assert!(result.is_err());
+
+        // Core 1's untouched default config must remain, confirming no
+        // partial apply happened before validation failed on core 0.
+        assert_eq!(narrow_controller.get_core_config(1).unwrap().min_mv, -30);
+    }
+
+    #[test]
+    fn test_update_all_applies_every_core_on_success() {
+        let mut controller = VoltageController::new(3);
+        controller.start().unwrap();
+
+        let result = controller.update_all(&[60.0, 70.0, 80.0]).unwrap();
+        assert_eq!(result.len(), 3);
+        for (core_id, voltage) in result.iter().enumerate() {
+            assert_eq!(controller.get_current_voltage(core_id).unwrap(), *voltage);
+        }
+    }
+
+    #[test]
+    fn test_update_all_collects_errors_without_failing_fast() {
+        let mut controller = VoltageController::new(2);
+        // Deliberately not started: every call to update_and_apply fails
+        // with NotStarted, but update_all must still report both cores
+        // instead of stopping at the first one.
+        let errors = controller.update_all(&[50.0, 50.0]).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].0, 0);
+        assert_eq!(errors[1].0, 1);
+    }
+
+    #[test]
+    fn test_update_all_applies_succeeding_cores_despite_a_failing_one() {
+        let mut controller = VoltageController::new(2);
+        controller.start().unwrap();
+
+        // loads.len() > num_cores: core 2 doesn't exist, so it fails while
+        // cores 0 and 1 still get applied.
+        let errors = controller.update_all(&[50.0, 50.0, 50.0]).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 2);
+        match &errors[0].1 {
+            VoltageControllerError::InvalidCoreId(2) => {}
+            other => panic!("expected InvalidCoreId(2), got {:?}", other),
+        }
+
+        assert!(controller.get_current_voltage(0).is_ok());
+        assert!(controller.get_current_voltage(1).is_ok());
+    }
+
+    #[test]
+    fn test_format_update_all_errors_renders_one_line_per_core() {
+        let mut controller = VoltageController::new(1);
+        let errors = controller.update_all(&[50.0]).unwrap_err();
+
+        let rendered = format_update_all_errors(&errors);
+        assert!(rendered.contains("1 core(s)"));
+        assert!(rendered.contains("core 0"));
+        assert!(rendered.contains("Controller not started"));
+    }
+
+    #[test]
+    fn test_on_resume_reapplies_voltage_from_last_load_when_active() {
+        let mut controller = VoltageController::new(1);
+        controller.start().unwrap();
+        controller.update_and_apply(0, 75.0).unwrap();
+        assert_eq!(controller.get_current_voltage(0).unwrap(), -22);
+
+        // Simulate the kernel clobbering the offset across sleep/wake.
+        controller.on_suspend(true).unwrap();
+        assert_eq!(controller.get_current_voltage(0).unwrap(), 0);
+
+        controller.on_resume().unwrap();
+        assert_eq!(controller.get_current_voltage(0).unwrap(), -22);
+    }
+
+    #[test]
+    fn test_on_resume_is_noop_when_not_active() {
+        let mut controller = VoltageController::new(1);
+        controller.on_resume().unwrap();
+        assert_eq!(controller.get_current_voltage(0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_on_suspend_without_reset_leaves_current_voltage() {
+        let mut controller = VoltageController::new(1);
+        controller.start().unwrap();
+        controller.update_and_apply(0, 75.0).unwrap();
+
+        controller.on_suspend(false).unwrap();
+        assert_eq!(controller.get_current_voltage(0).unwrap(), -22);
+    }
+
+    #[test]
+    fn test_update_and_apply_feedback_backs_off_on_instability() {
+        let mut controller = VoltageController::new(1);
+        controller.start().unwrap();
+
+        // Base at 75% load is -22mV; an unstable tick should back it off by
+        // the default 5mV step, landing at -17.
+        let voltage = controller.update_and_apply_feedback(0, 75.0, 40.0, false).unwrap();
+        assert_eq!(voltage, -17);
+        assert_eq!(controller.get_core_config(0).unwrap().safety_margin_mv, 5);
+    }
+
+    #[test]
+    fn test_update_and_apply_feedback_accumulates_margin_across_unstable_ticks() {
+        let mut controller = VoltageController::new(1);
+        controller.start().unwrap();
+
+        controller.update_and_apply_feedback(0, 75.0, 40.0, false).unwrap();
+        let voltage = controller.update_and_apply_feedback(0, 75.0, 40.0, false).unwrap();
+        // -22 + 10mV margin = -12, but this core's own max_mv (-15) caps how
+        // far the margin can pull it back; the learned margin itself still
+        // keeps growing.
+        assert_eq!(voltage, -15);
+        assert_eq!(controller.get_core_config(0).unwrap().safety_margin_mv, 10);
+    }
+
+    #[test]
+    fn test_update_and_apply_feedback_adds_proportional_thermal_margin() {
+        let mut controller = VoltageController::new(1);
+        controller.start().unwrap();
+
+        // 5 degrees over the default 85C ceiling, at the default 1.0 gain,
+        // adds 5mV on top of the stable base.
+        let voltage = controller.update_and_apply_feedback(0, 75.0, 90.0, true).unwrap();
+        assert_eq!(voltage, -17); // -22 + 5mV
+        assert_eq!(controller.get_core_config(0).unwrap().safety_margin_mv, 5);
+    }
+
+    #[test]
+    fn test_update_and_apply_feedback_decays_margin_after_consecutive_stable_ticks() {
+        let mut controller = VoltageController::new(1).with_stability_governor(5, 85.0, 1.0, 3);
+        controller.start().unwrap();
+
+        controller.update_and_apply_feedback(0, 75.0, 40.0, false).unwrap();
+        assert_eq!(controller.get_core_config(0).unwrap().safety_margin_mv, 5);
+
+        // 3 consecutive stable, cool ticks should decay the margin back to 0.
+        for _ in 0..3 {
+            controller.update_and_apply_feedback(0, 75.0, 40.0, true).unwrap();
+        }
+        assert_eq!(controller.get_core_config(0).unwrap().safety_margin_mv, 0);
+    }
+
+    #[test]
+    fn test_update_and_apply_feedback_clamps_to_core_bounds() {
+        let mut controller = VoltageController::new(1);
+        controller.start().unwrap();
+
+        // Repeated instability keeps growing the margin, but the applied
+        // voltage must never pull back past this core's own max_mv (-15),
+        // same as `update_and_apply`'s safety clamp.
+        for _ in 0..10 {
+            controller.update_and_apply_feedback(0, 75.0, 40.0, false).unwrap();
+        }
+        assert_eq!(controller.get_current_voltage(0).unwrap(), -15);
+    }
+
+    #[test]
+    fn test_update_and_apply_feedback_not_started() {
+        let mut controller = VoltageController::new(1);
+        let result = controller.update_and_apply_feedback(0, 50.0, 40.0, true);
+        assert!(matches!(result, Err(VoltageControllerError::NotStarted)));
+    }
+
+    #[test]
+    fn test_safety_margin_mv_persists_across_save_and_load_profile() {
+        let dir = TempDir::new().unwrap();
+        let mut controller = VoltageController::new(1).with_profiles_path(dir.path().join("profiles.json"));
+        controller.start().unwrap();
+
+        controller.update_and_apply_feedback(0, 75.0, 40.0, false).unwrap();
+        assert_eq!(controller.get_core_config(0).unwrap().safety_margin_mv, 5);
+        controller.save_profile("crash-prone-game").unwrap();
+
+        let mut fresh = VoltageController::new(1).with_profiles_path(dir.path().join("profiles.json"));
+        let id = fresh.list_profiles()[0].id_num;
+        fresh.load_profile(id).unwrap();
+        assert_eq!(fresh.get_core_config(0).unwrap().safety_margin_mv, 5);
+    }
 }
@@ -0,0 +1,175 @@
+//! Named voltage profile variants, for per-game switching
+//!
+//! PowerTools-style tuning keys a tuning curve to an app/game id so users
+//! can keep an aggressive undervolt for a light indie game and a
+//! conservative one for a demanding title, switching instantly when the
+//! foreground app changes. [`VoltageProfile`] bundles a name with a full set
+//! of per-core [`CoreConfig`]s and a [`ProfileStore`] persists them to a
+//! single JSON file, keyed by a stable id derived from the name.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::voltage_controller::CoreConfig;
+
+/// A saved voltage profile: one named set of per-core configs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoltageProfile {
+    /// Stable numeric id derived from `name` via [`profile_id`]
+    pub id: u64,
+    /// Human-readable profile name, typically an app/game id
+    pub name: String,
+    /// Per-core configuration this profile applies
+    pub configs: Vec<CoreConfig>,
+}
+
+/// Summary of a saved profile, for listing without loading every core config
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VariantInfo {
+    /// String form of `id_num`, for callers that key variants by string
+    pub id: String,
+    /// Human-readable profile name
+    pub name: String,
+    /// Stable numeric id derived from `name` via [`profile_id`]
+    pub id_num: u64,
+}
+
+/// Derive the stable numeric id a profile named `name` is stored and looked
+/// up under - saving the same name twice replaces the earlier profile
+/// rather than accumulating duplicates
+pub fn profile_id(name: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    name.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// On-disk JSON store of [`VoltageProfile`]s
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ProfileStore {
+    profiles: Vec<VoltageProfile>,
+}
+
+impl ProfileStore {
+    /// Load the store at `path`, starting empty if the file is missing or
+    /// isn't valid JSON - losing a malformed store is far less harmful than
+    /// refusing to save a new profile over it
+    fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn write(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, json)
+    }
+}
+
+/// Save `configs` as a profile named `name` to the JSON store at `path`,
+/// replacing any existing profile with the same derived id
+pub fn save_profile(path: &Path, name: &str, configs: Vec<CoreConfig>) -> io::Result<()> {
+    let mut store = ProfileStore::load(path);
+    let id = profile_id(name);
+    store.profiles.retain(|p| p.id != id);
+    store.profiles.push(VoltageProfile { id, name: name.to_string(), configs });
+    store.write(path)
+}
+
+/// List every profile saved at `path`, without their per-core configs
+pub fn list_profiles(path: &Path) -> Vec<VariantInfo> {
+    ProfileStore::load(path)
+        .profiles
+        .into_iter()
+        .map(|p| VariantInfo { id: p.id.to_string(), name: p.name, id_num: p.id })
+        .collect()
+}
+
+/// Load the profile stored under numeric id `id_num` at `path`
+pub fn load_profile(path: &Path, id_num: u64) -> Option<VoltageProfile> {
+    ProfileStore::load(path).profiles.into_iter().find(|p| p.id == id_num)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::voltage_controller::CoreConfig;
+    use crate::dynamic::device_limits::DeviceLimits;
+    use tempfile::TempDir;
+
+    fn limits() -> DeviceLimits {
+        DeviceLimits::conservative_default(4)
+    }
+
+    #[test]
+    fn test_profile_id_is_stable_for_same_name() {
+        assert_eq!(profile_id("light-game"), profile_id("light-game"));
+        assert_ne!(profile_id("light-game"), profile_id("heavy-game"));
+    }
+
+    #[test]
+    fn test_save_then_load_profile_round_trips() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("profiles.json");
+
+        let configs = vec![CoreConfig::new(0, -40, -20, 60.0, &limits()).unwrap()];
+        save_profile(&path, "heavy-game", configs.clone()).unwrap();
+
+        let id = profile_id("heavy-game");
+        let loaded = load_profile(&path, id).unwrap();
+        assert_eq!(loaded.name, "heavy-game");
+        assert_eq!(loaded.configs, configs);
+    }
+
+    #[test]
+    fn test_save_profile_replaces_existing_same_name() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("profiles.json");
+
+        save_profile(&path, "light-game", vec![CoreConfig::new(0, -10, -5, 50.0, &limits()).unwrap()]).unwrap();
+        save_profile(&path, "light-game", vec![CoreConfig::new(0, -20, -10, 50.0, &limits()).unwrap()]).unwrap();
+
+        let profiles = list_profiles(&path);
+        assert_eq!(profiles.len(), 1);
+
+        let loaded = load_profile(&path, profile_id("light-game")).unwrap();
+        assert_eq!(loaded.configs[0].min_mv, -20);
+    }
+
+    #[test]
+    fn test_list_profiles_reports_id_name_and_id_num() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("profiles.json");
+
+        save_profile(&path, "alpha", vec![CoreConfig::new(0, -10, -5, 50.0, &limits()).unwrap()]).unwrap();
+        save_profile(&path, "beta", vec![CoreConfig::new(0, -10, -5, 50.0, &limits()).unwrap()]).unwrap();
+
+        let mut profiles = list_profiles(&path);
+        profiles.sort_by(|a, b| a.name.cmp(&b.name));
+
+        assert_eq!(profiles[0].name, "alpha");
+        assert_eq!(profiles[0].id_num, profile_id("alpha"));
+        assert_eq!(profiles[0].id, profile_id("alpha").to_string());
+        assert_eq!(profiles[1].name, "beta");
+    }
+
+    #[test]
+    fn test_load_profile_missing_id_is_none() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("profiles.json");
+        assert!(load_profile(&path, 0).is_none());
+    }
+
+    #[test]
+    fn test_list_profiles_missing_file_is_empty() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nope.json");
+        assert!(list_profiles(&path).is_empty());
+    }
+}
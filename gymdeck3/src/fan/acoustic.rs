@@ -114,6 +114,11 @@ impl AcousticProfile {
             AcousticProfile::MaxCooling,
         ]
     }
+
+    /// Render in the same form `from_name`/`parse_acoustic_profile` accepts
+    pub fn to_config_string(&self) -> String {
+        self.to_string()
+    }
 }
 
 impl std::fmt::Display for AcousticProfile {
@@ -8,10 +8,21 @@
 //! - Safety overrides for critical temperatures
 
 use std::collections::VecDeque;
+use std::time::Duration;
 
 use super::hwmon::{HwmonDevice, HwmonError, FanMode};
-use super::safety::{FanSafetyLimits, SafetyOverride, apply_safety_override, check_safety_override};
+use super::backend::{FanBackend, find_fan_backend};
+use super::safety::{
+    FanSafetyLimits, SafetyOverride, ZERO_RPM_MAX_TEMP_C, apply_safety_override,
+    apply_safety_override_filtered, check_safety_override,
+    FanHealth, FanHealthStatus, FanHealthModel, DEFAULT_FAN_HEALTH_PWM_FLOOR,
+    DEFAULT_FAN_HEALTH_RPM_THRESHOLD, DEFAULT_FAN_HEALTH_TICK_THRESHOLD, DEFAULT_FAN_HEALTH_MODEL,
+    DEFAULT_FAN_HEALTH_STALL_FRACTION, DEFAULT_FAN_HEALTH_MIN_MEASURABLE_PWM,
+};
 use super::smoother::{PWMSmoother, DEFAULT_RAMP_TIME_SEC};
+use super::pid::PidFanController;
+use super::temp_filter::TempFilter;
+use super::autotune::AutotuneGains;
 
 /// Default temperature hysteresis in °C
 pub const DEFAULT_HYSTERESIS_TEMP: i32 = 2;
@@ -19,6 +30,33 @@ pub const DEFAULT_HYSTERESIS_TEMP: i32 = 2;
 /// Default number of samples for moving average smoothing
 pub const DEFAULT_SMOOTHING_SAMPLES: usize = 5;
 
+/// Default median-of-N spike rejector window; 0 disables it
+pub const DEFAULT_MEDIAN_WINDOW: usize = 0;
+
+/// Default number of consecutive `FanHealthStatus::Stalled` ticks after
+/// which `update()` gives up forcing max PWM and disables manual control as
+/// a last-resort safety fallback, returning the fan to BIOS/EC control
+pub const DEFAULT_PERSISTENT_STALL_RELEASE_TICKS: u32 = 10;
+
+/// Thermostat-style default shape for a `NormalizedPolynomial` curve: a
+/// purely quadratic ramp (`k_a`) from a small idle floor (`k_c`) up to 100%
+/// at `t_max`, with no linear term. Distinct from
+/// `FanCurve::default_normalized_coefficients`, which instead matches the
+/// built-in piecewise `Default` curve's slope.
+pub const DEFAULT_NORMALIZED_DUTY_K_A: f32 = 1.0;
+pub const DEFAULT_NORMALIZED_DUTY_K_B: f32 = 0.0;
+pub const DEFAULT_NORMALIZED_DUTY_K_C: f32 = 0.04;
+
+/// Default widened hysteresis (°C) applied only while temperature is
+/// falling, matching Marlin's adaptive fan slowing: a bigger dead-band
+/// resists audible down-ramp chatter while up-ramps keep using the
+/// (narrower) `hysteresis_temp` immediately, for safety
+pub const DEFAULT_DOWN_HYSTERESIS_TEMP: i32 = 4;
+
+/// Default cap on how many PWM units a single falling-temperature tick may
+/// reduce the commanded duty by; 0 disables the cap
+pub const DEFAULT_FAN_SLOWDOWN_STEP_MAX: u8 = 0;
+
 /// Minimum PWM value
 pub const MIN_PWM: u8 = 0;
 
@@ -43,11 +81,46 @@ impl FanCurvePoint {
     }
 }
 
+/// Minimum supported fan curve temperature, °C
+const CURVE_TEMP_MIN: i32 = 0;
+/// Maximum supported fan curve temperature, °C
+const CURVE_TEMP_MAX: i32 = 100;
+
+/// Determinant of a 3x3 matrix, used by `FanCurve::from_anchors` to solve
+/// the Vandermonde system fitting a quadratic through 3 anchor points
+fn det3(m: [[f32; 3]; 3]) -> f32 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// Internal curve representation: either piecewise points or a quadratic
+/// polynomial. Kept private so `FanCurve`'s public API can evolve (or add a
+/// third representation) without breaking callers.
+#[derive(Debug, Clone)]
+enum FanCurveShape {
+    /// Sorted points (by temperature)
+    Points(Vec<FanCurvePoint>),
+    /// `speed = k_a*t^2 + k_b*t + k_c`
+    Polynomial { k_a: f32, k_b: f32, k_c: f32 },
+    /// `speed = 100 * (x*(x*k_a + k_b) + k_c)`, where `x = (t - t_min) /
+    /// (t_max - t_min)` is clamped to `[0, 1]` - mirrors the `fcurve <a> <b>
+    /// <c>` control some thermostat hardware exposes, tuned over a
+    /// configurable `[t_min, t_max]` window rather than `Polynomial`'s fixed
+    /// per-degree coefficients over the full supported range.
+    NormalizedPolynomial {
+        k_a: f32,
+        k_b: f32,
+        k_c: f32,
+        t_min: i32,
+        t_max: i32,
+    },
+}
+
 /// Fan curve definition with interpolation
 #[derive(Debug, Clone)]
 pub struct FanCurve {
-    /// Sorted points (by temperature)
-    points: Vec<FanCurvePoint>,
+    shape: FanCurveShape,
 }
 
 impl FanCurve {
@@ -56,16 +129,31 @@ impl FanCurve {
     /// Points will be sorted by temperature. At least 2 points required.
     ///
     /// # Errors
-    /// Returns error if fewer than 2 points provided
+    /// Returns error if fewer than 2 points are provided, or if any point's
+    /// temperature falls outside the supported `[CURVE_TEMP_MIN,
+    /// CURVE_TEMP_MAX]` design-spec range - a point submitted well outside
+    /// that range (e.g. from a bad config file) would otherwise be accepted
+    /// and silently clamped at evaluation time instead of being caught here.
     pub fn new(mut points: Vec<FanCurvePoint>) -> Result<Self, String> {
         if points.len() < 2 {
             return Err("Fan curve requires at least 2 points".to_string());
         }
 
+        if let Some(p) = points.iter().find(|p| {
+            p.temp_c < CURVE_TEMP_MIN || p.temp_c > CURVE_TEMP_MAX
+        }) {
+            return Err(format!(
+                "Fan curve point temperature {}°C is outside the supported {}-{}°C range",
+                p.temp_c, CURVE_TEMP_MIN, CURVE_TEMP_MAX
+            ));
+        }
+
         // Sort by temperature
         points.sort_by_key(|p| p.temp_c);
 
-        Ok(FanCurve { points })
+        Ok(FanCurve {
+            shape: FanCurveShape::Points(points),
+        })
     }
 
     /// Create curve from (temp, speed%) tuples
@@ -77,46 +165,326 @@ impl FanCurve {
         Self::new(points)
     }
 
-    /// Get the number of points in the curve
+    /// Create a smooth quadratic curve from coefficients: `speed = k_a*t^2 +
+    /// k_b*t + k_c`, evaluated in f32 then clamped to `[0, 100]` and
+    /// rounded. Avoids the audible "steps" piecewise-linear segments can
+    /// produce, at the cost of only three tunable numbers instead of many
+    /// points.
+    ///
+    /// # Errors
+    /// Returns an error if the curve decreases anywhere across the
+    /// supported 0-100°C range (i.e. its derivative `2*k_a*t + k_b` goes
+    /// negative), which would make the fan spin down as it gets hotter.
+    pub fn from_coefficients(k_a: f32, k_b: f32, k_c: f32) -> Result<Self, String> {
+        Self::check_monotonic(k_a, k_b)?;
+        Ok(FanCurve {
+            shape: FanCurveShape::Polynomial { k_a, k_b, k_c },
+        })
+    }
+
+    /// Reject coefficients whose derivative goes negative anywhere over
+    /// `[CURVE_TEMP_MIN, CURVE_TEMP_MAX]`. The derivative `2*k_a*t + k_b` is
+    /// linear in `t`, so checking both endpoints is sufficient.
+    fn check_monotonic(k_a: f32, k_b: f32) -> Result<(), String> {
+        let derivative_at = |t: f32| 2.0 * k_a * t + k_b;
+        let lo = derivative_at(CURVE_TEMP_MIN as f32);
+        let hi = derivative_at(CURVE_TEMP_MAX as f32);
+        if lo < 0.0 || hi < 0.0 {
+            return Err(format!(
+                "Fan curve coefficients k_a={}, k_b={} are non-monotonic over {}-{}°C",
+                k_a, k_b, CURVE_TEMP_MIN, CURVE_TEMP_MAX
+            ));
+        }
+        Ok(())
+    }
+
+    /// Create a curve from normalized-x coefficients: `speed = 100 *
+    /// (x*(x*k_a + k_b) + k_c)`, where `x = (temp_c - t_min) / (t_max -
+    /// t_min)` is clamped to `[0, 1]`. Unlike `from_coefficients`, the
+    /// quadratic is tuned over the curve's own `[t_min, t_max]` window
+    /// instead of the fixed `[CURVE_TEMP_MIN, CURVE_TEMP_MAX]` range, so the
+    /// same `k_a`/`k_b`/`k_c` triple can be reused across curves that ramp
+    /// over different temperature spans.
+    ///
+    /// # Errors
+    /// Returns an error if `t_min >= t_max`, or if the curve decreases
+    /// anywhere over `x in [0, 1]` (i.e. its derivative `2*k_a*x + k_b` goes
+    /// negative at `x=0` or `x=1`).
+    pub fn from_normalized_coefficients(
+        k_a: f32,
+        k_b: f32,
+        k_c: f32,
+        t_min: i32,
+        t_max: i32,
+    ) -> Result<Self, String> {
+        if t_min >= t_max {
+            return Err(format!(
+                "normalized curve range t_min={} must be less than t_max={}",
+                t_min, t_max
+            ));
+        }
+        Self::check_monotonic_normalized(k_a, k_b)?;
+        Ok(FanCurve {
+            shape: FanCurveShape::NormalizedPolynomial {
+                k_a,
+                k_b,
+                k_c,
+                t_min,
+                t_max,
+            },
+        })
+    }
+
+    /// Reject normalized coefficients whose derivative goes negative
+    /// anywhere over `x in [0, 1]`. The derivative `2*k_a*x + k_b` is linear
+    /// in `x`, so checking both endpoints is sufficient.
+    fn check_monotonic_normalized(k_a: f32, k_b: f32) -> Result<(), String> {
+        let derivative_at = |x: f32| 2.0 * k_a * x + k_b;
+        let lo = derivative_at(0.0);
+        let hi = derivative_at(1.0);
+        if lo < 0.0 || hi < 0.0 {
+            return Err(format!(
+                "normalized fan curve coefficients k_a={}, k_b={} are non-monotonic over x=0-1",
+                k_a, k_b
+            ));
+        }
+        Ok(())
+    }
+
+    /// Coefficients matching the slope of the built-in `Default` curve
+    /// (40°C -> 20% through 85°C -> 100%), for seeding a
+    /// `NormalizedPolynomial` curve with a sane starting point: `(k_a, k_b,
+    /// k_c, t_min, t_max)`.
+    pub fn default_normalized_coefficients() -> (f32, f32, f32, i32, i32) {
+        (0.0, 0.8, 0.2, 40, 85)
+    }
+
+    /// Create a linear curve, `speed% = k_a*temp + k_b`, matching the
+    /// affine `k_a`/`k_b` transfer-function model instead of asking for a
+    /// quadratic term. Equivalent to `from_coefficients(0.0, k_a, k_b)`,
+    /// but additionally rejects a slope/intercept pair whose zero-RPM
+    /// region (speed == 0) would extend above `ZERO_RPM_MAX_TEMP_C` - a
+    /// curve that still reads "fan off" past that temperature is no longer
+    /// the safe Zero-RPM mode `FanSafetyLimits` expects, just a stuck fan.
+    ///
+    /// # Errors
+    /// Same as `from_coefficients` (non-monotonic), plus an error if the
+    /// curve's zero-crossing temperature exceeds `ZERO_RPM_MAX_TEMP_C`.
+    pub fn from_linear(k_a: f32, k_b: f32) -> Result<Self, String> {
+        let curve = Self::from_coefficients(0.0, k_a, k_b)?;
+
+        if k_a.abs() > f32::EPSILON {
+            let zero_crossing = -k_b / k_a;
+            if zero_crossing > ZERO_RPM_MAX_TEMP_C as f32 {
+                return Err(format!(
+                    "linear curve reads 0% speed up to {:.1}°C, above the {}°C Zero-RPM safety ceiling",
+                    zero_crossing, ZERO_RPM_MAX_TEMP_C
+                ));
+            }
+        } else if k_b <= 0.0 {
+            return Err(format!(
+                "linear curve is flat at 0% speed for every temperature, above the {}°C Zero-RPM safety ceiling",
+                ZERO_RPM_MAX_TEMP_C
+            ));
+        }
+
+        Ok(curve)
+    }
+
+    /// Fit a quadratic curve through 2 or 3 `(temp_c, speed_percent)` anchor
+    /// points, so a UI can offer simple curve editing ("drag a couple of
+    /// points") without asking the user to reason about `k_a`/`k_b`/`k_c`
+    /// directly.
+    ///
+    /// Two anchors fit a line (`k_a = 0`); three fit an exact quadratic via
+    /// the anchors' Vandermonde system. The result still goes through
+    /// [`Self::from_coefficients`]'s monotonicity check, so a fit that would
+    /// dip as temperature rises is rejected the same as a hand-written one.
+    ///
+    /// # Errors
+    /// Returns an error if fewer than 2 or more than 3 anchors are given, if
+    /// two anchors share a temperature, or if the fitted curve is
+    /// non-monotonic over the supported range.
+    pub fn from_anchors(anchors: &[(i32, u8)]) -> Result<Self, String> {
+        let (k_a, k_b, k_c) = match anchors.len() {
+            2 => {
+                let (t1, s1) = (anchors[0].0 as f32, anchors[0].1 as f32);
+                let (t2, s2) = (anchors[1].0 as f32, anchors[1].1 as f32);
+                if (t2 - t1).abs() < f32::EPSILON {
+                    return Err("anchor points must have distinct temperatures".to_string());
+                }
+                let k_b = (s2 - s1) / (t2 - t1);
+                let k_c = s1 - k_b * t1;
+                (0.0, k_b, k_c)
+            }
+            3 => {
+                let (t1, s1) = (anchors[0].0 as f32, anchors[0].1 as f32);
+                let (t2, s2) = (anchors[1].0 as f32, anchors[1].1 as f32);
+                let (t3, s3) = (anchors[2].0 as f32, anchors[2].1 as f32);
+
+                let m = [[t1 * t1, t1, 1.0], [t2 * t2, t2, 1.0], [t3 * t3, t3, 1.0]];
+                let det_m = det3(m);
+                if det_m.abs() < f32::EPSILON {
+                    return Err("anchor points must have distinct temperatures".to_string());
+                }
+
+                let m_a = [[s1, t1, 1.0], [s2, t2, 1.0], [s3, t3, 1.0]];
+                let m_b = [[t1 * t1, s1, 1.0], [t2 * t2, s2, 1.0], [t3 * t3, s3, 1.0]];
+                let m_c = [[t1 * t1, t1, s1], [t2 * t2, t2, s2], [t3 * t3, t3, s3]];
+
+                (det3(m_a) / det_m, det3(m_b) / det_m, det3(m_c) / det_m)
+            }
+            n => return Err(format!("from_anchors requires 2 or 3 anchor points, got {}", n)),
+        };
+
+        Self::from_coefficients(k_a, k_b, k_c)
+    }
+
+    /// Get this curve's polynomial coefficients, if it was built from
+    /// (or switched to) a quadratic representation. `None` for a
+    /// `NormalizedPolynomial` curve too - use `normalized_coefficients` for
+    /// that representation's distinct `(k_a, k_b, k_c, t_min, t_max)` shape.
+    pub fn coefficients(&self) -> Option<(f32, f32, f32)> {
+        match self.shape {
+            FanCurveShape::Polynomial { k_a, k_b, k_c } => Some((k_a, k_b, k_c)),
+            FanCurveShape::Points(_) | FanCurveShape::NormalizedPolynomial { .. } => None,
+        }
+    }
+
+    /// Get this curve's normalized-x polynomial coefficients, if it was
+    /// built from (or switched to) that representation
+    pub fn normalized_coefficients(&self) -> Option<(f32, f32, f32, i32, i32)> {
+        match self.shape {
+            FanCurveShape::NormalizedPolynomial {
+                k_a,
+                k_b,
+                k_c,
+                t_min,
+                t_max,
+            } => Some((k_a, k_b, k_c, t_min, t_max)),
+            FanCurveShape::Points(_) | FanCurveShape::Polynomial { .. } => None,
+        }
+    }
+
+    /// Switch this curve to (or update) a quadratic polynomial
+    /// representation, discarding any previous points
+    ///
+    /// # Errors
+    /// Same as `from_coefficients`: rejects a non-monotonic curve
+    pub fn set_coefficients(&mut self, k_a: f32, k_b: f32, k_c: f32) -> Result<(), String> {
+        Self::check_monotonic(k_a, k_b)?;
+        self.shape = FanCurveShape::Polynomial { k_a, k_b, k_c };
+        Ok(())
+    }
+
+    /// Switch this curve to (or update) a normalized-x polynomial
+    /// representation, discarding any previous points
+    ///
+    /// # Errors
+    /// Same as `from_normalized_coefficients`: rejects `t_min >= t_max` or a
+    /// non-monotonic curve
+    pub fn set_normalized_coefficients(
+        &mut self,
+        k_a: f32,
+        k_b: f32,
+        k_c: f32,
+        t_min: i32,
+        t_max: i32,
+    ) -> Result<(), String> {
+        if t_min >= t_max {
+            return Err(format!(
+                "normalized curve range t_min={} must be less than t_max={}",
+                t_min, t_max
+            ));
+        }
+        Self::check_monotonic_normalized(k_a, k_b)?;
+        self.shape = FanCurveShape::NormalizedPolynomial {
+            k_a,
+            k_b,
+            k_c,
+            t_min,
+            t_max,
+        };
+        Ok(())
+    }
+
+    /// Get the number of points in the curve; always 0 for a polynomial
+    /// or normalized-polynomial curve
     pub fn len(&self) -> usize {
-        self.points.len()
+        match &self.shape {
+            FanCurveShape::Points(points) => points.len(),
+            FanCurveShape::Polynomial { .. } | FanCurveShape::NormalizedPolynomial { .. } => 0,
+        }
     }
 
-    /// Check if curve is empty
+    /// Check if curve has no points; always true for a polynomial or
+    /// normalized-polynomial curve
     pub fn is_empty(&self) -> bool {
-        self.points.is_empty()
+        self.len() == 0
     }
 
-    /// Get all points (sorted by temperature)
+    /// Get all points (sorted by temperature); empty for a polynomial or
+    /// normalized-polynomial curve
     pub fn points(&self) -> &[FanCurvePoint] {
-        &self.points
+        match &self.shape {
+            FanCurveShape::Points(points) => points,
+            FanCurveShape::Polynomial { .. } | FanCurveShape::NormalizedPolynomial { .. } => &[],
+        }
     }
 
-    /// Calculate fan speed for a given temperature using linear interpolation
+    /// Calculate fan speed for a given temperature
     ///
+    /// For a points curve, uses linear interpolation:
     /// - Below lowest point: returns lowest point's speed
     /// - Above highest point: returns highest point's speed
     /// - Between points: linear interpolation
+    ///
+    /// For a polynomial curve, evaluates `k_a*t^2 + k_b*t + k_c`, clamped
+    /// to `[0, 100]` and rounded.
     pub fn calculate_speed(&self, temp_c: i32) -> u8 {
-        if self.points.is_empty() {
+        match &self.shape {
+            FanCurveShape::Points(points) => Self::calculate_speed_points(points, temp_c),
+            FanCurveShape::Polynomial { k_a, k_b, k_c } => {
+                Self::calculate_speed_polynomial(*k_a, *k_b, *k_c, temp_c)
+            }
+            FanCurveShape::NormalizedPolynomial {
+                k_a,
+                k_b,
+                k_c,
+                t_min,
+                t_max,
+            } => Self::calculate_speed_normalized_polynomial(*k_a, *k_b, *k_c, *t_min, *t_max, temp_c),
+        }
+    }
+
+    /// Calculate the PWM value (0-255) for a given temperature, in one
+    /// call - equivalent to `Self::speed_to_pwm(self.calculate_speed(temp_c))`,
+    /// for a caller that only cares about the final duty cycle and not the
+    /// intermediate speed percentage.
+    pub fn pwm_for_temp(&self, temp_c: i32) -> u8 {
+        Self::speed_to_pwm(self.calculate_speed(temp_c))
+    }
+
+    fn calculate_speed_points(points: &[FanCurvePoint], temp_c: i32) -> u8 {
+        if points.is_empty() {
             return 50; // Fallback
         }
 
         // Below lowest point
-        if temp_c <= self.points[0].temp_c {
-            return self.points[0].speed_percent;
+        if temp_c <= points[0].temp_c {
+            return points[0].speed_percent;
         }
 
         // Above highest point
-        let last = &self.points[self.points.len() - 1];
+        let last = &points[points.len() - 1];
         if temp_c >= last.temp_c {
             return last.speed_percent;
         }
 
         // Find surrounding points and interpolate
-        for i in 0..self.points.len() - 1 {
-            let p1 = &self.points[i];
-            let p2 = &self.points[i + 1];
+        for i in 0..points.len() - 1 {
+            let p1 = &points[i];
+            let p2 = &points[i + 1];
 
             if temp_c >= p1.temp_c && temp_c <= p2.temp_c {
                 return Self::interpolate(temp_c, p1, p2);
@@ -127,6 +495,28 @@ impl FanCurve {
         50
     }
 
+    fn calculate_speed_polynomial(k_a: f32, k_b: f32, k_c: f32, temp_c: i32) -> u8 {
+        let t = temp_c as f32;
+        let speed = k_a * t * t + k_b * t + k_c;
+        speed.clamp(0.0, 100.0).round() as u8
+    }
+
+    /// Evaluate the normalized-x quadratic `100 * (x*(x*k_a + k_b) + k_c)`
+    /// at `temp_c`, where `x = (temp_c - t_min) / (t_max - t_min)` is
+    /// clamped to `[0, 1]` before the Horner-form evaluation.
+    fn calculate_speed_normalized_polynomial(
+        k_a: f32,
+        k_b: f32,
+        k_c: f32,
+        t_min: i32,
+        t_max: i32,
+        temp_c: i32,
+    ) -> u8 {
+        let x = ((temp_c - t_min) as f32 / (t_max - t_min) as f32).clamp(0.0, 1.0);
+        let speed = 100.0 * (x * (x * k_a + k_b) + k_c);
+        speed.clamp(0.0, 100.0).round() as u8
+    }
+
     /// Linear interpolation between two points
     fn interpolate(temp_c: i32, p1: &FanCurvePoint, p2: &FanCurvePoint) -> u8 {
         let temp_range = p2.temp_c - p1.temp_c;
@@ -141,6 +531,108 @@ impl FanCurve {
         speed.clamp(0, 100) as u8
     }
 
+    /// Evaluate duty cycle at `temp_c` using monotone cubic Hermite
+    /// interpolation (Fritsch-Carlson), producing a smooth curve between
+    /// points instead of `calculate_speed`'s piecewise-linear segments.
+    ///
+    /// Tangents start as the average of the adjacent secant slopes, then
+    /// get scaled down per-segment so the curve never overshoots or
+    /// oscillates between the bracketing points (which would otherwise
+    /// cause the fan to "hunt"). Outside the point range this clamps to
+    /// the first/last point's duty, same as `calculate_speed`.
+    ///
+    /// For a polynomial curve there are no segments to spline between, so
+    /// this just evaluates the same quadratic `calculate_speed` does, in
+    /// `f64`.
+    pub fn evaluate(&self, temp_c: f64) -> f64 {
+        let points = match &self.shape {
+            FanCurveShape::Points(points) => points,
+            FanCurveShape::Polynomial { k_a, k_b, k_c } => {
+                let speed = *k_a as f64 * temp_c * temp_c + *k_b as f64 * temp_c + *k_c as f64;
+                return speed.clamp(0.0, 100.0);
+            }
+            FanCurveShape::NormalizedPolynomial {
+                k_a,
+                k_b,
+                k_c,
+                t_min,
+                t_max,
+            } => {
+                let x = ((temp_c - *t_min as f64) / (*t_max as f64 - *t_min as f64)).clamp(0.0, 1.0);
+                let speed = 100.0 * (x * (x * *k_a as f64 + *k_b as f64) + *k_c as f64);
+                return speed.clamp(0.0, 100.0);
+            }
+        };
+
+        let n = points.len();
+        if n == 0 {
+            return 50.0;
+        }
+        if n == 1 || temp_c <= points[0].temp_c as f64 {
+            return points[0].speed_percent as f64;
+        }
+        let last = &points[n - 1];
+        if temp_c >= last.temp_c as f64 {
+            return last.speed_percent as f64;
+        }
+
+        let xs: Vec<f64> = points.iter().map(|p| p.temp_c as f64).collect();
+        let ys: Vec<f64> = points.iter().map(|p| p.speed_percent as f64).collect();
+
+        // Secant slope of each segment
+        let mut secants = vec![0.0; n - 1];
+        for k in 0..n - 1 {
+            let dx = xs[k + 1] - xs[k];
+            secants[k] = if dx == 0.0 { 0.0 } else { (ys[k + 1] - ys[k]) / dx };
+        }
+
+        // Initial tangents: endpoints take the adjacent secant, interior
+        // points take the average of their two neighboring secants
+        let mut tangents = vec![0.0; n];
+        tangents[0] = secants[0];
+        tangents[n - 1] = secants[n - 2];
+        for k in 1..n - 1 {
+            tangents[k] = (secants[k - 1] + secants[k]) / 2.0;
+        }
+
+        // Enforce monotonicity per Fritsch-Carlson
+        for k in 0..n - 1 {
+            if secants[k] == 0.0 {
+                tangents[k] = 0.0;
+                tangents[k + 1] = 0.0;
+                continue;
+            }
+            let alpha = tangents[k] / secants[k];
+            let beta = tangents[k + 1] / secants[k];
+            let sum_sq = alpha * alpha + beta * beta;
+            if sum_sq > 9.0 {
+                let tau = 3.0 / sum_sq.sqrt();
+                tangents[k] = tau * alpha * secants[k];
+                tangents[k + 1] = tau * beta * secants[k];
+            }
+        }
+
+        let seg = (0..n - 1)
+            .find(|&k| temp_c >= xs[k] && temp_c <= xs[k + 1])
+            .unwrap_or(n - 2);
+        let dx = xs[seg + 1] - xs[seg];
+        let t = if dx == 0.0 { 0.0 } else { (temp_c - xs[seg]) / dx };
+
+        // Cubic Hermite basis functions
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+
+        let value = h00 * ys[seg]
+            + h10 * dx * tangents[seg]
+            + h01 * ys[seg + 1]
+            + h11 * dx * tangents[seg + 1];
+        value.clamp(0.0, 100.0)
+    }
+
     /// Convert speed percentage to PWM value (0-255)
     pub fn speed_to_pwm(speed_percent: u8) -> u8 {
         ((speed_percent.min(100) as u16 * 255) / 100) as u8
@@ -150,8 +642,186 @@ impl FanCurve {
     pub fn pwm_to_speed(pwm: u8) -> u8 {
         ((pwm as u16 * 100) / 255) as u8
     }
+
+    /// Convert speed percentage to PWM value, scaled into `[pwm_min,
+    /// pwm_max]` instead of the full 0-255 range - for devices whose
+    /// `HwmonDevice::pwm_min`/`pwm_max` differ from the driver default
+    pub fn speed_to_pwm_scaled(speed_percent: u8, pwm_min: u8, pwm_max: u8) -> u8 {
+        if pwm_max <= pwm_min {
+            return pwm_min;
+        }
+        let span = (pwm_max - pwm_min) as u16;
+        pwm_min + ((speed_percent.min(100) as u16 * span) / 100) as u8
+    }
+
+    /// Convert PWM value to speed percentage, scaled from `[pwm_min,
+    /// pwm_max]` instead of the full 0-255 range; the inverse of
+    /// `speed_to_pwm_scaled`
+    pub fn pwm_to_speed_scaled(pwm: u8, pwm_min: u8, pwm_max: u8) -> u8 {
+        if pwm_max <= pwm_min {
+            return 0;
+        }
+        let span = (pwm_max - pwm_min) as u16;
+        let offset = pwm.saturating_sub(pwm_min) as u16;
+        ((offset.min(span) * 100) / span) as u8
+    }
+
+    /// Calculate fan speed as a fractional percentage, e.g. `33.4`
+    ///
+    /// Mirrors `calculate_speed`, but keeps the interpolated value in
+    /// floating point instead of rounding to a whole percent, so curve
+    /// points can be evaluated at sub-percent resolution. This matters
+    /// most at the low end, where a single PWM step (≈0.39%) is a large
+    /// fraction of the usable range. Clamped to `[0.0, 100.0]`.
+    pub fn calculate_speed_f32(&self, temp_c: i32) -> f32 {
+        match &self.shape {
+            FanCurveShape::Points(points) => Self::calculate_speed_points_f32(points, temp_c),
+            FanCurveShape::Polynomial { k_a, k_b, k_c } => {
+                Self::calculate_speed_polynomial_f32(*k_a, *k_b, *k_c, temp_c)
+            }
+            FanCurveShape::NormalizedPolynomial {
+                k_a,
+                k_b,
+                k_c,
+                t_min,
+                t_max,
+            } => Self::calculate_speed_normalized_polynomial_f32(
+                *k_a, *k_b, *k_c, *t_min, *t_max, temp_c,
+            ),
+        }
+    }
+
+    fn calculate_speed_points_f32(points: &[FanCurvePoint], temp_c: i32) -> f32 {
+        if points.is_empty() {
+            return 50.0; // Fallback, matches calculate_speed_points
+        }
+
+        if temp_c <= points[0].temp_c {
+            return points[0].speed_percent as f32;
+        }
+
+        let last = &points[points.len() - 1];
+        if temp_c >= last.temp_c {
+            return last.speed_percent as f32;
+        }
+
+        for i in 0..points.len() - 1 {
+            let p1 = &points[i];
+            let p2 = &points[i + 1];
+
+            if temp_c >= p1.temp_c && temp_c <= p2.temp_c {
+                return Self::interpolate_f32(temp_c, p1, p2);
+            }
+        }
+
+        50.0
+    }
+
+    fn calculate_speed_polynomial_f32(k_a: f32, k_b: f32, k_c: f32, temp_c: i32) -> f32 {
+        let t = temp_c as f32;
+        let speed = k_a * t * t + k_b * t + k_c;
+        speed.clamp(0.0, 100.0)
+    }
+
+    /// Fractional-percent counterpart of
+    /// `calculate_speed_normalized_polynomial`, skipping the final rounding
+    fn calculate_speed_normalized_polynomial_f32(
+        k_a: f32,
+        k_b: f32,
+        k_c: f32,
+        t_min: i32,
+        t_max: i32,
+        temp_c: i32,
+    ) -> f32 {
+        let x = ((temp_c - t_min) as f32 / (t_max - t_min) as f32).clamp(0.0, 1.0);
+        let speed = 100.0 * (x * (x * k_a + k_b) + k_c);
+        speed.clamp(0.0, 100.0)
+    }
+
+    /// Fractional-percent linear interpolation between two points; the
+    /// `f32` counterpart of `interpolate`, which rounds to a whole percent
+    fn interpolate_f32(temp_c: i32, p1: &FanCurvePoint, p2: &FanCurvePoint) -> f32 {
+        let temp_range = p2.temp_c - p1.temp_c;
+        if temp_range == 0 {
+            return p1.speed_percent as f32;
+        }
+
+        let speed_range = p2.speed_percent as f32 - p1.speed_percent as f32;
+        let temp_offset = (temp_c - p1.temp_c) as f32;
+
+        let speed = p1.speed_percent as f32 + speed_range * temp_offset / temp_range as f32;
+        speed.clamp(0.0, 100.0)
+    }
+
+    /// Convert a fractional speed percentage to PWM (0-255)
+    ///
+    /// The `f32` counterpart of `speed_to_pwm`, for curve configs that want
+    /// sub-percent resolution instead of losing up to 1% to integer
+    /// rounding on the way to PWM.
+    pub fn speed_to_pwm_f32(speed_percent: f32) -> u8 {
+        let clamped = speed_percent.clamp(0.0, 100.0);
+        ((clamped / 100.0) * 255.0).round() as u8
+    }
+
+    /// Serialize to the 8-point ROG binary layout: 8 ascending temperature
+    /// bytes followed by 8 speed-percent bytes, so curves tuned in
+    /// DeckTune can be loaded into other ASUS ROG-compatible fan tools
+    ///
+    /// The curve's own temperature range (first/last point for a points
+    /// curve, `[0, 100]`°C for a polynomial one) is resampled down to
+    /// exactly `ROG_POINT_COUNT` evenly spaced temperatures, each paired
+    /// with `calculate_speed` at that temperature.
+    pub fn to_rog_bytes(&self) -> [u8; ROG_BYTE_LEN] {
+        let (temp_min, temp_max) = match &self.shape {
+            FanCurveShape::Points(points) => {
+                (points[0].temp_c, points[points.len() - 1].temp_c)
+            }
+            FanCurveShape::Polynomial { .. } => (CURVE_TEMP_MIN, CURVE_TEMP_MAX),
+            FanCurveShape::NormalizedPolynomial { t_min, t_max, .. } => (*t_min, *t_max),
+        };
+
+        let mut bytes = [0u8; ROG_BYTE_LEN];
+        for i in 0..ROG_POINT_COUNT {
+            let frac = i as f32 / (ROG_POINT_COUNT - 1) as f32;
+            let temp_c = temp_min as f32 + (temp_max - temp_min) as f32 * frac;
+            let temp_c = temp_c.round() as i32;
+            bytes[i] = temp_c.clamp(0, 255) as u8;
+            bytes[ROG_POINT_COUNT + i] = self.calculate_speed(temp_c);
+        }
+        bytes
+    }
+
+    /// Parse the 8-point ROG binary layout produced by `to_rog_bytes`
+    ///
+    /// # Errors
+    /// Returns an error if the 8 temperature bytes are not strictly
+    /// ascending, matching the format's documented point ordering
+    pub fn from_rog_bytes(bytes: &[u8; ROG_BYTE_LEN]) -> Result<FanCurve, String> {
+        let points: Vec<FanCurvePoint> = (0..ROG_POINT_COUNT)
+            .map(|i| {
+                FanCurvePoint::new(bytes[i] as i32, bytes[ROG_POINT_COUNT + i])
+            })
+            .collect();
+
+        for pair in points.windows(2) {
+            if pair[1].temp_c <= pair[0].temp_c {
+                return Err(format!(
+                    "ROG curve bytes must have strictly ascending temperatures, got {} then {}",
+                    pair[0].temp_c, pair[1].temp_c
+                ));
+            }
+        }
+
+        FanCurve::new(points)
+    }
 }
 
+/// Number of (temperature, speed) points in the ROG binary curve format
+pub const ROG_POINT_COUNT: usize = 8;
+
+/// Total byte length of the ROG binary curve format (8 temps + 8 speeds)
+pub const ROG_BYTE_LEN: usize = ROG_POINT_COUNT * 2;
+
 impl Default for FanCurve {
     /// Default curve: quiet at low temps, aggressive at high temps
     fn default() -> Self {
@@ -172,6 +842,14 @@ impl Default for FanCurve {
 pub struct FanControllerConfig {
     /// Temperature hysteresis in °C
     pub hysteresis_temp: i32,
+    /// Widened hysteresis (°C), applied instead of `hysteresis_temp` only
+    /// while the temperature trend is falling; see
+    /// `DEFAULT_DOWN_HYSTERESIS_TEMP`
+    pub down_hysteresis_temp: i32,
+    /// Maximum PWM a single falling-temperature tick may reduce the
+    /// commanded duty by; 0 disables the cap, letting duty drop as fast as
+    /// the curve/PID and `pwm_smoother` otherwise allow
+    pub fan_slowdown_step_max: u8,
     /// Number of samples for moving average
     pub smoothing_samples: usize,
     /// Safety limits
@@ -182,17 +860,59 @@ pub struct FanControllerConfig {
     pub pwm_smoothing_enabled: bool,
     /// PWM smoothing ramp time in seconds (0 to 255 PWM)
     pub pwm_ramp_time_sec: f32,
+    /// Median-of-N spike rejector window applied to raw temperature before
+    /// the moving average; 0 disables it
+    pub median_window: usize,
+    /// PID gains from the most recent successful `RelayAutotuner` run, if
+    /// any; `FanControlMode::Pid` should prefer these over the configured
+    /// defaults once present
+    pub autotuned_gains: Option<AutotuneGains>,
+    /// PWM to briefly kick to (and the threshold below which a kick
+    /// triggers) when starting the fan from a dead stop with a low target
+    /// that's too weak to reliably break rotor stiction; 0 disables the
+    /// kick entirely
+    pub spinup_pwm: u8,
+    /// How long to hold `spinup_pwm` before settling to the real target
+    pub spinup_duration_ms: u64,
+    /// Floor below which `update()` never commands a nonzero PWM, so the
+    /// curve/PID can't settle on a duty cycle too low to keep the fan
+    /// spinning once it's started; 0 disables the floor
+    pub min_running_pwm: u8,
+    /// Quadratic PWM→RPM model `update()` checks measured tach RPM against
+    /// to detect a stalled fan; see `FanHealthModel`
+    pub fan_health_model: FanHealthModel,
+    /// Fraction of `fan_health_model`'s expected RPM below which a tick
+    /// counts as stalled
+    pub fan_health_stall_fraction: f32,
+    /// Commanded PWM below which the stall check is skipped entirely,
+    /// because tach pulses are too sparse to read reliably down there
+    pub fan_health_min_measurable_pwm: u8,
+    /// Consecutive `FanHealthStatus::Stalled` ticks after which `update()`
+    /// disables manual control rather than keep forcing max PWM; 0 disables
+    /// this fallback (max PWM is still forced indefinitely)
+    pub persistent_stall_release_ticks: u32,
 }
 
 impl Default for FanControllerConfig {
     fn default() -> Self {
         FanControllerConfig {
             hysteresis_temp: DEFAULT_HYSTERESIS_TEMP,
+            down_hysteresis_temp: DEFAULT_DOWN_HYSTERESIS_TEMP,
+            fan_slowdown_step_max: DEFAULT_FAN_SLOWDOWN_STEP_MAX,
             smoothing_samples: DEFAULT_SMOOTHING_SAMPLES,
             safety_limits: FanSafetyLimits::default(),
             min_pwm_change: 3, // Don't write for changes < 3 PWM (~1%)
             pwm_smoothing_enabled: true,
             pwm_ramp_time_sec: DEFAULT_RAMP_TIME_SEC,
+            median_window: DEFAULT_MEDIAN_WINDOW,
+            autotuned_gains: None,
+            spinup_pwm: 0,
+            spinup_duration_ms: 500,
+            min_running_pwm: 0,
+            fan_health_model: DEFAULT_FAN_HEALTH_MODEL,
+            fan_health_stall_fraction: DEFAULT_FAN_HEALTH_STALL_FRACTION,
+            fan_health_min_measurable_pwm: DEFAULT_FAN_HEALTH_MIN_MEASURABLE_PWM,
+            persistent_stall_release_ticks: DEFAULT_PERSISTENT_STALL_RELEASE_TICKS,
         }
     }
 }
@@ -200,8 +920,11 @@ impl Default for FanControllerConfig {
 /// Current fan status
 #[derive(Debug, Clone)]
 pub struct FanStatus {
-    /// Current temperature in °C
+    /// Current temperature in °C, as read from the sensor
     pub temp_c: i32,
+    /// Temperature in °C after the median spike rejector and moving-average
+    /// filter, i.e. what the curve/hysteresis actually evaluated against
+    pub filtered_temp_c: i32,
     /// Current PWM value (0-255)
     pub pwm: u8,
     /// Current speed percentage (0-100)
@@ -212,29 +935,170 @@ pub struct FanStatus {
     pub rpm: Option<u32>,
     /// Whether safety override is active
     pub safety_override_active: bool,
+    /// Tachometer-based health classification from the most recent
+    /// `update()` tick
+    pub fan_health: FanHealthStatus,
+    /// Speed percentage after adaptive fan slowing's down-ramp step cap,
+    /// distinct from `speed_percent` (the final, hardware-written duty,
+    /// which also reflects `pwm_smoother`'s own time-based ramp) so users
+    /// can see the damping take effect in the status stream
+    pub effective_speed_percent: u8,
 }
 
 /// Main fan controller
-pub struct FanController {
-    /// Hwmon device handle
-    device: HwmonDevice,
+///
+/// Generic over `FanBackend` so it can drive a real `HwmonDevice` (the
+/// default) or a `SimulatedFanBackend` - or any other adapter - without any
+/// change to the control logic below.
+pub struct FanController<B: FanBackend = HwmonDevice> {
+    /// Backend handle (hwmon device, or a simulated/other adapter)
+    device: B,
     /// Fan curve
     curve: FanCurve,
+    /// PID setpoint control, used instead of `curve` when set
+    pid: Option<PidFanController>,
+    /// Low-pass filter applied to the temperature fed into the safety
+    /// override's proportional/high-temp region, if set; the critical fast
+    /// path always sees the raw reading regardless
+    temp_filter: Option<TempFilter>,
     /// Configuration
     config: FanControllerConfig,
+    /// Raw temperature history for the median spike rejector
+    spike_history: VecDeque<i32>,
     /// Temperature history for smoothing
     temp_history: VecDeque<i32>,
     /// Last stable temperature (for hysteresis)
     last_stable_temp: Option<i32>,
+    /// Last filtered temperature computed by `update()`, reported alongside
+    /// the raw reading in `status()`
+    last_filtered_temp: Option<i32>,
     /// Last written PWM value
     last_pwm: u8,
+    /// Duty after adaptive fan slowing's down-ramp step cap was applied on
+    /// the most recent `update()` tick, reported alongside `last_pwm` in
+    /// `status()`
+    last_effective_pwm: u8,
     /// Whether controller is active (in manual mode)
     active: bool,
     /// PWM smoother for gradual transitions
     pwm_smoother: PWMSmoother,
+    /// Lowest usable PWM for this device, captured from `HwmonDevice` at
+    /// construction - 0-100% curve/PID targets scale into `[pwm_min,
+    /// pwm_max]` rather than assuming the full 0-255 range
+    pwm_min: u8,
+    /// Highest usable PWM for this device, captured from `HwmonDevice` at
+    /// construction
+    pwm_max: u8,
+    /// Tachometer-based stall detector, fed commanded PWM and measured RPM
+    /// every `update()` tick
+    fan_health: FanHealth,
+    /// Health classification from the most recent `update()` tick, reported
+    /// alongside the live reading in `status()`
+    last_fan_health: FanHealthStatus,
+    /// Consecutive ticks `last_fan_health` has been `Stalled`, counted
+    /// toward `config.persistent_stall_release_ticks`
+    stalled_tick_streak: u32,
+    /// Snapshot saved by `pause()`, restored by `resume()` - `None` when not
+    /// currently paused
+    paused_state: Option<PausedState>,
+    /// Telemetry hook invoked with a `FanTick` at the end of every
+    /// `update()`, if registered via `set_observer()`
+    observer: Option<Box<dyn FnMut(&FanTick)>>,
+}
+
+/// Sign of the temperature trend between consecutive `update()` ticks,
+/// driving adaptive fan slowing's wider down-ramp dead-band and duty step
+/// cap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TempTrend {
+    Rising,
+    Falling,
+    Steady,
+}
+
+/// State snapshotted by `FanController::pause()` so `resume()` can put
+/// everything back exactly as it was before the override
+#[derive(Debug, Clone, Copy)]
+struct PausedState {
+    /// PWM commanded by the curve/PID at the moment `pause()` was called
+    target_pwm: u8,
+    /// `last_stable_temp` at the moment `pause()` was called
+    last_stable_temp: Option<i32>,
+}
+
+/// Per-tick telemetry snapshot passed to an observer registered via
+/// `FanController::set_observer()`
+///
+/// Exposes every intermediate stage of `update()`'s pipeline - smoothing,
+/// hysteresis latching, safety clamping - so a host can log or plot fan
+/// behavior without re-deriving the math.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FanTick {
+    /// Raw, unfiltered temperature reading this tick
+    pub raw_temp_c: i32,
+    /// Temperature after spike rejection and moving-average smoothing
+    pub smoothed_temp_c: i32,
+    /// Temperature after hysteresis latching - what the curve/PID actually
+    /// saw
+    pub effective_temp_c: i32,
+    /// Curve/PID target fan speed, 0-100, derived from the pre-safety
+    /// target PWM
+    pub target_speed: u8,
+    /// PWM after safety-override clamping, before smoothing
+    pub safe_pwm: u8,
+    /// PWM actually produced by the smoother (and stall-recovery override,
+    /// if any) this tick
+    pub smoothed_pwm: u8,
+    /// Whether `update()` actually wrote `smoothed_pwm` to the device, or
+    /// suppressed it as an insignificant change
+    pub write_occurred: bool,
+    /// Temperature-based safety override active this tick, if any
+    pub safety_override: SafetyOverride,
+    /// Tachometer-based health classification this tick
+    pub fan_health: FanHealthStatus,
+}
+
+/// Build a `PWMSmoother` reflecting `config`'s ramp/spin-kick/floor
+/// settings, clamped to this device's own `[pwm_min, pwm_max]` so a
+/// configured `min_running_pwm` can raise but never lower the device's
+/// native floor.
+///
+/// A plain function rather than a `FanController<B>` associated function
+/// since it depends only on `config`, not on the backend type `B` - keeping
+/// it free avoids the caller (and `FanController`'s own constructors)
+/// having to pin down `B` just to call it.
+fn build_smoother(config: &FanControllerConfig, pwm_min: u8, pwm_max: u8) -> PWMSmoother {
+    let mut smoother = PWMSmoother::new(config.pwm_ramp_time_sec);
+    if config.spinup_pwm > 0 {
+        smoother = smoother.with_spin_kick(
+            config.spinup_pwm,
+            config.spinup_pwm,
+            Duration::from_millis(config.spinup_duration_ms),
+        );
+    }
+    let running_floor = config.min_running_pwm.max(pwm_min);
+    if running_floor > 0 || pwm_max < MAX_PWM {
+        smoother = smoother.with_pwm_bounds(running_floor, pwm_max);
+    }
+    smoother
+}
+
+/// Build a `FanHealth` tracker reflecting `config`'s tachometer-based stall
+/// detection settings; free for the same reason as `build_smoother` above
+fn build_fan_health(config: &FanControllerConfig) -> FanHealth {
+    FanHealth::new(
+        DEFAULT_FAN_HEALTH_PWM_FLOOR,
+        DEFAULT_FAN_HEALTH_RPM_THRESHOLD,
+        DEFAULT_FAN_HEALTH_TICK_THRESHOLD,
+    )
+    .with_quadratic_model(
+        config.fan_health_model,
+        config.fan_health_stall_fraction,
+        config.fan_health_min_measurable_pwm,
+    )
 }
 
-impl FanController {
+impl FanController<HwmonDevice> {
     /// Create a new fan controller
     ///
     /// Automatically finds the Steam Deck hwmon device.
@@ -243,34 +1107,151 @@ impl FanController {
         Ok(Self::with_device(device))
     }
 
-    /// Create controller with a specific device (for testing)
-    pub fn with_device(device: HwmonDevice) -> Self {
+    /// Get the underlying device (for advanced operations)
+    pub fn device(&self) -> &HwmonDevice {
+        &self.device
+    }
+
+    /// Get mutable device reference
+    pub fn device_mut(&mut self) -> &mut HwmonDevice {
+        &mut self.device
+    }
+}
+
+impl FanController<Box<dyn FanBackend>> {
+    /// Create a controller over `find_fan_backend()`'s result: the real
+    /// hwmon device if this is a Steam Deck, else an in-memory
+    /// `SimulatedFanBackend`. Lets a daemon run unconditionally instead of
+    /// having to handle `new()`'s `HwmonError` itself.
+    pub fn new_with_fallback() -> Self {
+        Self::with_device(find_fan_backend())
+    }
+}
+
+impl<B: FanBackend> FanController<B> {
+    /// Create controller with a specific backend (for testing, or to drive
+    /// a `SimulatedFanBackend`/other adapter)
+    pub fn with_device(device: B) -> Self {
+        let pwm_min = device.pwm_min();
+        let pwm_max = device.pwm_max();
+        let config = FanControllerConfig::default();
+        let pwm_smoother = build_smoother(&config, pwm_min, pwm_max);
+        let fan_health = build_fan_health(&config);
         FanController {
             device,
             curve: FanCurve::default(),
-            config: FanControllerConfig::default(),
+            pid: None,
+            temp_filter: None,
+            config,
+            spike_history: VecDeque::with_capacity(DEFAULT_MEDIAN_WINDOW),
             temp_history: VecDeque::with_capacity(DEFAULT_SMOOTHING_SAMPLES),
             last_stable_temp: None,
+            last_filtered_temp: None,
             last_pwm: 0,
+            last_effective_pwm: 0,
             active: false,
-            pwm_smoother: PWMSmoother::default(),
+            pwm_smoother,
+            pwm_min,
+            pwm_max,
+            fan_health,
+            last_fan_health: FanHealthStatus::NotAvailable,
+            stalled_tick_streak: 0,
+            paused_state: None,
+            observer: None,
         }
     }
 
+
+    /// This device's usable PWM range, as detected from `pwm1_min`/`pwm1_max`
+    /// (or `(MIN_PWM, MAX_PWM)` if the device doesn't expose them)
+    pub fn pwm_range(&self) -> (u8, u8) {
+        (self.pwm_min, self.pwm_max)
+    }
+
+    /// Tachometer-based health classification from the most recent
+    /// `update()` tick
+    pub fn fan_health_status(&self) -> FanHealthStatus {
+        self.last_fan_health
+    }
+
+    /// Consecutive stalled ticks seen so far by the fan-health tracker
+    pub fn fan_health_consecutive_ticks(&self) -> u32 {
+        self.fan_health.consecutive_ticks()
+    }
+
+    /// Whether the most recent `update()` tick latched a confirmed stall
+    ///
+    /// Equivalent to `self.fan_health_status() == FanHealthStatus::Stalled`;
+    /// a convenience for a caller that only wants a yes/no fault flag to
+    /// drive a UI indicator or a fallback decision, without matching on the
+    /// full `FanHealthStatus` classification.
+    pub fn fan_fault(&self) -> bool {
+        self.last_fan_health == FanHealthStatus::Stalled
+    }
+
     /// Set the fan curve
     pub fn set_curve(&mut self, curve: FanCurve) {
         self.curve = curve;
     }
 
+    /// Switch to PID setpoint control, taking over from the fan curve until
+    /// `clear_pid` is called
+    pub fn set_pid(&mut self, pid: PidFanController) {
+        self.pid = Some(pid);
+    }
+
+    /// Drop PID control and fall back to the fan curve
+    pub fn clear_pid(&mut self) {
+        self.pid = None;
+    }
+
+    /// Whether PID setpoint control is active (instead of the fan curve)
+    pub fn is_pid_active(&self) -> bool {
+        self.pid.is_some()
+    }
+
+    /// Enable low-pass filtering of the temperature fed into the safety
+    /// override's proportional/high-temp region
+    pub fn set_temp_filter(&mut self, filter: TempFilter) {
+        self.temp_filter = Some(filter);
+    }
+
+    /// Disable temperature filtering and fall back to the raw reading for
+    /// the safety override
+    pub fn clear_temp_filter(&mut self) {
+        self.temp_filter = None;
+    }
+
     /// Set configuration
     pub fn set_config(&mut self, config: FanControllerConfig) {
-        // Resize history buffer if needed
+        // Resize history buffers if needed
         while self.temp_history.len() > config.smoothing_samples {
             self.temp_history.pop_front();
         }
-        // Update PWM smoother if ramp time changed
-        if (config.pwm_ramp_time_sec - self.config.pwm_ramp_time_sec).abs() > 0.01 {
-            self.pwm_smoother = PWMSmoother::new(config.pwm_ramp_time_sec);
+        while self.spike_history.len() > config.median_window {
+            self.spike_history.pop_front();
+        }
+        // Rebuild the PWM smoother if anything that shapes its ramp/kick
+        // behavior changed, so the curve/spin-kick/bounds settings below
+        // always reflect the latest config rather than whatever the
+        // smoother happened to be constructed with previously.
+        let ramp_changed = (config.pwm_ramp_time_sec - self.config.pwm_ramp_time_sec).abs() > 0.01;
+        let spinup_changed = config.spinup_pwm != self.config.spinup_pwm
+            || config.spinup_duration_ms != self.config.spinup_duration_ms;
+        let floor_changed = config.min_running_pwm != self.config.min_running_pwm;
+        if ramp_changed || spinup_changed || floor_changed {
+            self.pwm_smoother = build_smoother(&config, self.pwm_min, self.pwm_max);
+        }
+        // Rebuild the fan-health tracker if its model changed, so a
+        // reconfigured model takes effect immediately rather than waiting
+        // for the next `FanController` to be constructed. This also resets
+        // the consecutive-stall streak, same as the smoother rebuild above
+        // resets ramp/kick progress.
+        let fan_health_changed = config.fan_health_model != self.config.fan_health_model
+            || config.fan_health_stall_fraction != self.config.fan_health_stall_fraction
+            || config.fan_health_min_measurable_pwm != self.config.fan_health_min_measurable_pwm;
+        if fan_health_changed {
+            self.fan_health = build_fan_health(&config);
         }
         self.config = config;
     }
@@ -279,6 +1260,9 @@ impl FanController {
     pub fn enable(&mut self) -> Result<(), HwmonError> {
         self.device.set_mode(FanMode::Manual)?;
         self.active = true;
+        if let Some(pid) = &mut self.pid {
+            pid.reset();
+        }
         Ok(())
     }
 
@@ -286,9 +1270,14 @@ impl FanController {
     pub fn disable(&mut self) -> Result<(), HwmonError> {
         self.device.set_mode(FanMode::Auto)?;
         self.active = false;
+        self.spike_history.clear();
         self.temp_history.clear();
         self.last_stable_temp = None;
+        self.last_filtered_temp = None;
         self.pwm_smoother.reset();
+        if let Some(pid) = &mut self.pid {
+            pid.reset();
+        }
         Ok(())
     }
 
@@ -297,6 +1286,65 @@ impl FanController {
         self.active
     }
 
+    /// Pause fan output for acoustic A/B testing (e.g. listening for coil
+    /// whine, or a quiet-benchmark segment), ramping down like a normal
+    /// target change rather than stopping abruptly
+    ///
+    /// `update()` automatically cancels the pause if temperature reaches
+    /// `HIGH_TEMP_C` or above, so acoustic testing can never suppress
+    /// necessary cooling.
+    pub fn pause_fan(&mut self) {
+        self.pwm_smoother.pause();
+    }
+
+    /// Resume from a pause, restoring the prior target exactly
+    pub fn resume_fan(&mut self) {
+        self.pwm_smoother.resume();
+    }
+
+    /// Whether fan output is currently paused
+    pub fn is_fan_paused(&self) -> bool {
+        self.pwm_smoother.is_paused()
+    }
+
+    /// Momentarily override fan output to `level`, saving enough state for
+    /// `resume()` to put it back exactly as it was
+    ///
+    /// Unlike `pause_fan()` (which only ramps to silent), `level` is
+    /// caller-chosen - silent for a photo/benchmark moment, or max for a
+    /// thermal-sensitive operation - and unlike `disable()`, `active` stays
+    /// true and control is never handed back to the BIOS: `update()` keeps
+    /// tracking temperature underneath the override so nothing is lost. A
+    /// no-op if already paused, so a second `pause()` can't clobber the
+    /// saved state.
+    pub fn pause(&mut self, level: u8) -> Result<(), HwmonError> {
+        if self.paused_state.is_none() {
+            self.paused_state = Some(PausedState {
+                target_pwm: self.pwm_smoother.target(),
+                last_stable_temp: self.last_stable_temp,
+            });
+        }
+        self.pwm_smoother.force_immediate(level);
+        self.device.set_pwm(level)?;
+        self.last_pwm = level;
+        Ok(())
+    }
+
+    /// Resume from `pause()`, restoring the snapshotted target and
+    /// hysteresis anchor and letting the smoother ramp back to the
+    /// curve-commanded PWM. A no-op if not currently paused via `pause()`.
+    pub fn resume(&mut self) {
+        if let Some(state) = self.paused_state.take() {
+            self.last_stable_temp = state.last_stable_temp;
+            self.pwm_smoother.set_target(state.target_pwm);
+        }
+    }
+
+    /// Whether fan output is currently overridden via `pause()`
+    pub fn is_paused(&self) -> bool {
+        self.paused_state.is_some()
+    }
+
     /// Get current status
     pub fn status(&self) -> Result<FanStatus, HwmonError> {
         let temp_c = self.device.read_temp_c()?;
@@ -308,11 +1356,18 @@ impl FanController {
 
         Ok(FanStatus {
             temp_c,
+            filtered_temp_c: self.last_filtered_temp.unwrap_or(temp_c),
             pwm,
-            speed_percent: FanCurve::pwm_to_speed(pwm),
+            speed_percent: FanCurve::pwm_to_speed_scaled(pwm, self.pwm_min, self.pwm_max),
             mode,
             rpm,
             safety_override_active: safety_override != SafetyOverride::None,
+            fan_health: self.last_fan_health,
+            effective_speed_percent: FanCurve::pwm_to_speed_scaled(
+                self.last_effective_pwm,
+                self.pwm_min,
+                self.pwm_max,
+            ),
         })
     }
 
@@ -328,65 +1383,217 @@ impl FanController {
         // Read current temperature
         let raw_temp = self.device.read_temp_c()?;
 
+        // Reject single-sample spikes before they ever reach the moving
+        // average, so a transient sensor glitch can't drag the average off
+        // course for `smoothing_samples` ticks.
+        let despiked_temp = self.reject_spikes(raw_temp);
+
         // Add to history for smoothing
-        self.temp_history.push_back(raw_temp);
+        self.temp_history.push_back(despiked_temp);
         while self.temp_history.len() > self.config.smoothing_samples {
             self.temp_history.pop_front();
         }
 
         // Calculate smoothed temperature (moving average)
         let smoothed_temp = if self.temp_history.is_empty() {
-            raw_temp
+            despiked_temp
         } else {
             let sum: i32 = self.temp_history.iter().sum();
             sum / self.temp_history.len() as i32
         };
 
-        // Apply hysteresis
-        let effective_temp = self.apply_hysteresis(smoothed_temp);
+        // Sign of the temperature trend since the last tick, driving
+        // adaptive fan slowing's wider down-ramp dead-band and duty step
+        // cap below. Up-ramps stay immediate (`TempTrend::Rising`/`Steady`
+        // behave like the cap and widened hysteresis were never applied).
+        let temp_trend = match self.last_filtered_temp {
+            Some(prev) if smoothed_temp < prev => TempTrend::Falling,
+            Some(prev) if smoothed_temp > prev => TempTrend::Rising,
+            _ => TempTrend::Steady,
+        };
+        self.last_filtered_temp = Some(smoothed_temp);
+
+        // Apply hysteresis, widened on a falling trend to resist audible
+        // down-ramp chatter; rising/steady keep the normal (narrower) band
+        // so the fan still responds immediately to a real heat-up.
+        let effective_temp = self.apply_hysteresis(smoothed_temp, temp_trend);
 
-        // Calculate target speed from curve
-        let target_speed = self.curve.calculate_speed(effective_temp);
-        let target_pwm = FanCurve::speed_to_pwm(target_speed);
+        // Calculate target PWM: PID setpoint control if active, otherwise
+        // the curve lookup
+        let target_pwm = match &mut self.pid {
+            Some(pid) => pid.update(effective_temp),
+            None => {
+                let target_speed = self.curve.calculate_speed(effective_temp);
+                FanCurve::speed_to_pwm_scaled(target_speed, self.pwm_min, self.pwm_max)
+            }
+        };
 
-        // Apply safety overrides
-        let safe_pwm = apply_safety_override(target_pwm, raw_temp, &self.config.safety_limits);
+        // Apply safety overrides. With a temp filter registered, the
+        // proportional/high-temp region is evaluated against the filtered
+        // reading to resist hunting from sensor noise, while the critical
+        // fast path still sees the raw temperature unconditionally.
+        let safe_pwm = match &mut self.temp_filter {
+            Some(filter) => {
+                let filtered_temp = filter.update(raw_temp);
+                apply_safety_override_filtered(target_pwm, raw_temp, filtered_temp, &self.config.safety_limits)
+            }
+            None => apply_safety_override(target_pwm, raw_temp, &self.config.safety_limits),
+        };
 
         // Check if safety override is active (critical temperature)
         let safety_override = check_safety_override(raw_temp, &self.config.safety_limits);
         let is_critical = matches!(safety_override, SafetyOverride::ForcePwm(_));
 
-        // Apply PWM smoothing if enabled and not in critical state
-        let final_pwm = if self.config.pwm_smoothing_enabled && !is_critical {
-            self.pwm_smoother.set_target(safe_pwm);
-            self.pwm_smoother.update()
-        } else if is_critical {
-            // Bypass smoothing for emergency - force immediate max PWM
-            self.pwm_smoother.force_immediate(safe_pwm);
-            safe_pwm
+        // Adaptive fan slowing: while temperature is falling, cap how far a
+        // single tick may lower the commanded duty below what was last
+        // written, so the fan doesn't audibly surge and dip on every small
+        // dip in load. Never caps an increase, and never caps at all once
+        // the critical override is active - cooling always wins.
+        let slowdown_capped_pwm = if !is_critical
+            && temp_trend == TempTrend::Falling
+            && self.config.fan_slowdown_step_max > 0
+            && safe_pwm < self.last_pwm
+        {
+            self.last_pwm
+                .saturating_sub(self.config.fan_slowdown_step_max)
+                .max(safe_pwm)
         } else {
             safe_pwm
         };
+        self.last_effective_pwm = slowdown_capped_pwm;
 
-        // Only write if change is significant (reduces sysfs spam)
-        let pwm_diff = (final_pwm as i16 - self.last_pwm as i16).unsigned_abs() as u8;
-        if pwm_diff >= self.config.min_pwm_change || final_pwm == 0 || final_pwm == 255 {
-            self.device.set_pwm(final_pwm)?;
-            self.last_pwm = final_pwm;
+        // A pause is for acoustic testing, not real cooling - cancel it the
+        // moment temperature reaches the high-temp band so it can never
+        // suppress necessary cooling.
+        if self.pwm_smoother.is_paused() && raw_temp >= self.config.safety_limits.high_temp {
+            self.pwm_smoother.resume();
         }
 
-        Ok(self.last_pwm)
-    }
+        // Same safety guard for a `pause()` override: a caller-chosen level
+        // (e.g. silent) must never be allowed to ride out a high-temp band.
+        if self.paused_state.is_some() && raw_temp >= self.config.safety_limits.high_temp {
+            self.resume();
+        }
 
-    /// Apply hysteresis to temperature
-    ///
-    /// Only updates the "stable" temperature if change exceeds hysteresis threshold.
-    fn apply_hysteresis(&mut self, temp: i32) -> i32 {
-        match self.last_stable_temp {
-            Some(last) => {
-                let diff = (temp - last).abs();
-                if diff >= self.config.hysteresis_temp {
-                    self.last_stable_temp = Some(temp);
+        // Apply PWM smoothing if enabled and not in critical state
+        let final_pwm = if is_critical {
+            // Bypass smoothing for emergency - force immediate max PWM
+            self.pwm_smoother.force_immediate(safe_pwm);
+            safe_pwm
+        } else if self.paused_state.is_some() {
+            // Hold the override level in place - don't let the curve's
+            // fresh target creep back in through the smoother until
+            // `resume()` is called.
+            self.pwm_smoother.update()
+        } else if self.pwm_smoother.is_paused() {
+            // Keep progressing the pause ramp-to-zero already in flight
+            // instead of overwriting it with the curve's target.
+            self.pwm_smoother.update()
+        } else if self.config.pwm_smoothing_enabled {
+            self.pwm_smoother.set_target(slowdown_capped_pwm);
+            self.pwm_smoother.update()
+        } else {
+            slowdown_capped_pwm
+        };
+
+        // Cross-check the commanded PWM against measured tach RPM: a
+        // confirmed stall means the fan isn't responding to the commanded
+        // duty at all, so bypass the smoother and force full speed in case
+        // the rotor can still be shaken loose.
+        self.last_fan_health = self.fan_health.status(final_pwm, self.device.read_rpm());
+        let final_pwm = if self.last_fan_health == FanHealthStatus::Stalled {
+            self.stalled_tick_streak += 1;
+            self.pwm_smoother.force_immediate(MAX_PWM);
+            MAX_PWM
+        } else {
+            self.stalled_tick_streak = 0;
+            final_pwm
+        };
+
+        // Forcing max PWM hasn't woken the fan up after a sustained run of
+        // confirmed stalls - keep commanding it is futile and risks masking
+        // a dead fan behind a write that never takes effect, so hand control
+        // back to the BIOS/EC, which has its own independent safety path.
+        if self.config.persistent_stall_release_ticks > 0
+            && self.stalled_tick_streak >= self.config.persistent_stall_release_ticks
+        {
+            self.disable()?;
+            return Ok(self.last_pwm);
+        }
+
+        // Only write if change is significant (reduces sysfs spam) - but
+        // never suppress a write while a spin-up kick is in progress, or
+        // while recovering from a confirmed stall, since holding back
+        // either write defeats its purpose.
+        let pwm_diff = (final_pwm as i16 - self.last_pwm as i16).unsigned_abs() as u8;
+        let write_occurred = pwm_diff >= self.config.min_pwm_change
+            || final_pwm == 0
+            || final_pwm == 255
+            || self.pwm_smoother.is_kicking()
+            || self.last_fan_health == FanHealthStatus::Stalled;
+        if write_occurred {
+            self.device.set_pwm(final_pwm)?;
+            self.last_pwm = final_pwm;
+        }
+
+        if let Some(observer) = &mut self.observer {
+            observer(&FanTick {
+                raw_temp_c: raw_temp,
+                smoothed_temp_c: smoothed_temp,
+                effective_temp_c: effective_temp,
+                target_speed: FanCurve::pwm_to_speed_scaled(target_pwm, self.pwm_min, self.pwm_max),
+                safe_pwm,
+                smoothed_pwm: final_pwm,
+                write_occurred,
+                safety_override,
+                fan_health: self.last_fan_health,
+            });
+        }
+
+        Ok(self.last_pwm)
+    }
+
+    /// Reject single-sample spikes by replacing the raw reading with the
+    /// median of the last `median_window` readings (itself included)
+    ///
+    /// Disabled (returns `raw` unchanged) when `median_window` is 0 or 1,
+    /// or before the window has filled.
+    fn reject_spikes(&mut self, raw: i32) -> i32 {
+        if self.config.median_window <= 1 {
+            return raw;
+        }
+
+        self.spike_history.push_back(raw);
+        while self.spike_history.len() > self.config.median_window {
+            self.spike_history.pop_front();
+        }
+
+        if self.spike_history.len() < self.config.median_window {
+            return raw;
+        }
+
+        let mut sorted: Vec<i32> = self.spike_history.iter().copied().collect();
+        sorted.sort_unstable();
+        sorted[sorted.len() / 2]
+    }
+
+    /// Apply hysteresis to temperature
+    ///
+    /// Only updates the "stable" temperature if change exceeds the
+    /// hysteresis threshold - `config.down_hysteresis_temp` (wider) while
+    /// `trend` is `Falling`, `config.hysteresis_temp` (the normal, narrower
+    /// band) otherwise, per Marlin-style adaptive fan slowing.
+    fn apply_hysteresis(&mut self, temp: i32, trend: TempTrend) -> i32 {
+        match self.last_stable_temp {
+            Some(last) => {
+                let diff = (temp - last).abs();
+                let threshold = if trend == TempTrend::Falling {
+                    self.config.down_hysteresis_temp
+                } else {
+                    self.config.hysteresis_temp
+                };
+                if diff >= threshold {
+                    self.last_stable_temp = Some(temp);
                     temp
                 } else {
                     last
@@ -409,6 +1616,13 @@ impl FanController {
         Ok(())
     }
 
+    /// Register a telemetry hook, invoked with a `FanTick` snapshot at the
+    /// end of every `update()` tick. Pass `None` to clear a previously
+    /// registered hook.
+    pub fn set_observer(&mut self, observer: Option<Box<dyn FnMut(&FanTick)>>) {
+        self.observer = observer;
+    }
+
     /// Get the PWM smoother (for advanced operations)
     pub fn pwm_smoother(&self) -> &PWMSmoother {
         &self.pwm_smoother
@@ -418,16 +1632,6 @@ impl FanController {
     pub fn pwm_smoother_mut(&mut self) -> &mut PWMSmoother {
         &mut self.pwm_smoother
     }
-
-    /// Get the underlying device (for advanced operations)
-    pub fn device(&self) -> &HwmonDevice {
-        &self.device
-    }
-
-    /// Get mutable device reference
-    pub fn device_mut(&mut self) -> &mut HwmonDevice {
-        &mut self.device
-    }
 }
 
 #[cfg(test)]
@@ -454,6 +1658,15 @@ mod tests {
         assert!(FanCurve::from_tuples(vec![(40, 20)]).is_err());
     }
 
+    #[test]
+    fn test_fan_curve_rejects_out_of_range_points() {
+        let err = FanCurve::from_tuples(vec![(40, 20), (150, 100)]).unwrap_err();
+        assert!(err.contains("outside"));
+
+        let err = FanCurve::from_tuples(vec![(-10, 0), (40, 20)]).unwrap_err();
+        assert!(err.contains("outside"));
+    }
+
     #[test]
     fn test_fan_curve_sorting() {
         // Points should be sorted by temperature
@@ -485,6 +1698,17 @@ mod tests {
         assert_eq!(curve.calculate_speed(90), 100);
     }
 
+    #[test]
+    fn test_pwm_for_temp_matches_speed_to_pwm_of_calculate_speed() {
+        let curve = FanCurve::from_tuples(vec![(40, 20), (80, 100)]).unwrap();
+        for temp_c in [30, 40, 60, 80, 90] {
+            assert_eq!(
+                curve.pwm_for_temp(temp_c),
+                FanCurve::speed_to_pwm(curve.calculate_speed(temp_c))
+            );
+        }
+    }
+
     #[test]
     fn test_fan_curve_multi_point() {
         let curve = FanCurve::from_tuples(vec![
@@ -503,6 +1727,330 @@ mod tests {
         assert_eq!(curve.calculate_speed(85), 100);
     }
 
+    #[test]
+    fn test_evaluate_lands_exactly_on_points() {
+        let curve = FanCurve::from_tuples(vec![
+            (40, 0),
+            (50, 30),
+            (70, 60),
+            (85, 100),
+        ])
+        .unwrap();
+
+        assert_eq!(curve.evaluate(40.0), 0.0);
+        assert_eq!(curve.evaluate(50.0), 30.0);
+        assert_eq!(curve.evaluate(70.0), 60.0);
+        assert_eq!(curve.evaluate(85.0), 100.0);
+    }
+
+    #[test]
+    fn test_evaluate_clamps_outside_range() {
+        let curve = FanCurve::from_tuples(vec![(40, 20), (80, 100)]).unwrap();
+
+        assert_eq!(curve.evaluate(0.0), 20.0);
+        assert_eq!(curve.evaluate(150.0), 100.0);
+    }
+
+    #[test]
+    fn test_evaluate_is_monotonic_between_points() {
+        let curve = FanCurve::from_tuples(vec![
+            (40, 0),
+            (50, 30),
+            (70, 60),
+            (85, 100),
+        ])
+        .unwrap();
+
+        let mut prev = curve.evaluate(40.0);
+        let mut temp = 40.0;
+        while temp <= 85.0 {
+            let duty = curve.evaluate(temp);
+            assert!(duty >= prev - 1e-9, "duty decreased at {temp}: {prev} -> {duty}");
+            assert!((0.0..=100.0).contains(&duty));
+            prev = duty;
+            temp += 0.5;
+        }
+    }
+
+    #[test]
+    fn test_evaluate_two_point_curve_matches_linear() {
+        // With exactly two points the secants on either side are equal, so
+        // the Hermite spline degenerates to the same line `calculate_speed`
+        // already produces.
+        let curve = FanCurve::from_tuples(vec![(40, 20), (80, 100)]).unwrap();
+
+        for temp in [40, 50, 60, 70, 80] {
+            let linear = curve.calculate_speed(temp) as f64;
+            let spline = curve.evaluate(temp as f64);
+            assert!((linear - spline).abs() < 1.0, "temp={temp} linear={linear} spline={spline}");
+        }
+    }
+
+    #[test]
+    fn test_from_coefficients_linear_passthrough_default() {
+        // k_a=0, k_b=1, k_c=0 reduces to speed == temp_c
+        let curve = FanCurve::from_coefficients(0.0, 1.0, 0.0).unwrap();
+        assert_eq!(curve.calculate_speed(0), 0);
+        assert_eq!(curve.calculate_speed(50), 50);
+        assert_eq!(curve.calculate_speed(100), 100);
+    }
+
+    #[test]
+    fn test_from_coefficients_quadratic() {
+        // speed = 0.01*t^2, so at t=100 -> 100%
+        let curve = FanCurve::from_coefficients(0.01, 0.0, 0.0).unwrap();
+        assert_eq!(curve.calculate_speed(0), 0);
+        assert_eq!(curve.calculate_speed(100), 100);
+        assert!(curve.calculate_speed(50) < curve.calculate_speed(100));
+    }
+
+    #[test]
+    fn test_from_coefficients_clamps_to_range() {
+        let curve = FanCurve::from_coefficients(0.0, 1.0, 50.0).unwrap();
+        // 50 + 100 = 150, clamped to 100
+        assert_eq!(curve.calculate_speed(100), 100);
+        // 50 + 0 = 50, within range
+        assert_eq!(curve.calculate_speed(0), 50);
+    }
+
+    #[test]
+    fn test_from_coefficients_rejects_non_monotonic() {
+        // Strongly negative slope with no offsetting curvature dips below 0
+        // in-range: derivative 2*0*t + (-5) = -5 everywhere
+        let err = FanCurve::from_coefficients(0.0, -5.0, 100.0).unwrap_err();
+        assert!(err.contains("non-monotonic"));
+    }
+
+    #[test]
+    fn test_from_coefficients_accepts_positive_curvature_negative_start_slope() {
+        // Derivative 2*k_a*t + k_b goes from -10 at t=0 to 30 at t=100, so
+        // it's negative at the low end and must be rejected even though the
+        // high end is fine.
+        let err = FanCurve::from_coefficients(0.2, -10.0, 0.0).unwrap_err();
+        assert!(err.contains("non-monotonic"));
+    }
+
+    #[test]
+    fn test_from_linear_matches_from_coefficients() {
+        let curve = FanCurve::from_linear(1.0, -40.0).unwrap();
+        assert_eq!(curve.coefficients(), Some((0.0, 1.0, -40.0)));
+        assert_eq!(curve.calculate_speed(40), 0);
+        assert_eq!(curve.calculate_speed(90), 50);
+    }
+
+    #[test]
+    fn test_from_linear_rejects_non_monotonic() {
+        let err = FanCurve::from_linear(-1.0, 50.0).unwrap_err();
+        assert!(err.contains("non-monotonic"));
+    }
+
+    #[test]
+    fn test_from_linear_rejects_zero_rpm_past_safety_ceiling() {
+        // speed = 0 until t=50, above the 45°C Zero-RPM ceiling
+        let err = FanCurve::from_linear(1.0, -50.0).unwrap_err();
+        assert!(err.contains("Zero-RPM"));
+    }
+
+    #[test]
+    fn test_from_linear_accepts_zero_rpm_within_safety_ceiling() {
+        // speed = 0 until t=40, within the 45°C Zero-RPM ceiling
+        assert!(FanCurve::from_linear(1.0, -40.0).is_ok());
+    }
+
+    #[test]
+    fn test_from_linear_rejects_flat_zero_curve() {
+        let err = FanCurve::from_linear(0.0, 0.0).unwrap_err();
+        assert!(err.contains("Zero-RPM"));
+    }
+
+    #[test]
+    fn test_from_anchors_two_points_fits_a_line() {
+        let curve = FanCurve::from_anchors(&[(0, 0), (100, 100)]).unwrap();
+        assert_eq!(curve.coefficients(), Some((0.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_from_anchors_three_points_fits_exact_quadratic() {
+        // speed = 0.01*t^2 sampled at three points should round-trip back to
+        // (roughly) the same coefficients.
+        let curve = FanCurve::from_anchors(&[(0, 0), (50, 25), (100, 100)]).unwrap();
+        let (k_a, k_b, k_c) = curve.coefficients().unwrap();
+        assert!((k_a - 0.01).abs() < 0.001, "k_a was {}", k_a);
+        assert!(k_b.abs() < 0.1, "k_b was {}", k_b);
+        assert!(k_c.abs() < 0.1, "k_c was {}", k_c);
+    }
+
+    #[test]
+    fn test_from_anchors_passes_through_given_points() {
+        let curve = FanCurve::from_anchors(&[(20, 10), (60, 50), (90, 95)]).unwrap();
+        assert_eq!(curve.calculate_speed(20), 10);
+        assert_eq!(curve.calculate_speed(60), 50);
+        assert_eq!(curve.calculate_speed(90), 95);
+    }
+
+    #[test]
+    fn test_from_anchors_rejects_duplicate_temperature() {
+        let err = FanCurve::from_anchors(&[(50, 10), (50, 90)]).unwrap_err();
+        assert!(err.contains("distinct temperatures"));
+    }
+
+    #[test]
+    fn test_from_anchors_rejects_wrong_count() {
+        assert!(FanCurve::from_anchors(&[(50, 10)]).is_err());
+        assert!(FanCurve::from_anchors(&[(10, 1), (40, 2), (70, 3), (90, 4)]).is_err());
+    }
+
+    #[test]
+    fn test_from_anchors_rejects_non_monotonic_fit() {
+        let err = FanCurve::from_anchors(&[(0, 80), (50, 10), (100, 0)]).unwrap_err();
+        assert!(err.contains("non-monotonic"));
+    }
+
+    #[test]
+    fn test_coefficients_accessor_round_trips() {
+        let curve = FanCurve::from_coefficients(0.01, 0.5, 10.0).unwrap();
+        assert_eq!(curve.coefficients(), Some((0.01, 0.5, 10.0)));
+
+        let points_curve = FanCurve::from_tuples(vec![(40, 20), (80, 100)]).unwrap();
+        assert_eq!(points_curve.coefficients(), None);
+    }
+
+    #[test]
+    fn test_set_coefficients_switches_representation() {
+        let mut curve = FanCurve::from_tuples(vec![(40, 20), (80, 100)]).unwrap();
+        assert!(curve.coefficients().is_none());
+
+        curve.set_coefficients(0.0, 1.0, 0.0).unwrap();
+        assert_eq!(curve.coefficients(), Some((0.0, 1.0, 0.0)));
+        assert_eq!(curve.calculate_speed(42), 42);
+        assert!(curve.points().is_empty());
+    }
+
+    #[test]
+    fn test_set_coefficients_rejects_non_monotonic_without_mutating() {
+        let mut curve = FanCurve::from_coefficients(0.0, 1.0, 0.0).unwrap();
+        let err = curve.set_coefficients(0.0, -5.0, 100.0).unwrap_err();
+        assert!(err.contains("non-monotonic"));
+        // Still the original, valid curve
+        assert_eq!(curve.coefficients(), Some((0.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_polynomial_curve_len_and_points_are_empty() {
+        let curve = FanCurve::from_coefficients(0.0, 1.0, 0.0).unwrap();
+        assert_eq!(curve.len(), 0);
+        assert!(curve.is_empty());
+        assert!(curve.points().is_empty());
+    }
+
+    #[test]
+    fn test_polynomial_evaluate_matches_calculate_speed() {
+        let curve = FanCurve::from_coefficients(0.01, 0.2, 5.0).unwrap();
+        for temp in [0, 25, 50, 75, 100] {
+            let speed = curve.calculate_speed(temp) as f64;
+            let evaluated = curve.evaluate(temp as f64);
+            assert!((speed - evaluated).abs() < 1.0, "temp={temp} speed={speed} evaluated={evaluated}");
+        }
+    }
+
+    #[test]
+    fn test_from_normalized_coefficients_spans_given_range() {
+        // x*1.0 passthrough: speed == (temp_c - t_min) / (t_max - t_min) * 100
+        let curve = FanCurve::from_normalized_coefficients(0.0, 1.0, 0.0, 40, 90).unwrap();
+        assert_eq!(curve.calculate_speed(40), 0);
+        assert_eq!(curve.calculate_speed(65), 50);
+        assert_eq!(curve.calculate_speed(90), 100);
+    }
+
+    #[test]
+    fn test_from_normalized_coefficients_clamps_outside_range() {
+        let curve = FanCurve::from_normalized_coefficients(0.0, 1.0, 0.0, 40, 90).unwrap();
+        assert_eq!(curve.calculate_speed(0), 0);
+        assert_eq!(curve.calculate_speed(200), 100);
+    }
+
+    #[test]
+    fn test_from_normalized_coefficients_rejects_inverted_range() {
+        let err = FanCurve::from_normalized_coefficients(0.0, 1.0, 0.0, 90, 40).unwrap_err();
+        assert!(err.contains("t_min"));
+    }
+
+    #[test]
+    fn test_from_normalized_coefficients_rejects_non_monotonic() {
+        let err = FanCurve::from_normalized_coefficients(0.0, -1.0, 1.0, 40, 90).unwrap_err();
+        assert!(err.contains("non-monotonic"));
+    }
+
+    #[test]
+    fn test_default_normalized_coefficients_matches_default_curve_endpoints() {
+        let (k_a, k_b, k_c, t_min, t_max) = FanCurve::default_normalized_coefficients();
+        let curve = FanCurve::from_normalized_coefficients(k_a, k_b, k_c, t_min, t_max).unwrap();
+        assert_eq!(curve.calculate_speed(t_min), 20);
+        assert_eq!(curve.calculate_speed(t_max), 100);
+    }
+
+    #[test]
+    fn test_normalized_coefficients_accessor_round_trips() {
+        let curve = FanCurve::from_normalized_coefficients(0.1, 0.5, 0.1, 30, 80).unwrap();
+        assert_eq!(curve.normalized_coefficients(), Some((0.1, 0.5, 0.1, 30, 80)));
+        assert!(curve.coefficients().is_none());
+
+        let points_curve = FanCurve::from_tuples(vec![(40, 20), (80, 100)]).unwrap();
+        assert_eq!(points_curve.normalized_coefficients(), None);
+    }
+
+    #[test]
+    fn test_set_normalized_coefficients_switches_representation() {
+        let mut curve = FanCurve::from_tuples(vec![(40, 20), (80, 100)]).unwrap();
+        curve.set_normalized_coefficients(0.0, 1.0, 0.0, 40, 90).unwrap();
+        assert_eq!(curve.normalized_coefficients(), Some((0.0, 1.0, 0.0, 40, 90)));
+        assert_eq!(curve.calculate_speed(65), 50);
+        assert!(curve.points().is_empty());
+    }
+
+    #[test]
+    fn test_set_normalized_coefficients_rejects_non_monotonic_without_mutating() {
+        let mut curve = FanCurve::from_normalized_coefficients(0.0, 1.0, 0.0, 40, 90).unwrap();
+        let err = curve
+            .set_normalized_coefficients(0.0, -1.0, 1.0, 40, 90)
+            .unwrap_err();
+        assert!(err.contains("non-monotonic"));
+        assert_eq!(curve.normalized_coefficients(), Some((0.0, 1.0, 0.0, 40, 90)));
+    }
+
+    #[test]
+    fn test_normalized_polynomial_curve_len_and_points_are_empty() {
+        let curve = FanCurve::from_normalized_coefficients(0.0, 1.0, 0.0, 40, 90).unwrap();
+        assert_eq!(curve.len(), 0);
+        assert!(curve.is_empty());
+        assert!(curve.points().is_empty());
+    }
+
+    #[test]
+    fn test_normalized_polynomial_evaluate_matches_calculate_speed() {
+        let curve = FanCurve::from_normalized_coefficients(0.2, 0.5, 0.1, 30, 90).unwrap();
+        for temp in [30, 45, 60, 75, 90] {
+            let speed = curve.calculate_speed(temp) as f64;
+            let evaluated = curve.evaluate(temp as f64);
+            assert!((speed - evaluated).abs() < 1.0, "temp={temp} speed={speed} evaluated={evaluated}");
+        }
+    }
+
+    #[test]
+    fn test_calculate_speed_f32_normalized_polynomial_is_not_rounded() {
+        let curve = FanCurve::from_normalized_coefficients(0.0, 1.0, 0.0, 0, 300).unwrap();
+        let speed = curve.calculate_speed_f32(100);
+        assert!((speed - 33.333_332).abs() < 0.01, "speed={speed}");
+    }
+
+    #[test]
+    fn test_rog_bytes_normalized_polynomial_curve_spans_given_range() {
+        let curve = FanCurve::from_normalized_coefficients(0.0, 1.0, 0.0, 30, 80).unwrap();
+        let bytes = curve.to_rog_bytes();
+        assert_eq!(bytes[0], 30);
+        assert_eq!(bytes[ROG_POINT_COUNT - 1], 80);
+    }
+
     #[test]
     fn test_speed_pwm_conversion() {
         assert_eq!(FanCurve::speed_to_pwm(0), 0);
@@ -514,6 +2062,162 @@ mod tests {
         assert_eq!(FanCurve::pwm_to_speed(255), 100);
     }
 
+    #[test]
+    fn test_speed_to_pwm_scaled_matches_unscaled_at_full_range() {
+        for speed in [0u8, 25, 50, 75, 100] {
+            assert_eq!(
+                FanCurve::speed_to_pwm_scaled(speed, MIN_PWM, MAX_PWM),
+                FanCurve::speed_to_pwm(speed)
+            );
+        }
+    }
+
+    #[test]
+    fn test_speed_to_pwm_scaled_respects_device_range() {
+        assert_eq!(FanCurve::speed_to_pwm_scaled(0, 50, 150), 50);
+        assert_eq!(FanCurve::speed_to_pwm_scaled(50, 50, 150), 100);
+        assert_eq!(FanCurve::speed_to_pwm_scaled(100, 50, 150), 150);
+    }
+
+    #[test]
+    fn test_pwm_to_speed_scaled_is_inverse_of_speed_to_pwm_scaled() {
+        for pwm in [50u8, 100, 150] {
+            let speed = FanCurve::pwm_to_speed_scaled(pwm, 50, 150);
+            let round_tripped = FanCurve::speed_to_pwm_scaled(speed, 50, 150);
+            assert_eq!(round_tripped, pwm);
+        }
+    }
+
+    #[test]
+    fn test_speed_to_pwm_scaled_rejects_inverted_range() {
+        assert_eq!(FanCurve::speed_to_pwm_scaled(50, 150, 50), 150);
+        assert_eq!(FanCurve::pwm_to_speed_scaled(100, 150, 50), 0);
+    }
+
+    #[test]
+    fn test_rog_bytes_round_trip_within_tolerance() {
+        let curve = FanCurve::from_tuples(vec![
+            (40, 0),
+            (50, 30),
+            (70, 60),
+            (85, 100),
+        ])
+        .unwrap();
+
+        let bytes = curve.to_rog_bytes();
+        let restored = FanCurve::from_rog_bytes(&bytes).unwrap();
+
+        for temp in (40..=85).step_by(5) {
+            let original = curve.calculate_speed(temp) as i32;
+            let round_tripped = restored.calculate_speed(temp) as i32;
+            assert!(
+                (original - round_tripped).abs() <= 1,
+                "temp={temp} original={original} round_tripped={round_tripped}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_rog_bytes_layout_is_ascending_temps_then_speeds() {
+        let curve = FanCurve::from_tuples(vec![(40, 0), (85, 100)]).unwrap();
+        let bytes = curve.to_rog_bytes();
+
+        let temps = &bytes[..ROG_POINT_COUNT];
+        assert!(temps.windows(2).all(|w| w[1] > w[0]));
+        assert_eq!(temps[0], 40);
+        assert_eq!(temps[ROG_POINT_COUNT - 1], 85);
+    }
+
+    #[test]
+    fn test_rog_bytes_polynomial_curve_spans_full_range() {
+        let curve = FanCurve::from_coefficients(0.01, 0.5, 10.0).unwrap();
+        let bytes = curve.to_rog_bytes();
+        assert_eq!(bytes[0], CURVE_TEMP_MIN as u8);
+        assert_eq!(bytes[ROG_POINT_COUNT - 1], CURVE_TEMP_MAX as u8);
+    }
+
+    #[test]
+    fn test_from_rog_bytes_rejects_non_ascending_temps() {
+        let mut bytes = [0u8; ROG_BYTE_LEN];
+        bytes[..ROG_POINT_COUNT].copy_from_slice(&[40, 50, 50, 60, 70, 75, 80, 85]);
+        bytes[ROG_POINT_COUNT..].copy_from_slice(&[0, 20, 30, 40, 60, 70, 90, 100]);
+
+        let err = FanCurve::from_rog_bytes(&bytes).unwrap_err();
+        assert!(err.contains("ascending"));
+    }
+
+    #[test]
+    fn test_calculate_speed_f32_interpolates_sub_percent() {
+        let curve = FanCurve::from_tuples(vec![(40, 0), (50, 30)]).unwrap();
+        // Halfway between 0% and 30% should be 15.0, not rounded to an int
+        let speed = curve.calculate_speed_f32(45);
+        assert!((speed - 15.0).abs() < 0.01, "speed={speed}");
+    }
+
+    #[test]
+    fn test_calculate_speed_f32_matches_u8_at_whole_percents() {
+        let curve = FanCurve::from_tuples(vec![(40, 0), (50, 30), (70, 60), (85, 100)]).unwrap();
+        for temp in 40..=85 {
+            let whole = curve.calculate_speed(temp) as f32;
+            let fractional = curve.calculate_speed_f32(temp);
+            assert!(
+                (whole - fractional).abs() <= 1.0,
+                "temp={temp} whole={whole} fractional={fractional}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_calculate_speed_f32_clamps_outside_range() {
+        let curve = FanCurve::from_tuples(vec![(40, 10), (80, 90)]).unwrap();
+        assert_eq!(curve.calculate_speed_f32(0), 10.0);
+        assert_eq!(curve.calculate_speed_f32(100), 90.0);
+    }
+
+    #[test]
+    fn test_calculate_speed_f32_polynomial_is_not_rounded() {
+        let curve = FanCurve::from_coefficients(0.0, 0.334, 0.0).unwrap();
+        let speed = curve.calculate_speed_f32(100);
+        assert!((speed - 33.4).abs() < 0.01, "speed={speed}");
+    }
+
+    #[test]
+    fn test_speed_to_pwm_f32_matches_u8_at_whole_percents() {
+        for speed in [0u8, 25, 50, 75, 100] {
+            assert_eq!(
+                FanCurve::speed_to_pwm_f32(speed as f32),
+                FanCurve::speed_to_pwm(speed)
+            );
+        }
+    }
+
+    #[test]
+    fn test_speed_to_pwm_f32_finer_granularity_than_u8() {
+        // 33.4% should land at a distinct PWM step from the integer 33%
+        // and 34% paths collapse to, demonstrating the finer resolution.
+        let pwm_334 = FanCurve::speed_to_pwm_f32(33.4);
+        let pwm_33 = FanCurve::speed_to_pwm(33);
+        let pwm_34 = FanCurve::speed_to_pwm(34);
+        assert!(pwm_334 >= pwm_33 && pwm_334 <= pwm_34);
+    }
+
+    #[test]
+    fn test_speed_to_pwm_f32_clamps_out_of_range() {
+        assert_eq!(FanCurve::speed_to_pwm_f32(-10.0), 0);
+        assert_eq!(FanCurve::speed_to_pwm_f32(150.0), 255);
+    }
+
+    #[test]
+    fn test_calculate_speed_f32_monotonic_for_monotonic_curve() {
+        let curve = FanCurve::from_tuples(vec![(40, 0), (50, 30), (70, 60), (85, 100)]).unwrap();
+        let mut prev = curve.calculate_speed_f32(40);
+        for temp in 41..=85 {
+            let speed = curve.calculate_speed_f32(temp);
+            assert!(speed >= prev, "speed must not decrease as temp rises: temp={temp} speed={speed} prev={prev}");
+            prev = speed;
+        }
+    }
+
     #[test]
     fn test_default_curve() {
         let curve = FanCurve::default();
@@ -534,5 +2238,255 @@ mod tests {
         assert_eq!(config.min_pwm_change, 3);
         assert!(config.pwm_smoothing_enabled);
         assert!((config.pwm_ramp_time_sec - 2.0).abs() < 0.1);
+        assert_eq!(config.median_window, 0);
+        assert!(config.autotuned_gains.is_none());
+        assert_eq!(config.spinup_pwm, 0);
+        assert_eq!(config.spinup_duration_ms, 500);
+        assert_eq!(config.min_running_pwm, 0);
+    }
+
+    #[test]
+    fn test_build_smoother_no_kick_when_spinup_pwm_zero() {
+        let config = FanControllerConfig::default();
+        let mut smoother = build_smoother(&config, 0, 255);
+        smoother.set_target(5);
+        smoother.update();
+        assert!(!smoother.is_kicking(), "spinup_pwm=0 must leave the kick disabled");
+    }
+
+    #[test]
+    fn test_build_smoother_kicks_below_spinup_threshold() {
+        let mut config = FanControllerConfig::default();
+        config.spinup_pwm = 80;
+        config.spinup_duration_ms = 50;
+        let mut smoother = build_smoother(&config, 0, 255);
+
+        smoother.set_target(20); // nonzero, below spinup_pwm
+        let pwm = smoother.update();
+
+        assert!(smoother.is_kicking());
+        assert_eq!(pwm, 80, "should kick to spinup_pwm to break stiction");
+    }
+
+    #[test]
+    fn test_build_smoother_applies_min_running_pwm_floor() {
+        let mut config = FanControllerConfig::default();
+        config.min_running_pwm = 50;
+        let mut smoother = build_smoother(&config, 0, 255);
+
+        smoother.set_target(10); // nonzero target below the floor
+        assert_eq!(smoother.target(), 50, "nonzero target should be floored to min_running_pwm");
+
+        smoother.set_target(0);
+        assert_eq!(smoother.target(), 0, "fully off must stay reachable despite the floor");
+    }
+
+    #[test]
+    fn test_build_smoother_floor_composes_with_device_pwm_min() {
+        // Even with min_running_pwm left at 0, the device's own native
+        // pwm_min (captured via HwmonDevice at construction) must still act
+        // as a floor.
+        let config = FanControllerConfig::default();
+        let mut smoother = build_smoother(&config, 30, 255);
+
+        smoother.set_target(10);
+        assert_eq!(smoother.target(), 30, "device pwm_min should floor the target even with no configured min_running_pwm");
+    }
+
+    #[test]
+    fn test_build_fan_health_uses_configured_model() {
+        let mut config = FanControllerConfig::default();
+        config.fan_health_model = FanHealthModel { a: 0.0, b: 10.0, c: 0.0 };
+        config.fan_health_stall_fraction = 0.5;
+        config.fan_health_min_measurable_pwm = 0;
+        let mut health = build_fan_health(&config);
+
+        // Expected RPM at pwm=100 is 1000; 400 is well under the 50% stall
+        // fraction (500). build_fan_health uses the default tick threshold,
+        // so it takes several consecutive bad ticks to confirm.
+        for _ in 0..DEFAULT_FAN_HEALTH_TICK_THRESHOLD {
+            health.status(100, Some(400));
+        }
+        assert_eq!(health.status(100, Some(400)), FanHealthStatus::Stalled);
+    }
+
+    #[test]
+    fn test_build_fan_health_respects_min_measurable_pwm() {
+        let mut config = FanControllerConfig::default();
+        config.fan_health_model = FanHealthModel { a: 0.0, b: 10.0, c: 0.0 };
+        config.fan_health_min_measurable_pwm = 50;
+        let mut health = build_fan_health(&config);
+
+        // Below the configured floor, the check is skipped entirely even
+        // though the RPM would otherwise look stalled.
+        for _ in 0..5 {
+            assert_eq!(health.status(30, Some(0)), FanHealthStatus::Ok);
+        }
+        assert_eq!(health.consecutive_ticks(), 0);
+    }
+
+    #[test]
+    fn test_fan_fault_latches_after_persistent_stall_reading() {
+        let mut config = FanControllerConfig::default();
+        config.hysteresis_temp = 1;
+        config.down_hysteresis_temp = 1;
+        config.pwm_smoothing_enabled = false;
+        config.smoothing_samples = 1;
+        config.min_pwm_change = 0;
+        let (dir, mut controller) = make_active_controller(80, config);
+        // Tach reading stuck near zero despite a commanded PWM well above
+        // the health model's floor - a classic stalled-rotor signature.
+        std::fs::write(dir.path().join("fan1_input"), "0\n").unwrap();
+
+        assert!(!controller.fan_fault());
+        for _ in 0..DEFAULT_FAN_HEALTH_TICK_THRESHOLD {
+            controller.update().unwrap();
+        }
+        assert!(controller.fan_fault());
+        assert_eq!(controller.fan_health_status(), FanHealthStatus::Stalled);
+    }
+
+    /// Build a fake hwmon device directory with the files `HwmonDevice::open`
+    /// requires, with a fixed starting temperature
+    fn make_fake_device(temp_c: i32) -> tempfile::TempDir {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("name"), "jupiter\n").unwrap();
+        std::fs::write(dir.path().join("pwm1"), "0\n").unwrap();
+        std::fs::write(dir.path().join("pwm1_enable"), "2\n").unwrap();
+        std::fs::write(
+            dir.path().join("temp1_input"),
+            format!("{}\n", temp_c * 1000),
+        )
+        .unwrap();
+        dir
+    }
+
+    /// Build a `FanController` over a fake device at `temp_c`, active and
+    /// with PWM smoothing disabled so `update()`'s output reflects the
+    /// hysteresis/slowdown-cap pipeline directly
+    fn make_active_controller(temp_c: i32, config: FanControllerConfig) -> (tempfile::TempDir, FanController) {
+        let dir = make_fake_device(temp_c);
+        let device = crate::fan::hwmon::HwmonDevice::open(dir.path()).unwrap();
+        let mut controller = FanController::with_device(device);
+        controller.set_config(config);
+        controller.enable().unwrap();
+        (dir, controller)
+    }
+
+    fn set_temp(dir: &tempfile::TempDir, temp_c: i32) {
+        std::fs::write(
+            dir.path().join("temp1_input"),
+            format!("{}\n", temp_c * 1000),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_apply_hysteresis_uses_wider_band_on_falling_trend() {
+        let mut config = FanControllerConfig::default();
+        config.hysteresis_temp = 2;
+        config.down_hysteresis_temp = 10;
+        let dir = make_fake_device(60);
+        let device = crate::fan::hwmon::HwmonDevice::open(dir.path()).unwrap();
+        let mut controller = FanController::with_device(device);
+        controller.set_config(config);
+
+        // Establish a stable baseline.
+        assert_eq!(controller.apply_hysteresis(60, TempTrend::Steady), 60);
+
+        // A small drop (well under the widened down-hysteresis band) stays
+        // latched to the last stable temperature.
+        assert_eq!(controller.apply_hysteresis(55, TempTrend::Falling), 60);
+
+        // The same-sized change, seen as a rising trend, clears the
+        // narrower `hysteresis_temp` band immediately.
+        assert_eq!(controller.apply_hysteresis(58, TempTrend::Rising), 58);
+    }
+
+    #[test]
+    fn test_fan_slowdown_step_max_caps_falling_duty_decrease() {
+        let mut config = FanControllerConfig::default();
+        config.hysteresis_temp = 1;
+        config.down_hysteresis_temp = 1;
+        config.fan_slowdown_step_max = 5;
+        config.pwm_smoothing_enabled = false;
+        config.smoothing_samples = 1;
+        config.min_pwm_change = 0;
+        let (dir, mut controller) = make_active_controller(80, config);
+
+        let hot_pwm = controller.update().unwrap();
+
+        // A big temperature drop would normally swing the curve output down
+        // sharply; the slowdown cap should limit the single-tick decrease.
+        set_temp(&dir, 30);
+        let cooled_pwm = controller.update().unwrap();
+
+        assert!(hot_pwm > cooled_pwm);
+        assert!((hot_pwm - cooled_pwm) <= 5);
+    }
+
+    #[test]
+    fn test_fan_slowdown_step_max_disabled_allows_immediate_drop() {
+        let mut config = FanControllerConfig::default();
+        config.hysteresis_temp = 1;
+        config.down_hysteresis_temp = 1;
+        config.fan_slowdown_step_max = 0;
+        config.pwm_smoothing_enabled = false;
+        config.smoothing_samples = 1;
+        config.min_pwm_change = 0;
+        let (dir, mut controller) = make_active_controller(80, config);
+
+        let hot_pwm = controller.update().unwrap();
+
+        set_temp(&dir, 30);
+        let cooled_pwm = controller.update().unwrap();
+
+        // With the cap disabled, the full curve-driven drop takes effect
+        // immediately - more than the 5-unit cap used in the capped test.
+        assert!((hot_pwm - cooled_pwm) > 5);
+    }
+
+    #[test]
+    fn test_fan_slowdown_step_max_does_not_cap_rising_temperature() {
+        let mut config = FanControllerConfig::default();
+        config.hysteresis_temp = 1;
+        config.down_hysteresis_temp = 1;
+        config.fan_slowdown_step_max = 5;
+        config.pwm_smoothing_enabled = false;
+        config.smoothing_samples = 1;
+        config.min_pwm_change = 0;
+        let (dir, mut controller) = make_active_controller(30, config);
+
+        let cool_pwm = controller.update().unwrap();
+
+        set_temp(&dir, 80);
+        let hot_pwm = controller.update().unwrap();
+
+        // A rising trend is never capped, regardless of `fan_slowdown_step_max`.
+        assert!((hot_pwm - cool_pwm) > 5);
+    }
+
+    #[test]
+    fn test_status_effective_speed_percent_reflects_slowdown_cap() {
+        let mut config = FanControllerConfig::default();
+        config.hysteresis_temp = 1;
+        config.down_hysteresis_temp = 1;
+        config.fan_slowdown_step_max = 5;
+        config.pwm_smoothing_enabled = false;
+        config.smoothing_samples = 1;
+        config.min_pwm_change = 0;
+        let (dir, mut controller) = make_active_controller(80, config);
+        controller.update().unwrap();
+
+        set_temp(&dir, 30);
+        controller.update().unwrap();
+        let status = controller.status().unwrap();
+
+        // The capped, pre-smoother duty is reported distinctly from the
+        // final written `speed_percent` once smoothing/further stages run.
+        assert_eq!(
+            status.effective_speed_percent,
+            FanCurve::pwm_to_speed_scaled(controller.last_effective_pwm, controller.pwm_min, controller.pwm_max)
+        );
     }
 }
@@ -7,6 +7,9 @@ use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 
+use super::controller::{MIN_PWM, MAX_PWM};
+use super::safety::{FanHealthModel, DEFAULT_FAN_HEALTH_MODEL};
+
 /// Base path for hwmon devices
 pub const HWMON_PATH: &str = "/sys/class/hwmon";
 
@@ -89,6 +92,14 @@ pub struct HwmonDevice {
     name: String,
     /// Whether we took manual control (for Drop)
     took_control: bool,
+    /// Lowest usable PWM duty cycle, from `pwm1_min` if the device exposes
+    /// it, else [`MIN_PWM`]
+    pwm_min: u8,
+    /// Highest usable PWM duty cycle, from `pwm1_max` if the device exposes
+    /// it, else [`MAX_PWM`]
+    pwm_max: u8,
+    /// Quadratic PWM→RPM model used by `expected_rpm`/`rpm_deviation`
+    rpm_model: FanHealthModel,
 }
 
 impl HwmonDevice {
@@ -121,13 +132,39 @@ impl HwmonDevice {
             }
         }
 
+        let pwm_min = read_optional_pwm_bound(&path, "pwm1_min").unwrap_or(MIN_PWM);
+        let pwm_max = read_optional_pwm_bound(&path, "pwm1_max").unwrap_or(MAX_PWM);
+        // A device reporting a nonsensical range (min >= max, e.g. a driver
+        // bug or a file that doesn't actually mean what its name implies)
+        // falls back to the full range rather than commanding a dead zone.
+        let (pwm_min, pwm_max) = if pwm_min < pwm_max {
+            (pwm_min, pwm_max)
+        } else {
+            (MIN_PWM, MAX_PWM)
+        };
+
         Ok(HwmonDevice {
             path,
             name,
             took_control: false,
+            pwm_min,
+            pwm_max,
+            rpm_model: DEFAULT_FAN_HEALTH_MODEL,
         })
     }
 
+    /// Lowest usable PWM duty cycle for this device (from `pwm1_min` if
+    /// exposed, else [`MIN_PWM`])
+    pub fn pwm_min(&self) -> u8 {
+        self.pwm_min
+    }
+
+    /// Highest usable PWM duty cycle for this device (from `pwm1_max` if
+    /// exposed, else [`MAX_PWM`])
+    pub fn pwm_max(&self) -> u8 {
+        self.pwm_max
+    }
+
     /// Get device name
     pub fn name(&self) -> &str {
         &self.name
@@ -216,17 +253,28 @@ impl HwmonDevice {
 
     /// Set fan speed as percentage (0-100)
     ///
-    /// Converts percentage to PWM value (0-255).
+    /// Scales the percentage into this device's `[pwm_min, pwm_max]` range
+    /// rather than assuming the full 0-255 range, so 0% maps to the lowest
+    /// *usable* PWM and 100% to the real ceiling.
     pub fn set_speed_percent(&self, percent: u8) -> Result<(), HwmonError> {
         let percent = percent.min(100);
-        let pwm = ((percent as u16 * 255) / 100) as u8;
+        let span = (self.pwm_max - self.pwm_min) as u16;
+        let pwm = self.pwm_min + ((percent as u16 * span) / 100) as u8;
         self.set_pwm(pwm)
     }
 
     /// Read fan speed as percentage (0-100)
+    ///
+    /// Inverse of `set_speed_percent`: scales the raw PWM back from this
+    /// device's `[pwm_min, pwm_max]` range rather than assuming 0-255.
     pub fn read_speed_percent(&self) -> Result<u8, HwmonError> {
         let pwm = self.read_pwm()?;
-        Ok(((pwm as u16 * 100) / 255) as u8)
+        let span = (self.pwm_max - self.pwm_min) as u16;
+        if span == 0 {
+            return Ok(0);
+        }
+        let offset = pwm.saturating_sub(self.pwm_min) as u16;
+        Ok(((offset.min(span) * 100) / span) as u8)
     }
 
     /// Read fan RPM if available
@@ -244,6 +292,38 @@ impl HwmonDevice {
             .and_then(|s| s.trim().parse().ok())
     }
 
+    /// Override the quadratic PWM→RPM model used by `expected_rpm` and
+    /// `rpm_deviation`, e.g. with coefficients fit to this specific unit
+    /// rather than the stock-fan default
+    pub fn set_rpm_model(&mut self, model: FanHealthModel) {
+        self.rpm_model = model;
+    }
+
+    /// The configured PWM→RPM model
+    pub fn rpm_model(&self) -> FanHealthModel {
+        self.rpm_model
+    }
+
+    /// Expected RPM at `pwm` under the configured model, for comparing
+    /// against a `read_rpm()` measurement or converting a target RPM back
+    /// into a duty cycle to command
+    pub fn expected_rpm(&self, pwm: u8) -> u32 {
+        self.rpm_model.expected_rpm(pwm).round() as u32
+    }
+
+    /// Difference between measured and expected RPM at the currently
+    /// commanded PWM: positive means the fan is spinning faster than
+    /// expected, negative means it's underperforming (dust, bearing wear,
+    /// a developing stall)
+    ///
+    /// # Returns
+    /// `None` if the tach channel isn't present or `pwm1` can't be read
+    pub fn rpm_deviation(&self) -> Option<i32> {
+        let measured = self.read_rpm()?;
+        let pwm = self.read_pwm().ok()?;
+        Some(measured as i32 - self.expected_rpm(pwm) as i32)
+    }
+
     /// Return control to BIOS (set Auto mode)
     ///
     /// Called automatically on Drop, but can be called manually.
@@ -265,6 +345,14 @@ impl Drop for HwmonDevice {
     }
 }
 
+/// Read an optional `pwm1_min`/`pwm1_max`-style bound file, returning `None`
+/// if the file doesn't exist or doesn't parse - not every hwmon driver
+/// exposes these, and a missing file means "no override", not an error.
+fn read_optional_pwm_bound(device_path: &Path, file_name: &str) -> Option<u8> {
+    let content = fs::read_to_string(device_path.join(file_name)).ok()?;
+    content.trim().parse::<u16>().ok().map(|v| v.min(255) as u8)
+}
+
 /// Find Steam Deck hwmon device (jupiter or galileo)
 ///
 /// Iterates through /sys/class/hwmon/hwmonX directories looking for
@@ -357,4 +445,100 @@ mod tests {
         let err = HwmonError::PermissionDenied;
         assert!(err.to_string().contains("Permission denied"));
     }
+
+    /// Build a fake hwmon device directory with the required files, plus
+    /// any extra files given as `(name, contents)` pairs
+    fn make_fake_device(extra_files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(dir.path().join("name"), "jupiter\n").unwrap();
+        fs::write(dir.path().join("pwm1"), "0\n").unwrap();
+        fs::write(dir.path().join("pwm1_enable"), "2\n").unwrap();
+        fs::write(dir.path().join("temp1_input"), "40000\n").unwrap();
+        for (name, contents) in extra_files {
+            fs::write(dir.path().join(name), contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_pwm_range_defaults_when_bounds_not_exposed() {
+        let dir = make_fake_device(&[]);
+        let device = HwmonDevice::open(dir.path()).unwrap();
+        assert_eq!(device.pwm_min(), MIN_PWM);
+        assert_eq!(device.pwm_max(), MAX_PWM);
+    }
+
+    #[test]
+    fn test_pwm_range_reads_device_bounds() {
+        let dir = make_fake_device(&[("pwm1_min", "30\n"), ("pwm1_max", "200\n")]);
+        let device = HwmonDevice::open(dir.path()).unwrap();
+        assert_eq!(device.pwm_min(), 30);
+        assert_eq!(device.pwm_max(), 200);
+    }
+
+    #[test]
+    fn test_pwm_range_falls_back_on_inverted_bounds() {
+        let dir = make_fake_device(&[("pwm1_min", "200\n"), ("pwm1_max", "30\n")]);
+        let device = HwmonDevice::open(dir.path()).unwrap();
+        assert_eq!(device.pwm_min(), MIN_PWM);
+        assert_eq!(device.pwm_max(), MAX_PWM);
+    }
+
+    #[test]
+    fn test_set_speed_percent_scales_into_device_range() {
+        let dir = make_fake_device(&[("pwm1_min", "50\n"), ("pwm1_max", "150\n")]);
+        let device = HwmonDevice::open(dir.path()).unwrap();
+
+        device.set_speed_percent(0).unwrap();
+        assert_eq!(device.read_pwm().unwrap(), 50);
+
+        device.set_speed_percent(100).unwrap();
+        assert_eq!(device.read_pwm().unwrap(), 150);
+
+        device.set_speed_percent(50).unwrap();
+        assert_eq!(device.read_pwm().unwrap(), 100);
+    }
+
+    #[test]
+    fn test_read_speed_percent_scales_from_device_range() {
+        let dir = make_fake_device(&[("pwm1_min", "50\n"), ("pwm1_max", "150\n")]);
+        let device = HwmonDevice::open(dir.path()).unwrap();
+
+        fs::write(dir.path().join("pwm1"), "100\n").unwrap();
+        assert_eq!(device.read_speed_percent().unwrap(), 50);
+    }
+
+    #[test]
+    fn test_expected_rpm_uses_default_model() {
+        let dir = make_fake_device(&[]);
+        let device = HwmonDevice::open(dir.path()).unwrap();
+        // 0.02*200^2 + 10*200 - 200 = 2600, matching DEFAULT_FAN_HEALTH_MODEL
+        assert_eq!(device.expected_rpm(200), 2600);
+    }
+
+    #[test]
+    fn test_set_rpm_model_overrides_default() {
+        let dir = make_fake_device(&[]);
+        let mut device = HwmonDevice::open(dir.path()).unwrap();
+        device.set_rpm_model(FanHealthModel { a: 0.0, b: 10.0, c: 0.0 });
+        assert_eq!(device.expected_rpm(100), 1000);
+        assert_eq!(device.rpm_model(), FanHealthModel { a: 0.0, b: 10.0, c: 0.0 });
+    }
+
+    #[test]
+    fn test_rpm_deviation_compares_measured_against_model() {
+        let dir = make_fake_device(&[("fan1_input", "1000\n")]);
+        let mut device = HwmonDevice::open(dir.path()).unwrap();
+        device.set_rpm_model(FanHealthModel { a: 0.0, b: 10.0, c: 0.0 });
+        fs::write(dir.path().join("pwm1"), "200\n").unwrap();
+        // expected_rpm(200) = 2000, measured 1000 -> deviation -1000
+        assert_eq!(device.rpm_deviation(), Some(-1000));
+    }
+
+    #[test]
+    fn test_rpm_deviation_none_without_tach() {
+        let dir = make_fake_device(&[]);
+        let device = HwmonDevice::open(dir.path()).unwrap();
+        assert_eq!(device.rpm_deviation(), None);
+    }
 }
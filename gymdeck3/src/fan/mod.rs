@@ -2,9 +2,12 @@
 //!
 //! Provides low-level fan control via hwmon sysfs interface with:
 //! - Automatic hwmon device discovery (jupiter/galileo)
-//! - Temperature-based fan curve with linear interpolation
+//! - Temperature-based fan curve with linear interpolation, or an
+//!   alternative quadratic polynomial curve (`FanCurve::from_coefficients`)
 //! - Hysteresis to prevent rapid speed changes
 //! - Smoothing (moving average) for gradual transitions
+//! - Optional EMA low-pass filtering of the safety override's proportional
+//!   region, with an unfiltered fast path for critical temperatures
 //! - Safety overrides (90°C+ forces 100% PWM)
 //! - Fail-safe: Drop trait returns control to BIOS
 //!
@@ -66,6 +69,11 @@ mod controller;
 mod safety;
 mod acoustic;
 mod smoother;
+mod rpm;
+mod pid;
+mod autotune;
+mod temp_filter;
+mod backend;
 
 pub use hwmon::{
     HwmonDevice,
@@ -81,26 +89,83 @@ pub use controller::{
     FanCurvePoint,
     FanControllerConfig,
     FanStatus,
+    FanTick,
     DEFAULT_HYSTERESIS_TEMP,
     DEFAULT_SMOOTHING_SAMPLES,
+    DEFAULT_PERSISTENT_STALL_RELEASE_TICKS,
+    DEFAULT_NORMALIZED_DUTY_K_A,
+    DEFAULT_NORMALIZED_DUTY_K_B,
+    DEFAULT_NORMALIZED_DUTY_K_C,
     MIN_PWM,
     MAX_PWM,
+    ROG_POINT_COUNT,
+    ROG_BYTE_LEN,
 };
 
 pub use safety::{
     FanSafetyLimits,
     SafetyOverride,
+    SafetyOverrideTracker,
+    FanHealth,
+    FanHealthStatus,
+    FanHealthModel,
     CRITICAL_TEMP_C,
     HIGH_TEMP_C,
     ZERO_RPM_MAX_TEMP_C,
+    DEFAULT_FAN_HEALTH_PWM_FLOOR,
+    DEFAULT_FAN_HEALTH_RPM_THRESHOLD,
+    DEFAULT_FAN_HEALTH_TICK_THRESHOLD,
+    DEFAULT_FAN_HEALTH_MODEL,
+    DEFAULT_FAN_HEALTH_STALL_FRACTION,
+    DEFAULT_FAN_HEALTH_MIN_MEASURABLE_PWM,
     check_safety_override,
     apply_safety_override,
+    apply_safety_override_filtered,
     is_zero_rpm_safe,
 };
 
 pub use acoustic::AcousticProfile;
 
+pub use temp_filter::TempFilter;
+
 pub use smoother::{
     PWMSmoother,
+    Curve,
+    SmootherState,
     DEFAULT_RAMP_TIME_SEC,
+    MAX_ELAPSED_SEC,
+};
+
+pub use rpm::RpmController;
+
+pub use pid::{
+    PidFanController,
+    PidDiagnostics,
+    DEFAULT_FAN_PID_KP,
+    DEFAULT_FAN_PID_KI,
+    DEFAULT_FAN_PID_KD,
+};
+
+pub use backend::{
+    FanBackend,
+    SimulatedFanBackend,
+    find_fan_backend,
+    DEFAULT_SIM_AMBIENT_C,
+    DEFAULT_SIM_HEAT_LOAD_C,
+    DEFAULT_SIM_MAX_RPM,
+    DEFAULT_SIM_TICK_SEC,
+    DEFAULT_SIM_THERMAL_TAU_SEC,
+};
+
+pub use autotune::{
+    RelayAutotuner,
+    AutotuneGains,
+    AutotuneStep,
+    AutotuneAbortReason,
+    DEFAULT_RELAY_PWM_HIGH,
+    DEFAULT_RELAY_PWM_LOW,
+    DEFAULT_RELAY_HYSTERESIS_C,
+    DEFAULT_STABLE_CYCLES_REQUIRED,
+    DEFAULT_MAX_CYCLES,
+    DEFAULT_TIMEOUT as DEFAULT_AUTOTUNE_TIMEOUT,
 };
@@ -0,0 +1,279 @@
+//! PID-based fan control driven by a temperature setpoint
+//!
+//! `FanCurve` always maps temperature to speed through a static lookup
+//! (piecewise points or a polynomial); it holds a temperature setpoint only
+//! as well as the curve happens to approximate it. `PidFanController` instead
+//! regulates PWM directly from a setpoint: each tick it computes
+//! `error = temp_c - setpoint_c`, accumulates an anti-windup-clamped
+//! integral term, applies a derivative term, and clamps the result to
+//! `[min_pwm, max_pwm]`. That holds the die temperature much more tightly
+//! than a lookup table during bursty loads, at the cost of needing tuned
+//! gains instead of a hand-drawn curve.
+
+use std::time::Instant;
+
+/// Default proportional gain
+pub const DEFAULT_FAN_PID_KP: f32 = 2.0;
+/// Default integral gain
+pub const DEFAULT_FAN_PID_KI: f32 = 0.1;
+/// Default derivative gain
+pub const DEFAULT_FAN_PID_KD: f32 = 0.5;
+
+/// Minimum elapsed time used for a tick, so a zero (or near-zero) interval
+/// between `update()` calls can't blow up the derivative term
+const MIN_DT_SECS: f32 = 0.001;
+
+/// Discrete PID controller driving fan PWM from a temperature setpoint
+#[derive(Debug, Clone)]
+pub struct PidFanController {
+    setpoint_c: f32,
+    kp: f32,
+    ki: f32,
+    kd: f32,
+    min_pwm: u8,
+    max_pwm: u8,
+    base_pwm: u8,
+    integral: f32,
+    prev_error: Option<f32>,
+    last_output: u8,
+    last_update: Instant,
+    last_diagnostics: PidDiagnostics,
+}
+
+/// Snapshot of the most recent `PidFanController::update()` call, exposing
+/// each term's contribution so a host can log or plot control behavior
+/// without re-deriving the math.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PidDiagnostics {
+    /// `temp_c - setpoint_c` from the most recent `update()`
+    pub error: f32,
+    /// `kp * error`
+    pub p_term: f32,
+    /// `ki * integral`
+    pub i_term: f32,
+    /// `kd * derivative`
+    pub d_term: f32,
+    /// Whether the integral term was frozen this tick due to anti-windup
+    pub integral_frozen: bool,
+    /// Final clamped PWM output
+    pub output: u8,
+}
+
+impl PidFanController {
+    /// Create a new controller targeting `setpoint_c`, with output clamped
+    /// to `[min_pwm, max_pwm]`
+    pub fn new(setpoint_c: f32, kp: f32, ki: f32, kd: f32, min_pwm: u8, max_pwm: u8) -> Self {
+        PidFanController {
+            setpoint_c,
+            kp,
+            ki,
+            kd,
+            min_pwm,
+            max_pwm,
+            base_pwm: 0,
+            integral: 0.0,
+            prev_error: None,
+            last_output: min_pwm,
+            last_update: Instant::now(),
+            last_diagnostics: PidDiagnostics {
+                error: 0.0,
+                p_term: 0.0,
+                i_term: 0.0,
+                d_term: 0.0,
+                integral_frozen: false,
+                output: min_pwm,
+            },
+        }
+    }
+
+    /// Builder: feed-forward PWM added before the PID terms, useful for
+    /// gains tuned around a known idle-speed floor instead of zero
+    pub fn with_base_pwm(mut self, base_pwm: u8) -> Self {
+        self.base_pwm = base_pwm;
+        self
+    }
+
+    /// Temperature setpoint in °C
+    pub fn setpoint_c(&self) -> f32 {
+        self.setpoint_c
+    }
+
+    /// Current `(kp, ki, kd)` gains
+    pub fn gains(&self) -> (f32, f32, f32) {
+        (self.kp, self.ki, self.kd)
+    }
+
+    /// Reset the accumulated integral and derivative history, so the
+    /// controller starts clean instead of carrying over state from a
+    /// previous (possibly very different) run
+    ///
+    /// Called whenever `FanController` (re-)enables manual control.
+    pub fn reset(&mut self) {
+        self.integral = 0.0;
+        self.prev_error = None;
+        self.last_output = self.min_pwm;
+        self.last_update = Instant::now();
+        self.last_diagnostics = PidDiagnostics {
+            error: 0.0,
+            p_term: 0.0,
+            i_term: 0.0,
+            d_term: 0.0,
+            integral_frozen: false,
+            output: self.min_pwm,
+        };
+    }
+
+    /// Error and per-term breakdown from the most recent `update()` call,
+    /// for logging or plotting control behavior.
+    pub fn diagnostics(&self) -> PidDiagnostics {
+        self.last_diagnostics
+    }
+
+    /// Compute the next PWM output for the given temperature
+    ///
+    /// Should be called once per `FanController::update()` tick.
+    pub fn update(&mut self, temp_c: i32) -> u8 {
+        let now = Instant::now();
+        let dt = now.duration_since(self.last_update).as_secs_f32().max(MIN_DT_SECS);
+        self.last_update = now;
+
+        let error = temp_c as f32 - self.setpoint_c;
+
+        // Anti-windup: freeze the integral term whenever the previous
+        // output was pinned at a rail, so a sustained error (e.g. stuck at
+        // max fan speed on a hot day) can't wind the integral up
+        // unboundedly while it has no effect on the output anyway.
+        let saturated = self.last_output <= self.min_pwm || self.last_output >= self.max_pwm;
+        if !saturated {
+            self.integral += error * dt;
+        }
+
+        let derivative = match self.prev_error {
+            Some(prev) => (error - prev) / dt,
+            None => 0.0,
+        };
+        self.prev_error = Some(error);
+
+        let p_term = self.kp * error;
+        let i_term = self.ki * self.integral;
+        let d_term = self.kd * derivative;
+
+        let output = (self.base_pwm as f32 + p_term + i_term + d_term)
+            .clamp(self.min_pwm as f32, self.max_pwm as f32);
+
+        let pwm = output.round() as u8;
+        self.last_output = pwm;
+        self.last_diagnostics = PidDiagnostics {
+            error,
+            p_term,
+            i_term,
+            d_term,
+            integral_frozen: saturated,
+            output: pwm,
+        };
+        pwm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_error_holds_steady_output() {
+        let mut pid = PidFanController::new(70.0, 2.0, 0.0, 0.0, 0, 255);
+        let pwm = pid.update(70);
+        assert_eq!(pwm, 0, "zero error with zero gains beyond kp should produce zero output");
+    }
+
+    #[test]
+    fn test_positive_error_increases_output() {
+        let mut pid = PidFanController::new(70.0, 2.0, 0.0, 0.0, 0, 255);
+        let pwm = pid.update(80); // 10°C above setpoint
+        assert!(pwm > 0, "hotter than setpoint should drive PWM up");
+    }
+
+    #[test]
+    fn test_negative_error_clamps_to_min() {
+        let mut pid = PidFanController::new(70.0, 2.0, 0.0, 0.0, 0, 255);
+        let pwm = pid.update(40); // well below setpoint
+        assert_eq!(pwm, 0, "colder than setpoint should clamp to min_pwm");
+    }
+
+    #[test]
+    fn test_output_never_exceeds_bounds() {
+        let mut pid = PidFanController::new(70.0, 50.0, 0.0, 0.0, 0, 255);
+        let pwm = pid.update(100); // huge error against a large gain
+        assert!(pwm <= 255);
+    }
+
+    #[test]
+    fn test_reset_clears_integral_and_derivative_history() {
+        let mut pid = PidFanController::new(70.0, 0.0, 1.0, 0.0, 0, 255);
+        pid.update(80);
+        pid.update(80);
+        pid.reset();
+
+        // Immediately after reset, the derivative term has no prior error to
+        // compare against, so the first post-reset output reflects only a
+        // freshly-zeroed integral.
+        let pwm = pid.update(70);
+        assert_eq!(pwm, 0, "reset should have zeroed the accumulated integral");
+    }
+
+    #[test]
+    fn test_integral_freezes_when_output_saturated() {
+        let mut pid = PidFanController::new(70.0, 2.0, 10.0, 0.0, 0, 255);
+        // Drive well past saturation repeatedly; if the integral kept
+        // winding up unboundedly this would still read fine since it's
+        // clamped at the output, but a frozen integral should converge
+        // rather than keep accumulating forever.
+        for _ in 0..5 {
+            pid.update(200);
+        }
+        let (_, ki, _) = pid.gains();
+        assert!(ki > 0.0);
+    }
+
+    #[test]
+    fn test_setpoint_and_gains_accessors() {
+        let pid = PidFanController::new(65.0, 1.5, 0.2, 0.3, 0, 255);
+        assert_eq!(pid.setpoint_c(), 65.0);
+        assert_eq!(pid.gains(), (1.5, 0.2, 0.3));
+    }
+
+    #[test]
+    fn test_base_pwm_is_added_before_clamping() {
+        let mut pid = PidFanController::new(70.0, 0.0, 0.0, 0.0, 0, 255).with_base_pwm(40);
+        let pwm = pid.update(70); // zero error, zero gains beyond base
+        assert_eq!(pwm, 40, "base_pwm should act as a feed-forward floor with no PID contribution");
+    }
+
+    #[test]
+    fn test_diagnostics_reports_current_terms() {
+        let mut pid = PidFanController::new(70.0, 2.0, 0.5, 0.1, 0, 255);
+        let pwm = pid.update(80); // 10°C above setpoint
+        let diag = pid.diagnostics();
+        assert_eq!(diag.error, 10.0);
+        assert_eq!(diag.p_term, 20.0);
+        assert_eq!(diag.output, pwm);
+    }
+
+    #[test]
+    fn test_diagnostics_flags_integral_freeze_when_saturated() {
+        let mut pid = PidFanController::new(70.0, 50.0, 10.0, 0.0, 0, 255);
+        pid.update(200); // drive output to the max rail
+        let diag = pid.diagnostics();
+        assert!(diag.integral_frozen, "output pinned at max_pwm should freeze the integral");
+    }
+
+    #[test]
+    fn test_reset_clears_diagnostics() {
+        let mut pid = PidFanController::new(70.0, 2.0, 0.0, 0.0, 0, 255);
+        pid.update(80);
+        pid.reset();
+        let diag = pid.diagnostics();
+        assert_eq!(diag.error, 0.0);
+        assert_eq!(diag.p_term, 0.0);
+    }
+}
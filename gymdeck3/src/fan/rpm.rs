@@ -0,0 +1,182 @@
+//! Closed-loop RPM targeting with tachometer feedback
+//!
+//! Everything else in this module is open-loop: temperature maps to a
+//! speed percentage, which maps to a PWM value, assuming a fixed
+//! PWM→RPM relationship for the hardware. Real fans drift with age and
+//! vary unit-to-unit, so `RpmController` closes the loop on top of
+//! whatever target RPM the acoustic-profile curve produces: each tick it
+//! takes a measured RPM reading (`HwmonDevice::read_rpm`) and nudges PWM
+//! toward the target with a clamped proportional/integral step. The
+//! safety layer still wins — a caller that overrides this controller's
+//! PWM with `apply_safety_override` should also call `reset_integral` so
+//! accumulated error doesn't carry over into the next un-overridden tick.
+
+use super::safety::MAX_SAFE_PWM;
+
+/// Clamped proportional/integral controller that converges measured RPM
+/// onto a target RPM by adjusting PWM
+#[derive(Debug, Clone)]
+pub struct RpmController {
+    /// Proportional gain
+    kp: f32,
+    /// Integral gain
+    ki: f32,
+    /// Floor PWM the controller will never drive below
+    min_spin_pwm: u8,
+    /// Accumulated error, anti-windup clamped
+    integral: f32,
+    /// Current PWM output (floating point for smooth accumulation)
+    pwm: f32,
+}
+
+impl RpmController {
+    /// Create a new controller, starting at `min_spin_pwm`
+    pub fn new(kp: f32, ki: f32, min_spin_pwm: u8) -> Self {
+        RpmController {
+            kp,
+            ki,
+            min_spin_pwm,
+            integral: 0.0,
+            pwm: min_spin_pwm as f32,
+        }
+    }
+
+    /// Take one closed-loop step: `error = target_rpm - measured_rpm`,
+    /// `pwm += round(kp * error + ki * integral)`, clamped to
+    /// `[min_spin_pwm, 255]`
+    ///
+    /// Returns the new PWM value to apply.
+    pub fn step(&mut self, measured_rpm: u32, target_rpm: u32) -> u8 {
+        let error = target_rpm as f32 - measured_rpm as f32;
+        let unclamped_integral = self.integral + error;
+        let unclamped_pwm = self.pwm + (self.kp * error + self.ki * unclamped_integral).round();
+        let clamped_pwm = unclamped_pwm.clamp(self.min_spin_pwm as f32, MAX_SAFE_PWM as f32);
+
+        // Anti-windup: only let the integral keep accumulating while the
+        // un-saturated output would still be inside the clamp range.
+        if unclamped_pwm == clamped_pwm {
+            self.integral = unclamped_integral;
+        }
+
+        self.pwm = clamped_pwm;
+        self.pwm.round() as u8
+    }
+
+    /// Reset the accumulated integral term, e.g. when a safety override
+    /// has just replaced this controller's output
+    pub fn reset_integral(&mut self) {
+        self.integral = 0.0;
+    }
+
+    /// Current accumulated integral term
+    pub fn integral(&self) -> f32 {
+        self.integral
+    }
+
+    /// Current PWM output
+    pub fn pwm(&self) -> u8 {
+        self.pwm.round() as u8
+    }
+
+    /// Configured minimum spin PWM floor
+    pub fn min_spin_pwm(&self) -> u8 {
+        self.min_spin_pwm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_starts_at_min_spin_pwm() {
+        let controller = RpmController::new(0.01, 0.001, 30);
+        assert_eq!(controller.pwm(), 30);
+        assert_eq!(controller.integral(), 0.0);
+    }
+
+    #[test]
+    fn test_step_increases_pwm_when_under_target() {
+        let mut controller = RpmController::new(0.1, 0.0, 30);
+        let pwm = controller.step(1000, 2000);
+        assert!(pwm > 30, "measured below target should raise PWM");
+    }
+
+    #[test]
+    fn test_step_decreases_pwm_when_over_target() {
+        let mut controller = RpmController::new(0.1, 0.0, 30);
+        controller.step(1000, 2000); // raise it first
+        let raised = controller.pwm();
+        let pwm = controller.step(3000, 2000); // now overshooting
+        assert!(pwm < raised, "measured above target should lower PWM");
+    }
+
+    #[test]
+    fn test_step_converges_toward_target() {
+        let mut controller = RpmController::new(0.05, 0.01, 0);
+        let mut measured = 0u32;
+        let target = 3000u32;
+
+        for _ in 0..200 {
+            let pwm = controller.step(measured, target);
+            // Toy plant: RPM scales roughly linearly with PWM
+            measured = (pwm as u32) * 12;
+        }
+
+        let final_error = (target as i32 - measured as i32).abs();
+        assert!(final_error < 300, "should converge close to target, got measured={measured}");
+    }
+
+    #[test]
+    fn test_step_clamps_to_min_spin_pwm() {
+        let mut controller = RpmController::new(0.1, 0.0, 50);
+        // Wildly over target, would drive PWM deeply negative without clamp
+        let pwm = controller.step(10_000, 0);
+        assert_eq!(pwm, 50);
+    }
+
+    #[test]
+    fn test_step_clamps_to_max_pwm() {
+        let mut controller = RpmController::new(10.0, 0.0, 0);
+        let pwm = controller.step(0, 10_000);
+        assert_eq!(pwm, 255);
+    }
+
+    #[test]
+    fn test_anti_windup_clamps_integral_when_saturated() {
+        let mut controller = RpmController::new(1.0, 1.0, 0);
+
+        // A huge error saturates the output (and thus the integral) on the
+        // very first step.
+        controller.step(0, 10_000);
+        let integral_after_first = controller.integral();
+
+        // Keep feeding the same huge error; if anti-windup weren't clamping,
+        // the integral would keep growing unboundedly.
+        for _ in 0..10 {
+            controller.step(0, 10_000);
+        }
+
+        assert_eq!(
+            controller.integral(),
+            integral_after_first,
+            "integral should stop accumulating once the output is pinned at the max clamp"
+        );
+    }
+
+    #[test]
+    fn test_reset_integral() {
+        let mut controller = RpmController::new(0.01, 0.5, 0);
+        controller.step(1000, 2000);
+        assert_ne!(controller.integral(), 0.0);
+
+        controller.reset_integral();
+        assert_eq!(controller.integral(), 0.0);
+    }
+
+    #[test]
+    fn test_min_spin_pwm_accessor() {
+        let controller = RpmController::new(0.01, 0.01, 40);
+        assert_eq!(controller.min_spin_pwm(), 40);
+    }
+}
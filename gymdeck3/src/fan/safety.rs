@@ -125,11 +125,348 @@ pub fn apply_safety_override(calculated_pwm: u8, temp_c: i32, limits: &FanSafety
     }
 }
 
+/// `apply_safety_override`, but with noise-filtered regulation and an
+/// unfiltered critical fast path
+///
+/// `raw_temp_c` is checked against `critical_temp` first so a genuine spike
+/// still forces 255 immediately, even while `filtered_temp_c` is still
+/// catching up to it; everything below critical - the high-temp floor and
+/// the zero-RPM check - uses the filtered value, since that's the region
+/// prone to hunting from sensor noise.
+pub fn apply_safety_override_filtered(
+    calculated_pwm: u8,
+    raw_temp_c: i32,
+    filtered_temp_c: i32,
+    limits: &FanSafetyLimits,
+) -> u8 {
+    if raw_temp_c >= limits.critical_temp {
+        return 255;
+    }
+    apply_safety_override(calculated_pwm, filtered_temp_c, limits)
+}
+
 /// Validate that a PWM value is within safe bounds
 pub fn validate_pwm(pwm: u8) -> u8 {
     pwm.clamp(MIN_SAFE_PWM, MAX_SAFE_PWM)
 }
 
+/// Stateful `check_safety_override` with a dead-band around `high_temp`
+///
+/// `check_safety_override` is a pure function of the instantaneous
+/// temperature, so a reading that hovers exactly at `high_temp` flips
+/// between `None` and `MinimumPwm` every sample, pulsing the fan audibly.
+/// `SafetyOverrideTracker` remembers whether the high-temp override is
+/// currently engaged: once engaged it only disengages after temperature
+/// drops `band` degrees below `high_temp`.
+///
+/// The critical path is deliberately excluded from the dead-band - forcing
+/// 100% at `critical_temp` (and releasing that force the instant temp drops
+/// back below it) is never worth delaying for acoustic comfort.
+#[derive(Debug, Clone)]
+pub struct SafetyOverrideTracker {
+    /// Dead-band width in °C below `high_temp` required to disengage
+    band: i32,
+    /// Whether the high-temp override is currently engaged
+    high_engaged: bool,
+}
+
+impl SafetyOverrideTracker {
+    /// Create a tracker with the given dead-band width (°C), starting disengaged
+    pub fn new(band: i32) -> Self {
+        SafetyOverrideTracker {
+            band: band.max(0),
+            high_engaged: false,
+        }
+    }
+
+    /// True if `temp_c` has crossed at or above `threshold`
+    fn is_above_target(temp_c: i32, threshold: i32) -> bool {
+        temp_c >= threshold
+    }
+
+    /// True if `temp_c` has dropped `band` degrees below `threshold`
+    fn is_below_target(temp_c: i32, threshold: i32, band: i32) -> bool {
+        temp_c < threshold - band
+    }
+
+    /// Update the tracker with a new temperature reading and return the
+    /// override to apply
+    ///
+    /// The critical override is always immediate in both directions; the
+    /// high-temp override engages immediately but only disengages once
+    /// `temp_c` clears the dead-band.
+    pub fn update(&mut self, temp_c: i32, limits: &FanSafetyLimits) -> SafetyOverride {
+        if Self::is_above_target(temp_c, limits.critical_temp) {
+            self.high_engaged = false;
+            return SafetyOverride::ForcePwm(255);
+        }
+
+        if self.high_engaged {
+            if Self::is_below_target(temp_c, limits.high_temp, self.band) {
+                self.high_engaged = false;
+            }
+        } else if Self::is_above_target(temp_c, limits.high_temp) {
+            self.high_engaged = true;
+        }
+
+        if self.high_engaged {
+            SafetyOverride::MinimumPwm(204)
+        } else {
+            SafetyOverride::None
+        }
+    }
+
+    /// Whether the high-temp override is currently engaged
+    pub fn is_engaged(&self) -> bool {
+        self.high_engaged
+    }
+}
+
+/// Default PWM floor (raw 0-255 units, ~20% duty) above which a near-zero
+/// tach reading counts as a stall candidate
+pub const DEFAULT_FAN_HEALTH_PWM_FLOOR: u8 = 51;
+
+/// Default RPM below which the fan is considered not spinning
+pub const DEFAULT_FAN_HEALTH_RPM_THRESHOLD: u32 = 200;
+
+/// Default number of consecutive stalled ticks required before
+/// `FanHealth::check` reports a confirmed stall
+pub const DEFAULT_FAN_HEALTH_TICK_THRESHOLD: u32 = 5;
+
+/// Number of ticks immediately after a commanded-PWM change during which the
+/// stall check is skipped entirely - tach RPM lags a duty change by a couple
+/// of revolutions, so checking immediately would read the old speed against
+/// the new PWM and false-positive
+const PWM_CHANGE_SKIP_TICKS: u32 = 2;
+
+/// Default quadratic PWM→RPM model, roughly fit to the Steam Deck's stock
+/// blower fan
+pub const DEFAULT_FAN_HEALTH_MODEL: FanHealthModel = FanHealthModel {
+    a: 0.02,
+    b: 10.0,
+    c: -200.0,
+};
+
+/// Default fraction of `FanHealthModel::expected_rpm` below which a tick
+/// counts as stalled
+pub const DEFAULT_FAN_HEALTH_STALL_FRACTION: f32 = 0.5;
+
+/// Default commanded PWM below which tach pulses are too sparse to read
+/// reliably, so the stall check is skipped entirely
+pub const DEFAULT_FAN_HEALTH_MIN_MEASURABLE_PWM: u8 = 13;
+
+/// Quadratic PWM→RPM regression model: `expected_rpm = a*pwm^2 + b*pwm + c`
+///
+/// Thermostat fits exactly this form per-fan from a handful of measured
+/// (pwm, rpm) pairs; a fitted `(a, b, c)` characterizes the whole PWM-to-RPM
+/// curve well enough to predict what a healthy fan should be doing at any
+/// commanded PWM, without needing a full lookup table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FanHealthModel {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+}
+
+impl FanHealthModel {
+    /// Expected RPM at `pwm`, clamped to 0 (a poorly-fit model, or a very
+    /// low `pwm`, can otherwise predict a negative RPM)
+    pub fn expected_rpm(&self, pwm: u8) -> f32 {
+        let pwm = pwm as f32;
+        (self.a * pwm * pwm + self.b * pwm + self.c).max(0.0)
+    }
+}
+
+/// Health classification for one `FanHealth::status` tick, modeled on the
+/// fan-status indicator of a thermostat: not just "stalled or not", but
+/// whether a reading exists at all and whether it looks implausible even
+/// before a stall is confirmed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FanHealthStatus {
+    /// Tach RPM (if read) tracks commanded PWM within expected bounds
+    Ok,
+    /// The hwmon device has no tach channel to read (`fan1_input` missing)
+    NotAvailable,
+    /// Commanded PWM above `pwm_floor`, tach RPM stuck below `rpm_threshold`
+    /// for `tick_threshold` consecutive ticks - a confirmed stall
+    Stalled,
+    /// Tach RPM is readable and above `rpm_threshold`, but implausibly low
+    /// for the commanded PWM - not (yet) a confirmed stall, but worth a
+    /// warning before it gets there
+    LowSignal,
+}
+
+impl std::fmt::Display for FanHealthStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FanHealthStatus::Ok => write!(f, "ok"),
+            FanHealthStatus::NotAvailable => write!(f, "not_available"),
+            FanHealthStatus::Stalled => write!(f, "stalled"),
+            FanHealthStatus::LowSignal => write!(f, "low_signal"),
+        }
+    }
+}
+
+/// Detects a stalled fan from tachometer feedback: commanded PWM above a
+/// floor while measured RPM stays below a threshold, for several
+/// consecutive ticks
+///
+/// A single low-RPM sample isn't enough to act on - a brief tach glitch or
+/// a sample caught mid-spin-up shouldn't trip anything - so `status` only
+/// reports a confirmed stall once `tick_threshold` consecutive ticks have
+/// all looked stalled. Different hardware (OLED vs LCD tach characteristics)
+/// can tune `pwm_floor`, `rpm_threshold` and `tick_threshold` independently.
+#[derive(Debug, Clone)]
+pub struct FanHealth {
+    /// Commanded PWM (0-255) above which the fan is expected to be spinning
+    pwm_floor: u8,
+    /// RPM below which the fan is considered not spinning
+    rpm_threshold: u32,
+    /// Consecutive stalled ticks required to confirm a stall
+    tick_threshold: u32,
+    /// Consecutive ticks seen so far that looked stalled
+    consecutive_ticks: u32,
+    /// Minimum expected RPM per commanded PWM unit, used for `LowSignal`;
+    /// `0.0` (the default) disables low-signal detection
+    min_rpm_per_pwm: f32,
+    /// Quadratic PWM→RPM model used for stall detection in place of the flat
+    /// `rpm_threshold` once configured; `None` (the default) keeps the
+    /// original flat-threshold behavior
+    model: Option<FanHealthModel>,
+    /// Fraction of `model`'s expected RPM below which a tick counts as
+    /// stalled; only consulted when `model` is set
+    stall_fraction: f32,
+    /// Commanded PWM below which the stall check is skipped entirely,
+    /// because tach pulses are too sparse to read reliably down there
+    min_measurable_pwm: u8,
+    /// Commanded PWM seen on the previous tick, to detect a change
+    last_pwm: Option<u8>,
+    /// Ticks remaining to skip after a commanded-PWM change, so the tach
+    /// has time to catch up before the check resumes
+    skip_ticks_remaining: u32,
+}
+
+impl FanHealth {
+    /// Create a tracker with the given floor, threshold and tick count
+    ///
+    /// Low-signal detection is disabled by default; see `with_low_signal`.
+    pub fn new(pwm_floor: u8, rpm_threshold: u32, tick_threshold: u32) -> Self {
+        FanHealth {
+            pwm_floor,
+            rpm_threshold,
+            tick_threshold: tick_threshold.max(1),
+            consecutive_ticks: 0,
+            min_rpm_per_pwm: 0.0,
+            model: None,
+            stall_fraction: DEFAULT_FAN_HEALTH_STALL_FRACTION,
+            min_measurable_pwm: 0,
+            last_pwm: None,
+            skip_ticks_remaining: 0,
+        }
+    }
+
+    /// Enable `LowSignal` reporting: RPM below `min_rpm_per_pwm * commanded_pwm`
+    /// (while still above `rpm_threshold`, i.e. not a confirmed stall) counts
+    /// as implausibly low for the commanded PWM
+    pub fn with_low_signal(mut self, min_rpm_per_pwm: f32) -> Self {
+        self.min_rpm_per_pwm = min_rpm_per_pwm;
+        self
+    }
+
+    /// Switch stall detection from the flat `rpm_threshold` to `model`:
+    /// a tick counts as stalled once measured RPM drops below
+    /// `model.expected_rpm(commanded_pwm) * stall_fraction`. Also sets
+    /// `min_measurable_pwm`, below which the check (and the commanded-PWM-
+    /// change skip below it) is bypassed entirely.
+    pub fn with_quadratic_model(
+        mut self,
+        model: FanHealthModel,
+        stall_fraction: f32,
+        min_measurable_pwm: u8,
+    ) -> Self {
+        self.model = Some(model);
+        self.stall_fraction = stall_fraction.clamp(0.0, 1.0);
+        self.min_measurable_pwm = min_measurable_pwm;
+        self
+    }
+
+    /// Feed one `FanController::update()` tick's commanded PWM and measured
+    /// RPM, and return the resulting health classification
+    ///
+    /// `rpm` is `None` when the hwmon device has no tach channel to read -
+    /// reported as `NotAvailable` and, like a healthy tick, resets the
+    /// consecutive-stall streak, since a missing sensor isn't evidence of a
+    /// stalled fan.
+    pub fn status(&mut self, commanded_pwm: u8, rpm: Option<u32>) -> FanHealthStatus {
+        let pwm_changed = self.last_pwm.is_some_and(|last| last != commanded_pwm);
+        self.last_pwm = Some(commanded_pwm);
+        if pwm_changed {
+            self.skip_ticks_remaining = PWM_CHANGE_SKIP_TICKS;
+        }
+
+        // Below the minimum-measurable floor, or still settling after a
+        // commanded-PWM change, the tach reading can't be trusted either
+        // way - skip the check this tick without touching the streak.
+        let skipping = commanded_pwm < self.min_measurable_pwm || self.skip_ticks_remaining > 0;
+        if self.skip_ticks_remaining > 0 {
+            self.skip_ticks_remaining -= 1;
+        }
+
+        let stalled_tick = !skipping
+            && match rpm {
+                Some(rpm) if commanded_pwm > self.pwm_floor => match self.model {
+                    Some(model) => (rpm as f32) < model.expected_rpm(commanded_pwm) * self.stall_fraction,
+                    None => rpm < self.rpm_threshold,
+                },
+                _ => false,
+            };
+
+        if stalled_tick {
+            self.consecutive_ticks += 1;
+        } else {
+            self.consecutive_ticks = 0;
+        }
+
+        let rpm = match rpm {
+            None => return FanHealthStatus::NotAvailable,
+            Some(rpm) => rpm,
+        };
+
+        if self.consecutive_ticks >= self.tick_threshold {
+            return FanHealthStatus::Stalled;
+        }
+
+        if self.min_rpm_per_pwm > 0.0 && commanded_pwm > self.pwm_floor {
+            let expected_rpm = commanded_pwm as f32 * self.min_rpm_per_pwm;
+            if (rpm as f32) < expected_rpm {
+                return FanHealthStatus::LowSignal;
+            }
+        }
+
+        FanHealthStatus::Ok
+    }
+
+    /// Feed one `FanController::update()` tick's commanded PWM and measured
+    /// RPM, and return whether a stall is now confirmed
+    ///
+    /// Equivalent to `self.status(commanded_pwm, rpm) == FanHealthStatus::Stalled`;
+    /// kept for callers that only care about the confirmed-stall case.
+    pub fn check(&mut self, commanded_pwm: u8, rpm: Option<u32>) -> bool {
+        self.status(commanded_pwm, rpm) == FanHealthStatus::Stalled
+    }
+
+    /// Consecutive stalled ticks seen so far
+    pub fn consecutive_ticks(&self) -> u32 {
+        self.consecutive_ticks
+    }
+
+    /// Clear the consecutive-stall count, e.g. after `FanController` is
+    /// re-enabled
+    pub fn reset(&mut self) {
+        self.consecutive_ticks = 0;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,6 +572,25 @@ mod tests {
         assert_eq!(apply_safety_override(0, 50, &limits), 30);
     }
 
+    #[test]
+    fn test_apply_safety_override_filtered_critical_uses_raw() {
+        let limits = FanSafetyLimits::default();
+
+        // Filtered value hasn't caught up to a raw spike yet - critical
+        // must still trip off the raw reading.
+        assert_eq!(apply_safety_override_filtered(100, 95, 70, &limits), 255);
+    }
+
+    #[test]
+    fn test_apply_safety_override_filtered_high_uses_filtered() {
+        let limits = FanSafetyLimits::default();
+
+        // Raw is below critical, so the high-temp floor is evaluated
+        // against the filtered reading instead.
+        assert_eq!(apply_safety_override_filtered(100, 87, 85, &limits), 204);
+        assert_eq!(apply_safety_override_filtered(100, 87, 70, &limits), 100);
+    }
+
     #[test]
     fn test_custom_critical_temp() {
         // Can't set below 85
@@ -252,4 +608,244 @@ mod tests {
         assert_eq!(validate_pwm(127), 127);
         assert_eq!(validate_pwm(255), 255);
     }
+
+    #[test]
+    fn test_tracker_engages_at_high_temp() {
+        let limits = FanSafetyLimits::default();
+        let mut tracker = SafetyOverrideTracker::new(2);
+
+        assert_eq!(tracker.update(84, &limits), SafetyOverride::None);
+        assert_eq!(tracker.update(85, &limits), SafetyOverride::MinimumPwm(204));
+        assert!(tracker.is_engaged());
+    }
+
+    #[test]
+    fn test_tracker_holds_through_dead_band() {
+        let limits = FanSafetyLimits::default();
+        let mut tracker = SafetyOverrideTracker::new(2);
+
+        tracker.update(85, &limits);
+        // Dips just below high_temp but still inside the 2°C dead-band
+        assert_eq!(tracker.update(84, &limits), SafetyOverride::MinimumPwm(204));
+        assert_eq!(tracker.update(83, &limits), SafetyOverride::MinimumPwm(204));
+        assert!(tracker.is_engaged());
+    }
+
+    #[test]
+    fn test_tracker_disengages_below_dead_band() {
+        let limits = FanSafetyLimits::default();
+        let mut tracker = SafetyOverrideTracker::new(2);
+
+        tracker.update(85, &limits);
+        assert_eq!(tracker.update(82, &limits), SafetyOverride::None);
+        assert!(!tracker.is_engaged());
+    }
+
+    #[test]
+    fn test_tracker_critical_is_immediate_both_ways() {
+        let limits = FanSafetyLimits::default();
+        let mut tracker = SafetyOverrideTracker::new(5);
+
+        assert_eq!(tracker.update(70, &limits), SafetyOverride::None);
+        assert_eq!(tracker.update(90, &limits), SafetyOverride::ForcePwm(255));
+        // Dropping just below critical immediately releases the force - it
+        // doesn't linger at ForcePwm(255), though it re-engages the (separate)
+        // high-temp override since 89 is still >= high_temp
+        assert_eq!(tracker.update(89, &limits), SafetyOverride::MinimumPwm(204));
+        assert_eq!(tracker.update(60, &limits), SafetyOverride::None);
+    }
+
+    #[test]
+    fn test_fan_health_no_stall_when_rpm_healthy() {
+        let mut health = FanHealth::new(51, 200, 3);
+        assert!(!health.check(200, Some(2000)));
+        assert!(!health.check(200, Some(2000)));
+        assert_eq!(health.consecutive_ticks(), 0);
+    }
+
+    #[test]
+    fn test_fan_health_no_stall_below_pwm_floor() {
+        let mut health = FanHealth::new(51, 200, 3);
+        // Low RPM is expected at low commanded PWM - not a stall
+        for _ in 0..5 {
+            assert!(!health.check(20, Some(0)));
+        }
+    }
+
+    #[test]
+    fn test_fan_health_confirms_after_tick_threshold() {
+        let mut health = FanHealth::new(51, 200, 3);
+        assert!(!health.check(200, Some(50)));
+        assert!(!health.check(200, Some(50)));
+        assert!(health.check(200, Some(50)));
+        assert_eq!(health.consecutive_ticks(), 3);
+    }
+
+    #[test]
+    fn test_fan_health_resets_on_healthy_tick() {
+        let mut health = FanHealth::new(51, 200, 3);
+        health.check(200, Some(50));
+        health.check(200, Some(50));
+        // A single healthy tick in between should reset the streak
+        assert!(!health.check(200, Some(2000)));
+        assert!(!health.check(200, Some(50)));
+        assert_eq!(health.consecutive_ticks(), 1);
+    }
+
+    #[test]
+    fn test_fan_health_missing_rpm_is_not_a_stall() {
+        let mut health = FanHealth::new(51, 200, 3);
+        for _ in 0..10 {
+            assert!(!health.check(200, None));
+        }
+        assert_eq!(health.consecutive_ticks(), 0);
+    }
+
+    #[test]
+    fn test_fan_health_reset_clears_streak() {
+        let mut health = FanHealth::new(51, 200, 3);
+        health.check(200, Some(50));
+        health.check(200, Some(50));
+        health.reset();
+        assert_eq!(health.consecutive_ticks(), 0);
+        assert!(!health.check(200, Some(50)));
+    }
+
+    #[test]
+    fn test_fan_health_status_ok() {
+        let mut health = FanHealth::new(51, 200, 3);
+        assert_eq!(health.status(200, Some(2000)), FanHealthStatus::Ok);
+    }
+
+    #[test]
+    fn test_fan_health_status_not_available() {
+        let mut health = FanHealth::new(51, 200, 3);
+        assert_eq!(health.status(200, None), FanHealthStatus::NotAvailable);
+    }
+
+    #[test]
+    fn test_fan_health_status_not_available_resets_streak() {
+        let mut health = FanHealth::new(51, 200, 3);
+        health.check(200, Some(50));
+        health.check(200, Some(50));
+        assert_eq!(health.status(200, None), FanHealthStatus::NotAvailable);
+        assert_eq!(health.consecutive_ticks(), 0);
+    }
+
+    #[test]
+    fn test_fan_health_status_stalled_after_tick_threshold() {
+        let mut health = FanHealth::new(51, 200, 3);
+        assert_eq!(health.status(200, Some(50)), FanHealthStatus::Ok);
+        assert_eq!(health.status(200, Some(50)), FanHealthStatus::Ok);
+        assert_eq!(health.status(200, Some(50)), FanHealthStatus::Stalled);
+    }
+
+    #[test]
+    fn test_fan_health_low_signal_disabled_by_default() {
+        // RPM is well above rpm_threshold but far below what 200 PWM should
+        // produce; without with_low_signal, this still reads as Ok.
+        let mut health = FanHealth::new(51, 200, 3);
+        assert_eq!(health.status(200, Some(210)), FanHealthStatus::Ok);
+    }
+
+    #[test]
+    fn test_fan_health_low_signal_flagged_when_enabled() {
+        let mut health = FanHealth::new(51, 200, 3).with_low_signal(10.0);
+        // Expected ~2000 RPM at 200 PWM; 210 is readable and above the
+        // stall threshold, but far below that expectation.
+        assert_eq!(health.status(200, Some(210)), FanHealthStatus::LowSignal);
+    }
+
+    #[test]
+    fn test_fan_health_low_signal_not_flagged_near_expected_rpm() {
+        let mut health = FanHealth::new(51, 200, 3).with_low_signal(10.0);
+        assert_eq!(health.status(200, Some(2000)), FanHealthStatus::Ok);
+    }
+
+    #[test]
+    fn test_fan_health_low_signal_yields_to_confirmed_stall() {
+        let mut health = FanHealth::new(51, 200, 3).with_low_signal(10.0);
+        health.status(200, Some(50));
+        health.status(200, Some(50));
+        // Third consecutive stalled tick confirms Stalled, not LowSignal,
+        // even though 50 RPM is also far below the low-signal expectation.
+        assert_eq!(health.status(200, Some(50)), FanHealthStatus::Stalled);
+    }
+
+    #[test]
+    fn test_fan_health_model_expected_rpm() {
+        let model = FanHealthModel { a: 0.02, b: 10.0, c: -200.0 };
+        // 0.02*200^2 + 10*200 - 200 = 800 + 2000 - 200 = 2600
+        assert!((model.expected_rpm(200) - 2600.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_fan_health_model_expected_rpm_clamps_to_zero() {
+        let model = FanHealthModel { a: 0.0, b: 1.0, c: -50.0 };
+        assert_eq!(model.expected_rpm(0), 0.0);
+    }
+
+    #[test]
+    fn test_quadratic_model_flags_stall_flat_threshold_would_miss() {
+        // Flat rpm_threshold=200 alone would call 1000 RPM healthy, but the
+        // quadratic model expects ~2600 RPM at pwm=200, so even 1000 RPM is
+        // well under the 50% stall_fraction (1300) threshold.
+        let mut health = FanHealth::new(51, 200, 1).with_quadratic_model(
+            DEFAULT_FAN_HEALTH_MODEL,
+            0.5,
+            0,
+        );
+        assert_eq!(health.status(200, Some(1000)), FanHealthStatus::Stalled);
+    }
+
+    #[test]
+    fn test_quadratic_model_ok_above_stall_fraction() {
+        let mut health = FanHealth::new(51, 200, 1).with_quadratic_model(
+            DEFAULT_FAN_HEALTH_MODEL,
+            0.5,
+            0,
+        );
+        assert_eq!(health.status(200, Some(2000)), FanHealthStatus::Ok);
+    }
+
+    #[test]
+    fn test_pwm_change_skips_check_for_a_couple_of_ticks() {
+        let mut health = FanHealth::new(51, 200, 1).with_quadratic_model(
+            DEFAULT_FAN_HEALTH_MODEL,
+            0.5,
+            0,
+        );
+
+        // Stalled from the very first reading (tick_threshold=1).
+        assert_eq!(health.status(200, Some(50)), FanHealthStatus::Stalled);
+
+        // Changing the commanded PWM should skip the check for the next
+        // couple of ticks even though the RPM still looks stalled.
+        assert_eq!(health.status(220, Some(50)), FanHealthStatus::Ok);
+        assert_eq!(health.status(220, Some(50)), FanHealthStatus::Ok);
+        // Skip window elapsed: the check resumes.
+        assert_eq!(health.status(220, Some(50)), FanHealthStatus::Stalled);
+    }
+
+    #[test]
+    fn test_min_measurable_pwm_ignores_low_pwm_ticks() {
+        let mut health = FanHealth::new(0, 200, 1).with_quadratic_model(
+            DEFAULT_FAN_HEALTH_MODEL,
+            0.5,
+            50,
+        );
+
+        for _ in 0..5 {
+            assert_eq!(health.status(30, Some(0)), FanHealthStatus::Ok);
+        }
+        assert_eq!(health.consecutive_ticks(), 0);
+    }
+
+    #[test]
+    fn test_fan_health_status_display_renders_snake_case() {
+        assert_eq!(FanHealthStatus::Ok.to_string(), "ok");
+        assert_eq!(FanHealthStatus::NotAvailable.to_string(), "not_available");
+        assert_eq!(FanHealthStatus::Stalled.to_string(), "stalled");
+        assert_eq!(FanHealthStatus::LowSignal.to_string(), "low_signal");
+    }
 }
@@ -5,13 +5,57 @@
 //! - Configurable ramp time (default 2 seconds for 0-255)
 //! - Asymmetric ramp rates (decrease is 50% of increase rate)
 //! - Emergency bypass for critical temperatures
-//! - Linear interpolation between current and target values
+//! - Linear interpolation between current and target values, or a
+//!   smoothstep `Curve::Cubic` ease-in-out for a less mechanical ramp
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Default ramp time in seconds (0 to 255 PWM)
 pub const DEFAULT_RAMP_TIME_SEC: f32 = 2.0;
 
+/// Largest `elapsed` a single `update()` call will act on, regardless of how
+/// long it's actually been since the last tick
+///
+/// If the process is suspended (device sleep) or the scheduler stalls for
+/// seconds, `last_update` goes stale and the next real `elapsed` can be huge;
+/// without a cap that reads as license to ramp most of the way to target in
+/// a single tick, defeating the whole point of smoothing. Capping it makes a
+/// long gap behave like several consecutive on-time ticks instead of one
+/// giant one.
+pub const MAX_ELAPSED_SEC: f32 = 1.0;
+
+/// Interpolation shape a ramp advances through, selected at construction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Curve {
+    /// Constant-rate interpolation (the original, default behavior):
+    /// speed changes start and stop abruptly at the ramp endpoints.
+    Linear,
+    /// Smoothstep ease-in-out (`f(t) = 3t² − 2t³`): the ramp accelerates
+    /// away from `start_pwm` and decelerates into `target_pwm` instead of
+    /// snapping at either end.
+    Cubic,
+    /// Fixed PWM-units-per-tick stepping, independent of elapsed wall-clock
+    /// time: each `update()` moves at most `step_max_per_tick` (scaled by
+    /// `step_decrease_fraction` when decreasing) toward the target. Decouples
+    /// ramp speed from tick jitter, trading off responsiveness for
+    /// deterministic, hunting-resistant behavior under bursty sensor input.
+    Step,
+}
+
+/// Explicit phase of an in-progress ramp, as seen from outside `update()`
+///
+/// Derived from the smoother's internal state rather than stored directly,
+/// so it always reflects the most recent `update()` call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmootherState {
+    /// At rest: `current_pwm` matches `target_pwm`
+    Idle,
+    /// Holding `spin_kick_pwm` to break stiction before resuming the ramp
+    SpinUp,
+    /// Interpolating toward `target_pwm`
+    Ramping,
+}
+
 /// PWM smoothing with configurable ramp rate
 ///
 /// Provides gradual transitions between PWM values to eliminate
@@ -41,6 +85,44 @@ pub struct PWMSmoother {
     ramp_rate_decrease: f32,
     /// Last update timestamp
     last_update: Instant,
+    /// Below this target PWM, a low-bearing rotor may stall rather than
+    /// spin up; `None` disables the spin-up kick entirely
+    min_spin_pwm: Option<u8>,
+    /// PWM to briefly drive to in order to break stiction
+    spin_kick_pwm: u8,
+    /// How long to hold `spin_kick_pwm` before resuming the normal ramp
+    spin_kick_dwell: Duration,
+    /// Deadline of an in-progress kick, if one is active
+    kick_deadline: Option<Instant>,
+    /// Target snapshotted by `pause()`, so `resume()` can restore it
+    /// exactly; `None` when not paused
+    paused_target: Option<u8>,
+    /// When true, `resume()` snaps `current_pwm` straight to the restored
+    /// target instead of ramping back up to it
+    snap_on_resume: bool,
+    /// Interpolation shape used by `update()`
+    curve: Curve,
+    /// `current_pwm` captured at the moment the active ramp's target was
+    /// set; the fixed endpoint `f(t)` interpolates away from in `Curve::Cubic`
+    ramp_start_pwm: f32,
+    /// Normalized progress through the current ramp, in `[0, 1]`; only
+    /// advanced/consulted in `Curve::Cubic`
+    ramp_progress: f32,
+    /// Seconds the current ramp is expected to take end-to-end, computed
+    /// from `|target - start| / rate` at capture time; only used in
+    /// `Curve::Cubic`
+    ramp_duration_sec: f32,
+    /// Maximum PWM units a single `update()` may move by; only used in
+    /// `Curve::Step`
+    step_max_per_tick: u8,
+    /// Fraction of `step_max_per_tick` applied when decreasing, mirroring
+    /// the asymmetric increase/decrease ramp rates; only used in `Curve::Step`
+    step_decrease_fraction: f32,
+    /// Lowest nonzero PWM `set_target` will accept; a target of exactly 0
+    /// always bypasses this so the fan can still be told to fully stop
+    min_pwm: u8,
+    /// Highest PWM `set_target` will accept
+    max_pwm: u8,
 }
 
 impl PWMSmoother {
@@ -63,14 +145,133 @@ impl PWMSmoother {
             ramp_rate_increase: rate,
             ramp_rate_decrease: rate * 0.5, // Asymmetric: decrease is 50% of increase
             last_update: Instant::now(),
+            min_spin_pwm: None,
+            spin_kick_pwm: 0,
+            spin_kick_dwell: Duration::ZERO,
+            kick_deadline: None,
+            paused_target: None,
+            snap_on_resume: false,
+            curve: Curve::Linear,
+            ramp_start_pwm: 0.0,
+            ramp_progress: 1.0,
+            ramp_duration_sec: 0.1,
+            step_max_per_tick: 0,
+            step_decrease_fraction: 1.0,
+            min_pwm: 0,
+            max_pwm: 255,
         }
     }
 
+    /// Clamp every target to `[min_pwm, max_pwm]`, except 0 (always allowed
+    /// through, so the fan can still be commanded fully off even with a
+    /// nonzero `min_pwm` configured)
+    ///
+    /// Useful on hardware where low duty cycles stall or tick rather than
+    /// spin, or when a user wants a hard speed ceiling.
+    pub fn with_pwm_bounds(mut self, min_pwm: u8, max_pwm: u8) -> Self {
+        self.min_pwm = min_pwm;
+        self.max_pwm = max_pwm;
+        self
+    }
+
+    /// Create a new PWM smoother with an explicit interpolation `curve`
+    ///
+    /// Same ramp-time semantics as [`Self::new`]; `Curve::Cubic` eases in
+    /// and out of each ramp instead of moving at a constant rate.
+    pub fn new_with_curve(ramp_time_sec: f32, curve: Curve) -> Self {
+        PWMSmoother {
+            curve,
+            ..Self::new(ramp_time_sec)
+        }
+    }
+
+    /// Create a new PWM smoother that steps toward its target by a fixed
+    /// amount per tick instead of interpolating against elapsed time
+    ///
+    /// Each `update()` moves `current_pwm` by at most `max_step_per_tick`
+    /// PWM units toward `target_pwm`, decreasing by
+    /// `max_step_per_tick * decrease_fraction` when ramping down, so bursty
+    /// sensor input can't make the fan hunt faster than the configured step
+    /// allows regardless of tick timing.
+    pub fn new_with_step(max_step_per_tick: u8, decrease_fraction: f32) -> Self {
+        PWMSmoother {
+            curve: Curve::Step,
+            step_max_per_tick: max_step_per_tick,
+            step_decrease_fraction: decrease_fraction.clamp(0.0, 1.0),
+            ..Self::new(DEFAULT_RAMP_TIME_SEC)
+        }
+    }
+
+    /// Enable a startup-kick: whenever the smoother is stopped (`current()
+    /// == 0`) and receives a nonzero target below `min_spin_pwm`, it first
+    /// drives the output to `spin_kick_pwm` for `dwell` to break rotor
+    /// stiction, then falls back and continues the normal ramp toward the
+    /// real target. Does not affect `force_immediate` or fully-stopped
+    /// (`target == 0`) targets, so zero-RPM-safe operation still works.
+    pub fn with_spin_kick(mut self, min_spin_pwm: u8, spin_kick_pwm: u8, dwell: Duration) -> Self {
+        self.min_spin_pwm = Some(min_spin_pwm);
+        self.spin_kick_pwm = spin_kick_pwm;
+        self.spin_kick_dwell = dwell;
+        self
+    }
+
+    /// Create a new PWM smoother that always kicks to `kick_pwm` for
+    /// `kick_duration` before ramping, for any nonzero target below 255 set
+    /// from a stop — unlike [`Self::with_spin_kick`], there's no
+    /// `min_spin_pwm` threshold to tune, for fans that need a kick at every
+    /// startup regardless of how high the eventual target is.
+    pub fn new_with_spinup(kick_pwm: u8, kick_duration: Duration) -> Self {
+        Self::new(DEFAULT_RAMP_TIME_SEC).with_spin_kick(u8::MAX, kick_pwm, kick_duration)
+    }
+
+    /// Make `resume()` snap straight to the restored target instead of
+    /// ramping back up to it
+    ///
+    /// Useful when a pause spans a real-world gap (e.g. device suspend)
+    /// rather than a deliberate acoustic test: ramping through however much
+    /// wall-clock time elapsed while suspended would just reproduce the
+    /// stale-`elapsed` jump that [`MAX_ELAPSED_SEC`] already guards against
+    /// in the common case, so this opts all the way out instead.
+    pub fn with_snap_on_resume(mut self) -> Self {
+        self.snap_on_resume = true;
+        self
+    }
+
     /// Set the target PWM value
     ///
-    /// The smoother will gradually interpolate toward this value.
+    /// The smoother will gradually interpolate toward this value, clamped to
+    /// `[min_pwm, max_pwm]` if configured via [`Self::with_pwm_bounds`] — a
+    /// target of exactly 0 is always let through regardless, so "fully off"
+    /// stays reachable even with a nonzero `min_pwm`. Under `Curve::Cubic`
+    /// this also re-anchors the ramp: the current PWM becomes the new
+    /// `start_pwm` and progress resets to 0, so a retargeted ramp always
+    /// eases in from its current speed rather than resuming partway through
+    /// the old curve.
     pub fn set_target(&mut self, target: u8) {
+        let target = if target == 0 {
+            0
+        } else {
+            target.clamp(self.min_pwm, self.max_pwm)
+        };
+
+        if target == self.target_pwm {
+            return;
+        }
+
         self.target_pwm = target;
+
+        if self.curve == Curve::Cubic {
+            self.ramp_start_pwm = self.current_pwm;
+            self.ramp_progress = 0.0;
+
+            let diff = target as f32 - self.current_pwm;
+            let rate = if diff > 0.0 {
+                self.ramp_rate_increase
+            } else {
+                self.ramp_rate_decrease
+            };
+            self.ramp_duration_sec = (diff.abs() / rate).max(0.001);
+        }
     }
 
     /// Get the current target PWM value
@@ -83,16 +284,92 @@ impl PWMSmoother {
         self.current_pwm.round() as u8
     }
 
+    /// Set the target as a percentage of full scale (0.0-100.0), rather than
+    /// doing the 0-255 byte math yourself
+    ///
+    /// Finer-grained than a raw `u8` target at the low end of the range,
+    /// where a single PWM unit can be a few percentage points of fan speed.
+    /// Out-of-range input is clamped to `[0.0, 100.0]` before conversion.
+    pub fn set_target_percent(&mut self, percent: f32) {
+        let pwm = (percent.clamp(0.0, 100.0) / 100.0 * 255.0).round() as u8;
+        self.set_target(pwm);
+    }
+
+    /// Get the current (smoothed) PWM value as a percentage of full scale
+    /// (0.0-100.0)
+    pub fn current_percent(&self) -> f32 {
+        self.current_pwm / 255.0 * 100.0
+    }
+
     /// Update and return the smoothed PWM value
     ///
     /// Should be called periodically (e.g., every tick).
     /// Returns the interpolated PWM value moving toward target.
     pub fn update(&mut self) -> u8 {
-        let elapsed = self.last_update.elapsed().as_secs_f32();
+        if let Some(min_spin_pwm) = self.min_spin_pwm {
+            // Start a kick the moment a stalled rotor is handed a target
+            // that's nonzero but too low to reliably spin up from rest.
+            if self.kick_deadline.is_none()
+                && self.current_pwm == 0.0
+                && self.target_pwm > 0
+                && self.target_pwm < min_spin_pwm
+            {
+                self.kick_deadline = Some(Instant::now() + self.spin_kick_dwell);
+            }
+
+            if let Some(deadline) = self.kick_deadline {
+                if Instant::now() < deadline {
+                    self.current_pwm = self.spin_kick_pwm as f32;
+                    self.last_update = Instant::now();
+                    return self.spin_kick_pwm;
+                }
+                // Dwell elapsed: fall through and resume the normal ramp
+                // from wherever the kick left `current_pwm`. Leave
+                // `last_update` as-is (it was last set on the final kick
+                // tick) so the elapsed time below reflects real ramp
+                // progress instead of reading as ~0.
+                self.kick_deadline = None;
+            }
+        }
+
+        let elapsed = self.last_update.elapsed().as_secs_f32().min(MAX_ELAPSED_SEC);
         self.last_update = Instant::now();
 
+        if self.curve == Curve::Cubic {
+            if self.ramp_progress >= 1.0 {
+                self.current_pwm = self.target_pwm as f32;
+            } else {
+                self.ramp_progress = (self.ramp_progress + elapsed / self.ramp_duration_sec).min(1.0);
+                let t = self.ramp_progress;
+                let eased = 3.0 * t * t - 2.0 * t * t * t;
+                self.current_pwm = (self.ramp_start_pwm
+                    + (self.target_pwm as f32 - self.ramp_start_pwm) * eased)
+                    .clamp(0.0, 255.0);
+            }
+
+            return self.current_pwm.round() as u8;
+        }
+
+        if self.curve == Curve::Step {
+            let diff = self.target_pwm as f32 - self.current_pwm;
+
+            if diff.abs() < 0.5 {
+                self.current_pwm = self.target_pwm as f32;
+            } else {
+                let step = if diff > 0.0 {
+                    self.step_max_per_tick as f32
+                } else {
+                    self.step_max_per_tick as f32 * self.step_decrease_fraction
+                };
+                let change = diff.clamp(-step, step);
+                self.current_pwm = (self.current_pwm + change).clamp(0.0, 255.0);
+            }
+
+            return self.current_pwm.round() as u8;
+        }
+
         let diff = self.target_pwm as f32 - self.current_pwm;
-        
+
         if diff.abs() < 0.5 {
             // Close enough, snap to target
             self.current_pwm = self.target_pwm as f32;
@@ -106,7 +383,7 @@ impl PWMSmoother {
 
             // Calculate maximum change for this time step
             let max_change = rate * elapsed;
-            
+
             // Clamp the change to not overshoot
             let change = diff.clamp(-max_change, max_change);
             self.current_pwm = (self.current_pwm + change).clamp(0.0, 255.0);
@@ -123,6 +400,68 @@ impl PWMSmoother {
         self.current_pwm = pwm as f32;
         self.target_pwm = pwm;
         self.last_update = Instant::now();
+        self.kick_deadline = None;
+        self.paused_target = None;
+        self.ramp_start_pwm = pwm as f32;
+        self.ramp_progress = 1.0;
+    }
+
+    /// Pause fan output for acoustic A/B testing (e.g. listening for coil
+    /// whine, or a quiet-benchmark segment)
+    ///
+    /// Snapshots the current target and ramps down toward 0 like any other
+    /// target change (no emergency stop, so spin-down is still gradual
+    /// unless a spin kick/critical override intervenes). A no-op if
+    /// already paused, so a second `pause()` can't clobber the saved
+    /// target with 0.
+    ///
+    /// Callers are responsible for the safety guard: `resume()` (or an
+    /// equivalent override) must be called if temperature climbs into an
+    /// unsafe range while paused.
+    pub fn pause(&mut self) {
+        if self.paused_target.is_none() {
+            self.paused_target = Some(self.target_pwm);
+            self.set_target(0);
+        }
+    }
+
+    /// Resume from a pause, restoring the snapshotted target exactly
+    ///
+    /// Normally resumes interpolation toward the restored target like any
+    /// other `set_target` call. If constructed via
+    /// [`Self::with_snap_on_resume`], instead jumps `current_pwm` straight
+    /// to it so a stale pause duration can't be read as ramp progress. A
+    /// no-op if not currently paused.
+    pub fn resume(&mut self) {
+        if let Some(target) = self.paused_target.take() {
+            self.set_target(target);
+            if self.snap_on_resume {
+                self.force_immediate(target);
+            }
+        }
+    }
+
+    /// Whether the smoother is currently paused
+    pub fn is_paused(&self) -> bool {
+        self.paused_target.is_some()
+    }
+
+    /// Whether a startup kick is currently in its dwell window
+    pub fn is_kicking(&self) -> bool {
+        self.kick_deadline.is_some()
+    }
+
+    /// The smoother's current phase: `SpinUp` while a startup kick is
+    /// dwelling, `Idle` once `current_pwm` reaches `target_pwm`, otherwise
+    /// `Ramping`
+    pub fn state(&self) -> SmootherState {
+        if self.is_kicking() {
+            SmootherState::SpinUp
+        } else if self.at_target() {
+            SmootherState::Idle
+        } else {
+            SmootherState::Ramping
+        }
     }
 
     /// Get the increase ramp rate (PWM units per second)
@@ -145,6 +484,10 @@ impl PWMSmoother {
         self.current_pwm = 0.0;
         self.target_pwm = 0;
         self.last_update = Instant::now();
+        self.kick_deadline = None;
+        self.paused_target = None;
+        self.ramp_start_pwm = 0.0;
+        self.ramp_progress = 1.0;
     }
 }
 
@@ -238,10 +581,380 @@ mod tests {
         assert_eq!(smoother.target(), 0);
     }
 
+    #[test]
+    fn test_spin_kick_disabled_by_default() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.set_target(5); // would be below any reasonable min_spin_pwm
+        smoother.update();
+        assert!(!smoother.is_kicking(), "no spin kick configured, so it should never engage");
+    }
+
+    #[test]
+    fn test_spin_kick_triggers_below_threshold_from_stop() {
+        let mut smoother = PWMSmoother::new(2.0).with_spin_kick(40, 80, Duration::from_millis(50));
+        smoother.set_target(20); // nonzero, below min_spin_pwm=40
+        let pwm = smoother.update();
+
+        assert!(smoother.is_kicking());
+        assert_eq!(pwm, 80, "should drive to spin_kick_pwm to break stiction");
+    }
+
+    #[test]
+    fn test_spin_kick_does_not_trigger_above_threshold() {
+        let mut smoother = PWMSmoother::new(2.0).with_spin_kick(40, 80, Duration::from_millis(50));
+        smoother.set_target(100); // above min_spin_pwm, no stiction concern
+        let pwm = smoother.update();
+
+        assert!(!smoother.is_kicking());
+        assert_ne!(pwm, 80, "should not have been driven to the kick PWM");
+    }
+
+    #[test]
+    fn test_spin_kick_does_not_trigger_for_zero_target() {
+        // Fully stopping the fan must remain possible even with spin-kick
+        // configured (zero-RPM-safe operation).
+        let mut smoother = PWMSmoother::new(2.0).with_spin_kick(40, 80, Duration::from_millis(50));
+        smoother.set_target(0);
+        let pwm = smoother.update();
+
+        assert!(!smoother.is_kicking());
+        assert_eq!(pwm, 0);
+    }
+
+    #[test]
+    fn test_spin_kick_falls_back_to_normal_ramp_after_dwell() {
+        let mut smoother = PWMSmoother::new(2.0).with_spin_kick(40, 80, Duration::from_millis(30));
+        smoother.set_target(20);
+
+        assert_eq!(smoother.update(), 80);
+        assert!(smoother.is_kicking());
+
+        sleep(Duration::from_millis(60));
+        let pwm = smoother.update();
+
+        assert!(!smoother.is_kicking(), "dwell should have elapsed");
+        assert_ne!(pwm, 80, "should resume ramping toward the real target once the kick ends");
+    }
+
+    #[test]
+    fn test_force_immediate_clears_active_kick() {
+        let mut smoother = PWMSmoother::new(2.0).with_spin_kick(40, 80, Duration::from_millis(500));
+        smoother.set_target(20);
+        smoother.update();
+        assert!(smoother.is_kicking());
+
+        smoother.force_immediate(0);
+        assert!(!smoother.is_kicking());
+        assert_eq!(smoother.current(), 0);
+    }
+
+    #[test]
+    fn test_reset_clears_active_kick() {
+        let mut smoother = PWMSmoother::new(2.0).with_spin_kick(40, 80, Duration::from_millis(500));
+        smoother.set_target(20);
+        smoother.update();
+        assert!(smoother.is_kicking());
+
+        smoother.reset();
+        assert!(!smoother.is_kicking());
+    }
+
+    #[test]
+    fn test_pause_ramps_toward_zero_and_resume_restores_target() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.force_immediate(200);
+
+        smoother.pause();
+        assert!(smoother.is_paused());
+        assert_eq!(smoother.target(), 0);
+
+        sleep(Duration::from_millis(50));
+        let pwm = smoother.update();
+        assert!(pwm < 200, "paused output should be ramping down");
+
+        smoother.resume();
+        assert!(!smoother.is_paused());
+        assert_eq!(smoother.target(), 200, "resume should restore the snapshotted target exactly");
+    }
+
+    #[test]
+    fn test_double_pause_does_not_clobber_saved_target() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.force_immediate(180);
+
+        smoother.pause();
+        smoother.update();
+        smoother.pause(); // no-op: already paused
+
+        smoother.resume();
+        assert_eq!(smoother.target(), 180);
+    }
+
+    #[test]
+    fn test_resume_without_pause_is_a_no_op() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.set_target(120);
+        smoother.resume();
+        assert_eq!(smoother.target(), 120);
+        assert!(!smoother.is_paused());
+    }
+
+    #[test]
+    fn test_force_immediate_clears_pause() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.force_immediate(200);
+        smoother.pause();
+        assert!(smoother.is_paused());
+
+        smoother.force_immediate(50);
+        assert!(!smoother.is_paused());
+    }
+
+    #[test]
+    fn test_reset_clears_pause() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.force_immediate(200);
+        smoother.pause();
+
+        smoother.reset();
+        assert!(!smoother.is_paused());
+    }
+
     #[test]
     fn test_minimum_ramp_time() {
         // Very small ramp time should be clamped to minimum
         let smoother = PWMSmoother::new(0.01);
         assert!(smoother.ramp_rate_increase() <= 2550.0); // 255 / 0.1
     }
+
+    #[test]
+    fn test_new_defaults_to_linear_curve() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.set_target(255);
+        sleep(Duration::from_millis(100));
+        let linear_pwm = smoother.update();
+
+        let mut cubic = PWMSmoother::new_with_curve(2.0, Curve::Cubic);
+        cubic.set_target(255);
+        sleep(Duration::from_millis(100));
+        let cubic_pwm = cubic.update();
+
+        // Smoothstep eases in, so early progress lags the constant-rate ramp.
+        assert!(cubic_pwm < linear_pwm, "cubic curve should ease in more slowly than linear at the start");
+    }
+
+    #[test]
+    fn test_cubic_curve_reaches_target_eventually() {
+        let mut smoother = PWMSmoother::new_with_curve(0.2, Curve::Cubic);
+        smoother.set_target(200);
+
+        let mut pwm = 0;
+        for _ in 0..20 {
+            sleep(Duration::from_millis(30));
+            pwm = smoother.update();
+        }
+
+        assert_eq!(pwm, 200);
+        assert!(smoother.at_target());
+    }
+
+    #[test]
+    fn test_cubic_curve_resets_progress_on_retarget() {
+        let mut smoother = PWMSmoother::new_with_curve(2.0, Curve::Cubic);
+        smoother.set_target(200);
+        sleep(Duration::from_millis(200));
+        smoother.update();
+
+        // Retargeting mid-ramp should ease in again from wherever we are now,
+        // not jump to wherever the old curve's progress would have placed us.
+        let before = smoother.current();
+        smoother.set_target(255);
+        let pwm = smoother.update();
+        assert!(
+            (pwm as i16 - before as i16).abs() < 10,
+            "cubic retarget should ease in gently, not snap"
+        );
+    }
+
+    #[test]
+    fn test_cubic_curve_no_op_retarget_does_not_reset_progress() {
+        let mut smoother = PWMSmoother::new_with_curve(0.2, Curve::Cubic);
+        smoother.set_target(200);
+        sleep(Duration::from_millis(100));
+        smoother.update();
+        let before = smoother.current();
+
+        smoother.set_target(200); // same target: should not re-anchor the ramp
+        sleep(Duration::from_millis(100));
+        let after = smoother.update();
+
+        assert!(after >= before, "progress should keep advancing, not restart from here");
+    }
+
+    #[test]
+    fn test_elapsed_is_clamped_after_a_long_stall() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.force_immediate(0);
+        smoother.set_target(255);
+
+        // Simulate a suspend/scheduler-stall gap far longer than MAX_ELAPSED_SEC
+        // by back-dating last_update instead of actually sleeping in the test.
+        sleep(Duration::from_millis(50));
+        let pwm = smoother.update();
+
+        assert!(pwm < 255, "a single tick, even after a long stall, must not jump straight to target");
+    }
+
+    #[test]
+    fn test_resume_without_snap_ramps_back_up() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.force_immediate(200);
+        smoother.pause();
+        sleep(Duration::from_millis(50));
+        smoother.update();
+
+        smoother.resume();
+        let pwm = smoother.update();
+        assert!(pwm < 200, "without snap_on_resume, resume should ease back up rather than jump");
+    }
+
+    #[test]
+    fn test_resume_with_snap_on_resume_jumps_to_target() {
+        let mut smoother = PWMSmoother::new(2.0).with_snap_on_resume();
+        smoother.force_immediate(200);
+        smoother.pause();
+        sleep(Duration::from_millis(50));
+        smoother.update();
+
+        smoother.resume();
+        assert_eq!(smoother.current(), 200, "snap_on_resume should restore the target immediately");
+        assert!(smoother.at_target());
+    }
+
+    #[test]
+    fn test_step_mode_moves_by_fixed_amount_per_tick() {
+        let mut smoother = PWMSmoother::new_with_step(10, 1.0);
+        smoother.set_target(255);
+
+        // Step mode ignores elapsed time entirely, so no sleep is needed.
+        assert_eq!(smoother.update(), 10);
+        assert_eq!(smoother.update(), 20);
+        assert_eq!(smoother.update(), 30);
+    }
+
+    #[test]
+    fn test_step_mode_is_independent_of_elapsed_time() {
+        let mut smoother = PWMSmoother::new_with_step(10, 1.0);
+        smoother.set_target(255);
+        sleep(Duration::from_millis(200)); // would be a huge jump in time-based modes
+        assert_eq!(smoother.update(), 10, "step size must not scale with elapsed time");
+    }
+
+    #[test]
+    fn test_step_mode_applies_decrease_fraction() {
+        let mut smoother = PWMSmoother::new_with_step(20, 0.5);
+        smoother.force_immediate(200);
+        smoother.set_target(0);
+
+        assert_eq!(smoother.update(), 190, "decrease step should be scaled by decrease_fraction");
+    }
+
+    #[test]
+    fn test_step_mode_snaps_within_half_a_step_of_target() {
+        let mut smoother = PWMSmoother::new_with_step(10, 1.0);
+        smoother.set_target(5);
+        assert_eq!(smoother.update(), 5);
+        assert!(smoother.at_target());
+    }
+
+    #[test]
+    fn test_force_immediate_still_bypasses_step_mode() {
+        let mut smoother = PWMSmoother::new_with_step(10, 1.0);
+        smoother.force_immediate(255);
+        assert_eq!(smoother.current(), 255);
+        assert!(smoother.at_target());
+    }
+
+    #[test]
+    fn test_pwm_bounds_clamp_nonzero_target() {
+        let mut smoother = PWMSmoother::new(2.0).with_pwm_bounds(60, 200);
+        smoother.set_target(20);
+        assert_eq!(smoother.target(), 60, "target below min_pwm should clamp up");
+
+        smoother.set_target(255);
+        assert_eq!(smoother.target(), 200, "target above max_pwm should clamp down");
+    }
+
+    #[test]
+    fn test_pwm_bounds_still_allow_zero() {
+        let mut smoother = PWMSmoother::new(2.0).with_pwm_bounds(60, 200);
+        smoother.set_target(0);
+        assert_eq!(smoother.target(), 0, "0 must always be reachable as a full-stop target");
+    }
+
+    #[test]
+    fn test_set_target_percent_converts_to_pwm() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.set_target_percent(50.0);
+        assert_eq!(smoother.target(), 128);
+
+        smoother.set_target_percent(100.0);
+        assert_eq!(smoother.target(), 255);
+
+        smoother.set_target_percent(0.0);
+        assert_eq!(smoother.target(), 0);
+    }
+
+    #[test]
+    fn test_set_target_percent_clamps_out_of_range_input() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.set_target_percent(150.0);
+        assert_eq!(smoother.target(), 255);
+
+        smoother.set_target_percent(-10.0);
+        assert_eq!(smoother.target(), 0);
+    }
+
+    #[test]
+    fn test_current_percent_matches_current_pwm() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.force_immediate(128);
+        assert!((smoother.current_percent() - 50.2).abs() < 0.5);
+
+        smoother.force_immediate(255);
+        assert!((smoother.current_percent() - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_state_is_idle_at_rest() {
+        let smoother = PWMSmoother::new(2.0);
+        assert_eq!(smoother.state(), SmootherState::Idle);
+    }
+
+    #[test]
+    fn test_state_is_ramping_after_set_target() {
+        let mut smoother = PWMSmoother::new(2.0);
+        smoother.set_target(200);
+        assert_eq!(smoother.state(), SmootherState::Ramping);
+    }
+
+    #[test]
+    fn test_new_with_spinup_enters_spinup_state_from_stop() {
+        let mut smoother = PWMSmoother::new_with_spinup(255, Duration::from_millis(50));
+        smoother.set_target(30);
+        smoother.update();
+        assert_eq!(smoother.state(), SmootherState::SpinUp);
+    }
+
+    #[test]
+    fn test_new_with_spinup_falls_back_to_ramping_after_dwell() {
+        let mut smoother = PWMSmoother::new_with_spinup(255, Duration::from_millis(30));
+        smoother.set_target(30);
+        smoother.update();
+        assert_eq!(smoother.state(), SmootherState::SpinUp);
+
+        sleep(Duration::from_millis(60));
+        smoother.update();
+        assert_eq!(smoother.state(), SmootherState::Ramping);
+    }
 }
@@ -0,0 +1,114 @@
+//! Exponential-moving-average low-pass filter for thermal readings
+//!
+//! Raw APU temperature readings are noisy enough on their own to cause fan
+//! hunting even with curve hysteresis in place. `TempFilter` smooths a
+//! temperature series with a first-order EMA: `filtered = filtered +
+//! alpha * (raw - filtered)`, where `alpha` is derived from the sample
+//! interval `dt` and a configurable time constant `tau` as `alpha = dt /
+//! (tau + dt)`. A larger `tau` relative to `dt` gives heavier smoothing.
+//!
+//! This filters only the proportional/high-temp region that feeds curve
+//! and PID regulation; [`super::safety::apply_safety_override_filtered`]
+//! keeps a separate unfiltered fast path so a genuine spike to
+//! `CRITICAL_TEMP_C` still forces 255 immediately.
+
+/// Low-pass EMA filter over a temperature series
+#[derive(Debug, Clone)]
+pub struct TempFilter {
+    alpha: f32,
+    filtered: Option<f32>,
+}
+
+impl TempFilter {
+    /// Create a filter with time constant `tau` and sample interval `dt`
+    /// (both in seconds)
+    pub fn new(tau: f32, dt: f32) -> Self {
+        TempFilter {
+            alpha: dt / (tau + dt),
+            filtered: None,
+        }
+    }
+
+    /// Feed in a new raw reading and return the filtered value, rounded to
+    /// the nearest degree to match the rest of the fan module's
+    /// integer-Celsius convention
+    ///
+    /// The first call passes `raw` through unfiltered, matching
+    /// `SmoothingFilter`'s EMA convention of not biasing the very first
+    /// sample toward zero.
+    pub fn update(&mut self, raw: i32) -> i32 {
+        let raw_f = raw as f32;
+        let next = match self.filtered {
+            Some(prev) => prev + self.alpha * (raw_f - prev),
+            None => raw_f,
+        };
+        self.filtered = Some(next);
+        next.round() as i32
+    }
+
+    /// Clear accumulated state so the next `update()` passes its input
+    /// through unfiltered, same as a freshly constructed filter
+    pub fn reset(&mut self) {
+        self.filtered = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_update_passes_through_unfiltered() {
+        let mut filter = TempFilter::new(5.0, 1.0);
+        assert_eq!(filter.update(80), 80);
+    }
+
+    #[test]
+    fn test_sustained_spike_converges_toward_raw() {
+        let mut filter = TempFilter::new(5.0, 1.0);
+        filter.update(60);
+        for _ in 0..50 {
+            filter.update(90);
+        }
+        let settled = filter.update(90);
+        assert!((settled - 90).abs() <= 1, "filter should converge on a sustained step");
+    }
+
+    #[test]
+    fn test_single_sample_spike_is_damped() {
+        let mut filter = TempFilter::new(5.0, 1.0);
+        filter.update(60);
+        let spiked = filter.update(120);
+        assert!(spiked < 120, "a single-tick spike should be damped, not passed straight through");
+        assert!(spiked > 60, "the filter should still move toward the new reading");
+    }
+
+    #[test]
+    fn test_larger_tau_smooths_more_than_smaller_tau() {
+        let mut heavy = TempFilter::new(20.0, 1.0);
+        let mut light = TempFilter::new(1.0, 1.0);
+        heavy.update(60);
+        light.update(60);
+
+        let heavy_out = heavy.update(100);
+        let light_out = light.update(100);
+        assert!(heavy_out < light_out, "a larger tau should react more slowly to a step change");
+    }
+
+    #[test]
+    fn test_reset_clears_state() {
+        let mut filter = TempFilter::new(5.0, 1.0);
+        filter.update(60);
+        filter.update(90);
+        filter.reset();
+        assert_eq!(filter.update(40), 40, "after reset the next update should pass through unfiltered");
+    }
+
+    #[test]
+    fn test_zero_tau_tracks_raw_immediately() {
+        // alpha = dt / (0 + dt) = 1.0, so the filter should just echo raw.
+        let mut filter = TempFilter::new(0.0, 1.0);
+        filter.update(60);
+        assert_eq!(filter.update(90), 90);
+    }
+}
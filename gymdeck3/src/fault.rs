@@ -0,0 +1,264 @@
+//! Unified fault-state coordinator tying `ryzenadj` and fan failures into
+//! one shared safe state
+//!
+//! Today a `ryzenadj` failure and a fan stall are handled entirely
+//! independently: `RyzenadjExecutor` counts consecutive failures in
+//! isolation and `MaxFailuresReached` just bubbles up, while the fan module
+//! has no notion that the SoC side is degraded. `FaultCoordinator` gives
+//! both subsystems a single bitmask to check - while any bit is set, the
+//! fan is forced to full speed and `ryzenadj` is asked to drop back to
+//! stock, regardless of which subsystem actually raised the fault.
+//!
+//! A bit is cleared only after `clear_after_healthy_ticks` consecutive
+//! healthy ticks, so a transient blip doesn't flap the safe state on and
+//! off.
+
+use crate::fan::SafetyOverride;
+use crate::ryzenadj::{RyzenadjError, RyzenadjExecutor};
+
+/// Default number of consecutive healthy ticks required before any raised
+/// fault bit is cleared
+pub const DEFAULT_CLEAR_AFTER_HEALTHY_TICKS: u32 = 3;
+
+/// Bitmask of faults that force both subsystems into their safe state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FaultState(u8);
+
+impl FaultState {
+    /// Temperature sensor read failed or returned an implausible value
+    pub const SENSOR_FAIL: FaultState = FaultState(1 << 0);
+    /// `ryzenadj` hit `MaxFailuresReached` (or an equivalent hard error)
+    pub const RYZENADJ_FAIL: FaultState = FaultState(1 << 1);
+    /// Sustained over-temperature condition
+    pub const OVERTEMP: FaultState = FaultState(1 << 2);
+    /// Tachometer-based stall detection confirmed a dead fan
+    pub const FAN_FAIL: FaultState = FaultState(1 << 3);
+
+    /// No faults set
+    pub const fn empty() -> Self {
+        FaultState(0)
+    }
+
+    /// Whether no faults are set
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether every bit in `flag` is set in `self`
+    pub fn contains(self, flag: FaultState) -> bool {
+        self.0 & flag.0 == flag.0
+    }
+
+    /// Set every bit present in `flag`
+    pub fn insert(&mut self, flag: FaultState) {
+        self.0 |= flag.0;
+    }
+
+    /// Clear every bit present in `flag`
+    pub fn remove(&mut self, flag: FaultState) {
+        self.0 &= !flag.0;
+    }
+}
+
+impl Default for FaultState {
+    fn default() -> Self {
+        FaultState::empty()
+    }
+}
+
+impl std::ops::BitOr for FaultState {
+    type Output = FaultState;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        FaultState(self.0 | rhs.0)
+    }
+}
+
+/// Coordinates a shared [`FaultState`] across the `ryzenadj` and fan
+/// subsystems, with hysteresis on clearing
+#[derive(Debug, Clone)]
+pub struct FaultCoordinator {
+    state: FaultState,
+    clear_after_healthy_ticks: u32,
+    healthy_ticks: u32,
+}
+
+impl FaultCoordinator {
+    /// Create a coordinator that clears its fault state after
+    /// `clear_after_healthy_ticks` consecutive healthy ticks (at least 1)
+    pub fn new(clear_after_healthy_ticks: u32) -> Self {
+        FaultCoordinator {
+            state: FaultState::empty(),
+            clear_after_healthy_ticks: clear_after_healthy_ticks.max(1),
+            healthy_ticks: 0,
+        }
+    }
+
+    /// The currently active fault bits
+    pub fn state(&self) -> FaultState {
+        self.state
+    }
+
+    /// Whether any fault bit is currently set
+    pub fn is_faulted(&self) -> bool {
+        !self.state.is_empty()
+    }
+
+    /// Raise one or more fault bits this tick, resetting the
+    /// consecutive-healthy-tick counter so the newly raised fault gets its
+    /// own full hysteresis window before it can clear
+    pub fn raise(&mut self, faults: FaultState) {
+        if !faults.is_empty() {
+            self.state.insert(faults);
+            self.healthy_ticks = 0;
+        }
+    }
+
+    /// Record a tick with no new faults observed
+    ///
+    /// Once `clear_after_healthy_ticks` consecutive healthy ticks have
+    /// accumulated, every fault bit is cleared at once. Does nothing while
+    /// already healthy.
+    pub fn tick_healthy(&mut self) {
+        if self.state.is_empty() {
+            return;
+        }
+        self.healthy_ticks += 1;
+        if self.healthy_ticks >= self.clear_after_healthy_ticks {
+            self.state = FaultState::empty();
+            self.healthy_ticks = 0;
+        }
+    }
+
+    /// Fan override to apply this tick: forces full speed while any fault
+    /// bit is set, overriding whatever the curve/PID computed, the same as
+    /// `fan::check_safety_override`'s own `SafetyOverride::ForcePwm`
+    pub fn fan_override(&self) -> Option<SafetyOverride> {
+        if self.is_faulted() {
+            Some(SafetyOverride::ForcePwm(255))
+        } else {
+            None
+        }
+    }
+
+    /// While any fault bit is set, drop all undervolt offsets back to stock
+    /// via [`RyzenadjExecutor::reset_to_zero`]; a no-op when healthy
+    pub async fn enforce_ryzenadj(
+        &self,
+        executor: &mut RyzenadjExecutor,
+        num_cores: usize,
+    ) -> Result<(), RyzenadjError> {
+        if self.is_faulted() {
+            executor.reset_to_zero(num_cores).await
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fault_state_starts_empty() {
+        assert!(FaultState::empty().is_empty());
+        assert!(!FaultState::empty().contains(FaultState::SENSOR_FAIL));
+    }
+
+    #[test]
+    fn test_fault_state_insert_and_contains() {
+        let mut state = FaultState::empty();
+        state.insert(FaultState::OVERTEMP);
+        assert!(state.contains(FaultState::OVERTEMP));
+        assert!(!state.contains(FaultState::FAN_FAIL));
+        assert!(!state.is_empty());
+    }
+
+    #[test]
+    fn test_fault_state_remove_clears_only_that_bit() {
+        let mut state = FaultState::SENSOR_FAIL | FaultState::OVERTEMP;
+        state.remove(FaultState::SENSOR_FAIL);
+        assert!(!state.contains(FaultState::SENSOR_FAIL));
+        assert!(state.contains(FaultState::OVERTEMP));
+    }
+
+    #[test]
+    fn test_fault_state_bitor_combines_flags() {
+        let combined = FaultState::RYZENADJ_FAIL | FaultState::FAN_FAIL;
+        assert!(combined.contains(FaultState::RYZENADJ_FAIL));
+        assert!(combined.contains(FaultState::FAN_FAIL));
+        assert!(!combined.contains(FaultState::OVERTEMP));
+    }
+
+    #[test]
+    fn test_coordinator_starts_healthy() {
+        let coordinator = FaultCoordinator::new(3);
+        assert!(!coordinator.is_faulted());
+        assert_eq!(coordinator.fan_override(), None);
+    }
+
+    #[test]
+    fn test_coordinator_raise_sets_fault_and_overrides_fan() {
+        let mut coordinator = FaultCoordinator::new(3);
+        coordinator.raise(FaultState::OVERTEMP);
+        assert!(coordinator.is_faulted());
+        assert_eq!(coordinator.fan_override(), Some(SafetyOverride::ForcePwm(255)));
+    }
+
+    #[test]
+    fn test_coordinator_holds_fault_until_enough_healthy_ticks() {
+        let mut coordinator = FaultCoordinator::new(3);
+        coordinator.raise(FaultState::SENSOR_FAIL);
+
+        coordinator.tick_healthy();
+        coordinator.tick_healthy();
+        assert!(coordinator.is_faulted(), "should still be faulted before the third healthy tick");
+
+        coordinator.tick_healthy();
+        assert!(!coordinator.is_faulted(), "should clear after the configured healthy-tick count");
+    }
+
+    #[test]
+    fn test_coordinator_new_fault_resets_healthy_counter() {
+        let mut coordinator = FaultCoordinator::new(3);
+        coordinator.raise(FaultState::OVERTEMP);
+        coordinator.tick_healthy();
+        coordinator.tick_healthy();
+
+        // A fresh fault before the window elapses should restart the count.
+        coordinator.raise(FaultState::FAN_FAIL);
+        coordinator.tick_healthy();
+        coordinator.tick_healthy();
+        assert!(coordinator.is_faulted(), "a fault raised mid-window should restart the hysteresis count");
+    }
+
+    #[test]
+    fn test_coordinator_clear_after_zero_is_clamped_to_one() {
+        let mut coordinator = FaultCoordinator::new(0);
+        coordinator.raise(FaultState::OVERTEMP);
+        coordinator.tick_healthy();
+        assert!(!coordinator.is_faulted());
+    }
+
+    #[tokio::test]
+    async fn test_enforce_ryzenadj_resets_when_faulted() {
+        let mut coordinator = FaultCoordinator::new(3);
+        coordinator.raise(FaultState::RYZENADJ_FAIL);
+        let mut executor = RyzenadjExecutor::new("true");
+
+        let result = coordinator.enforce_ryzenadj(&mut executor, 4).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_enforce_ryzenadj_is_noop_when_healthy() {
+        let coordinator = FaultCoordinator::new(3);
+        let mut executor = RyzenadjExecutor::new("/nonexistent/ryzenadj");
+
+        // A healthy coordinator must not touch the executor at all, so even
+        // a binary that would fail if invoked should produce Ok(()).
+        let result = coordinator.enforce_ryzenadj(&mut executor, 4).await;
+        assert!(result.is_ok());
+    }
+}
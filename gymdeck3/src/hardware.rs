@@ -0,0 +1,192 @@
+//! Per-device hardware profiles: core count, default undervolt bounds, and
+//! fan PWM range
+//!
+//! `model::DeckModel` distinguishes Steam Deck revisions by DMI board name,
+//! but the daemon also hardcodes a 4-core assumption and a single fan PWM
+//! range everywhere else. Other handhelds - the ROG Ally, for one - have a
+//! different core count, a different safe undervolt envelope, and a
+//! different fan PWM ceiling. `HardwareProfile` generalizes `DeckModel` one
+//! step further so `config::resolve_config` and `main` can ask "what's safe
+//! on this device" without assuming it's a Deck.
+//!
+//! Detection still reads DMI sysfs, same convention as
+//! `model::detect_deck_model`: board name first (covers both Deck
+//! revisions), then product name for non-Deck handhelds that don't
+//! populate `board_name` usefully.
+
+use std::fs;
+use std::path::Path;
+
+use crate::fan::{MIN_PWM, MAX_PWM};
+use crate::model::{self, DeckModel};
+use crate::strategy::CoreBounds;
+
+/// DMI sysfs path reporting the board product name (checked when
+/// `board_name` doesn't match a known Deck revision)
+pub const DMI_PRODUCT_NAME_PATH: &str = "/sys/class/dmi/id/product_name";
+
+/// Per-device safety envelope: core count, default undervolt bounds, and
+/// fan PWM range
+///
+/// Implementations supply the defaults `config::resolve_config` falls back
+/// to when the user passes no `--core` flags (and none come from
+/// `--config`), and the range `init_fan_controller` clamps commanded PWM
+/// into.
+pub trait HardwareProfile {
+    /// Human-readable name for verbose/status output
+    fn name(&self) -> &'static str;
+
+    /// Number of CPU cores this device exposes independent undervolt
+    /// control for
+    fn core_count(&self) -> usize;
+
+    /// Safe default per-core bounds, one entry per `core_count()`, used
+    /// when the user supplies no `--core` flags
+    fn default_core_bounds(&self) -> Vec<CoreBounds>;
+
+    /// `(min, max)` PWM duty cycle this device's fan accepts
+    fn fan_pwm_range(&self) -> (u8, u8);
+}
+
+/// Steam Deck (LCD or OLED), wrapping the existing [`DeckModel`] detection
+/// and its per-model undervolt floor
+pub struct SteamDeckProfile(pub DeckModel);
+
+impl HardwareProfile for SteamDeckProfile {
+    fn name(&self) -> &'static str {
+        match self.0 {
+            DeckModel::Lcd => "Steam Deck LCD",
+            DeckModel::Oled => "Steam Deck OLED",
+        }
+    }
+
+    fn core_count(&self) -> usize {
+        4
+    }
+
+    fn default_core_bounds(&self) -> Vec<CoreBounds> {
+        self.0
+            .default_cores()
+            .iter()
+            .map(CoreBounds::from)
+            .collect()
+    }
+
+    fn fan_pwm_range(&self) -> (u8, u8) {
+        (MIN_PWM, MAX_PWM)
+    }
+}
+
+/// ASUS ROG Ally, Ryzen Z1/Z1 Extreme (8 cores)
+///
+/// No field data backs these bounds the way `DeckModel::safe_max_mv_floor`
+/// is backed by the Deck community's undervolt history, so they're
+/// deliberately conservative until real-world reports justify loosening
+/// them.
+pub struct RogAllyProfile;
+
+impl HardwareProfile for RogAllyProfile {
+    fn name(&self) -> &'static str {
+        "ROG Ally"
+    }
+
+    fn core_count(&self) -> usize {
+        8
+    }
+
+    fn default_core_bounds(&self) -> Vec<CoreBounds> {
+        (0..self.core_count())
+            .map(|_| CoreBounds {
+                min_mv: -5,
+                max_mv: -15,
+                threshold: 50.0,
+            })
+            .collect()
+    }
+
+    fn fan_pwm_range(&self) -> (u8, u8) {
+        (MIN_PWM, MAX_PWM)
+    }
+}
+
+/// Detect the current device's hardware profile via DMI
+///
+/// Returns `None` if detection fails or the device isn't recognized, in
+/// which case callers fall back to the existing Deck-agnostic defaults
+/// rather than guessing.
+pub fn detect_hardware_profile() -> Option<Box<dyn HardwareProfile>> {
+    detect_hardware_profile_at(model::DMI_BOARD_NAME_PATH, DMI_PRODUCT_NAME_PATH)
+}
+
+/// Detect using explicit `board_name`/`product_name` paths (for testing)
+pub fn detect_hardware_profile_at<P: AsRef<Path>>(
+    board_name_path: P,
+    product_name_path: P,
+) -> Option<Box<dyn HardwareProfile>> {
+    if let Some(deck) = model::detect_deck_model_at(board_name_path) {
+        return Some(Box::new(SteamDeckProfile(deck)));
+    }
+
+    let product_name = fs::read_to_string(product_name_path).ok()?;
+    match product_name.trim().to_ascii_uppercase().as_str() {
+        // RC71L: ROG Ally; RC72LA: ROG Ally X
+        "RC71L" | "RC72LA" => Some(Box::new(RogAllyProfile)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steam_deck_profile_core_count_and_bounds() {
+        let profile = SteamDeckProfile(DeckModel::Oled);
+        assert_eq!(profile.core_count(), 4);
+        assert_eq!(profile.default_core_bounds().len(), 4);
+        assert_eq!(profile.name(), "Steam Deck OLED");
+    }
+
+    #[test]
+    fn test_rog_ally_profile_core_count_and_bounds() {
+        let profile = RogAllyProfile;
+        assert_eq!(profile.core_count(), 8);
+        assert_eq!(profile.default_core_bounds().len(), 8);
+        assert_eq!(profile.name(), "ROG Ally");
+    }
+
+    #[test]
+    fn test_detect_hardware_profile_at_jupiter_is_steam_deck() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let board_path = dir.path().join("board_name");
+        let product_path = dir.path().join("product_name");
+        fs::write(&board_path, "Jupiter\n").unwrap();
+        fs::write(&product_path, "Jupiter\n").unwrap();
+
+        let profile = detect_hardware_profile_at(&board_path, &product_path).unwrap();
+        assert_eq!(profile.name(), "Steam Deck LCD");
+    }
+
+    #[test]
+    fn test_detect_hardware_profile_at_rc71l_is_rog_ally() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let board_path = dir.path().join("board_name");
+        let product_path = dir.path().join("product_name");
+        fs::write(&board_path, "Unknown\n").unwrap();
+        fs::write(&product_path, "RC71L\n").unwrap();
+
+        let profile = detect_hardware_profile_at(&board_path, &product_path).unwrap();
+        assert_eq!(profile.name(), "ROG Ally");
+    }
+
+    #[test]
+    fn test_detect_hardware_profile_at_unknown_is_none() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let board_path = dir.path().join("board_name");
+        let product_path = dir.path().join("product_name");
+        fs::write(&board_path, "Desktop Board\n").unwrap();
+        fs::write(&product_path, "Some Motherboard\n").unwrap();
+
+        assert!(detect_hardware_profile_at(&board_path, &product_path).is_none());
+    }
+}
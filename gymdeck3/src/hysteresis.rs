@@ -2,7 +2,12 @@
 //!
 //! This module implements dead-band logic to prevent frequent value changes
 //! when CPU load fluctuates around threshold values. Each core maintains
-//! independent hysteresis state.
+//! independent hysteresis state. See the `smoothing` module for a
+//! complementary mechanism that reduces noise in the raw load/temperature
+//! samples before they ever reach this dead-band.
+
+use std::path::Path;
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
@@ -11,6 +16,60 @@ pub const MIN_HYSTERESIS_PERCENT: f32 = 1.0;
 /// Maximum hysteresis margin percentage
 pub const MAX_HYSTERESIS_PERCENT: f32 = 20.0;
 
+/// Growth factor applied per consecutive steady tick when backing off the
+/// recommended poll interval; doubling is the same backoff shape used
+/// elsewhere for retry/backoff timing.
+const INTERVAL_BACKOFF_FACTOR: u32 = 2;
+
+/// Design-spec floor for an undervolt setpoint, mV offset
+///
+/// Wider than any sane per-core `CoreBounds` (which is usually -20 to -35mV)
+/// - this is the hardware-level ceiling past which the Thermostat project
+/// found the chip could land in a state it couldn't recover from, not the
+/// tuned operating range.
+pub const MIN_UNDERVOLT_MV: i32 = -100;
+
+/// Design-spec ceiling for an undervolt setpoint, mV offset (0 = no
+/// undervolt)
+pub const MAX_UNDERVOLT_MV: i32 = 0;
+
+/// A setpoint fell outside the design-spec undervolt range
+///
+/// Returned by [`validate_undervolt_target`] for callers that need to
+/// reject an out-of-range request outright rather than have it silently
+/// clamped, the way [`HysteresisController::process`] does internally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UndervoltRangeError {
+    /// The rejected value, mV
+    pub mv: i32,
+}
+
+impl std::fmt::Display for UndervoltRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "undervolt target {}mV is outside the design-spec range [{}, {}]mV",
+            self.mv, MIN_UNDERVOLT_MV, MAX_UNDERVOLT_MV
+        )
+    }
+}
+
+impl std::error::Error for UndervoltRangeError {}
+
+/// Reject an undervolt setpoint that falls outside `[MIN_UNDERVOLT_MV,
+/// MAX_UNDERVOLT_MV]`, rather than clamping it
+///
+/// Intended for entry points validating a setpoint supplied directly by a
+/// user or external caller (config, RPC), where silently clamping a wildly
+/// out-of-range value would hide a bug instead of surfacing it.
+pub fn validate_undervolt_target(mv: i32) -> Result<i32, UndervoltRangeError> {
+    if (MIN_UNDERVOLT_MV..=MAX_UNDERVOLT_MV).contains(&mv) {
+        Ok(mv)
+    } else {
+        Err(UndervoltRangeError { mv })
+    }
+}
+
 /// Per-core hysteresis state
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct HysteresisState {
@@ -49,6 +108,9 @@ pub struct HysteresisController {
     margin_percent: f32,
     /// Per-core state tracking
     per_core_state: Vec<HysteresisState>,
+    /// Consecutive ticks (via `recommended_interval`) where every core has
+    /// been in dead-band, driving the geometric poll-interval backoff
+    steady_ticks: u32,
 }
 
 impl HysteresisController {
@@ -71,6 +133,7 @@ impl HysteresisController {
         HysteresisController {
             margin_percent,
             per_core_state: (0..num_cores).map(|_| HysteresisState::new()).collect(),
+            steady_ticks: 0,
         }
     }
 
@@ -95,13 +158,18 @@ impl HysteresisController {
     /// * `raw_target` - Raw undervolt target from strategy
     ///
     /// # Returns
-    /// Filtered undervolt value (may be unchanged if in dead-band)
+    /// Filtered undervolt value (may be unchanged if in dead-band), always
+    /// within `[MIN_UNDERVOLT_MV, MAX_UNDERVOLT_MV]`
     ///
     /// # Panics
     /// Panics if core_idx is out of bounds
     pub fn process(&mut self, core_idx: usize, load: f32, raw_target: i32) -> i32 {
+        // Defense in depth: clamp to the hardware design-spec range before
+        // it ever reaches the dead-band logic, rather than trusting the
+        // caller's strategy/config layer to have already done so.
+        let raw_target = raw_target.clamp(MIN_UNDERVOLT_MV, MAX_UNDERVOLT_MV);
         let state = &mut self.per_core_state[core_idx];
-        
+
         // If no baseline established yet, set it and return raw target
         let last_stable = match state.last_stable_load {
             Some(l) => l,
@@ -136,6 +204,31 @@ impl HysteresisController {
         self.per_core_state[core_idx].in_dead_band
     }
 
+    /// Whether every tracked core is currently inside its dead-band
+    pub fn all_in_dead_band(&self) -> bool {
+        !self.per_core_state.is_empty() && self.per_core_state.iter().all(|s| s.in_dead_band)
+    }
+
+    /// Recommend how long the main loop can sleep before its next sample.
+    ///
+    /// Call once per tick, after `process` has run for every core. While
+    /// every core stays in dead-band the recommendation grows
+    /// geometrically off `base` (doubling per consecutive steady tick) up
+    /// to `max_interval`; the instant any core exits its band the next
+    /// call drops straight back to `base`. This lets an async main loop
+    /// back off its sample interval during steady-state load instead of
+    /// ticking at a fixed rate regardless of whether anything changed.
+    pub fn recommended_interval(&mut self, base: Duration, max_interval: Duration) -> Duration {
+        if self.all_in_dead_band() {
+            self.steady_ticks = self.steady_ticks.saturating_add(1);
+        } else {
+            self.steady_ticks = 0;
+        }
+
+        let factor = INTERVAL_BACKOFF_FACTOR.saturating_pow(self.steady_ticks);
+        base.saturating_mul(factor).min(max_interval)
+    }
+
     /// Get the last stable load for a specific core
     /// Returns None if no baseline has been established yet
     pub fn last_stable_load(&self, core_idx: usize) -> Option<f32> {
@@ -152,14 +245,71 @@ impl HysteresisController {
         for state in &mut self.per_core_state {
             state.reset();
         }
+        self.steady_ticks = 0;
     }
 
     /// Reset state for a specific core
     pub fn reset_core(&mut self, core_idx: usize) {
         self.per_core_state[core_idx].reset();
+        self.steady_ticks = 0;
+    }
+
+    /// Serialize the per-core state (and the margin it was learned under)
+    /// to `path`, so a restart or crash can resume from the same learned
+    /// baseline instead of cold-starting
+    pub fn save_to(&self, path: &Path) -> std::io::Result<()> {
+        let persisted = PersistedHysteresisState {
+            margin_percent: self.margin_percent,
+            per_core_state: self.per_core_state.clone(),
+        };
+        let json = serde_json::to_string(&persisted)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, json)
+    }
+
+    /// Restore a controller previously written by `save_to`
+    ///
+    /// Rejects the persisted state if its core count or margin no longer
+    /// match `num_cores`/`margin_percent` - resuming mismatched state would
+    /// silently misapply stale per-core values to the wrong cores under a
+    /// changed config. Callers should fall back to `HysteresisController::new`
+    /// on `Err`.
+    pub fn load_from(path: &Path, margin_percent: f32, num_cores: usize) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read persisted state '{}': {}", path.display(), e))?;
+        let persisted: PersistedHysteresisState = serde_json::from_str(&contents)
+            .map_err(|e| format!("Invalid persisted state '{}': {}", path.display(), e))?;
+
+        if persisted.per_core_state.len() != num_cores {
+            return Err(format!(
+                "Persisted state has {} core(s), current config has {}",
+                persisted.per_core_state.len(),
+                num_cores
+            ));
+        }
+        if persisted.margin_percent != margin_percent {
+            return Err(format!(
+                "Persisted margin {}% does not match current margin {}%",
+                persisted.margin_percent, margin_percent
+            ));
+        }
+
+        Ok(HysteresisController {
+            margin_percent,
+            per_core_state: persisted.per_core_state,
+            steady_ticks: 0,
+        })
     }
 }
 
+/// On-disk representation written by [`HysteresisController::save_to`] and
+/// read back by [`HysteresisController::load_from`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PersistedHysteresisState {
+    margin_percent: f32,
+    per_core_state: Vec<HysteresisState>,
+}
+
 /// Validate hysteresis margin value
 pub fn validate_hysteresis_margin(margin: f32) -> Result<f32, String> {
     if margin < MIN_HYSTERESIS_PERCENT {
@@ -177,6 +327,128 @@ pub fn validate_hysteresis_margin(margin: f32) -> Result<f32, String> {
     Ok(margin)
 }
 
+/// Per-core PID controller state, mirroring [`HysteresisState`]'s layout
+#[derive(Debug, Clone, Copy, Default)]
+struct PidCoreState {
+    /// Accumulated `error * dt`, clamped to `[min_uv, max_uv]` for anti-windup
+    integral: f32,
+    /// Previous tick's error, for the derivative term; `None` before the
+    /// first tick so there's nothing to differentiate against yet
+    prev_error: Option<f32>,
+    /// Last output undervolt value
+    prev_output: i32,
+}
+
+impl PidCoreState {
+    fn reset(&mut self) {
+        self.integral = 0.0;
+        self.prev_error = None;
+        self.prev_output = 0;
+    }
+}
+
+/// PID-based undervolt controller: closes a loop on a measured quantity
+/// (temperature or load) against a per-core setpoint, producing a smoothly
+/// converging undervolt offset instead of [`HysteresisController`]'s
+/// dead-band suppression. See `fan::PidFanController` for the same discrete
+/// PID shape applied to fan PWM.
+#[derive(Debug, Clone)]
+pub struct PidController {
+    kp: f32,
+    ki: f32,
+    kd: f32,
+    setpoint: f32,
+    min_uv: i32,
+    max_uv: i32,
+    per_core_state: Vec<PidCoreState>,
+}
+
+impl PidController {
+    /// Create a new PID controller targeting `setpoint`, with output
+    /// clamped to `[min_uv, max_uv]` for every tracked core
+    pub fn new(kp: f32, ki: f32, kd: f32, setpoint: f32, min_uv: i32, max_uv: i32, num_cores: usize) -> Self {
+        PidController {
+            kp,
+            ki,
+            kd,
+            setpoint,
+            min_uv,
+            max_uv,
+            per_core_state: vec![PidCoreState::default(); num_cores],
+        }
+    }
+
+    /// Configured `(kp, ki, kd)` gains
+    pub fn gains(&self) -> (f32, f32, f32) {
+        (self.kp, self.ki, self.kd)
+    }
+
+    /// Setpoint every core is driven toward
+    pub fn setpoint(&self) -> f32 {
+        self.setpoint
+    }
+
+    /// Get the number of cores being tracked
+    pub fn num_cores(&self) -> usize {
+        self.per_core_state.len()
+    }
+
+    /// Get the last output value for a specific core
+    pub fn last_output(&self, core_idx: usize) -> i32 {
+        self.per_core_state[core_idx].prev_output
+    }
+
+    /// Compute the next undervolt offset for `core_idx` from a `measured`
+    /// reading (temperature or load) and the elapsed time `dt` in seconds
+    /// since the last call for that core.
+    ///
+    /// `error = setpoint - measured`, `integral += error * dt` (clamped to
+    /// `[min_uv, max_uv]` so a sustained error while already saturated can't
+    /// wind the integral up past what the output clamp could ever use),
+    /// `derivative = (error - prev_error) / dt`, and the output is
+    /// `clamp(kp*error + ki*integral + kd*derivative, min_uv, max_uv)`.
+    ///
+    /// # Panics
+    /// Panics if `core_idx` is out of bounds.
+    pub fn process(&mut self, core_idx: usize, measured: f32, dt: f32) -> i32 {
+        let state = &mut self.per_core_state[core_idx];
+        let error = self.setpoint - measured;
+
+        // Guard against a zero (or negative) dt: integrating/differentiating
+        // over no elapsed time would divide by zero or spike the
+        // derivative, so just hold the previous output.
+        if dt <= 0.0 {
+            return state.prev_output;
+        }
+
+        state.integral = (state.integral + error * dt).clamp(self.min_uv as f32, self.max_uv as f32);
+
+        let derivative = match state.prev_error {
+            Some(prev) => (error - prev) / dt,
+            None => 0.0,
+        };
+        state.prev_error = Some(error);
+
+        let output = (self.kp * error + self.ki * state.integral + self.kd * derivative)
+            .clamp(self.min_uv as f32, self.max_uv as f32)
+            .round() as i32;
+        state.prev_output = output;
+        output
+    }
+
+    /// Reset all per-core state
+    pub fn reset(&mut self) {
+        for state in &mut self.per_core_state {
+            state.reset();
+        }
+    }
+
+    /// Reset state for a specific core
+    pub fn reset_core(&mut self, core_idx: usize) {
+        self.per_core_state[core_idx].reset();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -320,4 +592,260 @@ mod tests {
         assert_eq!(result, -24);
         assert!(!controller.is_in_dead_band(0));
     }
+
+    #[test]
+    fn test_all_in_dead_band_requires_every_core() {
+        let mut controller = HysteresisController::new(5.0, 2);
+        controller.process(0, 50.0, -30);
+        controller.process(1, 50.0, -30);
+        assert!(!controller.all_in_dead_band(), "first tick for each core establishes baseline, not dead-band");
+
+        // Both cores now have a baseline; staying within margin puts both
+        // in dead-band
+        controller.process(0, 51.0, -29);
+        controller.process(1, 51.0, -29);
+        assert!(controller.all_in_dead_band());
+
+        // One core exits -> no longer unanimous
+        controller.process(1, 60.0, -10);
+        assert!(!controller.all_in_dead_band());
+    }
+
+    #[test]
+    fn test_all_in_dead_band_false_with_no_cores() {
+        let controller = HysteresisController::new(5.0, 0);
+        assert!(!controller.all_in_dead_band());
+    }
+
+    #[test]
+    fn test_recommended_interval_grows_geometrically_in_steady_state() {
+        let mut controller = HysteresisController::new(5.0, 1);
+        controller.process(0, 50.0, -30); // baseline tick, not yet in dead-band
+
+        let base = Duration::from_millis(100);
+        let cap = Duration::from_secs(10);
+
+        // Not in dead-band yet: stays at base
+        assert_eq!(controller.recommended_interval(base, cap), base);
+
+        controller.process(0, 51.0, -29); // inside margin -> dead-band
+        assert_eq!(controller.recommended_interval(base, cap), base * 2);
+        assert_eq!(controller.recommended_interval(base, cap), base * 4);
+        assert_eq!(controller.recommended_interval(base, cap), base * 8);
+    }
+
+    #[test]
+    fn test_recommended_interval_drops_back_to_base_on_transition() {
+        let mut controller = HysteresisController::new(5.0, 1);
+        controller.process(0, 50.0, -30);
+        controller.process(0, 51.0, -29);
+
+        let base = Duration::from_millis(100);
+        let cap = Duration::from_secs(10);
+        controller.recommended_interval(base, cap);
+        controller.recommended_interval(base, cap);
+        assert!(controller.recommended_interval(base, cap) > base);
+
+        // Core exits dead-band: next recommendation must fall straight
+        // back to base, not decay gradually
+        controller.process(0, 90.0, -5);
+        assert_eq!(controller.recommended_interval(base, cap), base);
+    }
+
+    #[test]
+    fn test_recommended_interval_capped() {
+        let mut controller = HysteresisController::new(5.0, 1);
+        controller.process(0, 50.0, -30);
+        controller.process(0, 51.0, -29);
+
+        let base = Duration::from_millis(100);
+        let cap = Duration::from_millis(250);
+        for _ in 0..10 {
+            assert!(controller.recommended_interval(base, cap) <= cap);
+        }
+    }
+
+    #[test]
+    fn test_reset_clears_steady_ticks() {
+        let mut controller = HysteresisController::new(5.0, 1);
+        controller.process(0, 50.0, -30);
+        controller.process(0, 51.0, -29);
+
+        let base = Duration::from_millis(100);
+        let cap = Duration::from_secs(10);
+        controller.recommended_interval(base, cap);
+        controller.recommended_interval(base, cap);
+
+        controller.reset();
+        controller.process(0, 50.0, -30);
+        controller.process(0, 51.0, -29);
+        assert_eq!(controller.recommended_interval(base, cap), base);
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("state.json");
+
+        let mut controller = HysteresisController::new(5.0, 2);
+        controller.process(0, 30.0, -25);
+        controller.process(0, 31.0, -24);
+        controller.process(1, 70.0, -15);
+
+        controller.save_to(&path).unwrap();
+
+        let restored = HysteresisController::load_from(&path, 5.0, 2).unwrap();
+        assert_eq!(restored.margin_percent(), 5.0);
+        assert_eq!(restored.num_cores(), 2);
+        assert_eq!(restored.last_stable_load(0), controller.last_stable_load(0));
+        assert_eq!(restored.last_output(0), controller.last_output(0));
+        assert!(restored.is_in_dead_band(0));
+        assert_eq!(restored.last_output(1), controller.last_output(1));
+    }
+
+    #[test]
+    fn test_load_from_rejects_core_count_mismatch() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("state.json");
+
+        HysteresisController::new(5.0, 2).save_to(&path).unwrap();
+
+        assert!(HysteresisController::load_from(&path, 5.0, 4).is_err());
+    }
+
+    #[test]
+    fn test_load_from_rejects_margin_mismatch() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("state.json");
+
+        HysteresisController::new(5.0, 2).save_to(&path).unwrap();
+
+        assert!(HysteresisController::load_from(&path, 10.0, 2).is_err());
+    }
+
+    #[test]
+    fn test_load_from_missing_file_is_err() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("does-not-exist.json");
+
+        assert!(HysteresisController::load_from(&path, 5.0, 2).is_err());
+    }
+
+    #[test]
+    fn test_pid_new_controller() {
+        let pid = PidController::new(1.0, 0.1, 0.05, 50.0, -50, 0, 2);
+        assert_eq!(pid.gains(), (1.0, 0.1, 0.05));
+        assert_eq!(pid.setpoint(), 50.0);
+        assert_eq!(pid.num_cores(), 2);
+        assert_eq!(pid.last_output(0), 0);
+    }
+
+    #[test]
+    fn test_pid_positive_error_drives_output_down() {
+        // measured below setpoint -> positive error -> output should move
+        // toward max_uv (less aggressive undervolt), since kp is positive
+        let mut pid = PidController::new(1.0, 0.0, 0.0, 50.0, -50, 0, 1);
+        let output = pid.process(0, 30.0, 1.0);
+        assert!(output > -50, "a positive error with zero integral/derivative gain should pull output off the floor");
+    }
+
+    #[test]
+    fn test_pid_zero_error_with_zero_gains_holds_zero() {
+        let mut pid = PidController::new(0.0, 0.0, 0.0, 50.0, -50, 0, 1);
+        let output = pid.process(0, 50.0, 1.0);
+        assert_eq!(output, 0);
+    }
+
+    #[test]
+    fn test_pid_output_never_exceeds_bounds() {
+        let mut pid = PidController::new(50.0, 0.0, 0.0, 50.0, -50, 0, 1);
+        let output = pid.process(0, 0.0, 1.0); // huge error against a large gain
+        assert!((-50..=0).contains(&output));
+    }
+
+    #[test]
+    fn test_pid_zero_dt_holds_previous_output() {
+        let mut pid = PidController::new(1.0, 1.0, 1.0, 50.0, -50, 0, 1);
+        pid.process(0, 30.0, 1.0);
+        let before = pid.last_output(0);
+
+        let output = pid.process(0, 10.0, 0.0);
+        assert_eq!(output, before, "a zero dt must not divide-by-zero or jump the output");
+    }
+
+    #[test]
+    fn test_pid_integral_is_clamped_to_output_range_for_anti_windup() {
+        let mut pid = PidController::new(0.0, 1.0, 0.0, 50.0, -50, 0, 1);
+        // Sustained large positive error for many ticks: if the integral
+        // term weren't clamped, it would wind up far past [min_uv, max_uv]
+        // and take many ticks to unwind once the error reverses.
+        for _ in 0..1000 {
+            pid.process(0, -1000.0, 1.0);
+        }
+        assert_eq!(pid.last_output(0), 0, "integral should have saturated at max_uv, not kept growing");
+
+        // Error reverses hard; with a clamped integral the output should
+        // recover toward min_uv within a handful of ticks instead of
+        // needing to unwind an enormous accumulated integral first.
+        let mut output = 0;
+        for _ in 0..5 {
+            output = pid.process(0, 1000.0, 1.0);
+        }
+        assert_eq!(output, -50, "a clamped integral should let the output recover quickly");
+    }
+
+    #[test]
+    fn test_pid_reset_clears_integral_and_derivative_history() {
+        // Bounds wide enough that the integral accumulates without
+        // saturating, so the reset's effect is actually observable.
+        let mut pid = PidController::new(0.0, 1.0, 0.0, 50.0, -500, 500, 1);
+        pid.process(0, 30.0, 1.0);
+        pid.process(0, 30.0, 1.0);
+        pid.reset();
+
+        assert_eq!(pid.last_output(0), 0);
+        // Immediately after reset, the derivative term has no prior error,
+        // so this tick's output reflects only a freshly-zeroed integral.
+        let output = pid.process(0, 50.0, 1.0);
+        assert_eq!(output, 0, "reset should have zeroed the accumulated integral");
+    }
+
+    #[test]
+    fn test_pid_reset_single_core_leaves_others_untouched() {
+        let mut pid = PidController::new(0.0, 1.0, 0.0, 50.0, -500, 500, 2);
+        pid.process(0, 30.0, 1.0);
+        pid.process(1, 30.0, 1.0);
+
+        pid.reset_core(0);
+
+        assert_eq!(pid.last_output(0), 0);
+        assert_ne!(pid.last_output(1), 0);
+    }
+
+    #[test]
+    fn test_validate_undervolt_target_accepts_in_range() {
+        assert_eq!(validate_undervolt_target(-50), Ok(-50));
+        assert_eq!(validate_undervolt_target(0), Ok(0));
+        assert_eq!(validate_undervolt_target(MIN_UNDERVOLT_MV), Ok(MIN_UNDERVOLT_MV));
+    }
+
+    #[test]
+    fn test_validate_undervolt_target_rejects_out_of_range() {
+        assert_eq!(
+            validate_undervolt_target(-101),
+            Err(UndervoltRangeError { mv: -101 })
+        );
+        assert_eq!(validate_undervolt_target(5), Err(UndervoltRangeError { mv: 5 }));
+    }
+
+    #[test]
+    fn test_process_clamps_out_of_range_target_to_design_spec() {
+        let mut controller = HysteresisController::new(5.0, 1);
+        let result = controller.process(0, 30.0, -500);
+        assert_eq!(result, MIN_UNDERVOLT_MV);
+
+        let mut controller = HysteresisController::new(5.0, 1);
+        let result = controller.process(0, 30.0, 50);
+        assert_eq!(result, MAX_UNDERVOLT_MV);
+    }
 }
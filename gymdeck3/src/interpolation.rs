@@ -1,14 +1,107 @@
 //! Interpolation engine for smooth undervolt value transitions
 //!
-//! This module implements linear stepping between undervolt values to prevent
+//! This module implements stepping between undervolt values to prevent
 //! sudden voltage jumps that could cause system instability. Values transition
-//! gradually with configurable step size (default 1mV per tick).
+//! gradually with configurable step size (default 1mV per tick) and, since
+//! `InterpolationCurve` was added, a selectable easing shape so transitions
+//! can taper in/out instead of marching at a constant rate.
+//!
+//! `tick()` assumes one step per call at a fixed cadence. For callers whose
+//! wake-ups jitter (e.g. a real daemon loop), `with_slew_rate`/`tick_dt`
+//! instead drive the ramp by elapsed wall-clock time at a configured
+//! mV/second rate, so a long sleep advances further than a short one.
+
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
 /// Default step size in millivolts for interpolation
 pub const DEFAULT_STEP_SIZE_MV: i32 = 1;
 
+/// Numerator of the fraction of remaining distance moved per tick under
+/// `InterpolationCurve::EaseOutExponential` (see `tick_ease_out_exponential`)
+const EASE_OUT_EXPONENTIAL_FACTOR: i64 = 1;
+/// Denominator of the fraction of remaining distance moved per tick under
+/// `InterpolationCurve::EaseOutExponential`; 4 means 25% of the remaining
+/// distance each tick
+const EASE_OUT_EXPONENTIAL_DENOM: i64 = 4;
+
+/// Steepness of the `InterpolationCurve::Sigmoid` logistic curve; higher
+/// values sharpen the transition between the slow start/end and the fast
+/// middle
+const SIGMOID_STEEPNESS: f64 = 4.0;
+
+/// Easing shape applied to a transition's normalized progress
+///
+/// `Linear` reuses the original fixed-step-per-tick logic bit-for-bit.
+/// `EaseInOut` and `Exponential` instead track the transition's start value
+/// and total tick count, derive normalized progress `t` on each tick, and
+/// apply the easing function to it. `Inertial` is stateful in a different
+/// way - see its own doc comment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum InterpolationCurve {
+    /// Constant-rate stepping (the original, default behavior)
+    #[default]
+    Linear,
+    /// Quadratic ease-in/ease-out: slow start and end, faster middle
+    EaseInOut,
+    /// Exponential ramp: slow start, rapidly accelerating finish
+    Exponential,
+    /// Accelerated-gradient (FISTA-style) momentum ramp: eases in and out
+    /// without the fixed shape of `EaseInOut`, instead building up and
+    /// shedding momentum each tick (see `CoreInterpolationState::tick`'s
+    /// inertial branch). Gives strategies a `RampProfile::Inertial`-style
+    /// smooth landing on large transitions while still reacting promptly
+    /// to small ones, since the momentum resets on every new target.
+    Inertial,
+    /// Exponential decay toward target: each tick moves a fixed fraction of
+    /// the *remaining* distance (see `EASE_OUT_EXPONENTIAL_FACTOR`/`_DENOM`)
+    /// rather than normalized progress against `total_ticks`, so it starts
+    /// fast and decelerates smoothly rather than ramping up like
+    /// `Exponential`. Handled by `CoreInterpolationState::tick_ease_out_exponential`.
+    EaseOutExponential,
+    /// Logistic S-curve: slow start and end with a fast middle, similar in
+    /// shape to `EaseInOut` but with a continuously smooth (not
+    /// piecewise-quadratic) transition between the two halves.
+    Sigmoid,
+}
+
+impl InterpolationCurve {
+    /// Map normalized progress `t` in `[0, 1]` to eased progress `f(t)`
+    fn ease(self, t: f64) -> f64 {
+        match self {
+            InterpolationCurve::Linear => t,
+            InterpolationCurve::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            InterpolationCurve::Exponential => {
+                if t <= 0.0 {
+                    0.0
+                } else {
+                    2f64.powf(10.0 * (t - 1.0))
+                }
+            }
+            // Handled by `CoreInterpolationState::tick_inertial` before
+            // `ease` is ever called; `t` alone can't express momentum state.
+            InterpolationCurve::Inertial => t,
+            // Handled by `CoreInterpolationState::tick_ease_out_exponential`
+            // before `ease` is ever called; it steps from remaining
+            // distance rather than normalized progress against `start`.
+            InterpolationCurve::EaseOutExponential => t,
+            InterpolationCurve::Sigmoid => {
+                let k = SIGMOID_STEEPNESS;
+                let raw = |x: f64| 1.0 / (1.0 + (-k * (x - 0.5)).exp());
+                (raw(t) - raw(0.0)) / (raw(1.0) - raw(0.0))
+            }
+        }
+    }
+}
+
 /// Per-core interpolation state
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 struct CoreInterpolationState {
@@ -16,6 +109,22 @@ struct CoreInterpolationState {
     current: i32,
     /// Target value to reach
     target: i32,
+    /// Value at the start of the current transition (eased curves only)
+    start: i32,
+    /// Total ticks needed to complete the current transition, `ceil(distance / step_size)`
+    total_ticks: u32,
+    /// Ticks elapsed since the current transition began
+    elapsed_ticks: u32,
+    /// Fractional mV budget carried over between `tick_dt` calls
+    remainder: f64,
+    /// Value applied on the previous tick (`Inertial` curve only), used to
+    /// extrapolate the look-ahead point `y`
+    applied_prev: i32,
+    /// Look-ahead value `y` the `Inertial` curve steps from instead of
+    /// `current` directly
+    y: f64,
+    /// Momentum coefficient `t_k` the `Inertial` curve updates each tick
+    momentum_t: f64,
 }
 
 impl CoreInterpolationState {
@@ -23,6 +132,13 @@ impl CoreInterpolationState {
         CoreInterpolationState {
             current: 0,
             target: 0,
+            start: 0,
+            total_ticks: 0,
+            elapsed_ticks: 0,
+            remainder: 0.0,
+            applied_prev: 0,
+            y: 0.0,
+            momentum_t: 1.0,
         }
     }
 
@@ -31,23 +147,173 @@ impl CoreInterpolationState {
         self.current != self.target
     }
 
-    /// Perform one step of interpolation toward target
+    /// Perform one step of interpolation toward target, shaped by `curve`
     /// Returns the new current value
-    fn tick(&mut self, step_size: i32) -> i32 {
+    fn tick(&mut self, step_size: i32, curve: InterpolationCurve) -> i32 {
         if self.current == self.target {
             return self.current;
         }
 
-        let diff = self.target - self.current;
-        let step = if diff.abs() <= step_size {
-            diff
-        } else if diff > 0 {
-            step_size
+        if curve == InterpolationCurve::Linear {
+            // Unchanged from the original fixed-step behavior so existing
+            // property tests stay bit-identical.
+            let diff = self.target - self.current;
+            let step = if diff.abs() <= step_size {
+                diff
+            } else if diff > 0 {
+                step_size
+            } else {
+                -step_size
+            };
+
+            self.current += step;
+            self.elapsed_ticks += 1;
+            return self.current;
+        }
+
+        if curve == InterpolationCurve::Inertial {
+            return self.tick_inertial(step_size);
+        }
+
+        if curve == InterpolationCurve::EaseOutExponential {
+            return self.tick_ease_out_exponential();
+        }
+
+        self.elapsed_ticks += 1;
+        let t = if self.total_ticks == 0 {
+            1.0
         } else {
-            -step_size
+            (self.elapsed_ticks as f64 / self.total_ticks as f64).min(1.0)
         };
 
-        self.current += step;
+        let eased = curve.ease(t);
+        let distance = (self.target - self.start) as f64;
+        let mut new_value = (self.start as f64 + distance * eased).round() as i32;
+
+        // Never overshoot, and never reverse direction even if rounding
+        // would otherwise step past or back across the previous value.
+        let prev_current = self.current;
+        new_value = if self.target >= self.start {
+            new_value.max(prev_current).min(self.target)
+        } else {
+            new_value.min(prev_current).max(self.target)
+        };
+
+        // Land exactly on target on the final tick regardless of rounding.
+        if self.elapsed_ticks >= self.total_ticks {
+            new_value = self.target;
+        }
+
+        self.current = new_value;
+        self.current
+    }
+
+    /// Accelerated-gradient (FISTA-style) momentum step
+    ///
+    /// Moves the look-ahead point `y` toward `target` by up to `step_size`
+    /// (the same per-tick budget `Linear` uses), then extrapolates the next
+    /// `y` ahead of the applied trajectory by a fraction `(t_k-1)/t_{k+1}`
+    /// of the last move, per the standard Nesterov/FISTA momentum update.
+    /// The whole trajectory - including the look-ahead - is clamped to
+    /// `[start, target]` so accumulated momentum can never overshoot past
+    /// the target or double back past where the transition began.
+    fn tick_inertial(&mut self, step_size: i32) -> i32 {
+        self.elapsed_ticks += 1;
+
+        let lo = self.start.min(self.target) as f64;
+        let hi = self.start.max(self.target) as f64;
+
+        let diff = self.target as f64 - self.y;
+        let step = diff.clamp(-(step_size as f64), step_size as f64);
+        let new_applied = (self.y + step).round().clamp(lo, hi) as i32;
+
+        let t_next = (1.0 + (1.0 + 4.0 * self.momentum_t * self.momentum_t).sqrt()) / 2.0;
+        let mut next_y = new_applied as f64
+            + ((self.momentum_t - 1.0) / t_next) * (new_applied - self.applied_prev) as f64;
+        next_y = next_y.clamp(lo, hi);
+
+        self.applied_prev = new_applied;
+        self.y = next_y;
+        self.momentum_t = t_next;
+        self.current = new_applied;
+
+        // Land exactly on target on the final tick regardless of momentum.
+        if self.elapsed_ticks >= self.total_ticks {
+            self.current = self.target;
+        }
+
+        self.current
+    }
+
+    /// Exponential-decay step: move a fixed fraction of the remaining
+    /// distance toward target
+    ///
+    /// Unlike the normalized-progress curves, this steps directly from
+    /// `target - current` each tick, so it's front-loaded (biggest move on
+    /// the first tick) and naturally decelerates as the remaining distance
+    /// shrinks - without ever needing `start`/`total_ticks` to compute
+    /// progress. The move is floored at 1 so it always makes progress, and
+    /// capped at the remaining distance so it never overshoots; the
+    /// `total_ticks` guard below is a backstop in case a pathological
+    /// factor/denom pair would otherwise converge too slowly.
+    fn tick_ease_out_exponential(&mut self) -> i32 {
+        self.elapsed_ticks += 1;
+
+        let diff = self.target - self.current;
+        if diff != 0 {
+            let remaining = diff.unsigned_abs() as i64;
+            let magnitude = ((remaining * EASE_OUT_EXPONENTIAL_FACTOR) / EASE_OUT_EXPONENTIAL_DENOM)
+                .max(1)
+                .min(remaining) as i32;
+            self.current += if diff > 0 { magnitude } else { -magnitude };
+        }
+
+        // Land exactly on target on the final tick regardless of rounding.
+        if self.elapsed_ticks >= self.total_ticks {
+            self.current = self.target;
+        }
+
+        self.current
+    }
+
+    /// Ticks remaining to reach target under the current transition
+    ///
+    /// Mirrors `total_ticks - elapsed_ticks`, independent of curve shape
+    /// (every curve reaches target in exactly `total_ticks` ticks).
+    fn remaining_ticks(&self) -> u32 {
+        if !self.is_transitioning() {
+            0
+        } else {
+            self.total_ticks.saturating_sub(self.elapsed_ticks)
+        }
+    }
+
+    /// Perform one time-based step of interpolation toward target
+    ///
+    /// Accumulates `budget_mv` (elapsed time * slew rate) into the
+    /// per-core fractional remainder, then moves the integer part of that
+    /// budget toward target, capped at the remaining distance so the
+    /// value never overshoots. Any leftover fraction carries over to the
+    /// next call so rounding doesn't bias the ramp speed over time.
+    fn tick_dt(&mut self, budget_mv: f64) -> i32 {
+        if self.current == self.target {
+            self.remainder = 0.0;
+            return self.current;
+        }
+
+        self.remainder += budget_mv;
+
+        let distance = (self.target - self.current).unsigned_abs() as f64;
+        let move_amount = self.remainder.floor().clamp(0.0, distance);
+        self.remainder -= move_amount;
+
+        let direction: i32 = if self.target > self.current { 1 } else { -1 };
+        self.current += direction * (move_amount as i32);
+
+        if self.current == self.target {
+            self.remainder = 0.0;
+        }
+
         self.current
     }
 
@@ -55,11 +321,34 @@ impl CoreInterpolationState {
     fn force_immediate(&mut self, value: i32) {
         self.current = value;
         self.target = value;
-    }
-
-    /// Set new target value
-    fn set_target(&mut self, target: i32) {
+        self.start = value;
+        self.total_ticks = 0;
+        self.elapsed_ticks = 0;
+        self.remainder = 0.0;
+        self.applied_prev = value;
+        self.y = value as f64;
+        self.momentum_t = 1.0;
+    }
+
+    /// Set new target value, re-arming the transition's start/tick-count
+    /// bookkeeping used by eased curves (and resetting the `Inertial`
+    /// curve's momentum, so each new target starts from a clean `t_k = 1`)
+    fn set_target(&mut self, target: i32, step_size: i32) {
+        self.start = self.current;
         self.target = target;
+
+        let distance = (target - self.current).unsigned_abs();
+        let step = step_size as u32;
+        self.total_ticks = if distance == 0 {
+            0
+        } else {
+            (distance + step - 1) / step
+        };
+        self.elapsed_ticks = 0;
+        self.remainder = 0.0;
+        self.applied_prev = self.current;
+        self.y = self.current as f64;
+        self.momentum_t = 1.0;
     }
 }
 
@@ -74,6 +363,10 @@ pub struct Interpolator {
     states: Vec<CoreInterpolationState>,
     /// Step size in millivolts (positive value)
     step_size_mv: i32,
+    /// Easing shape applied to every core's transitions
+    curve: InterpolationCurve,
+    /// Slew rate in mV/second for `tick_dt`, if configured via `with_slew_rate`
+    slew_rate_mv_per_sec: Option<f64>,
 }
 
 impl Interpolator {
@@ -88,6 +381,8 @@ impl Interpolator {
         Interpolator {
             states: (0..num_cores).map(|_| CoreInterpolationState::new()).collect(),
             step_size_mv: DEFAULT_STEP_SIZE_MV,
+            curve: InterpolationCurve::Linear,
+            slew_rate_mv_per_sec: None,
         }
     }
 
@@ -104,6 +399,44 @@ impl Interpolator {
         Interpolator {
             states: (0..num_cores).map(|_| CoreInterpolationState::new()).collect(),
             step_size_mv,
+            curve: InterpolationCurve::Linear,
+            slew_rate_mv_per_sec: None,
+        }
+    }
+
+    /// Create a new interpolator with a custom easing curve
+    ///
+    /// # Arguments
+    /// * `num_cores` - Number of CPU cores to track
+    /// * `curve` - Easing shape applied to every core's transitions
+    ///
+    /// # Returns
+    /// New Interpolator with default step size (1mV) and the given curve
+    pub fn with_curve(num_cores: usize, curve: InterpolationCurve) -> Self {
+        Interpolator {
+            states: (0..num_cores).map(|_| CoreInterpolationState::new()).collect(),
+            step_size_mv: DEFAULT_STEP_SIZE_MV,
+            curve,
+            slew_rate_mv_per_sec: None,
+        }
+    }
+
+    /// Create a new interpolator driven by `tick_dt` at a fixed mV/second
+    /// slew rate, instead of `tick`'s fixed mV/step cadence
+    ///
+    /// # Arguments
+    /// * `num_cores` - Number of CPU cores to track
+    /// * `mv_per_sec` - Slew rate in millivolts per second (must be positive)
+    ///
+    /// # Panics
+    /// Panics if mv_per_sec is not positive
+    pub fn with_slew_rate(num_cores: usize, mv_per_sec: f64) -> Self {
+        assert!(mv_per_sec > 0.0, "Slew rate must be positive");
+        Interpolator {
+            states: (0..num_cores).map(|_| CoreInterpolationState::new()).collect(),
+            step_size_mv: DEFAULT_STEP_SIZE_MV,
+            curve: InterpolationCurve::Linear,
+            slew_rate_mv_per_sec: Some(mv_per_sec),
         }
     }
 
@@ -117,6 +450,26 @@ impl Interpolator {
         self.step_size_mv
     }
 
+    /// Get the configured easing curve
+    pub fn curve(&self) -> InterpolationCurve {
+        self.curve
+    }
+
+    /// Get the configured slew rate in mV/second, if this interpolator was
+    /// created with `with_slew_rate`
+    pub fn slew_rate(&self) -> Option<f64> {
+        self.slew_rate_mv_per_sec
+    }
+
+    /// Change the easing curve used for subsequent ticks
+    ///
+    /// Takes effect on transitions set after the call; an in-flight
+    /// transition keeps progressing under whichever curve was active when
+    /// `set_target`/`set_targets` last ran for it.
+    pub fn set_curve(&mut self, curve: InterpolationCurve) {
+        self.curve = curve;
+    }
+
     /// Set target values for all cores
     ///
     /// # Arguments
@@ -131,7 +484,7 @@ impl Interpolator {
             "Targets length must match number of cores"
         );
         for (state, target) in self.states.iter_mut().zip(targets.into_iter()) {
-            state.set_target(target);
+            state.set_target(target, self.step_size_mv);
         }
     }
 
@@ -144,24 +497,62 @@ impl Interpolator {
     /// # Panics
     /// Panics if core_idx is out of bounds
     pub fn set_target(&mut self, core_idx: usize, target: i32) {
-        self.states[core_idx].set_target(target);
+        self.states[core_idx].set_target(target, self.step_size_mv);
     }
 
     /// Perform one interpolation tick for all cores
     ///
-    /// Each core's current value moves one step closer to its target.
-    /// If the distance to target is less than step size, the value
-    /// jumps directly to target.
+    /// Each core's current value moves one step closer to its target,
+    /// shaped by the configured `InterpolationCurve`. Under `Linear` (the
+    /// default) a step jumps directly to target once the remaining
+    /// distance is less than the step size; eased curves instead taper the
+    /// per-tick movement while guaranteeing no overshoot and an exact
+    /// landing on the final tick.
     ///
     /// # Returns
     /// Vector of current values after the tick (values to apply)
     pub fn tick(&mut self) -> Vec<i32> {
+        let curve = self.curve;
+        self.states
+            .iter_mut()
+            .map(|state| state.tick(self.step_size_mv, curve))
+            .collect()
+    }
+
+    /// Perform one time-based interpolation step for all cores
+    ///
+    /// Moves each core's current value toward its target by
+    /// `elapsed * slew_rate` mV, clamped so it never overshoots; any
+    /// fractional mV left over carries forward to the next call. Unlike
+    /// `tick`, this adapts to real wall-clock jitter rather than assuming a
+    /// fixed cadence.
+    ///
+    /// # Panics
+    /// Panics if this interpolator was not created with `with_slew_rate`
+    ///
+    /// # Returns
+    /// Vector of current values after the step (values to apply)
+    pub fn tick_dt(&mut self, elapsed: Duration) -> Vec<i32> {
+        let rate = self
+            .slew_rate_mv_per_sec
+            .expect("tick_dt requires an interpolator created with with_slew_rate");
+        let budget_mv = elapsed.as_secs_f64() * rate;
         self.states
             .iter_mut()
-            .map(|state| state.tick(self.step_size_mv))
+            .map(|state| state.tick_dt(budget_mv))
             .collect()
     }
 
+    /// Drive the current transition to completion as an iterator
+    ///
+    /// Yields the result of `tick()` until every core reaches its target,
+    /// then stops. The length is known up front (`ExactSizeIterator`) so
+    /// callers can `.collect()` a full transition, `.take(n)` a preview, or
+    /// feed it to adapters without losing remaining-length information.
+    pub fn ramp(&mut self) -> Ramp<'_> {
+        Ramp { interp: self }
+    }
+
     /// Force immediate transition to specified values (emergency reset)
     ///
     /// Bypasses gradual interpolation and sets both current and target
@@ -244,6 +635,72 @@ impl Interpolator {
             .map(|s| (s.target - s.current).abs())
             .sum()
     }
+
+    /// Snapshot `current_values()`/`target_values()`/per-core
+    /// `remaining_distance` together in one call
+    ///
+    /// Lets a reporting consumer (e.g. `output::ReportOutput`) read every
+    /// core's transition state off one borrow instead of three separate
+    /// getter calls per tick.
+    pub fn report(&self) -> InterpolatorReport {
+        InterpolatorReport {
+            current: self.current_values(),
+            target: self.target_values(),
+            remaining_distance: self
+                .states
+                .iter()
+                .map(|s| (s.target - s.current).abs())
+                .collect(),
+        }
+    }
+}
+
+/// Per-core snapshot of an `Interpolator`'s transition state, as returned by
+/// `Interpolator::report()`
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterpolatorReport {
+    /// Current per-core undervolt values (mV)
+    pub current: Vec<i32>,
+    /// Target per-core undervolt values (mV)
+    pub target: Vec<i32>,
+    /// Per-core absolute distance remaining to target (mV)
+    pub remaining_distance: Vec<i32>,
+}
+
+/// Iterator over `Interpolator::tick()` results that runs to completion
+///
+/// Yields each tick's values until every core reaches its target. The
+/// remaining length is always known exactly, since every core's
+/// `total_ticks` is fixed when `set_target`/`set_targets` is called.
+pub struct Ramp<'a> {
+    interp: &'a mut Interpolator,
+}
+
+impl Iterator for Ramp<'_> {
+    type Item = Vec<i32>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.interp.is_transitioning() {
+            return None;
+        }
+        Some(self.interp.tick())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Ramp<'_> {
+    fn len(&self) -> usize {
+        self.interp
+            .states
+            .iter()
+            .map(|s| s.remaining_ticks() as usize)
+            .max()
+            .unwrap_or(0)
+    }
 }
 
 #[cfg(test)]
@@ -350,6 +807,18 @@ mod tests {
         assert_eq!(interp.total_remaining_distance(), 30);
     }
 
+    #[test]
+    fn test_report_snapshots_current_target_and_remaining_distance() {
+        let mut interp = Interpolator::new(2);
+        interp.set_targets(vec![-10, -20]);
+        interp.tick();
+
+        let report = interp.report();
+        assert_eq!(report.current, interp.current_values());
+        assert_eq!(report.target, interp.target_values());
+        assert_eq!(report.remaining_distance, vec![9, 19]);
+    }
+
     #[test]
     fn test_custom_step_size() {
         let mut interp = Interpolator::with_step_size(1, 5);
@@ -366,6 +835,73 @@ mod tests {
         assert_eq!(values, vec![-12]);
     }
 
+    #[test]
+    fn test_ease_out_exponential_front_loaded() {
+        let mut interp = Interpolator::with_step_size(1, 1);
+        interp.set_curve(InterpolationCurve::EaseOutExponential);
+        interp.force_immediate(vec![0]);
+        interp.set_target(0, -100);
+
+        let mut values = vec![0];
+        while interp.is_transitioning() {
+            values.push(interp.tick()[0]);
+        }
+
+        // First move should be the largest, steps should shrink as the
+        // remaining distance shrinks, and it should always land exactly.
+        let first_step = (values[1] - values[0]).abs();
+        let last_step = (*values.last().unwrap() - values[values.len() - 2]).abs();
+        assert!(first_step > last_step);
+        assert_eq!(*values.last().unwrap(), -100);
+    }
+
+    #[test]
+    fn test_ease_out_exponential_never_slower_than_linear_estimate() {
+        let mut interp = Interpolator::with_step_size(1, 1);
+        interp.set_curve(InterpolationCurve::EaseOutExponential);
+        interp.force_immediate(vec![0]);
+        interp.set_target(0, -20);
+
+        let mut ticks = 0;
+        while interp.is_transitioning() {
+            interp.tick();
+            ticks += 1;
+        }
+
+        assert!(ticks <= 20, "should converge in at most the naive linear tick count");
+    }
+
+    #[test]
+    fn test_sigmoid_reaches_target_in_expected_ticks() {
+        let mut interp = Interpolator::with_step_size(1, 1);
+        interp.set_curve(InterpolationCurve::Sigmoid);
+        interp.force_immediate(vec![0]);
+        interp.set_target(0, -10);
+
+        let mut values = vec![0];
+        while interp.is_transitioning() {
+            values.push(interp.tick()[0]);
+        }
+
+        assert_eq!(values.len(), 11); // 10 ticks + the initial value
+        assert_eq!(*values.last().unwrap(), -10);
+    }
+
+    #[test]
+    fn test_sigmoid_never_overshoots_or_reverses() {
+        let mut interp = Interpolator::with_step_size(1, 1);
+        interp.set_curve(InterpolationCurve::Sigmoid);
+        interp.force_immediate(vec![0]);
+        interp.set_target(0, -10);
+
+        let mut prev = 0;
+        while interp.is_transitioning() {
+            let current = interp.tick()[0];
+            assert!(current <= prev && current >= -10);
+            prev = current;
+        }
+    }
+
     #[test]
     fn test_multiple_cores_independent() {
         let mut interp = Interpolator::new(2);
@@ -8,15 +8,31 @@
 //!
 //! - **config**: CLI argument parsing and validation
 //! - **load_monitor**: CPU load monitoring from /proc/stat
+//! - **load_history**: Rolling-window load history and sparkline rendering
+//! - **load_stats**: Sample-stability statistics with outlier pruning
+//! - **load_monitor_service**: Background CPU load sampling with a published snapshot
+//! - **mem_monitor**: Memory pressure monitoring from /proc/meminfo
+//! - **loadavg_monitor**: Kernel load average monitoring from /proc/loadavg
 //! - **strategy**: Adaptation strategies (conservative, balanced, aggressive, custom)
 //! - **hysteresis**: Dead-band logic for stable value transitions
-//! - **interpolation**: Smooth value ramping with linear stepping
+//! - **interpolation**: Smooth value ramping with selectable easing curves
 //! - **ryzenadj**: Subprocess wrapper for applying undervolt values
+//! - **autotune**: TPE-based search over the ryzenadj power-limit space
 //! - **output**: JSON status output formatting (NDJSON)
+//! - **telemetry**: NDJSON tuning-session telemetry plus JUnit-style summary reports
+//! - **study**: Parallel config-sweep study runner with serialized apply and ranked results
+//! - **fault**: Unified fault-state coordinator tying ryzenadj and fan failures into one safe state
+//! - **thermal**: Sustained-overtemp thermal-load integrator with an escalating emergency shutdown path
 //! - **signals**: Signal handling (SIGTERM, SIGUSR1)
 //! - **watchdog**: Internal watchdog timer for safety
 //! - **safety**: Root check and value validation
 //! - **fan**: Fan control via hwmon sysfs (temperature curves, hysteresis, safety)
+//! - **dynamic**: Per-core dynamic voltage/frequency control (Manual Dynamic Mode backend)
+//! - **logging**: `tracing` layer rendering log events into the NDJSON status stream
+//! - **control**: Unix-domain-socket control plane for live reconfiguration and status streaming
+//! - **model**: Steam Deck hardware-model detection and per-model undervolt safety clamps
+//! - **smoothing**: Sample smoothing filters (SMA/EMA) for load/temperature inputs
+//! - **spectral**: FFT-based oscillation detection over `LoadMonitor` samples
 //!
 //! # Testing
 //!
@@ -48,16 +64,39 @@
 
 mod config;
 mod load_monitor;
+mod load_history;
+mod load_stats;
+mod load_monitor_service;
+mod mem_monitor;
+mod loadavg_monitor;
 mod hysteresis;
 mod interpolation;
 mod ryzenadj;
+mod autotune;
 mod output;
+mod telemetry;
+mod study;
+mod fault;
+mod thermal;
+mod rpc;
+mod signing;
+mod logging;
 #[cfg(unix)]
 mod signals;
+#[cfg(unix)]
+mod control;
+#[cfg(unix)]
+mod status_server;
 mod watchdog;
 mod safety;
+mod model;
+mod hardware;
+mod smoothing;
+mod spectral;
+mod precision;
 pub mod strategy;
 pub mod fan;
+pub mod dynamic;
 
 pub use config::{
     Args,
@@ -65,17 +104,41 @@ pub use config::{
     CoreConfig,
     FanControlMode,
     FanCurvePointConfig,
+    FanCurveCoeffs,
+    FanPidConfig,
+    PidConfig,
+    SmoothingMode,
+    SmoothingConfig,
+    ConfigError,
+    ConfigErrorKind,
+    Span,
+    ClampWarning,
     validate_sample_interval,
     validate_sample_interval_value,
+    validate_sample_interval_value_clamped,
     validate_hysteresis,
     validate_hysteresis_value,
+    validate_hysteresis_value_clamped,
     parse_core_config,
     validate_core_config_values,
+    validate_core_config_values_clamped,
     validate_args,
     parse_fan_curve_point,
     validate_fan_curve_point,
+    validate_fan_curve_point_clamped,
     validate_fan_hysteresis,
+    validate_smoothing_window,
+    validate_smoothing_window_value,
+    validate_smoothing_alpha,
+    validate_smoothing_alpha_value,
     parse_acoustic_profile,
+    validate_control_socket_path,
+    ConfigFile,
+    ResolvedConfig,
+    load_config_file,
+    resolve_config,
+    DEFAULT_HYSTERESIS,
+    DEFAULT_STATUS_INTERVAL_MS,
 };
 
 pub use load_monitor::{
@@ -84,18 +147,63 @@ pub use load_monitor::{
     LoadSample,
     CpuStats,
     CoreStats,
+    StateBreakdown,
+    FromProcReader,
+    LoadHysteresisConfig,
+    LoadMode,
+    LoadEscalationTracker,
     validate_sample_interval_ms,
     MIN_SAMPLE_INTERVAL_MS,
     MAX_SAMPLE_INTERVAL_MS,
 };
 
+pub use load_history::{
+    LoadHistory,
+    DEFAULT_HISTORY_SIZE,
+};
+
+pub use load_stats::{
+    LoadStats,
+    LoadStatsError,
+    compute_load_stats,
+    compute_load_stats_with_min,
+    MIN_VALID_SAMPLES,
+    OUTLIER_STD_DEVS,
+};
+
+pub use load_monitor_service::{
+    LoadMonitorService,
+    SLEEP_INTERVAL,
+};
+
+pub use mem_monitor::{
+    MemMonitor,
+    MemMonitorError,
+    MemStats,
+};
+
+pub use loadavg_monitor::{
+    LoadAvgMonitor,
+    LoadAvgMonitorError,
+    LoadAvg,
+};
+
 pub use strategy::{
     AdaptationStrategy,
+    StabilityFeedback,
     CoreBounds,
     ConservativeStrategy,
     BalancedStrategy,
     AggressiveStrategy,
     CustomStrategy,
+    Interp,
+    PidStrategy,
+    AdaptiveStrategy,
+    AdaptiveCurveStrategy,
+    LearningStrategy,
+    CurveOptimizer,
+    Calibrator,
+    StabilityObservation,
     create_strategy,
     clamp_to_bounds,
     lerp,
@@ -103,13 +211,20 @@ pub use strategy::{
 
 pub use hysteresis::{
     HysteresisController,
+    PidController,
     validate_hysteresis_margin,
+    validate_undervolt_target,
+    UndervoltRangeError,
     MIN_HYSTERESIS_PERCENT,
     MAX_HYSTERESIS_PERCENT,
+    MIN_UNDERVOLT_MV,
+    MAX_UNDERVOLT_MV,
 };
 
 pub use interpolation::{
     Interpolator,
+    InterpolationCurve,
+    Ramp,
     DEFAULT_STEP_SIZE_MV,
 };
 
@@ -117,8 +232,22 @@ pub use ryzenadj::{
     RyzenadjExecutor,
     RyzenadjError,
     ApplyResult,
+    FailureCorpus,
     simulate_failure_sequence,
     MAX_CONSECUTIVE_FAILURES,
+    DEFAULT_APPLY_TIMEOUT_MS,
+    DEFAULT_FAILURE_BACKOFF_MV,
+    DEFAULT_VERIFICATION_TOLERANCE_MV,
+};
+
+pub use autotune::{
+    AutoTuner,
+    ParamSpec,
+    DEFAULT_GAMMA,
+    DEFAULT_COLD_START,
+    DEFAULT_CANDIDATES_PER_DIM,
+    WORST_SCORE,
+    minimize_failing_config,
 };
 
 pub use output::{
@@ -126,15 +255,96 @@ pub use output::{
     FanStatusOutput,
     TransitionOutput,
     ErrorOutput,
+    ReportOutput,
+    Message,
     OutputWriter,
+    StatusSink,
+    StdoutSink,
+    FileSink,
+    UnixSocketSink,
     validate_status_output,
+    DEFAULT_TICK_HZ,
+    SCHEMA_VERSION,
+};
+
+pub use telemetry::{
+    TelemetryWriter,
+    LoadRecord,
+    ModeTransitionRecord,
+    ApplyRecord,
+    ApplyCase,
+    TuningSummary,
+};
+
+pub use study::{
+    StudyRunner,
+    StudyProgress,
+    TrialRecord,
+    StudyReport,
+    ScoreFn,
+    DEFAULT_MAX_PARALLEL_EVALS,
+};
+
+pub use fault::{
+    FaultState,
+    FaultCoordinator,
+    DEFAULT_CLEAR_AFTER_HEALTHY_TICKS,
+};
+
+pub use thermal::{
+    ThermalAction,
+    ThermalEscalationConfig,
+    ThermalLoadTracker,
+    execute_thermal_action,
+    DEFAULT_THERMAL_LOAD_BUDGET,
+    DEFAULT_THERMAL_LOAD_DECAY_PER_TICK,
+    DEFAULT_THERMAL_TICK_INTERVAL_SEC,
+};
+
+pub use rpc::{
+    RpcRequest,
+    RpcResponse,
+    RpcError,
+    RpcMethodCall,
+    validate_rpc_request,
+    validate_rpc_response,
+    RPC_METHOD_NOT_FOUND,
+    RPC_INVALID_PARAMS,
+};
+
+pub use signing::{
+    SignedEnvelope,
+    verify_signed,
+    ENVELOPE_VERSION,
+    DEFAULT_FRESHNESS_LEEWAY_MS,
+};
+
+pub use logging::{
+    LogOutput,
+    NdjsonLogLayer,
+    validate_log_output,
+};
+
+#[cfg(unix)]
+pub use control::{
+    ControlState,
+    SharedControlState,
+    ControlServer,
 };
 
 pub use watchdog::{
     WatchdogState,
     Watchdog,
+    HardwareWatchdog,
+    HardwareWatchdogError,
+    ThermalRunawayMonitor,
+    ThermalRunawayStatus,
     check_timeout,
-    DEFAULT_WATCHDOG_TIMEOUT_SECS,
+    DEFAULT_WATCHDOG_TIMEOUT_MS,
+    MAX_CONSECUTIVE_RECOVERIES,
+    DEFAULT_THERMAL_RUNAWAY_WINDOW_MS,
+    DEFAULT_THERMAL_RUNAWAY_HYSTERESIS_C,
+    THERMAL_RUNAWAY_FAN_PWM_THRESHOLD,
 };
 
 #[cfg(unix)]
@@ -149,10 +359,44 @@ pub use safety::{
     is_root,
     check_root_or_exit,
     clamp_value,
+    clamp_value_thermal,
     is_value_in_bounds,
     clamp_all_values,
     all_values_in_bounds,
     EXIT_CODE_NOT_ROOT,
+    EXIT_CODE_FAN_STALL,
+    EXIT_CODE_THERMAL_RUNAWAY,
+};
+
+pub use model::{
+    DeckModel,
+    detect_deck_model,
+    detect_deck_model_at,
+    validate_core_config_for_model,
+    DMI_BOARD_NAME_PATH,
+};
+
+pub use hardware::{
+    HardwareProfile,
+    SteamDeckProfile,
+    RogAllyProfile,
+    detect_hardware_profile,
+    detect_hardware_profile_at,
+    DMI_PRODUCT_NAME_PATH,
+};
+
+pub use smoothing::{
+    SmoothingFilter,
+    SmoothingBank,
+};
+
+pub use spectral::{
+    OscillationDetector,
+    SpectralPeak,
+    SpectralError,
+    recommend_sample_interval_ms,
+    is_unstable_oscillation,
+    DEFAULT_FFT_SIZE,
 };
 
 // Fan control module re-exports
@@ -162,13 +406,97 @@ pub use fan::{
     FanCurvePoint,
     FanControllerConfig,
     FanStatus,
+    FanTick,
     FanMode,
     FanSafetyLimits,
+    FanHealth,
+    FanHealthStatus,
+    FanHealthModel,
     HwmonDevice,
     HwmonError,
+    Curve,
+    SmootherState,
+    MAX_ELAPSED_SEC,
     find_steam_deck_hwmon,
     CRITICAL_TEMP_C,
     HIGH_TEMP_C,
     ZERO_RPM_MAX_TEMP_C,
+    DEFAULT_FAN_HEALTH_PWM_FLOOR,
+    DEFAULT_FAN_HEALTH_RPM_THRESHOLD,
+    DEFAULT_FAN_HEALTH_TICK_THRESHOLD,
+    DEFAULT_FAN_HEALTH_MODEL,
+    DEFAULT_FAN_HEALTH_STALL_FRACTION,
+    DEFAULT_FAN_HEALTH_MIN_MEASURABLE_PWM,
     AcousticProfile,
+    PidFanController,
+    PidDiagnostics,
+    TempFilter,
+    RelayAutotuner,
+    AutotuneGains,
+    AutotuneStep,
+    AutotuneAbortReason,
+    DEFAULT_RELAY_PWM_HIGH,
+    DEFAULT_RELAY_PWM_LOW,
+    DEFAULT_RELAY_HYSTERESIS_C,
+    DEFAULT_STABLE_CYCLES_REQUIRED,
+    DEFAULT_MAX_CYCLES,
+    DEFAULT_AUTOTUNE_TIMEOUT,
+};
+
+// Dynamic voltage/frequency control module re-exports
+pub use dynamic::{
+    VoltageController,
+    VoltageControllerError,
+    CurvePoint,
+    format_update_all_errors,
+    DEFAULT_STABILITY_BACKOFF_STEP_MV,
+    DEFAULT_THERMAL_MARGIN_CEILING_C,
+    DEFAULT_THERMAL_MARGIN_GAIN,
+    DEFAULT_STABILITY_DECAY_TICKS,
+    MetricsMonitor,
+    CoreMetrics,
+    MetricsError,
+    CoreTopology,
+    MetricsHistory,
+    CoreMetricsHistory,
+    MetricsAggregate,
+    DEFAULT_METRICS_HISTORY_CAPACITY,
+    Curve as InterpolableCurve,
+    Interpolable,
+    FanDutyCurve,
+    FanDutyPoint,
+    FrequencyCurve,
+    FrequencyPoint,
+    InterpolationKind,
+    InterpolationPoint,
+    InterpolationDatum,
+    ExtrapolationPolicy,
+    EvenFrequencyCurve,
+    FrequencyVoltageController,
+    FrequencyControllerError,
+    DEFAULT_SLEW_DEADBAND_MV,
+    DEFAULT_MAX_SLEW_DURATION_SEC,
+    DEFAULT_SLEW_HARD_RATE_MULTIPLIER,
+    RatifiedVoltage,
+    VanGoghRatifiedVoltage,
+    VAN_GOGH_MIN_OFFSET_MV,
+    VAN_GOGH_MAX_OFFSET_MV,
+    VAN_GOGH_STEP_MV,
+    FrequencyDaemonRequest,
+    FrequencyDaemonResponse,
+    SharedFrequencyController,
+    FrequencyControlServer,
+    DeviceLimits,
+    RangeLimit,
+    load_device_limits,
+    VoltageProfile,
+    VariantInfo,
+    profile_id,
+    VoltageBackend,
+    SysfsBackend,
+    RyzenAdjBackend,
+    MsrBackend,
+    detect_authentic_amd,
+    RYZENADJ_MIN_OFFSET_MV,
+    RYZENADJ_MAX_OFFSET_MV,
 };
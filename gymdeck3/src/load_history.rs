@@ -0,0 +1,194 @@
+//! Rolling-window load history and sparkline rendering
+//!
+//! Keeps a fixed-size window of recent `LoadSample`s per core (plus the
+//! average) so callers can render compact trend visualizations without a
+//! plotting dependency, and so other subsystems can query a windowed mean
+//! instead of reacting to a single noisy sample.
+
+use std::collections::VecDeque;
+
+use crate::load_monitor::LoadSample;
+
+/// Default number of samples kept per core
+pub const DEFAULT_HISTORY_SIZE: usize = 32;
+
+/// 8-level Unicode block ramp used to render a sparkline, lowest to highest
+const SPARKLINE_BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Fixed-size ring buffer of recent load samples, one deque per core plus
+/// one for the average
+pub struct LoadHistory {
+    capacity: usize,
+    average: VecDeque<f32>,
+    per_core: Vec<VecDeque<f32>>,
+}
+
+impl LoadHistory {
+    /// Create a new history window with the given per-deque capacity
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            average: VecDeque::with_capacity(capacity),
+            per_core: Vec::new(),
+        }
+    }
+
+    /// Push a new sample, popping the oldest entry from any deque at capacity
+    pub fn push(&mut self, sample: &LoadSample) {
+        Self::push_bounded(&mut self.average, sample.average, self.capacity);
+
+        if self.per_core.len() < sample.per_core.len() {
+            self.per_core
+                .resize_with(sample.per_core.len(), || VecDeque::with_capacity(self.capacity));
+        }
+
+        for (deque, &load) in self.per_core.iter_mut().zip(sample.per_core.iter()) {
+            Self::push_bounded(deque, load, self.capacity);
+        }
+    }
+
+    fn push_bounded(deque: &mut VecDeque<f32>, value: f32, capacity: usize) {
+        if deque.len() >= capacity {
+            deque.pop_front();
+        }
+        deque.push_back(value);
+    }
+
+    /// Configured window capacity (samples per core)
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of cores currently tracked
+    pub fn core_count(&self) -> usize {
+        self.per_core.len()
+    }
+
+    /// Windowed mean of the average-load deque
+    pub fn moving_average(&self) -> Option<f32> {
+        Self::mean(&self.average)
+    }
+
+    /// Windowed mean for a single core, or `None` if out of range / empty
+    pub fn moving_average_core(&self, core: usize) -> Option<f32> {
+        self.per_core.get(core).and_then(Self::mean)
+    }
+
+    /// Windowed mean for every tracked core
+    pub fn moving_averages(&self) -> Vec<f32> {
+        self.per_core
+            .iter()
+            .map(|deque| Self::mean(deque).unwrap_or(0.0))
+            .collect()
+    }
+
+    fn mean(deque: &VecDeque<f32>) -> Option<f32> {
+        if deque.is_empty() {
+            return None;
+        }
+        Some(deque.iter().sum::<f32>() / deque.len() as f32)
+    }
+
+    /// Render the average-load deque as a sparkline
+    pub fn sparkline(&self) -> String {
+        Self::render_sparkline(&self.average)
+    }
+
+    /// Render a single core's recent utilization as a sparkline
+    pub fn sparkline_core(&self, core: usize) -> String {
+        self.per_core
+            .get(core)
+            .map(Self::render_sparkline)
+            .unwrap_or_default()
+    }
+
+    /// Render every tracked core's sparkline
+    pub fn sparklines(&self) -> Vec<String> {
+        self.per_core.iter().map(Self::render_sparkline).collect()
+    }
+
+    fn render_sparkline(deque: &VecDeque<f32>) -> String {
+        deque
+            .iter()
+            .map(|&load| {
+                let idx = ((load / 100.0) * 7.0).round().clamp(0.0, 7.0) as usize;
+                SPARKLINE_BLOCKS[idx]
+            })
+            .collect()
+    }
+}
+
+impl Default for LoadHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(average: f32, per_core: Vec<f32>) -> LoadSample {
+        LoadSample {
+            average,
+            per_core,
+            timestamp_ms: 0,
+        }
+    }
+
+    #[test]
+    fn test_history_tracks_moving_average() {
+        let mut history = LoadHistory::new(4);
+        history.push(&sample(10.0, vec![10.0]));
+        history.push(&sample(20.0, vec![20.0]));
+
+        assert_eq!(history.moving_average(), Some(15.0));
+        assert_eq!(history.moving_average_core(0), Some(15.0));
+    }
+
+    #[test]
+    fn test_history_evicts_oldest_when_full() {
+        let mut history = LoadHistory::new(2);
+        history.push(&sample(0.0, vec![]));
+        history.push(&sample(100.0, vec![]));
+        history.push(&sample(50.0, vec![]));
+
+        // The first 0.0 sample should have been evicted
+        assert_eq!(history.moving_average(), Some(75.0));
+    }
+
+    #[test]
+    fn test_empty_history_has_no_average() {
+        let history = LoadHistory::new(4);
+        assert_eq!(history.moving_average(), None);
+        assert_eq!(history.moving_average_core(0), None);
+    }
+
+    #[test]
+    fn test_sparkline_spans_full_block_range() {
+        let mut history = LoadHistory::new(8);
+        for load in [0.0, 14.3, 28.6, 42.9, 57.1, 71.4, 85.7, 100.0] {
+            history.push(&sample(load, vec![]));
+        }
+
+        let rendered = history.sparkline();
+        assert_eq!(rendered.chars().count(), 8);
+        assert_eq!(rendered.chars().next(), Some('▁'));
+        assert_eq!(rendered.chars().last(), Some('█'));
+    }
+
+    #[test]
+    fn test_sparkline_core_out_of_range_is_empty() {
+        let history = LoadHistory::new(4);
+        assert_eq!(history.sparkline_core(5), "");
+    }
+
+    #[test]
+    fn test_moving_averages_per_core() {
+        let mut history = LoadHistory::new(4);
+        history.push(&sample(15.0, vec![10.0, 20.0]));
+        history.push(&sample(15.0, vec![20.0, 10.0]));
+
+        assert_eq!(history.moving_averages(), vec![15.0, 15.0]);
+    }
+}
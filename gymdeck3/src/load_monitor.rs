@@ -0,0 +1,936 @@
+//! CPU load monitoring from /proc/stat
+//!
+//! Provides parsing of CPU statistics and calculation of per-core and
+//! average utilization percentages. Parsing goes through [`FromProcReader`]
+//! so a snapshot can come from a path, an in-memory buffer, or any other
+//! `Read`/`BufRead` source, not only the filesystem.
+
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Statistics for a single CPU core (or total)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CoreStats {
+    pub user: u64,
+    pub nice: u64,
+    pub system: u64,
+    pub idle: u64,
+    pub iowait: u64,
+    pub irq: u64,
+    pub softirq: u64,
+    pub steal: u64,
+    /// Time stolen running guest OSes, parsed as `0` on kernels that predate
+    /// this column. Already counted inside `user` by the kernel.
+    pub guest: u64,
+    /// Time stolen running niced guest OSes, parsed as `0` on kernels that
+    /// predate this column. Already counted inside `nice` by the kernel.
+    pub guest_nice: u64,
+}
+
+impl CoreStats {
+    /// Total CPU time (all fields combined)
+    ///
+    /// `guest` and `guest_nice` are excluded: the kernel already folds guest
+    /// time into `user`/`nice` respectively, so adding them again would
+    /// double-count and inflate `total()`.
+    pub fn total(&self) -> u64 {
+        self.user + self.nice + self.system + self.idle + self.iowait + self.irq + self.softirq + self.steal
+    }
+
+    /// Active (non-idle) CPU time
+    pub fn active(&self) -> u64 {
+        self.user + self.nice + self.system + self.irq + self.softirq + self.steal
+    }
+
+    /// Idle CPU time (idle + iowait)
+    pub fn idle_time(&self) -> u64 {
+        self.idle + self.iowait
+    }
+}
+
+/// Snapshot of CPU statistics at a point in time
+#[derive(Debug, Clone)]
+pub struct CpuStats {
+    pub total: CoreStats,
+    pub per_core: Vec<CoreStats>,
+    pub timestamp: Instant,
+}
+
+/// Result of a load sample calculation
+#[derive(Debug, Clone)]
+pub struct LoadSample {
+    /// Average load across all cores (0.0 - 100.0)
+    pub average: f32,
+    /// Per-core load percentages (0.0 - 100.0 each)
+    pub per_core: Vec<f32>,
+    /// Timestamp in milliseconds since monitor start
+    pub timestamp_ms: u64,
+}
+
+/// Delta-based percentage breakdown of where total CPU time went between
+/// two snapshots, so callers can distinguish genuine compute load from
+/// I/O stalls, hypervisor contention, and interrupt storms.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StateBreakdown {
+    /// Percentage of total time spent in `user` (0.0 - 100.0)
+    pub user_pct: f32,
+    /// Percentage of total time spent in `system` (0.0 - 100.0)
+    pub system_pct: f32,
+    /// Percentage of total time spent blocked on I/O (0.0 - 100.0)
+    pub iowait_pct: f32,
+    /// Percentage of total time servicing hardware and software interrupts
+    /// (`irq` + `softirq` combined, 0.0 - 100.0)
+    pub interrupt_pct: f32,
+    /// Percentage of total time stolen by the hypervisor for other guests
+    /// (0.0 - 100.0)
+    pub steal_pct: f32,
+    /// Percentage of total time spent running guest OSes (`guest` +
+    /// `guest_nice`, 0.0 - 100.0)
+    pub guest_pct: f32,
+}
+
+impl StateBreakdown {
+    /// Compute the state breakdown between two consecutive snapshots of the
+    /// same core (or total)
+    ///
+    /// Returns all-zero percentages if the total delta is zero.
+    pub fn from_delta(prev: &CoreStats, current: &CoreStats) -> StateBreakdown {
+        let total_delta = current.total().saturating_sub(prev.total());
+        if total_delta == 0 {
+            return StateBreakdown::default();
+        }
+
+        let pct = |field: u64| (field as f64 / total_delta as f64 * 100.0).clamp(0.0, 100.0) as f32;
+
+        StateBreakdown {
+            user_pct: pct(current.user.saturating_sub(prev.user)),
+            system_pct: pct(current.system.saturating_sub(prev.system)),
+            iowait_pct: pct(current.iowait.saturating_sub(prev.iowait)),
+            interrupt_pct: pct(
+                current.irq.saturating_sub(prev.irq) + current.softirq.saturating_sub(prev.softirq),
+            ),
+            steal_pct: pct(current.steal.saturating_sub(prev.steal)),
+            guest_pct: pct(
+                current.guest.saturating_sub(prev.guest) + current.guest_nice.saturating_sub(prev.guest_nice),
+            ),
+        }
+    }
+}
+
+/// Error types for LoadMonitor operations
+#[derive(Debug)]
+pub enum LoadMonitorError {
+    IoError(io::Error),
+    ParseError(String),
+    NoPreviousSample,
+    InvalidSampleInterval(String),
+}
+
+impl std::fmt::Display for LoadMonitorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadMonitorError::IoError(e) => write!(f, "I/O error: {}", e),
+            LoadMonitorError::ParseError(s) => write!(f, "Parse error: {}", s),
+            LoadMonitorError::NoPreviousSample => write!(f, "No previous sample available"),
+            LoadMonitorError::InvalidSampleInterval(s) => write!(f, "Invalid sample interval: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for LoadMonitorError {}
+
+impl From<io::Error> for LoadMonitorError {
+    fn from(e: io::Error) -> Self {
+        LoadMonitorError::IoError(e)
+    }
+}
+
+/// Parses a type from a line-oriented `/proc` source
+///
+/// Modeled on procfs-core's `FromRead`/`FromBufRead` traits: implementors
+/// only need to provide `from_buf_read`, and get `from_read`/`from_file`
+/// for free. This decouples parsing from the filesystem, so tests and
+/// remote-agent scenarios can feed a snapshot from an in-memory buffer or a
+/// socket without touching a path.
+pub trait FromProcReader: Sized {
+    /// Parse from any buffered reader
+    fn from_buf_read<R: BufRead>(reader: R) -> Result<Self, LoadMonitorError>;
+
+    /// Parse from any reader, wrapping it in a `BufReader`
+    fn from_read<R: Read>(reader: R) -> Result<Self, LoadMonitorError> {
+        Self::from_buf_read(BufReader::new(reader))
+    }
+
+    /// Parse from a file path
+    fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, LoadMonitorError> {
+        Self::from_read(fs::File::open(path)?)
+    }
+}
+
+impl FromProcReader for CpuStats {
+    fn from_buf_read<R: BufRead>(reader: R) -> Result<Self, LoadMonitorError> {
+        let mut total: Option<CoreStats> = None;
+        let mut per_core: Vec<CoreStats> = Vec::new();
+
+        for line in reader.lines() {
+            let line = line?;
+            if line.starts_with("cpu") {
+                let stats = LoadMonitor::parse_cpu_line(&line)?;
+                if line.starts_with("cpu ") {
+                    // Total CPU line (note the space after "cpu")
+                    total = Some(stats);
+                } else if line.chars().nth(3).map_or(false, |c| c.is_ascii_digit()) {
+                    // Per-core line (cpu0, cpu1, etc.)
+                    per_core.push(stats);
+                }
+            }
+        }
+
+        let total = total.ok_or_else(|| {
+            LoadMonitorError::ParseError("Missing total CPU line in /proc/stat".to_string())
+        })?;
+
+        Ok(CpuStats {
+            total,
+            per_core,
+            timestamp: Instant::now(),
+        })
+    }
+}
+
+/// Validate sample interval is within the allowed range (10-5000ms)
+///
+/// # Arguments
+/// * `interval_ms` - Sample interval in milliseconds
+///
+/// # Returns
+/// * `Ok(interval_ms)` if valid
+/// * `Err(LoadMonitorError::InvalidSampleInterval)` if outside range [10, 5000]
+pub fn validate_sample_interval_ms(interval_ms: u64) -> Result<u64, LoadMonitorError> {
+    if interval_ms < MIN_SAMPLE_INTERVAL_MS {
+        return Err(LoadMonitorError::InvalidSampleInterval(format!(
+            "{} ms is too small (minimum: {} ms)",
+            interval_ms, MIN_SAMPLE_INTERVAL_MS
+        )));
+    }
+    if interval_ms > MAX_SAMPLE_INTERVAL_MS {
+        return Err(LoadMonitorError::InvalidSampleInterval(format!(
+            "{} ms is too large (maximum: {} ms)",
+            interval_ms, MAX_SAMPLE_INTERVAL_MS
+        )));
+    }
+    Ok(interval_ms)
+}
+
+/// CPU load monitor that reads from /proc/stat
+pub struct LoadMonitor {
+    prev_stats: Option<CpuStats>,
+    sample_interval: Duration,
+    start_time: Instant,
+    proc_stat_path: String,
+}
+
+
+/// Minimum sample interval in milliseconds
+pub const MIN_SAMPLE_INTERVAL_MS: u64 = 10;
+/// Maximum sample interval in milliseconds
+pub const MAX_SAMPLE_INTERVAL_MS: u64 = 5000;
+
+impl LoadMonitor {
+    /// Create a new LoadMonitor with the specified sample interval
+    ///
+    /// # Arguments
+    /// * `sample_interval_ms` - Sample interval in milliseconds (10-5000)
+    ///
+    /// # Errors
+    /// Returns error if sample_interval_ms is outside the valid range [10, 5000]
+    pub fn new(sample_interval_ms: u64) -> Result<Self, LoadMonitorError> {
+        Self::with_path(sample_interval_ms, "/proc/stat".to_string())
+    }
+
+    /// Create a new LoadMonitor with a custom /proc/stat path (for testing)
+    ///
+    /// # Arguments
+    /// * `sample_interval_ms` - Sample interval in milliseconds (10-5000)
+    /// * `proc_stat_path` - Path to the proc stat file (usually "/proc/stat")
+    ///
+    /// # Errors
+    /// Returns error if sample_interval_ms is outside the valid range [10, 5000]
+    pub fn with_path(sample_interval_ms: u64, proc_stat_path: String) -> Result<Self, LoadMonitorError> {
+        validate_sample_interval_ms(sample_interval_ms)?;
+        
+        Ok(Self {
+            prev_stats: None,
+            sample_interval: Duration::from_millis(sample_interval_ms),
+            start_time: Instant::now(),
+            proc_stat_path,
+        })
+    }
+
+    /// Get the configured sample interval
+    pub fn sample_interval(&self) -> Duration {
+        self.sample_interval
+    }
+
+    /// Read and parse current CPU statistics from /proc/stat
+    fn read_stats(&self) -> Result<CpuStats, LoadMonitorError> {
+        CpuStats::from_file(&self.proc_stat_path)
+    }
+
+    /// Parse /proc/stat content into CpuStats
+    ///
+    /// Convenience wrapper over [`FromProcReader::from_read`] for callers
+    /// that already have the content as a string (e.g. existing tests).
+    pub fn parse_proc_stat(content: &str) -> Result<CpuStats, LoadMonitorError> {
+        CpuStats::from_read(content.as_bytes())
+    }
+
+    /// Parse a single CPU line from /proc/stat
+    ///
+    /// Format: cpu[N] user nice system idle iowait irq softirq steal [guest] [guest_nice]
+    fn parse_cpu_line(line: &str) -> Result<CoreStats, LoadMonitorError> {
+        let mut parts = line.split_whitespace();
+        
+        // Skip the "cpu" or "cpuN" label
+        parts.next();
+
+        let parse_field = |parts: &mut std::str::SplitWhitespace, name: &str| -> Result<u64, LoadMonitorError> {
+            parts
+                .next()
+                .ok_or_else(|| LoadMonitorError::ParseError(format!("Missing {} field", name)))?
+                .parse()
+                .map_err(|_| LoadMonitorError::ParseError(format!("Invalid {} value", name)))
+        };
+
+        let user = parse_field(&mut parts, "user")?;
+        let nice = parse_field(&mut parts, "nice")?;
+        let system = parse_field(&mut parts, "system")?;
+        let idle = parse_field(&mut parts, "idle")?;
+        let iowait = parse_field(&mut parts, "iowait").unwrap_or(0);
+        let irq = parse_field(&mut parts, "irq").unwrap_or(0);
+        let softirq = parse_field(&mut parts, "softirq").unwrap_or(0);
+        let steal = parse_field(&mut parts, "steal").unwrap_or(0);
+        let guest = parse_field(&mut parts, "guest").unwrap_or(0);
+        let guest_nice = parse_field(&mut parts, "guest_nice").unwrap_or(0);
+
+        Ok(CoreStats {
+            user,
+            nice,
+            system,
+            idle,
+            iowait,
+            irq,
+            softirq,
+            steal,
+            guest,
+            guest_nice,
+        })
+    }
+
+    /// Take a sample and calculate CPU load since last sample
+    ///
+    /// Returns LoadSample with per-core and average utilization percentages.
+    /// First call will store stats and return an error (no previous sample).
+    pub fn sample(&mut self) -> Result<LoadSample, LoadMonitorError> {
+        let current = self.read_stats()?;
+        
+        let result = match &self.prev_stats {
+            Some(prev) => {
+                let sample = Self::calculate_load(prev, &current, self.start_time);
+                Ok(sample)
+            }
+            None => Err(LoadMonitorError::NoPreviousSample),
+        };
+
+        self.prev_stats = Some(current);
+        result
+    }
+
+    /// Calculate load percentages from two consecutive stat snapshots
+    pub fn calculate_load(prev: &CpuStats, current: &CpuStats, start_time: Instant) -> LoadSample {
+        let per_core: Vec<f32> = prev
+            .per_core
+            .iter()
+            .zip(current.per_core.iter())
+            .map(|(p, c)| Self::calculate_core_load(p, c))
+            .collect();
+
+        let average = if per_core.is_empty() {
+            Self::calculate_core_load(&prev.total, &current.total)
+        } else {
+            per_core.iter().sum::<f32>() / per_core.len() as f32
+        };
+
+        LoadSample {
+            average,
+            per_core,
+            timestamp_ms: start_time.elapsed().as_millis() as u64,
+        }
+    }
+
+    /// Calculate load percentage for a single core between two samples
+    fn calculate_core_load(prev: &CoreStats, current: &CoreStats) -> f32 {
+        let total_delta = current.total().saturating_sub(prev.total());
+        let idle_delta = current.idle_time().saturating_sub(prev.idle_time());
+
+        if total_delta == 0 {
+            return 0.0;
+        }
+
+        let active_delta = total_delta.saturating_sub(idle_delta);
+        let load = (active_delta as f64 / total_delta as f64) * 100.0;
+        
+        // Clamp to valid range
+        load.clamp(0.0, 100.0) as f32
+    }
+}
+
+/// Configuration for EWMA smoothing and hysteresis-banded mode escalation
+/// over a stream of `LoadSample`s
+///
+/// `calculate_load`'s raw per-interval average is noisy enough that a
+/// threshold-based mode switch can flap rapidly when load oscillates
+/// around the boundary. [`LoadEscalationTracker`] smooths the average with
+/// an EWMA before comparing it to either threshold, and additionally
+/// requires a minimum dwell time since the last transition before
+/// switching again.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoadHysteresisConfig {
+    /// EWMA smoothing factor applied to `LoadSample::average`, in `(0.0,
+    /// 1.0]` - `1.0` disables smoothing (passthrough)
+    pub ewma_alpha: f32,
+    /// The smoothed average must rise to at least this value to escalate
+    pub escalate_threshold: f32,
+    /// The smoothed average must fall to at most this value to de-escalate
+    pub de_escalate_threshold: f32,
+    /// Minimum time that must elapse between transitions
+    pub dwell: Duration,
+}
+
+/// Escalation state tracked by [`LoadEscalationTracker`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadMode {
+    /// Smoothed load is below the escalate threshold (or still dwelling)
+    Normal,
+    /// Smoothed load has crossed the escalate threshold and hasn't yet
+    /// de-escalated
+    Escalated,
+}
+
+/// Stateful tracker that EWMA-smooths `LoadSample` averages and applies
+/// hysteresis-banded, dwell-gated mode escalation/de-escalation
+///
+/// This sits alongside, not inside, `calculate_load`: the raw
+/// `LoadSample::average`/`per_core` values keep their existing `[0, 100]`
+/// range and monotonicity guarantees unchanged, and a caller that wants
+/// stable mode-switch behavior feeds each sample through this tracker
+/// separately.
+#[derive(Debug, Clone)]
+pub struct LoadEscalationTracker {
+    config: LoadHysteresisConfig,
+    smoothed: Option<f32>,
+    mode: LoadMode,
+    last_transition: Option<Instant>,
+}
+
+impl LoadEscalationTracker {
+    /// Create a new tracker starting in `LoadMode::Normal` with no smoothing
+    /// history
+    pub fn new(config: LoadHysteresisConfig) -> Self {
+        LoadEscalationTracker {
+            config,
+            smoothed: None,
+            mode: LoadMode::Normal,
+            last_transition: None,
+        }
+    }
+
+    /// The current escalation mode
+    pub fn mode(&self) -> LoadMode {
+        self.mode
+    }
+
+    /// The current EWMA-smoothed average, or `None` before the first sample
+    pub fn smoothed_average(&self) -> Option<f32> {
+        self.smoothed
+    }
+
+    /// Feed one `LoadSample` through the EWMA and hysteresis band at time
+    /// `now`, returning the (possibly unchanged) mode
+    ///
+    /// `sample.average` is clamped to `[0, 100]` before smoothing, so the
+    /// smoothed average - a convex combination of in-range values - stays
+    /// in `[0, 100]` too.
+    pub fn update(&mut self, sample: &LoadSample, now: Instant) -> LoadMode {
+        let raw = sample.average.clamp(0.0, 100.0);
+        let smoothed = match self.smoothed {
+            Some(prev) => self.config.ewma_alpha * raw + (1.0 - self.config.ewma_alpha) * prev,
+            None => raw,
+        };
+        self.smoothed = Some(smoothed);
+
+        let dwell_elapsed = self
+            .last_transition
+            .is_none_or(|t| now.duration_since(t) >= self.config.dwell);
+
+        if dwell_elapsed {
+            match self.mode {
+                LoadMode::Normal if smoothed >= self.config.escalate_threshold => {
+                    self.mode = LoadMode::Escalated;
+                    self.last_transition = Some(now);
+                }
+                LoadMode::Escalated if smoothed <= self.config.de_escalate_threshold => {
+                    self.mode = LoadMode::Normal;
+                    self.last_transition = Some(now);
+                }
+                _ => {}
+            }
+        }
+
+        self.mode
+    }
+
+    /// Reset all smoothing and mode state, as if freshly constructed
+    pub fn reset(&mut self) {
+        self.smoothed = None;
+        self.mode = LoadMode::Normal;
+        self.last_transition = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_PROC_STAT: &str = r#"cpu  10132153 290696 3084719 46828483 16683 0 25195 0 0 0
+cpu0 1393280 32966 572056 13343292 6130 0 17875 0 0 0
+cpu1 1335535 34218 506820 13496949 3908 0 3556 0 0 0
+cpu2 1339767 33239 502039 13496407 3742 0 1829 0 0 0
+cpu3 1340270 33232 502039 13496407 3742 0 1829 0 0 0
+intr 620315706 0 0 0 0 0 0 0 0 1 79 0 0 156 0 0 0
+"#;
+
+    #[test]
+    fn test_parse_proc_stat() {
+        let stats = LoadMonitor::parse_proc_stat(SAMPLE_PROC_STAT).unwrap();
+        
+        assert_eq!(stats.per_core.len(), 4);
+        assert_eq!(stats.total.user, 10132153);
+        assert_eq!(stats.total.nice, 290696);
+        assert_eq!(stats.total.system, 3084719);
+        assert_eq!(stats.total.idle, 46828483);
+    }
+
+    #[test]
+    fn test_cpu_stats_from_buf_read_matches_parse_proc_stat() {
+        // Non-filesystem source: an in-memory cursor read through the
+        // generic FromProcReader surface should parse identically
+        let from_reader = CpuStats::from_read(SAMPLE_PROC_STAT.as_bytes()).unwrap();
+        let from_str = LoadMonitor::parse_proc_stat(SAMPLE_PROC_STAT).unwrap();
+
+        assert_eq!(from_reader.total, from_str.total);
+        assert_eq!(from_reader.per_core, from_str.per_core);
+    }
+
+    #[test]
+    fn test_parse_cpu_line() {
+        let line = "cpu0 1393280 32966 572056 13343292 6130 0 17875 0 0 0";
+        let stats = LoadMonitor::parse_cpu_line(line).unwrap();
+        
+        assert_eq!(stats.user, 1393280);
+        assert_eq!(stats.nice, 32966);
+        assert_eq!(stats.system, 572056);
+        assert_eq!(stats.idle, 13343292);
+        assert_eq!(stats.iowait, 6130);
+        assert_eq!(stats.irq, 0);
+        assert_eq!(stats.softirq, 17875);
+        assert_eq!(stats.steal, 0);
+        assert_eq!(stats.guest, 0);
+        assert_eq!(stats.guest_nice, 0);
+    }
+
+    #[test]
+    fn test_parse_cpu_line_missing_guest_columns() {
+        // Older kernels don't emit the guest/guest_nice columns at all
+        let line = "cpu0 1393280 32966 572056 13343292 6130 0 17875 0";
+        let stats = LoadMonitor::parse_cpu_line(line).unwrap();
+
+        assert_eq!(stats.steal, 0);
+        assert_eq!(stats.guest, 0);
+        assert_eq!(stats.guest_nice, 0);
+    }
+
+    #[test]
+    fn test_core_stats_total_excludes_guest_to_avoid_double_counting() {
+        // Linux already folds guest time into user/nice, so total() must not
+        // add guest/guest_nice on top of them
+        let with_guest = CoreStats {
+            user: 100,
+            guest: 40,
+            nice: 10,
+            guest_nice: 5,
+            ..CoreStats::default()
+        };
+        let without_guest = CoreStats {
+            user: 100,
+            nice: 10,
+            ..CoreStats::default()
+        };
+
+        assert_eq!(with_guest.total(), without_guest.total());
+    }
+
+    #[test]
+    fn test_state_breakdown_distinguishes_iowait_and_steal() {
+        let prev = CoreStats::default();
+        let current = CoreStats {
+            user: 10,
+            iowait: 60,
+            steal: 30,
+            ..CoreStats::default()
+        };
+
+        let breakdown = StateBreakdown::from_delta(&prev, &current);
+
+        assert!((breakdown.user_pct - 10.0).abs() < 0.01);
+        assert!((breakdown.iowait_pct - 60.0).abs() < 0.01);
+        assert!((breakdown.steal_pct - 30.0).abs() < 0.01);
+        assert_eq!(breakdown.system_pct, 0.0);
+        assert_eq!(breakdown.guest_pct, 0.0);
+    }
+
+    #[test]
+    fn test_state_breakdown_zero_delta_is_all_zero() {
+        let stats = CoreStats {
+            user: 100,
+            idle: 900,
+            ..CoreStats::default()
+        };
+
+        let breakdown = StateBreakdown::from_delta(&stats, &stats);
+        assert_eq!(breakdown, StateBreakdown::default());
+    }
+
+    #[test]
+    fn test_core_stats_calculations() {
+        let stats = CoreStats {
+            user: 100,
+            nice: 10,
+            system: 50,
+            idle: 800,
+            iowait: 20,
+            irq: 5,
+            softirq: 10,
+            steal: 5,
+            guest: 0,
+            guest_nice: 0,
+        };
+
+        assert_eq!(stats.total(), 1000);
+        assert_eq!(stats.active(), 180); // user + nice + system + irq + softirq + steal
+        assert_eq!(stats.idle_time(), 820); // idle + iowait
+    }
+
+    #[test]
+    fn test_calculate_core_load() {
+        let prev = CoreStats {
+            user: 100,
+            nice: 0,
+            system: 0,
+            idle: 900,
+            iowait: 0,
+            irq: 0,
+            softirq: 0,
+            steal: 0,
+            guest: 0,
+            guest_nice: 0,
+        };
+
+        let current = CoreStats {
+            user: 200,
+            nice: 0,
+            system: 0,
+            idle: 1800,
+            iowait: 0,
+            irq: 0,
+            softirq: 0,
+            steal: 0,
+            guest: 0,
+            guest_nice: 0,
+        };
+
+        // Delta: 100 active, 900 idle = 1000 total
+        // Load = 100/1000 = 10%
+        let load = LoadMonitor::calculate_core_load(&prev, &current);
+        assert!((load - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_calculate_load_average() {
+        let prev = CpuStats {
+            total: CoreStats::default(),
+            per_core: vec![
+                CoreStats { user: 0, nice: 0, system: 0, idle: 100, iowait: 0, irq: 0, softirq: 0, steal: 0, guest: 0, guest_nice: 0 },
+                CoreStats { user: 0, nice: 0, system: 0, idle: 100, iowait: 0, irq: 0, softirq: 0, steal: 0, guest: 0, guest_nice: 0 },
+            ],
+            timestamp: Instant::now(),
+        };
+
+        let current = CpuStats {
+            total: CoreStats::default(),
+            per_core: vec![
+                CoreStats { user: 50, nice: 0, system: 0, idle: 150, iowait: 0, irq: 0, softirq: 0, steal: 0, guest: 0, guest_nice: 0 },
+                CoreStats { user: 30, nice: 0, system: 0, idle: 170, iowait: 0, irq: 0, softirq: 0, steal: 0, guest: 0, guest_nice: 0 },
+            ],
+            timestamp: Instant::now(),
+        };
+
+        let sample = LoadMonitor::calculate_load(&prev, &current, Instant::now());
+        
+        // Core 0: 50 active / 100 total = 50%
+        // Core 1: 30 active / 100 total = 30%
+        // Average: 40%
+        assert!((sample.per_core[0] - 50.0).abs() < 0.01);
+        assert!((sample.per_core[1] - 30.0).abs() < 0.01);
+        assert!((sample.average - 40.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_load_clamped_to_valid_range() {
+        // Test that load is always in [0, 100] range
+        let prev = CoreStats {
+            user: 1000,
+            nice: 0,
+            system: 0,
+            idle: 0,
+            iowait: 0,
+            irq: 0,
+            softirq: 0,
+            steal: 0,
+            guest: 0,
+            guest_nice: 0,
+        };
+
+        let current = CoreStats {
+            user: 2000,
+            nice: 0,
+            system: 0,
+            idle: 0,
+            iowait: 0,
+            irq: 0,
+            softirq: 0,
+            steal: 0,
+            guest: 0,
+            guest_nice: 0,
+        };
+
+        let load = LoadMonitor::calculate_core_load(&prev, &current);
+        assert!(load >= 0.0 && load <= 100.0);
+    }
+
+    #[test]
+    fn test_zero_delta_returns_zero_load() {
+        let stats = CoreStats {
+            user: 100,
+            nice: 0,
+            system: 0,
+            idle: 900,
+            iowait: 0,
+            irq: 0,
+            softirq: 0,
+            steal: 0,
+            guest: 0,
+            guest_nice: 0,
+        };
+
+        let load = LoadMonitor::calculate_core_load(&stats, &stats);
+        assert_eq!(load, 0.0);
+    }
+
+    #[test]
+    fn test_validate_sample_interval_valid() {
+        // Minimum valid
+        assert!(validate_sample_interval_ms(10).is_ok());
+        // Maximum valid
+        assert!(validate_sample_interval_ms(5000).is_ok());
+        // Middle value
+        assert!(validate_sample_interval_ms(100).is_ok());
+        assert!(validate_sample_interval_ms(1000).is_ok());
+    }
+
+    #[test]
+    fn test_validate_sample_interval_too_small() {
+        assert!(validate_sample_interval_ms(9).is_err());
+        assert!(validate_sample_interval_ms(0).is_err());
+        assert!(validate_sample_interval_ms(1).is_err());
+    }
+
+    #[test]
+    fn test_validate_sample_interval_too_large() {
+        assert!(validate_sample_interval_ms(5001).is_err());
+        assert!(validate_sample_interval_ms(10000).is_err());
+        assert!(validate_sample_interval_ms(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_load_monitor_rejects_invalid_interval() {
+        // Too small
+        assert!(LoadMonitor::with_path(5, "/proc/stat".to_string()).is_err());
+        // Too large
+        assert!(LoadMonitor::with_path(6000, "/proc/stat".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_load_monitor_accepts_valid_interval() {
+        // Note: This will fail on non-Linux systems due to /proc/stat not existing,
+        // but the validation itself should pass
+        let result = LoadMonitor::with_path(100, "/nonexistent".to_string());
+        // The constructor should succeed (validation passes),
+        // only actual sampling would fail
+        assert!(result.is_ok());
+    }
+
+    fn sample(average: f32) -> LoadSample {
+        LoadSample {
+            average,
+            per_core: vec![average],
+            timestamp_ms: 0,
+        }
+    }
+
+    fn hysteresis_config(dwell: Duration) -> LoadHysteresisConfig {
+        LoadHysteresisConfig {
+            ewma_alpha: 0.5,
+            escalate_threshold: 80.0,
+            de_escalate_threshold: 30.0,
+            dwell,
+        }
+    }
+
+    #[test]
+    fn test_load_escalation_tracker_starts_normal() {
+        let tracker = LoadEscalationTracker::new(hysteresis_config(Duration::ZERO));
+        assert_eq!(tracker.mode(), LoadMode::Normal);
+        assert_eq!(tracker.smoothed_average(), None);
+    }
+
+    #[test]
+    fn test_load_escalation_tracker_first_sample_is_unsmoothed() {
+        let mut tracker = LoadEscalationTracker::new(hysteresis_config(Duration::ZERO));
+        tracker.update(&sample(42.0), Instant::now());
+        assert_eq!(tracker.smoothed_average(), Some(42.0));
+    }
+
+    #[test]
+    fn test_load_escalation_tracker_escalates_on_sustained_high_load() {
+        let mut tracker = LoadEscalationTracker::new(hysteresis_config(Duration::ZERO));
+        let t0 = Instant::now();
+        // Establish a mid-range baseline, then spike once: a single spike
+        // isn't enough to cross the threshold under EWMA smoothing.
+        tracker.update(&sample(50.0), t0);
+        tracker.update(&sample(95.0), t0);
+        assert_eq!(tracker.mode(), LoadMode::Normal);
+
+        // A sustained high load converges the smoothed average upward until
+        // it crosses the escalate threshold.
+        for _ in 0..10 {
+            tracker.update(&sample(95.0), t0);
+        }
+        assert_eq!(tracker.mode(), LoadMode::Escalated);
+    }
+
+    #[test]
+    fn test_load_escalation_tracker_ignores_brief_dip_below_de_escalate_band() {
+        // A dip that lands between the two thresholds should not
+        // de-escalate - only crossing the lower threshold should.
+        let mut tracker = LoadEscalationTracker::new(hysteresis_config(Duration::ZERO));
+        let t0 = Instant::now();
+        for _ in 0..10 {
+            tracker.update(&sample(95.0), t0);
+        }
+        assert_eq!(tracker.mode(), LoadMode::Escalated);
+
+        tracker.update(&sample(50.0), t0);
+        assert_eq!(tracker.mode(), LoadMode::Escalated);
+    }
+
+    #[test]
+    fn test_load_escalation_tracker_de_escalates_on_sustained_low_load() {
+        let mut tracker = LoadEscalationTracker::new(hysteresis_config(Duration::ZERO));
+        let t0 = Instant::now();
+        for _ in 0..10 {
+            tracker.update(&sample(95.0), t0);
+        }
+        assert_eq!(tracker.mode(), LoadMode::Escalated);
+
+        for _ in 0..10 {
+            tracker.update(&sample(5.0), t0);
+        }
+        assert_eq!(tracker.mode(), LoadMode::Normal);
+    }
+
+    #[test]
+    fn test_load_escalation_tracker_dwell_suppresses_immediate_transition_back() {
+        let mut tracker = LoadEscalationTracker::new(hysteresis_config(Duration::from_millis(500)));
+        let t0 = Instant::now();
+        for _ in 0..10 {
+            tracker.update(&sample(95.0), t0);
+        }
+        assert_eq!(tracker.mode(), LoadMode::Escalated);
+
+        // Still within the dwell window: even a sustained low reading must
+        // not flip the mode back yet
+        let t1 = t0 + Duration::from_millis(200);
+        for _ in 0..10 {
+            tracker.update(&sample(0.0), t1);
+        }
+        assert_eq!(tracker.mode(), LoadMode::Escalated);
+
+        // Past the dwell window, the same sustained low reading takes effect
+        let t2 = t0 + Duration::from_millis(600);
+        for _ in 0..10 {
+            tracker.update(&sample(0.0), t2);
+        }
+        assert_eq!(tracker.mode(), LoadMode::Normal);
+    }
+
+    #[test]
+    fn test_load_escalation_tracker_smoothed_average_stays_in_range() {
+        let mut tracker = LoadEscalationTracker::new(hysteresis_config(Duration::ZERO));
+        let t0 = Instant::now();
+        for &load in &[0.0, 100.0, 50.0, 100.0, 0.0] {
+            tracker.update(&sample(load), t0);
+            let smoothed = tracker.smoothed_average().unwrap();
+            assert!((0.0..=100.0).contains(&smoothed));
+        }
+    }
+
+    #[test]
+    fn test_load_escalation_tracker_alpha_one_is_passthrough() {
+        let config = LoadHysteresisConfig {
+            ewma_alpha: 1.0,
+            ..hysteresis_config(Duration::ZERO)
+        };
+        let mut tracker = LoadEscalationTracker::new(config);
+        tracker.update(&sample(10.0), Instant::now());
+        assert_eq!(tracker.smoothed_average(), Some(10.0));
+        tracker.update(&sample(90.0), Instant::now());
+        assert_eq!(tracker.smoothed_average(), Some(90.0));
+    }
+
+    #[test]
+    fn test_load_escalation_tracker_reset_clears_state() {
+        let mut tracker = LoadEscalationTracker::new(hysteresis_config(Duration::ZERO));
+        let t0 = Instant::now();
+        for _ in 0..10 {
+            tracker.update(&sample(95.0), t0);
+        }
+        assert_eq!(tracker.mode(), LoadMode::Escalated);
+
+        tracker.reset();
+        assert_eq!(tracker.mode(), LoadMode::Normal);
+        assert_eq!(tracker.smoothed_average(), None);
+    }
+}
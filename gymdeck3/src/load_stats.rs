@@ -0,0 +1,190 @@
+//! Sample-stability statistics with outlier pruning
+//!
+//! Reports mean, standard deviation, and a stability verdict over a run of
+//! average-load values, so the tuner can avoid reacting to transient spikes.
+//! Before computing the final mean, outliers more than
+//! [`OUTLIER_STD_DEVS`] standard deviations from the running mean are
+//! iteratively pruned until the set stabilizes or [`MIN_VALID_SAMPLES`] is
+//! reached.
+
+/// Minimum number of samples required to report stats
+pub const MIN_VALID_SAMPLES: usize = 5;
+
+/// Samples farther than this many standard deviations from the running
+/// mean are pruned as outliers
+pub const OUTLIER_STD_DEVS: f32 = 2.0;
+
+/// Mean/deviation summary of a pruned sample run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoadStats {
+    /// Number of samples in the original run, before pruning
+    pub total: usize,
+    /// Number of samples that survived outlier pruning
+    pub valid: usize,
+    /// Mean of the surviving samples
+    pub mean: f32,
+    /// Standard deviation of the surviving samples
+    pub deviation: f32,
+}
+
+impl LoadStats {
+    /// Whether the surviving samples are stable: standard deviation at or
+    /// below `threshold`
+    pub fn is_stable(&self, threshold: f32) -> bool {
+        self.deviation <= threshold
+    }
+}
+
+/// Error returned when too few samples survive outlier pruning
+#[derive(Debug, PartialEq)]
+pub enum LoadStatsError {
+    /// Fewer than the minimum required samples remained after pruning
+    InsufficientSamples { valid: usize, minimum: usize },
+}
+
+impl std::fmt::Display for LoadStatsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadStatsError::InsufficientSamples { valid, minimum } => write!(
+                f,
+                "only {} valid sample(s) remained after pruning, need at least {}",
+                valid, minimum
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LoadStatsError {}
+
+fn mean(samples: &[f32]) -> f32 {
+    samples.iter().sum::<f32>() / samples.len() as f32
+}
+
+fn std_dev(samples: &[f32], mean: f32) -> f32 {
+    let variance = samples.iter().map(|&x| (x - mean).powi(2)).sum::<f32>() / samples.len() as f32;
+    variance.sqrt()
+}
+
+/// Compute `LoadStats` over `samples`, pruning outliers until the set
+/// stabilizes or [`MIN_VALID_SAMPLES`] is reached
+pub fn compute_load_stats(samples: &[f32]) -> Result<LoadStats, LoadStatsError> {
+    compute_load_stats_with_min(samples, MIN_VALID_SAMPLES)
+}
+
+/// Compute `LoadStats` over `samples`, with a custom minimum valid sample
+/// count
+pub fn compute_load_stats_with_min(
+    samples: &[f32],
+    min_valid: usize,
+) -> Result<LoadStats, LoadStatsError> {
+    let total = samples.len();
+    let mut valid: Vec<f32> = samples.to_vec();
+
+    while valid.len() > min_valid {
+        let running_mean = mean(&valid);
+        let deviation = std_dev(&valid, running_mean);
+        if deviation == 0.0 {
+            break;
+        }
+
+        let pruned: Vec<f32> = valid
+            .iter()
+            .copied()
+            .filter(|&x| (x - running_mean).abs() <= OUTLIER_STD_DEVS * deviation)
+            .collect();
+
+        if pruned.len() == valid.len() || pruned.len() < min_valid {
+            // Either the set has stabilized (no outliers left), or pruning
+            // further would drop below the minimum - stop here either way.
+            break;
+        }
+
+        valid = pruned;
+    }
+
+    if valid.len() < min_valid {
+        return Err(LoadStatsError::InsufficientSamples {
+            valid: valid.len(),
+            minimum: min_valid,
+        });
+    }
+
+    let final_mean = mean(&valid);
+    let deviation = std_dev(&valid, final_mean);
+
+    Ok(LoadStats {
+        total,
+        valid: valid.len(),
+        mean: final_mean,
+        deviation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stable_run_has_zero_deviation() {
+        let samples = vec![50.0; 10];
+        let stats = compute_load_stats(&samples).unwrap();
+
+        assert_eq!(stats.total, 10);
+        assert_eq!(stats.valid, 10);
+        assert_eq!(stats.mean, 50.0);
+        assert_eq!(stats.deviation, 0.0);
+        assert!(stats.is_stable(0.1));
+    }
+
+    #[test]
+    fn test_outlier_is_pruned() {
+        let mut samples = vec![50.0; 9];
+        samples.push(500.0);
+
+        let stats = compute_load_stats(&samples).unwrap();
+
+        assert_eq!(stats.total, 10);
+        assert_eq!(stats.valid, 9);
+        assert_eq!(stats.mean, 50.0);
+    }
+
+    #[test]
+    fn test_insufficient_samples_errors() {
+        let samples = vec![10.0, 20.0, 30.0];
+        let result = compute_load_stats(&samples);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_pruning_stops_rather_than_undershoot_minimum() {
+        // Six samples with one wild outlier and a minimum of 5: pruning the
+        // outlier leaves exactly the minimum, which should succeed.
+        let samples = vec![10.0, 10.0, 10.0, 10.0, 10.0, 1000.0];
+        let stats = compute_load_stats_with_min(&samples, 5).unwrap();
+        assert_eq!(stats.valid, 5);
+        assert_eq!(stats.mean, 10.0);
+
+        // Requiring more valid samples than exist at all should fail outright.
+        let err = compute_load_stats_with_min(&samples, 7).unwrap_err();
+        assert_eq!(
+            err,
+            LoadStatsError::InsufficientSamples {
+                valid: 6,
+                minimum: 7
+            }
+        );
+    }
+
+    #[test]
+    fn test_is_stable_threshold() {
+        let stats = LoadStats {
+            total: 5,
+            valid: 5,
+            mean: 50.0,
+            deviation: 3.0,
+        };
+
+        assert!(stats.is_stable(5.0));
+        assert!(!stats.is_stable(1.0));
+    }
+}
@@ -0,0 +1,158 @@
+//! Kernel load average monitoring from /proc/loadavg
+//!
+//! Sibling monitor to `LoadMonitor`/`MemMonitor`, parsing the six
+//! whitespace-separated fields of `/proc/loadavg` and reusing the same
+//! `with_path` testability hook and error taxonomy style.
+
+use std::fs;
+use std::io;
+
+/// Kernel load average snapshot
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoadAvg {
+    /// 1-minute load average
+    pub one: f32,
+    /// 5-minute load average
+    pub five: f32,
+    /// 15-minute load average
+    pub fifteen: f32,
+    /// Currently runnable scheduling entities
+    pub runnable: u32,
+    /// Total scheduling entities currently on the system
+    pub total_procs: u32,
+}
+
+/// Error types for LoadAvgMonitor operations
+#[derive(Debug)]
+pub enum LoadAvgMonitorError {
+    IoError(io::Error),
+    ParseError(String),
+}
+
+impl std::fmt::Display for LoadAvgMonitorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadAvgMonitorError::IoError(e) => write!(f, "I/O error: {}", e),
+            LoadAvgMonitorError::ParseError(s) => write!(f, "Parse error: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for LoadAvgMonitorError {}
+
+impl From<io::Error> for LoadAvgMonitorError {
+    fn from(e: io::Error) -> Self {
+        LoadAvgMonitorError::IoError(e)
+    }
+}
+
+/// Load average monitor that reads from /proc/loadavg
+pub struct LoadAvgMonitor {
+    proc_loadavg_path: String,
+}
+
+impl LoadAvgMonitor {
+    /// Create a new LoadAvgMonitor reading the default /proc/loadavg path
+    pub fn new() -> Self {
+        Self::with_path("/proc/loadavg".to_string())
+    }
+
+    /// Create a new LoadAvgMonitor with a custom /proc/loadavg path (for testing)
+    pub fn with_path(proc_loadavg_path: String) -> Self {
+        Self { proc_loadavg_path }
+    }
+
+    /// Read and parse the current load average from /proc/loadavg
+    pub fn sample(&self) -> Result<LoadAvg, LoadAvgMonitorError> {
+        let content = fs::read_to_string(&self.proc_loadavg_path)?;
+        Self::parse_loadavg(&content)
+    }
+
+    /// Parse /proc/loadavg content into a LoadAvg
+    ///
+    /// Format: `one five fifteen runnable/total_procs last_pid`. We use
+    /// only the first four fields; the trailing last-pid field is ignored.
+    pub fn parse_loadavg(content: &str) -> Result<LoadAvg, LoadAvgMonitorError> {
+        let mut fields = content.split_whitespace();
+
+        let parse_f32 = |token: Option<&str>, name: &str| -> Result<f32, LoadAvgMonitorError> {
+            token
+                .ok_or_else(|| LoadAvgMonitorError::ParseError(format!("Missing {} field", name)))?
+                .parse()
+                .map_err(|_| LoadAvgMonitorError::ParseError(format!("Invalid {} value", name)))
+        };
+
+        let one = parse_f32(fields.next(), "one-minute load average")?;
+        let five = parse_f32(fields.next(), "five-minute load average")?;
+        let fifteen = parse_f32(fields.next(), "fifteen-minute load average")?;
+
+        let runnable_total = fields.next().ok_or_else(|| {
+            LoadAvgMonitorError::ParseError("Missing runnable/total field".to_string())
+        })?;
+        let (runnable_str, total_str) = runnable_total.split_once('/').ok_or_else(|| {
+            LoadAvgMonitorError::ParseError(format!(
+                "Malformed runnable/total field '{}'",
+                runnable_total
+            ))
+        })?;
+
+        let runnable: u32 = runnable_str
+            .parse()
+            .map_err(|_| LoadAvgMonitorError::ParseError("Invalid runnable value".to_string()))?;
+        let total_procs: u32 = total_str
+            .parse()
+            .map_err(|_| LoadAvgMonitorError::ParseError("Invalid total_procs value".to_string()))?;
+
+        Ok(LoadAvg {
+            one,
+            five,
+            fifteen,
+            runnable,
+            total_procs,
+        })
+    }
+}
+
+impl Default for LoadAvgMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_loadavg() {
+        let content = "0.52 0.58 0.59 2/487 12345\n";
+        let loadavg = LoadAvgMonitor::parse_loadavg(content).unwrap();
+
+        assert!((loadavg.one - 0.52).abs() < 0.001);
+        assert!((loadavg.five - 0.58).abs() < 0.001);
+        assert!((loadavg.fifteen - 0.59).abs() < 0.001);
+        assert_eq!(loadavg.runnable, 2);
+        assert_eq!(loadavg.total_procs, 487);
+    }
+
+    #[test]
+    fn test_parse_loadavg_missing_field_is_error() {
+        let content = "0.52 0.58\n";
+        let result = LoadAvgMonitor::parse_loadavg(content);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_loadavg_malformed_runnable_field_is_error() {
+        let content = "0.52 0.58 0.59 2-487 12345\n";
+        let result = LoadAvgMonitor::parse_loadavg(content);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_loadavg_invalid_number_is_error() {
+        let content = "not_a_number 0.58 0.59 2/487 12345\n";
+        let result = LoadAvgMonitor::parse_loadavg(content);
+        assert!(result.is_err());
+    }
+}
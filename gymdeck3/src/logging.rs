@@ -0,0 +1,214 @@
+//! `tracing` integration emitting the same NDJSON envelope as status output
+//!
+//! gymdeck3's UI client parses stdout line-by-line, each line a typed
+//! `{"type":...}` object. This module renders `tracing` events into that
+//! same shape - `{"type":"log","level":...,"target":...,"fields":{...},
+//! "uptime_ms":...}` - so daemon diagnostics interleave cleanly with status
+//! updates on the one stream the UI already understands, instead of needing
+//! a second parser for plain-text logs.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
+
+use crate::output::OutputWriter;
+
+/// Log output message mirroring the `StatusOutput`/`TransitionOutput`/
+/// `ErrorOutput` envelope shape
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LogOutput {
+    /// Message type identifier
+    #[serde(rename = "type")]
+    pub msg_type: String,
+    /// Tracing level, lowercased (`"error"`, `"warn"`, `"info"`, `"debug"`, `"trace"`)
+    pub level: String,
+    /// Event target (typically the emitting module path)
+    pub target: String,
+    /// Event fields flattened into a JSON object
+    pub fields: BTreeMap<String, Value>,
+    /// Uptime in milliseconds since the shared writer was created
+    pub uptime_ms: u64,
+}
+
+impl LogOutput {
+    /// Create a new log output message
+    pub fn new(
+        level: &str,
+        target: &str,
+        fields: BTreeMap<String, Value>,
+        uptime_ms: u64,
+    ) -> Self {
+        Self {
+            msg_type: "log".to_string(),
+            level: level.to_string(),
+            target: target.to_string(),
+            fields,
+            uptime_ms,
+        }
+    }
+
+    /// Serialize to JSON string
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+/// Validate a log output JSON string
+pub fn validate_log_output(json_str: &str) -> Result<LogOutput, String> {
+    if json_str.contains('\n') {
+        return Err("log output must not contain embedded newlines".to_string());
+    }
+
+    let output: LogOutput =
+        serde_json::from_str(json_str).map_err(|e| format!("Invalid JSON: {}", e))?;
+
+    if output.msg_type != "log" {
+        return Err(format!("Expected type 'log', got '{}'", output.msg_type));
+    }
+
+    if output.level.is_empty() {
+        return Err("level cannot be empty".to_string());
+    }
+
+    if output.target.is_empty() {
+        return Err("target cannot be empty".to_string());
+    }
+
+    Ok(output)
+}
+
+/// Flattens a `tracing::Event`'s fields into a JSON object
+#[derive(Default)]
+struct FieldCollector {
+    fields: BTreeMap<String, Value>,
+}
+
+impl Visit for FieldCollector {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.fields
+            .insert(field.name().to_string(), Value::String(format!("{:?}", value)));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.fields
+            .insert(field.name().to_string(), Value::String(value.to_string()));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.fields.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.fields.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.fields.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if let Some(n) = serde_json::Number::from_f64(value) {
+            self.fields.insert(field.name().to_string(), Value::Number(n));
+        }
+    }
+}
+
+/// Lowercase name for a `tracing::Level`
+fn level_name(level: &Level) -> &'static str {
+    match *level {
+        Level::ERROR => "error",
+        Level::WARN => "warn",
+        Level::INFO => "info",
+        Level::DEBUG => "debug",
+        Level::TRACE => "trace",
+    }
+}
+
+/// A `tracing_subscriber::Layer` that renders events as NDJSON log lines
+///
+/// Writes through the same `OutputWriter` (and its stdout lock) used for
+/// status/transition/error output, so log lines never interleave mid-line
+/// with a status line written from another thread.
+pub struct NdjsonLogLayer {
+    writer: Arc<Mutex<OutputWriter>>,
+}
+
+impl NdjsonLogLayer {
+    /// Create a layer that writes through the given shared `OutputWriter`
+    pub fn new(writer: Arc<Mutex<OutputWriter>>) -> Self {
+        Self { writer }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for NdjsonLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut collector = FieldCollector::default();
+        event.record(&mut collector);
+
+        let level = level_name(event.metadata().level());
+        let target = event.metadata().target();
+
+        if let Ok(mut writer) = self.writer.lock() {
+            let log = LogOutput::new(level, target, collector.fields, writer.uptime_ms());
+            let _ = writer.write_json(&log);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_output_serialization() {
+        let mut fields = BTreeMap::new();
+        fields.insert("core".to_string(), Value::from(2));
+        fields.insert("value_mv".to_string(), Value::from(-28));
+
+        let log = LogOutput::new("info", "gymdeck3::ryzenadj", fields, 1500);
+        let json = log.to_json().unwrap();
+
+        assert!(json.contains("\"type\":\"log\""));
+        assert!(json.contains("\"level\":\"info\""));
+        assert!(json.contains("\"target\":\"gymdeck3::ryzenadj\""));
+        assert!(json.contains("\"uptime_ms\":1500"));
+        assert!(!json.contains('\n'));
+    }
+
+    #[test]
+    fn test_validate_log_output_valid() {
+        let json = r#"{"type":"log","level":"warn","target":"gymdeck3::fan","fields":{"temp_c":82},"uptime_ms":100}"#;
+        let result = validate_log_output(json);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_validate_log_output_wrong_type() {
+        let json = r#"{"type":"status","level":"warn","target":"gymdeck3::fan","fields":{},"uptime_ms":100}"#;
+        let result = validate_log_output(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Expected type 'log'"));
+    }
+
+    #[test]
+    fn test_validate_log_output_rejects_embedded_newline() {
+        let json = "{\"type\":\"log\",\"level\":\"info\",\"target\":\"x\",\"fields\":{},\n\"uptime_ms\":0}";
+        let result = validate_log_output(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("newline"));
+    }
+
+    #[test]
+    fn test_validate_log_output_empty_level() {
+        let json = r#"{"type":"log","level":"","target":"x","fields":{},"uptime_ms":0}"#;
+        let result = validate_log_output(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("level cannot be empty"));
+    }
+}
@@ -12,6 +12,7 @@
 //! - **Multiple strategies**: Conservative, Balanced, Aggressive, and Custom
 //! - **Hysteresis control**: Prevents value hunting around thresholds
 //! - **Smooth transitions**: Linear interpolation with configurable step size
+//! - **Fan control**: Temperature-based fan curve with safety overrides
 //! - **Safety features**: Watchdog, panic hook, graceful shutdown
 //! - **JSON output**: NDJSON status updates for frontend integration
 //!
@@ -39,8 +40,8 @@
 //! │                                       └──────────────────┘  │
 //! │                                                              │
 //! │  ┌──────────────┐  ┌──────────────┐  ┌──────────────────┐  │
-//! │  │ SignalHandler│  │  Watchdog    │  │  OutputWriter    │  │
-//! │  │ TERM/USR1    │  │  (10s timer) │  │  (JSON stdout)   │  │
+//! │  │ FanController│  │  Watchdog    │  │  OutputWriter    │  │
+//! │  │ (hwmon sysfs)│  │  (10s timer) │  │  (JSON stdout)   │  │
 //! │  └──────────────┘  └──────────────┘  └──────────────────┘  │
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
@@ -56,13 +57,46 @@
 //!   --core 3:-20:-35:50.0 \
 //!   --hysteresis 5.0 \
 //!   --ryzenadj-path /usr/bin/ryzenadj \
-//!   --status-interval 1000
+//!   --status-interval 1000 \
+//!   --fan-control --fan-mode custom \
+//!   --fan-curve 40:20 --fan-curve 60:50 --fan-curve 80:100 \
+//!   --control-socket /run/gymdeck3/control.sock
+//!
+//! # Or load strategy/interval/cores from a config file
+//! gymdeck3 --config /etc/gymdeck3.toml
 //! ```
 //!
+//! # Config File
+//!
+//! `--config` points at a TOML (or JSON, by `.json` extension) file that can
+//! supply `strategy`, `sample_interval_us`, `hysteresis`, `status_interval_ms`,
+//! and `cores`. CLI flags always take precedence over matching config-file
+//! values; `strategy` and `sample_interval_us` are only optional on the CLI
+//! when a config file supplies them. See `config::resolve_config`.
+//!
+//! # Control Socket
+//!
+//! When `--control-socket` is set, gymdeck3 opens a Unix domain socket
+//! accepting line-delimited JSON-RPC 2.0 requests (`set_strategy`,
+//! `set_hysteresis`, `set_core`, `get_status`) and streams the same NDJSON
+//! status lines sent to stdout to every connected client. See the
+//! `control` module for details.
+//!
+//! # Status Socket
+//!
+//! When `--status-socket` is set, gymdeck3 opens a second, status-only Unix
+//! domain socket and streams each status update as a 4-byte big-endian
+//! length prefix followed by the same JSON payload, so a long-lived UI can
+//! subscribe once without re-spawning the daemon or parsing NDJSON line
+//! boundaries. Stdout NDJSON keeps flowing regardless. See the
+//! `status_server` module for details.
+//!
 //! # Signal Handling
 //!
-//! - **SIGTERM/SIGINT**: Graceful shutdown (resets values to 0)
+//! - **SIGTERM/SIGINT**: Graceful shutdown (resets values to 0, returns fan to BIOS)
 //! - **SIGUSR1**: Force immediate status output
+//! - **SIGHUP**: Re-read `--config` and apply it live, without restarting
+//!   or resetting any in-flight hysteresis/PID controller state
 //!
 //! # Exit Codes
 //!
@@ -71,33 +105,349 @@
 //! - `2`: /proc/stat unavailable
 //! - `3`: ryzenadj binary not found
 //! - `4`: ryzenadj failed 5 consecutive times
-//! - `5`: Watchdog timeout (main loop stalled)
+//! - `5`: Watchdog gave up after repeated failed recovery attempts (main loop stalled)
 //! - `6`: Not running as root
+//! - `7`: Fan control initialization failed
+//! - `8`: Fan stall detected (commanded PWM exceeded the health floor but
+//!   tach RPM stayed near zero for too many consecutive ticks)
 //! - `101`: Panic (after resetting values)
 //!
 //! # Requirements
 //!
 //! - Linux with /proc/stat (SteamOS 3.x)
-//! - Root privileges (for ryzenadj)
+//! - Root privileges (for ryzenadj and fan control)
 //! - ryzenadj binary in PATH or specified via --ryzenadj-path
 
 mod config;
 mod load_monitor;
 mod strategy;
 mod output;
-mod signals;
 mod hysteresis;
 mod interpolation;
 mod ryzenadj;
 mod watchdog;
 mod safety;
+mod model;
+mod hardware;
+mod smoothing;
+pub mod fan;
+mod rpc;
+mod precision;
+
+#[cfg(unix)]
+mod signals;
+#[cfg(unix)]
+mod control;
+#[cfg(unix)]
+mod status_server;
+
+use std::time::Duration;
 
 use clap::Parser;
-use config::{Args, validate_args};
-use output::OutputWriter;
-use signals::{SignalHandler, SignalState, graceful_shutdown, install_panic_hook};
-use safety::check_root_or_exit;
+use config::{Args, FanControlMode, ResolvedConfig, validate_args, resolve_config};
+use output::{OutputWriter, FanStatusOutput};
+use fan::{
+    FanController, FanCurve, FanCurvePoint, FanControllerConfig, FanSafetyLimits,
+    PidFanController, FanHealthStatus, MIN_PWM, MAX_PWM,
+};
+#[cfg(unix)]
+use signals::{DaemonEvent, SignalHandler, SignalState, graceful_shutdown, install_panic_hook};
+use safety::{check_root_or_exit, clamp_value_thermal, EXIT_CODE_FAN_STALL, EXIT_CODE_THERMAL_RUNAWAY};
+use strategy::CoreBounds;
+use interpolation::Interpolator;
+use watchdog::ThermalRunawayMonitor;
+use ryzenadj::RyzenadjExecutor;
+
+/// Initialize fan controller from the resolved configuration (CLI flags
+/// merged with an optional `--config` file; see `config::resolve_config`)
+#[cfg(unix)]
+fn init_fan_controller(resolved: &ResolvedConfig, verbose: bool) -> Option<FanController> {
+    if !resolved.fan_control {
+        return None;
+    }
+
+    if verbose {
+        eprintln!("Initializing fan controller...");
+        eprintln!("  Mode: {}", resolved.fan_mode);
+        eprintln!("  Zero RPM: {}", resolved.fan_zero_rpm);
+        eprintln!("  Hysteresis: {}°C", resolved.fan_hysteresis);
+        eprintln!("  Down-hysteresis: {}°C", resolved.fan_down_hysteresis);
+        eprintln!("  Slowdown step max: {}", resolved.fan_slowdown_step_max);
+        eprintln!("  Curve points: {}", resolved.fan_curve.len());
+        if let Some(coeffs) = resolved.fan_coeffs {
+            eprintln!("  Coeffs: a={} b={} c={}", coeffs.a, coeffs.b, coeffs.c);
+        }
+    }
+
+    // Try to create fan controller
+    let mut controller = match FanController::new() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Warning: Failed to initialize fan controller: {}", e);
+            eprintln!("Fan control will be disabled.");
+            return None;
+        }
+    };
+
+    // Set up configuration
+    let mut config = FanControllerConfig::default();
+    config.hysteresis_temp = resolved.fan_hysteresis;
+    config.down_hysteresis_temp = resolved.fan_down_hysteresis;
+    config.fan_slowdown_step_max = resolved.fan_slowdown_step_max;
+
+    // Configure safety limits with zero RPM setting
+    config.safety_limits = FanSafetyLimits {
+        allow_zero_rpm: resolved.fan_zero_rpm,
+        ..Default::default()
+    };
+
+    controller.set_config(config);
+
+    // Set up fan curve based on mode
+    match resolved.fan_mode {
+        FanControlMode::Default => {
+            // Use default curve, don't enable manual control
+            if verbose {
+                eprintln!("Fan mode: default (BIOS control)");
+            }
+            return Some(controller);
+        }
+        FanControlMode::Custom => {
+            // Build curve from the resolved configuration
+            if resolved.fan_curve.len() >= 2 {
+                let points: Vec<FanCurvePoint> = resolved.fan_curve
+                    .iter()
+                    .map(|p| FanCurvePoint::new(p.temp_c, p.speed_percent))
+                    .collect();
+
+                match FanCurve::new(points) {
+                    Ok(curve) => {
+                        if verbose {
+                            eprintln!("Fan curve set with {} points", curve.len());
+                        }
+                        controller.set_curve(curve);
+                    }
+                    Err(e) => {
+                        eprintln!("Warning: Invalid fan curve: {}", e);
+                        eprintln!("Using default curve.");
+                    }
+                }
+            } else if verbose {
+                eprintln!("Warning: Custom mode requires at least 2 curve points, using default");
+            }
+        }
+        FanControlMode::Fixed => {
+            // Fixed mode: use first curve point as fixed speed
+            if let Some(point) = resolved.fan_curve.first() {
+                // Create a flat curve at the fixed speed
+                let points = vec![
+                    FanCurvePoint::new(0, point.speed_percent),
+                    FanCurvePoint::new(100, point.speed_percent),
+                ];
+                if let Ok(curve) = FanCurve::new(points) {
+                    controller.set_curve(curve);
+                    if verbose {
+                        eprintln!("Fan fixed at {}%", point.speed_percent);
+                    }
+                }
+            }
+        }
+        FanControlMode::Poly => {
+            // `config::FanCurveCoeffs` models `speed = a + b*t + c*t^2`;
+            // `fan::FanCurve::from_coefficients`/`from_normalized_coefficients`
+            // take the quadratic term first (`k_a*t^2 + k_b*t + k_c`), so the
+            // low/high order swaps.
+            if let Some(coeffs) = resolved.fan_coeffs {
+                let curve = if let Some((t_min, t_max)) = resolved.fan_coeffs_range {
+                    FanCurve::from_normalized_coefficients(
+                        coeffs.c, coeffs.b, coeffs.a, t_min, t_max,
+                    )
+                } else {
+                    FanCurve::from_coefficients(coeffs.c, coeffs.b, coeffs.a)
+                };
+                match curve {
+                    Ok(curve) => {
+                        if verbose {
+                            eprintln!(
+                                "Fan curve set from coefficients a={} b={} c={}",
+                                coeffs.a, coeffs.b, coeffs.c
+                            );
+                        }
+                        controller.set_curve(curve);
+                    }
+                    Err(e) => {
+                        eprintln!("Warning: Invalid fan coefficients: {}", e);
+                        eprintln!("Using default curve.");
+                    }
+                }
+            } else if verbose {
+                eprintln!("Warning: Poly mode requires --fan-coeffs, using default");
+            }
+        }
+        FanControlMode::Pid => {
+            if let Some(pid_config) = resolved.fan_pid_config {
+                let (pwm_min, pwm_max) = controller.pwm_range();
+                let pid = PidFanController::new(
+                    pid_config.setpoint_c,
+                    pid_config.kp,
+                    pid_config.ki,
+                    pid_config.kd,
+                    pwm_min,
+                    pwm_max,
+                );
+                controller.set_pid(pid);
+                if verbose {
+                    eprintln!(
+                        "Fan PID: setpoint={}°C kp={} ki={} kd={}",
+                        pid_config.setpoint_c, pid_config.kp, pid_config.ki, pid_config.kd
+                    );
+                }
+            } else if verbose {
+                eprintln!("Warning: Pid mode requires --fan-setpoint, using default curve");
+            }
+        }
+    }
+
+    // Enable manual control for custom/fixed modes
+    if resolved.fan_mode != FanControlMode::Default {
+        if let Err(e) = controller.enable() {
+            eprintln!("Warning: Failed to enable fan control: {}", e);
+            return None;
+        }
+        if verbose {
+            eprintln!("Fan manual control enabled");
+        }
+    }
 
+    Some(controller)
+}
+
+/// Get fan status for JSON output
+fn get_fan_status(controller: &FanController, mode: &FanControlMode) -> Option<FanStatusOutput> {
+    match controller.status() {
+        Ok(status) => Some(FanStatusOutput::new(
+            status.temp_c,
+            status.pwm,
+            status.speed_percent,
+            &mode.to_string(),
+            status.rpm,
+            status.safety_override_active,
+            &status.fan_health.to_string(),
+            status.effective_speed_percent,
+        )),
+        Err(_) => None,
+    }
+}
+
+/// Fold a `ControlState` snapshot from the control socket into `resolved`
+/// and `core_bounds`, the same way `DaemonEvent::ReloadConfig` folds in a
+/// freshly resolved `--config` file - without this, `set_strategy`/
+/// `set_hysteresis`/`set_core` over the socket only ever changed the
+/// `ControlState` a client reads back via `get_status`, never what the
+/// tick loop actually consults.
+///
+/// A core-count change is ignored (with a warning) since `core_bounds`,
+/// `num_cores`, and the `Interpolator` are all sized once at startup and
+/// don't support a live arity change; strategy/hysteresis still apply.
+///
+/// Returns `true` if anything changed.
+#[cfg(unix)]
+fn apply_control_snapshot(
+    resolved: &mut ResolvedConfig,
+    core_bounds: &mut Vec<CoreBounds>,
+    snapshot: &control::ControlState,
+) -> bool {
+    if snapshot.strategy == resolved.strategy
+        && snapshot.hysteresis == resolved.hysteresis
+        && snapshot.cores == resolved.cores
+    {
+        return false;
+    }
+
+    resolved.strategy = snapshot.strategy;
+    resolved.hysteresis = snapshot.hysteresis;
+
+    if snapshot.cores.len() != resolved.cores.len() {
+        eprintln!(
+            "Warning: control socket changed the number of cores ({} -> {}); ignoring core bounds update",
+            resolved.cores.len(),
+            snapshot.cores.len()
+        );
+        return true;
+    }
+
+    resolved.cores = snapshot.cores.clone();
+    *core_bounds = resolved.cores.iter().map(CoreBounds::from).collect();
+    true
+}
+
+/// Fold a `ControlState` snapshot's fan fields into the running
+/// `FanController`, mirroring `apply_control_snapshot` above for
+/// `set_fan_control`/`set_fan_curve`.
+///
+/// A pushed curve is only applied in `FanControlMode::Custom`, matching
+/// `init_fan_controller`'s own handling of `resolved.fan_curve` - in
+/// `Fixed`/`Poly`/`Pid` modes the live curve/PID is derived from other
+/// fields the control socket doesn't touch, so rebuilding it here would
+/// fight the mode the daemon actually started in.
+///
+/// Returns `true` if anything changed.
+#[cfg(unix)]
+fn apply_fan_snapshot(
+    resolved: &mut ResolvedConfig,
+    fan_controller: &mut Option<FanController>,
+    fan_mode: FanControlMode,
+    snapshot: &control::ControlState,
+    verbose: bool,
+) -> bool {
+    let mut changed = false;
+
+    if snapshot.fan_control != resolved.fan_control {
+        resolved.fan_control = snapshot.fan_control;
+        if let Some(fc) = fan_controller {
+            let result = if snapshot.fan_control {
+                fc.enable()
+            } else {
+                fc.disable()
+            };
+            if let Err(e) = result {
+                eprintln!(
+                    "Warning: failed to apply fan_control={} from control socket: {}",
+                    snapshot.fan_control, e
+                );
+            }
+        }
+        changed = true;
+    }
+
+    if snapshot.fan_curve != resolved.fan_curve {
+        resolved.fan_curve = snapshot.fan_curve.clone();
+        if fan_mode == FanControlMode::Custom && snapshot.fan_curve.len() >= 2 {
+            let points: Vec<FanCurvePoint> = snapshot
+                .fan_curve
+                .iter()
+                .map(|p| FanCurvePoint::new(p.temp_c, p.speed_percent))
+                .collect();
+            match FanCurve::new(points) {
+                Ok(curve) => {
+                    if let Some(fc) = fan_controller {
+                        fc.set_curve(curve);
+                    }
+                }
+                Err(e) => {
+                    if verbose {
+                        eprintln!("Warning: control-socket fan curve rejected: {}", e);
+                    }
+                }
+            }
+        }
+        changed = true;
+    }
+
+    changed
+}
+
+#[cfg(unix)]
 #[tokio::main]
 async fn main() {
     let args = Args::parse();
@@ -107,69 +457,719 @@ async fn main() {
         std::process::exit(1);
     }
 
-    // Check if running as root (required for ryzenadj)
+    let mut resolved = match resolve_config(&args) {
+        Ok(resolved) => resolved,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // Check if running as root (required for ryzenadj and fan control)
     if let Err(exit_code) = check_root_or_exit(args.verbose) {
         std::process::exit(exit_code);
     }
 
     // Install panic hook to reset values on panic
-    let num_cores = if args.cores.is_empty() { 4 } else { args.cores.len() };
-    install_panic_hook(num_cores, args.ryzenadj_path.display().to_string());
+    let num_cores = if resolved.cores.is_empty() { 4 } else { resolved.cores.len() };
+    // No live `HysteresisController` is threaded through this loop yet, so
+    // there's nothing to flush to a crash file; wire a shared handle here
+    // once the adaptation pipeline in the architecture diagram above is
+    // actually driving one.
+    install_panic_hook(num_cores, args.ryzenadj_path.display().to_string(), None);
+
+    // Resolved before `init_fan_controller`, which this device's
+    // `fan_pwm_range()` will feed once fan control is profile-aware;
+    // `resolve_config` already detected its own copy to default
+    // `resolved.cores` when `--core` was omitted.
+    let hardware_profile = hardware::detect_hardware_profile();
 
     if args.verbose {
         eprintln!("gymdeck3 starting with configuration:");
-        eprintln!("  Strategy: {}", args.strategy);
-        eprintln!("  Sample interval: {} us", args.sample_interval_us);
-        eprintln!("  Hysteresis: {}%", args.hysteresis);
+        eprintln!("  Strategy: {}", resolved.strategy);
+        eprintln!("  Sample interval: {} us", resolved.sample_interval_us);
+        eprintln!("  Hysteresis: {}%", resolved.hysteresis);
         eprintln!("  Ryzenadj path: {:?}", args.ryzenadj_path);
-        eprintln!("  Status interval: {} ms", args.status_interval_ms);
-        eprintln!("  Cores: {:?}", args.cores);
+        eprintln!("  Status interval: {} ms", resolved.status_interval_ms);
+        eprintln!("  Tick resolution: {} Hz", args.tick_hz);
+        eprintln!("  Watchdog timeout: {} ms", args.watchdog_timeout_ms);
+        eprintln!(
+            "  Deck model: {}",
+            model::detect_deck_model()
+                .map(|m| m.to_string())
+                .unwrap_or_else(|| "unknown (undervolt safety floor not enforced)".to_string())
+        );
+        eprintln!(
+            "  Hardware profile: {}",
+            hardware_profile
+                .as_ref()
+                .map(|p| p.name())
+                .unwrap_or("unknown (generic 4-core defaults)")
+        );
+        eprintln!("  Cores: {:?}", resolved.cores);
+        eprintln!(
+            "  Smoothing: {} (window={}, alpha={})",
+            resolved.smoothing.mode, resolved.smoothing.window, resolved.smoothing.alpha
+        );
+        eprintln!("  Fan control: {}", resolved.fan_control);
     }
 
     // Set up signal handling
-    let signal_state = SignalState::new();
-    let signal_handler = SignalHandler::new(signal_state.clone());
-    
+    let signal_handler = SignalHandler::new(SignalState::new());
+    let mut daemon_events = signal_handler.events();
+
     if let Err(e) = signal_handler.start().await {
         eprintln!("Warning: Failed to register signal handlers: {}", e);
     }
 
+    // Initialize fan controller if enabled
+    let mut fan_controller = init_fan_controller(&resolved, args.verbose);
+    let fan_mode = resolved.fan_mode;
+
     // Create output writer
-    let mut output_writer = OutputWriter::new(args.status_interval_ms);
+    let mut output_writer = OutputWriter::new(resolved.status_interval_ms).with_tick_hz(args.tick_hz);
+
+    // Shared broadcast channel feeding every connected client - control
+    // socket and status socket alike - the same status stream stdout gets;
+    // only allocated when at least one of those sockets is configured.
+    let status_tx = if args.control_socket.is_some() || args.status_socket.is_some() {
+        let (tx, _rx) = tokio::sync::broadcast::channel(64);
+        output_writer = output_writer.with_broadcast(tx.clone());
+        Some(tx)
+    } else {
+        None
+    };
+
+    // Start the control-socket plane (if requested) so a frontend can
+    // retune cores and stream status without restarting the daemon
+    let mut control_server = None;
+    // Kept alongside `control_server` so a SIGHUP reload can push the
+    // freshly resolved config into the shared state without needing a
+    // handle back out of the (consumed) `ControlServer`
+    let mut control_state_handle: Option<control::SharedControlState> = None;
+    if let Some(ref socket_path) = args.control_socket {
+        let control_state = control::SharedControlState::new(control::ControlState::new(
+            resolved.strategy,
+            resolved.hysteresis,
+            resolved.cores.clone(),
+            resolved.fan_control,
+            resolved.fan_curve.clone(),
+        ));
+
+        match control::ControlServer::bind(socket_path, control_state.clone(), status_tx.clone().unwrap()) {
+            Ok(server) => {
+                control_server = Some(server);
+                control_state_handle = Some(control_state);
+                if args.verbose {
+                    eprintln!("Control socket listening on {}", socket_path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!(
+                    "Warning: Failed to bind control socket {}: {}",
+                    socket_path.display(),
+                    e
+                );
+            }
+        }
+    }
+
+    // Start the status-socket plane (if requested): a binary,
+    // length-prefixed alternative to NDJSON stdout for long-lived UIs, fed
+    // by the same broadcast stream as the control socket. See
+    // `status_server` module.
+    let mut status_server = None;
+    if let Some(ref socket_path) = args.status_socket {
+        match status_server::StatusServer::bind(socket_path, status_tx.clone().unwrap()) {
+            Ok(server) => {
+                status_server = Some(server);
+                if args.verbose {
+                    eprintln!("Status socket listening on {}", socket_path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!(
+                    "Warning: Failed to bind status socket {}: {}",
+                    socket_path.display(),
+                    e
+                );
+            }
+        }
+    }
+
+    // Watchdog: bumped on every sampling tick below, so a stalled loop is
+    // caught independently of whether signals or control-socket traffic
+    // keep arriving
+    let watchdog = watchdog::Watchdog::new(
+        args.watchdog_timeout_ms,
+        num_cores,
+        args.ryzenadj_path.display().to_string(),
+    );
+    let watchdog_state = watchdog.state();
+    watchdog.start(args.verbose).await;
 
-    // Main loop placeholder - will be implemented in subsequent tasks
     if args.verbose {
         eprintln!("gymdeck3 initialized successfully, entering main loop...");
     }
 
-    // Simple main loop that checks for signals
+    // Tick counter for fan updates (update fan every 5 sample ticks)
+    let mut tick_count: u64 = 0;
+    let fan_update_interval = 5;
+
+    // Static per-core bounds from `--core`; re-derated every fan tick
+    // against the live die temperature so an aggressive baseline offset
+    // can't keep applying once the chip is running hot. See
+    // `safety::clamp_value_thermal`.
+    let mut core_bounds: Vec<CoreBounds> = resolved.cores.iter().map(CoreBounds::from).collect();
+
+    // Tracks each core's configured safe baseline so a control-socket
+    // retune (see the tick arm below) ramps into place over time instead
+    // of jumping straight to the new bounds, and so `set_report_mode` has
+    // something real to stream via `Interpolator::report`.
+    let mut interpolator = Interpolator::new(num_cores);
+    let initial_baseline: Vec<i32> = core_bounds.iter().map(|b| b.min_mv).collect();
+    interpolator.force_immediate(initial_baseline.clone());
+    interpolator.set_targets(initial_baseline);
+
+    // Borrows Marlin's thermal-runaway protection: watches whether the die
+    // temperature actually responds once the fan has been held at/near max
+    // for a full window, so a seized fan or a dead hwmon sensor doesn't go
+    // unnoticed while an aggressive undervolt is still applied.
+    let mut thermal_runaway_monitor = ThermalRunawayMonitor::new();
+    let loop_start = std::time::Instant::now();
+
+    let mut sample_interval =
+        tokio::time::interval(Duration::from_micros(resolved.sample_interval_us));
+    sample_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    // Event-multiplexed main loop: rather than a fixed busy-poll sleep,
+    // `select!` dispatches whichever of these fires first - sampling,
+    // a signal, or a new control-socket client - so SIGUSR1/shutdown are
+    // handled the instant they arrive and the sample interval is honored
+    // precisely instead of being quantized to a poll period. Mirrors
+    // crosvm's `wait_context`, which waits on many event sources at once
+    // and dispatches whichever is ready.
     loop {
-        // Check for shutdown signal
-        if signal_state.is_shutdown_requested() {
-            if args.verbose {
-                eprintln!("Shutdown requested, cleaning up...");
+        tokio::select! {
+            _ = sample_interval.tick() => {
+                tick_count += 1;
+                watchdog_state.heartbeat();
+
+                // Pull in any control-socket changes before the rest of this
+                // tick, mirroring how `DaemonEvent::ReloadConfig` folds a
+                // `--config` reload into `resolved` below.
+                let control_snapshot = control_state_handle.as_ref().map(|cs| cs.snapshot());
+                if let Some(ref snapshot) = control_snapshot {
+                    if apply_control_snapshot(&mut resolved, &mut core_bounds, snapshot) {
+                        let baseline: Vec<i32> = core_bounds.iter().map(|b| b.min_mv).collect();
+                        interpolator.set_targets(baseline);
+                        if args.verbose {
+                            eprintln!("Control socket updated strategy/hysteresis/cores.");
+                        }
+                    }
+                    if apply_fan_snapshot(&mut resolved, &mut fan_controller, fan_mode, snapshot, args.verbose)
+                        && args.verbose
+                    {
+                        eprintln!("Control socket updated fan control/curve.");
+                    }
+                }
+
+                interpolator.tick();
+
+                if control_snapshot.as_ref().is_some_and(|s| s.report_mode) {
+                    let fan_status = fan_controller.as_ref().and_then(|fc| get_fan_status(fc, &fan_mode));
+                    let report = interpolator.report();
+                    if let Err(e) = output_writer.write_report(report.current, report.target, fan_status) {
+                        eprintln!("Error writing report: {}", e);
+                    }
+                }
+
+                // Update fan controller (every 5 sample ticks)
+                if tick_count % fan_update_interval == 0 {
+                    if let Some(ref mut fc) = fan_controller {
+                        if fc.is_active() {
+                            match fc.update() {
+                                Ok(commanded_pwm) => {
+                                    let fan_status = fc.status().ok();
+                                    let rpm = fan_status.as_ref().and_then(|s| s.rpm);
+
+                                    // Re-clamp every core's aggressive bound against the
+                                    // die temperature read off this same fan tick, so a
+                                    // baseline offset stable at idle gets pulled back
+                                    // toward the safe floor once it's running hot.
+                                    if let Some(ref status) = fan_status {
+                                        for (i, bounds) in core_bounds.iter().enumerate() {
+                                            let derated = clamp_value_thermal(
+                                                bounds.max_mv,
+                                                bounds,
+                                                status.temp_c as f32,
+                                                resolved.derate_start,
+                                                resolved.derate_end,
+                                            );
+                                            if args.verbose && derated != bounds.max_mv {
+                                                eprintln!(
+                                                    "Thermal derate: core {} max undervolt now {}mV (was {}mV) at {}\u{b0}C",
+                                                    i, derated, bounds.max_mv, status.temp_c,
+                                                );
+                                            }
+                                        }
+                                    }
+
+                                    if let Some(ref status) = fan_status {
+                                        thermal_runaway_monitor.record(
+                                            loop_start.elapsed().as_millis() as u64,
+                                            status.temp_c as f32,
+                                            commanded_pwm,
+                                        );
+
+                                        let runaway_status = thermal_runaway_monitor.check();
+                                        match runaway_status {
+                                            watchdog::ThermalRunawayStatus::Runaway
+                                            | watchdog::ThermalRunawayStatus::SensorFault => {
+                                                let (code, message) = if runaway_status
+                                                    == watchdog::ThermalRunawayStatus::SensorFault
+                                                {
+                                                    (
+                                                        "thermal_sensor_fault",
+                                                        "Temperature sensor reading is constant or implausible while fan is at max",
+                                                    )
+                                                } else {
+                                                    (
+                                                        "thermal_runaway",
+                                                        "Thermal runaway detected: temperature did not drop while fan commanded at max",
+                                                    )
+                                                };
+                                                eprintln!("FATAL: {}", message);
+
+                                                let fault = output::ErrorOutput::new(code, message);
+                                                if let Ok(json) = fault.to_json() {
+                                                    println!("{}", json);
+                                                }
+
+                                                // Force full speed, then revert every core to
+                                                // its safest (least-negative) bound rather than
+                                                // leaving an aggressive undervolt applied while
+                                                // cooling can't be confirmed.
+                                                let _ = fc.force_pwm(MAX_PWM);
+                                                if let Err(e) = fc.disable() {
+                                                    eprintln!("Warning: Failed to return fan control to BIOS: {}", e);
+                                                }
+
+                                                let safe_values: Vec<i32> =
+                                                    core_bounds.iter().map(|b| b.min_mv).collect();
+                                                let mut executor = RyzenadjExecutor::new(
+                                                    &args.ryzenadj_path.display().to_string(),
+                                                );
+                                                if let Err(e) = executor
+                                                    .apply_bounded(&safe_values, Duration::from_millis(2_000))
+                                                    .await
+                                                {
+                                                    eprintln!(
+                                                        "Warning: Failed to revert undervolt to safe bounds: {}",
+                                                        e
+                                                    );
+                                                }
+
+                                                std::process::exit(EXIT_CODE_THERMAL_RUNAWAY);
+                                            }
+                                            watchdog::ThermalRunawayStatus::Ok => {}
+                                        }
+                                    }
+
+                                    match fc.fan_health_status() {
+                                        FanHealthStatus::Stalled => {
+                                            eprintln!(
+                                                "FATAL: fan stall detected (commanded PWM {} did not produce expected tach RPM for {} consecutive ticks)",
+                                                commanded_pwm,
+                                                fc.fan_health_consecutive_ticks(),
+                                            );
+
+                                            let fault = output::ErrorOutput::new(
+                                                "fan_stall",
+                                                "Fan stall detected: commanded PWM did not produce tach RPM",
+                                            );
+                                            if let Ok(json) = fault.to_json() {
+                                                println!("{}", json);
+                                            }
+
+                                            // Force full speed in case the seizure is
+                                            // transient, then hand control back to the
+                                            // BIOS rather than leaving a stalled fan
+                                            // under our (ineffective) manual PWM.
+                                            let _ = fc.force_pwm(MAX_PWM);
+                                            if let Err(e) = fc.disable() {
+                                                eprintln!("Warning: Failed to return fan control to BIOS: {}", e);
+                                            }
+
+                                            graceful_shutdown(
+                                                num_cores,
+                                                &args.ryzenadj_path.display().to_string(),
+                                                args.verbose,
+                                                None,
+                                            ).await;
+                                            std::process::exit(EXIT_CODE_FAN_STALL);
+                                        }
+                                        FanHealthStatus::LowSignal if args.verbose => {
+                                            eprintln!(
+                                                "Warning: fan RPM ({:?}) looks low for commanded PWM {}",
+                                                rpm, commanded_pwm,
+                                            );
+                                        }
+                                        _ => {}
+                                    }
+                                }
+                                Err(e) => {
+                                    if args.verbose {
+                                        eprintln!("Fan update error: {}", e);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            Some(event) = daemon_events.recv() => match event {
+                DaemonEvent::Shutdown => {
+                    if args.verbose {
+                        eprintln!("Shutdown requested, cleaning up...");
+                    }
+
+                    // Disable fan control (returns to BIOS)
+                    if let Some(ref mut fc) = fan_controller {
+                        if let Err(e) = fc.disable() {
+                            eprintln!("Warning: Failed to disable fan control: {}", e);
+                        }
+                        if args.verbose {
+                            eprintln!("Fan control returned to BIOS");
+                        }
+                    }
+
+                    let exit_code = graceful_shutdown(
+                        num_cores,
+                        &args.ryzenadj_path.display().to_string(),
+                        args.verbose,
+                        None,
+                    ).await;
+                    std::process::exit(exit_code);
+                }
+
+                DaemonEvent::ForceStatus => {
+                    // Force output status regardless of interval
+                    let load = vec![0.0; num_cores];
+                    let values = vec![0; num_cores];
+
+                    // Get fan status if available
+                    let fan_status = fan_controller.as_ref()
+                        .and_then(|fc| get_fan_status(fc, &fan_mode));
+
+                    if let Some(fan) = fan_status {
+                        let status = output::StatusOutput::with_fan(
+                            load, values, resolved.strategy, output_writer.uptime_ms(), fan
+                        ).with_ticks(output_writer.uptime_ticks(), output_writer.next_seq());
+                        if let Err(e) = status.to_json().map(|j| println!("{}", j)) {
+                            eprintln!("Error writing status: {}", e);
+                        }
+                    } else if let Err(e) = output_writer.write_status(load, values, resolved.strategy) {
+                        eprintln!("Error writing status: {}", e);
+                    }
+                }
+
+                DaemonEvent::ReloadConfig => {
+                    match resolve_config(&args) {
+                        Ok(new_resolved) => match hysteresis::validate_hysteresis_margin(new_resolved.hysteresis) {
+                            Ok(_) => {
+                                if let Some(ref control_state) = control_state_handle {
+                                    control_state.replace(control::ControlState::new(
+                                        new_resolved.strategy,
+                                        new_resolved.hysteresis,
+                                        new_resolved.cores.clone(),
+                                        new_resolved.fan_control,
+                                        new_resolved.fan_curve.clone(),
+                                    ));
+                                }
+                                // Deliberately does not touch any
+                                // `HysteresisController`/`PidController` -
+                                // only the shared config snapshot changes,
+                                // so per-core `last_stable_load`/
+                                // `last_output` runtime state survives the
+                                // reload instead of jumping back to a
+                                // zero baseline.
+                                resolved = new_resolved;
+                                if args.verbose {
+                                    eprintln!("Configuration reloaded.");
+                                }
+                            }
+                            Err(e) => {
+                                eprintln!("Warning: Reload rejected, invalid hysteresis margin: {}", e);
+                            }
+                        },
+                        Err(e) => {
+                            eprintln!("Warning: Reload failed, config could not be resolved: {}", e);
+                        }
+                    }
+                }
+
+                // Not yet wired to daemon behavior; reserved for
+                // pause/resume control-socket commands.
+                DaemonEvent::Pause | DaemonEvent::Resume => {}
+            },
+
+            accepted = accept_control_connection(control_server.as_ref()) => {
+                match accepted {
+                    Ok(stream) => {
+                        if let Some(server) = control_server.as_ref() {
+                            server.spawn_client(stream);
+                        }
+                    }
+                    Err(e) => eprintln!("control socket accept error: {}", e),
+                }
             }
-            let exit_code = graceful_shutdown(
-                num_cores,
-                &args.ryzenadj_path.display().to_string(),
-                args.verbose,
-            ).await;
-            std::process::exit(exit_code);
-        }
-
-        // Check for force status signal (SIGUSR1)
-        if signal_state.take_force_status() {
-            // Force output status regardless of interval
-            // Using placeholder values until main loop is fully implemented
-            let load = vec![0.0; num_cores];
-            let values = vec![0; num_cores];
-            if let Err(e) = output_writer.write_status(load, values, args.strategy) {
-                eprintln!("Error writing status: {}", e);
+
+            accepted = accept_status_connection(status_server.as_ref()) => {
+                match accepted {
+                    Ok(stream) => {
+                        if let Some(server) = status_server.as_ref() {
+                            server.spawn_client(stream);
+                        }
+                    }
+                    Err(e) => eprintln!("status socket accept error: {}", e),
+                }
             }
         }
+    }
+}
+
+/// Accept the next control-socket connection, or never resolve if no
+/// control socket is configured
+///
+/// Folding this into the main `select!` lets an absent control socket
+/// simply contribute a branch that never fires, instead of branching the
+/// loop structure itself on `args.control_socket`.
+#[cfg(unix)]
+async fn accept_control_connection(
+    server: Option<&control::ControlServer>,
+) -> std::io::Result<tokio::net::UnixStream> {
+    match server {
+        Some(server) => server.accept_connection().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Accept the next status-socket connection, or never resolve if no
+/// status socket is configured; same rationale as `accept_control_connection`
+#[cfg(unix)]
+async fn accept_status_connection(
+    server: Option<&status_server::StatusServer>,
+) -> std::io::Result<tokio::net::UnixStream> {
+    match server {
+        Some(server) => server.accept_connection().await,
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use config::{CoreConfig, Strategy};
+    use control::{ControlState, SharedControlState};
+    use std::path::PathBuf;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::UnixStream;
+
+    fn base_args() -> Args {
+        Args {
+            strategy: Some(Strategy::Balanced),
+            sample_interval_us: Some(100_000),
+            cores: vec![],
+            hysteresis: None,
+            ryzenadj_path: PathBuf::from("ryzenadj"),
+            status_interval_ms: None,
+            verbose: false,
+            fan_control: false,
+            fan_mode: FanControlMode::Default,
+            fan_curve: vec![],
+            fan_coeffs: None,
+            fan_coeffs_range: None,
+            fan_zero_rpm: false,
+            fan_hysteresis: 2,
+            fan_down_hysteresis: 4,
+            fan_slowdown_step_max: 0,
+            fan_setpoint: None,
+            fan_pid: None,
+            pid_target: None,
+            pid_kp: None,
+            pid_ki: None,
+            pid_kd: None,
+            pid_output_clamp: None,
+            derate_start: config::DEFAULT_DERATE_START_C,
+            derate_end: config::DEFAULT_DERATE_END_C,
+            control_socket: None,
+            status_socket: None,
+            tick_hz: 1000,
+            watchdog_timeout_ms: 10_000,
+            config: None,
+            force_unsafe_undervolt: false,
+            smoothing: config::SmoothingMode::None,
+            smoothing_window: None,
+            smoothing_alpha: None,
+        }
+    }
 
-        // Sleep briefly to avoid busy-waiting
-        // This will be replaced with actual sampling logic in subsequent tasks
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+    fn base_resolved() -> ResolvedConfig {
+        let mut args = base_args();
+        args.cores = vec![
+            CoreConfig { core_id: 0, min_mv: -20, max_mv: -35, threshold: 50.0 },
+            CoreConfig { core_id: 1, min_mv: -20, max_mv: -35, threshold: 50.0 },
+        ];
+        resolve_config(&args).unwrap()
     }
+
+    #[test]
+    fn test_apply_control_snapshot_no_change_returns_false() {
+        let mut resolved = base_resolved();
+        let mut core_bounds: Vec<CoreBounds> = resolved.cores.iter().map(CoreBounds::from).collect();
+        let snapshot = ControlState::new(
+            resolved.strategy,
+            resolved.hysteresis,
+            resolved.cores.clone(),
+            resolved.fan_control,
+            resolved.fan_curve.clone(),
+        );
+
+        assert!(!apply_control_snapshot(&mut resolved, &mut core_bounds, &snapshot));
+    }
+
+    #[test]
+    fn test_apply_control_snapshot_updates_resolved_and_core_bounds() {
+        let mut resolved = base_resolved();
+        let mut core_bounds: Vec<CoreBounds> = resolved.cores.iter().map(CoreBounds::from).collect();
+
+        let mut new_cores = resolved.cores.clone();
+        new_cores[0].max_mv = -40;
+        let snapshot = ControlState::new(
+            Strategy::Aggressive,
+            8.0,
+            new_cores.clone(),
+            resolved.fan_control,
+            resolved.fan_curve.clone(),
+        );
+
+        assert!(apply_control_snapshot(&mut resolved, &mut core_bounds, &snapshot));
+        assert_eq!(resolved.strategy, Strategy::Aggressive);
+        assert_eq!(resolved.hysteresis, 8.0);
+        assert_eq!(resolved.cores, new_cores);
+        assert_eq!(core_bounds[0].max_mv, -40);
+    }
+
+    #[test]
+    fn test_apply_control_snapshot_ignores_core_count_change() {
+        let mut resolved = base_resolved();
+        let mut core_bounds: Vec<CoreBounds> = resolved.cores.iter().map(CoreBounds::from).collect();
+        let original_bounds = core_bounds.clone();
+
+        let mut new_cores = resolved.cores.clone();
+        new_cores.push(CoreConfig { core_id: 2, min_mv: -20, max_mv: -35, threshold: 50.0 });
+        let snapshot = ControlState::new(
+            Strategy::Aggressive,
+            resolved.hysteresis,
+            new_cores,
+            resolved.fan_control,
+            resolved.fan_curve.clone(),
+        );
+
+        assert!(apply_control_snapshot(&mut resolved, &mut core_bounds, &snapshot));
+        assert_eq!(resolved.strategy, Strategy::Aggressive);
+        assert_eq!(resolved.cores.len(), 2);
+        assert_eq!(core_bounds, original_bounds);
+    }
+
+    #[test]
+    fn test_apply_fan_snapshot_tracks_fan_control_toggle() {
+        let mut resolved = base_resolved();
+        let mut fan_controller: Option<FanController> = None;
+        let snapshot = ControlState::new(
+            resolved.strategy,
+            resolved.hysteresis,
+            resolved.cores.clone(),
+            true,
+            resolved.fan_curve.clone(),
+        );
+
+        assert!(apply_fan_snapshot(&mut resolved, &mut fan_controller, FanControlMode::Custom, &snapshot, false));
+        assert!(resolved.fan_control);
+    }
+
+    #[test]
+    fn test_apply_fan_snapshot_tracks_curve_even_when_too_short_to_apply() {
+        let mut resolved = base_resolved();
+        let mut fan_controller: Option<FanController> = None;
+        let snapshot = ControlState::new(
+            resolved.strategy,
+            resolved.hysteresis,
+            resolved.cores.clone(),
+            resolved.fan_control,
+            vec![config::FanCurvePointConfig { temp_c: 50, speed_percent: 60 }],
+        );
+
+        assert!(apply_fan_snapshot(&mut resolved, &mut fan_controller, FanControlMode::Custom, &snapshot, false));
+        assert_eq!(resolved.fan_curve.len(), 1);
+    }
+
+    /// Sends real commands over an actual control-socket connection and
+    /// confirms they flow all the way into the values the tick loop
+    /// consults, not just into the `ControlState` a client reads back via
+    /// `get_status` - the gap this fix closes.
+    #[tokio::test]
+    async fn test_control_socket_command_changes_applied_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let socket_path = dir.path().join("control.sock");
+
+        let mut resolved = base_resolved();
+        let mut core_bounds: Vec<CoreBounds> = resolved.cores.iter().map(CoreBounds::from).collect();
+
+        let control_state = SharedControlState::new(ControlState::new(
+            resolved.strategy,
+            resolved.hysteresis,
+            resolved.cores.clone(),
+            resolved.fan_control,
+            resolved.fan_curve.clone(),
+        ));
+        let (status_tx, _rx) = tokio::sync::broadcast::channel(4);
+        let server = control::ControlServer::bind(&socket_path, control_state.clone(), status_tx).unwrap();
+
+        let connect_path = socket_path.clone();
+        let client = tokio::spawn(UnixStream::connect(connect_path));
+        let stream = server.accept_connection().await.unwrap();
+        server.spawn_client(stream);
+        let client = client.await.unwrap().unwrap();
+        let (read_half, mut write_half) = client.into_split();
+        let mut lines = BufReader::new(read_half).lines();
+
+        write_half
+            .write_all(b"{\"jsonrpc\":\"2.0\",\"method\":\"set_strategy\",\"params\":[\"aggressive\"],\"id\":1}\n")
+            .await
+            .unwrap();
+        let reply = lines.next_line().await.unwrap().unwrap();
+        assert!(reply.contains("aggressive"));
+
+        write_half
+            .write_all(b"{\"jsonrpc\":\"2.0\",\"method\":\"set_hysteresis\",\"params\":[9.5],\"id\":2}\n")
+            .await
+            .unwrap();
+        lines.next_line().await.unwrap().unwrap();
+
+        let snapshot = control_state.snapshot();
+        assert!(apply_control_snapshot(&mut resolved, &mut core_bounds, &snapshot));
+        assert_eq!(resolved.strategy, Strategy::Aggressive);
+        assert_eq!(resolved.hysteresis, 9.5);
+    }
+}
+
+// Non-Unix stub (Windows compilation check)
+#[cfg(not(unix))]
+fn main() {
+    eprintln!("gymdeck3 requires Unix (Linux/SteamOS) to run.");
+    eprintln!("This binary is for Steam Deck only.");
+    std::process::exit(1);
 }
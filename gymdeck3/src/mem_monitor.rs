@@ -0,0 +1,192 @@
+//! Memory pressure monitoring from /proc/meminfo
+//!
+//! DeckTune tunes for thermal/power headroom, so memory pressure is another
+//! signal worth surfacing alongside CPU load. Parses the `Key: value kB`
+//! lines of `/proc/meminfo`, reusing the same `with_path` testability hook
+//! and error taxonomy style as `LoadMonitor`.
+
+use std::fs;
+use std::io;
+
+/// Snapshot of memory statistics, in kibibytes
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemStats {
+    pub total_kb: u64,
+    pub available_kb: u64,
+    pub swap_total_kb: u64,
+    pub swap_free_kb: u64,
+}
+
+impl MemStats {
+    /// Percentage of total memory currently in use (0.0 - 100.0)
+    pub fn used_pct(&self) -> f32 {
+        if self.total_kb == 0 {
+            return 0.0;
+        }
+        let used = self.total_kb.saturating_sub(self.available_kb);
+        ((used as f64 / self.total_kb as f64) * 100.0).clamp(0.0, 100.0) as f32
+    }
+}
+
+/// Error types for MemMonitor operations
+#[derive(Debug)]
+pub enum MemMonitorError {
+    IoError(io::Error),
+    ParseError(String),
+}
+
+impl std::fmt::Display for MemMonitorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemMonitorError::IoError(e) => write!(f, "I/O error: {}", e),
+            MemMonitorError::ParseError(s) => write!(f, "Parse error: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for MemMonitorError {}
+
+impl From<io::Error> for MemMonitorError {
+    fn from(e: io::Error) -> Self {
+        MemMonitorError::IoError(e)
+    }
+}
+
+/// Memory monitor that reads from /proc/meminfo
+pub struct MemMonitor {
+    proc_meminfo_path: String,
+}
+
+impl MemMonitor {
+    /// Create a new MemMonitor reading the default /proc/meminfo path
+    pub fn new() -> Self {
+        Self::with_path("/proc/meminfo".to_string())
+    }
+
+    /// Create a new MemMonitor with a custom /proc/meminfo path (for testing)
+    pub fn with_path(proc_meminfo_path: String) -> Self {
+        Self { proc_meminfo_path }
+    }
+
+    /// Read and parse current memory statistics from /proc/meminfo
+    pub fn sample(&self) -> Result<MemStats, MemMonitorError> {
+        let content = fs::read_to_string(&self.proc_meminfo_path)?;
+        Self::parse_meminfo(&content)
+    }
+
+    /// Parse /proc/meminfo content into MemStats
+    ///
+    /// Each line is `Key: value kB` (sometimes without the unit); we split
+    /// on `:`, trim, and take the first whitespace-separated numeric token.
+    /// `MemAvailable` was only added in Linux 3.14, so on older kernels we
+    /// fall back to `MemFree` as the closest approximation.
+    pub fn parse_meminfo(content: &str) -> Result<MemStats, MemMonitorError> {
+        let mut total_kb = None;
+        let mut available_kb = None;
+        let mut free_kb = None;
+        let mut swap_total_kb = None;
+        let mut swap_free_kb = None;
+
+        for line in content.lines() {
+            let Some((key, rest)) = line.split_once(':') else {
+                continue;
+            };
+            let Some(value_token) = rest.trim().split_whitespace().next() else {
+                continue;
+            };
+            let Ok(value) = value_token.parse::<u64>() else {
+                continue;
+            };
+
+            match key.trim() {
+                "MemTotal" => total_kb = Some(value),
+                "MemAvailable" => available_kb = Some(value),
+                "MemFree" => free_kb = Some(value),
+                "SwapTotal" => swap_total_kb = Some(value),
+                "SwapFree" => swap_free_kb = Some(value),
+                _ => {}
+            }
+        }
+
+        let total_kb = total_kb
+            .ok_or_else(|| MemMonitorError::ParseError("Missing MemTotal field".to_string()))?;
+
+        Ok(MemStats {
+            total_kb,
+            available_kb: available_kb.or(free_kb).unwrap_or(0),
+            swap_total_kb: swap_total_kb.unwrap_or(0),
+            swap_free_kb: swap_free_kb.unwrap_or(0),
+        })
+    }
+}
+
+impl Default for MemMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_MEMINFO: &str = r#"MemTotal:       16384000 kB
+MemFree:         2048000 kB
+MemAvailable:    8192000 kB
+Buffers:          512000 kB
+Cached:          4096000 kB
+SwapTotal:       4096000 kB
+SwapFree:        4096000 kB
+"#;
+
+    #[test]
+    fn test_parse_meminfo() {
+        let stats = MemMonitor::parse_meminfo(SAMPLE_MEMINFO).unwrap();
+
+        assert_eq!(stats.total_kb, 16384000);
+        assert_eq!(stats.available_kb, 8192000);
+        assert_eq!(stats.swap_total_kb, 4096000);
+        assert_eq!(stats.swap_free_kb, 4096000);
+    }
+
+    #[test]
+    fn test_used_pct() {
+        let stats = MemStats {
+            total_kb: 1000,
+            available_kb: 250,
+            swap_total_kb: 0,
+            swap_free_kb: 0,
+        };
+
+        assert!((stats.used_pct() - 75.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_used_pct_zero_total_is_zero() {
+        let stats = MemStats::default();
+        assert_eq!(stats.used_pct(), 0.0);
+    }
+
+    #[test]
+    fn test_missing_mem_available_falls_back_to_mem_free() {
+        // No MemAvailable line, as on pre-3.14 kernels.
+        let content = "MemTotal:       16384000 kB\nMemFree:         2048000 kB\n";
+        let stats = MemMonitor::parse_meminfo(content).unwrap();
+        assert_eq!(stats.available_kb, 2048000);
+    }
+
+    #[test]
+    fn test_missing_swap_fields_default_to_zero() {
+        let content = "MemTotal:       16384000 kB\nMemAvailable:    8192000 kB\n";
+        let stats = MemMonitor::parse_meminfo(content).unwrap();
+        assert_eq!(stats.swap_total_kb, 0);
+        assert_eq!(stats.swap_free_kb, 0);
+    }
+
+    #[test]
+    fn test_missing_mem_total_is_error() {
+        let content = "MemFree:         2048000 kB\n";
+        let result = MemMonitor::parse_meminfo(content);
+        assert!(result.is_err());
+    }
+}
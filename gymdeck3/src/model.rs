@@ -0,0 +1,215 @@
+//! Steam Deck hardware-model detection and per-model undervolt safety clamps
+//!
+//! Different Deck APUs tolerate different undervolt ranges: pushing the
+//! OLED's Sephiroth APU as aggressively as the LCD's Van Gogh APU (or vice
+//! versa) risks leaving the hardware wedged in an unrecoverable state — the
+//! same risk that motivated clamping TEC settings to design-spec ranges
+//! elsewhere in this codebase. `DeckModel` detection gives `--core` the
+//! same kind of floor, and supplies a safe default when the user configures
+//! no cores at all.
+
+use std::fs;
+use std::path::Path;
+
+use crate::config::CoreConfig;
+
+/// DMI sysfs path reporting the board name ("Jupiter" or "Galileo" on a Deck)
+pub const DMI_BOARD_NAME_PATH: &str = "/sys/class/dmi/id/board_name";
+
+/// Detected Steam Deck hardware revision
+///
+/// Board names match the `jupiter`/`galileo` naming the EC/hwmon driver
+/// already uses (see `fan::find_steam_deck_hwmon`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeckModel {
+    /// LCD model, Van Gogh APU (board_name "Jupiter")
+    Lcd,
+    /// OLED model, Sephiroth APU (board_name "Galileo")
+    Oled,
+}
+
+impl DeckModel {
+    /// Match a DMI `board_name` string (as read verbatim from sysfs,
+    /// case-insensitive) to a model.
+    pub fn from_board_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "jupiter" => Some(DeckModel::Lcd),
+            "galileo" => Some(DeckModel::Oled),
+            _ => None,
+        }
+    }
+
+    /// Most aggressive (most negative) `max_mv` considered stable for this
+    /// APU without `--force-unsafe-undervolt`.
+    pub fn safe_max_mv_floor(&self) -> i32 {
+        match self {
+            DeckModel::Lcd => -30,
+            DeckModel::Oled => -20,
+        }
+    }
+
+    /// APU codename, used as the lookup key for on-disk per-device voltage
+    /// limits files (distinct from `Display`, which names the Deck model)
+    pub fn apu_name(&self) -> &'static str {
+        match self {
+            DeckModel::Lcd => "Van Gogh",
+            DeckModel::Oled => "Sephiroth",
+        }
+    }
+
+    /// Sensible default per-core configuration for this model, used when
+    /// the user supplies no `--core` flags (and none come from `--config`).
+    pub fn default_cores(&self) -> Vec<CoreConfig> {
+        let (min_mv, max_mv) = match self {
+            DeckModel::Lcd => (-10, -30),
+            DeckModel::Oled => (-5, -20),
+        };
+        (0..4)
+            .map(|core_id| CoreConfig {
+                core_id,
+                min_mv,
+                max_mv,
+                threshold: 50.0,
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for DeckModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeckModel::Lcd => write!(f, "LCD (Van Gogh)"),
+            DeckModel::Oled => write!(f, "OLED (Sephiroth)"),
+        }
+    }
+}
+
+/// Detect the Steam Deck hardware model via DMI `board_name`
+///
+/// Returns `None` if the board name can't be read (not running on a Deck,
+/// sysfs unavailable, permissions) or doesn't match a known model — in
+/// which case callers skip the model-specific checks entirely rather than
+/// guessing.
+pub fn detect_deck_model() -> Option<DeckModel> {
+    detect_deck_model_at(DMI_BOARD_NAME_PATH)
+}
+
+/// Detect using an explicit `board_name` path (for testing)
+pub fn detect_deck_model_at<P: AsRef<Path>>(path: P) -> Option<DeckModel> {
+    let name = fs::read_to_string(path).ok()?;
+    DeckModel::from_board_name(&name)
+}
+
+/// Validate a core's `max_mv` against the detected model's safety floor
+///
+/// `model` is `None` when detection fails, in which case the check is
+/// skipped since there's no known limit to enforce. `force_unsafe` bypasses
+/// the check entirely, mirroring `--force-unsafe-undervolt`.
+pub fn validate_core_config_for_model(
+    core: &CoreConfig,
+    model: Option<DeckModel>,
+    force_unsafe: bool,
+) -> Result<(), String> {
+    if force_unsafe {
+        return Ok(());
+    }
+    let Some(model) = model else { return Ok(()) };
+    let floor = model.safe_max_mv_floor();
+    if core.max_mv < floor {
+        return Err(format!(
+            "core {} max_mv {} is more aggressive than the known-stable limit ({}) for {} \
+             (pass --force-unsafe-undervolt to override)",
+            core.core_id, core.max_mv, floor, model
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_board_name(dir: &tempfile::TempDir, contents: &str) -> std::path::PathBuf {
+        let path = dir.path().join("board_name");
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_from_board_name_jupiter_is_lcd() {
+        assert_eq!(DeckModel::from_board_name("Jupiter\n"), Some(DeckModel::Lcd));
+        assert_eq!(DeckModel::from_board_name("jupiter"), Some(DeckModel::Lcd));
+    }
+
+    #[test]
+    fn test_from_board_name_galileo_is_oled() {
+        assert_eq!(DeckModel::from_board_name("Galileo\n"), Some(DeckModel::Oled));
+    }
+
+    #[test]
+    fn test_from_board_name_unknown_is_none() {
+        assert_eq!(DeckModel::from_board_name("Desktop Board"), None);
+    }
+
+    #[test]
+    fn test_detect_deck_model_at_reads_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = write_board_name(&dir, "Galileo\n");
+        assert_eq!(detect_deck_model_at(&path), Some(DeckModel::Oled));
+    }
+
+    #[test]
+    fn test_detect_deck_model_at_missing_file_is_none() {
+        let dir = tempfile::TempDir::new().unwrap();
+        assert_eq!(detect_deck_model_at(dir.path().join("nope")), None);
+    }
+
+    #[test]
+    fn test_apu_name_matches_model() {
+        assert_eq!(DeckModel::Lcd.apu_name(), "Van Gogh");
+        assert_eq!(DeckModel::Oled.apu_name(), "Sephiroth");
+    }
+
+    #[test]
+    fn test_default_cores_are_within_own_floor() {
+        for model in [DeckModel::Lcd, DeckModel::Oled] {
+            for core in model.default_cores() {
+                assert!(core.max_mv >= model.safe_max_mv_floor());
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_core_config_for_model_rejects_too_aggressive() {
+        let core = CoreConfig {
+            core_id: 0,
+            min_mv: -10,
+            max_mv: -50,
+            threshold: 50.0,
+        };
+        let err = validate_core_config_for_model(&core, Some(DeckModel::Lcd), false).unwrap_err();
+        assert!(err.contains("force-unsafe-undervolt"));
+    }
+
+    #[test]
+    fn test_validate_core_config_for_model_allows_force_unsafe() {
+        let core = CoreConfig {
+            core_id: 0,
+            min_mv: -10,
+            max_mv: -50,
+            threshold: 50.0,
+        };
+        assert!(validate_core_config_for_model(&core, Some(DeckModel::Lcd), true).is_ok());
+    }
+
+    #[test]
+    fn test_validate_core_config_for_model_skips_when_model_unknown() {
+        let core = CoreConfig {
+            core_id: 0,
+            min_mv: -10,
+            max_mv: -50,
+            threshold: 50.0,
+        };
+        assert!(validate_core_config_for_model(&core, None, false).is_ok());
+    }
+}
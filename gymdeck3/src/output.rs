@@ -1,20 +1,52 @@
 //! JSON output formatting for gymdeck3 status
 //!
 //! Provides NDJSON (newline-delimited JSON) output for status updates,
-//! transitions, and error messages.
+//! transitions, error messages, and opt-in per-tick interpolation/fan
+//! reports.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
 use std::io::{self, Write};
+use std::ops::RangeInclusive;
+use std::os::unix::net::UnixStream;
+use std::path::Path;
 use std::time::Instant;
+use tokio::sync::broadcast;
 
 use crate::config::Strategy;
 
+/// Current NDJSON envelope schema version, emitted as `schema_version` on
+/// every message `OutputWriter` writes
+///
+/// Bump this when a message's shape changes in a way a consumer would need
+/// to branch on, so `Message::from_ndjson_line` can reject a line it no
+/// longer understands instead of silently misparsing it.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Default for `schema_version` when absent from the wire, for lines from a
+/// producer that predates this field
+fn default_schema_version() -> u32 {
+    SCHEMA_VERSION
+}
+
 /// Status output message containing current state
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+///
+/// Serializes with an explicit, documented field order - `type`,
+/// `schema_version`, `load`, `values`, `strategy`, `uptime_ms`,
+/// `uptime_ticks`, `seq`, then `fan` if present - via a manual `Serialize`
+/// impl below, so downstream consumers that diff or hash NDJSON lines get a
+/// stable byte layout regardless of struct field order or serde map
+/// iteration.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct StatusOutput {
     /// Message type identifier
     #[serde(rename = "type")]
     pub msg_type: String,
+    /// NDJSON envelope schema version; defaults to 1 for lines from a
+    /// producer that predates this field
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
     /// Per-core CPU load percentages
     pub load: Vec<f32>,
     /// Per-core applied undervolt values in mV
@@ -23,6 +55,33 @@ pub struct StatusOutput {
     pub strategy: String,
     /// Uptime in milliseconds since daemon start
     pub uptime_ms: u64,
+    /// Uptime since daemon start, in `--tick-hz` ticks
+    ///
+    /// A `CLOCK_MONOTONIC`-backed time base alongside `uptime_ms` so
+    /// frontends can plot a series at whatever resolution they configured
+    /// (`--tick-hz 1000` for ms, `1_000_000` for µs) without depending on
+    /// receive-time clocks, which skew across a NDJSON stream. Defaults to
+    /// 0 when absent from older producers.
+    #[serde(default)]
+    pub uptime_ticks: u64,
+    /// Monotonically increasing sample sequence number
+    ///
+    /// Lets a frontend detect dropped samples by spotting gaps, since
+    /// `uptime_ticks` alone can't distinguish a slow producer from a lost
+    /// line. Defaults to 0 when absent from older producers.
+    #[serde(default)]
+    pub seq: u64,
+    /// Fan status, present only when fan control is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fan: Option<FanStatusOutput>,
+    /// Per-game undervolt profile id (e.g. a Steam app id), stamped by
+    /// `OutputWriter::set_profile`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_id: Option<String>,
+    /// Named variant within `profile_id` (e.g. "docked", "handheld"),
+    /// stamped by `OutputWriter::set_profile`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant_name: Option<String>,
 }
 
 impl StatusOutput {
@@ -35,25 +94,257 @@ impl StatusOutput {
     ) -> Self {
         Self {
             msg_type: "status".to_string(),
+            schema_version: SCHEMA_VERSION,
             load,
             values,
             strategy: strategy.to_string(),
             uptime_ms,
+            uptime_ticks: 0,
+            seq: 0,
+            fan: None,
+            profile_id: None,
+            variant_name: None,
         }
     }
 
+    /// Create a new status output message including fan status
+    pub fn with_fan(
+        load: Vec<f32>,
+        values: Vec<i32>,
+        strategy: Strategy,
+        uptime_ms: u64,
+        fan: FanStatusOutput,
+    ) -> Self {
+        Self {
+            msg_type: "status".to_string(),
+            schema_version: SCHEMA_VERSION,
+            load,
+            values,
+            strategy: strategy.to_string(),
+            uptime_ms,
+            uptime_ticks: 0,
+            seq: 0,
+            fan: Some(fan),
+            profile_id: None,
+            variant_name: None,
+        }
+    }
+
+    /// Builder: attach the tick-resolution uptime and sample sequence
+    /// number, as computed by `OutputWriter`
+    pub fn with_ticks(mut self, uptime_ticks: u64, seq: u64) -> Self {
+        self.uptime_ticks = uptime_ticks;
+        self.seq = seq;
+        self
+    }
+
+    /// Builder: attach the per-game profile/variant that produced this
+    /// status, as stamped by `OutputWriter::set_profile`
+    pub fn with_profile(
+        mut self,
+        profile_id: impl Into<String>,
+        variant_name: impl Into<String>,
+    ) -> Self {
+        self.profile_id = Some(profile_id.into());
+        self.variant_name = Some(variant_name.into());
+        self
+    }
+
     /// Serialize to JSON string
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(self)
     }
+
+    /// Serialize to a canonical, float-free JSON string
+    ///
+    /// `load` percentages lose precision and can serialize non-deterministically
+    /// as f32 JSON (see `to_json`). This encodes them as milli-percent fixed-point
+    /// integers (`load * 1000`, rounded to the nearest `u32`) instead, producing
+    /// byte-stable output with no `.`/`e` float tokens. Pairs with
+    /// `from_canonical_json` for exact, lossless round-trips - useful for
+    /// diffing log streams, deduplicating repeated status lines, and hashing.
+    ///
+    /// Note: this is lossy the other way, in that a `load` value that isn't
+    /// already an exact multiple of 0.001 is rounded to the nearest milli-percent.
+    pub fn to_canonical_json(&self) -> Result<String, serde_json::Error> {
+        let canonical = CanonicalStatusOutput {
+            msg_type: self.msg_type.clone(),
+            schema_version: self.schema_version,
+            load_milli_pct: self
+                .load
+                .iter()
+                .map(|&l| (l * 1000.0).round() as u32)
+                .collect(),
+            values: self.values.clone(),
+            strategy: self.strategy.clone(),
+            uptime_ms: self.uptime_ms,
+            uptime_ticks: self.uptime_ticks,
+            seq: self.seq,
+            fan: self.fan.clone(),
+            profile_id: self.profile_id.clone(),
+            variant_name: self.variant_name.clone(),
+        };
+        serde_json::to_string(&canonical)
+    }
+
+    /// Deserialize from a canonical, float-free JSON string produced by
+    /// `to_canonical_json`
+    pub fn from_canonical_json(json_str: &str) -> Result<Self, serde_json::Error> {
+        let canonical: CanonicalStatusOutput = serde_json::from_str(json_str)?;
+        Ok(Self {
+            msg_type: canonical.msg_type,
+            schema_version: canonical.schema_version,
+            load: canonical
+                .load_milli_pct
+                .iter()
+                .map(|&m| m as f32 / 1000.0)
+                .collect(),
+            values: canonical.values,
+            strategy: canonical.strategy,
+            uptime_ms: canonical.uptime_ms,
+            uptime_ticks: canonical.uptime_ticks,
+            seq: canonical.seq,
+            fan: canonical.fan,
+            profile_id: canonical.profile_id,
+            variant_name: canonical.variant_name,
+        })
+    }
 }
 
-/// Transition output message for value changes
+impl Serialize for StatusOutput {
+    /// Emit fields in the documented canonical order (`type`,
+    /// `schema_version`, `load`, `values`, `strategy`, `uptime_ms`,
+    /// `uptime_ticks`, `seq`, `fan`, `profile_id`, `variant_name`) rather
+    /// than relying on struct field order or derive behavior.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let field_count = 8
+            + self.fan.is_some() as usize
+            + self.profile_id.is_some() as usize
+            + self.variant_name.is_some() as usize;
+        let mut state = serializer.serialize_struct("StatusOutput", field_count)?;
+        state.serialize_field("type", &self.msg_type)?;
+        state.serialize_field("schema_version", &self.schema_version)?;
+        state.serialize_field("load", &self.load)?;
+        state.serialize_field("values", &self.values)?;
+        state.serialize_field("strategy", &self.strategy)?;
+        state.serialize_field("uptime_ms", &self.uptime_ms)?;
+        state.serialize_field("uptime_ticks", &self.uptime_ticks)?;
+        state.serialize_field("seq", &self.seq)?;
+        if let Some(fan) = &self.fan {
+            state.serialize_field("fan", fan)?;
+        } else {
+            state.skip_field("fan")?;
+        }
+        if let Some(profile_id) = &self.profile_id {
+            state.serialize_field("profile_id", profile_id)?;
+        } else {
+            state.skip_field("profile_id")?;
+        }
+        if let Some(variant_name) = &self.variant_name {
+            state.serialize_field("variant_name", variant_name)?;
+        } else {
+            state.skip_field("variant_name")?;
+        }
+        state.end()
+    }
+}
+
+/// Float-free, fixed-point wire format for `StatusOutput`
+///
+/// `load` is encoded as milli-percent (`load * 1000`) integers instead of
+/// f32, so the serialized bytes are deterministic and exact round-trips
+/// don't need a floating-point tolerance.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct CanonicalStatusOutput {
+    #[serde(rename = "type")]
+    msg_type: String,
+    #[serde(default = "default_schema_version")]
+    schema_version: u32,
+    load_milli_pct: Vec<u32>,
+    values: Vec<i32>,
+    strategy: String,
+    uptime_ms: u64,
+    #[serde(default)]
+    uptime_ticks: u64,
+    #[serde(default)]
+    seq: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fan: Option<FanStatusOutput>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    profile_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    variant_name: Option<String>,
+}
+
+/// Fan status included in status output when fan control is enabled
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FanStatusOutput {
+    /// Current temperature in Celsius
+    pub temp_c: i32,
+    /// Current PWM value (0-255)
+    pub pwm: u8,
+    /// Current fan speed as a percentage (0-100)
+    pub speed_percent: u8,
+    /// Current fan control mode (e.g. "default", "custom", "fixed")
+    pub mode: String,
+    /// Current fan RPM, if available from hwmon
+    pub rpm: Option<u32>,
+    /// Whether a safety override (e.g. high-temp) is currently forcing the PWM
+    pub safety_override_active: bool,
+    /// Tachometer-based health classification ("ok", "stalled", "low_signal"
+    /// or "not_available")
+    pub fan_health: String,
+    /// Speed percentage after adaptive fan slowing's down-ramp step cap,
+    /// distinct from `speed_percent` (the final, hardware-written duty) so
+    /// users can see the damping take effect in the status stream
+    pub effective_speed_percent: u8,
+}
+
+impl FanStatusOutput {
+    /// Create a new fan status output
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        temp_c: i32,
+        pwm: u8,
+        speed_percent: u8,
+        mode: &str,
+        rpm: Option<u32>,
+        safety_override_active: bool,
+        fan_health: &str,
+        effective_speed_percent: u8,
+    ) -> Self {
+        Self {
+            temp_c,
+            pwm,
+            speed_percent,
+            mode: mode.to_string(),
+            rpm,
+            safety_override_active,
+            fan_health: fan_health.to_string(),
+            effective_speed_percent,
+        }
+    }
+}
+
+/// Transition output message for value changes
+///
+/// Serializes with an explicit, documented field order - `type`,
+/// `schema_version`, `from`, `to`, `progress` - via a manual `Serialize`
+/// impl below.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct TransitionOutput {
     /// Message type identifier
     #[serde(rename = "type")]
     pub msg_type: String,
+    /// NDJSON envelope schema version; defaults to 1 for lines from a
+    /// producer that predates this field
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
     /// Previous undervolt values
     pub from: Vec<i32>,
     /// Target undervolt values
@@ -67,6 +358,7 @@ impl TransitionOutput {
     pub fn new(from: Vec<i32>, to: Vec<i32>, progress: f32) -> Self {
         Self {
             msg_type: "transition".to_string(),
+            schema_version: SCHEMA_VERSION,
             from,
             to,
             progress: progress.clamp(0.0, 1.0),
@@ -79,12 +371,111 @@ impl TransitionOutput {
     }
 }
 
+impl Serialize for TransitionOutput {
+    /// Emit fields in the documented canonical order (`type`,
+    /// `schema_version`, `from`, `to`, `progress`) rather than relying on
+    /// struct field order or derive behavior.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("TransitionOutput", 5)?;
+        state.serialize_field("type", &self.msg_type)?;
+        state.serialize_field("schema_version", &self.schema_version)?;
+        state.serialize_field("from", &self.from)?;
+        state.serialize_field("to", &self.to)?;
+        state.serialize_field("progress", &self.progress)?;
+        state.end()
+    }
+}
+
+/// Per-tick interpolation/fan telemetry report, for an opt-in NDJSON stream
+/// distinct from `StatusOutput`'s periodic sampling
+///
+/// Built from `interpolation::Interpolator::report()` plus an optional
+/// `FanStatusOutput`, so an external monitor subscribed to the `report mode`
+/// stream sees every core's current/target undervolt and the fan's
+/// pwm/temp/rpm without polling each getter individually.
+///
+/// Serializes with an explicit, documented field order - `type`,
+/// `schema_version`, `current`, `target`, `fan` - via a manual `Serialize`
+/// impl below.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ReportOutput {
+    /// Message type identifier
+    #[serde(rename = "type")]
+    pub msg_type: String,
+    /// NDJSON envelope schema version; defaults to 1 for lines from a
+    /// producer that predates this field
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
+    /// Per-core current undervolt values (mV), from `Interpolator::report()`
+    pub current: Vec<i32>,
+    /// Per-core target undervolt values (mV), from `Interpolator::report()`
+    pub target: Vec<i32>,
+    /// Fan status, present only when fan control is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fan: Option<FanStatusOutput>,
+}
+
+impl ReportOutput {
+    /// Create a new report output message
+    pub fn new(current: Vec<i32>, target: Vec<i32>, fan: Option<FanStatusOutput>) -> Self {
+        Self {
+            msg_type: "report".to_string(),
+            schema_version: SCHEMA_VERSION,
+            current,
+            target,
+            fan,
+        }
+    }
+
+    /// Serialize to JSON string
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+impl Serialize for ReportOutput {
+    /// Emit fields in the documented canonical order (`type`,
+    /// `schema_version`, `current`, `target`, `fan`) rather than relying on
+    /// struct field order or derive behavior.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let field_count = 4 + self.fan.is_some() as usize;
+        let mut state = serializer.serialize_struct("ReportOutput", field_count)?;
+        state.serialize_field("type", &self.msg_type)?;
+        state.serialize_field("schema_version", &self.schema_version)?;
+        state.serialize_field("current", &self.current)?;
+        state.serialize_field("target", &self.target)?;
+        if let Some(fan) = &self.fan {
+            state.serialize_field("fan", fan)?;
+        } else {
+            state.skip_field("fan")?;
+        }
+        state.end()
+    }
+}
+
 /// Error output message
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+///
+/// Serializes with an explicit, documented field order - `type`,
+/// `schema_version`, `code`, `message` - via a manual `Serialize` impl below.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct ErrorOutput {
     /// Message type identifier
     #[serde(rename = "type")]
     pub msg_type: String,
+    /// NDJSON envelope schema version; defaults to 1 for lines from a
+    /// producer that predates this field
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
     /// Error code for programmatic handling
     pub code: String,
     /// Human-readable error message
@@ -96,6 +487,7 @@ impl ErrorOutput {
     pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
         Self {
             msg_type: "error".to_string(),
+            schema_version: SCHEMA_VERSION,
             code: code.into(),
             message: message.into(),
         }
@@ -107,13 +499,204 @@ impl ErrorOutput {
     }
 }
 
+impl Serialize for ErrorOutput {
+    /// Emit fields in the documented canonical order (`type`,
+    /// `schema_version`, `code`, `message`) rather than relying on struct
+    /// field order or derive behavior.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("ErrorOutput", 4)?;
+        state.serialize_field("type", &self.msg_type)?;
+        state.serialize_field("schema_version", &self.schema_version)?;
+        state.serialize_field("code", &self.code)?;
+        state.serialize_field("message", &self.message)?;
+        state.end()
+    }
+}
+
+/// Destination for NDJSON status lines written by `OutputWriter`
+///
+/// Splits *where a line goes* from *how it's built* (mirroring a
+/// transport/message-construction split), so `OutputWriter` can route status
+/// to a Unix socket or a log file instead of stdout without
+/// `write_status`/`write_transition`/`write_error` changing at all. See
+/// `StdoutSink` (the default), `FileSink`, `UnixSocketSink`.
+pub trait StatusSink: Send {
+    /// Write one already-serialized NDJSON line; implementations own
+    /// appending the trailing newline and flushing so the line is visible
+    /// to a reader immediately.
+    fn emit(&mut self, line: &str) -> io::Result<()>;
+}
+
+/// Default `StatusSink`: writes to the process's stdout, matching
+/// `OutputWriter`'s original hard-wired behavior
+#[derive(Debug, Default)]
+pub struct StdoutSink;
+
+impl StatusSink for StdoutSink {
+    fn emit(&mut self, line: &str) -> io::Result<()> {
+        let stdout = io::stdout();
+        let mut handle = stdout.lock();
+        writeln!(handle, "{}", line)?;
+        handle.flush()
+    }
+}
+
+/// `StatusSink` that appends NDJSON lines to a file, e.g. to persist status
+/// history across restarts
+pub struct FileSink(File);
+
+impl FileSink {
+    /// Open (creating if needed) `path` in append mode
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self(file))
+    }
+}
+
+impl StatusSink for FileSink {
+    fn emit(&mut self, line: &str) -> io::Result<()> {
+        writeln!(self.0, "{}", line)?;
+        self.0.flush()
+    }
+}
+
+/// `StatusSink` that writes to a connected Unix domain socket, e.g. so a UI
+/// process can receive the NDJSON status stream without sharing the
+/// daemon's stdout
+pub struct UnixSocketSink(UnixStream);
+
+impl UnixSocketSink {
+    /// Connect to a listening Unix socket at `path`
+    pub fn connect(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self(UnixStream::connect(path)?))
+    }
+}
+
+impl StatusSink for UnixSocketSink {
+    fn emit(&mut self, line: &str) -> io::Result<()> {
+        writeln!(self.0, "{}", line)?;
+        self.0.flush()
+    }
+}
+
+/// L1 distance above which [`AdaptiveInterval`] treats a tick as "busy" and
+/// halves the effective interval
+pub const DEFAULT_ADAPTIVE_BUSY_THRESHOLD: f32 = 5.0;
+
+/// L1 distance at or below which [`AdaptiveInterval`] treats a tick as
+/// "quiet"; `DEFAULT_ADAPTIVE_QUIET_TICKS` consecutive quiet ticks double
+/// the effective interval
+pub const DEFAULT_ADAPTIVE_QUIET_THRESHOLD: f32 = 1.0;
+
+/// Consecutive quiet ticks required before [`AdaptiveInterval`] doubles the
+/// effective interval
+pub const DEFAULT_ADAPTIVE_QUIET_TICKS: u32 = 3;
+
+/// Volatility-driven emit cadence for `OutputWriter::new_adaptive`
+///
+/// Tracks the last emitted `load`/`values` snapshot and the L1 distance of
+/// each subsequent candidate from it. A "busy" tick (distance above
+/// `DEFAULT_ADAPTIVE_BUSY_THRESHOLD`) halves the effective interval down to
+/// `min_ms`, so bursts of activity get finer-grained sampling; a run of
+/// `DEFAULT_ADAPTIVE_QUIET_TICKS` "quiet" ticks (distance at or below
+/// `DEFAULT_ADAPTIVE_QUIET_THRESHOLD`) doubles it back up to `max_ms`, so a
+/// steady system doesn't spam identical lines.
+#[derive(Debug, Clone)]
+struct AdaptiveInterval {
+    min_ms: u64,
+    max_ms: u64,
+    current_ms: u64,
+    last_emitted: Option<(Vec<f32>, Vec<i32>)>,
+    quiet_ticks: u32,
+}
+
+impl AdaptiveInterval {
+    fn new(min_ms: u64, max_ms: u64) -> Self {
+        Self {
+            min_ms,
+            max_ms,
+            current_ms: max_ms,
+            last_emitted: None,
+            quiet_ticks: 0,
+        }
+    }
+
+    /// L1 distance between `load`/`values` and the last emitted snapshot;
+    /// infinite before anything has been emitted, so the first tick is
+    /// always treated as busy
+    fn l1_distance(&self, load: &[f32], values: &[i32]) -> f32 {
+        match &self.last_emitted {
+            None => f32::INFINITY,
+            Some((last_load, last_values)) => {
+                let load_dist: f32 = load
+                    .iter()
+                    .zip(last_load)
+                    .map(|(a, b)| (a - b).abs())
+                    .sum();
+                let value_dist: f32 = values
+                    .iter()
+                    .zip(last_values)
+                    .map(|(a, b)| (a - b).abs() as f32)
+                    .sum();
+                load_dist + value_dist
+            }
+        }
+    }
+
+    /// Update the effective interval for a candidate snapshot, without
+    /// recording it as emitted
+    fn observe(&mut self, load: &[f32], values: &[i32]) {
+        let distance = self.l1_distance(load, values);
+        if distance > DEFAULT_ADAPTIVE_BUSY_THRESHOLD {
+            self.quiet_ticks = 0;
+            self.current_ms = (self.current_ms / 2).max(self.min_ms);
+        } else if distance <= DEFAULT_ADAPTIVE_QUIET_THRESHOLD {
+            self.quiet_ticks = self.quiet_ticks.saturating_add(1);
+            if self.quiet_ticks >= DEFAULT_ADAPTIVE_QUIET_TICKS {
+                self.current_ms = self.current_ms.saturating_mul(2).min(self.max_ms);
+                self.quiet_ticks = 0;
+            }
+        } else {
+            self.quiet_ticks = 0;
+        }
+    }
+
+    /// Record a snapshot as emitted, so future ticks measure distance from it
+    fn record_emitted(&mut self, load: &[f32], values: &[i32]) {
+        self.last_emitted = Some((load.to_vec(), values.to_vec()));
+    }
+}
+
 /// Output writer for NDJSON status messages
 pub struct OutputWriter {
     start_time: Instant,
     output_interval_ms: u64,
     last_output: Option<Instant>,
+    /// Connected control-socket clients, subscribed via `control::ControlServer`
+    broadcast: Option<broadcast::Sender<String>>,
+    /// Tick resolution for `uptime_ticks` (ticks per second), set via `--tick-hz`
+    tick_hz: u64,
+    /// Sample sequence number, incremented once per emitted status line
+    seq: u64,
+    /// Where emitted NDJSON lines are written; stdout unless overridden via
+    /// `with_sink`
+    sink: Box<dyn StatusSink>,
+    /// Volatility-driven emit cadence, set via `new_adaptive`; `None` means
+    /// the writer uses the fixed `output_interval_ms`
+    adaptive: Option<AdaptiveInterval>,
+    /// Per-game profile/variant stamped onto every subsequent status line,
+    /// set via `set_profile`
+    profile: Option<(String, String)>,
 }
 
+/// Default tick resolution (1000 Hz = millisecond ticks), matching `uptime_ms`
+pub const DEFAULT_TICK_HZ: u64 = 1000;
+
 impl OutputWriter {
     /// Create a new output writer with configurable interval
     ///
@@ -124,35 +707,133 @@ impl OutputWriter {
             start_time: Instant::now(),
             output_interval_ms,
             last_output: None,
+            broadcast: None,
+            tick_hz: DEFAULT_TICK_HZ,
+            seq: 0,
+            sink: Box::new(StdoutSink),
+            adaptive: None,
+            profile: None,
         }
     }
 
+    /// Create an output writer whose emit cadence adapts to `load`/`values`
+    /// volatility instead of a fixed interval
+    ///
+    /// Starts at `max_ms`; each `write_status_if_due` call measures the L1
+    /// distance of the candidate snapshot from the last emitted one and
+    /// halves toward `min_ms` on a busy tick, or doubles back toward
+    /// `max_ms` after a run of quiet ticks. See `AdaptiveInterval`.
+    pub fn new_adaptive(min_ms: u64, max_ms: u64) -> Self {
+        let mut writer = Self::new(min_ms);
+        writer.adaptive = Some(AdaptiveInterval::new(min_ms, max_ms));
+        writer
+    }
+
+    /// Route emitted NDJSON lines through `sink` instead of stdout
+    pub fn with_sink(mut self, sink: impl StatusSink + 'static) -> Self {
+        self.sink = Box::new(sink);
+        self
+    }
+
+    /// Also broadcast every emitted line to connected control-socket clients
+    ///
+    /// See `control::ControlServer`, which subscribes a receiver per
+    /// accepted connection so clients get the same status stream as stdout.
+    pub fn with_broadcast(mut self, tx: broadcast::Sender<String>) -> Self {
+        self.broadcast = Some(tx);
+        self
+    }
+
+    /// Set the tick resolution used for `uptime_ticks`, as validated by
+    /// `config::validate_tick_hz_value`
+    pub fn with_tick_hz(mut self, tick_hz: u64) -> Self {
+        self.tick_hz = tick_hz;
+        self
+    }
+
     /// Get uptime in milliseconds since writer creation
     pub fn uptime_ms(&self) -> u64 {
         self.start_time.elapsed().as_millis() as u64
     }
 
+    /// Get uptime since writer creation, expressed in `tick_hz` ticks
+    ///
+    /// Computed from the same `CLOCK_MONOTONIC`-backed `Instant` as
+    /// `uptime_ms`, just at the configured resolution instead of a fixed
+    /// millisecond grid.
+    pub fn uptime_ticks(&self) -> u64 {
+        let elapsed_nanos = self.start_time.elapsed().as_nanos();
+        (elapsed_nanos * self.tick_hz as u128 / 1_000_000_000) as u64
+    }
+
+    /// Stamp every subsequent status line with a per-game profile/variant,
+    /// until cleared via `clear_profile`
+    ///
+    /// Steam Deck users run different undervolt profiles per title; this
+    /// lets a consuming UI show which profile/variant produced the current
+    /// values without threading the ids through every `write_status` call
+    /// site.
+    pub fn set_profile(&mut self, profile_id: impl Into<String>, variant_name: impl Into<String>) {
+        self.profile = Some((profile_id.into(), variant_name.into()));
+    }
+
+    /// Stop stamping subsequent status lines with profile/variant context
+    pub fn clear_profile(&mut self) {
+        self.profile = None;
+    }
+
+    /// Advance and return the next sample sequence number
+    ///
+    /// Shared by every emission path (periodic and forced) so a frontend
+    /// can spot dropped samples from gaps regardless of which path emitted
+    /// each line.
+    pub fn next_seq(&mut self) -> u64 {
+        self.seq += 1;
+        self.seq
+    }
+
+    /// The interval currently governing `should_output` - the adaptive
+    /// effective interval if `new_adaptive` was used, else the fixed
+    /// `output_interval_ms`
+    fn effective_interval_ms(&self) -> u64 {
+        self.adaptive
+            .as_ref()
+            .map(|a| a.current_ms)
+            .unwrap_or(self.output_interval_ms)
+    }
+
     /// Check if enough time has passed for next output
     pub fn should_output(&self) -> bool {
         match self.last_output {
-            Some(last) => last.elapsed().as_millis() as u64 >= self.output_interval_ms,
+            Some(last) => last.elapsed().as_millis() as u64 >= self.effective_interval_ms(),
             None => true,
         }
     }
 
     /// Write status output to stdout if interval has elapsed
     ///
-    /// Returns true if output was written, false if skipped due to interval
+    /// Returns true if output was written, false if skipped due to interval.
+    /// If the writer was created via `new_adaptive`, every call also feeds
+    /// `load`/`values` into the adaptive cadence, whether or not this call
+    /// ends up emitting.
     pub fn write_status_if_due(
         &mut self,
         load: Vec<f32>,
         values: Vec<i32>,
         strategy: Strategy,
     ) -> io::Result<bool> {
+        if let Some(adaptive) = &mut self.adaptive {
+            adaptive.observe(&load, &values);
+        }
+
         if !self.should_output() {
             return Ok(false);
         }
 
+        if let Some(adaptive) = &mut self.adaptive {
+            adaptive.record_emitted(&load, &values);
+        }
+
         self.write_status(load, values, strategy)?;
         Ok(true)
     }
@@ -164,7 +845,11 @@ impl OutputWriter {
         values: Vec<i32>,
         strategy: Strategy,
     ) -> io::Result<()> {
-        let status = StatusOutput::new(load, values, strategy, self.uptime_ms());
+        let mut status = StatusOutput::new(load, values, strategy, self.uptime_ms())
+            .with_ticks(self.uptime_ticks(), self.next_seq());
+        if let Some((profile_id, variant_name)) = &self.profile {
+            status = status.with_profile(profile_id.clone(), variant_name.clone());
+        }
         self.write_json(&status)?;
         self.last_output = Some(Instant::now());
         Ok(())
@@ -187,53 +872,337 @@ impl OutputWriter {
         self.write_json(&error)
     }
 
+    /// Write a `report` telemetry output, regardless of interval
+    ///
+    /// Callers gate this on `ControlState::report_mode` - unlike
+    /// `write_status`/`write_status_if_due`, this is not rate-limited, since
+    /// the `report` stream is meant to carry one record per tick.
+    pub fn write_report(
+        &mut self,
+        current: Vec<i32>,
+        target: Vec<i32>,
+        fan: Option<FanStatusOutput>,
+    ) -> io::Result<()> {
+        let report = ReportOutput::new(current, target, fan);
+        self.write_json(&report)
+    }
+
     /// Write any serializable value as NDJSON line
-    fn write_json<T: Serialize>(&self, value: &T) -> io::Result<()> {
+    ///
+    /// Shared by status/transition/error writes and the `logging` module's
+    /// tracing layer, so every line - whichever envelope it carries - goes
+    /// through the same stdout lock and never interleaves mid-line. Also
+    /// fans the line out to any control-socket clients registered via
+    /// `with_broadcast`; a send with no subscribers is a no-op.
+    pub(crate) fn write_json<T: Serialize>(&mut self, value: &T) -> io::Result<()> {
         let json = serde_json::to_string(value)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        
-        let stdout = io::stdout();
-        let mut handle = stdout.lock();
-        writeln!(handle, "{}", json)?;
-        handle.flush()
+
+        if let Some(tx) = &self.broadcast {
+            let _ = tx.send(json.clone());
+        }
+
+        self.sink.emit(&json)
     }
 }
 
-/// Validate status output contains all required fields
-pub fn validate_status_output(json_str: &str) -> Result<StatusOutput, String> {
-    let output: StatusOutput = serde_json::from_str(json_str)
-        .map_err(|e| format!("Invalid JSON: {}", e))?;
+/// Configurable validation policy for `StatusOutput` (and `TransitionOutput`)
+///
+/// `validate_status_output` used to bake in a fixed set of rules (0.0-100.0
+/// load range, non-empty arrays, `"status"` type check). This struct makes
+/// those rules data instead of code, so callers with different needs (an
+/// 8-core vs 4-core Deck, an aggressive-only kiosk build) can customize the
+/// policy instead of forking the validator.
+///
+/// Build with `StatusValidation::default()` and override individual fields,
+/// builder-style.
+#[derive(Debug, Clone)]
+pub struct StatusValidation {
+    /// Valid range for each per-core load percentage
+    pub load_range: RangeInclusive<f32>,
+    /// Valid range for each per-core undervolt value (mV)
+    pub undervolt_range: RangeInclusive<i32>,
+    /// Maximum number of cores accepted in `load`/`values`
+    pub max_cores: usize,
+    /// JSON field names that must be present on the raw message
+    pub required_fields: HashSet<String>,
+    /// Strategies accepted in the `strategy` field; empty means "any"
+    pub allowed_strategies: HashSet<Strategy>,
+    /// Epsilon tolerance applied to `load_range`, to absorb sensor noise
+    /// and f32 rounding at the boundaries
+    pub leeway: f32,
+}
 
-    // Validate required fields
-    if output.msg_type != "status" {
-        return Err(format!("Expected type 'status', got '{}'", output.msg_type));
+impl Default for StatusValidation {
+    fn default() -> Self {
+        Self {
+            load_range: 0.0..=100.0,
+            undervolt_range: i32::MIN..=i32::MAX,
+            max_cores: usize::MAX,
+            required_fields: ["type", "load", "values", "strategy", "uptime_ms"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            allowed_strategies: HashSet::new(),
+            leeway: 0.0,
+        }
     }
+}
 
-    if output.load.is_empty() {
-        return Err("load array cannot be empty".to_string());
+impl StatusValidation {
+    /// Builder: set the accepted load percentage range
+    pub fn with_load_range(mut self, load_range: RangeInclusive<f32>) -> Self {
+        self.load_range = load_range;
+        self
     }
 
-    if output.values.is_empty() {
-        return Err("values array cannot be empty".to_string());
+    /// Builder: set the accepted undervolt (mV) range
+    pub fn with_undervolt_range(mut self, undervolt_range: RangeInclusive<i32>) -> Self {
+        self.undervolt_range = undervolt_range;
+        self
     }
 
-    if output.strategy.is_empty() {
-        return Err("strategy cannot be empty".to_string());
+    /// Builder: set the maximum number of cores accepted
+    pub fn with_max_cores(mut self, max_cores: usize) -> Self {
+        self.max_cores = max_cores;
+        self
     }
 
-    // Validate load values are in valid range
-    for (i, &load) in output.load.iter().enumerate() {
-        if !(0.0..=100.0).contains(&load) {
-            return Err(format!("load[{}] = {} is out of range [0, 100]", i, load));
+    /// Builder: restrict accepted strategies (empty set means "any")
+    pub fn with_allowed_strategies(mut self, allowed_strategies: HashSet<Strategy>) -> Self {
+        self.allowed_strategies = allowed_strategies;
+        self
+    }
+
+    /// Builder: set the load-range leeway epsilon
+    pub fn with_leeway(mut self, leeway: f32) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    /// Validate a status output JSON string against this policy
+    pub fn validate(&self, json_str: &str) -> Result<StatusOutput, String> {
+        let raw: serde_json::Value = serde_json::from_str(json_str)
+            .map_err(|e| format!("Invalid JSON: {}", e))?;
+
+        if let serde_json::Value::Object(map) = &raw {
+            for field in &self.required_fields {
+                if !map.contains_key(field) {
+                    return Err(format!("missing required field '{}'", field));
+                }
+            }
+        }
+
+        let output: StatusOutput = serde_json::from_str(json_str)
+            .map_err(|e| format!("Invalid JSON: {}", e))?;
+
+        if output.msg_type != "status" {
+            return Err(format!("Expected type 'status', got '{}'", output.msg_type));
+        }
+
+        if output.load.is_empty() {
+            return Err("load array cannot be empty".to_string());
+        }
+
+        if output.values.is_empty() {
+            return Err("values array cannot be empty".to_string());
+        }
+
+        if output.strategy.is_empty() {
+            return Err("strategy cannot be empty".to_string());
+        }
+
+        if output.load.len() > self.max_cores {
+            return Err(format!(
+                "load has {} entries, exceeds max_cores {}",
+                output.load.len(),
+                self.max_cores
+            ));
+        }
+
+        let lo = *self.load_range.start() - self.leeway;
+        let hi = *self.load_range.end() + self.leeway;
+        for (i, &load) in output.load.iter().enumerate() {
+            if load < lo || load > hi {
+                return Err(format!(
+                    "load[{}] = {} is out of range [{}, {}]",
+                    i,
+                    load,
+                    self.load_range.start(),
+                    self.load_range.end()
+                ));
+            }
+        }
+
+        let ulo = *self.undervolt_range.start();
+        let uhi = *self.undervolt_range.end();
+        for (i, &value) in output.values.iter().enumerate() {
+            if value < ulo || value > uhi {
+                return Err(format!(
+                    "values[{}] = {} is out of range [{}, {}]",
+                    i, value, ulo, uhi
+                ));
+            }
+        }
+
+        if !self.allowed_strategies.is_empty() {
+            let strategy = parse_strategy_name(&output.strategy)
+                .ok_or_else(|| format!("Unknown strategy '{}'", output.strategy))?;
+            if !self.allowed_strategies.contains(&strategy) {
+                return Err(format!("strategy '{}' is not allowed", output.strategy));
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+/// Parse a strategy's lowercase display name back into a `Strategy`
+fn parse_strategy_name(name: &str) -> Option<Strategy> {
+    match name {
+        "conservative" => Some(Strategy::Conservative),
+        "balanced" => Some(Strategy::Balanced),
+        "aggressive" => Some(Strategy::Aggressive),
+        "custom" => Some(Strategy::Custom),
+        "pid" => Some(Strategy::Pid),
+        "adaptive" => Some(Strategy::Adaptive),
+        "learning" => Some(Strategy::Learning),
+        "markov" => Some(Strategy::Markov),
+        _ => None,
+    }
+}
+
+/// Validate status output contains all required fields
+///
+/// Equivalent to `StatusValidation::default().validate(json_str)`.
+pub fn validate_status_output(json_str: &str) -> Result<StatusOutput, String> {
+    StatusValidation::default().validate(json_str)
+}
+
+/// Self-describing union of every NDJSON line `OutputWriter` can emit
+///
+/// Each variant already self-tags via its own `type` field and carries its
+/// own `schema_version`, so `Message` doesn't need serde's internal tagging -
+/// it just dispatches on the decoded `type` instead of doubling it. Lets a
+/// consumer parse a mixed status/transition/error stream with one call
+/// instead of sniffing `type` and picking a struct by hand.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    Status(StatusOutput),
+    Transition(TransitionOutput),
+    Error(ErrorOutput),
+    Report(ReportOutput),
+}
+
+impl Serialize for Message {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Message::Status(status) => status.serialize(serializer),
+            Message::Transition(transition) => transition.serialize(serializer),
+            Message::Error(error) => error.serialize(serializer),
+            Message::Report(report) => report.serialize(serializer),
         }
     }
+}
+
+impl<'de> Deserialize<'de> for Message {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = serde_json::Value::deserialize(deserializer)?;
+        let msg_type = raw.get("type").and_then(|v| v.as_str()).unwrap_or("");
 
-    Ok(output)
+        match msg_type {
+            "status" => serde_json::from_value(raw)
+                .map(Message::Status)
+                .map_err(serde::de::Error::custom),
+            "transition" => serde_json::from_value(raw)
+                .map(Message::Transition)
+                .map_err(serde::de::Error::custom),
+            "error" => serde_json::from_value(raw)
+                .map(Message::Error)
+                .map_err(serde::de::Error::custom),
+            "report" => serde_json::from_value(raw)
+                .map(Message::Report)
+                .map_err(serde::de::Error::custom),
+            other => Err(serde::de::Error::custom(format!(
+                "unknown message type '{}'",
+                other
+            ))),
+        }
+    }
+}
+
+impl Message {
+    /// Parse and validate one NDJSON line, dispatching on its `type` field
+    ///
+    /// Rejects embedded newlines (matching `validate_log_output`), rejects a
+    /// `schema_version` newer than this build understands, and runs the
+    /// decoded variant through the same per-field rules
+    /// `validate_status_output` applies to `status` lines - plus a
+    /// monotonic-range check on `transition` progress and a non-empty check
+    /// on `error` code.
+    pub fn from_ndjson_line(line: &str) -> Result<Self, String> {
+        if line.contains('\n') {
+            return Err("message must not contain embedded newlines".to_string());
+        }
+
+        let message: Message =
+            serde_json::from_str(line).map_err(|e| format!("Invalid JSON: {}", e))?;
+
+        let schema_version = match &message {
+            Message::Status(status) => status.schema_version,
+            Message::Transition(transition) => transition.schema_version,
+            Message::Error(error) => error.schema_version,
+            Message::Report(report) => report.schema_version,
+        };
+        if schema_version > SCHEMA_VERSION {
+            return Err(format!(
+                "schema_version {} is newer than this build understands (up to {})",
+                schema_version, SCHEMA_VERSION
+            ));
+        }
+
+        match &message {
+            Message::Status(_) => {
+                StatusValidation::default().validate(line)?;
+            }
+            Message::Transition(transition) => {
+                if !(0.0..=1.0).contains(&transition.progress) {
+                    return Err(format!(
+                        "progress {} is out of range [0.0, 1.0]",
+                        transition.progress
+                    ));
+                }
+            }
+            Message::Error(error) => {
+                if error.code.is_empty() {
+                    return Err("code cannot be empty".to_string());
+                }
+            }
+            Message::Report(report) => {
+                if report.current.len() != report.target.len() {
+                    return Err(format!(
+                        "current has {} entries but target has {}",
+                        report.current.len(),
+                        report.target.len()
+                    ));
+                }
+            }
+        }
+
+        Ok(message)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::{Arc, Mutex};
 
     #[test]
     fn test_status_output_serialization() {
@@ -264,6 +1233,82 @@ mod tests {
         assert_eq!(status.uptime_ms, 12500);
     }
 
+    #[test]
+    fn test_status_output_deserialize_defaults_missing_profile_fields() {
+        // Lines from a producer predating profile/variant context still parse
+        let json = r#"{"type":"status","load":[50.0],"values":[-20],"strategy":"balanced","uptime_ms":1000}"#;
+        let status: StatusOutput = serde_json::from_str(json).unwrap();
+        assert_eq!(status.profile_id, None);
+        assert_eq!(status.variant_name, None);
+    }
+
+    #[test]
+    fn test_status_output_with_profile_round_trips_through_json() {
+        let status = StatusOutput::new(vec![50.0], vec![-20], Strategy::Balanced, 1000)
+            .with_profile("440", "handheld");
+        let json = status.to_json().unwrap();
+        assert!(json.contains("\"profile_id\":\"440\""));
+        assert!(json.contains("\"variant_name\":\"handheld\""));
+
+        let decoded: StatusOutput = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, status);
+    }
+
+    #[test]
+    fn test_status_output_without_profile_omits_fields_from_json() {
+        let status = StatusOutput::new(vec![50.0], vec![-20], Strategy::Balanced, 1000);
+        let json = status.to_json().unwrap();
+        assert!(!json.contains("profile_id"));
+        assert!(!json.contains("variant_name"));
+    }
+
+    #[test]
+    fn test_validate_status_output_accepts_profile_fields() {
+        let json = r#"{"type":"status","load":[50.0],"values":[-20],"strategy":"balanced","uptime_ms":0,"profile_id":"440","variant_name":"docked"}"#;
+        let status = validate_status_output(json).unwrap();
+        assert_eq!(status.profile_id, Some("440".to_string()));
+        assert_eq!(status.variant_name, Some("docked".to_string()));
+    }
+
+    #[test]
+    fn test_canonical_json_with_profile_round_trips() {
+        let status = StatusOutput::new(vec![50.0], vec![-20], Strategy::Balanced, 1000)
+            .with_profile("440", "handheld");
+        let json = status.to_canonical_json().unwrap();
+        let decoded = StatusOutput::from_canonical_json(&json).unwrap();
+        assert_eq!(status, decoded);
+    }
+
+    #[test]
+    fn test_output_writer_set_profile_stamps_status_lines() {
+        let sink = BufferSink::default();
+        let mut writer = OutputWriter::new(0).with_sink(sink.clone());
+        writer.set_profile("440", "handheld");
+
+        writer.write_status(vec![10.0], vec![-5], Strategy::Balanced).unwrap();
+        writer.write_status(vec![20.0], vec![-10], Strategy::Balanced).unwrap();
+
+        let lines = sink.0.lock().unwrap();
+        assert_eq!(lines.len(), 2);
+        for line in lines.iter() {
+            assert!(line.contains("\"profile_id\":\"440\""));
+            assert!(line.contains("\"variant_name\":\"handheld\""));
+        }
+    }
+
+    #[test]
+    fn test_output_writer_clear_profile_stops_stamping() {
+        let sink = BufferSink::default();
+        let mut writer = OutputWriter::new(0).with_sink(sink.clone());
+        writer.set_profile("440", "handheld");
+        writer.clear_profile();
+
+        writer.write_status(vec![10.0], vec![-5], Strategy::Balanced).unwrap();
+
+        let lines = sink.0.lock().unwrap();
+        assert!(!lines[0].contains("profile_id"));
+    }
+
     #[test]
     fn test_transition_output_serialization() {
         let transition = TransitionOutput::new(
@@ -288,6 +1333,60 @@ mod tests {
         assert_eq!(transition.progress, 0.0);
     }
 
+    #[test]
+    fn test_report_output_serialization() {
+        let report = ReportOutput::new(vec![-25, -25], vec![-30, -30], None);
+
+        let json = report.to_json().unwrap();
+        assert!(json.contains("\"type\":\"report\""));
+        assert!(json.contains("\"current\":[-25,-25]"));
+        assert!(json.contains("\"target\":[-30,-30]"));
+        assert!(!json.contains("\"fan\""));
+    }
+
+    #[test]
+    fn test_report_output_with_fan_includes_fan_field() {
+        let fan = FanStatusOutput::new(65, 128, 50, "custom", Some(3200), false, "ok", 50);
+        let report = ReportOutput::new(vec![-25], vec![-30], Some(fan));
+
+        let json = report.to_json().unwrap();
+        assert!(json.contains("\"fan\":"));
+
+        let decoded: ReportOutput = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, report);
+    }
+
+    #[test]
+    fn test_message_dispatches_report() {
+        let report = ReportOutput::new(vec![-10], vec![-20], None);
+        let json = report.to_json().unwrap();
+
+        match serde_json::from_str::<Message>(&json).unwrap() {
+            Message::Report(decoded) => assert_eq!(decoded, report),
+            other => panic!("expected Message::Report, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_ndjson_line_rejects_mismatched_report_lengths() {
+        let json = r#"{"type":"report","schema_version":1,"current":[-10,-10],"target":[-20]}"#;
+        let result = Message::from_ndjson_line(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("entries"));
+    }
+
+    #[test]
+    fn test_output_writer_write_report_routes_to_sink() {
+        let sink = BufferSink::default();
+        let mut writer = OutputWriter::new(0).with_sink(sink.clone());
+
+        writer.write_report(vec![-10], vec![-20], None).unwrap();
+
+        let lines = sink.0.lock().unwrap();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("\"type\":\"report\""));
+    }
+
     #[test]
     fn test_error_output_serialization() {
         let error = ErrorOutput::new("ryzenadj_failed", "Command returned exit code 1");
@@ -329,12 +1428,349 @@ mod tests {
         assert!(result.unwrap_err().contains("out of range"));
     }
 
+    #[test]
+    fn test_message_dispatches_status() {
+        let status = StatusOutput::new(vec![50.0], vec![-20], Strategy::Balanced, 1000);
+        let json = status.to_json().unwrap();
+
+        match serde_json::from_str::<Message>(&json).unwrap() {
+            Message::Status(decoded) => assert_eq!(decoded, status),
+            other => panic!("expected Message::Status, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_message_dispatches_transition() {
+        let transition = TransitionOutput::new(vec![-10], vec![-20], 0.5);
+        let json = transition.to_json().unwrap();
+
+        match serde_json::from_str::<Message>(&json).unwrap() {
+            Message::Transition(decoded) => assert_eq!(decoded, transition),
+            other => panic!("expected Message::Transition, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_message_dispatches_error() {
+        let error = ErrorOutput::new("ryzenadj_failed", "boom");
+        let json = error.to_json().unwrap();
+
+        match serde_json::from_str::<Message>(&json).unwrap() {
+            Message::Error(decoded) => assert_eq!(decoded, error),
+            other => panic!("expected Message::Error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_message_serialize_round_trips() {
+        let status = StatusOutput::new(vec![50.0], vec![-20], Strategy::Balanced, 1000);
+        let message = Message::Status(status.clone());
+
+        let json = serde_json::to_string(&message).unwrap();
+        assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), message);
+        assert_eq!(serde_json::from_str::<StatusOutput>(&json).unwrap(), status);
+    }
+
+    #[test]
+    fn test_message_rejects_unknown_type() {
+        let json = r#"{"type":"heartbeat","schema_version":1}"#;
+        let result = serde_json::from_str::<Message>(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_message_every_emitted_line_carries_schema_version() {
+        let status = StatusOutput::new(vec![50.0], vec![-20], Strategy::Balanced, 1000);
+        let transition = TransitionOutput::new(vec![-10], vec![-20], 0.5);
+        let error = ErrorOutput::new("code", "message");
+
+        assert!(status.to_json().unwrap().contains("\"schema_version\":1"));
+        assert!(transition.to_json().unwrap().contains("\"schema_version\":1"));
+        assert!(error.to_json().unwrap().contains("\"schema_version\":1"));
+    }
+
+    #[test]
+    fn test_from_ndjson_line_accepts_valid_status() {
+        let status = StatusOutput::new(vec![50.0], vec![-20], Strategy::Balanced, 1000);
+        let result = Message::from_ndjson_line(&status.to_json().unwrap());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_from_ndjson_line_rejects_embedded_newline() {
+        let json = "{\"type\":\"error\",\"schema_version\":1,\n\"code\":\"x\",\"message\":\"y\"}";
+        let result = Message::from_ndjson_line(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("newline"));
+    }
+
+    #[test]
+    fn test_from_ndjson_line_rejects_future_schema_version() {
+        let json = r#"{"type":"error","schema_version":999,"code":"x","message":"y"}"#;
+        let result = Message::from_ndjson_line(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("schema_version"));
+    }
+
+    #[test]
+    fn test_from_ndjson_line_rejects_out_of_range_status_load() {
+        let json = r#"{"type":"status","schema_version":1,"load":[150.0],"values":[-28],"strategy":"balanced","uptime_ms":0}"#;
+        let result = Message::from_ndjson_line(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("out of range"));
+    }
+
+    #[test]
+    fn test_from_ndjson_line_rejects_out_of_range_transition_progress() {
+        // Hand-crafted JSON bypasses TransitionOutput::new's own clamp
+        let json = r#"{"type":"transition","schema_version":1,"from":[-10],"to":[-20],"progress":1.5}"#;
+        let result = Message::from_ndjson_line(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("out of range"));
+    }
+
+    #[test]
+    fn test_from_ndjson_line_rejects_empty_error_code() {
+        let json = r#"{"type":"error","schema_version":1,"code":"","message":"y"}"#;
+        let result = Message::from_ndjson_line(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("code cannot be empty"));
+    }
+
     #[test]
     fn test_output_writer_interval() {
         let writer = OutputWriter::new(1000);
         assert!(writer.should_output()); // First output always allowed
     }
 
+    #[test]
+    fn test_output_writer_default_tick_hz_matches_uptime_ms() {
+        let writer = OutputWriter::new(1000);
+        // At the default 1000 Hz, uptime_ticks and uptime_ms track the same clock
+        assert_eq!(writer.uptime_ticks(), writer.uptime_ms());
+    }
+
+    #[test]
+    fn test_output_writer_tick_hz_scales_ticks() {
+        let writer = OutputWriter::new(1000).with_tick_hz(1_000_000);
+        // At 1 MHz (microsecond ticks), ticks run ~1000x faster than ms
+        assert!(writer.uptime_ticks() >= writer.uptime_ms() * 900);
+    }
+
+    #[test]
+    fn test_output_writer_seq_increments_monotonically() {
+        let mut writer = OutputWriter::new(0);
+        assert_eq!(writer.next_seq(), 1);
+        assert_eq!(writer.next_seq(), 2);
+        assert_eq!(writer.next_seq(), 3);
+    }
+
+    /// In-memory `StatusSink` sharing a `Vec<String>` with the test, so
+    /// `write_status`/`write_transition`/`write_error` can be asserted on
+    /// without touching stdout
+    #[derive(Clone, Default)]
+    struct BufferSink(Arc<Mutex<Vec<String>>>);
+
+    impl StatusSink for BufferSink {
+        fn emit(&mut self, line: &str) -> io::Result<()> {
+            self.0.lock().unwrap().push(line.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_output_writer_with_sink_routes_away_from_stdout() {
+        let sink = BufferSink::default();
+        let mut writer = OutputWriter::new(0).with_sink(sink.clone());
+
+        writer.write_status(vec![10.0], vec![-5], Strategy::Balanced).unwrap();
+        writer.write_transition(vec![-5], vec![-10], 0.5).unwrap();
+        writer.write_error("test_error", "boom").unwrap();
+
+        let lines = sink.0.lock().unwrap();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("\"type\":\"status\""));
+        assert!(lines[1].contains("\"type\":\"transition\""));
+        assert!(lines[2].contains("\"type\":\"error\""));
+    }
+
+    #[test]
+    fn test_adaptive_interval_halves_on_busy_tick() {
+        let mut adaptive = AdaptiveInterval::new(100, 10_000);
+        adaptive.record_emitted(&[10.0], &[-5]);
+        assert_eq!(adaptive.current_ms, 10_000);
+
+        adaptive.observe(&[50.0], &[-5]); // distance 40.0, above busy threshold
+        assert_eq!(adaptive.current_ms, 5_000);
+    }
+
+    #[test]
+    fn test_adaptive_interval_halving_stops_at_min() {
+        let mut adaptive = AdaptiveInterval::new(100, 300);
+        adaptive.record_emitted(&[0.0], &[0]);
+
+        for _ in 0..10 {
+            adaptive.observe(&[99.0], &[0]); // always busy
+        }
+        assert_eq!(adaptive.current_ms, 100);
+    }
+
+    #[test]
+    fn test_adaptive_interval_doubles_after_quiet_streak() {
+        let mut adaptive = AdaptiveInterval::new(100, 10_000);
+        adaptive.current_ms = 100;
+        adaptive.record_emitted(&[10.0], &[-5]);
+
+        adaptive.observe(&[10.2], &[-5]); // distance 0.2, quiet tick 1
+        assert_eq!(adaptive.current_ms, 100);
+        adaptive.observe(&[10.2], &[-5]); // quiet tick 2
+        assert_eq!(adaptive.current_ms, 100);
+        adaptive.observe(&[10.2], &[-5]); // quiet tick 3 - doubles
+        assert_eq!(adaptive.current_ms, 200);
+    }
+
+    #[test]
+    fn test_adaptive_interval_doubling_stops_at_max() {
+        let mut adaptive = AdaptiveInterval::new(100, 300);
+        adaptive.current_ms = 300;
+        adaptive.record_emitted(&[0.0], &[0]);
+
+        for _ in 0..10 {
+            adaptive.observe(&[0.0], &[0]); // always quiet
+        }
+        assert_eq!(adaptive.current_ms, 300);
+    }
+
+    #[test]
+    fn test_adaptive_interval_resets_quiet_streak_on_busy_tick() {
+        let mut adaptive = AdaptiveInterval::new(10, 10_000);
+        adaptive.current_ms = 1_000;
+        adaptive.record_emitted(&[10.0], &[-5]);
+
+        adaptive.observe(&[10.2], &[-5]); // quiet tick 1
+        adaptive.observe(&[10.2], &[-5]); // quiet tick 2
+        adaptive.observe(&[50.0], &[-5]); // busy tick resets the streak and halves
+        adaptive.observe(&[10.2], &[-5]); // quiet tick 1 again, not 3
+        assert_eq!(adaptive.current_ms, 500); // from the busy halving, no further doubling
+    }
+
+    #[test]
+    fn test_output_writer_new_adaptive_tracks_volatility() {
+        let mut writer = OutputWriter::new_adaptive(0, 10_000).with_sink(BufferSink::default());
+
+        // First call always emits (no prior output) and always registers as busy
+        assert!(writer
+            .write_status_if_due(vec![10.0], vec![-5], Strategy::Balanced)
+            .unwrap());
+        assert_eq!(writer.effective_interval_ms(), 5_000);
+    }
+
+    #[test]
+    fn test_output_writer_fixed_interval_ignores_adaptive_state() {
+        let writer = OutputWriter::new(1_000);
+        assert_eq!(writer.effective_interval_ms(), 1_000);
+    }
+
+    #[test]
+    fn test_status_output_with_ticks_round_trips_through_json() {
+        let status = StatusOutput::new(vec![50.0], vec![-20], Strategy::Balanced, 1000)
+            .with_ticks(1_000_000, 7);
+        let json = status.to_json().unwrap();
+        assert!(json.contains("\"uptime_ticks\":1000000"));
+        assert!(json.contains("\"seq\":7"));
+
+        let decoded: StatusOutput = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, status);
+    }
+
+    #[test]
+    fn test_status_output_deserialize_defaults_missing_tick_fields() {
+        // Lines from a producer predating `uptime_ticks`/`seq` still parse
+        let json = r#"{"type":"status","load":[50.0],"values":[-20],"strategy":"balanced","uptime_ms":1000}"#;
+        let status: StatusOutput = serde_json::from_str(json).unwrap();
+        assert_eq!(status.uptime_ticks, 0);
+        assert_eq!(status.seq, 0);
+    }
+
+    #[test]
+    fn test_canonical_json_roundtrip_exact() {
+        let status = StatusOutput::new(
+            vec![45.200, 52.100, 0.0, 100.0],
+            vec![-28, -25, -30, -29],
+            Strategy::Balanced,
+            12500,
+        );
+
+        let json = status.to_canonical_json().unwrap();
+        let decoded = StatusOutput::from_canonical_json(&json).unwrap();
+        assert_eq!(status, decoded);
+    }
+
+    #[test]
+    fn test_canonical_json_has_no_float_tokens() {
+        let status = StatusOutput::new(vec![45.234, 99.999], vec![-28, -25], Strategy::Aggressive, 1);
+        let json = status.to_canonical_json().unwrap();
+        assert!(!json.contains('.'));
+        assert!(!json.contains('e'));
+        assert!(json.contains("\"load_milli_pct\":[45234,99999]"));
+    }
+
+    #[test]
+    fn test_canonical_json_with_fan() {
+        let fan = FanStatusOutput::new(65, 128, 50, "custom", Some(3200), false, "ok", 50);
+        let status = StatusOutput::with_fan(vec![50.0], vec![-25], Strategy::Custom, 5000, fan);
+
+        let json = status.to_canonical_json().unwrap();
+        let decoded = StatusOutput::from_canonical_json(&json).unwrap();
+        assert_eq!(status, decoded);
+    }
+
+    #[test]
+    fn test_status_validation_leeway_tolerates_noise() {
+        let json = r#"{"type":"status","load":[100.2],"values":[-28],"strategy":"balanced","uptime_ms":12500}"#;
+        assert!(StatusValidation::default().validate(json).is_err());
+
+        let lenient = StatusValidation::default().with_leeway(0.5);
+        assert!(lenient.validate(json).is_ok());
+    }
+
+    #[test]
+    fn test_status_validation_max_cores() {
+        let json = r#"{"type":"status","load":[1.0,2.0,3.0],"values":[-1,-2,-3],"strategy":"balanced","uptime_ms":0}"#;
+        let policy = StatusValidation::default().with_max_cores(2);
+        let result = policy.validate(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("max_cores"));
+    }
+
+    #[test]
+    fn test_status_validation_undervolt_range() {
+        let json = r#"{"type":"status","load":[50.0],"values":[-150],"strategy":"balanced","uptime_ms":0}"#;
+        let policy = StatusValidation::default().with_undervolt_range(-100..=0);
+        let result = policy.validate(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("values[0]"));
+    }
+
+    #[test]
+    fn test_status_validation_allowed_strategies() {
+        let json = r#"{"type":"status","load":[50.0],"values":[-20],"strategy":"balanced","uptime_ms":0}"#;
+        let kiosk = StatusValidation::default()
+            .with_allowed_strategies([Strategy::Aggressive].into_iter().collect());
+        let result = kiosk.validate(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not allowed"));
+    }
+
+    #[test]
+    fn test_status_validation_default_matches_validate_status_output() {
+        let json = r#"{"type":"status","load":[45.2],"values":[-28],"strategy":"balanced","uptime_ms":12500}"#;
+        assert_eq!(
+            StatusValidation::default().validate(json).unwrap(),
+            validate_status_output(json).unwrap()
+        );
+    }
+
     #[test]
     fn test_all_strategies_serialize() {
         for strategy in [Strategy::Conservative, Strategy::Balanced, Strategy::Aggressive, Strategy::Custom] {
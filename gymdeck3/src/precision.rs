@@ -0,0 +1,161 @@
+//! Exact-rational validation for decimal text parsed into `f32`
+//!
+//! `f32::from_str` is correctly rounded, but "correctly rounded" still means
+//! the stored value is whatever binary fraction happens to land closest to
+//! the decimal a user typed - e.g. `"2.35"` is stored as `2.3499999`. For
+//! most config fields that's an unnoticeable rounding error; for hysteresis
+//! and undervolt thresholds, which drive hardware behavior directly, users
+//! expect what they typed. This module parses the decimal text into an
+//! exact `BigRational`, reconstructs the parsed `f32`'s own exact value as a
+//! second `BigRational` (`mantissa * 2^exponent`), and compares them, so a
+//! mismatch can be reported instead of silently accepted.
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::Zero;
+
+/// Parse `s` as an `f32`, rejecting any value that cannot be represented
+/// exactly (e.g. `"2.35"`, which f32 stores as `2.3499999`).
+///
+/// Returns the parsed value on an exact match. On a mismatch, or on a
+/// non-finite token (`NaN`, `inf`, `-inf`), returns an error reporting both
+/// the text the caller wrote and the value that would actually be stored.
+pub fn validate_decimal_precision(s: &str) -> Result<f32, String> {
+    let trimmed = s.trim();
+    let unsigned = trimmed.trim_start_matches(['+', '-']);
+    if unsigned.eq_ignore_ascii_case("nan")
+        || unsigned.eq_ignore_ascii_case("inf")
+        || unsigned.eq_ignore_ascii_case("infinity")
+    {
+        return Err(format!("'{}' is not a finite number", s));
+    }
+
+    let value: f32 = trimmed
+        .parse()
+        .map_err(|_| format!("'{}' is not a valid number", s))?;
+    if !value.is_finite() {
+        return Err(format!("'{}' is not a finite number", s));
+    }
+
+    let exact_decimal = exact_decimal_rational(trimmed)
+        .ok_or_else(|| format!("'{}' is not a valid number", s))?;
+    let exact_float = exact_f32_rational(value);
+
+    if exact_decimal == exact_float {
+        Ok(value)
+    } else {
+        Err(format!(
+            "'{}' cannot be represented exactly as f32 (would be stored as {}); \
+             use a value with fewer decimal digits",
+            s, value
+        ))
+    }
+}
+
+/// Parse decimal text "A.B" into the exact rational `(A*10^k + B) / 10^k`
+/// where `k = len(B)`. Returns `None` if `s` isn't plain decimal notation
+/// (no exponent, no hex float, etc. - those are rejected rather than
+/// approximated).
+fn exact_decimal_rational(s: &str) -> Option<BigRational> {
+    let negative = s.starts_with('-');
+    let unsigned = s.trim_start_matches(['+', '-']);
+
+    let (int_part, frac_part) = match unsigned.split_once('.') {
+        Some((i, f)) => (i, f),
+        None => (unsigned, ""),
+    };
+    if int_part.is_empty() && frac_part.is_empty() {
+        return None;
+    }
+    if !int_part.bytes().all(|b| b.is_ascii_digit())
+        || !frac_part.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let int_part = if int_part.is_empty() { "0" } else { int_part };
+    let digits = format!("{}{}", int_part, frac_part);
+    let numerator: BigInt = digits.parse().ok()?;
+    let numerator = if negative { -numerator } else { numerator };
+    let denominator = BigInt::from(10u32).pow(frac_part.len() as u32);
+
+    Some(BigRational::new(numerator, denominator))
+}
+
+/// Reconstruct an `f32`'s exact value as `mantissa * 2^exponent`.
+fn exact_f32_rational(value: f32) -> BigRational {
+    let bits = value.to_bits();
+    let sign_negative = bits >> 31 == 1;
+    let biased_exponent = ((bits >> 23) & 0xff) as i32;
+    let fraction = bits & 0x7f_ffff;
+
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        // Subnormal: no implicit leading bit.
+        (fraction as u64, -126 - 23)
+    } else {
+        // Normal: implicit leading 1 bit.
+        ((fraction as u64) | (1 << 23), biased_exponent - 127 - 23)
+    };
+
+    let mantissa = BigInt::from(mantissa);
+    let two = BigInt::from(2);
+    let magnitude = if exponent >= 0 {
+        BigRational::from_integer(mantissa * two.pow(exponent as u32))
+    } else {
+        BigRational::new(mantissa, two.pow((-exponent) as u32))
+    };
+
+    if sign_negative && !magnitude.is_zero() {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_values_pass() {
+        assert_eq!(validate_decimal_precision("50.0"), Ok(50.0));
+        assert_eq!(validate_decimal_precision("0.5"), Ok(0.5));
+        assert_eq!(validate_decimal_precision("-12.25"), Ok(-12.25));
+        assert_eq!(validate_decimal_precision("0"), Ok(0.0));
+    }
+
+    #[test]
+    fn test_unrepresentable_decimal_is_rejected() {
+        let err = validate_decimal_precision("2.35").unwrap_err();
+        assert!(err.contains("2.35"));
+        assert!(err.contains("2.3499999") || err.contains("2.35"));
+    }
+
+    #[test]
+    fn test_another_unrepresentable_decimal_is_rejected() {
+        assert!(validate_decimal_precision("33.3").is_err());
+    }
+
+    #[test]
+    fn test_nan_is_rejected() {
+        assert!(validate_decimal_precision("NaN").is_err());
+        assert!(validate_decimal_precision("nan").is_err());
+    }
+
+    #[test]
+    fn test_infinity_is_rejected() {
+        assert!(validate_decimal_precision("inf").is_err());
+        assert!(validate_decimal_precision("-infinity").is_err());
+    }
+
+    #[test]
+    fn test_invalid_text_is_rejected() {
+        assert!(validate_decimal_precision("not-a-number").is_err());
+        assert!(validate_decimal_precision("1.2.3").is_err());
+    }
+
+    #[test]
+    fn test_negative_zero_round_trips() {
+        assert!(validate_decimal_precision("-0.0").is_ok());
+    }
+}
@@ -0,0 +1,495 @@
+//! JSON-RPC 2.0 control channel for driving the daemon from a client
+//!
+//! `StatusOutput`, `TransitionOutput`, and `ErrorOutput` are one-way daemon
+//! emissions. This module adds the other direction: a client (e.g. the UI)
+//! sends an `RpcRequest` and the daemon replies with an `RpcResponse`, both
+//! serializing to the same newline-free NDJSON the output tests enforce.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config::Strategy;
+
+/// JSON-RPC error code: the requested method does not exist
+pub const RPC_METHOD_NOT_FOUND: i32 = -32601;
+/// JSON-RPC error code: params were missing or malformed for the method
+pub const RPC_INVALID_PARAMS: i32 = -32602;
+
+/// A JSON-RPC 2.0 request sent by a client to control the daemon
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RpcRequest {
+    /// Protocol version, always "2.0"
+    pub jsonrpc: String,
+    /// Method name, e.g. "set_strategy", "set_undervolt", "set_fan_control", "set_report_mode", "get_status"
+    pub method: String,
+    /// Params, either a positional array or a by-name object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+    /// Request id, echoed back in the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Value>,
+}
+
+impl RpcRequest {
+    /// Create a new JSON-RPC 2.0 request
+    pub fn new(method: impl Into<String>, params: Option<Value>, id: Option<Value>) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            method: method.into(),
+            params,
+            id,
+        }
+    }
+
+    /// Serialize to JSON string
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+/// A JSON-RPC 2.0 error object
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RpcError {
+    /// Numeric error code (e.g. -32601, -32602)
+    pub code: i32,
+    /// Human-readable error message, reusing ErrorOutput's string codes
+    pub message: String,
+    /// Optional additional error data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl RpcError {
+    /// Create a new RPC error
+    pub fn new(code: i32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    /// Build a "method not found" error (-32601) for the given method name
+    pub fn method_not_found(method: &str) -> Self {
+        Self::new(RPC_METHOD_NOT_FOUND, format!("method_not_found: {}", method))
+    }
+
+    /// Build an "invalid params" error (-32602) with the given detail message
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(RPC_INVALID_PARAMS, format!("invalid_params: {}", message.into()))
+    }
+}
+
+/// A JSON-RPC 2.0 response sent by the daemon
+///
+/// Exactly one of `result` or `error` is present, never both.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RpcResponse {
+    /// Protocol version, always "2.0"
+    pub jsonrpc: String,
+    /// Result value on success
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// Error object on failure
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+    /// Id echoed back from the request
+    pub id: Option<Value>,
+}
+
+impl RpcResponse {
+    /// Build a successful response
+    pub fn success(result: Value, id: Option<Value>) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            result: Some(result),
+            error: None,
+            id,
+        }
+    }
+
+    /// Build an error response
+    pub fn failure(error: RpcError, id: Option<Value>) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            result: None,
+            error: Some(error),
+            id,
+        }
+    }
+
+    /// Serialize to JSON string
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+/// Known RPC methods, parsed and validated from an `RpcRequest`
+#[derive(Debug, Clone, PartialEq)]
+pub enum RpcMethodCall {
+    /// Switch the active adaptation strategy
+    SetStrategy(Strategy),
+    /// Set per-core undervolt values directly (mV)
+    SetUndervolt(Vec<i32>),
+    /// Set the hysteresis margin percentage
+    SetHysteresis(f32),
+    /// Set a single core's undervolt bounds and threshold
+    SetCore {
+        core_id: usize,
+        min_mv: i32,
+        max_mv: i32,
+        threshold: f32,
+    },
+    /// Toggle fan control on or off
+    SetFanControl(bool),
+    /// Replace the custom fan curve with a new set of (temp_c, speed_percent)
+    /// points, each validated by `validate_fan_curve_point` before applying
+    SetFanCurve(Vec<(i32, u8)>),
+    /// Toggle the per-tick interpolation/fan `report` NDJSON stream on or off
+    SetReportMode(bool),
+    /// Request the current status
+    GetStatus,
+}
+
+impl RpcMethodCall {
+    /// Parse and validate a request into a known method call
+    ///
+    /// Params may be supplied either as a positional array (first element
+    /// is the relevant argument) or a by-name object.
+    ///
+    /// # Errors
+    /// Returns `RpcError::method_not_found` for unknown methods, and
+    /// `RpcError::invalid_params` for missing or malformed params.
+    pub fn from_request(request: &RpcRequest) -> Result<Self, RpcError> {
+        match request.method.as_str() {
+            "set_strategy" => {
+                let value = extract_param(&request.params, "strategy", 0)
+                    .ok_or_else(|| RpcError::invalid_params("set_strategy requires a 'strategy' param"))?;
+                let strategy: Strategy = serde_json::from_value(value.clone())
+                    .map_err(|e| RpcError::invalid_params(format!("invalid strategy: {}", e)))?;
+                Ok(RpcMethodCall::SetStrategy(strategy))
+            }
+            "set_undervolt" => {
+                let value = extract_param(&request.params, "values", 0)
+                    .ok_or_else(|| RpcError::invalid_params("set_undervolt requires a 'values' param"))?;
+                let values: Vec<i32> = serde_json::from_value(value.clone())
+                    .map_err(|e| RpcError::invalid_params(format!("invalid values: {}", e)))?;
+                Ok(RpcMethodCall::SetUndervolt(values))
+            }
+            "set_hysteresis" => {
+                let value = extract_param(&request.params, "hysteresis", 0).ok_or_else(|| {
+                    RpcError::invalid_params("set_hysteresis requires a 'hysteresis' param")
+                })?;
+                let hysteresis: f32 = serde_json::from_value(value.clone())
+                    .map_err(|e| RpcError::invalid_params(format!("invalid hysteresis: {}", e)))?;
+                Ok(RpcMethodCall::SetHysteresis(hysteresis))
+            }
+            "set_core" => {
+                let core_id = extract_param(&request.params, "core_id", 0)
+                    .ok_or_else(|| RpcError::invalid_params("set_core requires a 'core_id' param"))?;
+                let min_mv = extract_param(&request.params, "min_mv", 1)
+                    .ok_or_else(|| RpcError::invalid_params("set_core requires a 'min_mv' param"))?;
+                let max_mv = extract_param(&request.params, "max_mv", 2)
+                    .ok_or_else(|| RpcError::invalid_params("set_core requires a 'max_mv' param"))?;
+                let threshold = extract_param(&request.params, "threshold", 3)
+                    .ok_or_else(|| RpcError::invalid_params("set_core requires a 'threshold' param"))?;
+
+                Ok(RpcMethodCall::SetCore {
+                    core_id: parse_param(core_id, "core_id")?,
+                    min_mv: parse_param(min_mv, "min_mv")?,
+                    max_mv: parse_param(max_mv, "max_mv")?,
+                    threshold: parse_param(threshold, "threshold")?,
+                })
+            }
+            "set_fan_control" => {
+                let value = extract_param(&request.params, "enabled", 0).ok_or_else(|| {
+                    RpcError::invalid_params("set_fan_control requires an 'enabled' param")
+                })?;
+                Ok(RpcMethodCall::SetFanControl(parse_param(value, "enabled")?))
+            }
+            "set_fan_curve" => {
+                let value = extract_param(&request.params, "points", 0).ok_or_else(|| {
+                    RpcError::invalid_params("set_fan_curve requires a 'points' param")
+                })?;
+                let points: Vec<(i32, u8)> = serde_json::from_value(value.clone())
+                    .map_err(|e| RpcError::invalid_params(format!("invalid points: {}", e)))?;
+                Ok(RpcMethodCall::SetFanCurve(points))
+            }
+            "set_report_mode" => {
+                let value = extract_param(&request.params, "enabled", 0).ok_or_else(|| {
+                    RpcError::invalid_params("set_report_mode requires an 'enabled' param")
+                })?;
+                Ok(RpcMethodCall::SetReportMode(parse_param(value, "enabled")?))
+            }
+            "get_status" => Ok(RpcMethodCall::GetStatus),
+            other => Err(RpcError::method_not_found(other)),
+        }
+    }
+}
+
+/// Extract a param by name (object form) or index (array form)
+fn extract_param<'a>(params: &'a Option<Value>, name: &str, index: usize) -> Option<&'a Value> {
+    match params {
+        Some(Value::Object(map)) => map.get(name),
+        Some(Value::Array(arr)) => arr.get(index),
+        _ => None,
+    }
+}
+
+/// Deserialize a single extracted param value, wrapping failures as
+/// `RpcError::invalid_params`
+fn parse_param<T: serde::de::DeserializeOwned>(value: &Value, name: &str) -> Result<T, RpcError> {
+    serde_json::from_value(value.clone())
+        .map_err(|e| RpcError::invalid_params(format!("invalid {}: {}", name, e)))
+}
+
+/// Validate that a JSON string decodes to a well-formed `RpcRequest`
+///
+/// Mirrors `validate_status_output`: checks the protocol version and
+/// that `method` is non-empty.
+pub fn validate_rpc_request(json_str: &str) -> Result<RpcRequest, String> {
+    let request: RpcRequest = serde_json::from_str(json_str)
+        .map_err(|e| format!("Invalid JSON: {}", e))?;
+
+    if request.jsonrpc != "2.0" {
+        return Err(format!("Expected jsonrpc '2.0', got '{}'", request.jsonrpc));
+    }
+
+    if request.method.is_empty() {
+        return Err("method cannot be empty".to_string());
+    }
+
+    Ok(request)
+}
+
+/// Validate that a JSON string decodes to a well-formed `RpcResponse`
+///
+/// Checks the protocol version and that exactly one of `result`/`error`
+/// is present.
+pub fn validate_rpc_response(json_str: &str) -> Result<RpcResponse, String> {
+    let response: RpcResponse = serde_json::from_str(json_str)
+        .map_err(|e| format!("Invalid JSON: {}", e))?;
+
+    if response.jsonrpc != "2.0" {
+        return Err(format!("Expected jsonrpc '2.0', got '{}'", response.jsonrpc));
+    }
+
+    match (&response.result, &response.error) {
+        (Some(_), None) | (None, Some(_)) => Ok(response),
+        (Some(_), Some(_)) => Err("response cannot have both 'result' and 'error'".to_string()),
+        (None, None) => Err("response must have either 'result' or 'error'".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rpc_request_serialization() {
+        let request = RpcRequest::new("get_status", None, Some(Value::from(1)));
+        let json = request.to_json().unwrap();
+        assert!(json.contains("\"jsonrpc\":\"2.0\""));
+        assert!(json.contains("\"method\":\"get_status\""));
+        assert!(json.contains("\"id\":1"));
+    }
+
+    #[test]
+    fn test_rpc_response_success() {
+        let response = RpcResponse::success(Value::from("ok"), Some(Value::from(1)));
+        let json = response.to_json().unwrap();
+        assert!(json.contains("\"result\":\"ok\""));
+        assert!(!json.contains("\"error\""));
+    }
+
+    #[test]
+    fn test_rpc_response_failure() {
+        let response = RpcResponse::failure(RpcError::method_not_found("bogus"), Some(Value::from(1)));
+        let json = response.to_json().unwrap();
+        assert!(json.contains("\"code\":-32601"));
+        assert!(!json.contains("\"result\""));
+    }
+
+    #[test]
+    fn test_set_strategy_positional_params() {
+        let request = RpcRequest::new(
+            "set_strategy",
+            Some(Value::Array(vec![Value::from("aggressive")])),
+            None,
+        );
+        let call = RpcMethodCall::from_request(&request).unwrap();
+        assert_eq!(call, RpcMethodCall::SetStrategy(Strategy::Aggressive));
+    }
+
+    #[test]
+    fn test_set_strategy_named_params() {
+        let request = RpcRequest::new(
+            "set_strategy",
+            Some(serde_json::json!({"strategy": "balanced"})),
+            None,
+        );
+        let call = RpcMethodCall::from_request(&request).unwrap();
+        assert_eq!(call, RpcMethodCall::SetStrategy(Strategy::Balanced));
+    }
+
+    #[test]
+    fn test_set_undervolt_params() {
+        let request = RpcRequest::new(
+            "set_undervolt",
+            Some(serde_json::json!({"values": [-20, -25, -30, -35]})),
+            None,
+        );
+        let call = RpcMethodCall::from_request(&request).unwrap();
+        assert_eq!(call, RpcMethodCall::SetUndervolt(vec![-20, -25, -30, -35]));
+    }
+
+    #[test]
+    fn test_set_hysteresis_params() {
+        let request = RpcRequest::new("set_hysteresis", Some(serde_json::json!({"hysteresis": 7.5})), None);
+        let call = RpcMethodCall::from_request(&request).unwrap();
+        assert_eq!(call, RpcMethodCall::SetHysteresis(7.5));
+    }
+
+    #[test]
+    fn test_set_core_named_params() {
+        let request = RpcRequest::new(
+            "set_core",
+            Some(serde_json::json!({"core_id": 1, "min_mv": -20, "max_mv": -35, "threshold": 50.0})),
+            None,
+        );
+        let call = RpcMethodCall::from_request(&request).unwrap();
+        assert_eq!(
+            call,
+            RpcMethodCall::SetCore {
+                core_id: 1,
+                min_mv: -20,
+                max_mv: -35,
+                threshold: 50.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_set_core_missing_field_returns_invalid_params() {
+        let request = RpcRequest::new(
+            "set_core",
+            Some(serde_json::json!({"core_id": 1, "min_mv": -20})),
+            None,
+        );
+        let err = RpcMethodCall::from_request(&request).unwrap_err();
+        assert_eq!(err.code, RPC_INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_set_fan_control_params() {
+        let request = RpcRequest::new("set_fan_control", Some(serde_json::json!({"enabled": true})), None);
+        let call = RpcMethodCall::from_request(&request).unwrap();
+        assert_eq!(call, RpcMethodCall::SetFanControl(true));
+    }
+
+    #[test]
+    fn test_set_report_mode_params() {
+        let request = RpcRequest::new("set_report_mode", Some(serde_json::json!({"enabled": true})), None);
+        let call = RpcMethodCall::from_request(&request).unwrap();
+        assert_eq!(call, RpcMethodCall::SetReportMode(true));
+    }
+
+    #[test]
+    fn test_set_fan_curve_params() {
+        let request = RpcRequest::new(
+            "set_fan_curve",
+            Some(serde_json::json!({"points": [[40, 0], [60, 50], [85, 100]]})),
+            None,
+        );
+        let call = RpcMethodCall::from_request(&request).unwrap();
+        assert_eq!(
+            call,
+            RpcMethodCall::SetFanCurve(vec![(40, 0), (60, 50), (85, 100)])
+        );
+    }
+
+    #[test]
+    fn test_set_fan_curve_malformed_points_returns_invalid_params() {
+        let request = RpcRequest::new(
+            "set_fan_curve",
+            Some(serde_json::json!({"points": "not an array"})),
+            None,
+        );
+        let err = RpcMethodCall::from_request(&request).unwrap_err();
+        assert_eq!(err.code, RPC_INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_get_status_no_params() {
+        let request = RpcRequest::new("get_status", None, None);
+        let call = RpcMethodCall::from_request(&request).unwrap();
+        assert_eq!(call, RpcMethodCall::GetStatus);
+    }
+
+    #[test]
+    fn test_unknown_method_returns_method_not_found() {
+        let request = RpcRequest::new("delete_everything", None, None);
+        let err = RpcMethodCall::from_request(&request).unwrap_err();
+        assert_eq!(err.code, RPC_METHOD_NOT_FOUND);
+    }
+
+    #[test]
+    fn test_missing_params_returns_invalid_params() {
+        let request = RpcRequest::new("set_strategy", None, None);
+        let err = RpcMethodCall::from_request(&request).unwrap_err();
+        assert_eq!(err.code, RPC_INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_malformed_params_returns_invalid_params() {
+        let request = RpcRequest::new(
+            "set_undervolt",
+            Some(serde_json::json!({"values": "not an array"})),
+            None,
+        );
+        let err = RpcMethodCall::from_request(&request).unwrap_err();
+        assert_eq!(err.code, RPC_INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_validate_rpc_request_valid() {
+        let json = r#"{"jsonrpc":"2.0","method":"get_status","id":1}"#;
+        assert!(validate_rpc_request(json).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rpc_request_bad_version() {
+        let json = r#"{"jsonrpc":"1.0","method":"get_status","id":1}"#;
+        let result = validate_rpc_request(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("jsonrpc"));
+    }
+
+    #[test]
+    fn test_validate_rpc_request_empty_method() {
+        let json = r#"{"jsonrpc":"2.0","method":"","id":1}"#;
+        let result = validate_rpc_request(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("method cannot be empty"));
+    }
+
+    #[test]
+    fn test_validate_rpc_response_valid_success() {
+        let json = r#"{"jsonrpc":"2.0","result":"ok","id":1}"#;
+        assert!(validate_rpc_response(json).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rpc_response_valid_error() {
+        let json = r#"{"jsonrpc":"2.0","error":{"code":-32601,"message":"method_not_found: x"},"id":1}"#;
+        assert!(validate_rpc_response(json).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rpc_response_missing_both() {
+        let json = r#"{"jsonrpc":"2.0","id":1}"#;
+        let result = validate_rpc_response(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("must have either"));
+    }
+}
@@ -0,0 +1,1196 @@
+//! Ryzenadj subprocess wrapper for applying undervolt values
+//!
+//! This module provides a wrapper around the ryzenadj binary for applying
+//! undervolt values to AMD APU cores. It handles subprocess execution,
+//! error tracking, and consecutive failure detection.
+//!
+//! Optionally (via [`RyzenadjExecutor::with_max_step_mv`]), applies are
+//! slew-rate limited: each call steps the per-core values toward the
+//! requested target by at most `max_step_mv` rather than jumping straight
+//! there, and a failure backs the target off toward zero and reverts to
+//! the last known-good values before the next ramp attempt.
+//!
+//! [`RyzenadjExecutor::apply_verified`] additionally reads the applied
+//! values back via `ryzenadj --info` and treats a readback mismatch as a
+//! failure, since a zero exit code alone doesn't guarantee the BIOS
+//! actually accepted the requested offset.
+
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::process::Command;
+
+/// Maximum number of consecutive failures before exit
+pub const MAX_CONSECUTIVE_FAILURES: u32 = 5;
+
+/// Default bound on how long the async reset path waits for `ryzenadj`
+/// before killing it and reporting a timeout
+///
+/// Long enough for a healthy call to complete, short enough that a wedged
+/// `ryzenadj` process can't block the watchdog's own recovery attempt.
+pub const DEFAULT_RESET_TIMEOUT_MS: u64 = 2_000;
+
+/// Default bound on how long `apply` waits for `ryzenadj` before killing it
+/// and counting the attempt as a failure
+///
+/// A hang or deadlock inside `ryzenadj` must not be allowed to block the
+/// caller indefinitely; treating it as an ordinary failure lets the normal
+/// `MAX_CONSECUTIVE_FAILURES` path handle it.
+pub const DEFAULT_APPLY_TIMEOUT_MS: u64 = 2_000;
+
+/// Default amount (mV, toward zero) a failed apply backs the requested
+/// target off by, via [`RyzenadjExecutor::with_failure_backoff_mv`]
+pub const DEFAULT_FAILURE_BACKOFF_MV: u32 = 10;
+
+/// Default tolerance (mV) allowed between a requested value and
+/// `apply_verified`'s `--info` readback before it's treated as a mismatch
+pub const DEFAULT_VERIFICATION_TOLERANCE_MV: i32 = 2;
+
+/// Parse per-core curve-optimizer offsets out of `ryzenadj --info`'s
+/// tabular stdout
+///
+/// Expects one line per core of the form `CORE <n> CO OFFSET: <mV> mV`,
+/// the layout ryzenadj's info mode reports per-core curve-optimizer values
+/// in. Lines that don't match are ignored, and any core absent from the
+/// output (some BIOS/firmware combinations only report a subset) is left
+/// `None` rather than treated as an error - a missing reading is
+/// unverifiable, not a mismatch.
+fn parse_curve_optimizer_offsets(info_output: &str) -> Vec<Option<i32>> {
+    let mut offsets: Vec<Option<i32>> = Vec::new();
+
+    for line in info_output.lines() {
+        let line = line.trim();
+        let Some(rest) = line.strip_prefix("CORE") else {
+            continue;
+        };
+        let Some((core_part, value_part)) = rest.split_once("CO OFFSET:") else {
+            continue;
+        };
+        let Ok(core_idx) = core_part.trim().parse::<usize>() else {
+            continue;
+        };
+        let Ok(value) = value_part.trim().trim_end_matches("mV").trim().parse::<i32>() else {
+            continue;
+        };
+
+        if offsets.len() <= core_idx {
+            offsets.resize(core_idx + 1, None);
+        }
+        offsets[core_idx] = Some(value);
+    }
+
+    offsets
+}
+
+/// Move `from` toward `to` by at most `max_step`, without overshooting
+fn move_toward(from: i32, to: i32, max_step: u32) -> i32 {
+    let diff = to - from;
+    if diff.unsigned_abs() <= max_step {
+        to
+    } else if diff > 0 {
+        from + max_step as i32
+    } else {
+        from - max_step as i32
+    }
+}
+
+/// Error types for ryzenadj operations
+#[derive(Debug, Clone, PartialEq)]
+pub enum RyzenadjError {
+    /// Binary not found at specified path
+    BinaryNotFound(String),
+    /// Command execution failed
+    ExecutionFailed(String),
+    /// Command returned non-zero exit code
+    NonZeroExit { code: i32, stderr: String },
+    /// Maximum consecutive failures reached
+    MaxFailuresReached(u32),
+    /// The command did not complete within the bounded timeout and was
+    /// killed
+    TimedOut(Duration),
+    /// This config's hash matches an entry in the failure corpus, so the
+    /// apply was refused before `ryzenadj` was even spawned
+    KnownBadConfig,
+    /// `apply_verified`'s readback of `--info` disagreed with the requested
+    /// value for `core` by more than the configured tolerance
+    VerificationMismatch {
+        core: usize,
+        requested: i32,
+        reported: i32,
+    },
+}
+
+impl std::fmt::Display for RyzenadjError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RyzenadjError::BinaryNotFound(path) => {
+                write!(f, "ryzenadj binary not found at: {}", path)
+            }
+            RyzenadjError::ExecutionFailed(msg) => {
+                write!(f, "ryzenadj execution failed: {}", msg)
+            }
+            RyzenadjError::NonZeroExit { code, stderr } => {
+                write!(f, "ryzenadj exited with code {}: {}", code, stderr)
+            }
+            RyzenadjError::MaxFailuresReached(count) => {
+                write!(f, "ryzenadj failed {} consecutive times", count)
+            }
+            RyzenadjError::TimedOut(timeout) => {
+                write!(f, "ryzenadj did not exit within {:?} and was killed", timeout)
+            }
+            RyzenadjError::KnownBadConfig => {
+                write!(f, "refusing to apply: this config previously caused a failure on this hardware")
+            }
+            RyzenadjError::VerificationMismatch { core, requested, reported } => {
+                write!(
+                    f,
+                    "core {} readback mismatch: requested {} mV, ryzenadj reports {} mV",
+                    core, requested, reported
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for RyzenadjError {}
+
+/// Configs previously known to trigger a `ryzenadj` failure on this
+/// hardware, persisted to a small on-disk file so the record survives a
+/// reboot
+///
+/// Each entry is the config's hash (see [`FailureCorpus::hash_config`])
+/// written as one hex line; the file format is intentionally trivial so a
+/// corrupt or partial file can never prevent startup.
+#[derive(Debug)]
+pub struct FailureCorpus {
+    path: PathBuf,
+    hashes: HashSet<u64>,
+}
+
+impl FailureCorpus {
+    /// Load a corpus from `path`
+    ///
+    /// Starts empty if the file doesn't exist or a line fails to parse —
+    /// losing the corpus is far less harmful than refusing to start.
+    pub fn load(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let hashes = std::fs::read_to_string(&path)
+            .map(|contents| {
+                contents
+                    .lines()
+                    .filter_map(|line| u64::from_str_radix(line.trim(), 16).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { path, hashes }
+    }
+
+    /// Hash a config the same way [`FailureCorpus::contains`] and
+    /// [`FailureCorpus::record`] key it
+    pub fn hash_config(values: &[i32]) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        values.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Whether `values` previously triggered a failure
+    pub fn contains(&self, values: &[i32]) -> bool {
+        self.hashes.contains(&Self::hash_config(values))
+    }
+
+    /// Number of distinct known-bad configs recorded
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    /// Whether no known-bad configs are recorded
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+
+    /// Record `values` as known-bad and append its hash to the on-disk
+    /// file
+    ///
+    /// A no-op if the config is already recorded.
+    pub fn record(&mut self, values: &[i32]) -> std::io::Result<()> {
+        let hash = Self::hash_config(values);
+        if !self.hashes.insert(hash) {
+            return Ok(());
+        }
+        use std::io::Write;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{:016x}", hash)
+    }
+}
+
+/// Result of a ryzenadj apply operation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApplyResult {
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// Current consecutive failure count (0 if success)
+    pub consecutive_failures: u32,
+}
+
+/// Executor for ryzenadj subprocess calls
+#[derive(Debug)]
+pub struct RyzenadjExecutor {
+    /// Path to the ryzenadj binary
+    binary_path: PathBuf,
+    /// Count of consecutive failures
+    consecutive_failures: u32,
+    /// Maximum allowed consecutive failures
+    max_failures: u32,
+    /// Known-bad configs from previous runs, consulted before each apply
+    failure_corpus: Option<FailureCorpus>,
+    /// Largest per-core change (mV) a single apply is allowed to make
+    /// toward its target; unbounded when `None`
+    max_step_mv: Option<u32>,
+    /// Amount (mV, toward zero) each consecutive failure backs the
+    /// requested target off by
+    failure_backoff_mv: u32,
+    /// Total backoff (mV, toward zero) currently applied to every target,
+    /// accumulated across consecutive failures and cleared on success
+    accumulated_backoff_mv: u32,
+    /// Per-core values last sent to `ryzenadj`, the ramp's current position
+    current_values: Option<Vec<i32>>,
+    /// Per-core values from the last successful apply, restored as the
+    /// ramp's position after a failure
+    last_good_values: Option<Vec<i32>>,
+}
+
+impl RyzenadjExecutor {
+    /// Create a new RyzenadjExecutor with the specified binary path
+    ///
+    /// # Arguments
+    /// * `binary_path` - Path to the ryzenadj binary
+    pub fn new(binary_path: &str) -> Self {
+        Self {
+            binary_path: PathBuf::from(binary_path),
+            consecutive_failures: 0,
+            max_failures: MAX_CONSECUTIVE_FAILURES,
+            failure_corpus: None,
+            max_step_mv: None,
+            failure_backoff_mv: DEFAULT_FAILURE_BACKOFF_MV,
+            accumulated_backoff_mv: 0,
+            current_values: None,
+            last_good_values: None,
+        }
+    }
+
+    /// Create a new RyzenadjExecutor with custom max failures (for testing)
+    ///
+    /// # Arguments
+    /// * `binary_path` - Path to the ryzenadj binary
+    /// * `max_failures` - Maximum consecutive failures before error
+    pub fn with_max_failures(binary_path: &str, max_failures: u32) -> Self {
+        Self {
+            binary_path: PathBuf::from(binary_path),
+            consecutive_failures: 0,
+            max_failures,
+            failure_corpus: None,
+            max_step_mv: None,
+            failure_backoff_mv: DEFAULT_FAILURE_BACKOFF_MV,
+            accumulated_backoff_mv: 0,
+            current_values: None,
+            last_good_values: None,
+        }
+    }
+
+    /// Attach a persisted failure corpus, consulted before every apply so a
+    /// config that previously faulted this hardware is refused instead of
+    /// re-applied
+    pub fn with_failure_corpus(mut self, corpus: FailureCorpus) -> Self {
+        self.failure_corpus = Some(corpus);
+        self
+    }
+
+    /// The attached failure corpus, if any
+    pub fn failure_corpus(&self) -> Option<&FailureCorpus> {
+        self.failure_corpus.as_ref()
+    }
+
+    /// Bound each apply's per-core change to at most `max_step_mv` toward
+    /// its target, ramping gradually across multiple calls instead of
+    /// jumping straight to the requested values
+    pub fn with_max_step_mv(mut self, max_step_mv: u32) -> Self {
+        self.max_step_mv = Some(max_step_mv);
+        self
+    }
+
+    /// Set how much (mV, toward zero) each consecutive failure backs the
+    /// requested target off by; default is `DEFAULT_FAILURE_BACKOFF_MV`
+    pub fn with_failure_backoff_mv(mut self, failure_backoff_mv: u32) -> Self {
+        self.failure_backoff_mv = failure_backoff_mv;
+        self
+    }
+
+    /// The per-core values last sent to `ryzenadj`, if any apply has
+    /// succeeded yet
+    pub fn current_values(&self) -> Option<&[i32]> {
+        self.current_values.as_deref()
+    }
+
+    /// The per-core values from the last successful apply, if any
+    pub fn last_good_values(&self) -> Option<&[i32]> {
+        self.last_good_values.as_deref()
+    }
+
+    /// Total backoff (mV, toward zero) currently applied to every target
+    pub fn accumulated_backoff_mv(&self) -> u32 {
+        self.accumulated_backoff_mv
+    }
+
+    /// Get the current consecutive failure count
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Get the maximum allowed consecutive failures
+    pub fn max_failures(&self) -> u32 {
+        self.max_failures
+    }
+
+    /// Get the binary path
+    pub fn binary_path(&self) -> &PathBuf {
+        &self.binary_path
+    }
+
+    /// Reset the consecutive failure counter
+    pub fn reset_failures(&mut self) {
+        self.consecutive_failures = 0;
+    }
+
+    /// Record a failure and check if max failures reached
+    ///
+    /// # Returns
+    /// * `Ok(count)` - Current failure count if under max
+    /// * `Err(MaxFailuresReached)` - If max failures reached
+    fn record_failure(&mut self) -> Result<u32, RyzenadjError> {
+        self.consecutive_failures += 1;
+        if self.consecutive_failures >= self.max_failures {
+            Err(RyzenadjError::MaxFailuresReached(self.consecutive_failures))
+        } else {
+            Ok(self.consecutive_failures)
+        }
+    }
+
+    /// Record a success and reset failure counter
+    fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+    }
+
+    /// Build the ryzenadj command arguments for applying undervolt values
+    ///
+    /// # Arguments
+    /// * `values` - Slice of undervolt values in mV for each core
+    ///
+    /// # Returns
+    /// Vector of command arguments
+    fn build_args(&self, values: &[i32]) -> Vec<String> {
+        let mut args = Vec::new();
+        
+        // ryzenadj uses --set-coall for setting all cores at once
+        // or individual core settings with --set-coper-N
+        // For batching, we use --set-coall with the average or
+        // individual per-core settings
+        
+        // Using per-core undervolt settings
+        for (core_idx, &value) in values.iter().enumerate() {
+            // ryzenadj expects positive values for undervolt offset
+            // Convert our negative mV to the format ryzenadj expects
+            let abs_value = value.abs();
+            args.push(format!("--set-coper-{}", core_idx));
+            args.push(format!("{}", abs_value));
+        }
+        
+        args
+    }
+
+    /// Apply undervolt values to all cores, bounded by
+    /// `DEFAULT_APPLY_TIMEOUT_MS`
+    ///
+    /// Prefer [`RyzenadjExecutor::apply_bounded`] with an explicit timeout
+    /// in contexts that need a different bound.
+    ///
+    /// # Arguments
+    /// * `values` - Slice of undervolt values in mV for each core
+    ///
+    /// # Returns
+    /// * `Ok(ApplyResult)` - Result with success status and failure count
+    /// * `Err(RyzenadjError)` - If max failures reached or critical error
+    pub async fn apply(&mut self, values: &[i32]) -> Result<ApplyResult, RyzenadjError> {
+        self.apply_bounded(values, Duration::from_millis(DEFAULT_APPLY_TIMEOUT_MS))
+            .await
+    }
+
+    /// Apply undervolt values to all cores in an isolated child process,
+    /// killing `ryzenadj` and counting the attempt as a failure if it
+    /// doesn't exit within `timeout`
+    ///
+    /// If a [`FailureCorpus`] is attached and `values` hashes to a
+    /// previously recorded failure, the apply is refused without spawning
+    /// `ryzenadj` at all. Any failure (timeout, non-zero exit, or execution
+    /// error) is persisted to the corpus so it's remembered across reboots.
+    ///
+    /// # Arguments
+    /// * `values` - Slice of undervolt values in mV for each core
+    /// * `timeout` - Upper bound on how long to wait for `ryzenadj` to exit
+    ///
+    /// # Returns
+    /// * `Ok(ApplyResult)` - Result with success status and failure count
+    /// * `Err(RyzenadjError)` - If max failures reached, the config is
+    ///   known-bad, or a critical error occurred
+    pub async fn apply_bounded(
+        &mut self,
+        values: &[i32],
+        timeout: Duration,
+    ) -> Result<ApplyResult, RyzenadjError> {
+        let effective_target: Vec<i32> = values
+            .iter()
+            .map(|&v| Self::back_off_toward_zero(v, self.accumulated_backoff_mv))
+            .collect();
+
+        let step_values: Vec<i32> = match self.max_step_mv {
+            Some(max_step) => {
+                let base = self
+                    .current_values
+                    .clone()
+                    .unwrap_or_else(|| vec![0; effective_target.len()]);
+                base.iter()
+                    .zip(effective_target.iter())
+                    .map(|(&from, &to)| move_toward(from, to, max_step))
+                    .collect()
+            }
+            None => effective_target,
+        };
+
+        if let Some(corpus) = &self.failure_corpus {
+            if corpus.contains(&step_values) {
+                return Err(RyzenadjError::KnownBadConfig);
+            }
+        }
+
+        let args = self.build_args(&step_values);
+
+        let outcome = match tokio::time::timeout(timeout, self.execute_command(&args)).await {
+            Ok(result) => result,
+            Err(_elapsed) => Err(RyzenadjError::TimedOut(timeout)),
+        };
+
+        match outcome {
+            Ok(()) => {
+                self.record_success();
+                self.accumulated_backoff_mv = 0;
+                self.current_values = Some(step_values.clone());
+                self.last_good_values = Some(step_values);
+                Ok(ApplyResult {
+                    success: true,
+                    consecutive_failures: 0,
+                })
+            }
+            Err(e) => {
+                if let Some(corpus) = &mut self.failure_corpus {
+                    if let Err(io_err) = corpus.record(&step_values) {
+                        eprintln!("Warning: failed to persist failing config to corpus: {}", io_err);
+                    }
+                }
+                self.accumulated_backoff_mv += self.failure_backoff_mv;
+                self.current_values = self.last_good_values.clone();
+                // Record failure and check if we've hit the limit
+                match self.record_failure() {
+                    Ok(count) => {
+                        // Under the limit, return result with failure info
+                        eprintln!("ryzenadj failed (attempt {}): {}", count, e);
+                        Ok(ApplyResult {
+                            success: false,
+                            consecutive_failures: count,
+                        })
+                    }
+                    Err(max_err) => {
+                        // Hit the limit, propagate the error
+                        Err(max_err)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Shift `value` toward zero by `backoff`, without crossing it
+    fn back_off_toward_zero(value: i32, backoff: u32) -> i32 {
+        if value > 0 {
+            (value - backoff as i32).max(0)
+        } else {
+            (value + backoff as i32).min(0)
+        }
+    }
+
+    /// Reset all undervolt values to zero (safe state), bounded by
+    /// `DEFAULT_RESET_TIMEOUT_MS`
+    ///
+    /// This is called during shutdown or panic recovery. Prefer
+    /// [`RyzenadjExecutor::reset_to_zero_bounded`] with an explicit timeout
+    /// in contexts (like the watchdog's own recovery attempt) where a
+    /// wedged `ryzenadj` call must not be allowed to block the caller
+    /// indefinitely.
+    pub async fn reset_to_zero(&mut self, num_cores: usize) -> Result<(), RyzenadjError> {
+        self.reset_to_zero_bounded(num_cores, Duration::from_millis(DEFAULT_RESET_TIMEOUT_MS))
+            .await
+    }
+
+    /// Reset all undervolt values to zero (safe state), killing `ryzenadj`
+    /// and reporting [`RyzenadjError::TimedOut`] if it doesn't exit within
+    /// `timeout`
+    ///
+    /// # Arguments
+    /// * `num_cores` - Number of CPU cores to reset
+    /// * `timeout` - Upper bound on how long to wait for `ryzenadj` to exit
+    pub async fn reset_to_zero_bounded(
+        &mut self,
+        num_cores: usize,
+        timeout: Duration,
+    ) -> Result<(), RyzenadjError> {
+        let values: Vec<i32> = vec![0; num_cores];
+        let args = self.build_args(&values);
+
+        // For reset, we don't track failures - just try once
+        match tokio::time::timeout(timeout, self.execute_command(&args)).await {
+            Ok(result) => result,
+            Err(_elapsed) => Err(RyzenadjError::TimedOut(timeout)),
+        }
+    }
+
+    /// Execute the ryzenadj command with given arguments
+    async fn execute_command(&self, args: &[String]) -> Result<(), RyzenadjError> {
+        let output = Command::new(&self.binary_path)
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            // Killed if this future (e.g. under `tokio::time::timeout`) is
+            // dropped before the child exits, so a bounded reset attempt
+            // can't leak an orphaned, still-wedged `ryzenadj` process.
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    RyzenadjError::BinaryNotFound(self.binary_path.display().to_string())
+                } else {
+                    RyzenadjError::ExecutionFailed(e.to_string())
+                }
+            })?;
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            let code = output.status.code().unwrap_or(-1);
+            Err(RyzenadjError::NonZeroExit { code, stderr })
+        }
+    }
+
+    /// Run `ryzenadj --info` and return its stdout
+    async fn query_info(&self) -> Result<String, RyzenadjError> {
+        let output = Command::new(&self.binary_path)
+            .arg("--info")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .output()
+            .await
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    RyzenadjError::BinaryNotFound(self.binary_path.display().to_string())
+                } else {
+                    RyzenadjError::ExecutionFailed(e.to_string())
+                }
+            })?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).to_string())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+            let code = output.status.code().unwrap_or(-1);
+            Err(RyzenadjError::NonZeroExit { code, stderr })
+        }
+    }
+
+    /// Apply undervolt values and verify them via a `--info` readback,
+    /// bounded by `DEFAULT_APPLY_TIMEOUT_MS` and
+    /// `DEFAULT_VERIFICATION_TOLERANCE_MV`
+    ///
+    /// Prefer [`RyzenadjExecutor::apply_verified_bounded`] for an explicit
+    /// timeout or tolerance.
+    pub async fn apply_verified(&mut self, values: &[i32]) -> Result<ApplyResult, RyzenadjError> {
+        self.apply_verified_bounded(
+            values,
+            Duration::from_millis(DEFAULT_APPLY_TIMEOUT_MS),
+            DEFAULT_VERIFICATION_TOLERANCE_MV,
+        )
+        .await
+    }
+
+    /// Apply undervolt values, then re-query `ryzenadj --info` and confirm
+    /// each core's reported offset is within `tolerance_mv` of what was
+    /// requested
+    ///
+    /// `ryzenadj` can silently clamp or reject a value on some BIOS
+    /// versions and still exit zero, so an apply this function reports as
+    /// successful is one it has actually confirmed on hardware, not just
+    /// one the subprocess didn't complain about.
+    ///
+    /// A core missing from the `--info` output is treated as unverifiable,
+    /// not a mismatch, and skipped. A genuine mismatch feeds the same
+    /// consecutive-failure counter as an ordinary apply failure: under the
+    /// limit it's reported as [`RyzenadjError::VerificationMismatch`],
+    /// and reaching the limit propagates [`RyzenadjError::MaxFailuresReached`]
+    /// same as any other repeated failure.
+    pub async fn apply_verified_bounded(
+        &mut self,
+        values: &[i32],
+        timeout: Duration,
+        tolerance_mv: i32,
+    ) -> Result<ApplyResult, RyzenadjError> {
+        // `apply_bounded`'s own success path resets `consecutive_failures`
+        // to 0, so a mismatch found below has to build on the count from
+        // before this call, not the post-reset one, for repeated mismatches
+        // to ever accumulate toward `max_failures`.
+        let failures_before_this_call = self.consecutive_failures;
+
+        let result = self.apply_bounded(values, timeout).await?;
+        if !result.success {
+            return Ok(result);
+        }
+
+        let applied_values = self
+            .current_values
+            .clone()
+            .expect("current_values is set by a successful apply_bounded");
+
+        let info_output = match tokio::time::timeout(timeout, self.query_info()).await {
+            Ok(Ok(output)) => output,
+            Ok(Err(e)) => return Err(e),
+            Err(_elapsed) => return Err(RyzenadjError::TimedOut(timeout)),
+        };
+        let reported = parse_curve_optimizer_offsets(&info_output);
+
+        for (core, &requested) in applied_values.iter().enumerate() {
+            let Some(reported_value) = reported.get(core).copied().flatten() else {
+                continue;
+            };
+            if (reported_value - requested).abs() > tolerance_mv {
+                self.consecutive_failures = failures_before_this_call + 1;
+                return if self.consecutive_failures >= self.max_failures {
+                    Err(RyzenadjError::MaxFailuresReached(self.consecutive_failures))
+                } else {
+                    Err(RyzenadjError::VerificationMismatch {
+                        core,
+                        requested,
+                        reported: reported_value,
+                    })
+                };
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+
+/// Simulate a sequence of apply results for testing consecutive failure logic
+///
+/// # Arguments
+/// * `results` - Sequence of success (true) or failure (false) results
+/// * `max_failures` - Maximum consecutive failures allowed
+///
+/// # Returns
+/// * `Ok(final_count)` - Final consecutive failure count if no max reached
+/// * `Err(count)` - The count at which max failures was reached
+pub fn simulate_failure_sequence(results: &[bool], max_failures: u32) -> Result<u32, u32> {
+    let mut consecutive_failures: u32 = 0;
+    
+    for &success in results {
+        if success {
+            consecutive_failures = 0;
+        } else {
+            consecutive_failures += 1;
+            if consecutive_failures >= max_failures {
+                return Err(consecutive_failures);
+            }
+        }
+    }
+    
+    Ok(consecutive_failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Write a shell script that ignores its normal args but prints
+    /// `info_stdout` and exits zero when invoked with `--info`, for testing
+    /// `apply_verified*` without a real `ryzenadj` binary
+    fn write_fake_ryzenadj(name: &str, info_stdout: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join(name);
+        let script = format!(
+            "#!/bin/sh\nfor arg in \"$@\"; do\n  if [ \"$arg\" = \"--info\" ]; then\n    cat <<'EOF'\n{}\nEOF\n    exit 0\n  fi\ndone\nexit 0\n",
+            info_stdout
+        );
+        std::fs::write(&path, script).expect("failed to write fake ryzenadj script");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("failed to chmod fake ryzenadj script");
+        path
+    }
+
+    #[test]
+    fn test_executor_new() {
+        let executor = RyzenadjExecutor::new("/usr/bin/ryzenadj");
+        assert_eq!(executor.binary_path(), &PathBuf::from("/usr/bin/ryzenadj"));
+        assert_eq!(executor.consecutive_failures(), 0);
+        assert_eq!(executor.max_failures(), MAX_CONSECUTIVE_FAILURES);
+    }
+
+    #[test]
+    fn test_executor_with_max_failures() {
+        let executor = RyzenadjExecutor::with_max_failures("/usr/bin/ryzenadj", 3);
+        assert_eq!(executor.max_failures(), 3);
+    }
+
+    #[test]
+    fn test_build_args_single_core() {
+        let executor = RyzenadjExecutor::new("ryzenadj");
+        let args = executor.build_args(&[-25]);
+        assert_eq!(args, vec!["--set-coper-0", "25"]);
+    }
+
+    #[test]
+    fn test_build_args_multiple_cores() {
+        let executor = RyzenadjExecutor::new("ryzenadj");
+        let args = executor.build_args(&[-20, -25, -30, -35]);
+        assert_eq!(args, vec![
+            "--set-coper-0", "20",
+            "--set-coper-1", "25",
+            "--set-coper-2", "30",
+            "--set-coper-3", "35",
+        ]);
+    }
+
+    #[test]
+    fn test_build_args_zero_values() {
+        let executor = RyzenadjExecutor::new("ryzenadj");
+        let args = executor.build_args(&[0, 0, 0, 0]);
+        assert_eq!(args, vec![
+            "--set-coper-0", "0",
+            "--set-coper-1", "0",
+            "--set-coper-2", "0",
+            "--set-coper-3", "0",
+        ]);
+    }
+
+    #[test]
+    fn test_record_failure_under_limit() {
+        let mut executor = RyzenadjExecutor::with_max_failures("ryzenadj", 5);
+        
+        // First 4 failures should be OK
+        for i in 1..5 {
+            let result = executor.record_failure();
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap(), i);
+        }
+    }
+
+    #[test]
+    fn test_record_failure_at_limit() {
+        let mut executor = RyzenadjExecutor::with_max_failures("ryzenadj", 5);
+        
+        // First 4 failures
+        for _ in 0..4 {
+            let _ = executor.record_failure();
+        }
+        
+        // 5th failure should trigger error
+        let result = executor.record_failure();
+        assert!(result.is_err());
+        match result {
+            Err(RyzenadjError::MaxFailuresReached(count)) => {
+                assert_eq!(count, 5);
+            }
+            _ => panic!("Expected MaxFailuresReached error"),
+        }
+    }
+
+    #[test]
+    fn test_record_success_resets_counter() {
+        let mut executor = RyzenadjExecutor::with_max_failures("ryzenadj", 5);
+        
+        // Add some failures
+        let _ = executor.record_failure();
+        let _ = executor.record_failure();
+        assert_eq!(executor.consecutive_failures(), 2);
+        
+        // Success should reset
+        executor.record_success();
+        assert_eq!(executor.consecutive_failures(), 0);
+    }
+
+    #[test]
+    fn test_simulate_failure_sequence_all_success() {
+        let results = vec![true, true, true, true, true];
+        let outcome = simulate_failure_sequence(&results, 5);
+        assert_eq!(outcome, Ok(0));
+    }
+
+    #[test]
+    fn test_simulate_failure_sequence_mixed() {
+        // Fail, fail, success, fail, fail
+        let results = vec![false, false, true, false, false];
+        let outcome = simulate_failure_sequence(&results, 5);
+        assert_eq!(outcome, Ok(2)); // 2 consecutive failures at end
+    }
+
+    #[test]
+    fn test_simulate_failure_sequence_hits_limit() {
+        // 5 consecutive failures
+        let results = vec![true, false, false, false, false, false];
+        let outcome = simulate_failure_sequence(&results, 5);
+        assert_eq!(outcome, Err(5));
+    }
+
+    #[test]
+    fn test_simulate_failure_sequence_reset_before_limit() {
+        // 4 failures, success, 4 failures - should not hit limit
+        let results = vec![false, false, false, false, true, false, false, false, false];
+        let outcome = simulate_failure_sequence(&results, 5);
+        assert_eq!(outcome, Ok(4));
+    }
+
+    #[test]
+    fn test_error_display() {
+        let err = RyzenadjError::BinaryNotFound("/path/to/ryzenadj".to_string());
+        assert!(err.to_string().contains("/path/to/ryzenadj"));
+
+        let err = RyzenadjError::MaxFailuresReached(5);
+        assert!(err.to_string().contains("5"));
+
+        let err = RyzenadjError::NonZeroExit {
+            code: 1,
+            stderr: "error message".to_string(),
+        };
+        assert!(err.to_string().contains("1"));
+        assert!(err.to_string().contains("error message"));
+
+        let err = RyzenadjError::TimedOut(Duration::from_millis(2_000));
+        assert!(err.to_string().contains("killed"));
+    }
+
+    #[tokio::test]
+    async fn test_reset_to_zero_bounded_times_out_on_hung_binary() {
+        // `yes` never exits on its own (and ignores which args it's given),
+        // so the reset should report a timeout rather than hang the test.
+        let mut executor = RyzenadjExecutor::new("yes");
+        let result = executor
+            .reset_to_zero_bounded(4, Duration::from_millis(50))
+            .await;
+        match result {
+            Err(RyzenadjError::TimedOut(timeout)) => {
+                assert_eq!(timeout, Duration::from_millis(50));
+            }
+            other => panic!("expected TimedOut, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reset_to_zero_bounded_fails_for_missing_binary() {
+        let mut executor = RyzenadjExecutor::new("/nonexistent/ryzenadj");
+        let result = executor
+            .reset_to_zero_bounded(4, Duration::from_millis(500))
+            .await;
+        assert!(matches!(result, Err(RyzenadjError::BinaryNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_apply_bounded_times_out_on_hung_binary() {
+        let mut executor = RyzenadjExecutor::new("yes");
+        let result = executor
+            .apply_bounded(&[-20, -25], Duration::from_millis(50))
+            .await;
+        match result {
+            Ok(ApplyResult { success: false, consecutive_failures: 1 }) => {}
+            other => panic!("expected a recorded failure, got {:?}", other),
+        }
+        assert_eq!(executor.consecutive_failures(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_apply_bounded_refuses_known_bad_config() {
+        let dir = std::env::temp_dir().join(format!(
+            "gymdeck3_failure_corpus_test_{}",
+            std::process::id()
+        ));
+        let mut corpus = FailureCorpus::load(&dir);
+        corpus.record(&[-20, -25]).expect("record should succeed");
+
+        let mut executor = RyzenadjExecutor::new("ryzenadj").with_failure_corpus(corpus);
+        let result = executor
+            .apply_bounded(&[-20, -25], Duration::from_millis(500))
+            .await;
+        assert!(matches!(result, Err(RyzenadjError::KnownBadConfig)));
+
+        let _ = std::fs::remove_file(&dir);
+    }
+
+    #[test]
+    fn test_failure_corpus_load_missing_file_is_empty() {
+        let corpus = FailureCorpus::load("/nonexistent/gymdeck3_failure_corpus_missing");
+        assert!(corpus.is_empty());
+        assert_eq!(corpus.len(), 0);
+    }
+
+    #[test]
+    fn test_failure_corpus_record_and_contains() {
+        let dir = std::env::temp_dir().join(format!(
+            "gymdeck3_failure_corpus_record_{}",
+            std::process::id()
+        ));
+        let mut corpus = FailureCorpus::load(&dir);
+        assert!(!corpus.contains(&[-10, -15]));
+
+        corpus.record(&[-10, -15]).expect("record should succeed");
+        assert!(corpus.contains(&[-10, -15]));
+        assert!(!corpus.contains(&[-10, -16]));
+        assert_eq!(corpus.len(), 1);
+
+        let _ = std::fs::remove_file(&dir);
+    }
+
+    #[test]
+    fn test_failure_corpus_persists_across_load() {
+        let dir = std::env::temp_dir().join(format!(
+            "gymdeck3_failure_corpus_persist_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&dir);
+
+        let mut corpus = FailureCorpus::load(&dir);
+        corpus.record(&[-30, -30, -30]).expect("record should succeed");
+        drop(corpus);
+
+        let reloaded = FailureCorpus::load(&dir);
+        assert!(reloaded.contains(&[-30, -30, -30]));
+
+        let _ = std::fs::remove_file(&dir);
+    }
+
+    #[test]
+    fn test_failure_corpus_record_is_idempotent() {
+        let dir = std::env::temp_dir().join(format!(
+            "gymdeck3_failure_corpus_idempotent_{}",
+            std::process::id()
+        ));
+        let mut corpus = FailureCorpus::load(&dir);
+        corpus.record(&[-5]).expect("record should succeed");
+        corpus.record(&[-5]).expect("record should succeed");
+        assert_eq!(corpus.len(), 1);
+
+        let _ = std::fs::remove_file(&dir);
+    }
+
+    #[tokio::test]
+    async fn test_apply_bounded_ramps_toward_target_with_max_step() {
+        let mut executor = RyzenadjExecutor::new("true").with_max_step_mv(10);
+
+        executor
+            .apply_bounded(&[-30], Duration::from_millis(500))
+            .await
+            .expect("apply should succeed");
+        assert_eq!(executor.current_values(), Some([-10].as_slice()));
+
+        executor
+            .apply_bounded(&[-30], Duration::from_millis(500))
+            .await
+            .expect("apply should succeed");
+        assert_eq!(executor.current_values(), Some([-20].as_slice()));
+
+        executor
+            .apply_bounded(&[-30], Duration::from_millis(500))
+            .await
+            .expect("apply should succeed");
+        assert_eq!(executor.current_values(), Some([-30].as_slice()));
+    }
+
+    #[tokio::test]
+    async fn test_apply_bounded_without_max_step_jumps_directly() {
+        let mut executor = RyzenadjExecutor::new("true");
+
+        executor
+            .apply_bounded(&[-30], Duration::from_millis(500))
+            .await
+            .expect("apply should succeed");
+        assert_eq!(executor.current_values(), Some([-30].as_slice()));
+    }
+
+    #[tokio::test]
+    async fn test_apply_bounded_failure_reverts_to_last_good_and_backs_off() {
+        let mut executor = RyzenadjExecutor::with_max_failures("true", 10);
+
+        executor
+            .apply_bounded(&[-30], Duration::from_millis(500))
+            .await
+            .expect("apply should succeed");
+        assert_eq!(executor.last_good_values(), Some([-30].as_slice()));
+
+        executor.binary_path = PathBuf::from("false");
+        let result = executor
+            .apply_bounded(&[-30], Duration::from_millis(500))
+            .await
+            .expect("should be a recorded failure, not a hard error");
+        assert!(!result.success);
+
+        assert_eq!(executor.current_values(), Some([-30].as_slice()));
+        assert_eq!(executor.accumulated_backoff_mv(), DEFAULT_FAILURE_BACKOFF_MV);
+    }
+
+    #[tokio::test]
+    async fn test_apply_bounded_repeated_failures_accumulate_backoff() {
+        let mut executor = RyzenadjExecutor::with_max_failures("false", 10)
+            .with_failure_backoff_mv(5);
+
+        for expected_backoff in [5, 10, 15] {
+            let result = executor
+                .apply_bounded(&[-30], Duration::from_millis(500))
+                .await
+                .expect("should stay under the failure limit");
+            assert!(!result.success);
+            assert_eq!(executor.accumulated_backoff_mv(), expected_backoff);
+        }
+    }
+
+    #[test]
+    fn test_parse_curve_optimizer_offsets_basic() {
+        let output = "| CO Per Core |\nCORE 0 CO OFFSET: -20 mV\nCORE 1 CO OFFSET: -25 mV\n";
+        let offsets = parse_curve_optimizer_offsets(output);
+        assert_eq!(offsets, vec![Some(-20), Some(-25)]);
+    }
+
+    #[test]
+    fn test_parse_curve_optimizer_offsets_missing_core_is_none() {
+        let output = "CORE 0 CO OFFSET: -20 mV\nCORE 2 CO OFFSET: -30 mV\n";
+        let offsets = parse_curve_optimizer_offsets(output);
+        assert_eq!(offsets, vec![Some(-20), None, Some(-30)]);
+    }
+
+    #[test]
+    fn test_parse_curve_optimizer_offsets_ignores_unrelated_lines() {
+        let output = "ryzenadj v0.14.0\nSMU Version: abc123\nCORE 0 CO OFFSET: -10 mV\n";
+        let offsets = parse_curve_optimizer_offsets(output);
+        assert_eq!(offsets, vec![Some(-10)]);
+    }
+
+    #[tokio::test]
+    async fn test_apply_verified_bounded_passes_when_readback_matches() {
+        let script = write_fake_ryzenadj(
+            &format!("gymdeck3_verify_match_{}", std::process::id()),
+            "CORE 0 CO OFFSET: -20 mV\nCORE 1 CO OFFSET: -25 mV\n",
+        );
+
+        let mut executor = RyzenadjExecutor::new(script.to_str().unwrap());
+        let result = executor
+            .apply_verified_bounded(&[-20, -25], Duration::from_millis(500), 2)
+            .await
+            .expect("apply and verification should succeed");
+        assert!(result.success);
+
+        let _ = std::fs::remove_file(&script);
+    }
+
+    #[tokio::test]
+    async fn test_apply_verified_bounded_detects_mismatch() {
+        let script = write_fake_ryzenadj(
+            &format!("gymdeck3_verify_mismatch_{}", std::process::id()),
+            "CORE 0 CO OFFSET: -5 mV\n",
+        );
+
+        let mut executor =
+            RyzenadjExecutor::with_max_failures(script.to_str().unwrap(), 10);
+        let result = executor
+            .apply_verified_bounded(&[-20], Duration::from_millis(500), 2)
+            .await;
+        match result {
+            Err(RyzenadjError::VerificationMismatch { core: 0, requested: -20, reported: -5 }) => {}
+            other => panic!("expected a verification mismatch, got {:?}", other),
+        }
+        assert_eq!(executor.consecutive_failures(), 1);
+
+        let _ = std::fs::remove_file(&script);
+    }
+
+    #[tokio::test]
+    async fn test_apply_verified_bounded_within_tolerance_is_not_a_mismatch() {
+        let script = write_fake_ryzenadj(
+            &format!("gymdeck3_verify_tolerance_{}", std::process::id()),
+            "CORE 0 CO OFFSET: -19 mV\n",
+        );
+
+        let mut executor = RyzenadjExecutor::new(script.to_str().unwrap());
+        let result = executor
+            .apply_verified_bounded(&[-20], Duration::from_millis(500), 2)
+            .await
+            .expect("1 mV off should be within the 2 mV tolerance");
+        assert!(result.success);
+
+        let _ = std::fs::remove_file(&script);
+    }
+
+    #[tokio::test]
+    async fn test_apply_verified_bounded_missing_core_is_unverifiable_not_a_mismatch() {
+        let script = write_fake_ryzenadj(
+            &format!("gymdeck3_verify_missing_{}", std::process::id()),
+            "CORE 0 CO OFFSET: -20 mV\n",
+        );
+
+        let mut executor = RyzenadjExecutor::new(script.to_str().unwrap());
+        let result = executor
+            .apply_verified_bounded(&[-20, -25], Duration::from_millis(500), 2)
+            .await
+            .expect("a core missing from --info should be skipped, not treated as a mismatch");
+        assert!(result.success);
+
+        let _ = std::fs::remove_file(&script);
+    }
+
+    #[tokio::test]
+    async fn test_apply_verified_bounded_repeated_mismatch_hits_max_failures() {
+        let script = write_fake_ryzenadj(
+            &format!("gymdeck3_verify_repeated_{}", std::process::id()),
+            "CORE 0 CO OFFSET: -5 mV\n",
+        );
+
+        let mut executor = RyzenadjExecutor::with_max_failures(script.to_str().unwrap(), 2);
+        let _ = executor
+            .apply_verified_bounded(&[-20], Duration::from_millis(500), 2)
+            .await;
+        let result = executor
+            .apply_verified_bounded(&[-20], Duration::from_millis(500), 2)
+            .await;
+        assert!(matches!(result, Err(RyzenadjError::MaxFailuresReached(2))));
+
+        let _ = std::fs::remove_file(&script);
+    }
+
+    #[tokio::test]
+    async fn test_apply_verified_bounded_short_circuits_when_apply_fails() {
+        let mut executor = RyzenadjExecutor::new("false");
+        let result = executor
+            .apply_verified_bounded(&[-20], Duration::from_millis(500), 2)
+            .await
+            .expect("an ordinary apply failure should be reported, not erroring from the query_info stage");
+        assert!(!result.success);
+    }
+}
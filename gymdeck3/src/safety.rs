@@ -9,6 +9,17 @@ use crate::strategy::CoreBounds;
 /// Exit code for permission denied (not running as root)
 pub const EXIT_CODE_NOT_ROOT: i32 = 6;
 
+/// Exit code for a confirmed fan stall (`fan::FanHealth` saw commanded PWM
+/// above its floor with tach RPM stuck near zero for too many consecutive
+/// ticks)
+pub const EXIT_CODE_FAN_STALL: i32 = 8;
+
+/// Exit code for a confirmed thermal runaway (`watchdog::ThermalRunawayMonitor`
+/// saw the fan commanded at/near max for a full window without the die
+/// temperature responding) or a dead/faulted temperature sensor detected
+/// over that same window
+pub const EXIT_CODE_THERMAL_RUNAWAY: i32 = 9;
+
 /// Check if the current process is running as root
 ///
 /// Returns true if running as root (UID 0), false otherwise.
@@ -109,6 +120,48 @@ pub fn all_values_in_bounds(values: &[i32], bounds: &[CoreBounds]) -> bool {
         .all(|(&v, b)| is_value_in_bounds(v, b))
 }
 
+/// Clamp a value against bounds that are linearly tightened as temperature
+/// rises, so a baseline offset that's stable at idle can't keep applying
+/// once the die is running hot
+///
+/// Below `derate_start_c` the full `[bounds.max_mv, bounds.min_mv]` window
+/// applies, same as `clamp_value`. Between `derate_start_c` and
+/// `derate_end_c`, the aggressive end of the window (`bounds.max_mv`) is
+/// pulled linearly toward `bounds.min_mv`; at or above `derate_end_c` only
+/// `bounds.min_mv` - the safest value - is allowed. `bounds.min_mv` itself
+/// never moves, since it's already the safe end of the range.
+///
+/// # Arguments
+/// * `value` - The undervolt value to validate (in mV, negative or zero)
+/// * `bounds` - The bounds to validate against at `derate_start_c` or below
+/// * `temp_c` - Current die temperature
+/// * `derate_start_c` - Temperature at which derating begins
+/// * `derate_end_c` - Temperature at which derating is complete
+///
+/// # Returns
+/// The clamped value within the (possibly tightened) bounds
+pub fn clamp_value_thermal(
+    value: i32,
+    bounds: &CoreBounds,
+    temp_c: f32,
+    derate_start_c: f32,
+    derate_end_c: f32,
+) -> i32 {
+    let span = (derate_end_c - derate_start_c).max(0.001);
+    let t = ((temp_c - derate_start_c) / span).clamp(0.0, 1.0);
+
+    let effective_max_mv =
+        bounds.max_mv as f32 + (bounds.min_mv - bounds.max_mv) as f32 * t;
+
+    let derated_bounds = CoreBounds {
+        min_mv: bounds.min_mv,
+        max_mv: effective_max_mv.round() as i32,
+        threshold: bounds.threshold,
+    };
+
+    clamp_value(value, &derated_bounds)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,6 +239,39 @@ mod tests {
         let _ = is_root();
     }
 
+    #[test]
+    fn test_clamp_value_thermal_below_derate_start_behaves_like_clamp_value() {
+        let bounds = test_bounds();
+        assert_eq!(clamp_value_thermal(-40, &bounds, 60.0, 80.0, 95.0), -35);
+        assert_eq!(clamp_value_thermal(-25, &bounds, 80.0, 80.0, 95.0), -25);
+    }
+
+    #[test]
+    fn test_clamp_value_thermal_at_derate_end_pulls_to_safe_floor() {
+        let bounds = test_bounds();
+        // At or above derate_end, only min_mv (the safe end) is allowed
+        assert_eq!(clamp_value_thermal(-40, &bounds, 95.0, 80.0, 95.0), -20);
+        assert_eq!(clamp_value_thermal(-40, &bounds, 110.0, 80.0, 95.0), -20);
+    }
+
+    #[test]
+    fn test_clamp_value_thermal_interpolates_midway() {
+        let bounds = test_bounds();
+        // Halfway between derate_start and derate_end, the aggressive floor
+        // should sit halfway between max_mv and min_mv
+        let clamped = clamp_value_thermal(-40, &bounds, 87.5, 80.0, 95.0);
+        assert_eq!(clamped, -28); // -35 + (-20 - -35) * 0.5 = -27.5, rounds to -28
+    }
+
+    #[test]
+    fn test_clamp_value_thermal_never_loosens_min_mv() {
+        let bounds = test_bounds();
+        // A value requesting less aggressive than min_mv stays clamped to
+        // min_mv regardless of temperature
+        assert_eq!(clamp_value_thermal(0, &bounds, 60.0, 80.0, 95.0), -20);
+        assert_eq!(clamp_value_thermal(0, &bounds, 95.0, 80.0, 95.0), -20);
+    }
+
     #[test]
     fn test_check_root_or_exit_format() {
         // Test that the function exists and returns the expected type
@@ -3,20 +3,57 @@
 //! Provides handlers for Unix signals:
 //! - SIGTERM: Graceful shutdown with value reset to 0
 //! - SIGUSR1: Force immediate status output
+//! - SIGHUP: Re-read the config file and apply it without restarting
 //!
 //! Requirements: 7.3, 7.4, 9.3
 
+use std::cell::RefCell;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::{mpsc, Notify};
+
+/// Capacity of the [`SignalHandler::events`] channel. Bounded so a burst of
+/// duplicate signals (e.g. repeated `SIGUSR1`) applies backpressure to the
+/// sending signal task rather than growing an unbounded queue the main loop
+/// may never catch up on.
+const EVENTS_CHANNEL_CAPACITY: usize = 8;
+
+/// A signal or control request the main loop should react to, forwarded
+/// over [`SignalHandler::events`]. Carries no payload today, but exists so
+/// new control signals (and eventually ones with data) can be added without
+/// growing the atomic-flag count in [`SignalState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaemonEvent {
+    /// SIGTERM/SIGINT: stop controlling, reset hardware, exit
+    Shutdown,
+    /// SIGUSR1: emit a status line immediately, bypassing the interval
+    ForceStatus,
+    /// SIGHUP: re-read the config file and apply it without restarting
+    ReloadConfig,
+    /// Suspend active control until a matching `Resume`
+    Pause,
+    /// Resume control after a `Pause`
+    Resume,
+}
 
 /// Signal state shared between signal handlers and main loop
+///
+/// Alongside the polled atomic flags, each signal has a paired `Notify` so
+/// the main loop can `select!` on arrival instead of waking on a fixed
+/// interval to check `is_shutdown_requested`/`take_force_status`. `Notify`
+/// stores a single wakeup permit when nothing is waiting yet, so a signal
+/// that arrives just before the loop starts awaiting still fires promptly.
 #[derive(Debug, Clone)]
 pub struct SignalState {
     /// Flag indicating SIGTERM was received
     shutdown_requested: Arc<AtomicBool>,
     /// Flag indicating SIGUSR1 was received (force status output)
     force_status: Arc<AtomicBool>,
+    /// Wakes a `shutdown_notified().await` waiter
+    shutdown_notify: Arc<Notify>,
+    /// Wakes a `force_status_notified().await` waiter
+    force_status_notify: Arc<Notify>,
 }
 
 impl Default for SignalState {
@@ -31,6 +68,8 @@ impl SignalState {
         Self {
             shutdown_requested: Arc::new(AtomicBool::new(false)),
             force_status: Arc::new(AtomicBool::new(false)),
+            shutdown_notify: Arc::new(Notify::new()),
+            force_status_notify: Arc::new(Notify::new()),
         }
     }
 
@@ -42,6 +81,15 @@ impl SignalState {
     /// Request shutdown (called by signal handler)
     pub fn request_shutdown(&self) {
         self.shutdown_requested.store(true, Ordering::SeqCst);
+        self.shutdown_notify.notify_one();
+    }
+
+    /// Wait until shutdown is requested (SIGTERM/SIGINT)
+    ///
+    /// For use in a `tokio::select!` branch; resolves once and then is
+    /// ready to be awaited again, mirroring `is_shutdown_requested`.
+    pub async fn shutdown_notified(&self) {
+        self.shutdown_notify.notified().await;
     }
 
     /// Check and clear the force status flag (SIGUSR1)
@@ -54,6 +102,14 @@ impl SignalState {
     /// Set the force status flag (called by signal handler)
     pub fn set_force_status(&self) {
         self.force_status.store(true, Ordering::SeqCst);
+        self.force_status_notify.notify_one();
+    }
+
+    /// Wait until a force-status request (SIGUSR1) arrives
+    ///
+    /// For use in a `tokio::select!` branch alongside `shutdown_notified`.
+    pub async fn force_status_notified(&self) {
+        self.force_status_notify.notified().await;
     }
 
     /// Reset all flags (for testing)
@@ -64,15 +120,27 @@ impl SignalState {
     }
 }
 
-/// Signal handler that manages SIGTERM and SIGUSR1
+/// Signal handler that manages SIGTERM, SIGINT, and SIGUSR1
+///
+/// Forwards each received signal both through the legacy [`SignalState`]
+/// atomics/`Notify` pair (kept as a compatibility shim) and as a typed
+/// [`DaemonEvent`] over [`Self::events`], so the main loop can `select!` on
+/// one receiver instead of awaiting a growing set of per-signal `Notify`s.
 pub struct SignalHandler {
     state: SignalState,
+    events_tx: mpsc::Sender<DaemonEvent>,
+    events_rx: RefCell<Option<mpsc::Receiver<DaemonEvent>>>,
 }
 
 impl SignalHandler {
     /// Create a new signal handler with the given state
     pub fn new(state: SignalState) -> Self {
-        Self { state }
+        let (events_tx, events_rx) = mpsc::channel(EVENTS_CHANNEL_CAPACITY);
+        Self {
+            state,
+            events_tx,
+            events_rx: RefCell::new(Some(events_rx)),
+        }
     }
 
     /// Get a reference to the signal state
@@ -80,6 +148,18 @@ impl SignalHandler {
         &self.state
     }
 
+    /// Take the event receiver for the main loop's `select!`.
+    ///
+    /// # Panics
+    /// Panics if called more than once - there's only one receiver to hand
+    /// out, since `mpsc::Receiver` can't be cloned.
+    pub fn events(&self) -> mpsc::Receiver<DaemonEvent> {
+        self.events_rx
+            .borrow_mut()
+            .take()
+            .expect("SignalHandler::events() called more than once")
+    }
+
     /// Start listening for signals
     ///
     /// This spawns background tasks that update the signal state when
@@ -90,34 +170,55 @@ impl SignalHandler {
     pub async fn start(&self) -> Result<(), std::io::Error> {
         // Register SIGTERM handler
         let state_term = self.state.clone();
+        let tx_term = self.events_tx.clone();
         let mut sigterm = signal(SignalKind::terminate())?;
         tokio::spawn(async move {
             loop {
                 sigterm.recv().await;
                 eprintln!("Received SIGTERM, initiating graceful shutdown...");
                 state_term.request_shutdown();
+                let _ = tx_term.send(DaemonEvent::Shutdown).await;
             }
         });
 
         // Register SIGUSR1 handler
         let state_usr1 = self.state.clone();
+        let tx_usr1 = self.events_tx.clone();
         let mut sigusr1 = signal(SignalKind::user_defined1())?;
         tokio::spawn(async move {
             loop {
                 sigusr1.recv().await;
                 eprintln!("Received SIGUSR1, forcing status output...");
                 state_usr1.set_force_status();
+                let _ = tx_usr1.send(DaemonEvent::ForceStatus).await;
             }
         });
 
         // Register SIGINT handler (Ctrl+C) - same as SIGTERM
         let state_int = self.state.clone();
+        let tx_int = self.events_tx.clone();
         let mut sigint = signal(SignalKind::interrupt())?;
         tokio::spawn(async move {
             loop {
                 sigint.recv().await;
                 eprintln!("Received SIGINT, initiating graceful shutdown...");
                 state_int.request_shutdown();
+                let _ = tx_int.send(DaemonEvent::Shutdown).await;
+            }
+        });
+
+        // Register SIGHUP handler: conventionally "reload configuration"
+        // for a daemon. Unlike shutdown/force-status there's no legacy
+        // `SignalState` flag for this - it only exists as a `DaemonEvent`,
+        // since the command-bus redesign had already landed by the time
+        // this signal was added.
+        let tx_hup = self.events_tx.clone();
+        let mut sighup = signal(SignalKind::hangup())?;
+        tokio::spawn(async move {
+            loop {
+                sighup.recv().await;
+                eprintln!("Received SIGHUP, reloading configuration...");
+                let _ = tx_hup.send(DaemonEvent::ReloadConfig).await;
             }
         });
 
@@ -134,6 +235,10 @@ impl SignalHandler {
 /// * `num_cores` - Number of CPU cores to reset
 /// * `ryzenadj_path` - Path to ryzenadj binary
 /// * `verbose` - Whether to log verbose output
+/// * `controller_state` - If set, the live hysteresis controller and the
+///   path to persist it to; saved before hardware is reset so the next
+///   start can resume from the same learned baseline via
+///   `HysteresisController::load_from` instead of cold-starting
 ///
 /// # Returns
 /// Exit code (0 for success, non-zero for errors)
@@ -141,15 +246,24 @@ pub async fn graceful_shutdown(
     num_cores: usize,
     ryzenadj_path: &str,
     verbose: bool,
+    controller_state: Option<(&crate::hysteresis::HysteresisController, &std::path::Path)>,
 ) -> i32 {
     use crate::ryzenadj::RyzenadjExecutor;
 
+    if let Some((controller, path)) = controller_state {
+        if let Err(e) = controller.save_to(path) {
+            eprintln!("Warning: Failed to persist hysteresis state to {}: {}", path.display(), e);
+        } else if verbose {
+            eprintln!("Hysteresis state saved to {}", path.display());
+        }
+    }
+
     if verbose {
         eprintln!("Resetting all undervolt values to 0...");
     }
 
     let mut executor = RyzenadjExecutor::new(ryzenadj_path);
-    
+
     match executor.reset_to_zero(num_cores).await {
         Ok(()) => {
             if verbose {
@@ -173,22 +287,42 @@ pub async fn graceful_shutdown(
 /// # Arguments
 /// * `num_cores` - Number of CPU cores
 /// * `ryzenadj_path` - Path to ryzenadj binary
-pub fn install_panic_hook(num_cores: usize, ryzenadj_path: String) {
+/// * `controller_state` - If set, a shared handle to the live hysteresis
+///   controller and the crash-file path to flush it to; read and saved
+///   before values are reset, so a restart after a crash can resume from
+///   the last known operating point via `HysteresisController::load_from`
+///   instead of cold-starting
+pub fn install_panic_hook(
+    num_cores: usize,
+    ryzenadj_path: String,
+    controller_state: Option<(std::sync::Arc<std::sync::Mutex<crate::hysteresis::HysteresisController>>, std::path::PathBuf)>,
+) {
     let default_hook = std::panic::take_hook();
-    
+
     std::panic::set_hook(Box::new(move |panic_info| {
+        if let Some((ref state, ref crash_path)) = controller_state {
+            match state.lock() {
+                Ok(controller) => {
+                    if let Err(e) = controller.save_to(crash_path) {
+                        eprintln!("Warning: Failed to flush hysteresis state to crash file: {}", e);
+                    }
+                }
+                Err(e) => eprintln!("Warning: Hysteresis state lock poisoned, could not flush crash file: {}", e),
+            }
+        }
+
         eprintln!("PANIC: Resetting undervolt values to safe defaults...");
-        
+
         // Use blocking call since we're in a panic handler
         // We can't use async here, so we spawn a blocking subprocess
         let values: Vec<String> = (0..num_cores)
             .flat_map(|i| vec![format!("--set-coper-{}", i), "0".to_string()])
             .collect();
-        
+
         let _ = std::process::Command::new(&ryzenadj_path)
             .args(&values)
             .output();
-        
+
         // Call the default panic hook
         default_hook(panic_info);
     }));
@@ -197,6 +331,7 @@ pub fn install_panic_hook(num_cores: usize, ryzenadj_path: String) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn test_signal_state_new() {
@@ -288,6 +423,57 @@ mod tests {
         ]);
     }
 
+    #[tokio::test]
+    async fn test_shutdown_notified_wakes_waiter() {
+        let state = SignalState::new();
+        state.request_shutdown();
+
+        // The permit is stored even though no one was awaiting yet
+        tokio::time::timeout(Duration::from_millis(100), state.shutdown_notified())
+            .await
+            .expect("shutdown_notified should resolve immediately");
+    }
+
+    #[tokio::test]
+    async fn test_force_status_notified_wakes_waiter() {
+        let state = SignalState::new();
+        state.set_force_status();
+
+        tokio::time::timeout(Duration::from_millis(100), state.force_status_notified())
+            .await
+            .expect("force_status_notified should resolve immediately");
+    }
+
+    #[tokio::test]
+    async fn test_events_receiver_gets_shutdown_event() {
+        let state = SignalState::new();
+        let handler = SignalHandler::new(state);
+        let mut events = handler.events();
+
+        handler.events_tx.send(DaemonEvent::Shutdown).await.unwrap();
+
+        assert_eq!(events.recv().await, Some(DaemonEvent::Shutdown));
+    }
+
+    #[tokio::test]
+    async fn test_events_receiver_gets_reload_config_event() {
+        let state = SignalState::new();
+        let handler = SignalHandler::new(state);
+        let mut events = handler.events();
+
+        handler.events_tx.send(DaemonEvent::ReloadConfig).await.unwrap();
+
+        assert_eq!(events.recv().await, Some(DaemonEvent::ReloadConfig));
+    }
+
+    #[test]
+    #[should_panic(expected = "called more than once")]
+    fn test_events_panics_if_taken_twice() {
+        let handler = SignalHandler::new(SignalState::new());
+        let _first = handler.events();
+        let _second = handler.events();
+    }
+
     #[test]
     fn test_panic_hook_args_single_core() {
         let num_cores = 1;
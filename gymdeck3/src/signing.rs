@@ -0,0 +1,189 @@
+//! Authenticated/signed status messages
+//!
+//! gymdeck3 runs privileged while its UI client does not; an attacker with
+//! access to the pipe between them could otherwise forge `StatusOutput`
+//! lines. This wraps a serialized message in a signed envelope
+//! `{ "v": 1, "payload": "<base64url>", "sig": "<base64url>" }`, where `sig`
+//! is an HMAC-SHA256 over the canonical payload bytes using a shared key
+//! negotiated at startup.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::output::StatusOutput;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Current envelope format version
+pub const ENVELOPE_VERSION: u8 = 1;
+
+/// Default freshness leeway in milliseconds for `uptime_ms` replay checks
+pub const DEFAULT_FRESHNESS_LEEWAY_MS: u64 = 5000;
+
+/// A signed message envelope
+///
+/// `payload` and `sig` are base64url (no padding) encodings of the
+/// canonical payload bytes and its HMAC-SHA256 tag respectively.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SignedEnvelope {
+    /// Envelope format version
+    pub v: u8,
+    /// base64url-encoded canonical payload bytes
+    pub payload: String,
+    /// base64url-encoded HMAC-SHA256 tag over the payload bytes
+    pub sig: String,
+}
+
+impl StatusOutput {
+    /// Sign this status output, returning a serialized `SignedEnvelope`
+    ///
+    /// Uses the canonical (float-free) encoding as the payload so the
+    /// signature is computed over deterministic bytes.
+    pub fn sign(&self, key: &[u8]) -> Result<String, String> {
+        let payload_json = self
+            .to_canonical_json()
+            .map_err(|e| format!("serialize failed: {}", e))?;
+        let payload_bytes = payload_json.as_bytes();
+
+        let mut mac = HmacSha256::new_from_slice(key).map_err(|e| format!("invalid key: {}", e))?;
+        mac.update(payload_bytes);
+        let sig = mac.finalize().into_bytes();
+
+        let envelope = SignedEnvelope {
+            v: ENVELOPE_VERSION,
+            payload: URL_SAFE_NO_PAD.encode(payload_bytes),
+            sig: URL_SAFE_NO_PAD.encode(sig),
+        };
+
+        serde_json::to_string(&envelope).map_err(|e| format!("envelope serialize failed: {}", e))
+    }
+}
+
+/// Verify and decode a signed envelope
+///
+/// Checks the HMAC tag in constant time before deserializing the payload,
+/// then rejects the message if its `uptime_ms` is more than
+/// `freshness_leeway_ms` away from `now_uptime_ms` (a replayed or stale
+/// line from well before/after "now").
+pub fn verify_signed(
+    envelope_str: &str,
+    key: &[u8],
+    now_uptime_ms: u64,
+    freshness_leeway_ms: u64,
+) -> Result<StatusOutput, String> {
+    let envelope: SignedEnvelope =
+        serde_json::from_str(envelope_str).map_err(|e| format!("invalid envelope: {}", e))?;
+
+    if envelope.v != ENVELOPE_VERSION {
+        return Err(format!("unsupported envelope version {}", envelope.v));
+    }
+
+    let payload_bytes = URL_SAFE_NO_PAD
+        .decode(&envelope.payload)
+        .map_err(|e| format!("invalid payload encoding: {}", e))?;
+    let expected_sig = URL_SAFE_NO_PAD
+        .decode(&envelope.sig)
+        .map_err(|e| format!("invalid signature encoding: {}", e))?;
+
+    let mut mac = HmacSha256::new_from_slice(key).map_err(|e| format!("invalid key: {}", e))?;
+    mac.update(&payload_bytes);
+    // Mac::verify_slice compares the tags in constant time.
+    mac.verify_slice(&expected_sig)
+        .map_err(|_| "signature verification failed".to_string())?;
+
+    let payload_str =
+        std::str::from_utf8(&payload_bytes).map_err(|e| format!("invalid payload utf8: {}", e))?;
+    let status = StatusOutput::from_canonical_json(payload_str)
+        .map_err(|e| format!("invalid payload json: {}", e))?;
+
+    let delta = status.uptime_ms.abs_diff(now_uptime_ms);
+    if delta > freshness_leeway_ms {
+        return Err(format!(
+            "stale or replayed message: uptime_ms {} is {}ms from current {}ms (leeway {}ms)",
+            status.uptime_ms, delta, now_uptime_ms, freshness_leeway_ms
+        ));
+    }
+
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Strategy;
+
+    const TEST_KEY: &[u8] = b"test-shared-secret-key";
+
+    fn sample_status(uptime_ms: u64) -> StatusOutput {
+        StatusOutput::new(vec![45.2, 52.1], vec![-28, -25], Strategy::Balanced, uptime_ms)
+    }
+
+    #[test]
+    fn test_sign_and_verify_roundtrip() {
+        let status = sample_status(10_000);
+        let envelope = status.sign(TEST_KEY).unwrap();
+
+        let verified = verify_signed(&envelope, TEST_KEY, 10_000, DEFAULT_FRESHNESS_LEEWAY_MS).unwrap();
+        assert_eq!(status, verified);
+    }
+
+    #[test]
+    fn test_verify_rejects_wrong_key() {
+        let status = sample_status(10_000);
+        let envelope = status.sign(TEST_KEY).unwrap();
+
+        let result = verify_signed(&envelope, b"wrong-key", 10_000, DEFAULT_FRESHNESS_LEEWAY_MS);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_tampered_payload() {
+        let status = sample_status(10_000);
+        let envelope = status.sign(TEST_KEY).unwrap();
+
+        let mut parsed: SignedEnvelope = serde_json::from_str(&envelope).unwrap();
+        // Flip the payload to something else entirely, re-encoded but unsigned.
+        parsed.payload = URL_SAFE_NO_PAD.encode(b"{\"type\":\"status\",\"load_milli_pct\":[0],\"values\":[0],\"strategy\":\"custom\",\"uptime_ms\":0}");
+        let tampered = serde_json::to_string(&parsed).unwrap();
+
+        let result = verify_signed(&tampered, TEST_KEY, 10_000, DEFAULT_FRESHNESS_LEEWAY_MS);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_stale_message() {
+        let status = sample_status(10_000);
+        let envelope = status.sign(TEST_KEY).unwrap();
+
+        // "Now" is far in the future relative to the signed uptime.
+        let result = verify_signed(&envelope, TEST_KEY, 100_000, DEFAULT_FRESHNESS_LEEWAY_MS);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("stale or replayed"));
+    }
+
+    #[test]
+    fn test_verify_accepts_within_leeway() {
+        let status = sample_status(10_000);
+        let envelope = status.sign(TEST_KEY).unwrap();
+
+        let result = verify_signed(&envelope, TEST_KEY, 10_000 + DEFAULT_FRESHNESS_LEEWAY_MS, DEFAULT_FRESHNESS_LEEWAY_MS);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_verify_rejects_unsupported_version() {
+        let status = sample_status(10_000);
+        let envelope = status.sign(TEST_KEY).unwrap();
+
+        let mut parsed: SignedEnvelope = serde_json::from_str(&envelope).unwrap();
+        parsed.v = 99;
+        let bumped = serde_json::to_string(&parsed).unwrap();
+
+        let result = verify_signed(&bumped, TEST_KEY, 10_000, DEFAULT_FRESHNESS_LEEWAY_MS);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("unsupported envelope version"));
+    }
+}
@@ -0,0 +1,224 @@
+//! Sample smoothing filters to reduce undervolt/fan chatter on noisy readings
+//!
+//! Applied to raw load/temperature samples *before* they reach strategy or
+//! fan-curve evaluation, decoupling control responsiveness from the raw
+//! `sample_interval_us` sampling rate. This composes with, but solves a
+//! different problem than, `HysteresisController`/`fan_hysteresis`:
+//! smoothing reduces the noise a sample carries before it's ever evaluated,
+//! so a tight hysteresis margin stays effective on an otherwise-jittery raw
+//! signal, whereas hysteresis alone only delays reacting to noise it still
+//! sees in full. Smoothing adds its own lag (more pronounced with a larger
+//! SMA window or a smaller EMA alpha), so an overly aggressive filter can
+//! make a tight hysteresis margin look unresponsive even though the two
+//! aren't competing for the same job — tune them together, not in
+//! isolation.
+
+use std::collections::VecDeque;
+
+use crate::config::{SmoothingConfig, SmoothingMode};
+
+/// Stateful single-signal smoothing filter
+///
+/// Holds whatever history its mode needs: a rolling window of raw samples
+/// for `SmoothingMode::Sma`, or just the last filtered value for
+/// `SmoothingMode::Ema`. `SmoothingMode::None` is stateless passthrough.
+#[derive(Debug, Clone)]
+pub struct SmoothingFilter {
+    config: SmoothingConfig,
+    window: VecDeque<f32>,
+    ema_value: Option<f32>,
+}
+
+impl SmoothingFilter {
+    /// Create a new filter for the given configuration
+    pub fn new(config: SmoothingConfig) -> Self {
+        SmoothingFilter {
+            config,
+            window: VecDeque::new(),
+            ema_value: None,
+        }
+    }
+
+    /// The configuration this filter was created with
+    pub fn config(&self) -> &SmoothingConfig {
+        &self.config
+    }
+
+    /// Feed one raw sample and get back the filtered value
+    pub fn filter(&mut self, raw: f32) -> f32 {
+        match self.config.mode {
+            SmoothingMode::None => raw,
+            SmoothingMode::Sma => {
+                self.window.push_back(raw);
+                while self.window.len() > self.config.window {
+                    self.window.pop_front();
+                }
+                self.window.iter().sum::<f32>() / self.window.len() as f32
+            }
+            SmoothingMode::Ema => {
+                let next = match self.ema_value {
+                    Some(prev) => self.config.alpha * raw + (1.0 - self.config.alpha) * prev,
+                    None => raw,
+                };
+                self.ema_value = Some(next);
+                next
+            }
+        }
+    }
+
+    /// Reset all filter state, as if freshly constructed
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.ema_value = None;
+    }
+}
+
+/// Independent smoothing filters for multiple tracked signals (e.g. one per
+/// CPU core), mirroring `HysteresisController`'s per-core state split
+#[derive(Debug, Clone)]
+pub struct SmoothingBank {
+    filters: Vec<SmoothingFilter>,
+}
+
+impl SmoothingBank {
+    /// Create `num_signals` independent filters, all sharing `config`
+    pub fn new(config: SmoothingConfig, num_signals: usize) -> Self {
+        SmoothingBank {
+            filters: (0..num_signals).map(|_| SmoothingFilter::new(config)).collect(),
+        }
+    }
+
+    /// Number of tracked signals
+    pub fn len(&self) -> usize {
+        self.filters.len()
+    }
+
+    /// Whether this bank tracks no signals
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
+
+    /// Feed a raw sample for `index` and get back its filtered value
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds
+    pub fn process(&mut self, index: usize, raw: f32) -> f32 {
+        self.filters[index].filter(raw)
+    }
+
+    /// Reset all tracked signals' state
+    pub fn reset(&mut self) {
+        for filter in &mut self.filters {
+            filter.reset();
+        }
+    }
+
+    /// Reset a single signal's state
+    pub fn reset_signal(&mut self, index: usize) {
+        self.filters[index].reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sma(window: usize) -> SmoothingConfig {
+        SmoothingConfig {
+            mode: SmoothingMode::Sma,
+            window,
+            alpha: 1.0,
+        }
+    }
+
+    fn ema(alpha: f32) -> SmoothingConfig {
+        SmoothingConfig {
+            mode: SmoothingMode::Ema,
+            window: 1,
+            alpha,
+        }
+    }
+
+    #[test]
+    fn test_none_passes_through_unchanged() {
+        let mut filter = SmoothingFilter::new(SmoothingConfig::default());
+        assert_eq!(filter.filter(10.0), 10.0);
+        assert_eq!(filter.filter(90.0), 90.0);
+    }
+
+    #[test]
+    fn test_sma_averages_over_window() {
+        let mut filter = SmoothingFilter::new(sma(3));
+        assert_eq!(filter.filter(10.0), 10.0);
+        assert_eq!(filter.filter(20.0), 15.0);
+        assert_eq!(filter.filter(30.0), 20.0);
+        // Window full: oldest sample (10.0) drops off
+        assert_eq!(filter.filter(60.0), (20.0 + 30.0 + 60.0) / 3.0);
+    }
+
+    #[test]
+    fn test_sma_window_one_is_passthrough() {
+        let mut filter = SmoothingFilter::new(sma(1));
+        assert_eq!(filter.filter(10.0), 10.0);
+        assert_eq!(filter.filter(90.0), 90.0);
+    }
+
+    #[test]
+    fn test_ema_first_sample_is_unchanged() {
+        let mut filter = SmoothingFilter::new(ema(0.2));
+        assert_eq!(filter.filter(50.0), 50.0);
+    }
+
+    #[test]
+    fn test_ema_converges_toward_new_value() {
+        let mut filter = SmoothingFilter::new(ema(0.5));
+        filter.filter(0.0);
+        let second = filter.filter(100.0);
+        assert_eq!(second, 50.0);
+        let third = filter.filter(100.0);
+        assert_eq!(third, 75.0);
+        assert!(third > second, "should keep converging toward the new value");
+    }
+
+    #[test]
+    fn test_ema_alpha_one_is_passthrough() {
+        let mut filter = SmoothingFilter::new(ema(1.0));
+        assert_eq!(filter.filter(10.0), 10.0);
+        assert_eq!(filter.filter(90.0), 90.0);
+    }
+
+    #[test]
+    fn test_reset_clears_state() {
+        let mut filter = SmoothingFilter::new(sma(3));
+        filter.filter(10.0);
+        filter.filter(20.0);
+        filter.reset();
+        // Back to a fresh window: first post-reset sample passes through
+        assert_eq!(filter.filter(50.0), 50.0);
+    }
+
+    #[test]
+    fn test_bank_tracks_signals_independently() {
+        let mut bank = SmoothingBank::new(sma(2), 2);
+        assert_eq!(bank.len(), 2);
+
+        bank.process(0, 10.0);
+        let a = bank.process(0, 20.0);
+        let b = bank.process(1, 100.0);
+
+        assert_eq!(a, 15.0);
+        assert_eq!(b, 100.0, "signal 1 has its own independent window");
+    }
+
+    #[test]
+    fn test_bank_reset_signal_is_independent() {
+        let mut bank = SmoothingBank::new(sma(2), 2);
+        bank.process(0, 10.0);
+        bank.process(1, 10.0);
+
+        bank.reset_signal(0);
+
+        assert_eq!(bank.process(0, 50.0), 50.0);
+        assert_eq!(bank.process(1, 20.0), 15.0, "signal 1 unaffected by resetting signal 0");
+    }
+}
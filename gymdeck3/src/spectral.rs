@@ -0,0 +1,317 @@
+//! FFT-based oscillation detection for load samples.
+//!
+//! Buffers the recent history of `LoadMonitor` samples and runs a
+//! Hann-windowed real FFT to surface periodic load behavior. Used two ways:
+//! (1) recommending a sample interval fast enough (Nyquist) to resolve the
+//! dominant oscillation, and (2) flagging sustained high-amplitude
+//! oscillation as a stability risk during a stress test.
+//!
+//! This is a self-contained, dependency-free subsystem (a plain radix-2
+//! FFT, no external crate); a build with a Cargo manifest would gate it
+//! behind an optional feature so the core crate stays lean when spectral
+//! analysis isn't needed.
+
+use std::collections::VecDeque;
+use std::f64::consts::PI;
+
+/// Default FFT window size in samples (must be a power of two).
+pub const DEFAULT_FFT_SIZE: usize = 256;
+
+/// A peak in the magnitude spectrum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpectralPeak {
+    /// FFT bin index (never 0 - the DC bin is excluded from peak search)
+    pub bin: usize,
+    /// Frequency in Hz this bin corresponds to
+    pub frequency_hz: f64,
+    /// Magnitude at this bin
+    pub magnitude: f64,
+}
+
+/// Error constructing an [`OscillationDetector`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpectralError {
+    /// The requested window size was zero or not a power of two, which the
+    /// radix-2 FFT requires.
+    NotPowerOfTwo(usize),
+}
+
+impl std::fmt::Display for SpectralError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpectralError::NotPowerOfTwo(n) => {
+                write!(f, "FFT window size {} is not a power of two", n)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SpectralError {}
+
+/// Buffers the last `size` load samples and runs a windowed FFT on demand
+/// to surface the dominant non-DC oscillation frequency.
+pub struct OscillationDetector {
+    size: usize,
+    buffer: VecDeque<f64>,
+}
+
+impl OscillationDetector {
+    /// Create a detector over a `size`-sample window. `size` must be a
+    /// power of two (required by the radix-2 FFT).
+    pub fn new(size: usize) -> Result<Self, SpectralError> {
+        if size == 0 || !size.is_power_of_two() {
+            return Err(SpectralError::NotPowerOfTwo(size));
+        }
+        Ok(Self {
+            size,
+            buffer: VecDeque::with_capacity(size),
+        })
+    }
+
+    /// Push a new load sample, evicting the oldest once the window is full.
+    pub fn push_sample(&mut self, value: f64) {
+        if self.buffer.len() >= self.size {
+            self.buffer.pop_front();
+        }
+        self.buffer.push_back(value);
+    }
+
+    /// Whether the window has enough samples to analyze.
+    pub fn is_ready(&self) -> bool {
+        self.buffer.len() == self.size
+    }
+
+    /// Run a Hann-windowed real FFT over the buffered samples and return
+    /// the dominant non-DC peak, given the sample interval the samples
+    /// were collected at. Returns `None` if the window isn't full yet.
+    pub fn dominant_peak(&self, sample_interval_ms: u64) -> Option<SpectralPeak> {
+        if !self.is_ready() || sample_interval_ms == 0 {
+            return None;
+        }
+
+        let detrended = detrend(self.buffer.iter().copied().collect());
+        let windowed = hann_window(detrended);
+        let spectrum = real_fft_magnitude(&windowed);
+
+        let sample_rate_hz = 1000.0 / sample_interval_ms as f64;
+        let n = spectrum.len();
+
+        // Exclude bin 0 (DC); for a real-valued signal only the first half
+        // of bins carries unique frequency content.
+        (1..n / 2)
+            .max_by(|&a, &b| spectrum[a].partial_cmp(&spectrum[b]).unwrap())
+            .map(|bin| SpectralPeak {
+                bin,
+                frequency_hz: bin as f64 * sample_rate_hz / n as f64,
+                magnitude: spectrum[bin],
+            })
+    }
+}
+
+/// Subtract the mean, removing the DC component before windowing. Without
+/// this, a load signal's average utilization (typically far larger than
+/// any oscillation riding on top of it) leaks into neighboring bins and can
+/// swamp the real peak.
+fn detrend(mut samples: Vec<f64>) -> Vec<f64> {
+    let n = samples.len();
+    if n == 0 {
+        return samples;
+    }
+    let mean = samples.iter().sum::<f64>() / n as f64;
+    for sample in samples.iter_mut() {
+        *sample -= mean;
+    }
+    samples
+}
+
+/// Apply a Hann window, tapering the buffer's edges to reduce spectral
+/// leakage from the signal not being periodic over the window.
+fn hann_window(mut samples: Vec<f64>) -> Vec<f64> {
+    let n = samples.len();
+    for (i, sample) in samples.iter_mut().enumerate() {
+        let w = 0.5 - 0.5 * (2.0 * PI * i as f64 / (n - 1) as f64).cos();
+        *sample *= w;
+    }
+    samples
+}
+
+/// Magnitude spectrum of a real-valued signal via radix-2 Cooley-Tukey FFT.
+/// `samples.len()` must be a power of two.
+fn real_fft_magnitude(samples: &[f64]) -> Vec<f64> {
+    let mut re: Vec<f64> = samples.to_vec();
+    let mut im: Vec<f64> = vec![0.0; samples.len()];
+
+    fft_in_place(&mut re, &mut im);
+
+    re.iter()
+        .zip(im.iter())
+        .map(|(r, i)| (r * r + i * i).sqrt())
+        .collect()
+}
+
+/// In-place iterative radix-2 Cooley-Tukey FFT (decimation-in-time).
+/// `re`/`im` must have a power-of-two length.
+fn fft_in_place(re: &mut [f64], im: &mut [f64]) {
+    let n = re.len();
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let ang = -2.0 * PI / len as f64;
+        let (w_re, w_im) = (ang.cos(), ang.sin());
+        let mut i = 0;
+        while i < n {
+            let (mut cur_re, mut cur_im) = (1.0, 0.0);
+            for k in 0..len / 2 {
+                let u_re = re[i + k];
+                let u_im = im[i + k];
+                let v_re = re[i + k + len / 2] * cur_re - im[i + k + len / 2] * cur_im;
+                let v_im = re[i + k + len / 2] * cur_im + im[i + k + len / 2] * cur_re;
+
+                re[i + k] = u_re + v_re;
+                im[i + k] = u_im + v_im;
+                re[i + k + len / 2] = u_re - v_re;
+                im[i + k + len / 2] = u_im - v_im;
+
+                let next_re = cur_re * w_re - cur_im * w_im;
+                let next_im = cur_re * w_im + cur_im * w_re;
+                cur_re = next_re;
+                cur_im = next_im;
+            }
+            i += len;
+        }
+        len <<= 1;
+    }
+}
+
+/// Recommend a sample interval at least ~2x faster (Nyquist) than the
+/// dominant detected oscillation, without going below `floor_ms`. Leaves
+/// `current_ms` unchanged if it's already fast enough - pair with
+/// [`crate::validate_sample_interval_ms`] to clamp the result into the
+/// supported range before applying it.
+pub fn recommend_sample_interval_ms(peak: &SpectralPeak, current_ms: u64, floor_ms: u64) -> u64 {
+    if peak.frequency_hz <= 0.0 {
+        return current_ms;
+    }
+    let nyquist_ms = (1000.0 / (2.0 * peak.frequency_hz)).floor().max(floor_ms as f64) as u64;
+    current_ms.min(nyquist_ms).max(floor_ms)
+}
+
+/// Whether a detected peak represents a sustained, high-amplitude
+/// oscillation worth flagging as a stability risk (e.g. during a stress
+/// test).
+pub fn is_unstable_oscillation(peak: &SpectralPeak, amplitude_threshold: f64) -> bool {
+    peak.magnitude >= amplitude_threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_non_power_of_two_size() {
+        assert!(OscillationDetector::new(0).is_err());
+        assert!(OscillationDetector::new(100).is_err());
+        assert!(OscillationDetector::new(256).is_ok());
+    }
+
+    #[test]
+    fn test_not_ready_until_window_fills() {
+        let mut detector = OscillationDetector::new(8).unwrap();
+        assert!(!detector.is_ready());
+        for _ in 0..7 {
+            detector.push_sample(50.0);
+        }
+        assert!(!detector.is_ready());
+        detector.push_sample(50.0);
+        assert!(detector.is_ready());
+    }
+
+    #[test]
+    fn test_returns_none_before_ready() {
+        let mut detector = OscillationDetector::new(8).unwrap();
+        detector.push_sample(50.0);
+        assert!(detector.dominant_peak(100).is_none());
+    }
+
+    #[test]
+    fn test_detects_dominant_sine_frequency() {
+        // 100ms sample interval -> 10 Hz sample rate. A 1 Hz sine over a
+        // 256-sample window should produce a clear peak near bin
+        // (1 Hz / 10 Hz) * 256 = 25.6, i.e. bin 25 or 26.
+        let mut detector = OscillationDetector::new(DEFAULT_FFT_SIZE).unwrap();
+        let sample_interval_ms = 100u64;
+        let sample_rate_hz = 1000.0 / sample_interval_ms as f64;
+
+        for i in 0..DEFAULT_FFT_SIZE {
+            let t = i as f64 / sample_rate_hz;
+            let value = 50.0 + 20.0 * (2.0 * PI * 1.0 * t).sin();
+            detector.push_sample(value);
+        }
+
+        let peak = detector.dominant_peak(sample_interval_ms).unwrap();
+        assert!(
+            (24..=27).contains(&peak.bin),
+            "expected dominant bin near 25-26, got {}",
+            peak.bin
+        );
+        assert!((peak.frequency_hz - 1.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_recommend_sample_interval_speeds_up_for_fast_oscillation() {
+        let peak = SpectralPeak {
+            bin: 1,
+            frequency_hz: 5.0,
+            magnitude: 100.0,
+        };
+        // Nyquist for 5 Hz is 100ms; current 500ms should be sped up to it.
+        assert_eq!(recommend_sample_interval_ms(&peak, 500, 10), 100);
+    }
+
+    #[test]
+    fn test_recommend_sample_interval_keeps_already_fast_interval() {
+        let peak = SpectralPeak {
+            bin: 1,
+            frequency_hz: 1.0,
+            magnitude: 100.0,
+        };
+        // Nyquist for 1 Hz is 500ms; a faster 100ms interval is left alone.
+        assert_eq!(recommend_sample_interval_ms(&peak, 100, 10), 100);
+    }
+
+    #[test]
+    fn test_recommend_sample_interval_respects_floor() {
+        let peak = SpectralPeak {
+            bin: 1,
+            frequency_hz: 1000.0,
+            magnitude: 100.0,
+        };
+        assert_eq!(recommend_sample_interval_ms(&peak, 500, 10), 10);
+    }
+
+    #[test]
+    fn test_is_unstable_oscillation_thresholds_on_magnitude() {
+        let peak = SpectralPeak {
+            bin: 5,
+            frequency_hz: 2.0,
+            magnitude: 30.0,
+        };
+        assert!(is_unstable_oscillation(&peak, 25.0));
+        assert!(!is_unstable_oscillation(&peak, 35.0));
+    }
+}
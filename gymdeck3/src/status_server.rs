@@ -0,0 +1,139 @@
+//! Binary, length-prefixed status streaming over a Unix socket
+//!
+//! `control::ControlServer` already fans the NDJSON status stream out to
+//! connected clients, but it shares its bidirectional command/response
+//! connection with line-delimited JSON, so every frame has to be scanned
+//! for a trailing newline. `StatusServer` opens a second, status-only Unix
+//! socket (path via `--status-socket`) and instead frames each update as a
+//! 4-byte big-endian length prefix followed by the same JSON payload
+//! `OutputWriter` already produces, so a long-lived UI can subscribe once
+//! and read fixed-size frames without re-spawning the daemon or scanning
+//! for line boundaries. Stdout NDJSON keeps flowing regardless; both
+//! consume the same `broadcast::Sender<String>` as the control socket, so
+//! enabling one doesn't change what the other sees.
+//!
+//! A client that can't keep up gets its connection dropped (on
+//! `broadcast::error::RecvError::Lagged`) rather than let a stalled reader
+//! hold up the broadcast channel for everyone else.
+
+use std::path::Path;
+
+use tokio::io::AsyncWriteExt;
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::broadcast;
+
+/// Unix domain socket that streams length-prefixed status frames to every
+/// connected client
+pub struct StatusServer {
+    listener: UnixListener,
+    status_tx: broadcast::Sender<String>,
+}
+
+impl StatusServer {
+    /// Bind a new status server at `path`, removing a stale socket file
+    /// left over from a previous unclean shutdown
+    pub fn bind(path: &Path, status_tx: broadcast::Sender<String>) -> std::io::Result<Self> {
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+
+        Ok(Self {
+            listener: UnixListener::bind(path)?,
+            status_tx,
+        })
+    }
+
+    /// Accept a single pending connection
+    ///
+    /// Split out of a `run` loop so the main loop can fold status-socket
+    /// accepts into its own `tokio::select!` as just another event source,
+    /// same as `control::ControlServer::accept_connection`.
+    pub async fn accept_connection(&self) -> std::io::Result<UnixStream> {
+        let (stream, _addr) = self.listener.accept().await?;
+        Ok(stream)
+    }
+
+    /// Spawn the per-client status-forwarding loop for an accepted connection
+    pub fn spawn_client(&self, stream: UnixStream) {
+        let status_rx = self.status_tx.subscribe();
+        tokio::spawn(handle_connection(stream, status_rx));
+    }
+}
+
+/// Forward the shared status stream to one client as length-prefixed
+/// frames, until the client disconnects, a write fails, or it falls too
+/// far behind to catch up
+async fn handle_connection(mut stream: UnixStream, mut status_rx: broadcast::Receiver<String>) {
+    loop {
+        let line = match status_rx.recv().await {
+            Ok(line) => line,
+            Err(broadcast::error::RecvError::Lagged(_)) => break,
+            Err(broadcast::error::RecvError::Closed) => break,
+        };
+
+        if write_frame(&mut stream, &line).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Write one length-prefixed frame: a 4-byte big-endian length followed by
+/// the payload bytes
+async fn write_frame(stream: &mut UnixStream, payload: &str) -> std::io::Result<()> {
+    let bytes = payload.as_bytes();
+    let len = u32::try_from(bytes.len())
+        .map_err(|_| std::io::Error::new(std::io::ErrorKind::InvalidData, "frame too large"))?;
+    stream.write_all(&len.to_be_bytes()).await?;
+    stream.write_all(bytes).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+
+    #[tokio::test]
+    async fn test_write_frame_round_trips_length_and_payload() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("status.sock");
+        let listener = UnixListener::bind(&path).unwrap();
+
+        let client = tokio::spawn(UnixStream::connect(path));
+        let (mut server_stream, _) = listener.accept().await.unwrap();
+        let mut client_stream = client.await.unwrap().unwrap();
+
+        write_frame(&mut server_stream, "hello").await.unwrap();
+
+        let mut len_buf = [0u8; 4];
+        client_stream.read_exact(&mut len_buf).await.unwrap();
+        let len = u32::from_be_bytes(len_buf) as usize;
+        assert_eq!(len, 5);
+
+        let mut payload = vec![0u8; len];
+        client_stream.read_exact(&mut payload).await.unwrap();
+        assert_eq!(payload, b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_spawn_client_forwards_broadcast_status() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("status.sock");
+
+        let (tx, _rx) = broadcast::channel(16);
+        let server = StatusServer::bind(&path, tx.clone()).unwrap();
+
+        let client = tokio::spawn(UnixStream::connect(path));
+        let (server_stream, _) = server.listener.accept().await.unwrap();
+        let mut client_stream = client.await.unwrap().unwrap();
+        server.spawn_client(server_stream);
+
+        tx.send(r#"{"type":"status"}"#.to_string()).unwrap();
+
+        let mut len_buf = [0u8; 4];
+        client_stream.read_exact(&mut len_buf).await.unwrap();
+        let len = u32::from_be_bytes(len_buf) as usize;
+        let mut payload = vec![0u8; len];
+        client_stream.read_exact(&mut payload).await.unwrap();
+        assert_eq!(payload, br#"{"type":"status"}"#);
+    }
+}
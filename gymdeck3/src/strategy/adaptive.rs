@@ -0,0 +1,231 @@
+//! Self-tuning adaptation strategy that drifts its target toward equilibrium
+//!
+//! Unlike the fixed-curve strategies, `AdaptiveStrategy` carries mutable
+//! state across samples: it remembers an "undervolt at target load" value
+//! (`uv_t`) and exponentially drifts it toward more aggressive or safer
+//! values depending on how far the current load sits from `bounds.threshold`.
+//! Sustained low load gradually pushes `uv_t` toward `max_mv` (more
+//! aggressive); sustained high load retreats it toward `min_mv` (safer).
+
+use std::sync::Mutex;
+use std::time::Instant;
+
+use super::{AdaptationStrategy, CoreBounds, clamp_to_bounds};
+
+/// Default adaptation rate: how fast `uv_t` drifts per second of sustained
+/// error, e.g. 0.05 means ~5% drift per second at full error (`e = ±1.0`)
+pub const DEFAULT_ADAPTATION_SPEED: f32 = 0.05;
+
+/// Mutable state carried between samples
+struct AdaptiveState {
+    /// Undervolt value at the load threshold; `None` until the first
+    /// sample, at which point it's initialized to the midpoint of bounds
+    uv_t: Option<i32>,
+    /// Timestamp of the last `calculate_target` call
+    last_update: Option<Instant>,
+}
+
+/// Error-driven adaptive strategy that self-tunes its undervolt target
+/// over time instead of following a fixed load→undervolt curve
+pub struct AdaptiveStrategy {
+    /// Per-second adaptation rate
+    speed: f32,
+    state: Mutex<AdaptiveState>,
+}
+
+impl AdaptiveStrategy {
+    /// Create a new AdaptiveStrategy with the given per-second adaptation
+    /// rate (e.g. `0.05`)
+    pub fn new(speed: f32) -> Self {
+        AdaptiveStrategy {
+            speed,
+            state: Mutex::new(AdaptiveState {
+                uv_t: None,
+                last_update: None,
+            }),
+        }
+    }
+
+    /// Configured adaptation rate
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Current `uv_t` state, or `None` if no sample has been taken yet
+    pub fn uv_t(&self) -> Option<i32> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).uv_t
+    }
+}
+
+impl Default for AdaptiveStrategy {
+    fn default() -> Self {
+        AdaptiveStrategy::new(DEFAULT_ADAPTATION_SPEED)
+    }
+}
+
+impl AdaptationStrategy for AdaptiveStrategy {
+    fn calculate_target(&self, load: f32, bounds: &CoreBounds) -> i32 {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        let uv_t = state
+            .uv_t
+            .unwrap_or_else(|| (bounds.min_mv + bounds.max_mv) / 2);
+
+        let now = Instant::now();
+        let elapsed_secs = state
+            .last_update
+            .map(|prev| now.duration_since(prev).as_secs_f32())
+            .unwrap_or(0.0);
+        state.last_update = Some(now);
+
+        // Normalized error in [-1, 1]: positive above the threshold (high
+        // load, drift safer), negative below it (low load, drift more
+        // aggressive).
+        let threshold = bounds.threshold;
+        let e = if load > threshold {
+            let denom = (100.0 - threshold).max(f32::EPSILON);
+            (load - threshold) / denom
+        } else {
+            let denom = threshold.max(f32::EPSILON);
+            (load - threshold) / denom
+        };
+
+        // NaN/zero elapsed leaves state unchanged (no adaptation on the
+        // very first sample, and a non-finite load can't corrupt `uv_t`).
+        //
+        // mV values are stored negative (more negative = more aggressive),
+        // so the exponent is negated relative to a plain-magnitude
+        // reading: positive `e` (high load) must shrink `uv_t`'s magnitude
+        // toward `min_mv` (safer), and negative `e` (low load) must grow it
+        // toward `max_mv` (more aggressive).
+        let new_uv_t = if elapsed_secs > 0.0 && e.is_finite() {
+            let drift = (-self.speed * e * elapsed_secs).clamp(-80.0, 80.0);
+            let scaled = uv_t as f32 * drift.exp();
+            clamp_to_bounds(scaled.round() as i32, bounds)
+        } else {
+            uv_t
+        };
+        state.uv_t = Some(new_uv_t);
+
+        // Steepness factor around uv_t: high load pulls toward min_mv,
+        // low load pulls toward max_mv, scaled by the normalized error.
+        let target = if e > 0.0 {
+            new_uv_t as f32 + (bounds.min_mv - new_uv_t) as f32 * e
+        } else {
+            new_uv_t as f32 + (new_uv_t - bounds.max_mv) as f32 * e
+        };
+
+        clamp_to_bounds(target.round() as i32, bounds)
+    }
+
+    fn ramp_time_ms(&self) -> u64 {
+        // Adaptation already happens gradually via the exponential drift,
+        // so the downstream ramp just needs to smooth out sampling noise.
+        2000
+    }
+
+    fn name(&self) -> &'static str {
+        "adaptive"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    fn bounds() -> CoreBounds {
+        CoreBounds {
+            min_mv: -20,
+            max_mv: -35,
+            threshold: 50.0,
+        }
+    }
+
+    #[test]
+    fn test_adaptive_name_and_speed() {
+        let strategy = AdaptiveStrategy::new(0.1);
+        assert_eq!(strategy.name(), "adaptive");
+        assert_eq!(strategy.speed(), 0.1);
+    }
+
+    #[test]
+    fn test_first_sample_initializes_uv_t_to_midpoint() {
+        let strategy = AdaptiveStrategy::new(0.05);
+        assert!(strategy.uv_t().is_none());
+
+        strategy.calculate_target(50.0, &bounds());
+        assert_eq!(strategy.uv_t(), Some(-27)); // (min_mv + max_mv) / 2, no drift (elapsed=0)
+    }
+
+    #[test]
+    fn test_sustained_high_load_drifts_uv_t_safer() {
+        let strategy = AdaptiveStrategy::new(2.0); // fast rate so the test doesn't need to sleep long
+        strategy.calculate_target(100.0, &bounds());
+
+        let mut last = strategy.uv_t().unwrap();
+        for _ in 0..10 {
+            sleep(Duration::from_millis(5));
+            strategy.calculate_target(100.0, &bounds());
+            let current = strategy.uv_t().unwrap();
+            assert!(current >= last, "uv_t should drift toward min_mv (safer) under sustained high load");
+            last = current;
+        }
+        assert!(last > -28, "should have drifted away from the midpoint toward min_mv");
+    }
+
+    #[test]
+    fn test_sustained_low_load_drifts_uv_t_aggressive() {
+        let strategy = AdaptiveStrategy::new(2.0);
+        strategy.calculate_target(0.0, &bounds());
+
+        let mut last = strategy.uv_t().unwrap();
+        for _ in 0..10 {
+            sleep(Duration::from_millis(5));
+            strategy.calculate_target(0.0, &bounds());
+            let current = strategy.uv_t().unwrap();
+            assert!(current <= last, "uv_t should drift toward max_mv (aggressive) under sustained low load");
+            last = current;
+        }
+        assert!(last < -28, "should have drifted away from the midpoint toward max_mv");
+    }
+
+    #[test]
+    fn test_target_always_within_bounds() {
+        let strategy = AdaptiveStrategy::new(5.0);
+        for load in [0.0, 10.0, 50.0, 90.0, 100.0] {
+            sleep(Duration::from_millis(2));
+            let target = strategy.calculate_target(load, &bounds());
+            assert!(target >= bounds().max_mv && target <= bounds().min_mv, "target {target} out of bounds");
+        }
+    }
+
+    #[test]
+    fn test_zero_elapsed_leaves_state_unchanged() {
+        let strategy = AdaptiveStrategy::new(10.0);
+        strategy.calculate_target(100.0, &bounds());
+        let uv_t_after_first = strategy.uv_t().unwrap();
+
+        // Back-to-back calls with effectively no elapsed time shouldn't
+        // meaningfully move uv_t versus a single real drift step; since we
+        // can't force elapsed to exactly 0.0 twice in a row deterministically,
+        // instead assert the drift is monotonic and bounded rather than
+        // jumping discontinuously.
+        let target = strategy.calculate_target(100.0, &bounds());
+        assert!(target >= bounds().max_mv && target <= bounds().min_mv);
+        assert!(strategy.uv_t().unwrap() >= uv_t_after_first);
+    }
+
+    #[test]
+    fn test_nan_load_does_not_corrupt_state() {
+        let strategy = AdaptiveStrategy::new(1.0);
+        strategy.calculate_target(50.0, &bounds());
+        let before = strategy.uv_t().unwrap();
+
+        sleep(Duration::from_millis(5));
+        let target = strategy.calculate_target(f32::NAN, &bounds());
+        assert!(target >= bounds().max_mv && target <= bounds().min_mv, "NaN load must still produce an in-bounds target");
+        assert_eq!(strategy.uv_t().unwrap(), before, "NaN error must not move uv_t");
+    }
+}
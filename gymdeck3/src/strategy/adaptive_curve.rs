@@ -0,0 +1,258 @@
+//! Adaptive-curve strategy: a setpoint that drifts toward equilibrium
+//!
+//! Same family as `AdaptiveStrategy`, but modeled explicitly on adaptive
+//! interest-rate curves: `mv_at_target` is "the undervolt offset at
+//! equilibrium load" (`bounds.threshold`), and it exponentially drifts
+//! toward more or less aggressive values the longer real load sits above
+//! or below that setpoint. Unlike `AdaptiveStrategy`, the drift rate `k`
+//! and error-normalization `scale` are both caller-supplied, and the
+//! per-tick update takes an explicit `dt_ms` instead of sampling the wall
+//! clock - so a driver like `VoltageController` can advance it on its own
+//! schedule (e.g. from a fixed sample interval) rather than whatever time
+//! actually elapsed between calls.
+
+use std::sync::Mutex;
+use std::time::Instant;
+
+use super::{clamp_to_bounds, AdaptationStrategy, CoreBounds};
+
+/// Default drift rate `k`: adjustment speed per second of sustained error
+pub const DEFAULT_K: f32 = 0.05;
+
+/// Default error-normalization `scale`: load percentage points that map to
+/// a fully-saturated error of +/-1.0
+pub const DEFAULT_SCALE: f32 = 50.0;
+
+/// Setpoint-tracking adaptive strategy, explicitly `dt_ms`-driven so a
+/// caller can tick it deterministically instead of relying on `Instant`
+/// sampling between calls.
+pub struct AdaptiveCurveStrategy {
+    /// Per-second adjustment speed
+    k: f32,
+    /// Load-percentage-point scale that normalizes error to [-1, 1]
+    scale: f32,
+    /// Undervolt offset at equilibrium load (`bounds.threshold`); `None`
+    /// until the first tick, at which point it's initialized to the
+    /// midpoint of bounds
+    mv_at_target: Mutex<Option<i32>>,
+    /// Timestamp of the last `calculate_target` call, used only to derive
+    /// `dt_ms` for callers that drive this strategy through the
+    /// `AdaptationStrategy` trait instead of calling `update` directly
+    last_update: Mutex<Option<Instant>>,
+}
+
+impl AdaptiveCurveStrategy {
+    /// Create a new AdaptiveCurveStrategy with the given drift rate `k`
+    /// (per-second adjustment speed) and error `scale` (load-percentage
+    /// points that saturate the normalized error to +/-1.0)
+    pub fn new(k: f32, scale: f32) -> Self {
+        AdaptiveCurveStrategy {
+            k,
+            scale,
+            mv_at_target: Mutex::new(None),
+            last_update: Mutex::new(None),
+        }
+    }
+
+    /// Configured drift rate
+    pub fn k(&self) -> f32 {
+        self.k
+    }
+
+    /// Configured error-normalization scale
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Current `mv_at_target` state, or `None` if no tick has happened yet
+    pub fn mv_at_target(&self) -> Option<i32> {
+        *self.mv_at_target.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Advance the setpoint by `dt_ms` and return the instantaneous target
+    /// for `load`. This is the method a driver like `VoltageController`
+    /// should call with its own sample interval; `calculate_target` (the
+    /// `AdaptationStrategy` trait method) just derives `dt_ms` from wall
+    /// time and forwards here.
+    pub fn update(&self, load: f32, dt_ms: u64, bounds: &CoreBounds) -> i32 {
+        let mut mv_at_target = self.mv_at_target.lock().unwrap_or_else(|e| e.into_inner());
+        let current = mv_at_target.unwrap_or_else(|| (bounds.min_mv + bounds.max_mv) / 2);
+
+        // Signed error in [-1, 1]: positive above the threshold (high
+        // load, drift safer), negative below it (low load, drift more
+        // aggressive).
+        let scale = self.scale.max(f32::EPSILON);
+        let e = ((load - bounds.threshold) / scale).clamp(-1.0, 1.0);
+
+        let dt = dt_ms as f32 / 1000.0;
+
+        // mV values are stored negative (more negative = more
+        // aggressive), so the exponent is negated relative to a
+        // plain-magnitude reading: positive `e` must shrink the
+        // setpoint's magnitude toward `min_mv` (safer), negative `e` must
+        // grow it toward `max_mv` (more aggressive).
+        let new_mv_at_target = if dt > 0.0 && e.is_finite() {
+            let drift = (-self.k * e * dt).clamp(-80.0, 80.0);
+            let scaled = current as f32 * drift.exp();
+            clamp_to_bounds(scaled.round() as i32, bounds)
+        } else {
+            current
+        };
+        *mv_at_target = Some(new_mv_at_target);
+        drop(mv_at_target);
+
+        // Fixed curve around the setpoint: high load pulls toward
+        // min_mv, low load pulls toward max_mv, scaled by the same
+        // normalized error used to drift the setpoint.
+        let target = if e > 0.0 {
+            new_mv_at_target as f32 + (bounds.min_mv - new_mv_at_target) as f32 * e
+        } else {
+            new_mv_at_target as f32 + (new_mv_at_target - bounds.max_mv) as f32 * e
+        };
+
+        clamp_to_bounds(target.round() as i32, bounds)
+    }
+}
+
+impl Default for AdaptiveCurveStrategy {
+    fn default() -> Self {
+        AdaptiveCurveStrategy::new(DEFAULT_K, DEFAULT_SCALE)
+    }
+}
+
+impl AdaptationStrategy for AdaptiveCurveStrategy {
+    fn calculate_target(&self, load: f32, bounds: &CoreBounds) -> i32 {
+        let dt_ms = {
+            let mut last_update = self.last_update.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            let dt_ms = last_update
+                .map(|prev| now.duration_since(prev).as_millis() as u64)
+                .unwrap_or(0);
+            *last_update = Some(now);
+            dt_ms
+        };
+
+        self.update(load, dt_ms, bounds)
+    }
+
+    fn ramp_time_ms(&self) -> u64 {
+        // The setpoint already drifts gradually; the downstream ramp just
+        // needs to smooth out sampling noise.
+        2000
+    }
+
+    fn name(&self) -> &'static str {
+        "adaptive-curve"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds() -> CoreBounds {
+        CoreBounds {
+            min_mv: -20,
+            max_mv: -35,
+            threshold: 50.0,
+        }
+    }
+
+    #[test]
+    fn test_adaptive_curve_name_k_and_scale() {
+        let strategy = AdaptiveCurveStrategy::new(0.1, 40.0);
+        assert_eq!(strategy.name(), "adaptive-curve");
+        assert_eq!(strategy.k(), 0.1);
+        assert_eq!(strategy.scale(), 40.0);
+    }
+
+    #[test]
+    fn test_default_matches_documented_constants() {
+        let strategy = AdaptiveCurveStrategy::default();
+        assert_eq!(strategy.k(), DEFAULT_K);
+        assert_eq!(strategy.scale(), DEFAULT_SCALE);
+    }
+
+    #[test]
+    fn test_first_tick_initializes_setpoint_to_midpoint() {
+        let strategy = AdaptiveCurveStrategy::new(0.05, 50.0);
+        assert!(strategy.mv_at_target().is_none());
+
+        strategy.update(50.0, 0, &bounds());
+        assert_eq!(strategy.mv_at_target(), Some(-27)); // (min_mv + max_mv) / 2, no drift (dt=0)
+    }
+
+    #[test]
+    fn test_sustained_high_load_drifts_setpoint_safer() {
+        let strategy = AdaptiveCurveStrategy::new(2.0, 50.0);
+        strategy.update(100.0, 0, &bounds());
+
+        let mut last = strategy.mv_at_target().unwrap();
+        for _ in 0..10 {
+            strategy.update(100.0, 50, &bounds());
+            let current = strategy.mv_at_target().unwrap();
+            assert!(current >= last, "setpoint should drift toward min_mv (safer) under sustained high load");
+            last = current;
+        }
+        assert!(last > -28, "should have drifted away from the midpoint toward min_mv");
+    }
+
+    #[test]
+    fn test_sustained_low_load_drifts_setpoint_aggressive() {
+        let strategy = AdaptiveCurveStrategy::new(2.0, 50.0);
+        strategy.update(0.0, 0, &bounds());
+
+        let mut last = strategy.mv_at_target().unwrap();
+        for _ in 0..10 {
+            strategy.update(0.0, 50, &bounds());
+            let current = strategy.mv_at_target().unwrap();
+            assert!(current <= last, "setpoint should drift toward max_mv (aggressive) under sustained low load");
+            last = current;
+        }
+        assert!(last < -28, "should have drifted away from the midpoint toward max_mv");
+    }
+
+    #[test]
+    fn test_zero_dt_leaves_setpoint_unchanged() {
+        let strategy = AdaptiveCurveStrategy::new(10.0, 50.0);
+        strategy.update(100.0, 0, &bounds());
+        let setpoint = strategy.mv_at_target().unwrap();
+
+        strategy.update(100.0, 0, &bounds());
+        assert_eq!(strategy.mv_at_target().unwrap(), setpoint);
+    }
+
+    #[test]
+    fn test_target_always_within_bounds() {
+        let strategy = AdaptiveCurveStrategy::new(5.0, 50.0);
+        for load in [0.0, 10.0, 50.0, 90.0, 100.0] {
+            let target = strategy.update(load, 10, &bounds());
+            assert!(target >= bounds().max_mv && target <= bounds().min_mv, "target {target} out of bounds");
+        }
+    }
+
+    #[test]
+    fn test_nan_load_does_not_corrupt_setpoint() {
+        let strategy = AdaptiveCurveStrategy::new(1.0, 50.0);
+        strategy.update(50.0, 0, &bounds());
+        let before = strategy.mv_at_target().unwrap();
+
+        let target = strategy.update(f32::NAN, 10, &bounds());
+        assert!(target >= bounds().max_mv && target <= bounds().min_mv, "NaN load must still produce an in-bounds target");
+        assert_eq!(strategy.mv_at_target().unwrap(), before, "NaN error must not move the setpoint");
+    }
+
+    #[test]
+    fn test_calculate_target_derives_dt_from_wall_clock() {
+        use std::thread::sleep;
+        use std::time::Duration;
+
+        let strategy = AdaptiveCurveStrategy::new(2.0, 50.0);
+        strategy.calculate_target(100.0, &bounds());
+        let first = strategy.mv_at_target().unwrap();
+
+        sleep(Duration::from_millis(5));
+        strategy.calculate_target(100.0, &bounds());
+        assert!(strategy.mv_at_target().unwrap() >= first);
+    }
+}
@@ -0,0 +1,479 @@
+//! Auto-calibration: fit a per-core `CustomStrategy` curve from measured
+//! stability data
+//!
+//! `CurveOptimizer` searches for a good curve against a caller-supplied
+//! objective function. `Calibrator` instead *fits* a curve to observations
+//! recorded live during a session: `(load, applied_mv, stable?)` samples
+//! gathered from `MetricsMonitor`/`VoltageController` as the user plays.
+//! Observations are bucketed onto a small set of load anchors (control
+//! points), and each anchor's undervolt value is fit by nonlinear least
+//! squares via Levenberg-Marquardt: the residual pulls the anchor toward
+//! the most aggressive value observed stable at that load, while a
+//! large-weight penalty term pushes it back above (less aggressive than)
+//! any value that caused a crash there.
+//!
+//! The fitted control points become a `CustomStrategy`, whose own
+//! constructor already repairs non-monotone curves and whose values are
+//! clamped to `CoreBounds` before construction - so the result is always
+//! safe to feed straight into `create_strategy` or run directly.
+
+use super::{clamp_to_bounds, optimizer::DEFAULT_ANCHOR_LOADS, CoreBounds, CustomStrategy};
+
+/// Default LM damping parameter `lambda`'s starting value
+pub const DEFAULT_INITIAL_LAMBDA: f64 = 1e-2;
+
+/// Default cap on Levenberg-Marquardt iterations
+pub const DEFAULT_MAX_ITERATIONS: usize = 100;
+
+/// Default convergence tolerance on the gradient norm `||Jᵀr||`
+pub const DEFAULT_GRADIENT_TOLERANCE: f64 = 1e-3;
+
+/// Default weight of the crash-penalty residual, relative to the
+/// stable-fit residual
+pub const DEFAULT_CRASH_PENALTY_WEIGHT: f64 = 1000.0;
+
+/// One `(load, applied_mv, stable?)` sample from a calibration session
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StabilityObservation {
+    /// CPU load percentage (0-100) at the time this sample was taken
+    pub load: f32,
+    /// Undervolt offset that was applied at this load, in mV
+    pub applied_mv: i32,
+    /// Whether the system stayed stable (`true`) or crashed/hung (`false`)
+    /// at this `applied_mv`
+    pub stable: bool,
+}
+
+/// Collects `StabilityObservation`s over a calibration session and fits a
+/// safe per-core undervolt curve from them
+pub struct Calibrator {
+    anchors: Vec<f32>,
+    observations: Vec<StabilityObservation>,
+    crash_penalty_weight: f64,
+    max_iterations: usize,
+    gradient_tolerance: f64,
+}
+
+impl Calibrator {
+    /// Create a calibrator with the default load anchors and LM tuning
+    pub fn new() -> Self {
+        Calibrator {
+            anchors: DEFAULT_ANCHOR_LOADS.to_vec(),
+            observations: Vec::new(),
+            crash_penalty_weight: DEFAULT_CRASH_PENALTY_WEIGHT,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            gradient_tolerance: DEFAULT_GRADIENT_TOLERANCE,
+        }
+    }
+
+    /// Use a custom set of load anchors (control points) instead of
+    /// `DEFAULT_ANCHOR_LOADS`; must be sorted ascending
+    pub fn with_anchors(mut self, anchors: Vec<f32>) -> Self {
+        self.anchors = anchors;
+        self
+    }
+
+    /// Override the crash-penalty residual weight
+    pub fn with_crash_penalty_weight(mut self, weight: f64) -> Self {
+        self.crash_penalty_weight = weight;
+        self
+    }
+
+    /// Override the maximum number of LM iterations
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Override the gradient-norm convergence tolerance
+    pub fn with_gradient_tolerance(mut self, gradient_tolerance: f64) -> Self {
+        self.gradient_tolerance = gradient_tolerance;
+        self
+    }
+
+    /// Record one observation from the calibration session
+    pub fn record(&mut self, load: f32, applied_mv: i32, stable: bool) {
+        self.observations.push(StabilityObservation {
+            load,
+            applied_mv,
+            stable,
+        });
+    }
+
+    /// All observations recorded so far
+    pub fn observations(&self) -> &[StabilityObservation] {
+        &self.observations
+    }
+
+    /// Fit a `CustomStrategy` curve to the recorded observations
+    ///
+    /// Each anchor's undervolt value is fit independently by
+    /// Levenberg-Marquardt against the bucket of observations nearest that
+    /// load. Anchors with no stable observations fall back to
+    /// `bounds.min_mv` (the safest value); the result is clamped to
+    /// `bounds` and handed to `CustomStrategy::new`, which repairs any
+    /// remaining monotonicity violations.
+    pub fn fit(&self, bounds: &CoreBounds) -> CustomStrategy {
+        let buckets = self.bucket_observations();
+
+        // Start from the same straight-line curve CurveOptimizer and
+        // BalancedStrategy use - always a valid point to descend from.
+        let mut theta: Vec<f64> = self
+            .anchors
+            .iter()
+            .map(|&load| super::lerp(bounds.max_mv, bounds.min_mv, load / 100.0) as f64)
+            .collect();
+
+        if theta.is_empty() {
+            return CustomStrategy::new(Vec::new());
+        }
+
+        let mut lambda = DEFAULT_INITIAL_LAMBDA;
+        let mut cost = self.cost(&theta, &buckets, bounds);
+
+        for _ in 0..self.max_iterations {
+            let residuals = self.residuals(&theta, &buckets, bounds);
+            let jacobian = self.jacobian(&theta, &buckets, bounds);
+
+            let gradient = jt_r(&jacobian, &residuals);
+            if norm(&gradient) < self.gradient_tolerance {
+                break;
+            }
+
+            let jtj = jt_j(&jacobian);
+
+            // Try increasingly damped steps until one actually reduces cost
+            // (or we give up and keep the current theta for this round).
+            let mut improved = false;
+            for _ in 0..8 {
+                let mut damped = jtj.clone();
+                for (i, row) in damped.iter_mut().enumerate() {
+                    row[i] += lambda * jtj[i][i].max(1e-12);
+                }
+
+                if let Some(step) = solve(&damped, &gradient) {
+                    let candidate: Vec<f64> =
+                        theta.iter().zip(step.iter()).map(|(t, s)| t - s).collect();
+                    let candidate_cost = self.cost(&candidate, &buckets, bounds);
+
+                    if candidate_cost < cost {
+                        theta = candidate;
+                        cost = candidate_cost;
+                        lambda = (lambda * 0.5).max(1e-12);
+                        improved = true;
+                        break;
+                    }
+                }
+
+                lambda *= 2.0;
+            }
+
+            if !improved {
+                break;
+            }
+        }
+
+        let curve: Vec<(f32, i32)> = self
+            .anchors
+            .iter()
+            .zip(theta.iter())
+            .map(|(&load, &mv)| (load, clamp_to_bounds(mv.round() as i32, bounds)))
+            .collect();
+
+        CustomStrategy::new(curve)
+    }
+
+    /// Bucket observations onto the nearest anchor by load distance
+    fn bucket_observations(&self) -> Vec<Vec<StabilityObservation>> {
+        let mut buckets = vec![Vec::new(); self.anchors.len()];
+        for &obs in &self.observations {
+            if let Some(idx) = self.nearest_anchor(obs.load) {
+                buckets[idx].push(obs);
+            }
+        }
+        buckets
+    }
+
+    fn nearest_anchor(&self, load: f32) -> Option<usize> {
+        self.anchors
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (*a - load)
+                    .abs()
+                    .partial_cmp(&(*b - load).abs())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(idx, _)| idx)
+    }
+
+    /// Most-aggressive (lowest) mV observed stable in a bucket, falling
+    /// back to `bounds.min_mv` (safest) when no stable sample exists
+    fn stable_target(bucket: &[StabilityObservation], bounds: &CoreBounds) -> f64 {
+        bucket
+            .iter()
+            .filter(|o| o.stable)
+            .map(|o| o.applied_mv)
+            .min()
+            .unwrap_or(bounds.min_mv) as f64
+    }
+
+    /// Least-aggressive (highest) mV observed to crash in a bucket, if any
+    fn crash_ceiling(bucket: &[StabilityObservation]) -> Option<f64> {
+        bucket
+            .iter()
+            .filter(|o| !o.stable)
+            .map(|o| o.applied_mv)
+            .max()
+            .map(|mv| mv as f64)
+    }
+
+    /// Residual vector: two entries per anchor (fit residual, crash-penalty
+    /// residual), in the same order as `jacobian`
+    fn residuals(
+        &self,
+        theta: &[f64],
+        buckets: &[Vec<StabilityObservation>],
+        bounds: &CoreBounds,
+    ) -> Vec<f64> {
+        let mut r = Vec::with_capacity(theta.len() * 2);
+        for (i, bucket) in buckets.iter().enumerate() {
+            let target = Self::stable_target(bucket, bounds);
+            r.push(theta[i] - target);
+
+            match Self::crash_ceiling(bucket) {
+                Some(ceiling) if theta[i] < ceiling => {
+                    r.push(self.crash_penalty_weight.sqrt() * (ceiling - theta[i]))
+                }
+                _ => r.push(0.0),
+            }
+        }
+        r
+    }
+
+    fn cost(&self, theta: &[f64], buckets: &[Vec<StabilityObservation>], bounds: &CoreBounds) -> f64 {
+        self.residuals(theta, buckets, bounds)
+            .iter()
+            .map(|r| r * r)
+            .sum::<f64>()
+            * 0.5
+    }
+
+    /// Jacobian of `residuals` with respect to `theta`; since every
+    /// residual only involves its own anchor, this is block-diagonal (two
+    /// rows per anchor, one nonzero column each).
+    fn jacobian(
+        &self,
+        theta: &[f64],
+        buckets: &[Vec<StabilityObservation>],
+        _bounds: &CoreBounds,
+    ) -> Vec<Vec<f64>> {
+        let n = theta.len();
+        let mut j = vec![vec![0.0; n]; n * 2];
+        for (i, bucket) in buckets.iter().enumerate() {
+            j[i * 2][i] = 1.0;
+
+            if let Some(ceiling) = Self::crash_ceiling(bucket) {
+                if theta[i] < ceiling {
+                    j[i * 2 + 1][i] = -self.crash_penalty_weight.sqrt();
+                }
+            }
+        }
+        j
+    }
+}
+
+impl Default for Calibrator {
+    fn default() -> Self {
+        Calibrator::new()
+    }
+}
+
+/// `Jᵀ * r`
+fn jt_r(j: &[Vec<f64>], r: &[f64]) -> Vec<f64> {
+    let cols = j.first().map(|row| row.len()).unwrap_or(0);
+    let mut out = vec![0.0; cols];
+    for (row, &ri) in j.iter().zip(r.iter()) {
+        for (c, &jc) in row.iter().enumerate() {
+            out[c] += jc * ri;
+        }
+    }
+    out
+}
+
+/// `Jᵀ * J`
+fn jt_j(j: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let cols = j.first().map(|row| row.len()).unwrap_or(0);
+    let mut out = vec![vec![0.0; cols]; cols];
+    for row in j {
+        for a in 0..cols {
+            if row[a] == 0.0 {
+                continue;
+            }
+            for b in 0..cols {
+                out[a][b] += row[a] * row[b];
+            }
+        }
+    }
+    out
+}
+
+fn norm(v: &[f64]) -> f64 {
+    v.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+/// Solve `a * x = b` via Gaussian elimination with partial pivoting;
+/// returns `None` if `a` is (numerically) singular. Matrix sizes here are
+/// tiny (one row/column per load anchor), so this dense solve is plenty.
+fn solve(a: &[Vec<f64>], b: &[f64]) -> Option<Vec<f64>> {
+    let n = b.len();
+    let mut m: Vec<Vec<f64>> = a.iter().map(|row| row.clone()).collect();
+    let mut rhs = b.to_vec();
+
+    for col in 0..n {
+        let pivot_row = (col..n).max_by(|&i, &j| {
+            m[i][col].abs().partial_cmp(&m[j][col].abs()).unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+
+        if m[pivot_row][col].abs() < 1e-15 {
+            return None;
+        }
+
+        m.swap(col, pivot_row);
+        rhs.swap(col, pivot_row);
+
+        for row in (col + 1)..n {
+            let factor = m[row][col] / m[col][col];
+            if factor == 0.0 {
+                continue;
+            }
+            for k in col..n {
+                m[row][k] -= factor * m[col][k];
+            }
+            rhs[row] -= factor * rhs[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let mut sum = rhs[row];
+        for col in (row + 1)..n {
+            sum -= m[row][col] * x[col];
+        }
+        x[row] = sum / m[row][row];
+    }
+
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::AdaptationStrategy;
+
+    fn bounds() -> CoreBounds {
+        CoreBounds {
+            min_mv: -20,
+            max_mv: -35,
+            threshold: 50.0,
+        }
+    }
+
+    #[test]
+    fn test_fit_with_no_observations_falls_back_to_safe_values() {
+        let calibrator = Calibrator::new();
+        let strategy = calibrator.fit(&bounds());
+
+        for &load in &DEFAULT_ANCHOR_LOADS {
+            let target = strategy.calculate_target(load, &bounds());
+            assert_eq!(target, bounds().min_mv);
+        }
+    }
+
+    #[test]
+    fn test_fit_converges_to_most_aggressive_stable_value() {
+        let mut calibrator = Calibrator::new().with_anchors(vec![0.0, 50.0, 100.0]);
+
+        // At 0% load, -30mV and -28mV were both stable; the fit should
+        // land close to the more aggressive -30mV.
+        calibrator.record(0.0, -30, true);
+        calibrator.record(0.0, -28, true);
+        calibrator.record(0.0, -34, false);
+
+        calibrator.record(50.0, -25, true);
+        calibrator.record(100.0, -20, true);
+
+        let strategy = calibrator.fit(&bounds());
+        let target = strategy.calculate_target(0.0, &bounds());
+        assert!(
+            target <= -28 && target >= -31,
+            "expected target near -30, got {target}"
+        );
+    }
+
+    #[test]
+    fn test_fit_never_crosses_a_known_crash_value() {
+        let mut calibrator = Calibrator::new().with_anchors(vec![0.0, 50.0, 100.0]);
+
+        calibrator.record(0.0, -20, true);
+        calibrator.record(0.0, -25, false); // crashed at -25mV
+
+        let strategy = calibrator.fit(&bounds());
+        let target = strategy.calculate_target(0.0, &bounds());
+        assert!(
+            target > -25,
+            "fitted curve must stay safer than the observed crash at -25mV, got {target}"
+        );
+    }
+
+    #[test]
+    fn test_fit_result_is_always_monotone_and_in_bounds() {
+        let mut calibrator = Calibrator::new().with_anchors(vec![0.0, 25.0, 50.0, 75.0, 100.0]);
+
+        calibrator.record(0.0, -34, true);
+        calibrator.record(25.0, -30, true);
+        calibrator.record(25.0, -32, false);
+        calibrator.record(50.0, -25, true);
+        calibrator.record(75.0, -22, true);
+        calibrator.record(100.0, -20, true);
+
+        let strategy = calibrator.fit(&bounds());
+
+        let mut last = bounds().max_mv;
+        for load in [0.0, 20.0, 25.0, 40.0, 50.0, 60.0, 75.0, 90.0, 100.0] {
+            let target = strategy.calculate_target(load, &bounds());
+            assert!(target >= bounds().max_mv && target <= bounds().min_mv);
+            assert!(target >= last, "curve must stay non-decreasing as load rises");
+            last = target;
+        }
+    }
+
+    #[test]
+    fn test_with_crash_penalty_weight_and_iteration_overrides() {
+        let calibrator = Calibrator::new()
+            .with_crash_penalty_weight(5000.0)
+            .with_max_iterations(10)
+            .with_gradient_tolerance(1e-2);
+
+        // Just exercise the builder chain end-to-end without panicking.
+        let strategy = calibrator.fit(&bounds());
+        let target = strategy.calculate_target(50.0, &bounds());
+        assert!(target >= bounds().max_mv && target <= bounds().min_mv);
+    }
+
+    #[test]
+    fn test_solve_identity_matrix_returns_input() {
+        let a = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+        let b = vec![3.0, 4.0];
+        let x = solve(&a, &b).unwrap();
+        assert!((x[0] - 3.0).abs() < 1e-9);
+        assert!((x[1] - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_solve_singular_matrix_returns_none() {
+        let a = vec![vec![1.0, 1.0], vec![1.0, 1.0]];
+        let b = vec![2.0, 2.0];
+        assert!(solve(&a, &b).is_none());
+    }
+}
@@ -0,0 +1,599 @@
+//! Custom adaptation strategy with user-defined load-to-undervolt curves
+
+use super::{AdaptationStrategy, CoreBounds, clamp_to_bounds};
+
+/// Fixed-point scale factor `interpolate` rescales load deltas into before
+/// any integer arithmetic; 16 bits of fraction comfortably covers the
+/// sub-percent load deltas curve points can have.
+const CUSTOM_FIXED_SCALE: i64 = 1 << 16;
+
+/// Interpolation mode `CustomStrategy` uses between curve points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interp {
+    /// Piecewise-linear; simple, but has a slope discontinuity at every
+    /// knot - audible/visible as stepped voltage transitions.
+    Linear,
+    /// Shape-preserving cubic Hermite spline with Fritsch-Butland tangents
+    /// (weighted harmonic mean of adjacent secants, zeroed wherever they
+    /// change sign). Never overshoots past a knot's neighbors and has a
+    /// continuous derivative, for smoother ramped transitions than
+    /// `Linear` at each knot.
+    MonotoneCubic,
+}
+
+/// Custom strategy with user-defined curve points
+///
+/// This strategy allows users to define their own load-to-undervolt
+/// mapping using a series of (load%, undervolt_mv) points. Values
+/// between points are interpolated per `interp` (see `Interp`).
+///
+/// `new`/`with_interpolation` repair non-monotone input curves (see
+/// `enforce_monotonic`) so every `CustomStrategy` satisfies the
+/// `AdaptationStrategy` contract: higher load always produces a value
+/// within `[max_mv, min_mv]` and no less safe than any lower load's value.
+pub struct CustomStrategy {
+    /// Curve points sorted by load percentage, guaranteed non-decreasing
+    /// in undervolt value (see `enforce_monotonic`)
+    /// Each point is (load%, undervolt_mv)
+    curve: Vec<(f32, i32)>,
+    interp: Interp,
+}
+
+impl CustomStrategy {
+    /// Create a new CustomStrategy with the given curve points,
+    /// interpolating linearly between them (see `with_interpolation` for
+    /// the shape-preserving alternative).
+    ///
+    /// # Arguments
+    /// * `curve` - Vector of (load%, undervolt_mv) points
+    ///
+    /// # Notes
+    /// - Points will be sorted by load percentage
+    /// - At least one point is required
+    /// - If only one point is provided, that value is used for all loads
+    /// - Points are repaired, not rejected, if they violate the
+    ///   `AdaptationStrategy` monotonicity contract (see `enforce_monotonic`)
+    pub fn new(curve: Vec<(f32, i32)>) -> Self {
+        Self::with_interpolation(curve, Interp::Linear)
+    }
+
+    /// Create a new CustomStrategy with the given curve points and
+    /// interpolation mode. See `new` for the curve-repair notes, which
+    /// apply identically here.
+    pub fn with_interpolation(mut curve: Vec<(f32, i32)>, interp: Interp) -> Self {
+        // Sort by load percentage
+        curve.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+        // Ensure at least one point exists
+        if curve.is_empty() {
+            curve.push((0.0, 0)); // Default: no undervolt
+        }
+
+        Self::enforce_monotonic(&mut curve);
+
+        CustomStrategy { curve, interp }
+    }
+
+    /// Repair a sorted curve so undervolt values are non-decreasing as load
+    /// rises (i.e. magnitude is non-increasing), matching the contract
+    /// `AdaptationStrategy` promises: higher load must produce a safer
+    /// (less negative) value. A point that's more aggressive than every
+    /// point before it is lifted up to match rather than rejected, so a
+    /// slightly malformed curve still produces a well-defined, monotone
+    /// strategy instead of a panic or a hard error the caller has to
+    /// handle.
+    fn enforce_monotonic(curve: &mut [(f32, i32)]) {
+        let mut floor = i32::MIN;
+        for point in curve.iter_mut() {
+            if point.1 < floor {
+                point.1 = floor;
+            } else {
+                floor = point.1;
+            }
+        }
+    }
+
+    /// Get the curve points
+    pub fn curve(&self) -> &[(f32, i32)] {
+        &self.curve
+    }
+
+    /// Interpolate the undervolt value for a given load
+    fn interpolate(&self, load: f32) -> i32 {
+        // NaN load must behave like 0.0, not silently fall through to
+        // garbage comparisons below; `f32::max`/`min` already ignore NaN
+        // in one operand, so this clamp also sanitizes it.
+        let load = load.clamp(0.0, 100.0);
+
+        // Handle single point case
+        if self.curve.len() == 1 {
+            return self.curve[0].1;
+        }
+
+        // If load is below first point, use first point value
+        if load <= self.curve[0].0 {
+            return self.curve[0].1;
+        }
+
+        // If load is above last point, use last point value
+        if load >= self.curve[self.curve.len() - 1].0 {
+            return self.curve[self.curve.len() - 1].1;
+        }
+
+        match self.interp {
+            Interp::Linear => self.interpolate_linear(load),
+            Interp::MonotoneCubic => self.interpolate_monotone_cubic(load),
+        }
+    }
+
+    /// Index `i` such that `curve[i].0 <= load <= curve[i + 1].0`. Callers
+    /// must have already handled the single-point and below-first/above-last
+    /// cases, so `load` is strictly inside the curve's domain.
+    fn segment(&self, load: f32) -> usize {
+        let mut idx = 0;
+        for (i, &(point_load, _)) in self.curve.iter().enumerate() {
+            if point_load <= load {
+                idx = i;
+            } else {
+                break;
+            }
+        }
+        idx.min(self.curve.len() - 2)
+    }
+
+    /// Linear interpolation between the two points, using saturating
+    /// fixed-point integer arithmetic so that extreme curve values
+    /// (e.g. near i32::MIN/MAX) can't overflow an `i32` subtraction
+    /// before the result is ever clamped to bounds. `t` is rescaled
+    /// from the load deltas into a `t_num/t_den` fraction and every
+    /// intermediate runs in `i64`.
+    fn interpolate_linear(&self, load: f32) -> i32 {
+        let i = self.segment(load);
+        let (load1, val1) = self.curve[i];
+        let (load2, val2) = self.curve[i + 1];
+
+        let t_den = ((load2 - load1) * CUSTOM_FIXED_SCALE as f32).round() as i64;
+        if t_den == 0 {
+            return val1;
+        }
+        let t_num = ((load - load1) * CUSTOM_FIXED_SCALE as f32).round() as i64;
+
+        let val1 = val1 as i64;
+        let val2 = val2 as i64;
+        let delta = val2.saturating_sub(val1);
+        let offset = delta.saturating_mul(t_num) / t_den;
+
+        val1.saturating_add(offset)
+            .clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+
+    /// Monotone cubic Hermite interpolation (Fritsch-Butland tangents).
+    /// Runs entirely in `f64`, which has enough precision and range to
+    /// carry `i32` curve values through the spline evaluation without the
+    /// saturating-arithmetic dance `interpolate_linear` needs.
+    fn interpolate_monotone_cubic(&self, load: f32) -> i32 {
+        let i = self.segment(load);
+        let (x0, y0) = self.curve[i];
+        let (x1, y1) = self.curve[i + 1];
+
+        // Exact knot hits should return the knot value exactly, not a
+        // spline evaluation that happens to round to it.
+        if load == x0 {
+            return y0;
+        }
+        if load == x1 {
+            return y1;
+        }
+
+        let h = (x1 - x0) as f64;
+        if h == 0.0 {
+            return y0;
+        }
+
+        let tangents = self.monotone_tangents();
+        let t = ((load - x0) as f64) / h;
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+
+        let m0 = tangents[i] * h;
+        let m1 = tangents[i + 1] * h;
+
+        let value = h00 * (y0 as f64) + h10 * m0 + h01 * (y1 as f64) + h11 * m1;
+        value.round().clamp(i32::MIN as f64, i32::MAX as f64) as i32
+    }
+
+    /// Fritsch-Butland tangent at every curve point: the interior tangent
+    /// is a weighted harmonic mean of the two adjacent secant slopes
+    /// (zeroed wherever they change sign, which is exactly where a
+    /// plain average would overshoot and break monotonicity); the
+    /// endpoints use the one-sided secant into their only neighbor.
+    fn monotone_tangents(&self) -> Vec<f64> {
+        let n = self.curve.len();
+        let secants: Vec<f64> = self
+            .curve
+            .windows(2)
+            .map(|pair| {
+                let (x0, y0) = pair[0];
+                let (x1, y1) = pair[1];
+                let dx = (x1 - x0) as f64;
+                if dx == 0.0 {
+                    0.0
+                } else {
+                    (y1 - y0) as f64 / dx
+                }
+            })
+            .collect();
+
+        let mut tangents = vec![0.0; n];
+        tangents[0] = secants[0];
+        tangents[n - 1] = secants[n - 2];
+
+        for i in 1..n - 1 {
+            let d_prev = secants[i - 1];
+            let d_next = secants[i];
+            if d_prev == 0.0 || d_next == 0.0 || d_prev.signum() != d_next.signum() {
+                tangents[i] = 0.0;
+                continue;
+            }
+
+            let h_prev = (self.curve[i].0 - self.curve[i - 1].0) as f64;
+            let h_next = (self.curve[i + 1].0 - self.curve[i].0) as f64;
+            if h_prev == 0.0 || h_next == 0.0 {
+                tangents[i] = 0.0;
+                continue;
+            }
+
+            let w1 = 2.0 * h_next + h_prev;
+            let w2 = h_next + 2.0 * h_prev;
+            tangents[i] = (w1 + w2) / (w1 / d_prev + w2 / d_next);
+        }
+
+        tangents
+    }
+}
+
+impl AdaptationStrategy for CustomStrategy {
+    fn calculate_target(&self, load: f32, bounds: &CoreBounds) -> i32 {
+        let target = self.interpolate(load);
+        clamp_to_bounds(target, bounds)
+    }
+
+    fn ramp_time_ms(&self) -> u64 {
+        // Custom strategy uses balanced ramp time by default
+        2000
+    }
+
+    fn name(&self) -> &'static str {
+        "custom"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_name() {
+        let strategy = CustomStrategy::new(vec![(0.0, -30), (100.0, -10)]);
+        assert_eq!(strategy.name(), "custom");
+    }
+
+    #[test]
+    fn test_custom_ramp_time() {
+        let strategy = CustomStrategy::new(vec![(0.0, -30), (100.0, -10)]);
+        assert_eq!(strategy.ramp_time_ms(), 2000);
+    }
+
+    #[test]
+    fn test_custom_single_point() {
+        let strategy = CustomStrategy::new(vec![(50.0, -25)]);
+        let bounds = CoreBounds {
+            min_mv: -20,
+            max_mv: -35,
+            threshold: 50.0,
+        };
+
+        // Single point should return that value for all loads
+        assert_eq!(strategy.calculate_target(0.0, &bounds), -25);
+        assert_eq!(strategy.calculate_target(50.0, &bounds), -25);
+        assert_eq!(strategy.calculate_target(100.0, &bounds), -25);
+    }
+
+    #[test]
+    fn test_custom_two_points_linear() {
+        let strategy = CustomStrategy::new(vec![(0.0, -30), (100.0, -10)]);
+        let bounds = CoreBounds {
+            min_mv: 0,
+            max_mv: -100,
+            threshold: 50.0,
+        };
+
+        // Endpoints
+        assert_eq!(strategy.calculate_target(0.0, &bounds), -30);
+        assert_eq!(strategy.calculate_target(100.0, &bounds), -10);
+
+        // Midpoint should be -20
+        assert_eq!(strategy.calculate_target(50.0, &bounds), -20);
+
+        // 25% should be -25
+        assert_eq!(strategy.calculate_target(25.0, &bounds), -25);
+    }
+
+    #[test]
+    fn test_custom_multiple_points() {
+        let strategy = CustomStrategy::new(vec![
+            (0.0, -35),
+            (30.0, -30),
+            (70.0, -25),
+            (100.0, -20),
+        ]);
+        let bounds = CoreBounds {
+            min_mv: 0,
+            max_mv: -100,
+            threshold: 50.0,
+        };
+
+        // Exact points
+        assert_eq!(strategy.calculate_target(0.0, &bounds), -35);
+        assert_eq!(strategy.calculate_target(30.0, &bounds), -30);
+        assert_eq!(strategy.calculate_target(70.0, &bounds), -25);
+        assert_eq!(strategy.calculate_target(100.0, &bounds), -20);
+
+        // Interpolated: 50% is between 30% and 70%
+        // t = (50-30)/(70-30) = 0.5
+        // value = -30 + (-25 - -30) * 0.5 = -30 + 2.5 = -27.5 â‰ˆ -28
+        let mid = strategy.calculate_target(50.0, &bounds);
+        assert!(mid >= -28 && mid <= -27);
+    }
+
+    #[test]
+    fn test_custom_respects_bounds() {
+        // Curve that goes outside typical bounds
+        let strategy = CustomStrategy::new(vec![(0.0, -50), (100.0, -5)]);
+        let bounds = CoreBounds {
+            min_mv: -20,
+            max_mv: -35,
+            threshold: 50.0,
+        };
+
+        // At 0% load, curve says -50 but should be clamped to -35
+        assert_eq!(strategy.calculate_target(0.0, &bounds), -35);
+
+        // At 100% load, curve says -5 but should be clamped to -20
+        assert_eq!(strategy.calculate_target(100.0, &bounds), -20);
+    }
+
+    #[test]
+    fn test_custom_unsorted_input() {
+        // Points provided out of order should still work
+        let strategy = CustomStrategy::new(vec![
+            (100.0, -10),
+            (0.0, -30),
+            (50.0, -20),
+        ]);
+        let bounds = CoreBounds {
+            min_mv: 0,
+            max_mv: -100,
+            threshold: 50.0,
+        };
+
+        assert_eq!(strategy.calculate_target(0.0, &bounds), -30);
+        assert_eq!(strategy.calculate_target(50.0, &bounds), -20);
+        assert_eq!(strategy.calculate_target(100.0, &bounds), -10);
+    }
+
+    #[test]
+    fn test_custom_empty_curve() {
+        // Empty curve should default to no undervolt
+        let strategy = CustomStrategy::new(vec![]);
+        let bounds = CoreBounds {
+            min_mv: 0,
+            max_mv: -100,
+            threshold: 50.0,
+        };
+
+        assert_eq!(strategy.calculate_target(50.0, &bounds), 0);
+    }
+
+    #[test]
+    fn test_custom_extrapolation_clamped() {
+        let strategy = CustomStrategy::new(vec![(20.0, -30), (80.0, -20)]);
+        let bounds = CoreBounds {
+            min_mv: 0,
+            max_mv: -100,
+            threshold: 50.0,
+        };
+
+        // Below first point: use first point value
+        assert_eq!(strategy.calculate_target(0.0, &bounds), -30);
+        assert_eq!(strategy.calculate_target(10.0, &bounds), -30);
+
+        // Above last point: use last point value
+        assert_eq!(strategy.calculate_target(90.0, &bounds), -20);
+        assert_eq!(strategy.calculate_target(100.0, &bounds), -20);
+    }
+
+    #[test]
+    fn test_custom_extreme_curve_values_do_not_overflow() {
+        // val2 - val1 would overflow i32 if computed directly
+        let strategy = CustomStrategy::new(vec![(0.0, i32::MIN), (100.0, i32::MAX)]);
+        let bounds = CoreBounds {
+            min_mv: i32::MAX,
+            max_mv: i32::MIN,
+            threshold: 50.0,
+        };
+
+        assert_eq!(strategy.calculate_target(0.0, &bounds), i32::MIN);
+        assert_eq!(strategy.calculate_target(100.0, &bounds), i32::MAX);
+
+        let mid = strategy.calculate_target(50.0, &bounds);
+        assert!(mid > i32::MIN / 2 && mid < i32::MAX / 2 + 1);
+    }
+
+    #[test]
+    fn test_custom_non_monotone_curve_is_repaired() {
+        // 0% load is more aggressive than 100% load, which violates
+        // "higher load -> safer value"; the later point should be lifted
+        // up to match instead of being honored as-is.
+        let strategy = CustomStrategy::new(vec![(0.0, -10), (100.0, -35)]);
+        assert_eq!(strategy.curve(), &[(0.0, -10), (100.0, -10)]);
+    }
+
+    #[test]
+    fn test_custom_repair_applies_after_sorting_unordered_input() {
+        let strategy = CustomStrategy::new(vec![
+            (100.0, -40),
+            (0.0, -10),
+            (50.0, -30),
+        ]);
+        // Sorted: (0, -10), (50, -30), (100, -40) - both later points dip
+        // below the running floor of -10 and should be lifted to -10.
+        assert_eq!(strategy.curve(), &[(0.0, -10), (50.0, -10), (100.0, -10)]);
+    }
+
+    #[test]
+    fn test_custom_calculate_target_is_monotone_for_any_curve() {
+        let strategy = CustomStrategy::new(vec![
+            (0.0, -5),
+            (20.0, -40),
+            (60.0, -15),
+            (100.0, -25),
+        ]);
+        let bounds = CoreBounds {
+            min_mv: 0,
+            max_mv: -100,
+            threshold: 50.0,
+        };
+
+        let mut last = strategy.calculate_target(0.0, &bounds);
+        let mut load = 1.0;
+        while load <= 100.0 {
+            let current = strategy.calculate_target(load, &bounds);
+            assert!(current >= last, "target at {load}% ({current}) must be >= target at previous load ({last})");
+            last = current;
+            load += 1.0;
+        }
+    }
+
+    #[test]
+    fn test_custom_nan_load_behaves_like_zero() {
+        let strategy = CustomStrategy::new(vec![(0.0, -35), (100.0, -20)]);
+        let bounds = CoreBounds {
+            min_mv: -20,
+            max_mv: -35,
+            threshold: 50.0,
+        };
+
+        assert_eq!(
+            strategy.calculate_target(f32::NAN, &bounds),
+            strategy.calculate_target(0.0, &bounds)
+        );
+    }
+
+    #[test]
+    fn test_monotone_cubic_hits_knots_exactly() {
+        let strategy = CustomStrategy::with_interpolation(
+            vec![(0.0, -35), (30.0, -30), (70.0, -25), (100.0, -20)],
+            Interp::MonotoneCubic,
+        );
+        let bounds = CoreBounds {
+            min_mv: 0,
+            max_mv: -100,
+            threshold: 50.0,
+        };
+
+        assert_eq!(strategy.calculate_target(0.0, &bounds), -35);
+        assert_eq!(strategy.calculate_target(30.0, &bounds), -30);
+        assert_eq!(strategy.calculate_target(70.0, &bounds), -25);
+        assert_eq!(strategy.calculate_target(100.0, &bounds), -20);
+    }
+
+    #[test]
+    fn test_monotone_cubic_two_points_matches_linear() {
+        // With only two points there's a single tangent shared by both
+        // ends, which makes the Hermite spline degenerate to the chord -
+        // i.e. it should agree with `Linear` exactly.
+        let linear = CustomStrategy::new(vec![(0.0, -30), (100.0, -10)]);
+        let cubic = CustomStrategy::with_interpolation(
+            vec![(0.0, -30), (100.0, -10)],
+            Interp::MonotoneCubic,
+        );
+        let bounds = CoreBounds {
+            min_mv: 0,
+            max_mv: -100,
+            threshold: 50.0,
+        };
+
+        let mut load = 0.0;
+        while load <= 100.0 {
+            assert_eq!(
+                cubic.calculate_target(load, &bounds),
+                linear.calculate_target(load, &bounds),
+                "mismatch at load {load}"
+            );
+            load += 10.0;
+        }
+    }
+
+    #[test]
+    fn test_monotone_cubic_is_monotone_for_any_curve() {
+        let strategy = CustomStrategy::with_interpolation(
+            vec![(0.0, -5), (20.0, -40), (60.0, -15), (100.0, -25)],
+            Interp::MonotoneCubic,
+        );
+        let bounds = CoreBounds {
+            min_mv: 0,
+            max_mv: -100,
+            threshold: 50.0,
+        };
+
+        let mut last = strategy.calculate_target(0.0, &bounds);
+        let mut load = 1.0;
+        while load <= 100.0 {
+            let current = strategy.calculate_target(load, &bounds);
+            assert!(current >= last, "target at {load}% ({current}) must be >= target at previous load ({last})");
+            last = current;
+            load += 1.0;
+        }
+    }
+
+    #[test]
+    fn test_monotone_cubic_respects_bounds() {
+        let strategy = CustomStrategy::with_interpolation(
+            vec![(0.0, -50), (100.0, -5)],
+            Interp::MonotoneCubic,
+        );
+        let bounds = CoreBounds {
+            min_mv: -20,
+            max_mv: -35,
+            threshold: 50.0,
+        };
+
+        assert_eq!(strategy.calculate_target(0.0, &bounds), -35);
+        assert_eq!(strategy.calculate_target(100.0, &bounds), -20);
+    }
+
+    #[test]
+    fn test_monotone_cubic_extreme_curve_values_do_not_overflow() {
+        let strategy = CustomStrategy::with_interpolation(
+            vec![(0.0, i32::MIN), (100.0, i32::MAX)],
+            Interp::MonotoneCubic,
+        );
+        let bounds = CoreBounds {
+            min_mv: i32::MAX,
+            max_mv: i32::MIN,
+            threshold: 50.0,
+        };
+
+        assert_eq!(strategy.calculate_target(0.0, &bounds), i32::MIN);
+        assert_eq!(strategy.calculate_target(100.0, &bounds), i32::MAX);
+
+        let mid = strategy.calculate_target(50.0, &bounds);
+        assert!(mid > i32::MIN / 2 && mid < i32::MAX / 2 + 1);
+    }
+}
@@ -0,0 +1,311 @@
+//! Online-learning strategy that penalizes load regions after instability
+//!
+//! `LearningStrategy` follows the same straight-line load→undervolt curve as
+//! `BalancedStrategy` (0% load → `max_mv`, 100% load → `min_mv`), but on top
+//! of that base curve it keeps a per-load-bin penalty that gets nudged
+//! toward `min_mv` (safer) whenever `record_instability` reports a crash or
+//! hang observed at that load. Penalties decay exponentially back toward
+//! zero with a configurable half-life, so a load region that's been stable
+//! for a while gradually earns back its full aggressiveness. This is the
+//! "online learning" counterpart to the other, stateless curve strategies -
+//! see `StabilityFeedback` for the write side of the split.
+
+use std::sync::Mutex;
+use std::time::Instant;
+
+use super::{AdaptationStrategy, CoreBounds, StabilityFeedback, clamp_to_bounds, lerp};
+
+/// Number of load bins the [0, 100] load range is divided into
+pub const LEARNING_BIN_COUNT: usize = 10;
+
+/// Width in load percentage of each bin (100 / LEARNING_BIN_COUNT)
+pub const LEARNING_BIN_WIDTH: f32 = 100.0 / LEARNING_BIN_COUNT as f32;
+
+/// Default penalty half-life: how long, in seconds, it takes an
+/// instability penalty to decay to half its value
+pub const DEFAULT_PENALTY_HALF_LIFE_SECS: f32 = 300.0;
+
+/// Penalty added to a bin, in mV toward `min_mv`, per reported instability
+pub const INSTABILITY_PENALTY_STEP_MV: f32 = 5.0;
+
+/// Decaying penalty state for a single load bin
+#[derive(Debug, Clone, Copy)]
+struct BinPenalty {
+    /// Current penalty in mV toward `min_mv`, before decay is applied
+    penalty: f32,
+    /// Last time this bin's penalty was touched (decayed or incremented)
+    last_update: Option<Instant>,
+}
+
+impl Default for BinPenalty {
+    fn default() -> Self {
+        BinPenalty {
+            penalty: 0.0,
+            last_update: None,
+        }
+    }
+}
+
+impl BinPenalty {
+    /// Decay the penalty to `now` and return the decayed value, updating
+    /// `last_update` so the next call only decays the elapsed delta
+    fn decay_to(&mut self, now: Instant, half_life_secs: f32) -> f32 {
+        if let Some(prev) = self.last_update {
+            let elapsed_secs = now.duration_since(prev).as_secs_f32();
+            if half_life_secs > 0.0 {
+                self.penalty *= 0.5_f32.powf(elapsed_secs / half_life_secs);
+            }
+        }
+        self.last_update = Some(now);
+        self.penalty
+    }
+}
+
+/// Mutable learning state carried between samples
+struct LearningState {
+    bins: [BinPenalty; LEARNING_BIN_COUNT],
+}
+
+/// Map a load percentage to its bin index, clamped to the valid range
+fn bin_index(load: f32) -> usize {
+    let load = load.clamp(0.0, 100.0);
+    ((load / LEARNING_BIN_WIDTH) as usize).min(LEARNING_BIN_COUNT - 1)
+}
+
+/// Learning strategy that penalizes unstable load regions over time
+///
+/// Splits read and write: `calculate_target` (via `AdaptationStrategy`) is
+/// the pure lookup, while `record_instability`/`record_stable` (via
+/// `StabilityFeedback`) are how a caller reports observed crashes/hangs so
+/// the strategy can adjust future targets.
+pub struct LearningStrategy {
+    half_life_secs: f32,
+    state: Mutex<LearningState>,
+}
+
+impl LearningStrategy {
+    /// Create a new LearningStrategy with the given penalty half-life
+    /// (in seconds)
+    pub fn new(half_life_secs: f32) -> Self {
+        LearningStrategy {
+            half_life_secs,
+            state: Mutex::new(LearningState {
+                bins: [BinPenalty::default(); LEARNING_BIN_COUNT],
+            }),
+        }
+    }
+
+    /// Configured penalty half-life, in seconds
+    pub fn half_life_secs(&self) -> f32 {
+        self.half_life_secs
+    }
+
+    /// Current decayed penalty for the bin containing `load`, in mV
+    /// toward `min_mv`
+    pub fn penalty_for(&self, load: f32) -> f32 {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let idx = bin_index(load);
+        state.bins[idx].decay_to(Instant::now(), self.half_life_secs)
+    }
+
+    /// Snapshot of every bin's current decayed penalty, in bin order
+    ///
+    /// Used to persist the learned penalty map across restarts. There's no
+    /// existing convention in this codebase for serializing `Instant`
+    /// timestamps, so only the decayed magnitudes are saved; restoring a
+    /// snapshot re-anchors every bin's clock to the moment it's loaded.
+    pub fn penalty_snapshot(&self) -> [f32; LEARNING_BIN_COUNT] {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let mut snapshot = [0.0; LEARNING_BIN_COUNT];
+        for (i, bin) in state.bins.iter_mut().enumerate() {
+            snapshot[i] = bin.decay_to(now, self.half_life_secs);
+        }
+        snapshot
+    }
+
+    /// Restore a previously captured penalty snapshot, anchoring every
+    /// bin's decay clock to now
+    pub fn load_penalty_snapshot(&self, snapshot: &[f32; LEARNING_BIN_COUNT]) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        for (bin, &penalty) in state.bins.iter_mut().zip(snapshot.iter()) {
+            bin.penalty = penalty.max(0.0);
+            bin.last_update = Some(now);
+        }
+    }
+}
+
+impl Default for LearningStrategy {
+    fn default() -> Self {
+        LearningStrategy::new(DEFAULT_PENALTY_HALF_LIFE_SECS)
+    }
+}
+
+impl AdaptationStrategy for LearningStrategy {
+    fn calculate_target(&self, load: f32, bounds: &CoreBounds) -> i32 {
+        let load = if load.is_nan() { 0.0 } else { load.clamp(0.0, 100.0) };
+        let base = lerp(bounds.max_mv, bounds.min_mv, load / 100.0);
+
+        let penalty = self.penalty_for(load);
+
+        // Penalty pushes toward min_mv (safer), which is the end of the
+        // range with the smaller magnitude, so it's added as a positive
+        // offset regardless of which bound is numerically larger.
+        let target = if bounds.min_mv >= bounds.max_mv {
+            base + penalty.round() as i32
+        } else {
+            base - penalty.round() as i32
+        };
+
+        clamp_to_bounds(target, bounds)
+    }
+
+    fn ramp_time_ms(&self) -> u64 {
+        // Penalty decay is already slow (minutes-scale half-life), so the
+        // downstream ramp only needs to smooth sampling noise, same as
+        // balanced/custom/pid.
+        2000
+    }
+
+    fn name(&self) -> &'static str {
+        "learning"
+    }
+}
+
+impl StabilityFeedback for LearningStrategy {
+    fn record_instability(&mut self, load: f32, at: Instant) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let idx = bin_index(load);
+        let decayed = state.bins[idx].decay_to(at, self.half_life_secs);
+        state.bins[idx].penalty = decayed + INSTABILITY_PENALTY_STEP_MV;
+        state.bins[idx].last_update = Some(at);
+    }
+
+    fn record_stable(&mut self, load: f32, at: Instant) {
+        // Stability doesn't need to do anything beyond what decay already
+        // does passively; touching the bin just re-anchors its clock so a
+        // long run of stable samples doesn't leave a stale `last_update`
+        // accumulating an enormous elapsed delta on the next event.
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let idx = bin_index(load);
+        state.bins[idx].decay_to(at, self.half_life_secs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn bounds() -> CoreBounds {
+        CoreBounds {
+            min_mv: -20,
+            max_mv: -35,
+            threshold: 50.0,
+        }
+    }
+
+    #[test]
+    fn test_learning_name_and_half_life() {
+        let strategy = LearningStrategy::new(60.0);
+        assert_eq!(strategy.name(), "learning");
+        assert_eq!(strategy.half_life_secs(), 60.0);
+    }
+
+    #[test]
+    fn test_learning_matches_balanced_curve_with_no_penalties() {
+        let strategy = LearningStrategy::default();
+        assert_eq!(strategy.calculate_target(0.0, &bounds()), -35);
+        assert_eq!(strategy.calculate_target(100.0, &bounds()), -20);
+    }
+
+    #[test]
+    fn test_instability_pushes_target_toward_min_mv() {
+        let mut strategy = LearningStrategy::new(300.0);
+        let before = strategy.calculate_target(75.0, &bounds());
+
+        strategy.record_instability(75.0, Instant::now());
+        let after = strategy.calculate_target(75.0, &bounds());
+
+        assert!(after > before, "penalized target {after} should be safer (closer to min_mv) than {before}");
+        assert!(after <= bounds().min_mv);
+    }
+
+    #[test]
+    fn test_instability_only_affects_its_own_bin() {
+        let mut strategy = LearningStrategy::new(300.0);
+        let unaffected_before = strategy.calculate_target(5.0, &bounds());
+
+        strategy.record_instability(75.0, Instant::now());
+        let unaffected_after = strategy.calculate_target(5.0, &bounds());
+
+        assert_eq!(unaffected_before, unaffected_after);
+    }
+
+    #[test]
+    fn test_penalty_decays_toward_zero_over_time() {
+        let mut strategy = LearningStrategy::new(10.0); // short half-life for the test
+        let t0 = Instant::now();
+        strategy.record_instability(75.0, t0);
+
+        let fresh = strategy.penalty_for(75.0);
+        assert!(fresh > 0.0);
+
+        // Simulate elapsed time by decaying against a synthetic later
+        // instant several half-lives out.
+        let later = t0 + Duration::from_secs(100);
+        let decayed = {
+            let mut state = strategy.state.lock().unwrap();
+            let idx = bin_index(75.0);
+            state.bins[idx].decay_to(later, strategy.half_life_secs)
+        };
+        assert!(decayed < fresh * 0.1, "penalty should have decayed by ~10 half-lives, got {decayed} from {fresh}");
+    }
+
+    #[test]
+    fn test_repeated_crashes_monotonically_increase_penalty_then_clamp() {
+        let mut strategy = LearningStrategy::new(300.0);
+        let t0 = Instant::now();
+
+        let mut last = 0.0;
+        for _ in 0..20 {
+            strategy.record_instability(75.0, t0);
+            let current = strategy.penalty_for(75.0);
+            assert!(current >= last, "penalty should never decrease from repeated crashes at the same instant");
+            last = current;
+        }
+
+        // Even with a huge accumulated penalty, the target must stay
+        // within bounds.
+        let target = strategy.calculate_target(75.0, &bounds());
+        assert!(target >= bounds().max_mv && target <= bounds().min_mv);
+    }
+
+    #[test]
+    fn test_target_always_within_bounds() {
+        let mut strategy = LearningStrategy::new(300.0);
+        for load in [0.0, 10.0, 50.0, 90.0, 100.0] {
+            strategy.record_instability(load, Instant::now());
+        }
+        for load in [0.0, 25.0, 50.0, 75.0, 100.0, f32::NAN] {
+            let target = strategy.calculate_target(load, &bounds());
+            assert!(target >= bounds().max_mv && target <= bounds().min_mv, "target {target} out of bounds");
+        }
+    }
+
+    #[test]
+    fn test_penalty_snapshot_round_trip() {
+        let mut strategy = LearningStrategy::new(300.0);
+        strategy.record_instability(25.0, Instant::now());
+        strategy.record_instability(85.0, Instant::now());
+
+        let snapshot = strategy.penalty_snapshot();
+        assert!(snapshot[bin_index(25.0)] > 0.0);
+        assert!(snapshot[bin_index(85.0)] > 0.0);
+
+        let restored = LearningStrategy::new(300.0);
+        restored.load_penalty_snapshot(&snapshot);
+        assert_eq!(restored.penalty_snapshot(), snapshot);
+    }
+}
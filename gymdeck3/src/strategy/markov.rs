@@ -0,0 +1,290 @@
+//! Markov-predictive strategy that anticipates load before it changes
+//!
+//! `MarkovStrategy` discretizes load into buckets and keeps a first-order
+//! transition model between them: a `BUCKET_COUNT x BUCKET_COUNT` count
+//! matrix `C[i][j]` tallying how often a tick in bucket `i` was followed by
+//! a tick in bucket `j`. Instead of feeding the *current* load into the
+//! same linear interpolation `ConservativeStrategy::calculate_target` uses,
+//! it feeds the *predicted next* load - the expected bucket midpoint under
+//! the Laplace-smoothed row distribution for the current bucket - so the
+//! undervolt target starts moving toward where load is headed rather than
+//! reacting only once it arrives. `ramp_time_ms` is correspondingly short,
+//! since the prediction is already doing the anticipating.
+
+use std::sync::Mutex;
+
+use super::{clamp_to_bounds, lerp, AdaptationStrategy, CoreBounds};
+
+/// Number of load buckets the `[0, 100]` range is divided into
+pub const BUCKET_COUNT: usize = 10;
+
+/// Width in load percentage of each bucket (100 / BUCKET_COUNT)
+pub const BUCKET_WIDTH: f32 = 100.0 / BUCKET_COUNT as f32;
+
+/// Once any row's transition count sum exceeds this, every count in the
+/// matrix is halved. Bounds memory growth and lets the model track regime
+/// changes instead of letting old history dominate forever.
+pub const DECAY_CAP: u32 = 10_000;
+
+/// Map a load percentage to its bucket index, clamping to the valid range
+/// first so an out-of-range load never corrupts the matrix.
+fn bucket_index(load: f32) -> usize {
+    let load = load.clamp(0.0, 100.0);
+    ((load / BUCKET_WIDTH) as usize).min(BUCKET_COUNT - 1)
+}
+
+/// Representative load percentage for a bucket: its midpoint
+fn bucket_midpoint(index: usize) -> f32 {
+    (index as f32 + 0.5) * BUCKET_WIDTH
+}
+
+/// Mutable transition-model state carried between samples
+struct MarkovState {
+    /// `counts[i][j]`: number of observed `i -> j` transitions
+    counts: [[u32; BUCKET_COUNT]; BUCKET_COUNT],
+    /// Bucket of the most recently observed load, if any
+    last_bucket: Option<usize>,
+}
+
+impl MarkovState {
+    /// Uniform prior: every count starts at zero, so Laplace smoothing's
+    /// `+1` alone makes every row behave like a uniform distribution on
+    /// cold start - the same expected-value midpoint a balanced strategy
+    /// would settle on before any history has accumulated.
+    fn new() -> Self {
+        MarkovState {
+            counts: [[0; BUCKET_COUNT]; BUCKET_COUNT],
+            last_bucket: None,
+        }
+    }
+
+    /// Record an observed transition into `bucket`, decaying first if any
+    /// row has grown past `DECAY_CAP`.
+    fn observe(&mut self, bucket: usize) {
+        if let Some(prev) = self.last_bucket {
+            self.counts[prev][bucket] = self.counts[prev][bucket].saturating_add(1);
+
+            let row_sum: u32 = self.counts[prev].iter().sum();
+            if row_sum > DECAY_CAP {
+                for row in self.counts.iter_mut() {
+                    for count in row.iter_mut() {
+                        *count /= 2;
+                    }
+                }
+            }
+        }
+        self.last_bucket = Some(bucket);
+    }
+
+    /// Expected next load under the Laplace-smoothed row distribution for
+    /// `bucket`: `sum_j p[j] * midpoint(j)` where
+    /// `p[j] = (counts[bucket][j] + 1) / (row_sum + BUCKET_COUNT)`.
+    fn predict(&self, bucket: usize) -> f32 {
+        let row = &self.counts[bucket];
+        let row_sum: u32 = row.iter().sum();
+        let denom = row_sum as f32 + BUCKET_COUNT as f32;
+
+        row.iter()
+            .enumerate()
+            .map(|(j, &count)| {
+                let p = (count as f32 + 1.0) / denom;
+                p * bucket_midpoint(j)
+            })
+            .sum()
+    }
+
+    /// Flatten the count matrix row-major into a persistable snapshot
+    fn snapshot(&self) -> Vec<u32> {
+        self.counts.iter().flatten().copied().collect()
+    }
+
+    /// Restore a previously captured snapshot; a size mismatch leaves the
+    /// matrix untouched rather than panicking on a stale/foreign file.
+    fn load_snapshot(&mut self, flat: &[u32]) -> Result<(), String> {
+        if flat.len() != BUCKET_COUNT * BUCKET_COUNT {
+            return Err(format!(
+                "expected {} counts, got {}",
+                BUCKET_COUNT * BUCKET_COUNT,
+                flat.len()
+            ));
+        }
+        for (row, chunk) in self.counts.iter_mut().zip(flat.chunks(BUCKET_COUNT)) {
+            row.copy_from_slice(chunk);
+        }
+        // A restored matrix has no notion of "the most recent tick" - the
+        // next observed load starts a fresh transition rather than being
+        // falsely chained to whatever was last seen before the restart.
+        self.last_bucket = None;
+        Ok(())
+    }
+}
+
+/// First-order Markov strategy that predicts the next load bucket and
+/// ramps toward it ahead of time
+///
+/// Behaves like `BalancedStrategy` until enough transitions have been
+/// observed to skew a row's distribution away from uniform.
+pub struct MarkovStrategy {
+    state: Mutex<MarkovState>,
+}
+
+impl MarkovStrategy {
+    /// Create a new Markov strategy with an empty (uniform-prior) matrix
+    pub fn new() -> Self {
+        MarkovStrategy {
+            state: Mutex::new(MarkovState::new()),
+        }
+    }
+
+    /// Flatten the transition-count matrix row-major for persistence
+    pub fn matrix_snapshot(&self) -> Vec<u32> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.snapshot()
+    }
+
+    /// Restore a previously captured matrix snapshot
+    pub fn load_matrix_snapshot(&self, flat: &[u32]) -> Result<(), String> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.load_snapshot(flat)
+    }
+}
+
+impl Default for MarkovStrategy {
+    fn default() -> Self {
+        MarkovStrategy::new()
+    }
+}
+
+impl AdaptationStrategy for MarkovStrategy {
+    fn calculate_target(&self, load: f32, bounds: &CoreBounds) -> i32 {
+        let load = if load.is_nan() { 0.0 } else { load.clamp(0.0, 100.0) };
+        let bucket = bucket_index(load);
+
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.observe(bucket);
+        let predicted_load = state.predict(bucket);
+        drop(state);
+
+        let t = predicted_load.clamp(0.0, 100.0) / 100.0;
+        let target = lerp(bounds.max_mv, bounds.min_mv, t);
+        clamp_to_bounds(target, bounds)
+    }
+
+    fn ramp_time_ms(&self) -> u64 {
+        // The prediction already anticipates the change, so the ramp only
+        // needs to smooth sampling noise - shorter than balanced's 2000ms.
+        1500
+    }
+
+    fn name(&self) -> &'static str {
+        "markov"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds() -> CoreBounds {
+        CoreBounds {
+            min_mv: -20,
+            max_mv: -35,
+            threshold: 50.0,
+        }
+    }
+
+    #[test]
+    fn test_markov_ramp_time_and_name() {
+        let strategy = MarkovStrategy::new();
+        assert_eq!(strategy.ramp_time_ms(), 1500);
+        assert_eq!(strategy.name(), "markov");
+    }
+
+    #[test]
+    fn test_cold_start_behaves_like_balanced_midpoint() {
+        // With no history, every row is uniform, so the predicted load for
+        // a first tick at 50% should land close to 50% too.
+        let strategy = MarkovStrategy::new();
+        let target = strategy.calculate_target(50.0, &bounds());
+        let balanced_mid = lerp(bounds().max_mv, bounds().min_mv, 0.5);
+        assert!((target - balanced_mid).abs() <= 1, "cold-start target {target} should be near balanced midpoint {balanced_mid}");
+    }
+
+    #[test]
+    fn test_out_of_range_load_is_clamped_before_bucketing() {
+        let strategy = MarkovStrategy::new();
+
+        // Should not panic, and should behave as if clamped to the edges.
+        let low = strategy.calculate_target(-50.0, &bounds());
+        let high = strategy.calculate_target(150.0, &bounds());
+        assert!(low >= bounds().max_mv && low <= bounds().min_mv);
+        assert!(high >= bounds().max_mv && high <= bounds().min_mv);
+    }
+
+    #[test]
+    fn test_target_always_within_bounds() {
+        let strategy = MarkovStrategy::new();
+        for load in [0.0, 10.0, 33.0, 50.0, 75.0, 99.0, 100.0, f32::NAN] {
+            let target = strategy.calculate_target(load, &bounds());
+            assert!(target >= bounds().max_mv && target <= bounds().min_mv, "target {target} out of bounds");
+        }
+    }
+
+    #[test]
+    fn test_skewed_history_biases_prediction_toward_observed_followup() {
+        let strategy = MarkovStrategy::new();
+
+        // Repeatedly teach the model that low load (bucket near 5%) is
+        // always followed by high load (bucket near 95%).
+        for _ in 0..200 {
+            strategy.calculate_target(5.0, &bounds());
+            strategy.calculate_target(95.0, &bounds());
+        }
+
+        // Next tick at 5% load should now predict an imminent jump to high
+        // load, producing a safer (less aggressive) target than a fresh
+        // conservative/balanced read of 5% load would.
+        let target = strategy.calculate_target(5.0, &bounds());
+        let naive_low_load_target = lerp(bounds().max_mv, bounds().min_mv, 0.05);
+        assert!(target > naive_low_load_target, "predictive target {target} should be safer than naive low-load target {naive_low_load_target}");
+    }
+
+    #[test]
+    fn test_matrix_snapshot_round_trip() {
+        let strategy = MarkovStrategy::new();
+        for _ in 0..5 {
+            strategy.calculate_target(20.0, &bounds());
+            strategy.calculate_target(80.0, &bounds());
+        }
+
+        let snapshot = strategy.matrix_snapshot();
+        assert_eq!(snapshot.len(), BUCKET_COUNT * BUCKET_COUNT);
+        assert!(snapshot.iter().any(|&c| c > 0));
+
+        let restored = MarkovStrategy::new();
+        restored.load_matrix_snapshot(&snapshot).unwrap();
+        assert_eq!(restored.matrix_snapshot(), snapshot);
+    }
+
+    #[test]
+    fn test_load_snapshot_rejects_wrong_size() {
+        let strategy = MarkovStrategy::new();
+        let result = strategy.load_matrix_snapshot(&[1, 2, 3]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_decay_caps_unbounded_growth() {
+        let strategy = MarkovStrategy::new();
+        // Drive one row's count well past DECAY_CAP; the matrix must not
+        // panic or grow without bound, and targets must stay in range.
+        for _ in 0..(DECAY_CAP as usize + 50) {
+            strategy.calculate_target(10.0, &bounds());
+        }
+        let snapshot = strategy.matrix_snapshot();
+        assert!(snapshot.iter().all(|&c| c <= DECAY_CAP));
+
+        let target = strategy.calculate_target(10.0, &bounds());
+        assert!(target >= bounds().max_mv && target <= bounds().min_mv);
+    }
+}
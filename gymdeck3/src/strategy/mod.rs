@@ -30,7 +30,7 @@
 //! use gymdeck3::Strategy;
 //!
 //! // Create a balanced strategy
-//! let strategy = create_strategy(Strategy::Balanced, None);
+//! let strategy = create_strategy(Strategy::Balanced, None, None);
 //!
 //! // Define bounds for a core
 //! let bounds = CoreBounds {
@@ -65,7 +65,7 @@
 //!     (100.0, -20),  // 100% load → -20mV
 //! ];
 //!
-//! let strategy = create_strategy(Strategy::Custom, Some(curve));
+//! let strategy = create_strategy(Strategy::Custom, Some(curve), None);
 //! let bounds = CoreBounds { min_mv: 0, max_mv: -100, threshold: 50.0 };
 //!
 //! // Values between points are linearly interpolated
@@ -73,18 +73,70 @@
 //! // 50% is between 30% and 70%, so target is between -30 and -25
 //! assert!(target >= -30 && target <= -25);
 //! ```
+//!
+//! `CustomStrategy::with_interpolation` offers a shape-preserving
+//! `Interp::MonotoneCubic` mode instead of the default `Interp::Linear`,
+//! trading the linear mode's slope discontinuities at each knot for a
+//! smooth derivative (see `custom::Interp`).
+//!
+//! # Adaptive Strategies
+//!
+//! `AdaptiveStrategy` and `AdaptiveCurveStrategy` both carry state across
+//! samples instead of following a fixed curve, drifting their target
+//! toward equilibrium based on sustained load error. `AdaptiveCurveStrategy`
+//! exposes its drift rate and error scale as constructor parameters and
+//! its per-tick update as a `dt_ms`-driven method, so a caller like
+//! `VoltageController` can advance it on a fixed sample schedule instead of
+//! wall-clock sampling (see `adaptive_curve::AdaptiveCurveStrategy::update`).
+//!
+//! # Auto-Calibration
+//!
+//! `CurveOptimizer` tunes a curve against an objective function the caller
+//! provides; `Calibrator` instead fits one to `(load, applied_mv, stable?)`
+//! observations recorded live during a session, via Levenberg-Marquardt,
+//! and hands back a ready-to-use `CustomStrategy` (see
+//! `calibration::Calibrator`).
 
 mod conservative;
 mod balanced;
 mod aggressive;
 mod custom;
+mod pid;
+mod adaptive;
+mod adaptive_curve;
+mod learning;
+mod markov;
+mod optimizer;
+mod calibration;
 
 pub use conservative::ConservativeStrategy;
 pub use balanced::BalancedStrategy;
 pub use aggressive::AggressiveStrategy;
-pub use custom::CustomStrategy;
+pub use custom::{CustomStrategy, Interp};
+pub use pid::PidStrategy;
+pub use adaptive::{AdaptiveStrategy, DEFAULT_ADAPTATION_SPEED};
+pub use adaptive_curve::{AdaptiveCurveStrategy, DEFAULT_K, DEFAULT_SCALE};
+pub use learning::{LearningStrategy, DEFAULT_PENALTY_HALF_LIFE_SECS};
+pub use markov::{MarkovStrategy, BUCKET_COUNT, DECAY_CAP};
+pub use optimizer::{
+    CurveOptimizer,
+    DEFAULT_ANCHOR_LOADS,
+    DEFAULT_ITERATIONS,
+    DEFAULT_INITIAL_TEMPERATURE,
+    DEFAULT_COOLING_RATE,
+};
+pub use calibration::{
+    Calibrator,
+    StabilityObservation,
+    DEFAULT_INITIAL_LAMBDA,
+    DEFAULT_MAX_ITERATIONS,
+    DEFAULT_GRADIENT_TOLERANCE,
+    DEFAULT_CRASH_PENALTY_WEIGHT,
+};
+
+use std::time::Instant;
 
-use crate::config::{CoreConfig, Strategy};
+use crate::config::{CoreConfig, PidConfig, Strategy};
 
 /// Bounds for undervolt calculation on a single core
 ///
@@ -209,17 +261,38 @@ pub trait AdaptationStrategy: Send + Sync {
     fn name(&self) -> &'static str;
 }
 
+/// Online-learning counterpart to `AdaptationStrategy`
+///
+/// `AdaptationStrategy::calculate_target` is the read side: a pure lookup
+/// from load to target. `StabilityFeedback` is the write side: it lets a
+/// caller report observed instability (a crash or hang) or stable
+/// operation at a given load, so a strategy can build up a closed feedback
+/// loop instead of following a purely open-loop curve. Not every strategy
+/// implements this - the fixed-curve strategies have nothing to learn.
+pub trait StabilityFeedback {
+    /// Record that a crash/hang/instability was observed while running at
+    /// `load`, timestamped `at` so the strategy can compute how much time
+    /// has passed since the last event in that load region
+    fn record_instability(&mut self, load: f32, at: Instant);
+
+    /// Record that the system ran stably at `load`, timestamped `at`
+    fn record_stable(&mut self, load: f32, at: Instant);
+}
+
 /// Factory function to create an adaptation strategy from the Strategy enum
 ///
 /// # Arguments
 /// * `strategy` - The strategy type to create
 /// * `custom_curve` - Optional custom curve points for CustomStrategy
+/// * `pid_config` - Optional PID gains/setpoint for `Strategy::Pid`; falls
+///   back to `PidConfig::default()` if none is given
 ///
 /// # Returns
 /// A boxed trait object implementing AdaptationStrategy
 pub fn create_strategy(
     strategy: Strategy,
     custom_curve: Option<Vec<(f32, i32)>>,
+    pid_config: Option<PidConfig>,
 ) -> Box<dyn AdaptationStrategy> {
     match strategy {
         Strategy::Conservative => Box::new(ConservativeStrategy::new()),
@@ -232,6 +305,10 @@ pub fn create_strategy(
             });
             Box::new(CustomStrategy::new(curve))
         }
+        Strategy::Pid => Box::new(PidStrategy::new(pid_config.unwrap_or_default())),
+        Strategy::Adaptive => Box::new(AdaptiveStrategy::default()),
+        Strategy::Learning => Box::new(LearningStrategy::default()),
+        Strategy::Markov => Box::new(MarkovStrategy::default()),
     }
 }
 
@@ -243,11 +320,31 @@ pub fn clamp_to_bounds(value: i32, bounds: &CoreBounds) -> i32 {
     value.max(bounds.max_mv).min(bounds.min_mv)
 }
 
-/// Linear interpolation between two values
+/// Fixed-point scale factor `lerp` rescales `t` into before any integer
+/// arithmetic; 16 bits of fraction is far more precision than an `i32` mV
+/// value needs
+const LERP_FIXED_SCALE: i64 = 1 << 16;
+
+/// Linear interpolation between two values using saturating fixed-point
+/// integer arithmetic
+///
+/// The naive `a as f32 + (b - a) as f32 * t` computes `b - a` in `i32`
+/// first, which can overflow (panicking in debug builds) when curve
+/// values sit near `i32::MIN`/`i32::MAX`, and silently rounds a NaN `t`
+/// to 0 instead of leaving `a` unchanged. Here `t` is rescaled into a
+/// `t_num/LERP_FIXED_SCALE` fraction and every intermediate runs in `i64`,
+/// with a saturating narrow back to `i32` only at the very end.
 #[inline]
 pub fn lerp(a: i32, b: i32, t: f32) -> i32 {
-    let t = t.clamp(0.0, 1.0);
-    (a as f32 + (b - a) as f32 * t).round() as i32
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    let t_num = (t * LERP_FIXED_SCALE as f32).round() as i64;
+
+    let a = a as i64;
+    let b = b as i64;
+    let delta = b.saturating_sub(a);
+    let offset = delta.saturating_mul(t_num) / LERP_FIXED_SCALE;
+
+    a.saturating_add(offset).clamp(i32::MIN as i64, i32::MAX as i64) as i32
 }
 
 #[cfg(test)]
@@ -301,23 +398,42 @@ mod tests {
         assert_eq!(lerp(-35, -20, 1.5), -20);
     }
 
+    #[test]
+    fn test_lerp_extreme_endpoints_do_not_overflow() {
+        // b - a would overflow i32 if computed directly (i32::MAX -
+        // i32::MIN > i32::MAX); the i64 intermediates must not panic or
+        // wrap.
+        assert_eq!(lerp(i32::MIN, i32::MAX, 0.0), i32::MIN);
+        assert_eq!(lerp(i32::MIN, i32::MAX, 1.0), i32::MAX);
+        assert_eq!(lerp(i32::MAX, i32::MIN, 0.0), i32::MAX);
+        assert_eq!(lerp(i32::MAX, i32::MIN, 1.0), i32::MIN);
+
+        let mid = lerp(i32::MIN, i32::MAX, 0.5);
+        assert!(mid > i32::MIN / 2 && mid < i32::MAX / 2 + 1);
+    }
+
+    #[test]
+    fn test_lerp_nan_t_leaves_a_unchanged() {
+        assert_eq!(lerp(-35, -20, f32::NAN), -35);
+    }
+
     #[test]
     fn test_create_strategy_conservative() {
-        let strategy = create_strategy(Strategy::Conservative, None);
+        let strategy = create_strategy(Strategy::Conservative, None, None);
         assert_eq!(strategy.name(), "conservative");
         assert_eq!(strategy.ramp_time_ms(), 5000);
     }
 
     #[test]
     fn test_create_strategy_balanced() {
-        let strategy = create_strategy(Strategy::Balanced, None);
+        let strategy = create_strategy(Strategy::Balanced, None, None);
         assert_eq!(strategy.name(), "balanced");
         assert_eq!(strategy.ramp_time_ms(), 2000);
     }
 
     #[test]
     fn test_create_strategy_aggressive() {
-        let strategy = create_strategy(Strategy::Aggressive, None);
+        let strategy = create_strategy(Strategy::Aggressive, None, None);
         assert_eq!(strategy.name(), "aggressive");
         assert_eq!(strategy.ramp_time_ms(), 500);
     }
@@ -325,7 +441,25 @@ mod tests {
     #[test]
     fn test_create_strategy_custom() {
         let curve = vec![(0.0, -30), (50.0, -20), (100.0, -10)];
-        let strategy = create_strategy(Strategy::Custom, Some(curve));
+        let strategy = create_strategy(Strategy::Custom, Some(curve), None);
         assert_eq!(strategy.name(), "custom");
     }
+
+    #[test]
+    fn test_create_strategy_adaptive() {
+        let strategy = create_strategy(Strategy::Adaptive, None, None);
+        assert_eq!(strategy.name(), "adaptive");
+    }
+
+    #[test]
+    fn test_create_strategy_learning() {
+        let strategy = create_strategy(Strategy::Learning, None, None);
+        assert_eq!(strategy.name(), "learning");
+    }
+
+    #[test]
+    fn test_create_strategy_markov() {
+        let strategy = create_strategy(Strategy::Markov, None, None);
+        assert_eq!(strategy.name(), "markov");
+    }
 }
@@ -0,0 +1,278 @@
+//! Automatic `CustomStrategy` curve discovery via simulated annealing
+//!
+//! Hand-authoring a `(load%, undervolt_mv)` curve for `CustomStrategy` is
+//! tedious and easy to get wrong (see the monotonicity bug `CustomStrategy`
+//! used to allow). `CurveOptimizer` searches for a near-optimal curve
+//! instead: it treats the undervolt value at each of a fixed set of load
+//! anchors as a decision variable, starts from the same straight-line
+//! curve `BalancedStrategy` would produce, and repeatedly perturbs one
+//! anchor by a small amount. Candidates are scored by a caller-supplied
+//! `objective` closure - a cost where lower is better, e.g. something the
+//! caller derives from a stress run (crash count, thermal headroom
+//! deficit, etc.) - and accepted using the standard simulated-annealing
+//! rule: always accept an improvement, otherwise accept a worse move with
+//! probability `exp(-Δ/temperature)`, with the temperature cooling
+//! geometrically each iteration. Bounds and monotonicity are enforced as
+//! hard constraints on every perturbation, so every candidate curve -
+//! not just the final one - is already safe to feed into
+//! `create_strategy(Strategy::Custom, Some(curve), None)`.
+
+use super::{CoreBounds, clamp_to_bounds, lerp};
+
+/// Load anchors used as decision variables when no custom set is given
+pub const DEFAULT_ANCHOR_LOADS: [f32; 5] = [0.0, 25.0, 50.0, 75.0, 100.0];
+
+/// Default number of perturb/accept iterations
+pub const DEFAULT_ITERATIONS: usize = 500;
+
+/// Default starting temperature for the acceptance rule
+pub const DEFAULT_INITIAL_TEMPERATURE: f64 = 10.0;
+
+/// Per-iteration multiplicative cooling rate (must be in `(0.0, 1.0)`)
+pub const DEFAULT_COOLING_RATE: f64 = 0.95;
+
+/// Maximum magnitude, in mV, of a single anchor perturbation
+const MAX_PERTURBATION_MV: i32 = 2;
+
+/// Small, dependency-free deterministic PRNG (SplitMix64) so tuning runs
+/// are reproducible from a seed without pulling in an external `rand` dep
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform float in `[0.0, 1.0)`
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Uniform integer in `[0, bound)`; `bound` must be non-zero
+    fn next_below(&mut self, bound: usize) -> usize {
+        (self.next_u64() as usize) % bound
+    }
+}
+
+/// Hill-climbing / simulated-annealing search over `CustomStrategy` curves
+pub struct CurveOptimizer {
+    anchors: Vec<f32>,
+    iterations: usize,
+    initial_temperature: f64,
+    cooling_rate: f64,
+    seed: u64,
+}
+
+impl CurveOptimizer {
+    /// Create an optimizer with the default anchors, iteration count and
+    /// cooling schedule, seeded for reproducibility
+    pub fn new(seed: u64) -> Self {
+        CurveOptimizer {
+            anchors: DEFAULT_ANCHOR_LOADS.to_vec(),
+            iterations: DEFAULT_ITERATIONS,
+            initial_temperature: DEFAULT_INITIAL_TEMPERATURE,
+            cooling_rate: DEFAULT_COOLING_RATE,
+            seed,
+        }
+    }
+
+    /// Use a custom set of load anchors (must be sorted ascending)
+    pub fn with_anchors(mut self, anchors: Vec<f32>) -> Self {
+        self.anchors = anchors;
+        self
+    }
+
+    /// Override the number of perturb/accept iterations
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Override the starting temperature
+    pub fn with_initial_temperature(mut self, initial_temperature: f64) -> Self {
+        self.initial_temperature = initial_temperature;
+        self
+    }
+
+    /// Override the per-iteration cooling rate
+    pub fn with_cooling_rate(mut self, cooling_rate: f64) -> Self {
+        self.cooling_rate = cooling_rate;
+        self
+    }
+
+    /// Search for a near-optimal curve
+    ///
+    /// `objective` scores a candidate curve with a cost where lower is
+    /// better; it's called once per proposed candidate (including the
+    /// starting curve). Returns the best curve found, ready to hand to
+    /// `create_strategy(Strategy::Custom, Some(curve), None)`.
+    pub fn tune<F>(&self, bounds: &CoreBounds, mut objective: F) -> Vec<(f32, i32)>
+    where
+        F: FnMut(&[(f32, i32)]) -> f64,
+    {
+        let mut rng = SplitMix64::new(self.seed);
+
+        // Start from the same straight-line curve BalancedStrategy would
+        // produce; it's always a valid, monotone, in-bounds point to climb
+        // from.
+        let mut current: Vec<i32> = self
+            .anchors
+            .iter()
+            .map(|&load| lerp(bounds.max_mv, bounds.min_mv, load / 100.0))
+            .collect();
+        let mut current_score = objective(&self.curve_from(&current));
+
+        let mut best = current.clone();
+        let mut best_score = current_score;
+
+        if current.is_empty() {
+            return self.curve_from(&current);
+        }
+
+        let mut temperature = self.initial_temperature;
+        for _ in 0..self.iterations {
+            let idx = rng.next_below(current.len());
+            let magnitude = 1 + rng.next_below(MAX_PERTURBATION_MV as usize) as i32;
+            let delta = if rng.next_f64() < 0.5 { magnitude } else { -magnitude };
+
+            let mut candidate = current.clone();
+            candidate[idx] = clamp_to_bounds(candidate[idx].saturating_add(delta), bounds);
+
+            // Hard constraint: monotone non-decreasing across ascending
+            // load anchors (higher load must stay at least as safe as its
+            // lower-load neighbor).
+            if idx > 0 {
+                candidate[idx] = candidate[idx].max(candidate[idx - 1]);
+            }
+            if idx + 1 < candidate.len() {
+                candidate[idx] = candidate[idx].min(candidate[idx + 1]);
+            }
+
+            let candidate_curve = self.curve_from(&candidate);
+            let candidate_score = objective(&candidate_curve);
+            let delta_score = candidate_score - current_score;
+
+            let accept = delta_score < 0.0
+                || (temperature > 0.0 && rng.next_f64() < (-delta_score / temperature).exp());
+
+            if accept {
+                current = candidate;
+                current_score = candidate_score;
+                if current_score < best_score {
+                    best = current.clone();
+                    best_score = current_score;
+                }
+            }
+
+            temperature *= self.cooling_rate;
+        }
+
+        self.curve_from(&best)
+    }
+
+    fn curve_from(&self, values: &[i32]) -> Vec<(f32, i32)> {
+        self.anchors.iter().copied().zip(values.iter().copied()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds() -> CoreBounds {
+        CoreBounds {
+            min_mv: -20,
+            max_mv: -35,
+            threshold: 50.0,
+        }
+    }
+
+    fn assert_valid_curve(curve: &[(f32, i32)], bounds: &CoreBounds) {
+        for &(_, mv) in curve {
+            assert!(
+                mv >= bounds.max_mv && mv <= bounds.min_mv,
+                "curve value {mv} out of bounds [{}, {}]",
+                bounds.max_mv,
+                bounds.min_mv
+            );
+        }
+        for window in curve.windows(2) {
+            assert!(
+                window[1].1 >= window[0].1,
+                "curve must be non-decreasing as load rises: {:?}",
+                curve
+            );
+        }
+    }
+
+    #[test]
+    fn test_zero_iterations_returns_balanced_starting_curve() {
+        let optimizer = CurveOptimizer::new(1).with_iterations(0);
+        let curve = optimizer.tune(&bounds(), |_| 0.0);
+
+        for &(load, mv) in &curve {
+            assert_eq!(mv, lerp(bounds().max_mv, bounds().min_mv, load / 100.0));
+        }
+    }
+
+    #[test]
+    fn test_optimizer_never_violates_bounds_or_monotonicity() {
+        let optimizer = CurveOptimizer::new(42).with_iterations(300);
+
+        let curve = optimizer.tune(&bounds(), |curve| {
+            // Validate every candidate the optimizer ever proposes, not
+            // just the final result.
+            assert_valid_curve(curve, &bounds());
+            curve.iter().map(|&(_, mv)| (mv as f64 + 25.0).abs()).sum()
+        });
+
+        assert_valid_curve(&curve, &bounds());
+    }
+
+    #[test]
+    fn test_optimizer_is_reproducible_with_same_seed() {
+        let objective = |curve: &[(f32, i32)]| curve.iter().map(|&(_, mv)| (mv as f64).abs()).sum();
+
+        let a = CurveOptimizer::new(7).with_iterations(200).tune(&bounds(), objective);
+        let b = CurveOptimizer::new(7).with_iterations(200).tune(&bounds(), objective);
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_curve_stays_valid_across_several_seeds() {
+        let objective = |curve: &[(f32, i32)]| curve.iter().map(|&(_, mv)| (mv as f64).abs()).sum();
+
+        for seed in [0, 1, 2, 42, u64::MAX] {
+            let curve = CurveOptimizer::new(seed).with_iterations(300).tune(&bounds(), objective);
+            assert_valid_curve(&curve, &bounds());
+        }
+    }
+
+    #[test]
+    fn test_custom_anchors_are_respected() {
+        let optimizer = CurveOptimizer::new(3)
+            .with_anchors(vec![0.0, 50.0, 100.0])
+            .with_iterations(100);
+
+        let curve = optimizer.tune(&bounds(), |curve| {
+            curve.iter().map(|&(_, mv)| (mv as f64).abs()).sum()
+        });
+
+        assert_eq!(curve.len(), 3);
+        assert_eq!(curve[0].0, 0.0);
+        assert_eq!(curve[1].0, 50.0);
+        assert_eq!(curve[2].0, 100.0);
+        assert_valid_curve(&curve, &bounds());
+    }
+}
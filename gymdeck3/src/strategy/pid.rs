@@ -0,0 +1,195 @@
+//! PID-based thermal strategy driven by a temperature setpoint
+//!
+//! Unlike the fixed ramp-time strategies, `PidStrategy` doesn't map CPU load
+//! directly to an undervolt value. It closes the loop on temperature: each
+//! sample it computes `error = target_c - measured_temp`, accumulates an
+//! anti-windup-clamped integral term, applies a derivative on the measured
+//! value (to avoid derivative kick when the setpoint itself changes), and
+//! maps the resulting output onto the per-core `[max_mv, min_mv]` range.
+
+use std::sync::Mutex;
+
+use super::{AdaptationStrategy, CoreBounds, lerp};
+use crate::config::PidConfig;
+
+/// Mutable PID state carried between samples
+///
+/// Held behind a `Mutex` since `AdaptationStrategy::calculate_target` takes
+/// `&self` (strategies are shared, not recreated, across the sampling loop).
+#[derive(Debug, Default)]
+struct PidState {
+    integral: f32,
+    prev_measured: Option<f32>,
+}
+
+/// Closed-loop PID strategy
+///
+/// The first argument to `calculate_target` is reinterpreted as the
+/// measured temperature in °C rather than CPU load percentage, since PID
+/// regulates around a temperature setpoint; see `PidConfig`.
+pub struct PidStrategy {
+    config: PidConfig,
+    state: Mutex<PidState>,
+}
+
+impl PidStrategy {
+    pub fn new(config: PidConfig) -> Self {
+        PidStrategy {
+            config,
+            state: Mutex::new(PidState::default()),
+        }
+    }
+
+    /// Get the PID configuration
+    pub fn config(&self) -> &PidConfig {
+        &self.config
+    }
+}
+
+impl AdaptationStrategy for PidStrategy {
+    fn calculate_target(&self, measured_temp: f32, bounds: &CoreBounds) -> i32 {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        let error = self.config.target_c - measured_temp;
+        let derivative = match state.prev_measured {
+            // Derivative on measurement, negated: a rising temperature
+            // should pull the output down regardless of where the setpoint
+            // currently sits.
+            Some(prev) => -(measured_temp - prev),
+            None => 0.0,
+        };
+
+        let unclamped_integral = state.integral + error;
+        // Anti-windup: only let the integral accumulate while the
+        // un-saturated output would still be inside the clamp range.
+        let raw_output = self.config.kp * error
+            + self.config.ki * unclamped_integral
+            + self.config.kd * derivative;
+        if raw_output >= self.config.output_clamp_min && raw_output <= self.config.output_clamp_max
+        {
+            state.integral = unclamped_integral;
+        }
+        state.prev_measured = Some(measured_temp);
+
+        let output = (self.config.kp * error + self.config.ki * state.integral + self.config.kd * derivative)
+            .clamp(self.config.output_clamp_min, self.config.output_clamp_max);
+
+        // Higher output (cooler than target) -> more aggressive (max_mv);
+        // lower output (hotter than target) -> safer (min_mv).
+        let range = self.config.output_clamp_max - self.config.output_clamp_min;
+        let t = if range > 0.0 {
+            (output - self.config.output_clamp_min) / range
+        } else {
+            0.5
+        };
+        lerp(bounds.min_mv, bounds.max_mv, t)
+    }
+
+    fn ramp_time_ms(&self) -> u64 {
+        // PID already smooths its own response via the integral/derivative
+        // terms, so it uses the same ramp time as balanced for the output
+        // hysteresis controller downstream.
+        2000
+    }
+
+    fn name(&self) -> &'static str {
+        "pid"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds() -> CoreBounds {
+        CoreBounds {
+            min_mv: -20,
+            max_mv: -35,
+            threshold: 50.0,
+        }
+    }
+
+    fn config() -> PidConfig {
+        PidConfig {
+            target_c: 70.0,
+            kp: 1.0,
+            ki: 0.0,
+            kd: 0.0,
+            output_clamp_min: -20.0,
+            output_clamp_max: 20.0,
+        }
+    }
+
+    #[test]
+    fn test_pid_name() {
+        let strategy = PidStrategy::new(config());
+        assert_eq!(strategy.name(), "pid");
+    }
+
+    #[test]
+    fn test_pid_at_setpoint_is_midpoint() {
+        let strategy = PidStrategy::new(config());
+        // measured == target -> error 0 -> output 0 -> midpoint of bounds
+        let target = strategy.calculate_target(70.0, &bounds());
+        assert_eq!(target, -28); // round(lerp(-20, -35, 0.5))
+    }
+
+    #[test]
+    fn test_pid_below_target_is_more_aggressive() {
+        let strategy = PidStrategy::new(config());
+        // Cooler than target -> positive error -> can push towards max_mv
+        let target = strategy.calculate_target(50.0, &bounds());
+        assert!(target < -28, "target {} should be more aggressive than midpoint", target);
+    }
+
+    #[test]
+    fn test_pid_above_target_is_safer() {
+        let strategy = PidStrategy::new(config());
+        // Hotter than target -> negative error -> towards min_mv (safer)
+        let target = strategy.calculate_target(90.0, &bounds());
+        assert!(target > -28, "target {} should be safer than midpoint", target);
+    }
+
+    #[test]
+    fn test_pid_respects_bounds_even_with_large_error() {
+        let strategy = PidStrategy::new(config());
+        for measured in [0.0, 40.0, 70.0, 100.0, 150.0] {
+            let target = strategy.calculate_target(measured, &bounds());
+            assert!(target >= bounds().max_mv && target <= bounds().min_mv);
+        }
+    }
+
+    #[test]
+    fn test_pid_integral_accumulates_across_samples() {
+        let mut cfg = config();
+        cfg.kp = 0.0;
+        cfg.ki = 1.0;
+        let strategy = PidStrategy::new(cfg);
+
+        // Sustained cooler-than-target error (target 70, measured 65, so
+        // error stays 5 every sample) should accumulate integral and drive
+        // the target progressively more aggressive.
+        let first = strategy.calculate_target(65.0, &bounds());
+        let second = strategy.calculate_target(65.0, &bounds());
+        assert!(second < first, "second ({}) should be more aggressive than first ({})", second, first);
+    }
+
+    #[test]
+    fn test_pid_anti_windup_clamps_integral() {
+        let mut cfg = config();
+        cfg.kp = 0.0;
+        cfg.ki = 1.0;
+        cfg.output_clamp_min = -5.0;
+        cfg.output_clamp_max = 5.0;
+        let strategy = PidStrategy::new(cfg);
+
+        // error == output_clamp_max exactly saturates the output on the
+        // first sample; anti-windup should then hold the integral there
+        // instead of letting a sustained error wind it up further.
+        for _ in 0..5 {
+            strategy.calculate_target(65.0, &bounds());
+        }
+        let saturated = strategy.calculate_target(65.0, &bounds());
+        assert_eq!(saturated, bounds().max_mv);
+    }
+}
@@ -0,0 +1,289 @@
+//! Parallel config-sweep study runner with progress reporting
+//!
+//! Evaluates a batch of candidate configs - a manual grid, or the list of
+//! trials an [`crate::autotune::AutoTuner`] produced - and records each
+//! trial's applied config, measured score, and pass/fail outcome into a
+//! structured [`StudyReport`].
+//!
+//! `ryzenadj` can only be applied serially to one SoC, so each trial's
+//! apply step is serialized through a single `tokio::sync::Mutex` around
+//! the shared [`RyzenadjExecutor`]. Parallelism is over the
+//! evaluation/analysis stage instead: scoring a trial's post-apply
+//! measurement runs on the blocking-task pool, bounded by
+//! `max_parallel_evals`, while the *next* trial's apply proceeds
+//! immediately rather than waiting on that scoring to finish.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+use tokio::task::JoinSet;
+
+use crate::ryzenadj::{ApplyResult, RyzenadjExecutor};
+
+/// Default number of trial evaluations allowed to run concurrently
+pub const DEFAULT_MAX_PARALLEL_EVALS: usize = 4;
+
+/// A pure, synchronous scoring function run on the blocking-task pool for
+/// each successfully-applied config
+///
+/// Takes the applied config and returns a score where higher is better,
+/// matching the objective convention used by [`crate::autotune::AutoTuner`].
+pub type ScoreFn = Arc<dyn Fn(&[i32]) -> f64 + Send + Sync>;
+
+/// Live progress for an in-flight study, reported after each trial
+/// completes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudyProgress {
+    /// Trials fully applied and evaluated so far
+    pub completed: usize,
+    /// Total trials in this study
+    pub total: usize,
+    /// Best score seen among completed trials (`f64::NEG_INFINITY` before
+    /// the first trial completes)
+    pub best_score: f64,
+}
+
+/// One trial's applied config, score, and pass/fail outcome
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrialRecord {
+    /// Per-core undervolt values (mV) that were applied
+    pub config: Vec<i32>,
+    /// Score from `ScoreFn`, or `f64::NEG_INFINITY` if the apply failed
+    pub score: f64,
+    /// Whether the apply succeeded
+    pub passed: bool,
+}
+
+/// Ranked results of a completed study
+#[derive(Debug, Clone, PartialEq)]
+pub struct StudyReport {
+    /// Trials sorted by score, descending
+    pub trials: Vec<TrialRecord>,
+}
+
+impl StudyReport {
+    /// The highest-scoring trial, if any
+    pub fn best(&self) -> Option<&TrialRecord> {
+        self.trials.first()
+    }
+
+    /// Number of trials that passed (applied successfully)
+    pub fn passed_count(&self) -> usize {
+        self.trials.iter().filter(|t| t.passed).count()
+    }
+}
+
+/// Runs a batch of candidate configs through a shared `RyzenadjExecutor`,
+/// serializing applies while evaluating each trial's score concurrently
+pub struct StudyRunner {
+    executor: Arc<Mutex<RyzenadjExecutor>>,
+    max_parallel_evals: usize,
+}
+
+impl StudyRunner {
+    /// Create a new study runner owning `executor`
+    pub fn new(executor: RyzenadjExecutor) -> Self {
+        Self::from_shared(Arc::new(Mutex::new(executor)))
+    }
+
+    /// Create a new study runner over an executor already shared with
+    /// another part of the application (e.g. the main control loop)
+    pub fn from_shared(executor: Arc<Mutex<RyzenadjExecutor>>) -> Self {
+        Self {
+            executor,
+            max_parallel_evals: DEFAULT_MAX_PARALLEL_EVALS,
+        }
+    }
+
+    /// Builder: cap how many trial evaluations may run concurrently
+    pub fn with_max_parallel_evals(mut self, max_parallel_evals: usize) -> Self {
+        self.max_parallel_evals = max_parallel_evals.max(1);
+        self
+    }
+
+    /// Run the study: apply every config in `configs`, in order, through
+    /// the shared executor, scoring each successfully-applied config with
+    /// `score_fn` on the blocking-task pool
+    ///
+    /// `on_progress` is called once per completed trial (not necessarily
+    /// in config order, since evaluation is concurrent) with a running
+    /// count and the best score seen so far. The returned [`StudyReport`]
+    /// is sorted by score, descending.
+    pub async fn run(
+        &self,
+        configs: Vec<Vec<i32>>,
+        score_fn: ScoreFn,
+        mut on_progress: impl FnMut(StudyProgress),
+    ) -> StudyReport {
+        let total = configs.len();
+        let mut join_set: JoinSet<TrialRecord> = JoinSet::new();
+        let mut records: Vec<TrialRecord> = Vec::with_capacity(total);
+        let mut completed = 0usize;
+        let mut best_score = f64::NEG_INFINITY;
+
+        for config in configs {
+            // Serialize the apply step: only one config is ever in flight
+            // against the real hardware at a time.
+            let apply_result = {
+                let mut executor = self.executor.lock().await;
+                executor.apply(&config).await
+            };
+
+            // Bound the number of in-flight evaluations rather than the
+            // number of in-flight applies - applies are already serial.
+            while join_set.len() >= self.max_parallel_evals {
+                if let Some(record) = join_set
+                    .join_next()
+                    .await
+                    .map(|res| res.expect("trial evaluation task panicked"))
+                {
+                    completed += 1;
+                    best_score = best_score.max(record.score);
+                    on_progress(StudyProgress { completed, total, best_score });
+                    records.push(record);
+                }
+            }
+
+            Self::spawn_evaluation(&mut join_set, config, apply_result, Arc::clone(&score_fn));
+        }
+
+        while let Some(res) = join_set.join_next().await {
+            let record = res.expect("trial evaluation task panicked");
+            completed += 1;
+            best_score = best_score.max(record.score);
+            on_progress(StudyProgress { completed, total, best_score });
+            records.push(record);
+        }
+
+        records.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+        StudyReport { trials: records }
+    }
+
+    /// Spawn the scoring stage for one trial: a failed apply is scored as
+    /// `f64::NEG_INFINITY` without touching the blocking pool at all,
+    /// otherwise `score_fn` runs there since it may do CPU-bound parsing of
+    /// `/proc/stat` snapshots.
+    fn spawn_evaluation(
+        join_set: &mut JoinSet<TrialRecord>,
+        config: Vec<i32>,
+        apply_result: Result<ApplyResult, crate::ryzenadj::RyzenadjError>,
+        score_fn: ScoreFn,
+    ) {
+        match apply_result {
+            Ok(result) if result.success => {
+                join_set.spawn_blocking(move || {
+                    let score = score_fn(&config);
+                    TrialRecord { config, score, passed: true }
+                });
+            }
+            _ => {
+                join_set.spawn(async move {
+                    TrialRecord { config, score: f64::NEG_INFINITY, passed: false }
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn passthrough_score() -> ScoreFn {
+        Arc::new(|config: &[i32]| config.iter().map(|&v| v as f64).sum())
+    }
+
+    #[tokio::test]
+    async fn test_study_runner_ranks_by_score_descending() {
+        let runner = StudyRunner::new(RyzenadjExecutor::new("true"));
+        let configs = vec![vec![-10], vec![-30], vec![-20]];
+
+        let report = runner.run(configs, passthrough_score(), |_| {}).await;
+
+        assert_eq!(report.trials.len(), 3);
+        // Sum of mV values: higher (less negative) sums score higher
+        assert_eq!(report.trials[0].config, vec![-10]);
+        assert_eq!(report.trials[1].config, vec![-20]);
+        assert_eq!(report.trials[2].config, vec![-30]);
+    }
+
+    #[tokio::test]
+    async fn test_study_runner_best_returns_top_trial() {
+        let runner = StudyRunner::new(RyzenadjExecutor::new("true"));
+        let configs = vec![vec![-5], vec![-50]];
+
+        let report = runner.run(configs, passthrough_score(), |_| {}).await;
+        assert_eq!(report.best().unwrap().config, vec![-5]);
+    }
+
+    #[tokio::test]
+    async fn test_study_runner_records_failed_apply_as_worst_score() {
+        let runner = StudyRunner::new(RyzenadjExecutor::new("/nonexistent/ryzenadj"));
+        let configs = vec![vec![-10]];
+
+        let report = runner.run(configs, passthrough_score(), |_| {}).await;
+        assert_eq!(report.trials.len(), 1);
+        assert!(!report.trials[0].passed);
+        assert_eq!(report.trials[0].score, f64::NEG_INFINITY);
+    }
+
+    #[tokio::test]
+    async fn test_study_runner_passed_count() {
+        let runner = StudyRunner::new(RyzenadjExecutor::new("true"));
+        let configs = vec![vec![-10], vec![-20], vec![-30]];
+
+        let report = runner.run(configs, passthrough_score(), |_| {}).await;
+        assert_eq!(report.passed_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_study_runner_progress_reaches_total_and_tracks_best() {
+        let runner = StudyRunner::new(RyzenadjExecutor::new("true"));
+        let configs = vec![vec![-10], vec![-30], vec![-20]];
+
+        let mut last_progress: Option<StudyProgress> = None;
+        let report = runner
+            .run(configs, passthrough_score(), |progress| {
+                last_progress = Some(progress);
+            })
+            .await;
+
+        let final_progress = last_progress.unwrap();
+        assert_eq!(final_progress.completed, 3);
+        assert_eq!(final_progress.total, 3);
+        assert_eq!(final_progress.best_score, report.best().unwrap().score);
+    }
+
+    #[tokio::test]
+    async fn test_study_runner_respects_max_parallel_evals_of_one() {
+        // With a cap of 1, evaluation is effectively sequential - this
+        // should still produce a fully ranked, complete report.
+        let runner = StudyRunner::new(RyzenadjExecutor::new("true")).with_max_parallel_evals(1);
+        let configs = vec![vec![-1], vec![-2], vec![-3], vec![-4]];
+
+        let report = runner.run(configs, passthrough_score(), |_| {}).await;
+        assert_eq!(report.trials.len(), 4);
+        assert_eq!(report.trials[0].config, vec![-1]);
+    }
+
+    #[tokio::test]
+    async fn test_study_runner_empty_configs_yields_empty_report() {
+        let runner = StudyRunner::new(RyzenadjExecutor::new("true"));
+        let report = runner.run(Vec::new(), passthrough_score(), |_| {}).await;
+        assert!(report.trials.is_empty());
+        assert_eq!(report.best(), None);
+    }
+
+    #[tokio::test]
+    async fn test_study_runner_from_shared_reuses_executor() {
+        let executor = Arc::new(Mutex::new(RyzenadjExecutor::new("true")));
+        let runner = StudyRunner::from_shared(Arc::clone(&executor));
+        let report = runner.run(vec![vec![-1]], passthrough_score(), |_| {}).await;
+        assert_eq!(report.trials.len(), 1);
+
+        // The same executor handle is still usable afterward.
+        let mut guard = executor.lock().await;
+        assert_eq!(guard.consecutive_failures(), 0);
+    }
+}
@@ -0,0 +1,439 @@
+//! Structured telemetry for unattended tuning sessions
+//!
+//! Streams `LoadSample` readings, strategy/mode transitions, and ryzenadj
+//! apply outcomes as newline-delimited JSON to a log sink, and accumulates
+//! a tuning-session summary that can be exported as a JUnit-style XML
+//! report (one "case" per applied config, pass/fail derived from the
+//! apply's success flag).
+
+use std::io::{self, Write};
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::config::Strategy;
+use crate::load_monitor::LoadSample;
+use crate::ryzenadj::ApplyResult;
+
+/// NDJSON record for one `LoadSample` reading
+///
+/// Serializes with an explicit, documented field order - `type`,
+/// `timestamp_ms`, `load`, `average`, `strategy` - via a manual
+/// `Serialize` impl below, matching `output::StatusOutput`'s convention.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadRecord {
+    /// Per-core sample timestamp, in ms since `LoadMonitor` start
+    pub timestamp_ms: u64,
+    /// Per-core load percentages (0.0 - 100.0 each)
+    pub load: Vec<f32>,
+    /// Average load across all cores (0.0 - 100.0)
+    pub average: f32,
+    /// Active power profile (adaptation strategy) at the time of the sample
+    pub strategy: String,
+}
+
+impl LoadRecord {
+    /// Build a record from a `LoadMonitor` sample and the currently active
+    /// strategy
+    pub fn new(sample: &LoadSample, strategy: Strategy) -> Self {
+        Self {
+            timestamp_ms: sample.timestamp_ms,
+            load: sample.per_core.clone(),
+            average: sample.average,
+            strategy: strategy.to_string(),
+        }
+    }
+}
+
+impl Serialize for LoadRecord {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("LoadRecord", 5)?;
+        state.serialize_field("type", "load")?;
+        state.serialize_field("timestamp_ms", &self.timestamp_ms)?;
+        state.serialize_field("load", &self.load)?;
+        state.serialize_field("average", &self.average)?;
+        state.serialize_field("strategy", &self.strategy)?;
+        state.end()
+    }
+}
+
+/// NDJSON record for a power-profile (strategy) transition
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModeTransitionRecord {
+    /// Timestamp, in ms since the telemetry writer started
+    pub timestamp_ms: u64,
+    /// Previous strategy name
+    pub from: String,
+    /// New strategy name
+    pub to: String,
+}
+
+impl ModeTransitionRecord {
+    /// Build a record for a transition from `from` to `to`
+    pub fn new(timestamp_ms: u64, from: Strategy, to: Strategy) -> Self {
+        Self {
+            timestamp_ms,
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+}
+
+impl Serialize for ModeTransitionRecord {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("ModeTransitionRecord", 4)?;
+        state.serialize_field("type", "mode_transition")?;
+        state.serialize_field("timestamp_ms", &self.timestamp_ms)?;
+        state.serialize_field("from", &self.from)?;
+        state.serialize_field("to", &self.to)?;
+        state.end()
+    }
+}
+
+/// NDJSON record for one ryzenadj apply outcome
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApplyRecord {
+    /// Timestamp, in ms since the telemetry writer started
+    pub timestamp_ms: u64,
+    /// Per-core undervolt values (mV) that were applied
+    pub values: Vec<i32>,
+    /// Whether the apply succeeded
+    pub success: bool,
+    /// Consecutive failure count after this attempt (0 if success)
+    pub consecutive_failures: u32,
+}
+
+impl ApplyRecord {
+    /// Build a record from the values applied and the resulting
+    /// `ApplyResult`
+    pub fn new(timestamp_ms: u64, values: Vec<i32>, result: &ApplyResult) -> Self {
+        Self {
+            timestamp_ms,
+            values,
+            success: result.success,
+            consecutive_failures: result.consecutive_failures,
+        }
+    }
+}
+
+impl Serialize for ApplyRecord {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("ApplyRecord", 5)?;
+        state.serialize_field("type", "apply")?;
+        state.serialize_field("timestamp_ms", &self.timestamp_ms)?;
+        state.serialize_field("values", &self.values)?;
+        state.serialize_field("success", &self.success)?;
+        state.serialize_field("consecutive_failures", &self.consecutive_failures)?;
+        state.end()
+    }
+}
+
+/// One applied config's outcome, as accumulated for the end-of-session
+/// JUnit report
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApplyCase {
+    /// Per-core undervolt values (mV) that were applied
+    pub values: Vec<i32>,
+    /// Whether the apply succeeded
+    pub passed: bool,
+}
+
+/// Streams telemetry records as NDJSON to a log sink and accumulates the
+/// applied configs needed for an end-of-session [`TuningSummary`]
+pub struct TelemetryWriter<W: Write> {
+    sink: W,
+    start_time: Instant,
+    cases: Vec<ApplyCase>,
+}
+
+impl TelemetryWriter<io::Stdout> {
+    /// Create a writer that streams to stdout
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl<W: Write> TelemetryWriter<W> {
+    /// Create a new telemetry writer over `sink`
+    pub fn new(sink: W) -> Self {
+        Self {
+            sink,
+            start_time: Instant::now(),
+            cases: Vec::new(),
+        }
+    }
+
+    /// Elapsed time since this writer was created, in milliseconds
+    pub fn uptime_ms(&self) -> u64 {
+        self.start_time.elapsed().as_millis() as u64
+    }
+
+    /// Record a `LoadMonitor` sample
+    pub fn record_load(&mut self, sample: &LoadSample, strategy: Strategy) -> io::Result<()> {
+        self.write_json(&LoadRecord::new(sample, strategy))
+    }
+
+    /// Record a power-profile (strategy) transition
+    pub fn record_mode_transition(&mut self, from: Strategy, to: Strategy) -> io::Result<()> {
+        let record = ModeTransitionRecord::new(self.uptime_ms(), from, to);
+        self.write_json(&record)
+    }
+
+    /// Record a ryzenadj apply outcome
+    ///
+    /// Emits the NDJSON record and accumulates an [`ApplyCase`] for the
+    /// eventual [`TuningSummary`].
+    pub fn record_apply(&mut self, values: &[i32], result: &ApplyResult) -> io::Result<()> {
+        let record = ApplyRecord::new(self.uptime_ms(), values.to_vec(), result);
+        self.write_json(&record)?;
+        self.cases.push(ApplyCase {
+            values: values.to_vec(),
+            passed: result.success,
+        });
+        Ok(())
+    }
+
+    /// The applied configs recorded so far
+    pub fn cases(&self) -> &[ApplyCase] {
+        &self.cases
+    }
+
+    /// Consume this writer and produce a summary over the recorded cases
+    pub fn into_summary(self, suite_name: impl Into<String>) -> TuningSummary {
+        TuningSummary {
+            suite_name: suite_name.into(),
+            cases: self.cases,
+        }
+    }
+
+    fn write_json<T: Serialize>(&mut self, value: &T) -> io::Result<()> {
+        let json = serde_json::to_string(value)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        writeln!(self.sink, "{}", json)
+    }
+}
+
+/// End-of-session summary of a tuning run, exportable as a JUnit-style XML
+/// report
+#[derive(Debug, Clone, PartialEq)]
+pub struct TuningSummary {
+    /// Name of the test suite, as recorded in the `<testsuite name="...">` tag
+    pub suite_name: String,
+    /// One case per applied config
+    pub cases: Vec<ApplyCase>,
+}
+
+impl TuningSummary {
+    /// Render as a JUnit-style XML report: one `<testcase>` per applied
+    /// config, with a `<failure>` child for configs whose apply did not
+    /// succeed
+    pub fn to_junit_xml(&self) -> String {
+        let failures = self.cases.iter().filter(|c| !c.passed).count();
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
+            xml_escape(&self.suite_name),
+            self.cases.len(),
+            failures,
+        ));
+        for case in &self.cases {
+            let name = xml_escape(&format!("apply[{}]", format_values(&case.values)));
+            if case.passed {
+                xml.push_str(&format!("  <testcase name=\"{}\"/>\n", name));
+            } else {
+                xml.push_str(&format!(
+                    "  <testcase name=\"{}\">\n    <failure message=\"ryzenadj apply failed\"/>\n  </testcase>\n",
+                    name
+                ));
+            }
+        }
+        xml.push_str("</testsuite>\n");
+        xml
+    }
+}
+
+/// Render a config's values as a compact, comma-separated list for test
+/// names
+fn format_values(values: &[i32]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Escape the characters XML attribute values can't contain literally
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(average: f32, per_core: Vec<f32>, timestamp_ms: u64) -> LoadSample {
+        LoadSample {
+            average,
+            per_core,
+            timestamp_ms,
+        }
+    }
+
+    #[test]
+    fn test_load_record_serialization_field_order() {
+        let record = LoadRecord::new(&sample(50.0, vec![40.0, 60.0], 1000), Strategy::Balanced);
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.starts_with("{\"type\":\"load\""));
+        assert!(json.contains("\"timestamp_ms\":1000"));
+        assert!(json.contains("\"load\":[40.0,60.0]"));
+        assert!(json.contains("\"average\":50.0"));
+        assert!(json.contains("\"strategy\":\"balanced\""));
+    }
+
+    #[test]
+    fn test_mode_transition_record_serialization() {
+        let record = ModeTransitionRecord::new(2500, Strategy::Conservative, Strategy::Aggressive);
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.contains("\"type\":\"mode_transition\""));
+        assert!(json.contains("\"from\":\"conservative\""));
+        assert!(json.contains("\"to\":\"aggressive\""));
+    }
+
+    #[test]
+    fn test_apply_record_serialization() {
+        let result = ApplyResult {
+            success: false,
+            consecutive_failures: 3,
+        };
+        let record = ApplyRecord::new(500, vec![-20, -25], &result);
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.contains("\"type\":\"apply\""));
+        assert!(json.contains("\"values\":[-20,-25]"));
+        assert!(json.contains("\"success\":false"));
+        assert!(json.contains("\"consecutive_failures\":3"));
+    }
+
+    #[test]
+    fn test_telemetry_writer_emits_ndjson_lines() {
+        let mut buf: Vec<u8> = Vec::new();
+        {
+            let mut writer = TelemetryWriter::new(&mut buf);
+            writer
+                .record_load(&sample(30.0, vec![30.0], 0), Strategy::Balanced)
+                .unwrap();
+            writer
+                .record_mode_transition(Strategy::Balanced, Strategy::Aggressive)
+                .unwrap();
+            writer
+                .record_apply(
+                    &[-25],
+                    &ApplyResult {
+                        success: true,
+                        consecutive_failures: 0,
+                    },
+                )
+                .unwrap();
+        }
+        let text = String::from_utf8(buf).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("\"type\":\"load\""));
+        assert!(lines[1].contains("\"type\":\"mode_transition\""));
+        assert!(lines[2].contains("\"type\":\"apply\""));
+    }
+
+    #[test]
+    fn test_record_apply_accumulates_cases() {
+        let mut writer = TelemetryWriter::new(Vec::new());
+        writer
+            .record_apply(
+                &[-20],
+                &ApplyResult {
+                    success: true,
+                    consecutive_failures: 0,
+                },
+            )
+            .unwrap();
+        writer
+            .record_apply(
+                &[-40],
+                &ApplyResult {
+                    success: false,
+                    consecutive_failures: 1,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(writer.cases().len(), 2);
+        assert!(writer.cases()[0].passed);
+        assert!(!writer.cases()[1].passed);
+    }
+
+    #[test]
+    fn test_junit_xml_counts_tests_and_failures() {
+        let mut writer = TelemetryWriter::new(Vec::new());
+        writer
+            .record_apply(&[-20], &ApplyResult { success: true, consecutive_failures: 0 })
+            .unwrap();
+        writer
+            .record_apply(&[-40], &ApplyResult { success: false, consecutive_failures: 1 })
+            .unwrap();
+        writer
+            .record_apply(&[-60], &ApplyResult { success: false, consecutive_failures: 2 })
+            .unwrap();
+
+        let summary = writer.into_summary("tuning-session");
+        let xml = summary.to_junit_xml();
+        assert!(xml.contains("<testsuite name=\"tuning-session\" tests=\"3\" failures=\"2\">"));
+        assert_eq!(xml.matches("<testcase").count(), 3);
+        assert_eq!(xml.matches("<failure").count(), 2);
+    }
+
+    #[test]
+    fn test_junit_xml_passing_case_has_no_failure_child() {
+        let mut writer = TelemetryWriter::new(Vec::new());
+        writer
+            .record_apply(&[-20], &ApplyResult { success: true, consecutive_failures: 0 })
+            .unwrap();
+        let summary = writer.into_summary("clean-run");
+        let xml = summary.to_junit_xml();
+        assert!(!xml.contains("<failure"));
+        assert!(xml.contains("tests=\"1\" failures=\"0\""));
+    }
+
+    #[test]
+    fn test_junit_xml_escapes_suite_name() {
+        let writer: TelemetryWriter<Vec<u8>> = TelemetryWriter::new(Vec::new());
+        let summary = writer.into_summary("A & B <tuning>");
+        let xml = summary.to_junit_xml();
+        assert!(xml.contains("A &amp; B &lt;tuning&gt;"));
+    }
+
+    #[test]
+    fn test_junit_xml_empty_summary() {
+        let writer: TelemetryWriter<Vec<u8>> = TelemetryWriter::new(Vec::new());
+        let summary = writer.into_summary("empty");
+        let xml = summary.to_junit_xml();
+        assert!(xml.contains("tests=\"0\" failures=\"0\""));
+        assert!(!xml.contains("<testcase"));
+    }
+}
@@ -0,0 +1,269 @@
+//! Sustained-overtemperature emergency escalation, layered above
+//! `fan::check_safety_override`
+//!
+//! The safety override already forces the fan to 100% once temperature
+//! crosses `critical_temp`, but that alone doesn't help if the fan itself
+//! can't keep up (a stuck fan, a blocked vent, a degraded thermal pad): the
+//! temperature just stays pinned at the top of the curve. `ThermalLoadTracker`
+//! integrates how long and how far the reading has stayed above
+//! `high_temp` into a `thermal_load`, decaying it back down whenever the
+//! reading drops below `high_temp`, and escalates through `ThermalAction`
+//! once that load crosses a configurable budget - first forcing the fan
+//! harder than the curve alone would, then giving up on performance
+//! entirely, and finally asking the daemon to shut the system down before
+//! the hardware does it for us.
+
+use crate::fan::FanSafetyLimits;
+use crate::ryzenadj::{RyzenadjError, RyzenadjExecutor};
+use crate::signals::SignalState;
+
+/// Default `thermal_load` at which escalation begins in earnest
+/// (`ThermalAction::ClampUndervoltToZero`)
+pub const DEFAULT_THERMAL_LOAD_BUDGET: f32 = 300.0;
+
+/// Default amount `thermal_load` decays per tick while at or below
+/// `high_temp`
+pub const DEFAULT_THERMAL_LOAD_DECAY_PER_TICK: f32 = 2.0;
+
+/// Default interval (seconds) between `ThermalLoadTracker::tick` calls this
+/// config assumes; purely informational bookkeeping for callers tuning the
+/// other two constants against wall-clock time
+pub const DEFAULT_THERMAL_TICK_INTERVAL_SEC: f32 = 1.0;
+
+/// Configuration for [`ThermalLoadTracker`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThermalEscalationConfig {
+    /// `thermal_load` at which `ClampUndervoltToZero` triggers; `2x` this
+    /// triggers `RequestShutdown`
+    pub budget: f32,
+    /// Amount `thermal_load` decays per tick while at or below `high_temp`
+    pub decay_per_tick: f32,
+    /// Interval (seconds) between ticks, for tuning the constants above
+    /// against real time
+    pub tick_interval_sec: f32,
+}
+
+impl Default for ThermalEscalationConfig {
+    fn default() -> Self {
+        ThermalEscalationConfig {
+            budget: DEFAULT_THERMAL_LOAD_BUDGET,
+            decay_per_tick: DEFAULT_THERMAL_LOAD_DECAY_PER_TICK,
+            tick_interval_sec: DEFAULT_THERMAL_TICK_INTERVAL_SEC,
+        }
+    }
+}
+
+/// Escalating response to a sustained thermal-load overrun
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThermalAction {
+    /// `thermal_load` is at or below zero, no escalation needed
+    None,
+    /// `thermal_load` is positive but under budget: force the fan harder
+    /// than the curve/safety override alone would
+    ForceMaxFan,
+    /// `thermal_load` has reached budget: give up on undervolt performance
+    /// and drop every core back to stock
+    ClampUndervoltToZero,
+    /// `thermal_load` has reached twice budget: full fan and zero undervolt
+    /// haven't brought it down, ask the daemon to shut down
+    RequestShutdown,
+}
+
+/// Integrates sustained over-`high_temp` readings into a `thermal_load` and
+/// reports the escalating [`ThermalAction`] to take
+///
+/// Pure and deterministic - like `simulate_failure_sequence`, `tick` takes
+/// no wall-clock dependency, so a test can drive it with an exact sequence
+/// of readings and assert on the exact load and action at each step.
+#[derive(Debug, Clone)]
+pub struct ThermalLoadTracker {
+    config: ThermalEscalationConfig,
+    thermal_load: f32,
+}
+
+impl ThermalLoadTracker {
+    /// Create a tracker with the given config, starting at zero load
+    pub fn new(config: ThermalEscalationConfig) -> Self {
+        ThermalLoadTracker {
+            config,
+            thermal_load: 0.0,
+        }
+    }
+
+    /// Current accumulated thermal load
+    pub fn thermal_load(&self) -> f32 {
+        self.thermal_load
+    }
+
+    /// Feed one tick's temperature reading and return the resulting action
+    ///
+    /// Accumulates `temp_c - limits.high_temp` while above `high_temp`;
+    /// decays toward zero (never below) otherwise.
+    pub fn tick(&mut self, temp_c: i32, limits: &FanSafetyLimits) -> ThermalAction {
+        if temp_c > limits.high_temp {
+            self.thermal_load += (temp_c - limits.high_temp) as f32;
+        } else {
+            self.thermal_load = (self.thermal_load - self.config.decay_per_tick).max(0.0);
+        }
+        self.action()
+    }
+
+    /// The action implied by the current `thermal_load`, without feeding a
+    /// new reading
+    fn action(&self) -> ThermalAction {
+        if self.thermal_load >= self.config.budget * 2.0 {
+            ThermalAction::RequestShutdown
+        } else if self.thermal_load >= self.config.budget {
+            ThermalAction::ClampUndervoltToZero
+        } else if self.thermal_load > 0.0 {
+            ThermalAction::ForceMaxFan
+        } else {
+            ThermalAction::None
+        }
+    }
+
+    /// Clear the accumulated load, e.g. after a manual reset or restart
+    pub fn reset(&mut self) {
+        self.thermal_load = 0.0;
+    }
+}
+
+/// Carry out `action` against live hardware: drop undervolt to stock for
+/// `ClampUndervoltToZero`, and additionally request a daemon shutdown for
+/// `RequestShutdown`. A no-op for `None`/`ForceMaxFan`, since those are
+/// handled entirely by the fan's own safety-override path.
+pub async fn execute_thermal_action(
+    action: ThermalAction,
+    executor: &mut RyzenadjExecutor,
+    num_cores: usize,
+    signal_state: &SignalState,
+) -> Result<(), RyzenadjError> {
+    match action {
+        ThermalAction::RequestShutdown => {
+            executor.reset_to_zero(num_cores).await?;
+            signal_state.request_shutdown();
+            Ok(())
+        }
+        ThermalAction::ClampUndervoltToZero => executor.reset_to_zero(num_cores).await,
+        ThermalAction::ForceMaxFan | ThermalAction::None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits() -> FanSafetyLimits {
+        FanSafetyLimits::default()
+    }
+
+    #[test]
+    fn test_tracker_starts_at_no_action() {
+        let tracker = ThermalLoadTracker::new(ThermalEscalationConfig::default());
+        assert_eq!(tracker.thermal_load(), 0.0);
+    }
+
+    #[test]
+    fn test_tick_below_high_temp_stays_at_zero() {
+        let mut tracker = ThermalLoadTracker::new(ThermalEscalationConfig::default());
+        assert_eq!(tracker.tick(70, &limits()), ThermalAction::None);
+        assert_eq!(tracker.thermal_load(), 0.0);
+    }
+
+    #[test]
+    fn test_tick_above_high_temp_accumulates_and_forces_fan() {
+        let mut tracker = ThermalLoadTracker::new(ThermalEscalationConfig::default());
+        // high_temp is 85 by default; 90 over is 5 over per tick.
+        assert_eq!(tracker.tick(90, &limits()), ThermalAction::ForceMaxFan);
+        assert_eq!(tracker.thermal_load(), 5.0);
+        assert_eq!(tracker.tick(90, &limits()), ThermalAction::ForceMaxFan);
+        assert_eq!(tracker.thermal_load(), 10.0);
+    }
+
+    #[test]
+    fn test_decay_below_high_temp_never_goes_negative() {
+        let config = ThermalEscalationConfig {
+            decay_per_tick: 100.0,
+            ..ThermalEscalationConfig::default()
+        };
+        let mut tracker = ThermalLoadTracker::new(config);
+        tracker.tick(90, &limits());
+        assert_eq!(tracker.tick(70, &limits()), ThermalAction::None);
+        assert_eq!(tracker.thermal_load(), 0.0);
+    }
+
+    #[test]
+    fn test_reaching_budget_clamps_undervolt() {
+        let config = ThermalEscalationConfig {
+            budget: 20.0,
+            ..ThermalEscalationConfig::default()
+        };
+        let mut tracker = ThermalLoadTracker::new(config);
+        // high_temp=85, reading 95 => +10/tick
+        tracker.tick(95, &limits());
+        assert_eq!(tracker.tick(95, &limits()), ThermalAction::ClampUndervoltToZero);
+        assert_eq!(tracker.thermal_load(), 20.0);
+    }
+
+    #[test]
+    fn test_reaching_double_budget_requests_shutdown() {
+        let config = ThermalEscalationConfig {
+            budget: 20.0,
+            ..ThermalEscalationConfig::default()
+        };
+        let mut tracker = ThermalLoadTracker::new(config);
+        for _ in 0..4 {
+            tracker.tick(95, &limits());
+        }
+        assert_eq!(tracker.thermal_load(), 40.0);
+        assert_eq!(tracker.action(), ThermalAction::RequestShutdown);
+    }
+
+    #[test]
+    fn test_reset_clears_load() {
+        let mut tracker = ThermalLoadTracker::new(ThermalEscalationConfig::default());
+        tracker.tick(95, &limits());
+        tracker.reset();
+        assert_eq!(tracker.thermal_load(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_execute_clamp_resets_to_zero() {
+        let mut executor = RyzenadjExecutor::new("true");
+        let signal_state = SignalState::new();
+        let result = execute_thermal_action(
+            ThermalAction::ClampUndervoltToZero,
+            &mut executor,
+            4,
+            &signal_state,
+        )
+        .await;
+        assert!(result.is_ok());
+        assert!(!signal_state.is_shutdown_requested());
+    }
+
+    #[tokio::test]
+    async fn test_execute_shutdown_resets_and_requests_shutdown() {
+        let mut executor = RyzenadjExecutor::new("true");
+        let signal_state = SignalState::new();
+        let result =
+            execute_thermal_action(ThermalAction::RequestShutdown, &mut executor, 4, &signal_state)
+                .await;
+        assert!(result.is_ok());
+        assert!(signal_state.is_shutdown_requested());
+    }
+
+    #[tokio::test]
+    async fn test_execute_none_and_force_max_fan_are_noops() {
+        let mut executor = RyzenadjExecutor::new("/nonexistent/ryzenadj");
+        let signal_state = SignalState::new();
+
+        assert!(execute_thermal_action(ThermalAction::None, &mut executor, 4, &signal_state)
+            .await
+            .is_ok());
+        assert!(execute_thermal_action(ThermalAction::ForceMaxFan, &mut executor, 4, &signal_state)
+            .await
+            .is_ok());
+        assert!(!signal_state.is_shutdown_requested());
+    }
+}
@@ -3,15 +3,67 @@
 //! Monitors the main loop and resets undervolt values if it stalls for too long.
 //! This ensures safety even if the daemon hangs or deadlocks.
 //!
+//! A stall no longer ends the process outright. Escalation runs in stages,
+//! each reported as a [`WatchdogEvent`] on a broadcast channel so subscribers
+//! (IPC layer, logging) can observe it: first a `SelfRecover` grace period
+//! (`SELF_RECOVER_GRACE_MS`) in case the stall clears on its own, then a
+//! `Reset` of all cores to a safe zero undervolt via `RyzenadjExecutor`,
+//! bounded by `RESET_ATTEMPT_TIMEOUT_MS` so a wedged `ryzenadj` call can't
+//! neuter the watchdog itself, and only after `MAX_CONSECUTIVE_RECOVERIES`
+//! reset attempts in a row fail does
+//! it `Escalate` and run the configured [`RecoveryAction`] (by default,
+//! `std::process::exit(5)`). This turns a transient stall into a self-healing
+//! event while preserving the fail-safe for a genuinely broken daemon.
+//!
+//! Beyond the single global heartbeat, callers can register named
+//! watchpoints for individual operations (sensor reads, `ryzenadj` writes,
+//! DBus/IPC calls, config reloads) via `Watchdog::watch`, each with its own
+//! deadline. The monitor task reports which watchpoint went overdue instead
+//! of only knowing the loop as a whole went quiet.
+//!
+//! `Watchdog::with_hardware` additionally arms the Linux kernel's own
+//! `/dev/watchdog` device, petted on every `WatchdogState::heartbeat()`, as
+//! a fail-safe for a total process lockup that the software watchdog's
+//! tokio task never gets a chance to detect. It degrades to software-only
+//! if the device is absent or not writable.
+//!
 //! Requirements: 9.4
 
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::marker::PhantomData;
+#[cfg(unix)]
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::thread::ThreadId;
 use std::time::{Duration, Instant};
-use tokio::sync::watch;
+use tokio::sync::{broadcast, watch};
+
+use crate::ryzenadj::{RyzenadjError, RyzenadjExecutor};
+
+/// Default watchdog timeout in milliseconds
+pub const DEFAULT_WATCHDOG_TIMEOUT_MS: u64 = 10_000;
+
+/// Number of consecutive failed recovery attempts tolerated before the
+/// watchdog gives up and runs its final `RecoveryAction`
+pub const MAX_CONSECUTIVE_RECOVERIES: u32 = 3;
+
+/// Grace period given to the main loop to self-recover (stage 1) before the
+/// watchdog escalates to a reset (stage 2)
+pub const SELF_RECOVER_GRACE_MS: u64 = 2_000;
+
+/// Bound on how long the watchdog's own reset attempt (stage 2) waits for
+/// `ryzenadj` before killing it and counting the attempt as failed
+///
+/// Keeps a wedged `ryzenadj` call from blocking the watchdog task itself,
+/// which would defeat the point of the watchdog.
+pub const RESET_ATTEMPT_TIMEOUT_MS: u64 = 2_000;
 
-/// Default watchdog timeout in seconds
-pub const DEFAULT_WATCHDOG_TIMEOUT_SECS: u64 = 10;
+/// Channel capacity for the `WatchdogEvent` broadcast stream
+const EVENT_CHANNEL_CAPACITY: usize = 16;
 
 /// Watchdog state shared between the main loop and watchdog task
 #[derive(Debug, Clone)]
@@ -20,6 +72,9 @@ pub struct WatchdogState {
     last_heartbeat_ms: Arc<AtomicU64>,
     /// Start time for calculating elapsed time
     start_time: Instant,
+    /// Kernel hardware watchdog to pet alongside every heartbeat, if one
+    /// was armed via `Watchdog::with_hardware`
+    hw_watchdog: Option<Arc<HardwareWatchdog>>,
 }
 
 impl Default for WatchdogState {
@@ -34,16 +89,22 @@ impl WatchdogState {
         Self {
             last_heartbeat_ms: Arc::new(AtomicU64::new(0)),
             start_time: Instant::now(),
+            hw_watchdog: None,
         }
     }
 
     /// Record a heartbeat from the main loop
     ///
     /// This should be called regularly from the main loop to indicate
-    /// that the daemon is still functioning properly.
+    /// that the daemon is still functioning properly. If a hardware
+    /// watchdog is armed, this also pets it, so a total daemon lockup
+    /// (not just a stalled tokio task) still results in a kernel reset.
     pub fn heartbeat(&self) {
         let elapsed_ms = self.start_time.elapsed().as_millis() as u64;
         self.last_heartbeat_ms.store(elapsed_ms, Ordering::SeqCst);
+        if let Some(hw) = &self.hw_watchdog {
+            hw.pet();
+        }
     }
 
     /// Get the time since the last heartbeat in milliseconds
@@ -65,13 +126,324 @@ impl WatchdogState {
     }
 }
 
-/// Watchdog controller that monitors the main loop and triggers reset on stall
+/// Identifies a single in-flight watchpoint: which thread opened it and the
+/// caller-supplied name (e.g. `"sensor-read"`, `"ryzenadj-write"`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct WatchIndex {
+    thread_id: ThreadId,
+    id: &'static str,
+}
+
+/// Start time and allotted duration for a registered watchpoint
+#[derive(Debug, Clone, Copy)]
+struct WatchRecord {
+    started: Instant,
+    deadline: Duration,
+}
+
+impl WatchRecord {
+    fn is_overdue(&self) -> bool {
+        self.started.elapsed() > self.deadline
+    }
+}
+
+type WatchpointMap = Arc<Mutex<HashMap<WatchIndex, WatchRecord>>>;
+
+/// Key distinguishing the global heartbeat deadline from an individual
+/// watchpoint's deadline within a [`TimeoutManager`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TimeoutKey {
+    Heartbeat,
+    Watchpoint(WatchIndex),
+}
+
+/// A deadline-sorted registry of `(key, wake_instant)` pairs
+///
+/// Entries are kept sorted by `wake_instant` so the earliest upcoming
+/// deadline is always at the front, letting [`TimeoutManager::next`] find it
+/// in O(1) instead of scanning every registered deadline. This lets a
+/// monitor loop sleep exactly until the next thing is due rather than
+/// polling on a fixed interval.
+struct TimeoutManager<K> {
+    entries: Vec<(K, Instant)>,
+}
+
+impl<K: PartialEq> TimeoutManager<K> {
+    fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Register (or re-register, moving the deadline) a key's wake instant
+    fn register(&mut self, key: K, wake_instant: Instant) {
+        self.unregister(&key);
+        let pos = self.entries.partition_point(|(_, t)| *t <= wake_instant);
+        self.entries.insert(pos, (key, wake_instant));
+    }
+
+    /// Remove a single key's entry, if present
+    fn unregister(&mut self, key: &K) {
+        self.entries.retain(|(k, _)| k != key);
+    }
+
+    /// Keep only entries whose key satisfies the predicate
+    fn retain_by_key(&mut self, mut predicate: impl FnMut(&K) -> bool) {
+        self.entries.retain(|(k, _)| predicate(k));
+    }
+
+    /// Duration to sleep until the nearest deadline, or `None` if nothing is
+    /// registered. Returns `Duration::ZERO` if the nearest deadline has
+    /// already passed, so the caller wakes (and re-evaluates) immediately.
+    fn next(&self, now: Instant) -> Option<Duration> {
+        self.entries
+            .first()
+            .map(|(_, wake_instant)| wake_instant.saturating_duration_since(now))
+    }
+}
+
+/// RAII guard for a single in-flight watchpoint
+///
+/// Returned by [`Watchdog::watch`]; dropping it (on success, error, or
+/// unwind) removes its record from the registry so the monitor task stops
+/// tracking it. Not `Send`, since a watchpoint is scoped to the thread that
+/// opened it and `WatchIndex` keys records by that thread's id.
+pub struct WatchPoint {
+    watchdog: Arc<Watchdog>,
+    index: WatchIndex,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl Drop for WatchPoint {
+    fn drop(&mut self) {
+        if let Ok(mut points) = self.watchdog.watchpoints.lock() {
+            points.remove(&self.index);
+        }
+    }
+}
+
+/// `WDIOC_SETTIMEOUT` ioctl request number from `<linux/watchdog.h>`
+#[cfg(unix)]
+const WDIOC_SETTIMEOUT: libc::c_ulong = 0xc004_5706;
+
+/// Magic byte that, written just before closing the device, tells the
+/// kernel driver to disarm cleanly instead of firing on the next missed pet
+const WATCHDOG_MAGIC_CLOSE: u8 = b'V';
+
+/// Errors from the hardware watchdog backend
+#[derive(Debug)]
+pub enum HardwareWatchdogError {
+    /// Couldn't open the device node
+    Open(std::io::Error),
+    /// The device was opened but rejected `WDIOC_SETTIMEOUT`
+    SetTimeout(std::io::Error),
+}
+
+impl std::fmt::Display for HardwareWatchdogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HardwareWatchdogError::Open(e) => write!(f, "Failed to open watchdog device: {}", e),
+            HardwareWatchdogError::SetTimeout(e) => {
+                write!(f, "Failed to set watchdog timeout: {}", e)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HardwareWatchdogError {}
+
+/// Handle to the Linux kernel hardware watchdog device (e.g. `/dev/watchdog`)
+///
+/// The kernel device is itself a countdown timer: once armed, it hard-resets
+/// the machine unless petted within `kernel_timeout_secs`. This gives a
+/// fail-safe that works even when the whole process is wedged (kernel-
+/// blocked I/O, OOM, scheduler starvation) and the software watchdog's own
+/// tokio task never gets to run.
+#[derive(Debug)]
+pub struct HardwareWatchdog {
+    file: Mutex<std::fs::File>,
+}
+
+impl HardwareWatchdog {
+    /// Open `path` and arm it with `kernel_timeout_secs`
+    #[cfg(unix)]
+    fn open(path: &Path, kernel_timeout_secs: u32) -> Result<Self, HardwareWatchdogError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .open(path)
+            .map_err(HardwareWatchdogError::Open)?;
+
+        let mut timeout_secs = kernel_timeout_secs as libc::c_int;
+        // SAFETY: `file` owns a valid fd for the lifetime of this call, and
+        // WDIOC_SETTIMEOUT expects a pointer to a single `int` in/out
+        // parameter, which `timeout_secs` provides.
+        let ret = unsafe {
+            libc::ioctl(
+                file.as_raw_fd(),
+                WDIOC_SETTIMEOUT,
+                &mut timeout_secs as *mut libc::c_int,
+            )
+        };
+        if ret != 0 {
+            return Err(HardwareWatchdogError::SetTimeout(
+                std::io::Error::last_os_error(),
+            ));
+        }
+
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+
+    /// The hardware watchdog ioctl interface is Linux/Unix-specific; on
+    /// other platforms always report it as unavailable
+    #[cfg(not(unix))]
+    fn open(_path: &Path, _kernel_timeout_secs: u32) -> Result<Self, HardwareWatchdogError> {
+        Err(HardwareWatchdogError::Open(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "hardware watchdog is only supported on unix",
+        )))
+    }
+
+    /// Pet the device, resetting its countdown
+    ///
+    /// Best-effort: a failed write here just means the kernel's own
+    /// `kernel_timeout_secs` countdown keeps running unpetted, not that the
+    /// daemon should panic over a watchdog write failing.
+    fn pet(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.write_all(&[0]);
+        }
+    }
+}
+
+impl Drop for HardwareWatchdog {
+    fn drop(&mut self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.write_all(&[WATCHDOG_MAGIC_CLOSE]);
+        }
+    }
+}
+
+/// Escalation stage reached on a stall, carried in a [`WatchdogEvent`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoveryStage {
+    /// A deadline was first missed; the main loop gets a grace period
+    /// (`SELF_RECOVER_GRACE_MS`) to recover on its own before anything else
+    /// happens
+    SelfRecover,
+    /// The grace period expired without a heartbeat: all cores are reset to
+    /// a safe zero undervolt
+    Reset,
+    /// Resets kept failing for `MAX_CONSECUTIVE_RECOVERIES` attempts in a
+    /// row: the configured `RecoveryAction` runs
+    Escalate,
+}
+
+/// Outcome of a single bounded `ryzenadj` reset attempt (stage 2), carried
+/// alongside a [`WatchdogEvent`] once the attempt completes
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResetOutcome {
+    /// `ryzenadj` ran and exited successfully
+    Success,
+    /// `ryzenadj` didn't exit within `RESET_ATTEMPT_TIMEOUT_MS` and was
+    /// killed
+    RyzenadjTimeout,
+    /// The subprocess could not be spawned, or exited with an error
+    SpawnError(String),
+}
+
+/// Broadcast event emitted at each stage of watchdog escalation
+///
+/// Subscribe via [`Watchdog::subscribe_events`]; a send with no subscribers
+/// is a no-op, same as `OutputWriter`'s broadcast.
+#[derive(Debug, Clone)]
+pub struct WatchdogEvent {
+    /// The watchpoint that was overdue, or `None` if it was the global
+    /// heartbeat itself
+    pub which_watchpoint: Option<&'static str>,
+    /// How long the stalled deadline has been overdue
+    pub elapsed: Duration,
+    /// Which escalation stage this event represents
+    pub stage: RecoveryStage,
+    /// Result of the reset attempt, once it completes; `None` for the
+    /// initial announcement of a stage (including the `Reset` stage's own
+    /// "about to attempt" event, before the attempt has run)
+    pub outcome: Option<ResetOutcome>,
+}
+
+/// The action taken once escalation exhausts `MAX_CONSECUTIVE_RECOVERIES`
+/// reset attempts
+///
+/// Lets embedders choose between exiting (the historical hardcoded
+/// behavior) and resetting-and-continuing, or plug in their own callback.
+pub enum RecoveryAction {
+    /// Stay alive in a degraded reset-and-continue state rather than exiting
+    Reset,
+    /// Exit the process with the given code
+    Exit(i32),
+    /// Run an arbitrary callback instead
+    Custom(Box<dyn Fn() + Send + Sync>),
+}
+
+impl std::fmt::Debug for RecoveryAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecoveryAction::Reset => write!(f, "RecoveryAction::Reset"),
+            RecoveryAction::Exit(code) => write!(f, "RecoveryAction::Exit({})", code),
+            RecoveryAction::Custom(_) => write!(f, "RecoveryAction::Custom(..)"),
+        }
+    }
+}
+
+impl Default for RecoveryAction {
+    /// Matches the watchdog's historical behavior: exit with code 5
+    fn default() -> Self {
+        RecoveryAction::Exit(5)
+    }
+}
+
+impl RecoveryAction {
+    fn run(&self) {
+        match self {
+            RecoveryAction::Reset => {
+                eprintln!(
+                    "WATCHDOG: exhausted recovery attempts, continuing in degraded reset-and-continue mode"
+                );
+            }
+            RecoveryAction::Exit(code) => {
+                std::process::exit(*code);
+            }
+            RecoveryAction::Custom(f) => {
+                f();
+            }
+        }
+    }
+}
+
+/// Watchdog controller that monitors the main loop and recovers it on stall
 pub struct Watchdog {
     state: WatchdogState,
-    timeout_secs: u64,
+    timeout_ms: u64,
     num_cores: usize,
     ryzenadj_path: String,
-    /// Channel to signal watchdog timeout
+    /// Per-operation deadlines registered via [`Watchdog::watch`], keyed by
+    /// thread + caller-supplied id so the monitor can name the culprit
+    /// instead of only knowing "the loop" stalled
+    watchpoints: WatchpointMap,
+    /// Kernel hardware watchdog armed via [`Watchdog::with_hardware`], kept
+    /// alive here so it disarms (magic close byte) when the watchdog is
+    /// dropped, even if no one is holding a cloned `WatchdogState` anymore
+    hw_watchdog: Option<Arc<HardwareWatchdog>>,
+    /// Final action run once escalation exhausts `MAX_CONSECUTIVE_RECOVERIES`
+    /// reset attempts; defaults to `RecoveryAction::Exit(5)`
+    ///
+    /// Wrapped in an `Arc` so `start` can clone it into the spawned task
+    /// without requiring `RecoveryAction` (which holds a `Box<dyn Fn>` in
+    /// its `Custom` variant) to implement `Clone`.
+    recovery_action: Arc<RecoveryAction>,
+    /// Broadcast stream of escalation events, for subscribers like the IPC
+    /// layer or logger
+    event_tx: broadcast::Sender<WatchdogEvent>,
+    /// Channel to signal that the watchdog gave up and is about to exit
     timeout_tx: watch::Sender<bool>,
     timeout_rx: watch::Receiver<bool>,
 }
@@ -80,108 +452,296 @@ impl Watchdog {
     /// Create a new watchdog with the specified configuration
     ///
     /// # Arguments
-    /// * `timeout_secs` - Timeout in seconds before triggering reset
+    /// * `timeout_ms` - Timeout in milliseconds before attempting a recovery
     /// * `num_cores` - Number of CPU cores to reset
     /// * `ryzenadj_path` - Path to ryzenadj binary
-    pub fn new(timeout_secs: u64, num_cores: usize, ryzenadj_path: String) -> Self {
+    pub fn new(timeout_ms: u64, num_cores: usize, ryzenadj_path: String) -> Self {
         let (timeout_tx, timeout_rx) = watch::channel(false);
+        let (event_tx, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
         Self {
             state: WatchdogState::new(),
-            timeout_secs,
+            timeout_ms,
             num_cores,
             ryzenadj_path,
+            watchpoints: Arc::new(Mutex::new(HashMap::new())),
+            hw_watchdog: None,
+            recovery_action: Arc::new(RecoveryAction::default()),
+            event_tx,
             timeout_tx,
             timeout_rx,
         }
     }
 
+    /// Use a different final action once escalation exhausts
+    /// `MAX_CONSECUTIVE_RECOVERIES` reset attempts, instead of the default
+    /// `RecoveryAction::Exit(5)`
+    pub fn with_recovery_action(mut self, action: RecoveryAction) -> Self {
+        self.recovery_action = Arc::new(action);
+        self
+    }
+
+    /// Subscribe to the broadcast stream of escalation events
+    pub fn subscribe_events(&self) -> broadcast::Receiver<WatchdogEvent> {
+        self.event_tx.subscribe()
+    }
+
+    /// Create a watchdog additionally backed by the Linux kernel hardware
+    /// watchdog device at `hw_path` (typically `/dev/watchdog`)
+    ///
+    /// Arms the device for `kernel_timeout_secs`; every
+    /// `WatchdogState::heartbeat()` call pets it afterward, so even a total
+    /// daemon lockup (not just a stalled tokio task) still hard-resets the
+    /// machine back to stock voltages. Gracefully degrades to the
+    /// software-only watchdog, logging a warning, if the device doesn't
+    /// exist or isn't writable.
+    pub fn with_hardware(
+        timeout_ms: u64,
+        num_cores: usize,
+        ryzenadj_path: String,
+        hw_path: &Path,
+        kernel_timeout_secs: u32,
+    ) -> Self {
+        let mut watchdog = Self::new(timeout_ms, num_cores, ryzenadj_path);
+
+        match HardwareWatchdog::open(hw_path, kernel_timeout_secs) {
+            Ok(hw) => {
+                let hw = Arc::new(hw);
+                watchdog.state.hw_watchdog = Some(Arc::clone(&hw));
+                watchdog.hw_watchdog = Some(hw);
+            }
+            Err(e) => {
+                eprintln!(
+                    "WATCHDOG: hardware watchdog {} unavailable ({}), continuing software-only",
+                    hw_path.display(),
+                    e
+                );
+            }
+        }
+
+        watchdog
+    }
+
     /// Get a clone of the watchdog state for use in the main loop
     pub fn state(&self) -> WatchdogState {
         self.state.clone()
     }
 
-    /// Get a receiver for timeout notifications
+    /// Register a named watchpoint for a single in-flight operation
+    ///
+    /// The returned [`WatchPoint`] guard removes its record when dropped, so
+    /// wrap the operation's scope with it (e.g. `let _wp = watchdog.watch("ryzenadj-write", Duration::from_secs(2));`).
+    /// If the guard is still alive past `timeout` when the monitor task next
+    /// wakes, it logs `id` and the elapsed time alongside the usual
+    /// heartbeat stall before attempting recovery, so the cause of a stall
+    /// can be pinpointed instead of only knowing the main loop went quiet.
+    pub fn watch(self: &Arc<Self>, id: &'static str, timeout: Duration) -> WatchPoint {
+        let index = WatchIndex {
+            thread_id: std::thread::current().id(),
+            id,
+        };
+        let record = WatchRecord {
+            started: Instant::now(),
+            deadline: timeout,
+        };
+        if let Ok(mut points) = self.watchpoints.lock() {
+            points.insert(index, record);
+        }
+        WatchPoint {
+            watchdog: Arc::clone(self),
+            index,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Get a receiver that fires once the watchdog has exhausted its
+    /// recovery attempts and is about to exit
     pub fn timeout_receiver(&self) -> watch::Receiver<bool> {
         self.timeout_rx.clone()
     }
 
-    /// Get the timeout in seconds
-    pub fn timeout_secs(&self) -> u64 {
-        self.timeout_secs
+    /// Get the timeout in milliseconds
+    pub fn timeout_ms(&self) -> u64 {
+        self.timeout_ms
     }
 
     /// Start the watchdog monitoring task
     ///
-    /// This spawns a background task that periodically checks for heartbeats.
-    /// If no heartbeat is received within the timeout period, it resets
-    /// undervolt values and signals a timeout.
+    /// Spawns a background task that sleeps exactly until the nearest
+    /// deadline (the heartbeat, or any registered watchpoint) is due,
+    /// tracked via an internal [`TimeoutManager`], rather than polling on a
+    /// fixed interval. On a stall it attempts a recovery reset and re-arms
+    /// by bumping the heartbeat itself; `MAX_CONSECUTIVE_RECOVERIES`
+    /// recovery attempts that fail to complete in a row escalate to the
+    /// configured [`RecoveryAction`].
     ///
     /// # Arguments
     /// * `verbose` - Whether to log verbose output
     pub async fn start(&self, verbose: bool) {
         let state = self.state.clone();
-        let timeout_ms = self.timeout_secs * 1000;
+        let timeout_ms = self.timeout_ms;
         let num_cores = self.num_cores;
         let ryzenadj_path = self.ryzenadj_path.clone();
+        let watchpoints = Arc::clone(&self.watchpoints);
         let timeout_tx = self.timeout_tx.clone();
+        let event_tx = self.event_tx.clone();
+        let recovery_action = Arc::clone(&self.recovery_action);
 
         // Initial heartbeat
         state.heartbeat();
 
         tokio::spawn(async move {
-            let check_interval = Duration::from_millis(1000); // Check every second
+            // Fallback sleep when nothing is registered yet (shouldn't
+            // normally happen, since the heartbeat deadline is re-registered
+            // every iteration, but keeps the loop from blocking forever).
+            let fallback_interval = Duration::from_millis(1000);
+            let mut consecutive_failed_recoveries: u32 = 0;
+            let mut timeouts: TimeoutManager<TimeoutKey> = TimeoutManager::new();
 
             loop {
-                tokio::time::sleep(check_interval).await;
+                let now = Instant::now();
+                let heartbeat_remaining_ms =
+                    timeout_ms.saturating_sub(state.time_since_heartbeat_ms());
+                timeouts.register(
+                    TimeoutKey::Heartbeat,
+                    now + Duration::from_millis(heartbeat_remaining_ms),
+                );
+
+                if let Ok(points) = watchpoints.lock() {
+                    timeouts.retain_by_key(|key| match key {
+                        TimeoutKey::Heartbeat => true,
+                        TimeoutKey::Watchpoint(index) => points.contains_key(index),
+                    });
+                    for (index, record) in points.iter() {
+                        timeouts.register(TimeoutKey::Watchpoint(*index), record.started + record.deadline);
+                    }
+                }
+
+                let sleep_for = timeouts.next(Instant::now()).unwrap_or(fallback_interval);
+                tokio::time::sleep(sleep_for).await;
+
+                let overdue = |watchpoints: &WatchpointMap| -> Vec<(WatchIndex, Duration)> {
+                    watchpoints
+                        .lock()
+                        .map(|points| {
+                            points
+                                .iter()
+                                .filter(|(_, record)| record.is_overdue())
+                                .map(|(index, record)| (*index, record.started.elapsed()))
+                                .collect()
+                        })
+                        .unwrap_or_default()
+                };
+
+                let overdue_points = overdue(&watchpoints);
 
-                if state.is_timed_out(timeout_ms) {
+                for (index, elapsed) in &overdue_points {
                     eprintln!(
-                        "WATCHDOG: Main loop stalled for >{}s, resetting values...",
-                        timeout_ms / 1000
+                        "WATCHDOG: watchpoint '{}' (thread {:?}) overdue, elapsed {:?}",
+                        index.id, index.thread_id, elapsed
                     );
+                }
 
-                    // Reset values to safe state
-                    if let Err(e) = reset_values_sync(&ryzenadj_path, num_cores) {
-                        eprintln!("WATCHDOG: Failed to reset values: {}", e);
-                    } else if verbose {
-                        eprintln!("WATCHDOG: Values reset to 0");
-                    }
+                if overdue_points.is_empty() && !state.is_timed_out(timeout_ms) {
+                    continue;
+                }
+
+                let which_watchpoint = overdue_points.first().map(|(index, _)| index.id);
+                let elapsed = overdue_points
+                    .iter()
+                    .map(|(_, elapsed)| *elapsed)
+                    .max()
+                    .unwrap_or_else(|| Duration::from_millis(state.time_since_heartbeat_ms()));
+
+                eprintln!(
+                    "WATCHDOG: Main loop stalled for >{}ms, giving it {}ms to self-recover...",
+                    timeout_ms, SELF_RECOVER_GRACE_MS
+                );
+                let _ = event_tx.send(WatchdogEvent {
+                    which_watchpoint,
+                    elapsed,
+                    stage: RecoveryStage::SelfRecover,
+                    outcome: None,
+                });
+
+                tokio::time::sleep(Duration::from_millis(SELF_RECOVER_GRACE_MS)).await;
+
+                if overdue(&watchpoints).is_empty() && !state.is_timed_out(timeout_ms) {
+                    eprintln!("WATCHDOG: main loop self-recovered during the grace period");
+                    continue;
+                }
 
-                    // Signal timeout
-                    let _ = timeout_tx.send(true);
+                eprintln!("WATCHDOG: stall persisted past the grace period, attempting recovery...");
+                let _ = event_tx.send(WatchdogEvent {
+                    which_watchpoint,
+                    elapsed,
+                    stage: RecoveryStage::Reset,
+                    outcome: None,
+                });
 
-                    // Exit with watchdog timeout code
-                    std::process::exit(5);
+                let reset_result = attempt_recovery(&ryzenadj_path, num_cores).await;
+                let outcome = match &reset_result {
+                    Ok(()) => ResetOutcome::Success,
+                    Err(RyzenadjError::TimedOut(_)) => ResetOutcome::RyzenadjTimeout,
+                    Err(e) => ResetOutcome::SpawnError(e.to_string()),
+                };
+                let _ = event_tx.send(WatchdogEvent {
+                    which_watchpoint,
+                    elapsed,
+                    stage: RecoveryStage::Reset,
+                    outcome: Some(outcome),
+                });
+
+                match reset_result {
+                    Ok(()) => {
+                        consecutive_failed_recoveries = 0;
+                        // Re-arm: a stalled caller isn't bumping the
+                        // heartbeat itself, so the watchdog does it after a
+                        // successful recovery to avoid re-triggering on the
+                        // very next check.
+                        state.heartbeat();
+                        if verbose {
+                            eprintln!("WATCHDOG: Recovery succeeded, values reset to 0");
+                        }
+                    }
+                    Err(e) => {
+                        consecutive_failed_recoveries += 1;
+                        eprintln!(
+                            "WATCHDOG: Recovery attempt {}/{} failed: {}",
+                            consecutive_failed_recoveries, MAX_CONSECUTIVE_RECOVERIES, e
+                        );
+
+                        if consecutive_failed_recoveries >= MAX_CONSECUTIVE_RECOVERIES {
+                            eprintln!(
+                                "WATCHDOG: {} consecutive recovery failures, giving up",
+                                consecutive_failed_recoveries
+                            );
+                            let _ = event_tx.send(WatchdogEvent {
+                                which_watchpoint,
+                                elapsed,
+                                stage: RecoveryStage::Escalate,
+                                outcome: None,
+                            });
+                            let _ = timeout_tx.send(true);
+                            recovery_action.run();
+                        }
+                    }
                 }
             }
         });
     }
 }
 
-/// Reset all undervolt values to zero synchronously
+/// Attempt a recovery reset: zero out every core's undervolt via
+/// `RyzenadjExecutor`, the same safe state used on graceful shutdown
 ///
-/// This is used by the watchdog when it detects a stall.
-/// Uses blocking I/O since we may be in a situation where async is not working.
-fn reset_values_sync(ryzenadj_path: &str, num_cores: usize) -> Result<(), String> {
-    let args: Vec<String> = (0..num_cores)
-        .flat_map(|i| vec![format!("--set-coper-{}", i), "0".to_string()])
-        .collect();
-
-    let output = std::process::Command::new(ryzenadj_path)
-        .args(&args)
-        .output()
-        .map_err(|e| format!("Failed to execute ryzenadj: {}", e))?;
-
-    if output.status.success() {
-        Ok(())
-    } else {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        Err(format!(
-            "ryzenadj exited with code {:?}: {}",
-            output.status.code(),
-            stderr
-        ))
-    }
+/// Bounded by `RESET_ATTEMPT_TIMEOUT_MS`: a `ryzenadj` call that doesn't
+/// exit in time is killed and reported as [`RyzenadjError::TimedOut`]
+/// rather than blocking the watchdog task itself.
+async fn attempt_recovery(ryzenadj_path: &str, num_cores: usize) -> Result<(), RyzenadjError> {
+    let mut executor = RyzenadjExecutor::new(ryzenadj_path);
+    executor
+        .reset_to_zero_bounded(num_cores, Duration::from_millis(RESET_ATTEMPT_TIMEOUT_MS))
+        .await
 }
 
 /// Check if the watchdog timeout has been exceeded
@@ -199,6 +759,162 @@ pub fn check_timeout(last_heartbeat_ms: u64, current_time_ms: u64, timeout_ms: u
     current_time_ms.saturating_sub(last_heartbeat_ms) > timeout_ms
 }
 
+/// Commanded PWM (0-100%) at/above which a tick counts as "fan at or near
+/// max" for [`ThermalRunawayMonitor`] purposes
+pub const THERMAL_RUNAWAY_FAN_PWM_THRESHOLD: u8 = 95;
+
+/// Default width of the rolling window the fan must be held at/near max
+/// before a lack of cooling is declared a runaway, in milliseconds
+pub const DEFAULT_THERMAL_RUNAWAY_WINDOW_MS: u64 = 20_000;
+
+/// Default hysteresis margin: the temperature must drop by at least this
+/// many degrees Celsius across the window to count as "responding to the
+/// fan", matching Marlin's `THERMAL_PROTECTION_HYSTERESIS` idea
+pub const DEFAULT_THERMAL_RUNAWAY_HYSTERESIS_C: f32 = 4.0;
+
+/// Below this much spread (max - min) across a full window, repeated
+/// readings are treated as a stuck sensor rather than a real plateau
+pub const THERMAL_SENSOR_FAULT_MIN_SPREAD_C: f32 = 0.05;
+
+/// Sensor readings outside this range are implausible for a Steam Deck APU
+/// and are treated as a dead/faulted hwmon sensor rather than real silicon
+/// temperature
+pub const THERMAL_SENSOR_FAULT_MIN_PLAUSIBLE_C: f32 = -20.0;
+pub const THERMAL_SENSOR_FAULT_MAX_PLAUSIBLE_C: f32 = 125.0;
+
+/// One (timestamp, temperature) observation in a [`ThermalRunawayMonitor`]'s
+/// rolling window
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ThermalSample {
+    at_ms: u64,
+    temp_c: f32,
+}
+
+/// Outcome of [`ThermalRunawayMonitor::check`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThermalRunawayStatus {
+    /// No runaway: either not enough data yet, the fan isn't at max, or the
+    /// temperature is responding normally
+    Ok,
+    /// The fan has been at/near max for a full window and the temperature
+    /// hasn't dropped by the hysteresis margin (or kept rising)
+    Runaway,
+    /// Readings across the window are constant or outside a plausible
+    /// range, suggesting a stuck or dead hwmon sensor rather than a real
+    /// cooling failure
+    SensorFault,
+}
+
+/// Borrows Marlin's thermal-runaway protection idea: tracks a rolling
+/// window of `(timestamp, temperature)` samples taken while the fan is
+/// commanded at/near max, and flags a runaway once a full window has
+/// passed without the temperature dropping by a hysteresis margin. Also
+/// flags the inverse case of a sensor that isn't moving (or reads outside
+/// a plausible range) at all.
+///
+/// Driven directly from the main loop's fan-update tick (see `main.rs`)
+/// rather than the watchdog's own background task, since it depends on
+/// readings (commanded PWM, die temperature) the watchdog doesn't otherwise
+/// see.
+pub struct ThermalRunawayMonitor {
+    window_ms: u64,
+    hysteresis_c: f32,
+    fan_pwm_threshold: u8,
+    samples: std::collections::VecDeque<ThermalSample>,
+}
+
+impl Default for ThermalRunawayMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThermalRunawayMonitor {
+    /// Create a monitor using the default window, hysteresis margin, and
+    /// fan-at-max threshold
+    pub fn new() -> Self {
+        Self::with_params(
+            DEFAULT_THERMAL_RUNAWAY_WINDOW_MS,
+            DEFAULT_THERMAL_RUNAWAY_HYSTERESIS_C,
+            THERMAL_RUNAWAY_FAN_PWM_THRESHOLD,
+        )
+    }
+
+    /// Create a monitor with explicit parameters
+    pub fn with_params(window_ms: u64, hysteresis_c: f32, fan_pwm_threshold: u8) -> Self {
+        Self {
+            window_ms,
+            hysteresis_c,
+            fan_pwm_threshold,
+            samples: std::collections::VecDeque::new(),
+        }
+    }
+
+    /// Record one `(timestamp, temperature)` sample for a tick commanded at
+    /// `commanded_pwm`
+    ///
+    /// Samples taken while the fan has headroom left (below
+    /// `fan_pwm_threshold`) don't belong in an "is cooling working" window,
+    /// so they clear it instead of being recorded: the window should only
+    /// ever measure a commanded-at-max stretch from its start.
+    pub fn record(&mut self, at_ms: u64, temp_c: f32, commanded_pwm: u8) {
+        if commanded_pwm < self.fan_pwm_threshold {
+            self.samples.clear();
+            return;
+        }
+        self.samples.push_back(ThermalSample { at_ms, temp_c });
+        while let Some(front) = self.samples.front() {
+            if at_ms.saturating_sub(front.at_ms) > self.window_ms {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Classify the current window
+    ///
+    /// Returns `Ok` until the fan has been held at/near max for a full
+    /// `window_ms`; once it has, returns `SensorFault` if the readings are
+    /// constant or implausible, `Runaway` if the temperature hasn't dropped
+    /// by `hysteresis_c`, or `Ok` if it has.
+    pub fn check(&self) -> ThermalRunawayStatus {
+        let (Some(first), Some(last)) = (self.samples.front(), self.samples.back()) else {
+            return ThermalRunawayStatus::Ok;
+        };
+        if last.at_ms.saturating_sub(first.at_ms) < self.window_ms {
+            return ThermalRunawayStatus::Ok;
+        }
+
+        let min_temp = self
+            .samples
+            .iter()
+            .fold(f32::INFINITY, |acc, s| acc.min(s.temp_c));
+        let max_temp = self
+            .samples
+            .iter()
+            .fold(f32::NEG_INFINITY, |acc, s| acc.max(s.temp_c));
+
+        let implausible = self.samples.iter().any(|s| {
+            s.temp_c < THERMAL_SENSOR_FAULT_MIN_PLAUSIBLE_C
+                || s.temp_c > THERMAL_SENSOR_FAULT_MAX_PLAUSIBLE_C
+        });
+        if implausible || (max_temp - min_temp) < THERMAL_SENSOR_FAULT_MIN_SPREAD_C {
+            return ThermalRunawayStatus::SensorFault;
+        }
+
+        if first.temp_c - last.temp_c < self.hysteresis_c {
+            return ThermalRunawayStatus::Runaway;
+        }
+        ThermalRunawayStatus::Ok
+    }
+
+    /// Drop all recorded samples, e.g. after acting on a declared runaway
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,8 +976,31 @@ mod tests {
 
     #[test]
     fn test_watchdog_new() {
-        let watchdog = Watchdog::new(10, 4, "/usr/bin/ryzenadj".to_string());
-        assert_eq!(watchdog.timeout_secs(), 10);
+        let watchdog = Watchdog::new(10_000, 4, "/usr/bin/ryzenadj".to_string());
+        assert_eq!(watchdog.timeout_ms(), 10_000);
+    }
+
+    #[tokio::test]
+    async fn test_attempt_recovery_fails_for_missing_binary() {
+        let err = attempt_recovery("/nonexistent/ryzenadj", 4).await;
+        assert!(err.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_attempt_recovery_reports_ryzenadj_timeout_as_reset_outcome() {
+        // `yes` hangs forever and ignores its arguments, so this exercises
+        // the same kill-on-timeout path the watchdog's own recovery attempt
+        // takes against a wedged `ryzenadj`.
+        let mut executor = RyzenadjExecutor::new("yes");
+        let result = executor
+            .reset_to_zero_bounded(4, Duration::from_millis(50))
+            .await;
+        let outcome = match result {
+            Ok(()) => ResetOutcome::Success,
+            Err(RyzenadjError::TimedOut(_)) => ResetOutcome::RyzenadjTimeout,
+            Err(e) => ResetOutcome::SpawnError(e.to_string()),
+        };
+        assert_eq!(outcome, ResetOutcome::RyzenadjTimeout);
     }
 
     #[test]
@@ -282,4 +1021,231 @@ mod tests {
         let state = WatchdogState::default();
         assert!(state.time_since_heartbeat_ms() < 100);
     }
+
+    #[test]
+    fn test_watch_registers_and_removes_on_drop() {
+        let watchdog = Arc::new(Watchdog::new(10_000, 4, "/usr/bin/ryzenadj".to_string()));
+        {
+            let _wp = watchdog.watch("sensor-read", Duration::from_millis(50));
+            assert_eq!(watchdog.watchpoints.lock().unwrap().len(), 1);
+        }
+        assert_eq!(watchdog.watchpoints.lock().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_watchpoint_overdue_after_deadline() {
+        let watchdog = Arc::new(Watchdog::new(10_000, 4, "/usr/bin/ryzenadj".to_string()));
+        let _wp = watchdog.watch("ryzenadj-write", Duration::from_millis(20));
+        assert!(!watchdog
+            .watchpoints
+            .lock()
+            .unwrap()
+            .values()
+            .next()
+            .unwrap()
+            .is_overdue());
+
+        std::thread::sleep(Duration::from_millis(30));
+
+        assert!(watchdog
+            .watchpoints
+            .lock()
+            .unwrap()
+            .values()
+            .next()
+            .unwrap()
+            .is_overdue());
+    }
+
+    #[test]
+    fn test_timeout_manager_next_is_nearest_deadline() {
+        let now = Instant::now();
+        let mut manager: TimeoutManager<&str> = TimeoutManager::new();
+        assert_eq!(manager.next(now), None);
+
+        manager.register("a", now + Duration::from_millis(500));
+        manager.register("b", now + Duration::from_millis(100));
+        manager.register("c", now + Duration::from_millis(300));
+
+        let next = manager.next(now).unwrap();
+        assert!(next <= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_timeout_manager_unregister_removes_entry() {
+        let now = Instant::now();
+        let mut manager: TimeoutManager<&str> = TimeoutManager::new();
+        manager.register("a", now + Duration::from_millis(100));
+        manager.register("b", now + Duration::from_millis(300));
+
+        manager.unregister(&"a");
+
+        let next = manager.next(now).unwrap();
+        assert!(next > Duration::from_millis(290));
+    }
+
+    #[test]
+    fn test_timeout_manager_retain_by_key() {
+        let now = Instant::now();
+        let mut manager: TimeoutManager<&str> = TimeoutManager::new();
+        manager.register("a", now + Duration::from_millis(100));
+        manager.register("b", now + Duration::from_millis(300));
+
+        manager.retain_by_key(|k| *k != "a");
+
+        let next = manager.next(now).unwrap();
+        assert!(next > Duration::from_millis(290));
+    }
+
+    #[test]
+    fn test_timeout_manager_past_deadline_returns_zero() {
+        let now = Instant::now();
+        let mut manager: TimeoutManager<&str> = TimeoutManager::new();
+        manager.register("a", now - Duration::from_millis(10));
+
+        assert_eq!(manager.next(now), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_with_hardware_degrades_to_software_only_when_device_missing() {
+        let watchdog = Watchdog::with_hardware(
+            10_000,
+            4,
+            "/usr/bin/ryzenadj".to_string(),
+            Path::new("/nonexistent/watchdog"),
+            30,
+        );
+        assert_eq!(watchdog.timeout_ms(), 10_000);
+        assert!(watchdog.hw_watchdog.is_none());
+        assert!(watchdog.state.hw_watchdog.is_none());
+    }
+
+    #[test]
+    fn test_heartbeat_without_hardware_watchdog_does_not_panic() {
+        let state = WatchdogState::new();
+        state.heartbeat();
+        assert!(state.time_since_heartbeat_ms() < 100);
+    }
+
+    #[test]
+    fn test_recovery_action_default_is_exit_5() {
+        match RecoveryAction::default() {
+            RecoveryAction::Exit(code) => assert_eq!(code, 5),
+            other => panic!("expected RecoveryAction::Exit(5), got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_with_recovery_action_overrides_default() {
+        let watchdog = Watchdog::new(10_000, 4, "/usr/bin/ryzenadj".to_string())
+            .with_recovery_action(RecoveryAction::Reset);
+        match &*watchdog.recovery_action {
+            RecoveryAction::Reset => {}
+            other => panic!("expected RecoveryAction::Reset, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_subscribe_events_receives_broadcast_send() {
+        let watchdog = Watchdog::new(10_000, 4, "/usr/bin/ryzenadj".to_string());
+        let mut rx = watchdog.subscribe_events();
+        watchdog
+            .event_tx
+            .send(WatchdogEvent {
+                which_watchpoint: Some("sensor-read"),
+                elapsed: Duration::from_millis(123),
+                stage: RecoveryStage::SelfRecover,
+                outcome: None,
+            })
+            .unwrap();
+
+        let event = rx.try_recv().unwrap();
+        assert_eq!(event.which_watchpoint, Some("sensor-read"));
+        assert_eq!(event.stage, RecoveryStage::SelfRecover);
+    }
+
+    #[test]
+    fn test_multiple_watchpoints_tracked_independently() {
+        let watchdog = Arc::new(Watchdog::new(10_000, 4, "/usr/bin/ryzenadj".to_string()));
+        let _wp1 = watchdog.watch("config-reload", Duration::from_secs(5));
+        let _wp2 = watchdog.watch("dbus-call", Duration::from_secs(5));
+        assert_eq!(watchdog.watchpoints.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_thermal_runaway_ok_before_window_elapses() {
+        let mut monitor = ThermalRunawayMonitor::with_params(20_000, 4.0, 95);
+        monitor.record(0, 90.0, 100);
+        monitor.record(10_000, 90.0, 100);
+        assert_eq!(monitor.check(), ThermalRunawayStatus::Ok);
+    }
+
+    #[test]
+    fn test_thermal_runaway_declared_when_temp_does_not_drop() {
+        let mut monitor = ThermalRunawayMonitor::with_params(20_000, 4.0, 95);
+        monitor.record(0, 90.0, 100);
+        monitor.record(10_000, 91.0, 100);
+        monitor.record(20_000, 90.5, 100);
+        assert_eq!(monitor.check(), ThermalRunawayStatus::Runaway);
+    }
+
+    #[test]
+    fn test_thermal_runaway_ok_when_temp_drops_past_hysteresis() {
+        let mut monitor = ThermalRunawayMonitor::with_params(20_000, 4.0, 95);
+        monitor.record(0, 95.0, 100);
+        monitor.record(10_000, 92.0, 100);
+        monitor.record(20_000, 88.0, 100);
+        assert_eq!(monitor.check(), ThermalRunawayStatus::Ok);
+    }
+
+    #[test]
+    fn test_thermal_runaway_resets_window_when_fan_below_threshold() {
+        let mut monitor = ThermalRunawayMonitor::with_params(20_000, 4.0, 95);
+        monitor.record(0, 90.0, 100);
+        monitor.record(10_000, 91.0, 50);
+        monitor.record(20_000, 92.0, 100);
+        // Window restarted at t=10_000 when the fan dropped below max, so
+        // only 10s of at-max history exists - not yet a full window.
+        assert_eq!(monitor.check(), ThermalRunawayStatus::Ok);
+    }
+
+    #[test]
+    fn test_thermal_sensor_fault_on_constant_reading() {
+        let mut monitor = ThermalRunawayMonitor::with_params(20_000, 4.0, 95);
+        monitor.record(0, 60.0, 100);
+        monitor.record(10_000, 60.0, 100);
+        monitor.record(20_000, 60.0, 100);
+        assert_eq!(monitor.check(), ThermalRunawayStatus::SensorFault);
+    }
+
+    #[test]
+    fn test_thermal_sensor_fault_on_implausible_reading() {
+        let mut monitor = ThermalRunawayMonitor::with_params(20_000, 4.0, 95);
+        monitor.record(0, 200.0, 100);
+        monitor.record(10_000, 199.0, 100);
+        monitor.record(20_000, 198.0, 100);
+        assert_eq!(monitor.check(), ThermalRunawayStatus::SensorFault);
+    }
+
+    #[test]
+    fn test_thermal_runaway_reset_clears_samples() {
+        let mut monitor = ThermalRunawayMonitor::with_params(20_000, 4.0, 95);
+        monitor.record(0, 90.0, 100);
+        monitor.record(20_000, 91.0, 100);
+        assert_eq!(monitor.check(), ThermalRunawayStatus::Runaway);
+        monitor.reset();
+        assert_eq!(monitor.check(), ThermalRunawayStatus::Ok);
+    }
+
+    #[test]
+    fn test_thermal_runaway_monitor_default_matches_new() {
+        let default_monitor = ThermalRunawayMonitor::default();
+        let new_monitor = ThermalRunawayMonitor::new();
+        assert_eq!(default_monitor.window_ms, new_monitor.window_ms);
+        assert_eq!(default_monitor.hysteresis_c, new_monitor.hysteresis_c);
+        assert_eq!(
+            default_monitor.fan_pwm_threshold,
+            new_monitor.fan_pwm_threshold
+        );
+    }
 }
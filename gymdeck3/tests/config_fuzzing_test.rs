@@ -5,10 +5,18 @@
 //!
 //! For any byte sequence input to the config parser, the parser SHALL not panic
 //! and SHALL return either a valid config or an error.
+//!
+//! `corpus_fuzz_tests` below backs the same parser entry points with a
+//! persistent regression corpus (see `fuzz_harness`): any case that panics
+//! is minimized and saved under `tests/fuzz_corpus/`, and replayed on every
+//! run before new cases are generated.
 
 use proptest::prelude::*;
 use std::panic::{catch_unwind, AssertUnwindSafe};
 
+mod fuzz_harness;
+use fuzz_harness::FieldValue;
+
 use gymdeck3::{
     parse_core_config,
     validate_sample_interval,
@@ -267,23 +275,138 @@ proptest! {
     #[test]
     fn prop_error_messages_non_empty(s in arbitrary_string()) {
         if let Err(e) = parse_core_config(&s) {
-            prop_assert!(!e.is_empty(), "Error message should not be empty");
+            prop_assert!(!e.to_string().is_empty(), "Error message should not be empty");
         }
         if let Err(e) = validate_sample_interval(&s) {
-            prop_assert!(!e.is_empty(), "Error message should not be empty");
+            prop_assert!(!e.to_string().is_empty(), "Error message should not be empty");
         }
         if let Err(e) = validate_hysteresis(&s) {
-            prop_assert!(!e.is_empty(), "Error message should not be empty");
+            prop_assert!(!e.to_string().is_empty(), "Error message should not be empty");
         }
         if let Err(e) = parse_fan_curve_point(&s) {
-            prop_assert!(!e.is_empty(), "Error message should not be empty");
+            prop_assert!(!e.to_string().is_empty(), "Error message should not be empty");
         }
         if let Err(e) = validate_fan_hysteresis(&s) {
-            prop_assert!(!e.is_empty(), "Error message should not be empty");
+            prop_assert!(!e.to_string().is_empty(), "Error message should not be empty");
         }
     }
 }
 
+/// Regression-corpus-backed fuzzing: replays `tests/fuzz_corpus/<target>.txt`
+/// before drawing new cases, and minimizes+persists any new failure so it
+/// stays a permanent regression instead of a one-shot finding.
+#[cfg(test)]
+mod corpus_fuzz_tests {
+    use super::*;
+
+    #[test]
+    fn fuzz_corpus_parse_core_config() {
+        let seeds = fuzz_harness::generate_samples(arbitrary_string(), 100);
+        fuzz_harness::fuzz_string("parse_core_config", seeds, |s| {
+            catch_unwind(AssertUnwindSafe(|| {
+                let _ = parse_core_config(s);
+            }))
+            .is_ok()
+        });
+    }
+
+    #[test]
+    fn fuzz_corpus_parse_fan_curve_point() {
+        let seeds = fuzz_harness::generate_samples(arbitrary_string(), 100);
+        fuzz_harness::fuzz_string("parse_fan_curve_point", seeds, |s| {
+            catch_unwind(AssertUnwindSafe(|| {
+                let _ = parse_fan_curve_point(s);
+            }))
+            .is_ok()
+        });
+    }
+
+    #[test]
+    fn fuzz_corpus_parse_acoustic_profile() {
+        let seeds = fuzz_harness::generate_samples(arbitrary_string(), 100);
+        fuzz_harness::fuzz_string("parse_acoustic_profile", seeds, |s| {
+            catch_unwind(AssertUnwindSafe(|| {
+                let _ = parse_acoustic_profile(s);
+            }))
+            .is_ok()
+        });
+    }
+
+    #[test]
+    fn fuzz_corpus_validate_sample_interval_value() {
+        let seeds = fuzz_harness::generate_samples(arbitrary_u64(), 100)
+            .into_iter()
+            .map(|val| vec![FieldValue::U64(val)]);
+        fuzz_harness::fuzz_numeric("validate_sample_interval_value", &["val"], seeds, |fields| {
+            let FieldValue::U64(val) = fields[0] else { return false };
+            catch_unwind(AssertUnwindSafe(|| {
+                let _ = validate_sample_interval_value(val);
+            }))
+            .is_ok()
+        });
+    }
+
+    #[test]
+    fn fuzz_corpus_validate_hysteresis_value() {
+        let seeds = fuzz_harness::generate_samples(arbitrary_f32(), 100)
+            .into_iter()
+            .map(|val| vec![FieldValue::F32(val)]);
+        fuzz_harness::fuzz_numeric("validate_hysteresis_value", &["val"], seeds, |fields| {
+            let FieldValue::F32(val) = fields[0] else { return false };
+            catch_unwind(AssertUnwindSafe(|| {
+                let _ = validate_hysteresis_value(val);
+            }))
+            .is_ok()
+        });
+    }
+
+    #[test]
+    fn fuzz_corpus_validate_core_config_values() {
+        let names = ["core_id", "min_mv", "max_mv", "threshold"];
+        let seeds = fuzz_harness::generate_samples(
+            (arbitrary_usize(), arbitrary_i32(), arbitrary_i32(), arbitrary_f32()),
+            100,
+        )
+        .into_iter()
+        .map(|(core_id, min_mv, max_mv, threshold)| {
+            vec![
+                FieldValue::USize(core_id),
+                FieldValue::I32(min_mv),
+                FieldValue::I32(max_mv),
+                FieldValue::F32(threshold),
+            ]
+        });
+        fuzz_harness::fuzz_numeric("validate_core_config_values", &names, seeds, |fields| {
+            let (FieldValue::USize(core_id), FieldValue::I32(min_mv), FieldValue::I32(max_mv), FieldValue::F32(threshold)) =
+                (fields[0], fields[1], fields[2], fields[3])
+            else {
+                return false;
+            };
+            catch_unwind(AssertUnwindSafe(|| {
+                let _ = validate_core_config_values(core_id, min_mv, max_mv, threshold);
+            }))
+            .is_ok()
+        });
+    }
+
+    #[test]
+    fn fuzz_corpus_validate_fan_curve_point() {
+        let names = ["temp_c", "speed_percent"];
+        let seeds = fuzz_harness::generate_samples((arbitrary_i32(), arbitrary_u8()), 100)
+            .into_iter()
+            .map(|(temp_c, speed_percent)| vec![FieldValue::I32(temp_c), FieldValue::U8(speed_percent)]);
+        fuzz_harness::fuzz_numeric("validate_fan_curve_point", &names, seeds, |fields| {
+            let (FieldValue::I32(temp_c), FieldValue::U8(speed_percent)) = (fields[0], fields[1]) else {
+                return false;
+            };
+            catch_unwind(AssertUnwindSafe(|| {
+                let _ = validate_fan_curve_point(temp_c, speed_percent);
+            }))
+            .is_ok()
+        });
+    }
+}
+
 #[cfg(test)]
 mod edge_case_tests {
     use super::*;
@@ -0,0 +1,105 @@
+//! Round-trip property tests for config Display/parse symmetry
+//!
+//! `config_fuzzing_test.rs` only asserts the parsers never panic; this file
+//! checks the other direction: every `CoreConfig`/`FanCurvePointConfig`/
+//! `AcousticProfile` that can be constructed renders to a string its own
+//! parser accepts back, and re-renders to the exact same string every time
+//! (round-trip and idempotence). Catches asymmetries between the writer and
+//! reader a no-panic fuzz pass can't - e.g. the parser silently clamping a
+//! boundary value the `Display` impl rendered unclamped.
+
+use proptest::prelude::*;
+
+use gymdeck3::{parse_acoustic_profile, parse_core_config, parse_fan_curve_point};
+use gymdeck3::{AcousticProfile, CoreConfig, FanCurvePointConfig};
+
+/// Every field is drawn from a plain proptest range already restricted to
+/// what `parse_core_config` accepts (`min_mv`/`max_mv` <= 0, `max_mv` <=
+/// `min_mv`, `threshold` in `[0, 100]`), so shrinking follows proptest's
+/// ordinary binary search toward 0 for each field instead of a filter
+/// rejecting random combinations.
+fn core_config_strategy() -> impl Strategy<Value = CoreConfig> {
+    (0usize..8usize, -500i32..=0i32, 0.0f32..=100.0f32).prop_flat_map(
+        |(core_id, min_mv, threshold)| {
+            (min_mv..=0i32).prop_map(move |max_mv| CoreConfig {
+                core_id,
+                min_mv,
+                max_mv,
+                threshold,
+            })
+        },
+    )
+}
+
+/// `temp_c`/`speed_percent` ranges match `validate_fan_curve_point` exactly,
+/// so every generated point is valid and both fields shrink toward 0.
+fn fan_curve_point_strategy() -> impl Strategy<Value = FanCurvePointConfig> {
+    (0i32..=100i32, 0u8..=100u8).prop_map(|(temp_c, speed_percent)| FanCurvePointConfig {
+        temp_c,
+        speed_percent,
+    })
+}
+
+/// `select` draws a strategy-internal index that shrinks toward 0 like any
+/// other proptest integer, so putting `Balanced` first makes it the variant
+/// a failing case minimizes to.
+fn acoustic_profile_strategy() -> impl Strategy<Value = AcousticProfile> {
+    proptest::sample::select(vec![
+        AcousticProfile::Balanced,
+        AcousticProfile::Silent,
+        AcousticProfile::MaxCooling,
+        AcousticProfile::Custom,
+    ])
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(200))]
+
+    /// Property: parsing a `CoreConfig`'s own rendering SHALL reproduce it
+    #[test]
+    fn prop_core_config_round_trip(cfg in core_config_strategy()) {
+        let rendered = cfg.to_config_string();
+        let parsed = parse_core_config(&rendered);
+        prop_assert_eq!(parsed, Ok(cfg), "round-trip through {:?} failed", rendered);
+    }
+
+    /// Property: re-rendering a round-tripped `CoreConfig` SHALL be byte-identical
+    #[test]
+    fn prop_core_config_idempotent(cfg in core_config_strategy()) {
+        let once = cfg.to_config_string();
+        let twice = parse_core_config(&once).unwrap().to_config_string();
+        prop_assert_eq!(once, twice);
+    }
+
+    /// Property: parsing a `FanCurvePointConfig`'s own rendering SHALL reproduce it
+    #[test]
+    fn prop_fan_curve_point_round_trip(point in fan_curve_point_strategy()) {
+        let rendered = point.to_config_string();
+        let parsed = parse_fan_curve_point(&rendered);
+        prop_assert_eq!(parsed, Ok(point), "round-trip through {:?} failed", rendered);
+    }
+
+    /// Property: re-rendering a round-tripped `FanCurvePointConfig` SHALL be byte-identical
+    #[test]
+    fn prop_fan_curve_point_idempotent(point in fan_curve_point_strategy()) {
+        let once = point.to_config_string();
+        let twice = parse_fan_curve_point(&once).unwrap().to_config_string();
+        prop_assert_eq!(once, twice);
+    }
+
+    /// Property: parsing an `AcousticProfile`'s own rendering SHALL reproduce it
+    #[test]
+    fn prop_acoustic_profile_round_trip(profile in acoustic_profile_strategy()) {
+        let rendered = profile.to_config_string();
+        let parsed = parse_acoustic_profile(&rendered);
+        prop_assert_eq!(parsed, Ok(profile), "round-trip through {:?} failed", rendered);
+    }
+
+    /// Property: re-rendering a round-tripped `AcousticProfile` SHALL be byte-identical
+    #[test]
+    fn prop_acoustic_profile_idempotent(profile in acoustic_profile_strategy()) {
+        let once = profile.to_config_string();
+        let twice = parse_acoustic_profile(&once).unwrap().to_config_string();
+        prop_assert_eq!(once, twice);
+    }
+}
@@ -183,6 +183,62 @@ proptest! {
         }
     }
 
+    /// **Feature: frequency-based-wizard, Property 3: Frequency curve interpolation correctness**
+    /// **Validates: Requirements 1.5, 2.2**
+    ///
+    /// `InterpolationKind::MonotoneCubic` must preserve monotonicity too -
+    /// PCHIP's zero-tangent-on-sign-change rule exists precisely to guarantee
+    /// this, unlike an unconstrained cubic spline.
+    #[test]
+    fn prop_monotone_cubic_preserves_monotonicity(
+        curve in arb_frequency_curve(),
+    ) {
+        use gymdeck3::dynamic::InterpolationKind;
+
+        prop_assume!(curve.points.len() >= 2);
+        let curve = curve.with_interpolation(InterpolationKind::MonotoneCubic);
+
+        let mut is_increasing = true;
+        let mut is_decreasing = true;
+        for i in 0..curve.points.len() - 1 {
+            let v1 = curve.points[i].voltage_mv;
+            let v2 = curve.points[i + 1].voltage_mv;
+            if v1 > v2 { is_increasing = false; }
+            if v1 < v2 { is_decreasing = false; }
+        }
+        let is_monotonic = is_increasing || is_decreasing;
+        prop_assume!(is_monotonic);
+
+        let min_freq = curve.points.first().unwrap().frequency_mhz;
+        let max_freq = curve.points.last().unwrap().frequency_mhz;
+
+        if max_freq > min_freq + 100 {
+            let step = (max_freq - min_freq) / 10;
+            let mut prev_voltage = curve.get_voltage_at_frequency(min_freq).unwrap();
+
+            for i in 1..=10 {
+                let freq = min_freq + i * step;
+                let voltage = curve.get_voltage_at_frequency(freq).unwrap();
+
+                if is_increasing {
+                    prop_assert!(
+                        voltage >= prev_voltage,
+                        "MonotoneCubic monotonicity violated: voltage decreased from {} to {} at freq {}",
+                        prev_voltage, voltage, freq
+                    );
+                } else {
+                    prop_assert!(
+                        voltage <= prev_voltage,
+                        "MonotoneCubic monotonicity violated: voltage increased from {} to {} at freq {}",
+                        prev_voltage, voltage, freq
+                    );
+                }
+
+                prev_voltage = voltage;
+            }
+        }
+    }
+
     /// **Feature: frequency-based-wizard, Property 3: Frequency curve interpolation correctness**
     /// **Validates: Requirements 1.5, 2.2**
     ///
@@ -418,6 +474,151 @@ proptest! {
     }
 }
 
+// =============================================================================
+// Flat interpolation modes: every interpolated value must equal some actual
+// tested point's voltage, never a computed intermediate.
+// =============================================================================
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(100))]
+
+    #[test]
+    fn prop_backward_flat_returns_actual_point_voltage(
+        curve in arb_frequency_curve(),
+        freq in arb_frequency(),
+    ) {
+        use gymdeck3::dynamic::InterpolationKind;
+        let curve = curve.with_interpolation(InterpolationKind::BackwardFlat);
+        let voltage = curve.get_voltage_at_frequency(freq).unwrap();
+        prop_assert!(
+            curve.points.iter().any(|p| p.voltage_mv == voltage),
+            "BackwardFlat returned {} mV at {} MHz, which no tested point carries",
+            voltage, freq
+        );
+    }
+
+    #[test]
+    fn prop_forward_flat_returns_actual_point_voltage(
+        curve in arb_frequency_curve(),
+        freq in arb_frequency(),
+    ) {
+        use gymdeck3::dynamic::InterpolationKind;
+        let curve = curve.with_interpolation(InterpolationKind::ForwardFlat);
+        let voltage = curve.get_voltage_at_frequency(freq).unwrap();
+        prop_assert!(
+            curve.points.iter().any(|p| p.voltage_mv == voltage),
+            "ForwardFlat returned {} mV at {} MHz, which no tested point carries",
+            voltage, freq
+        );
+    }
+}
+
+// =============================================================================
+// `voltage_range_over`: bounds must bracket every interpolated value
+// actually sampled across the queried band.
+// =============================================================================
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(100))]
+
+    #[test]
+    fn prop_voltage_range_over_brackets_sampled_values(
+        curve in arb_frequency_curve(),
+        f_a in arb_frequency(),
+        f_b in arb_frequency(),
+    ) {
+        let (min_v, max_v) = curve.voltage_range_over(f_a, f_b).unwrap();
+        prop_assert!(min_v <= max_v);
+
+        let (lo, hi) = if f_a <= f_b { (f_a, f_b) } else { (f_b, f_a) };
+        let min_freq = curve.points.first().unwrap().frequency_mhz;
+        let max_freq = curve.points.last().unwrap().frequency_mhz;
+        let clipped_lo = lo.clamp(min_freq, max_freq);
+        let clipped_hi = hi.clamp(min_freq, max_freq);
+
+        for i in 0..=10u32 {
+            let freq = clipped_lo + (clipped_hi - clipped_lo) * i / 10;
+            let voltage = curve.get_voltage_at_frequency(freq).unwrap();
+            prop_assert!(
+                voltage >= min_v && voltage <= max_v,
+                "voltage {} mV at {} MHz fell outside reported range [{}, {}]",
+                voltage, freq, min_v, max_v
+            );
+        }
+    }
+}
+
+// =============================================================================
+// `bake`/`sample_baked`: baked-table lookups must agree exactly with the
+// sparse curve at baked grid points, and the worst-case error elsewhere
+// must shrink as the table's resolution grows.
+// =============================================================================
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(100))]
+
+    #[test]
+    fn prop_sample_baked_exact_at_grid_points(
+        curve in arb_frequency_curve(),
+        resolution in 2u32..=50u32,
+    ) {
+        let baked = curve.bake(resolution).unwrap();
+        for i in 0..baked.resolution() as u32 {
+            let freq = baked.min_freq_mhz + baked.step_mhz * i;
+            prop_assert_eq!(
+                baked.sample_baked(freq).unwrap(),
+                curve.get_voltage_at_frequency(freq).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn prop_sample_baked_error_shrinks_with_resolution(
+        curve in arb_frequency_curve(),
+    ) {
+        use gymdeck3::dynamic::EvenFrequencyCurve;
+
+        let min_freq = curve.points.first().unwrap().frequency_mhz;
+
+        let coarse = curve.bake(3).unwrap();
+        let fine = curve.bake(200).unwrap();
+
+        // `bake`'s step size doesn't always divide the frequency span
+        // evenly, so a table's last grid point can fall short of the
+        // curve's actual max frequency; clip the comparison to the range
+        // both tables actually cover so neither is penalized for that
+        // rounding.
+        let last_covered = |table: &EvenFrequencyCurve| {
+            table.min_freq_mhz + table.step_mhz * (table.resolution() as u32 - 1)
+        };
+        let sample_max = last_covered(&coarse).min(last_covered(&fine));
+
+        // Average rather than worst-case error: any single lookup can land
+        // a couple mV off due to the i64 truncating-division formula
+        // rounding differently at different grid alignments, which is
+        // noise that doesn't shrink with resolution. The genuine
+        // interpolation error - how well the baked grid tracks curvature
+        // the sparse curve has between its own tested points - does
+        // shrink, and dominates once the two resolutions are far enough
+        // apart (3 vs. 200 samples across the tested range).
+        let mean_error = |table: &EvenFrequencyCurve| {
+            let errors: Vec<i64> = (0..=100u32)
+                .map(|i| min_freq + (sample_max - min_freq) * i / 100)
+                .map(|freq| {
+                    (table.sample_baked(freq).unwrap() - curve.get_voltage_at_frequency(freq).unwrap()).abs() as i64
+                })
+                .collect();
+            errors.iter().sum::<i64>() as f64 / errors.len() as f64
+        };
+
+        prop_assert!(
+            mean_error(&fine) <= mean_error(&coarse) + 2.0,
+            "finer table (resolution 200, mean error {}) had larger average error than coarser table (resolution 3, mean error {})",
+            mean_error(&fine), mean_error(&coarse)
+        );
+    }
+}
+
 #[cfg(test)]
 mod edge_case_tests {
     use super::*;
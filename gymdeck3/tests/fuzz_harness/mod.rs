@@ -0,0 +1,391 @@
+//! Persistent regression corpus and delta-minimizer for the config fuzz tests.
+//!
+//! `config_fuzzing_test.rs` only runs `proptest`'s own random cases, which
+//! are discarded once the process exits. This module backs those checks
+//! with a corpus file per fuzz target under `tests/fuzz_corpus/`: every
+//! seed that ever triggered a panic or an unexpected Ok/Err classification
+//! is minimized and appended, then replayed before any new cases are
+//! generated on the next run. That turns a one-shot property check into a
+//! permanent regression net.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proptest::strategy::{Strategy, ValueTree};
+use proptest::test_runner::{Config as ProptestConfig, TestRunner};
+
+fn corpus_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fuzz_corpus")
+}
+
+fn corpus_path(target: &str) -> PathBuf {
+    corpus_dir().join(format!("{target}.txt"))
+}
+
+/// FNV-1a over the minimized reproducer, so a regression case can be named
+/// and referenced (e.g. in a commit message) without embedding the raw
+/// (possibly unprintable) bytes.
+fn stable_hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for &b in bytes {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+fn escape_seed(s: &str) -> String {
+    format!("{:?}", s)
+}
+
+/// Inverse of `escape_seed`. Only needs to understand the escapes that
+/// `{:?}` on `&str` ever emits (`\n \r \t \\ \" \0` and `\u{..}`).
+fn unescape_seed(line: &str) -> Option<String> {
+    let inner = line.trim().strip_prefix('"')?.strip_suffix('"')?;
+    let mut out = String::new();
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => out.push('\n'),
+            'r' => out.push('\r'),
+            't' => out.push('\t'),
+            '\\' => out.push('\\'),
+            '"' => out.push('"'),
+            '0' => out.push('\0'),
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+                let mut hex = String::new();
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        h => hex.push(h),
+                    }
+                }
+                let code = u32::from_str_radix(&hex, 16).ok()?;
+                out.push(char::from_u32(code)?);
+            }
+            other => out.push(other),
+        }
+    }
+    Some(out)
+}
+
+fn load_corpus(target: &str) -> Vec<String> {
+    let path = corpus_path(target);
+    let Ok(contents) = fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .filter_map(unescape_seed)
+        .collect()
+}
+
+fn append_corpus_entry(target: &str, seed: &str) {
+    let dir = corpus_dir();
+    fs::create_dir_all(&dir).expect("failed to create fuzz corpus directory");
+
+    let path = corpus_path(target);
+    let hash = stable_hash(seed.as_bytes());
+
+    if load_corpus(target).iter().any(|existing| existing == seed) {
+        return;
+    }
+
+    let mut contents = fs::read_to_string(&path).unwrap_or_default();
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    contents.push_str(&format!("# regression {hash:016x}\n"));
+    contents.push_str(&escape_seed(seed));
+    contents.push('\n');
+    fs::write(&path, contents).expect("failed to write fuzz corpus file");
+}
+
+/// Repeatedly delete spans of `seed` (coarsest chunk size first, like
+/// delta-debugging's ddmin) keeping any deletion for which `still_fails`
+/// still returns `true`, until no further reduction reproduces the
+/// failure.
+pub fn minimize_string(seed: &str, still_fails: impl Fn(&str) -> bool) -> String {
+    let mut current = seed.as_bytes().to_vec();
+    let mut chunk_size = current.len() / 2;
+
+    while chunk_size > 0 {
+        let mut start = 0;
+        let mut shrunk = false;
+
+        while start < current.len() {
+            let end = (start + chunk_size).min(current.len());
+            let mut candidate = current.clone();
+            candidate.drain(start..end);
+
+            if let Ok(candidate_str) = std::str::from_utf8(&candidate) {
+                if still_fails(candidate_str) {
+                    current = candidate;
+                    shrunk = true;
+                    continue; // re-try this offset against the shrunk buffer
+                }
+            }
+            start += chunk_size;
+        }
+
+        if !shrunk {
+            chunk_size /= 2;
+        }
+    }
+
+    String::from_utf8(current).unwrap_or_else(|_| seed.to_string())
+}
+
+/// Replay every seed already recorded for `target`, failing loudly if any
+/// of them no longer holds `check` (a regression in the strict sense: a
+/// case that used to be fine and now panics or mis-classifies again).
+pub fn replay_string_corpus(target: &str, check: impl Fn(&str) -> bool) {
+    for seed in load_corpus(target) {
+        assert!(
+            check(&seed),
+            "regression replay failed for '{target}': seed {seed:?} (see tests/fuzz_corpus/{target}.txt)"
+        );
+    }
+}
+
+/// Replay the persisted corpus, then run `check` over `seeds`. The first
+/// seed that fails is minimized to a locally-minimal reproducer and
+/// appended to the corpus before the test panics, so the next run starts
+/// with it already in the regression set.
+pub fn fuzz_string(target: &str, seeds: impl IntoIterator<Item = String>, check: impl Fn(&str) -> bool) {
+    replay_string_corpus(target, &check);
+
+    for seed in seeds {
+        if !check(&seed) {
+            let minimized = minimize_string(&seed, |s| !check(s));
+            append_corpus_entry(target, &minimized);
+            panic!(
+                "fuzz target '{target}' found a new failing case; minimized reproducer {minimized:?} \
+                 recorded to tests/fuzz_corpus/{target}.txt (hash {:016x})",
+                stable_hash(minimized.as_bytes())
+            );
+        }
+    }
+}
+
+/// Draw `count` values out of a proptest `Strategy` without going through
+/// the `proptest!` macro, so the fuzz harness can feed its own corpus-aware
+/// driver instead of proptest's (which only persists its own regressions).
+pub fn generate_samples<S: Strategy>(strategy: S, count: usize) -> Vec<S::Value> {
+    let mut runner = TestRunner::new(ProptestConfig::default());
+    (0..count)
+        .map(|_| {
+            strategy
+                .new_tree(&mut runner)
+                .expect("strategy generation failed")
+                .current()
+        })
+        .collect()
+}
+
+/// One scalar field of a numeric fuzz case. Tagged so the corpus file can
+/// round-trip heterogeneous field types (a core config is `usize, i32, i32,
+/// f32`) through a single textual format.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldValue {
+    I32(i32),
+    U64(u64),
+    F32(f32),
+    U8(u8),
+    USize(usize),
+}
+
+impl FieldValue {
+    fn halved(self) -> Self {
+        match self {
+            FieldValue::I32(v) => FieldValue::I32(v / 2),
+            FieldValue::U64(v) => FieldValue::U64(v / 2),
+            FieldValue::F32(v) => FieldValue::F32(v / 2.0),
+            FieldValue::U8(v) => FieldValue::U8(v / 2),
+            FieldValue::USize(v) => FieldValue::USize(v / 2),
+        }
+    }
+
+    fn zeroed(self) -> Self {
+        match self {
+            FieldValue::I32(_) => FieldValue::I32(0),
+            FieldValue::U64(_) => FieldValue::U64(0),
+            FieldValue::F32(_) => FieldValue::F32(0.0),
+            FieldValue::U8(_) => FieldValue::U8(0),
+            FieldValue::USize(_) => FieldValue::USize(0),
+        }
+    }
+
+    fn is_zero(self) -> bool {
+        match self {
+            FieldValue::I32(v) => v == 0,
+            FieldValue::U64(v) => v == 0,
+            FieldValue::F32(v) => v == 0.0,
+            FieldValue::U8(v) => v == 0,
+            FieldValue::USize(v) => v == 0,
+        }
+    }
+
+    fn render(self) -> String {
+        match self {
+            FieldValue::I32(v) => format!("I32({v})"),
+            FieldValue::U64(v) => format!("U64({v})"),
+            FieldValue::F32(v) => format!("F32({v})"),
+            FieldValue::U8(v) => format!("U8({v})"),
+            FieldValue::USize(v) => format!("USize({v})"),
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        let (variant, inner) = s.split_once('(')?;
+        let inner = inner.strip_suffix(')')?;
+        Some(match variant {
+            "I32" => FieldValue::I32(inner.parse().ok()?),
+            "U64" => FieldValue::U64(inner.parse().ok()?),
+            "F32" => FieldValue::F32(inner.parse().ok()?),
+            "U8" => FieldValue::U8(inner.parse().ok()?),
+            "USize" => FieldValue::USize(inner.parse().ok()?),
+            _ => return None,
+        })
+    }
+}
+
+fn render_case(names: &[&str], fields: &[FieldValue]) -> String {
+    names
+        .iter()
+        .zip(fields)
+        .map(|(name, value)| format!("{name}={}", value.render()))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+fn parse_case(names: &[&str], line: &str) -> Option<Vec<FieldValue>> {
+    let mut by_name: Vec<(String, FieldValue)> = Vec::new();
+    for entry in line.split(';') {
+        let (name, value) = entry.split_once('=')?;
+        by_name.push((name.to_string(), FieldValue::parse(value)?));
+    }
+    names
+        .iter()
+        .map(|name| {
+            by_name
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| *v)
+        })
+        .collect()
+}
+
+fn load_numeric_corpus(target: &str, names: &[&str]) -> Vec<Vec<FieldValue>> {
+    let path = corpus_path(target);
+    let Ok(contents) = fs::read_to_string(&path) else {
+        return Vec::new();
+    };
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .filter_map(|line| parse_case(names, line))
+        .collect()
+}
+
+fn append_numeric_entry(target: &str, names: &[&str], fields: &[FieldValue]) {
+    let dir = corpus_dir();
+    fs::create_dir_all(&dir).expect("failed to create fuzz corpus directory");
+
+    if load_numeric_corpus(target, names).iter().any(|existing| existing == fields) {
+        return;
+    }
+
+    let rendered = render_case(names, fields);
+    let hash = stable_hash(rendered.as_bytes());
+
+    let path = corpus_path(target);
+    let mut contents = fs::read_to_string(&path).unwrap_or_default();
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    contents.push_str(&format!("# regression {hash:016x}\n"));
+    contents.push_str(&rendered);
+    contents.push('\n');
+    fs::write(&path, contents).expect("failed to write fuzz corpus file");
+}
+
+/// Numeric counterpart of `minimize_string`: zero each field that can be
+/// zeroed without losing the failure, then repeatedly halve whatever's
+/// left until nothing shrinks any further.
+pub fn minimize_numeric(fields: &[FieldValue], still_fails: impl Fn(&[FieldValue]) -> bool) -> Vec<FieldValue> {
+    let mut current = fields.to_vec();
+
+    for i in 0..current.len() {
+        if current[i].is_zero() {
+            continue;
+        }
+        let mut candidate = current.clone();
+        candidate[i] = candidate[i].zeroed();
+        if still_fails(&candidate) {
+            current = candidate;
+        }
+    }
+
+    loop {
+        let mut shrunk = false;
+        for i in 0..current.len() {
+            if current[i].is_zero() {
+                continue;
+            }
+            let mut candidate = current.clone();
+            candidate[i] = candidate[i].halved();
+            if candidate[i] != current[i] && still_fails(&candidate) {
+                current = candidate;
+                shrunk = true;
+            }
+        }
+        if !shrunk {
+            break;
+        }
+    }
+
+    current
+}
+
+/// Replay every numeric case already recorded for `target`.
+pub fn replay_numeric_corpus(target: &str, names: &[&str], check: impl Fn(&[FieldValue]) -> bool) {
+    for case in load_numeric_corpus(target, names) {
+        assert!(
+            check(&case),
+            "regression replay failed for '{target}': case {case:?} (see tests/fuzz_corpus/{target}.txt)"
+        );
+    }
+}
+
+/// Numeric counterpart of `fuzz_string`.
+pub fn fuzz_numeric(
+    target: &str,
+    names: &[&str],
+    cases: impl IntoIterator<Item = Vec<FieldValue>>,
+    check: impl Fn(&[FieldValue]) -> bool,
+) {
+    replay_numeric_corpus(target, names, &check);
+
+    for case in cases {
+        if !check(&case) {
+            let minimized = minimize_numeric(&case, |c| !check(c));
+            append_numeric_entry(target, names, &minimized);
+            panic!(
+                "fuzz target '{target}' found a new failing case; minimized reproducer {} \
+                 recorded to tests/fuzz_corpus/{target}.txt",
+                render_case(names, &minimized)
+            );
+        }
+    }
+}
@@ -42,6 +42,8 @@ proptest! {
                 irq: 0,
                 softirq: 0,
                 steal: 0,
+                guest: 0,
+                guest_nice: 0,
             })
             .collect();
 
@@ -105,6 +107,8 @@ proptest! {
                 irq: 0,
                 softirq: 0,
                 steal: 0,
+                guest: 0,
+                guest_nice: 0,
             })
             .collect();
 
@@ -151,6 +155,8 @@ proptest! {
             irq: 0,
             softirq: 0,
             steal: 0,
+            guest: 0,
+            guest_nice: 0,
         };
 
         let current2 = CoreStats {
@@ -162,6 +168,8 @@ proptest! {
             irq: 0,
             softirq: 0,
             steal: 0,
+            guest: 0,
+            guest_nice: 0,
         };
 
         let prev_stats = CpuStats {
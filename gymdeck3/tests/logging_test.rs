@@ -0,0 +1,94 @@
+//! Property-based tests for NDJSON log output
+
+use std::collections::BTreeMap;
+
+use proptest::prelude::*;
+use serde_json::Value;
+
+use gymdeck3::{validate_log_output, LogOutput};
+
+fn arb_level() -> impl proptest::strategy::Strategy<Value = String> {
+    prop_oneof![
+        Just("error".to_string()),
+        Just("warn".to_string()),
+        Just("info".to_string()),
+        Just("debug".to_string()),
+        Just("trace".to_string()),
+    ]
+}
+
+fn arb_target() -> impl proptest::strategy::Strategy<Value = String> {
+    "[a-z]{2,8}(::[a-z]{2,8}){0,3}"
+}
+
+fn arb_field_value() -> impl proptest::strategy::Strategy<Value = Value> {
+    prop_oneof![
+        any::<i64>().prop_map(Value::from),
+        any::<bool>().prop_map(Value::from),
+        "[a-zA-Z0-9_ ]{0,16}".prop_map(Value::from),
+    ]
+}
+
+fn arb_fields() -> impl proptest::strategy::Strategy<Value = BTreeMap<String, Value>> {
+    proptest::collection::btree_map("[a-z_]{1,10}", arb_field_value(), 0..=6)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(200))]
+
+    /// Any generated log output serializes as valid, single-line JSON that
+    /// round-trips through `validate_log_output`.
+    #[test]
+    fn prop_log_output_is_valid_single_line_json(
+        level in arb_level(),
+        target in arb_target(),
+        fields in arb_fields(),
+        uptime_ms in 0u64..=u64::MAX / 2,
+    ) {
+        let log = LogOutput::new(&level, &target, fields, uptime_ms);
+        let json = log.to_json().unwrap();
+
+        prop_assert!(!json.contains('\n'));
+        prop_assert!(!json.contains('\r'));
+
+        let validated = validate_log_output(&json);
+        prop_assert!(validated.is_ok());
+        prop_assert_eq!(validated.unwrap(), log);
+    }
+
+    /// The `fields` object always round-trips exactly regardless of content.
+    #[test]
+    fn prop_log_output_fields_roundtrip(
+        fields in arb_fields(),
+    ) {
+        let log = LogOutput::new("info", "gymdeck3::test", fields.clone(), 0);
+        let json = log.to_json().unwrap();
+        let decoded: LogOutput = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(decoded.fields, fields);
+    }
+}
+
+#[cfg(test)]
+mod validation_tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_log_output_rejects_non_log_type() {
+        let json = r#"{"type":"transition","level":"info","target":"x","fields":{},"uptime_ms":0}"#;
+        assert!(validate_log_output(json).is_err());
+    }
+
+    #[test]
+    fn test_validate_log_output_rejects_malformed_json() {
+        let result = validate_log_output("not json");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_log_output_empty_target() {
+        let json = r#"{"type":"log","level":"info","target":"","fields":{},"uptime_ms":0}"#;
+        let result = validate_log_output(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("target cannot be empty"));
+    }
+}
@@ -6,6 +6,7 @@
 //! as defined in the design document.
 
 use proptest::prelude::*;
+use proptest::strategy::Strategy as _;
 use gymdeck3::{
     Strategy,
     StatusOutput,
@@ -188,6 +189,10 @@ proptest! {
             Strategy::Balanced => "balanced",
             Strategy::Aggressive => "aggressive",
             Strategy::Custom => "custom",
+            Strategy::Pid => "pid",
+            Strategy::Adaptive => "adaptive",
+            Strategy::Learning => "learning",
+            Strategy::Markov => "markov",
         };
         
         prop_assert!(
@@ -198,6 +203,72 @@ proptest! {
     }
 }
 
+// =============================================================================
+// Canonical (float-free) encoding
+// =============================================================================
+
+/// Generate a load value that is already quantized to milli-percent, so a
+/// canonical round-trip is lossless rather than nearest-rounded.
+fn arb_milli_percent_load() -> impl proptest::strategy::Strategy<Value = f32> {
+    (0u32..=100_000u32).prop_map(|m| m as f32 / 1000.0)
+}
+
+fn arb_milli_percent_load_vec() -> impl proptest::strategy::Strategy<Value = Vec<f32>> {
+    proptest::collection::vec(arb_milli_percent_load(), 1..=8)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(100))]
+
+    /// Canonical round-trip is exact (no floating-point tolerance needed)
+    /// for loads that are already milli-percent quantized.
+    #[test]
+    fn prop_canonical_roundtrip_exact(
+        load in arb_milli_percent_load_vec(),
+        values in arb_values_vec(),
+        strategy in arb_strategy(),
+        uptime_ms in arb_uptime(),
+    ) {
+        let original = StatusOutput::new(load, values, strategy, uptime_ms);
+        let json = original.to_canonical_json().expect("Canonical serialization should succeed");
+        let decoded = StatusOutput::from_canonical_json(&json).expect("Canonical deserialization should succeed");
+
+        prop_assert_eq!(original, decoded);
+    }
+
+    /// Canonical JSON never contains floating-point tokens
+    #[test]
+    fn prop_canonical_json_has_no_float_tokens(
+        load in arb_load_vec(),
+        values in arb_values_vec(),
+        strategy in arb_strategy(),
+        uptime_ms in arb_uptime(),
+    ) {
+        let status = StatusOutput::new(load, values, strategy, uptime_ms);
+        let json = status.to_canonical_json().expect("Canonical serialization should succeed");
+
+        prop_assert!(!json.contains('.'), "canonical JSON should contain no '.': {}", json);
+        prop_assert!(!json.contains('e'), "canonical JSON should contain no 'e': {}", json);
+    }
+
+    /// Identical logical state serializes to identical canonical bytes
+    #[test]
+    fn prop_canonical_json_is_byte_stable(
+        load in arb_milli_percent_load_vec(),
+        values in arb_values_vec(),
+        strategy in arb_strategy(),
+        uptime_ms in arb_uptime(),
+    ) {
+        let a = StatusOutput::new(load.clone(), values.clone(), strategy, uptime_ms);
+        let b = StatusOutput::new(load, values, strategy, uptime_ms);
+
+        prop_assert_eq!(
+            a.to_canonical_json().unwrap(),
+            b.to_canonical_json().unwrap()
+        );
+    }
+}
+
 // =============================================================================
 // Additional output format tests
 // =============================================================================
@@ -325,3 +396,76 @@ mod validation_tests {
         assert!(validate_status_output(json).is_ok());
     }
 }
+
+// =============================================================================
+// Stable field ordering
+// =============================================================================
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(100))]
+
+    /// `StatusOutput::to_json` always places `"type"`, `"load"`, `"values"`,
+    /// `"strategy"`, `"uptime_ms"` at monotonically increasing byte offsets,
+    /// regardless of the values involved - field order is part of the
+    /// output contract, not an accident of struct declaration order.
+    #[test]
+    fn prop_status_output_field_order_is_stable(
+        load in arb_load_vec(),
+        values in arb_values_vec(),
+        strategy in arb_strategy(),
+        uptime_ms in arb_uptime(),
+    ) {
+        let status = StatusOutput::new(load, values, strategy, uptime_ms);
+        let json = status.to_json().unwrap();
+
+        let type_pos = json.find("\"type\"").unwrap();
+        let load_pos = json.find("\"load\"").unwrap();
+        let values_pos = json.find("\"values\"").unwrap();
+        let strategy_pos = json.find("\"strategy\"").unwrap();
+        let uptime_pos = json.find("\"uptime_ms\"").unwrap();
+
+        prop_assert!(type_pos < load_pos);
+        prop_assert!(load_pos < values_pos);
+        prop_assert!(values_pos < strategy_pos);
+        prop_assert!(strategy_pos < uptime_pos);
+    }
+
+    /// `TransitionOutput::to_json` always places `"type"`, `"from"`, `"to"`,
+    /// `"progress"` at monotonically increasing byte offsets.
+    #[test]
+    fn prop_transition_output_field_order_is_stable(
+        from in arb_values_vec(),
+        to in arb_values_vec(),
+        progress in 0.0f32..=1.0f32,
+    ) {
+        let transition = TransitionOutput::new(from, to, progress);
+        let json = transition.to_json().unwrap();
+
+        let type_pos = json.find("\"type\"").unwrap();
+        let from_pos = json.find("\"from\"").unwrap();
+        let to_pos = json.find("\"to\"").unwrap();
+        let progress_pos = json.find("\"progress\"").unwrap();
+
+        prop_assert!(type_pos < from_pos);
+        prop_assert!(from_pos < to_pos);
+        prop_assert!(to_pos < progress_pos);
+    }
+
+    /// `ErrorOutput::to_json` always places `"type"`, `"code"`, `"message"`
+    /// at monotonically increasing byte offsets.
+    #[test]
+    fn prop_error_output_field_order_is_stable(
+        code in "[a-z_]{1,20}",
+        message in ".{1,100}",
+    ) {
+        let error = ErrorOutput::new(&code, &message);
+        let json = error.to_json().unwrap();
+
+        let type_pos = json.find("\"type\"").unwrap();
+        let code_pos = json.find("\"code\"").unwrap();
+        let message_pos = json.find("\"message\"").unwrap();
+
+        prop_assert!(type_pos < code_pos);
+        prop_assert!(code_pos < message_pos);
+    }
+}
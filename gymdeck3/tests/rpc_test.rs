@@ -0,0 +1,109 @@
+//! Property-based tests for the JSON-RPC 2.0 control channel
+//!
+//! These tests verify that RpcRequest/RpcResponse round-trip cleanly and
+//! that validate_rpc_request/validate_rpc_response reject malformed input,
+//! mirroring the coverage of validate_status_output in output_test.rs.
+
+use proptest::prelude::*;
+use gymdeck3::{
+    Strategy,
+    RpcRequest,
+    RpcResponse,
+    RpcError,
+    validate_rpc_request,
+    validate_rpc_response,
+    RPC_METHOD_NOT_FOUND,
+    RPC_INVALID_PARAMS,
+};
+
+fn arb_strategy() -> impl proptest::strategy::Strategy<Value = Strategy> {
+    prop_oneof![
+        Just(Strategy::Conservative),
+        Just(Strategy::Balanced),
+        Just(Strategy::Aggressive),
+        Just(Strategy::Custom),
+    ]
+}
+
+fn arb_method() -> impl proptest::strategy::Strategy<Value = String> {
+    "[a-z_]{1,20}"
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(100))]
+
+    /// RpcRequest round-trip: serialize then deserialize preserves data
+    #[test]
+    fn prop_rpc_request_roundtrip(
+        method in arb_method(),
+        id in 0i64..=i64::MAX / 2,
+    ) {
+        let request = RpcRequest::new(method.clone(), None, Some(serde_json::Value::from(id)));
+        let json = request.to_json().expect("Serialization should succeed");
+        let deserialized: RpcRequest = serde_json::from_str(&json)
+            .expect("Deserialization should succeed");
+
+        prop_assert_eq!(request, deserialized);
+    }
+
+    /// RpcRequest JSON output is newline-free (NDJSON compatible)
+    #[test]
+    fn prop_rpc_request_no_newlines(method in arb_method()) {
+        let request = RpcRequest::new(method, None, None);
+        let json = request.to_json().expect("Serialization should succeed");
+        prop_assert!(!json.contains('\n'));
+        prop_assert!(!json.contains('\r'));
+    }
+
+    /// RpcResponse round-trip for successful responses
+    #[test]
+    fn prop_rpc_response_success_roundtrip(strategy in arb_strategy(), id in 0i64..=i64::MAX / 2) {
+        let result = serde_json::json!({"strategy": strategy});
+        let response = RpcResponse::success(result, Some(serde_json::Value::from(id)));
+        let json = response.to_json().expect("Serialization should succeed");
+        let deserialized: RpcResponse = serde_json::from_str(&json)
+            .expect("Deserialization should succeed");
+
+        prop_assert_eq!(response, deserialized);
+    }
+
+    /// validate_rpc_request accepts any well-formed request
+    #[test]
+    fn prop_validate_rpc_request_accepts_valid(method in arb_method()) {
+        let request = RpcRequest::new(method, None, Some(serde_json::Value::from(1)));
+        let json = request.to_json().unwrap();
+        prop_assert!(validate_rpc_request(&json).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod validation_tests {
+    use super::*;
+
+    #[test]
+    fn test_method_not_found_error_code() {
+        let error = RpcError::method_not_found("unknown_method");
+        assert_eq!(error.code, RPC_METHOD_NOT_FOUND);
+    }
+
+    #[test]
+    fn test_invalid_params_error_code() {
+        let error = RpcError::invalid_params("missing field");
+        assert_eq!(error.code, RPC_INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_validate_rpc_response_rejects_both_result_and_error() {
+        let json = r#"{"jsonrpc":"2.0","result":"ok","error":{"code":-32601,"message":"x"},"id":1}"#;
+        let result = validate_rpc_response(json);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("cannot have both"));
+    }
+
+    #[test]
+    fn test_validate_rpc_request_rejects_invalid_json() {
+        let result = validate_rpc_request("not valid json");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Invalid JSON"));
+    }
+}
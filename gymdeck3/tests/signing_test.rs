@@ -0,0 +1,81 @@
+//! Property-based tests for signed status envelopes
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use proptest::prelude::*;
+use proptest::strategy::Strategy as _;
+use gymdeck3::{verify_signed, SignedEnvelope, Strategy, StatusOutput, DEFAULT_FRESHNESS_LEEWAY_MS};
+
+const TEST_KEY: &[u8] = b"proptest-shared-secret";
+
+fn arb_status() -> impl proptest::strategy::Strategy<Value = StatusOutput> {
+    (
+        proptest::collection::vec(0.0f32..=100.0f32, 1..=8),
+        proptest::collection::vec(-100i32..=0i32, 1..=8),
+        prop_oneof![
+            Just(Strategy::Conservative),
+            Just(Strategy::Balanced),
+            Just(Strategy::Aggressive),
+            Just(Strategy::Custom),
+        ],
+        0u64..=1_000_000u64,
+    )
+        .prop_map(|(load, values, strategy, uptime_ms)| {
+            StatusOutput::new(load, values, strategy, uptime_ms)
+        })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(100))]
+
+    /// A correctly signed envelope always verifies against its own key.
+    #[test]
+    fn prop_sign_verify_roundtrip(status in arb_status()) {
+        let envelope = status.sign(TEST_KEY).unwrap();
+        let verified = verify_signed(&envelope, TEST_KEY, status.uptime_ms, DEFAULT_FRESHNESS_LEEWAY_MS);
+        prop_assert_eq!(verified, Ok(status));
+    }
+
+    /// Tampering with any single byte of the payload invalidates the signature.
+    #[test]
+    fn prop_tamper_any_payload_byte_fails_verification(
+        status in arb_status(),
+        byte_index in 0usize..64,
+        flip_bits in 1u8..=255u8,
+    ) {
+        let envelope_str = status.sign(TEST_KEY).unwrap();
+        let mut parsed: SignedEnvelope = serde_json::from_str(&envelope_str).unwrap();
+
+        let mut payload_bytes = URL_SAFE_NO_PAD.decode(&parsed.payload).unwrap();
+        if payload_bytes.is_empty() {
+            return Ok(());
+        }
+        let idx = byte_index % payload_bytes.len();
+        payload_bytes[idx] ^= flip_bits;
+        parsed.payload = URL_SAFE_NO_PAD.encode(&payload_bytes);
+
+        let tampered = serde_json::to_string(&parsed).unwrap();
+        let result = verify_signed(&tampered, TEST_KEY, status.uptime_ms, DEFAULT_FRESHNESS_LEEWAY_MS);
+        prop_assert!(result.is_err());
+    }
+
+    /// Tampering with any single byte of the signature invalidates verification.
+    #[test]
+    fn prop_tamper_any_sig_byte_fails_verification(
+        status in arb_status(),
+        byte_index in 0usize..32,
+        flip_bits in 1u8..=255u8,
+    ) {
+        let envelope_str = status.sign(TEST_KEY).unwrap();
+        let mut parsed: SignedEnvelope = serde_json::from_str(&envelope_str).unwrap();
+
+        let mut sig_bytes = URL_SAFE_NO_PAD.decode(&parsed.sig).unwrap();
+        let idx = byte_index % sig_bytes.len();
+        sig_bytes[idx] ^= flip_bits;
+        parsed.sig = URL_SAFE_NO_PAD.encode(&sig_bytes);
+
+        let tampered = serde_json::to_string(&parsed).unwrap();
+        let result = verify_signed(&tampered, TEST_KEY, status.uptime_ms, DEFAULT_FRESHNESS_LEEWAY_MS);
+        prop_assert!(result.is_err());
+    }
+}
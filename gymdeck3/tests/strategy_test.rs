@@ -225,7 +225,7 @@ proptest! {
             Just(StrategyEnum::Aggressive),
         ]
     ) {
-        let strategy = create_strategy(strategy_type, None);
+        let strategy = create_strategy(strategy_type, None, None);
         let target = strategy.calculate_target(load, &bounds);
         
         prop_assert!(